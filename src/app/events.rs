@@ -53,7 +53,7 @@ pub enum AppEvent {
 
     /// Query execution completed
     QueryExecutionComplete {
-        result: Result<(Vec<String>, Vec<Vec<String>>), String>,
+        result: Result<(Vec<String>, Vec<Vec<Option<String>>>), String>,
     },
 
     /// Table data load completed
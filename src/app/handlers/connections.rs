@@ -5,8 +5,14 @@
 #![forbid(unsafe_code)]
 
 use crate::{
-    app::{App, ConnectionEvent, TestConnectionEvent},
+    app::{
+        shortcuts::{Action, ActionContext, KeyResolution},
+        App, ConnectionEvent, TestConnectionEvent,
+    },
+    commands::CommandId,
     core::error::Result,
+    database::SslMode,
+    ui::components::{ConnectionModalState, UnlockThen},
 };
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
@@ -25,80 +31,7 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
                     .update_filtered_connections(&app.state.db.connections.connections);
             }
             KeyCode::Enter => {
-                // Get selected connection index
-                let selected_index = if let Some(index) = app
-                    .state
-                    .ui
-                    .get_selected_connection_index(&app.state.db.connections.connections)
-                {
-                    index
-                } else {
-                    return Ok(()); // No connection selected
-                };
-
-                // Don't start new connection if one is already in progress
-                if app.state.connecting_in_progress.is_some() {
-                    app.state
-                        .toast_manager
-                        .warning("Connection attempt already in progress");
-                    return Ok(());
-                }
-
-                // Mark connection as in progress
-                app.state.connecting_in_progress = Some(selected_index);
-                app.state.connecting_animation_frame = 0;
-                app.state.connection_start_time = Some(std::time::Instant::now());
-
-                // Set status to connecting immediately
-                if let Some(conn) = app.state.db.connections.connections.get_mut(selected_index) {
-                    conn.status = crate::database::ConnectionStatus::Connecting;
-                    app.state
-                        .toast_manager
-                        .info(format!("Connecting to {}...", conn.name));
-                }
-
-                // Clone necessary data for background task
-                let connection_config =
-                    app.state.db.connections.connections[selected_index].clone();
-                let connection_manager = app.state.connection_manager.clone();
-                let tx = app.connection_events_tx.clone();
-
-                // Spawn connection task in background
-                tokio::spawn(async move {
-                    // Attempt to establish connection
-                    match connection_manager.connect(&connection_config).await {
-                        Ok(_) => {
-                            // Connection succeeded, now get database objects
-                            match connection_manager
-                                .list_database_objects(&connection_config.id)
-                                .await
-                            {
-                                Ok(objects) => {
-                                    // Send success event
-                                    let _ = tx.send(ConnectionEvent::Success {
-                                        connection_index: selected_index,
-                                        objects,
-                                    });
-                                }
-                                Err(e) => {
-                                    // Connection succeeded but listing objects failed
-                                    let _ = tx.send(ConnectionEvent::Failed {
-                                        connection_index: selected_index,
-                                        error: format!("Failed to load database objects: {}", e),
-                                    });
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            // Connection failed
-                            let _ = tx.send(ConnectionEvent::Failed {
-                                connection_index: selected_index,
-                                error: e.to_string(),
-                            });
-                        }
-                    }
-                });
-
+                connect_selected(app);
                 app.state.ui.exit_connections_search();
             }
             KeyCode::Down => {
@@ -122,111 +55,67 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
         return Ok(());
     }
 
-    // Normal mode - direct key bindings
+    // Pane actions go through the configurable hotkey table
+    match app.state.hotkeys.resolve(ActionContext::Connections, key) {
+        KeyResolution::Action(action) => return handle_action(app, action).await,
+        KeyResolution::Pending(hint) => {
+            app.state.toast_manager.info(hint);
+            return Ok(());
+        }
+        KeyResolution::Unbound => {}
+    }
+
+    // Fixed motions
     match key.code {
-        // 'a' - Add new connection
-        KeyCode::Char('a') => {
+        // j/k or arrow keys - Navigate
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.state
+                .ui
+                .connections_selection_down(&app.state.db.connections.connections);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.state
+                .ui
+                .connections_selection_up(&app.state.db.connections.connections);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Run a connections pane action resolved from the hotkey table
+async fn handle_action(app: &mut App, action: Action) -> Result<()> {
+    match action {
+        Action::AddConnection => {
             app.state.open_add_connection_modal();
         }
-        // 'e' - Edit selected connection
-        KeyCode::Char('e') => {
+        Action::EditConnection => {
             app.state.open_edit_connection_modal();
         }
-        // 'd' - Delete selected connection
-        KeyCode::Char('d') => {
-            if !app.state.db.connections.connections.is_empty() {
-                let index = app.state.ui.selected_connection;
-                app.state.ui.confirmation_modal = Some(crate::ui::ConfirmationModal {
-                    title: "Delete Connection".to_string(),
-                    message: format!(
-                        "Are you sure you want to delete the connection '{}'?",
-                        app.state.db.connections.connections[index].name
-                    ),
-                    action: crate::ui::ConfirmationAction::DeleteConnection(index),
-                });
-            }
+        Action::DuplicateConnection => {
+            app.state.open_duplicate_connection_modal();
         }
-        // Enter or Space - Connect to selected database
-        KeyCode::Enter | KeyCode::Char(' ') => {
-            // Get selected connection index
-            let selected_index = if let Some(index) = app
-                .state
-                .ui
-                .get_selected_connection_index(&app.state.db.connections.connections)
-            {
-                index
-            } else {
-                return Ok(()); // No connection selected
-            };
-
-            // Don't start new connection if one is already in progress
-            if app.state.connecting_in_progress.is_some() {
-                app.state
-                    .toast_manager
-                    .warning("Connection attempt already in progress");
-                return Ok(());
-            }
-
-            // Mark connection as in progress
-            app.state.connecting_in_progress = Some(selected_index);
-            app.state.connecting_animation_frame = 0;
-            app.state.connection_start_time = Some(std::time::Instant::now());
-
-            // Set status to connecting immediately (for visual feedback)
-            if let Some(conn) = app.state.db.connections.connections.get_mut(selected_index) {
-                conn.status = crate::database::ConnectionStatus::Connecting;
-                app.state
-                    .toast_manager
-                    .info(format!("Connecting to {}...", conn.name));
-            }
-
-            // Clone necessary data for background task
-            let connection_config = app.state.db.connections.connections[selected_index].clone();
-            let connection_manager = app.state.connection_manager.clone();
-            let tx = app.connection_events_tx.clone();
-
-            // Spawn connection task in background
-            tokio::spawn(async move {
-                // Attempt to establish connection
-                match connection_manager.connect(&connection_config).await {
-                    Ok(_) => {
-                        // Connection succeeded, now get database objects
-                        match connection_manager
-                            .list_database_objects(&connection_config.id)
-                            .await
-                        {
-                            Ok(objects) => {
-                                // Send success event
-                                let _ = tx.send(ConnectionEvent::Success {
-                                    connection_index: selected_index,
-                                    objects,
-                                });
-                            }
-                            Err(e) => {
-                                // Connection succeeded but listing objects failed
-                                let _ = tx.send(ConnectionEvent::Failed {
-                                    connection_index: selected_index,
-                                    error: format!("Failed to load database objects: {}", e),
-                                });
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        // Connection failed
-                        let _ = tx.send(ConnectionEvent::Failed {
-                            connection_index: selected_index,
-                            error: e.to_string(),
-                        });
-                    }
-                }
+        Action::AddConnectionFromTemplate => {
+            app.state.open_connection_template_list();
+        }
+        Action::DeleteConnection if !app.state.db.connections.connections.is_empty() => {
+            let index = app.state.ui.selected_connection;
+            app.state.ui.confirmation_modal = Some(crate::ui::ConfirmationModal {
+                title: "Delete Connection".to_string(),
+                message: format!(
+                    "Are you sure you want to delete the connection '{}'?",
+                    app.state.db.connections.connections[index].name
+                ),
+                action: crate::ui::ConfirmationAction::DeleteConnection(index),
             });
         }
-        // 'r' - Refresh connections list
-        KeyCode::Char('r') => {
+        Action::Connect => {
+            connect_selected(app);
+        }
+        Action::RefreshConnections => {
             app.state.toast_manager.info("Connections refreshed");
         }
-        // 'x' - Disconnect from current database
-        KeyCode::Char('x') => {
+        Action::Disconnect => {
             let selected = app.state.ui.selected_connection;
             if let Some(connection) = app.state.db.connections.connections.get(selected).cloned() {
                 let connection_id = connection.id.clone();
@@ -245,26 +134,191 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
                     .info(format!("Disconnected from {}", connection_name));
             }
         }
-        // '/' - Enter search mode
-        KeyCode::Char('/') => {
+        Action::SearchConnections => {
             app.state.ui.enter_connections_search();
         }
-        // j/k or arrow keys - Navigate
-        KeyCode::Char('j') | KeyCode::Down => {
-            app.state
-                .ui
-                .connections_selection_down(&app.state.db.connections.connections);
+        Action::ImportConnections => {
+            app.execute_command(CommandId::ImportConnectionsFromSystem)
+                .await?;
         }
-        KeyCode::Char('k') | KeyCode::Up => {
-            app.state
-                .ui
-                .connections_selection_up(&app.state.db.connections.connections);
+        Action::ToggleFavoriteConnection => {
+            // Re-sorting shifts indices the background connect task reports back with
+            if app.state.connecting_in_progress.is_some() {
+                app.state
+                    .toast_manager
+                    .warning("Wait for the connection attempt to finish");
+                return Ok(());
+            }
+            let index = app.state.ui.selected_connection;
+            match app.state.db.connections.toggle_favorite(index).await {
+                Ok(new_index) => {
+                    app.state.ui.selected_connection = new_index;
+                    app.state.ui.connections_list_state.select(Some(new_index));
+                    let connection = &app.state.db.connections.connections[new_index];
+                    let message = if connection.favorite {
+                        format!("Pinned {}", connection.name)
+                    } else {
+                        format!("Unpinned {}", connection.name)
+                    };
+                    app.state.toast_manager.info(message);
+                }
+                Err(e) => {
+                    app.state
+                        .toast_manager
+                        .error(format!("Failed to update favorite: {e}"));
+                }
+            }
         }
         _ => {}
     }
     Ok(())
 }
 
+/// Handle keys while the `A` template list is open. Enter fills the add
+/// form from the highlighted template, `d` removes it.
+pub(crate) fn handle_template_list(app: &mut App, key: KeyEvent) {
+    let Some(list) = app.state.connection_template_list.as_mut() else {
+        return;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.state.connection_template_list = None,
+        KeyCode::Char('j') | KeyCode::Down => list.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => list.select_previous(),
+        KeyCode::Char('d') => {
+            if let Some(removed) = list.remove_selected() {
+                app.state.save_connection_template_list();
+                app.state
+                    .toast_manager
+                    .info(format!("Removed template {}", removed.name));
+            }
+        }
+        KeyCode::Enter => {
+            let template = list.selected_template().cloned();
+            app.state.connection_template_list = None;
+            if let Some(template) = template {
+                app.state.open_add_connection_from_template(&template);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Start connecting to the selected connection in the background
+pub(crate) fn connect_selected(app: &mut App) {
+    // Get selected connection index
+    let Some(selected_index) = app
+        .state
+        .ui
+        .get_selected_connection_index(&app.state.db.connections.connections)
+    else {
+        return; // No connection selected
+    };
+
+    // Don't start new connection if one is already in progress
+    if app.state.connecting_in_progress.is_some() {
+        app.state
+            .toast_manager
+            .warning("Connection attempt already in progress");
+        return;
+    }
+
+    // An encrypted password needs the session key; connect once it is typed
+    if app.state.db.connections.connections[selected_index].requires_encryption_key()
+        && !app.state.connection_manager.is_unlocked()
+    {
+        app.state.open_unlock_prompt(UnlockThen::Connect);
+        return;
+    }
+
+    // Mark connection as in progress
+    app.state.connecting_in_progress = Some(selected_index);
+    app.state.connecting_animation_frame = 0;
+    app.state.connection_start_time = Some(std::time::Instant::now());
+
+    // Set status to connecting immediately (for visual feedback)
+    if let Some(conn) = app.state.db.connections.connections.get_mut(selected_index) {
+        conn.status = crate::database::ConnectionStatus::Connecting;
+        app.state
+            .toast_manager
+            .info(format!("Connecting to {}...", conn.name));
+    }
+
+    // Clone necessary data for background task
+    let connection_config = app.state.db.connections.connections[selected_index].clone();
+    let connection_manager = app.state.connection_manager.clone();
+    let tx = app.connection_events_tx.clone();
+
+    // Switching connections abandons any listing still streaming for the old one
+    app.state.cancel_object_listing();
+
+    // Spawn connection task in background; objects are streamed once it succeeds
+    tokio::spawn(async move {
+        let event = match connection_manager.connect(&connection_config).await {
+            Ok(_) => ConnectionEvent::Success {
+                connection_index: selected_index,
+            },
+            Err(e) => ConnectionEvent::Failed {
+                connection_index: selected_index,
+                error: e.to_string(),
+            },
+        };
+        let _ = tx.send(event);
+    });
+}
+
+/// Type the session key; Enter checks it and carries on with what waited on it
+pub(crate) async fn handle_unlock_prompt(app: &mut App, key: KeyEvent) {
+    let Some(prompt) = app.state.unlock_prompt.as_mut() else {
+        return;
+    };
+    match key.code {
+        KeyCode::Esc => {
+            app.state.unlock_prompt = None;
+            app.state
+                .toast_manager
+                .info("Encrypted passwords stay locked until you connect to one");
+        }
+        KeyCode::Backspace => {
+            prompt.input.pop();
+        }
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            prompt.input.push(c);
+        }
+        KeyCode::Enter => match app.state.submit_unlock() {
+            Some(UnlockThen::Nothing) => {
+                app.state
+                    .toast_manager
+                    .success("Encrypted passwords unlocked for this session");
+            }
+            Some(UnlockThen::Connect) => connect_selected(app),
+            Some(UnlockThen::SaveConnection) => save_connection_form(app).await,
+            None => {}
+        },
+        _ => {}
+    }
+}
+
+/// Save the connection form, asking for the session key first when its
+/// password is to be encrypted
+pub(crate) async fn save_connection_form(app: &mut App) {
+    if app.state.connection_modal_state.encrypts_password()
+        && !app.state.connection_manager.is_unlocked()
+    {
+        app.state.open_unlock_prompt(UnlockThen::SaveConnection);
+        return;
+    }
+    if let Err(error) = app.state.save_connection_from_modal().await {
+        app.state
+            .toast_manager
+            .error(format!("Failed to save connection: {}", &error));
+        app.state.connection_modal_state.error_message = Some(error);
+    } else {
+        app.state
+            .toast_manager
+            .success("Connection saved successfully");
+    }
+}
+
 /// Handle connection modal key event
 pub(crate) async fn handle_connection_modal(app: &mut App, key: KeyEvent) -> Result<()> {
     use crate::ui::components::{ConnectionField, PasswordStorageType};
@@ -279,6 +333,47 @@ pub(crate) async fn handle_connection_modal(app: &mut App, key: KeyEvent) -> Res
             return Ok(());
         }
 
+        // SQLite file picker takes all keys while open
+        _ if app.state.connection_modal_state.file_picker.is_some() => {
+            handle_file_picker(app, key);
+        }
+
+        // SQLite database file: Tab completes the path, Ctrl+O browses, Ctrl+N creates
+        KeyCode::Tab if app.state.connection_modal_state.is_sqlite_path_focused() => {
+            app.state
+                .connection_modal_state
+                .complete_sqlite_path_or_advance();
+        }
+        KeyCode::Char('o')
+            if key.modifiers.contains(KeyModifiers::CONTROL)
+                && app.state.connection_modal_state.is_sqlite_path_focused() =>
+        {
+            let fallback_dir = app
+                .state
+                .ui
+                .last_sqlite_dir
+                .as_ref()
+                .map(std::path::PathBuf::from);
+            app.state
+                .connection_modal_state
+                .open_file_picker(fallback_dir);
+        }
+        KeyCode::Char('n')
+            if key.modifiers.contains(KeyModifiers::CONTROL)
+                && app.state.connection_modal_state.is_sqlite_path_focused() =>
+        {
+            match app.state.connection_modal_state.create_sqlite_file() {
+                Ok(path) => {
+                    app.state
+                        .toast_manager
+                        .success(format!("Created {}", path.display()));
+                }
+                Err(e) => {
+                    app.state.connection_modal_state.error_message = Some(e);
+                }
+            }
+        }
+
         // PRIORITY 1: Global shortcuts (work from any field EXCEPT text input fields)
         KeyCode::Char('t')
             if !key.modifiers.contains(KeyModifiers::CONTROL)
@@ -289,16 +384,7 @@ pub(crate) async fn handle_connection_modal(app: &mut App, key: KeyEvent) -> Res
         }
         KeyCode::Char('s') if !app.state.connection_modal_state.is_text_field() => {
             // Save shortcut - works from any field except text input fields
-            if let Err(error) = app.state.save_connection_from_modal().await {
-                app.state
-                    .toast_manager
-                    .error(format!("Failed to save connection: {}", &error));
-                app.state.connection_modal_state.error_message = Some(error);
-            } else {
-                app.state
-                    .toast_manager
-                    .success("Connection saved successfully");
-            }
+            save_connection_form(app).await;
         }
         KeyCode::Char('c') if !app.state.connection_modal_state.is_text_field() => {
             // Cancel shortcut - works from any field except text input fields
@@ -340,7 +426,7 @@ pub(crate) async fn handle_connection_modal(app: &mut App, key: KeyEvent) -> Res
                         .db_type_list_state
                         .selected()
                         .unwrap_or(0);
-                    let max_types = 4; // PostgreSQL, MySQL, MariaDB, SQLite
+                    let max_types = ConnectionModalState::pickable_database_types().len();
                     let new_index = if current + 1 < max_types {
                         current + 1
                     } else {
@@ -358,7 +444,7 @@ pub(crate) async fn handle_connection_modal(app: &mut App, key: KeyEvent) -> Res
                         .ssl_list_state
                         .selected()
                         .unwrap_or(0);
-                    let max_modes = 6; // All SSL modes
+                    let max_modes = SslMode::ALL.len();
                     let new_index = if current + 1 < max_modes {
                         current + 1
                     } else {
@@ -390,7 +476,7 @@ pub(crate) async fn handle_connection_modal(app: &mut App, key: KeyEvent) -> Res
                         .db_type_list_state
                         .selected()
                         .unwrap_or(0);
-                    let max_types = 4; // PostgreSQL, MySQL, MariaDB, SQLite
+                    let max_types = ConnectionModalState::pickable_database_types().len();
                     let new_index = if current > 0 {
                         current - 1
                     } else {
@@ -408,7 +494,7 @@ pub(crate) async fn handle_connection_modal(app: &mut App, key: KeyEvent) -> Res
                         .ssl_list_state
                         .selected()
                         .unwrap_or(0);
-                    let max_modes = 6; // All SSL modes
+                    let max_modes = SslMode::ALL.len();
                     let new_index = if current > 0 {
                         current - 1
                     } else {
@@ -420,9 +506,10 @@ pub(crate) async fn handle_connection_modal(app: &mut App, key: KeyEvent) -> Res
                     // Cycle backwards through password storage types
                     app.state.connection_modal_state.password_storage_type =
                         match app.state.connection_modal_state.password_storage_type {
-                            PasswordStorageType::PlainText => PasswordStorageType::Encrypted,
+                            PasswordStorageType::PlainText => PasswordStorageType::Keychain,
                             PasswordStorageType::Environment => PasswordStorageType::PlainText,
                             PasswordStorageType::Encrypted => PasswordStorageType::Environment,
+                            PasswordStorageType::Keychain => PasswordStorageType::Encrypted,
                         };
                 }
                 _ => {
@@ -441,19 +528,11 @@ pub(crate) async fn handle_connection_modal(app: &mut App, key: KeyEvent) -> Res
                 }
                 ConnectionField::Save => {
                     // Activate Save button
-                    if let Err(error) = app.state.save_connection_from_modal().await {
-                        app.state
-                            .toast_manager
-                            .error(format!("Failed to save connection: {}", &error));
-                        app.state.connection_modal_state.error_message = Some(error);
-                    } else {
-                        app.state
-                            .toast_manager
-                            .success("Connection saved successfully");
-                    }
+                    save_connection_form(app).await;
                 }
                 ConnectionField::Cancel => {
-                    // Activate Cancel button
+                    // Activate Cancel button, dropping any test still in flight
+                    abort_test_connection(app);
                     if app.state.ui.current_view.is_connection_form() {
                         app.state.close_add_connection_modal();
                     } else {
@@ -480,9 +559,11 @@ pub(crate) async fn handle_connection_modal(app: &mut App, key: KeyEvent) -> Res
                         crate::database::DatabaseType::PostgreSQL => "5432".to_string(),
                         crate::database::DatabaseType::MySQL
                         | crate::database::DatabaseType::MariaDB => "3306".to_string(),
+                        crate::database::DatabaseType::Redis => "6379".to_string(),
+                        crate::database::DatabaseType::MongoDB => "27017".to_string(),
                         _ => "5432".to_string(),
                     };
-                app.state.connection_modal_state.database.clear();
+                app.state.connection_modal_state.set_database(String::new());
                 app.state.connection_modal_state.username.clear();
                 app.state.connection_modal_state.password.clear();
             } else {
@@ -506,9 +587,44 @@ pub(crate) async fn handle_connection_modal(app: &mut App, key: KeyEvent) -> Res
     Ok(())
 }
 
+/// Handle keys while the SQLite file picker is open
+fn handle_file_picker(app: &mut App, key: KeyEvent) {
+    let modal = &mut app.state.connection_modal_state;
+    let Some(picker) = modal.file_picker.as_mut() else {
+        return;
+    };
+
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => picker.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => picker.select_previous(),
+        KeyCode::Char('h') | KeyCode::Left | KeyCode::Backspace => picker.go_up(),
+        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => {
+            if let Some(path) = picker.activate() {
+                match crate::ui::components::file_picker::check_sqlite_header(&path) {
+                    Ok(()) => {
+                        app.state.ui.last_sqlite_dir =
+                            path.parent().map(|dir| dir.display().to_string());
+                        modal.set_database(path.display().to_string());
+                        modal.error_message = None;
+                        modal.test_status = None;
+                        modal.file_picker = None;
+                    }
+                    Err(e) => {
+                        modal.error_message = Some(e);
+                    }
+                }
+            }
+        }
+        KeyCode::Esc => {
+            modal.file_picker = None;
+        }
+        _ => {}
+    }
+}
+
 /// Test connection from modal
 async fn test_connection_from_modal(app: &mut App) {
-    use crate::ui::components::TestConnectionStatus;
+    use crate::ui::components::{PasswordStorageType, TestConnectionStatus};
 
     // Don't start new test if one is already in progress
     if app.state.test_connection_in_progress {
@@ -523,7 +639,7 @@ async fn test_connection_from_modal(app: &mut App) {
     app.state.test_start_time = Some(std::time::Instant::now());
 
     // Try to create a connection config (no uniqueness check needed for testing)
-    let config = match app
+    let mut config = match app
         .state
         .connection_modal_state
         .try_create_connection(&[], None)
@@ -540,93 +656,151 @@ async fn test_connection_from_modal(app: &mut App) {
         }
     };
 
+    // Keychain and encrypted passwords are only written on save, so test with
+    // the typed one, or with the stored one when editing and the field was left blank
+    if matches!(
+        app.state.connection_modal_state.password_storage_type,
+        PasswordStorageType::Keychain | PasswordStorageType::Encrypted
+    ) {
+        let password = app.state.connection_modal_state.password.trim();
+        if !password.is_empty() {
+            config.set_plain_password(password.to_string());
+        } else if let Some(source) = app.state.edited_password_source() {
+            config.set_password_source(source);
+        }
+    }
+
+    // The test connects without the connection manager, so decrypt here
+    if config.requires_encryption_key() {
+        let key = app.state.connection_manager.session_key();
+        if let Ok(password) = config.resolve_password(key.as_ref().map(|k| k.expose())) {
+            config.set_plain_password(password);
+        }
+    }
+
     // Clone sender for background task
     let tx = app.test_connection_events_tx.clone();
+    let timeout_secs = app.state.test_timeout_seconds;
 
     // Spawn background task to test connection and store handle for abort capability
     let handle = tokio::spawn(async move {
         use crate::core::error::LazyTablesError;
         use crate::database::{Connection, DatabaseType};
 
-        let result = match config.database_type {
-            DatabaseType::PostgreSQL => {
-                use crate::database::postgres::PostgresConnection;
-                let mut conn = PostgresConnection::new(config);
+        let test = async move {
+            match config.database_type {
+                DatabaseType::PostgreSQL => {
+                    use crate::database::postgres::PostgresConnection;
+                    let mut conn = PostgresConnection::new(config);
 
-                match conn.connect().await {
-                    Ok(()) => {
-                        // Connection succeeded, now test it
-                        conn.test_connection()
-                            .await
-                            .map(|_| "Connection successful!".to_string())
-                    }
-                    Err(e) => {
-                        // Parse error into structured ConnectionError
-                        if let LazyTablesError::Database(ref sqlx_err) = e {
-                            Err(LazyTablesError::ConnectionFailed(
-                                conn.parse_connection_error(sqlx_err),
-                            ))
-                        } else {
-                            Err(e)
+                    match conn.connect().await {
+                        Ok(()) => {
+                            // Connection succeeded, now test it
+                            conn.test_connection()
+                                .await
+                                .map(|_| "Connection successful!".to_string())
+                        }
+                        Err(e) => {
+                            // Parse error into structured ConnectionError
+                            if let LazyTablesError::Database(ref sqlx_err) = e {
+                                Err(LazyTablesError::ConnectionFailed(
+                                    conn.parse_connection_error(sqlx_err),
+                                ))
+                            } else {
+                                Err(e)
+                            }
                         }
                     }
                 }
-            }
-            DatabaseType::MySQL | DatabaseType::MariaDB => {
-                use crate::database::mysql::MySqlConnection;
-                let mut conn = MySqlConnection::new(config);
-
-                match conn.connect().await {
-                    Ok(()) => {
-                        // Connection succeeded, now test it
-                        conn.test_connection()
-                            .await
-                            .map(|_| "Connection successful!".to_string())
-                    }
-                    Err(e) => {
-                        // Parse error into structured ConnectionError
-                        if let LazyTablesError::Database(ref sqlx_err) = e {
-                            Err(LazyTablesError::ConnectionFailed(
-                                conn.parse_connection_error(sqlx_err),
-                            ))
-                        } else {
-                            Err(e)
+                DatabaseType::MySQL | DatabaseType::MariaDB => {
+                    use crate::database::mysql::MySqlConnection;
+                    let mut conn = MySqlConnection::new(config);
+
+                    match conn.connect().await {
+                        Ok(()) => {
+                            // Connection succeeded, now test it
+                            conn.test_connection()
+                                .await
+                                .map(|_| "Connection successful!".to_string())
+                        }
+                        Err(e) => {
+                            // Parse error into structured ConnectionError
+                            if let LazyTablesError::Database(ref sqlx_err) = e {
+                                Err(LazyTablesError::ConnectionFailed(
+                                    conn.parse_connection_error(sqlx_err),
+                                ))
+                            } else {
+                                Err(e)
+                            }
                         }
                     }
                 }
-            }
-            DatabaseType::SQLite => {
-                use crate::database::sqlite::SqliteConnection;
-                let mut conn = SqliteConnection::new(config);
-
-                match conn.connect().await {
-                    Ok(()) => {
-                        // Connection succeeded, now test it
-                        conn.test_connection()
-                            .await
-                            .map(|_| "Connection successful!".to_string())
-                    }
-                    Err(e) => {
-                        // Parse error into structured ConnectionError
-                        if let LazyTablesError::Database(ref sqlx_err) = e {
-                            Err(LazyTablesError::ConnectionFailed(
-                                conn.parse_connection_error(sqlx_err),
-                            ))
-                        } else {
-                            Err(e)
+                DatabaseType::SQLite => {
+                    use crate::database::sqlite::SqliteConnection;
+                    let mut conn = SqliteConnection::new(config);
+
+                    match conn.connect().await {
+                        Ok(()) => {
+                            // Connection succeeded, now test it
+                            conn.test_connection()
+                                .await
+                                .map(|_| "Connection successful!".to_string())
+                        }
+                        Err(e) => {
+                            // Parse error into structured ConnectionError
+                            if let LazyTablesError::Database(ref sqlx_err) = e {
+                                Err(LazyTablesError::ConnectionFailed(
+                                    conn.parse_connection_error(sqlx_err),
+                                ))
+                            } else {
+                                Err(e)
+                            }
                         }
                     }
                 }
+                DatabaseType::Oracle => {
+                    use crate::database::AdapterFactory;
+                    let mut conn = AdapterFactory::create_connection(config)?;
+                    conn.connect().await?;
+                    let info = conn.get_server_info().await;
+                    let _ = conn.disconnect().await;
+                    info.map(|info| format!("Connection successful! Oracle {}", info.version))
+                }
+                DatabaseType::MsSql => {
+                    use crate::database::AdapterFactory;
+                    let mut conn = AdapterFactory::create_connection(config)?;
+                    conn.connect().await?;
+                    let info = conn.get_server_info().await;
+                    let _ = conn.disconnect().await;
+                    info.map(|info| format!("Connection successful! SQL Server {}", info.version))
+                }
+                DatabaseType::Redis => {
+                    use crate::database::AdapterFactory;
+                    let mut conn = AdapterFactory::create_connection(config)?;
+                    conn.connect().await?;
+                    let info = conn.get_server_info().await;
+                    let _ = conn.disconnect().await;
+                    info.map(|info| format!("Connection successful! Redis {}", info.version))
+                }
+                DatabaseType::MongoDB => {
+                    use crate::database::AdapterFactory;
+                    let mut conn = AdapterFactory::create_connection(config)?;
+                    conn.connect().await?;
+                    let info = conn.get_server_info().await;
+                    let _ = conn.disconnect().await;
+                    info.map(|info| format!("Connection successful! MongoDB {}", info.version))
+                }
             }
-            _ => Err(LazyTablesError::Connection(
-                "Database type not yet supported".to_string(),
-            )),
         };
 
         // Send result back to main loop with properly formatted errors
-        let event = match result {
-            Ok(msg) => TestConnectionEvent::Success(msg),
-            Err(e) => {
+        let timeout = std::time::Duration::from_secs(timeout_secs);
+        let event = match tokio::time::timeout(timeout, test).await {
+            Err(_) => {
+                TestConnectionEvent::Failed(format!("Connection timed out after {timeout_secs}s"))
+            }
+            Ok(Ok(msg)) => TestConnectionEvent::Success(msg),
+            Ok(Err(e)) => {
                 // Format error for display
                 let error_message = match &e {
                     LazyTablesError::ConnectionFailed(conn_err) => {
@@ -647,9 +821,7 @@ async fn test_connection_from_modal(app: &mut App) {
 }
 
 /// Abort ongoing test connection
-fn abort_test_connection(app: &mut App) {
-    use crate::ui::components::TestConnectionStatus;
-
+pub(crate) fn abort_test_connection(app: &mut App) {
     // Only abort if test is actually in progress
     if !app.state.test_connection_in_progress {
         return;
@@ -660,10 +832,8 @@ fn abort_test_connection(app: &mut App) {
         handle.abort();
     }
 
-    // Update status to show test was aborted
-    app.state.connection_modal_state.test_status = Some(TestConnectionStatus::Failed(
-        "Test aborted by user".to_string(),
-    ));
+    // Reset the modal so the form can be edited and tested again
+    app.state.connection_modal_state.test_status = None;
 
     // Clear all test-related state
     app.state.test_connection_in_progress = false;
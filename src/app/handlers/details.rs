@@ -1,33 +1,107 @@
 // FilePath: src/app/handlers/details.rs
 
-// Event handler for the Details pane (read-only scrolling of table metadata)
+// Event handler for the Details pane (read-only scrolling of table metadata and column list)
 
 #![forbid(unsafe_code)]
 
 use crate::{app::App, core::error::Result};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-/// Handle Details pane keys - READ-ONLY (just scrolling)
-pub(crate) fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
+/// Handle Details pane keys: scrolling, column selection and copy, index create/drop
+/// and the table statistics view
+pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
+    if key.code != KeyCode::Char('y') {
+        app.state.ui.pending_details_yank = false;
+    }
+
+    let column_count = app
+        .state
+        .db
+        .current_table_metadata
+        .as_ref()
+        .map_or(0, |metadata| metadata.columns_summary.len());
+    let selectable = selectable_entries(app);
+
     match key.code {
         KeyCode::Char('j') | KeyCode::Down => {
-            app.state.move_down();
+            if app.state.ui.details_selected_column + 1 < selectable {
+                app.state.ui.details_selected_column += 1;
+                scroll_to_selected_column(app);
+            } else {
+                app.state.move_down();
+            }
         }
         KeyCode::Char('k') | KeyCode::Up => {
-            app.state.move_up();
+            let selected = app.state.ui.details_selected_column.min(selectable);
+            if selected > 0 {
+                app.state.ui.details_selected_column = selected - 1;
+                scroll_to_selected_column(app);
+            } else {
+                app.state.move_up();
+            }
         }
-        KeyCode::Char('d') if key.modifiers == KeyModifiers::CONTROL => {
-            // Page down
-            if app.state.ui.details_viewport_offset + 10 < app.state.ui.details_max_scroll_offset {
-                app.state.ui.details_viewport_offset += 10;
+        KeyCode::Char('y') => {
+            if app.state.ui.pending_details_yank {
+                app.state.ui.pending_details_yank = false;
+                let name = app
+                    .state
+                    .db
+                    .current_table_metadata
+                    .as_ref()
+                    .and_then(|metadata| {
+                        metadata
+                            .columns_summary
+                            .get(app.state.ui.details_selected_column)
+                            .map(|column| column.name.clone())
+                    });
+                match name {
+                    Some(name) => copy_to_clipboard(app, &name, &format!("Copied '{name}'")),
+                    None => app.state.toast_manager.warning("No column selected"),
+                }
             } else {
-                app.state.ui.details_viewport_offset = app.state.ui.details_max_scroll_offset;
+                app.state.ui.pending_details_yank = true;
+            }
+        }
+        KeyCode::Char('Y') => {
+            let text = app
+                .state
+                .db
+                .current_table_metadata
+                .as_ref()
+                .map(|metadata| {
+                    metadata
+                        .columns_summary
+                        .iter()
+                        .map(|column| column.describe())
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                });
+            match text {
+                Some(text) if !text.is_empty() => {
+                    let message = format!("Copied {column_count} columns");
+                    copy_to_clipboard(app, &text, &message);
+                }
+                _ => app.state.toast_manager.warning("No columns to copy"),
             }
         }
+        KeyCode::Char('d') if key.modifiers == KeyModifiers::CONTROL => {
+            // Half a page down
+            let ui = &mut app.state.ui;
+            ui.details_viewport_offset = (ui.details_viewport_offset + ui.details_half_page())
+                .min(ui.details_max_scroll_offset);
+        }
         KeyCode::Char('u') if key.modifiers == KeyModifiers::CONTROL => {
-            // Page up
-            app.state.ui.details_viewport_offset =
-                app.state.ui.details_viewport_offset.saturating_sub(10);
+            // Half a page up
+            let ui = &mut app.state.ui;
+            ui.details_viewport_offset = ui
+                .details_viewport_offset
+                .saturating_sub(ui.details_half_page());
+        }
+        KeyCode::Char('d') => {
+            confirm_drop_index(app);
+        }
+        KeyCode::Char('I') => {
+            open_index_modal(app);
         }
         KeyCode::Char('g') => {
             if app.state.ui.pending_gg_command {
@@ -37,6 +111,18 @@ pub(crate) fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
                 app.state.ui.pending_gg_command = true;
             }
         }
+        KeyCode::Char('P') => {
+            app.start_column_profile();
+        }
+        KeyCode::Char('p') => {
+            toggle_table_statistics(app).await;
+        }
+        KeyCode::Char('c') => {
+            count_rows_exactly(app).await;
+        }
+        KeyCode::Char('R') => {
+            refresh_materialized_view(app).await;
+        }
         KeyCode::Char('G') => {
             app.state.ui.details_viewport_offset = app.state.ui.details_max_scroll_offset;
         }
@@ -44,3 +130,212 @@ pub(crate) fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
     }
     Ok(())
 }
+
+/// Show usage statistics of the selected table in place of its details, or
+/// go back to the details when they are already shown
+async fn toggle_table_statistics(app: &mut App) {
+    let Some(table_name) = app.state.ui.get_selected_table_name() else {
+        app.state.toast_manager.warning("No table selected");
+        return;
+    };
+    if statistics_shown(app) {
+        app.state.db.table_statistics = None;
+        app.state.ui.details_viewport_offset = 0;
+        return;
+    }
+    if let Err(e) = app.state.load_table_statistics(&table_name).await {
+        app.state
+            .toast_manager
+            .error(format!("Failed to load statistics: {e}"));
+    }
+}
+
+/// Replace an estimated row count with an exact one
+async fn count_rows_exactly(app: &mut App) {
+    match app.state.count_table_rows().await {
+        Ok(count) => app
+            .state
+            .toast_manager
+            .success(format!("Exact count: {count} rows")),
+        Err(e) => app.state.toast_manager.error(e),
+    }
+}
+
+/// Whether the statistics view replaces the details of the selected table
+fn statistics_shown(app: &App) -> bool {
+    let selected = app.state.ui.get_selected_table_name();
+    app.state
+        .db
+        .table_statistics
+        .as_ref()
+        .is_some_and(|statistics| Some(&statistics.table_name) == selected.as_ref())
+}
+
+/// Refresh the materialized view shown in Details. With an index present,
+/// ask first whether to refresh `CONCURRENTLY`, which needs a unique index.
+async fn refresh_materialized_view(app: &mut App) {
+    let Some(metadata) = app.state.db.current_table_metadata.as_ref() else {
+        app.state.toast_manager.warning("No object selected");
+        return;
+    };
+    if !metadata.is_materialized_view() {
+        app.state
+            .toast_manager
+            .info("Only materialized views can be refreshed");
+        return;
+    }
+
+    let view_name = metadata.display_name();
+    if metadata.indexes.is_empty() {
+        match app.state.refresh_materialized_view(&view_name, false).await {
+            Ok(()) => app
+                .state
+                .toast_manager
+                .success(format!("Refreshed {view_name}")),
+            Err(e) => app
+                .state
+                .toast_manager
+                .error(format!("Failed to refresh {view_name}: {e}")),
+        }
+    } else {
+        app.state.ui.confirmation_modal = Some(crate::ui::ConfirmationModal {
+            title: "Refresh materialized view".to_string(),
+            message: format!(
+                "Refresh '{view_name}' CONCURRENTLY?\n\nReads are not blocked while it runs. \
+                 This needs a unique index on the view."
+            ),
+            action: crate::ui::ConfirmationAction::RefreshMaterializedView(view_name),
+        });
+    }
+}
+
+/// Open the create-index form for the table shown in Details
+fn open_index_modal(app: &mut App) {
+    let Some(metadata) = app.state.db.current_table_metadata.as_ref() else {
+        app.state.toast_manager.warning("No table selected");
+        return;
+    };
+    if !metadata.supports_indexes() || metadata.columns_summary.is_empty() {
+        app.state
+            .toast_manager
+            .info("Indexes can only be created on tables and materialized views");
+        return;
+    }
+    let Some(database_type) = app
+        .state
+        .get_selected_connection()
+        .map(|connection| connection.database_type.clone())
+    else {
+        app.state
+            .toast_manager
+            .warning("No active database connection");
+        return;
+    };
+
+    let columns = metadata
+        .columns_summary
+        .iter()
+        .map(|column| column.name.clone())
+        .collect();
+    app.state.index_modal = Some(crate::ui::components::IndexModal::new(
+        metadata.display_name(),
+        database_type,
+        columns,
+    ));
+}
+
+/// Ask before dropping the index highlighted in the Indexes section
+fn confirm_drop_index(app: &mut App) {
+    let Some(metadata) = app.state.db.current_table_metadata.as_ref() else {
+        return;
+    };
+    let Some(index) = app
+        .state
+        .ui
+        .details_selected_column
+        .checked_sub(metadata.columns_summary.len())
+        .and_then(|i| metadata.indexes.get(i))
+        .filter(|_| metadata.supports_indexes())
+    else {
+        app.state
+            .toast_manager
+            .info("Select an index in the Indexes section to drop it");
+        return;
+    };
+    if index.is_primary {
+        app.state
+            .toast_manager
+            .warning("The primary key index can't be dropped here");
+        return;
+    }
+
+    let table = metadata.display_name();
+    app.state.ui.confirmation_modal = Some(crate::ui::ConfirmationModal {
+        title: "Drop index".to_string(),
+        message: format!(
+            "Drop index '{}' on '{table}'?\n\nThis cannot be undone.",
+            index.name
+        ),
+        action: crate::ui::ConfirmationAction::DropIndex {
+            table,
+            index: index.name.clone(),
+        },
+    });
+}
+
+/// Columns plus, when the Indexes section is listed, each index. The
+/// statistics view has no selectable entries, so j/k scroll it.
+fn selectable_entries(app: &App) -> usize {
+    if statistics_shown(app) {
+        return 0;
+    }
+    app.state
+        .db
+        .current_table_metadata
+        .as_ref()
+        .map_or(0, |metadata| {
+            let columns = metadata.columns_summary.len();
+            if columns > 0 && metadata.supports_indexes() {
+                columns + metadata.indexes.len()
+            } else {
+                columns
+            }
+        })
+}
+
+/// Keep the highlighted column or index entry inside the details viewport
+fn scroll_to_selected_column(app: &mut App) {
+    let column_count = app
+        .state
+        .db
+        .current_table_metadata
+        .as_ref()
+        .map_or(0, |metadata| metadata.columns_summary.len());
+    let ui = &mut app.state.ui;
+    let Some(start) = ui.details_columns_start else {
+        return;
+    };
+    let mut line = start + ui.details_selected_column;
+    if ui.details_selected_column >= column_count {
+        // Skip the blank line and heading that open the Indexes section
+        line += 2;
+    }
+    if line < ui.details_viewport_offset {
+        ui.details_viewport_offset = line;
+    } else if ui.details_viewport_height > 0
+        && line >= ui.details_viewport_offset + ui.details_viewport_height
+    {
+        ui.details_viewport_offset = line + 1 - ui.details_viewport_height;
+    }
+    ui.details_viewport_offset = ui.details_viewport_offset.min(ui.details_max_scroll_offset);
+}
+
+fn copy_to_clipboard(app: &mut App, text: &str, message: &str) {
+    match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(text)) {
+        Ok(()) => app.state.toast_manager.success(message),
+        Err(e) => app
+            .state
+            .toast_manager
+            .error(format!("Failed to copy to clipboard: {e}")),
+    }
+}
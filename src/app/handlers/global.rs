@@ -5,18 +5,58 @@
 #![forbid(unsafe_code)]
 
 use crate::{
-    app::{App, FocusedPane},
+    app::{
+        shortcuts::{Action, ActionContext},
+        App, FocusedPane,
+    },
     commands::CommandId,
     core::error::Result,
+    database::BackendSignal,
+    ui::layout::{LeftPane, SplitMode},
 };
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Handle global keys that work everywhere
-pub(crate) fn handle(app: &mut App, key: KeyEvent) -> Result<Option<()>> {
+pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<Option<()>> {
+    // Ctrl+J in the query editor inserts a snippet; nothing lies below the editor
+    if key.modifiers == KeyModifiers::CONTROL
+        && key.code == KeyCode::Char('j')
+        && app.state.ui.is_in_main()
+        && app.state.ui.focused_pane == FocusedPane::QueryWindow
+        && !app.state.query_editor.is_in_command_mode()
+    {
+        app.state.open_snippet_picker();
+        return Ok(Some(()));
+    }
+
+    // Pane focus keys come from `[keybindings.actions]`, falling back to defaults.
+    // Digits in the query editor's Normal mode are a count for `G` instead.
+    let editor_count = app.state.ui.focused_pane == FocusedPane::QueryWindow
+        && key.modifiers.is_empty()
+        && matches!(key.code, KeyCode::Char(c) if c.is_ascii_digit());
+    if app.state.ui.is_in_main() && !editor_count && (!is_text_key(key) || can_quit(app)) {
+        if let Some(action) = app.state.hotkeys.lookup(ActionContext::Navigation, key) {
+            run_navigation(app, action);
+            return Ok(Some(()));
+        }
+    }
+
     match (key.modifiers, key.code) {
         // Help - toggle with '?'
         (KeyModifiers::NONE, KeyCode::Char('?')) => {
-            app.execute_command(CommandId::ToggleHelp)?;
+            app.execute_command(CommandId::ToggleHelp).await?;
+            Ok(Some(()))
+        }
+        // Bookmarks - `'`, like jumping to a Vim mark
+        (KeyModifiers::NONE, KeyCode::Char('\'')) if can_quit(app) => {
+            app.execute_command(CommandId::ShowBookmarks).await?;
+            Ok(Some(()))
+        }
+        // Command palette - Ctrl+P, unless it is paging editor suggestions
+        (KeyModifiers::CONTROL, KeyCode::Char('p'))
+            if app.state.ui.is_in_main() && !app.state.query_editor.are_suggestions_active() =>
+        {
+            app.open_command_palette();
             Ok(Some(()))
         }
         // Debug view - toggle with Ctrl+B
@@ -24,6 +64,18 @@ pub(crate) fn handle(app: &mut App, key: KeyEvent) -> Result<Option<()>> {
             app.state.ui.toggle_debug_view();
             Ok(Some(()))
         }
+        // Toast history - toggle with `~`, outside of text input
+        (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char('~'))
+            if can_quit(app)
+                || app.state.ui.current_view.is_toast_history()
+                    && !app.state.toast_history.filter_active =>
+        {
+            if !app.state.ui.current_view.is_toast_history() {
+                app.state.toast_history.reset();
+            }
+            app.state.ui.toggle_toast_history();
+            Ok(Some(()))
+        }
         // Quit application - 'q' (only if not in edit modes)
         (KeyModifiers::NONE, KeyCode::Char('q')) if can_quit(app) => {
             app.state.ui.confirmation_modal = Some(crate::ui::ConfirmationModal {
@@ -33,81 +85,316 @@ pub(crate) fn handle(app: &mut App, key: KeyEvent) -> Result<Option<()>> {
             });
             Ok(Some(()))
         }
-        // Number keys 1-6 for direct pane navigation (only in main view)
-        // BUT NOT when editing a table cell - numbers should go into the edit buffer
-        (KeyModifiers::NONE, KeyCode::Char(c @ '1'..='6')) if app.state.ui.is_in_main() => {
-            // Check if we're in table viewer edit mode
-            let in_table_edit_mode = app.state.ui.focused_pane == FocusedPane::TabularOutput
-                && app
-                    .state
-                    .table_viewer_state
-                    .current_tab()
-                    .map(|tab| tab.in_edit_mode)
-                    .unwrap_or(false);
-
-            // Don't intercept number keys if in edit mode - let them pass through
-            if in_table_edit_mode {
-                return Ok(None); // Not handled, will be passed to table viewer edit handler
-            }
+        _ => Ok(None), // Key not handled globally
+    }
+}
 
-            if let Some(pane) = FocusedPane::from_number(c.to_digit(10).unwrap() as u8) {
-                // Check if the target pane is enabled before navigating to it
-                let is_enabled = match pane {
-                    FocusedPane::Connections => true, // Always enabled
-                    FocusedPane::Tables => app.state.is_tables_pane_enabled(),
-                    FocusedPane::Details => app.state.is_details_pane_enabled(),
-                    FocusedPane::TabularOutput => app.state.is_query_results_pane_enabled(),
-                    FocusedPane::QueryWindow => app.state.is_query_editor_enabled(),
-                    FocusedPane::SqlFiles => app.state.are_sql_panes_enabled(),
-                };
-
-                if is_enabled {
-                    app.state.ui.focused_pane = pane;
-                    app.state.ui.cancel_pending_gg();
-                }
-                // If disabled, do nothing (stay in current pane)
-            }
-            Ok(Some(()))
+/// Keys that belong to a text field while one is being edited
+fn is_text_key(key: KeyEvent) -> bool {
+    !key.modifiers
+        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        && matches!(key.code, KeyCode::Char(_) | KeyCode::Tab | KeyCode::BackTab)
+}
+
+/// Percentage points one resize key press moves a pane edge
+const RESIZE_STEP: i16 = 2;
+/// Percentage points `<leader>+` and `<leader>-` move a pane edge
+const LEADER_RESIZE_STEP: i16 = 5;
+
+/// Move focus or resize panes for a navigation action
+fn run_navigation(app: &mut App, action: Action) {
+    let pane = match action {
+        Action::NarrowLeftColumn => return resize_left_column(app, -RESIZE_STEP),
+        Action::WidenLeftColumn => return resize_left_column(app, RESIZE_STEP),
+        Action::GrowPane => return resize_focused_pane(app, RESIZE_STEP),
+        Action::ShrinkPane => return resize_focused_pane(app, -RESIZE_STEP),
+        Action::ResizeMode => {
+            app.state.ui.resize_mode = true;
+            app.state
+                .toast_manager
+                .info("Resize mode: h/l column width, j/k pane height, v/s split, Esc to finish");
+            return;
         }
-        // Tab/Shift+Tab for pane cycling
-        // Skip Tab in query editor insert mode (Tab inserts tab character there)
-        (KeyModifiers::NONE, KeyCode::Tab)
-            if app.state.ui.is_in_main()
-                && !(app.state.ui.focused_pane == FocusedPane::QueryWindow
-                    && app.state.query_editor.is_insert_mode()) =>
-        {
+        Action::FocusLeft => return app.state.move_focus_left(),
+        Action::FocusDown => return app.state.move_focus_down(),
+        Action::FocusUp => return app.state.move_focus_up(),
+        Action::FocusRight => return app.state.move_focus_right(),
+        Action::NextPane => {
             app.state.cycle_focus_forward();
             app.state.ui.cancel_pending_gg();
-            Ok(Some(()))
+            return;
         }
-        (KeyModifiers::SHIFT, KeyCode::BackTab)
-            if app.state.ui.is_in_main()
-                && !(app.state.ui.focused_pane == FocusedPane::QueryWindow
-                    && app.state.query_editor.is_insert_mode()) =>
-        {
+        Action::PreviousPane => {
             app.state.cycle_focus_backward();
             app.state.ui.cancel_pending_gg();
-            Ok(Some(()))
+            return;
         }
-        // Ctrl+h/j/k/l for pane navigation
-        (KeyModifiers::CONTROL, KeyCode::Char('h')) if app.state.ui.is_in_main() => {
-            app.state.move_focus_left();
-            Ok(Some(()))
+        Action::FocusConnections => FocusedPane::Connections,
+        Action::FocusTables => FocusedPane::Tables,
+        Action::FocusDetails => FocusedPane::Details,
+        Action::FocusResults => FocusedPane::TabularOutput,
+        Action::FocusQueryEditor => FocusedPane::QueryWindow,
+        Action::FocusSqlFiles => FocusedPane::SqlFiles,
+        _ => return,
+    };
+
+    // Only jump to panes that are enabled; otherwise stay put
+    let is_enabled = match pane {
+        FocusedPane::Connections => true,
+        FocusedPane::Tables | FocusedPane::Routines => app.state.is_tables_pane_enabled(),
+        FocusedPane::Details => app.state.is_details_pane_enabled(),
+        FocusedPane::TabularOutput => app.state.is_query_results_pane_enabled(),
+        FocusedPane::QueryWindow => app.state.is_query_editor_enabled(),
+        FocusedPane::SqlFiles => app.state.are_sql_panes_enabled(),
+    };
+    if is_enabled {
+        app.state.ui.focused_pane = pane;
+        app.state.ui.cancel_pending_gg();
+    }
+}
+
+/// Handle keys while the command palette is open: typing filters,
+/// Enter runs the highlighted command, Esc closes
+pub(crate) async fn handle_command_palette(app: &mut App, key: KeyEvent) -> Result<()> {
+    let Some(palette) = app.state.command_palette.as_mut() else {
+        return Ok(());
+    };
+    match (key.modifiers, key.code) {
+        (_, KeyCode::Esc) => app.state.command_palette = None,
+        (_, KeyCode::Enter) => {
+            let command = palette.selected_command();
+            app.state.command_palette = None;
+            if let Some(command) = command {
+                app.execute_command(command).await?;
+            }
         }
-        (KeyModifiers::CONTROL, KeyCode::Char('j')) if app.state.ui.is_in_main() => {
-            app.state.move_focus_down();
-            Ok(Some(()))
+        (_, KeyCode::Down) | (KeyModifiers::CONTROL, KeyCode::Char('n' | 'j')) => {
+            palette.select_next()
         }
-        (KeyModifiers::CONTROL, KeyCode::Char('k')) if app.state.ui.is_in_main() => {
-            app.state.move_focus_up();
-            Ok(Some(()))
+        (_, KeyCode::Up) | (KeyModifiers::CONTROL, KeyCode::Char('p' | 'k')) => {
+            palette.select_previous()
         }
-        (KeyModifiers::CONTROL, KeyCode::Char('l')) if app.state.ui.is_in_main() => {
-            app.state.move_focus_right();
-            Ok(Some(()))
+        (_, KeyCode::Backspace) => palette.backspace(),
+        (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => palette.insert_char(c),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keys while the `Ctrl+T` quick switcher is open: typing filters,
+/// Enter focuses or opens the highlighted table, Esc closes
+pub(crate) async fn handle_quick_switcher(app: &mut App, key: KeyEvent) -> Result<()> {
+    let Some(switcher) = app.state.quick_switcher.as_mut() else {
+        return Ok(());
+    };
+    match (key.modifiers, key.code) {
+        (_, KeyCode::Esc) => app.state.quick_switcher = None,
+        (_, KeyCode::Enter) => {
+            let entry = switcher.selected_entry().cloned();
+            app.state.quick_switcher = None;
+            if let Some(entry) = entry {
+                app.state.open_switcher_entry(entry).await;
+            }
         }
-        _ => Ok(None), // Key not handled globally
+        (_, KeyCode::Down) | (KeyModifiers::CONTROL, KeyCode::Char('n' | 'j')) => {
+            switcher.select_next()
+        }
+        (_, KeyCode::Up) | (KeyModifiers::CONTROL, KeyCode::Char('p' | 'k')) => {
+            switcher.select_previous()
+        }
+        (_, KeyCode::Backspace) => switcher.backspace(),
+        (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => switcher.insert_char(c),
+        _ => {}
     }
+    Ok(())
+}
+
+/// Handle keys while the `:theme list` gallery is open: moving previews the
+/// theme under the cursor, Enter keeps it, Esc restores the original
+pub(crate) fn handle_theme_gallery(app: &mut App, key: KeyEvent) {
+    let Some(gallery) = app.state.theme_gallery.as_mut() else {
+        return;
+    };
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => gallery.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => gallery.select_previous(),
+        KeyCode::Enter => {
+            if let Some((theme, path)) = gallery.selected().cloned() {
+                app.state.theme_gallery = None;
+                app.apply_theme(theme, path);
+            }
+            return;
+        }
+        KeyCode::Esc | KeyCode::Char('q') => {
+            let (theme, _) = gallery.original().clone();
+            app.state.theme_gallery = None;
+            app.preview_theme(theme);
+            return;
+        }
+        _ => return,
+    }
+    if let Some((theme, _)) = gallery.selected().cloned() {
+        app.preview_theme(theme);
+    }
+}
+
+/// Handle keys while the `'` bookmarks overlay is open. `e` types a label
+/// for the highlighted bookmark, `d` removes it, Enter opens it.
+pub(crate) async fn handle_bookmark_list(app: &mut App, key: KeyEvent) -> Result<()> {
+    let Some(list) = app.state.bookmark_list.as_mut() else {
+        return Ok(());
+    };
+    if let Some(label) = list.label_input.as_mut() {
+        match (key.modifiers, key.code) {
+            (_, KeyCode::Esc) => list.label_input = None,
+            (_, KeyCode::Enter) => {
+                list.apply_label();
+                app.state.save_bookmark_list();
+            }
+            (_, KeyCode::Backspace) => {
+                label.pop();
+            }
+            (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => label.push(c),
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('\'') => app.state.bookmark_list = None,
+        KeyCode::Char('j') | KeyCode::Down => list.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => list.select_previous(),
+        KeyCode::Char('e') => list.start_label(),
+        KeyCode::Char('d') => {
+            if let Some(removed) = list.remove_selected() {
+                app.state.save_bookmark_list();
+                app.state
+                    .toast_manager
+                    .info(format!("Removed bookmark {}", removed.display_name()));
+            }
+        }
+        KeyCode::Enter => {
+            let bookmark = list.selected_bookmark().cloned();
+            app.state.bookmark_list = None;
+            if let Some(bookmark) = bookmark {
+                app.open_bookmark(bookmark).await;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keys while the `:locks` monitor is open. `k` and `Ctrl+K` ask to
+/// cancel or terminate the selected backend; `y` confirms.
+pub(crate) fn handle_lock_monitor(app: &mut App, key: KeyEvent) {
+    let Some(monitor) = app.state.lock_monitor.as_mut() else {
+        return;
+    };
+    if let Some((signal, pid)) = monitor.pending.take() {
+        if key.code == KeyCode::Char('y') {
+            app.start_backend_signal(signal, pid);
+        }
+        return;
+    }
+    match (key.modifiers, key.code) {
+        (_, KeyCode::Esc | KeyCode::Char('q')) => {
+            app.state.lock_monitor = None;
+            // A reload still running belongs to this monitor, not the next one
+            if let Some(task) = app.lock_task.take() {
+                task.abort();
+            }
+        }
+        (_, KeyCode::Char('j') | KeyCode::Down) => monitor.select_next(),
+        (_, KeyCode::Up) => monitor.select_previous(),
+        (KeyModifiers::CONTROL, KeyCode::Char('k')) => monitor.request(BackendSignal::Terminate),
+        (_, KeyCode::Char('k')) => monitor.request(BackendSignal::Cancel),
+        (_, KeyCode::Char('r')) => monitor.refresh_now(),
+        _ => {}
+    }
+}
+
+/// Handle keys while `Ctrl+w` resize mode is active. Like Vim's window
+/// commands, `v`/`s` split the results and editor, `Ctrl+w` (or `w`) moves to
+/// the other split and `q` closes the split without focus.
+pub(crate) fn handle_resize_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('v') => {
+            app.state.ui.resize_mode = false;
+            app.state.toggle_split(SplitMode::Vertical);
+        }
+        KeyCode::Char('s') => {
+            app.state.ui.resize_mode = false;
+            app.state.toggle_split(SplitMode::Horizontal);
+        }
+        KeyCode::Char('w') if app.state.split.is_some() => {
+            app.state.ui.resize_mode = false;
+            app.state.focus_other_split();
+        }
+        KeyCode::Char('q') if app.state.split.is_some() => {
+            app.state.ui.resize_mode = false;
+            app.state.close_other_split();
+        }
+        KeyCode::Char('h') | KeyCode::Left => resize_left_column(app, -RESIZE_STEP),
+        KeyCode::Char('l') | KeyCode::Right => resize_left_column(app, RESIZE_STEP),
+        KeyCode::Char('j') | KeyCode::Down => resize_focused_pane(app, RESIZE_STEP),
+        KeyCode::Char('k') | KeyCode::Up => resize_focused_pane(app, -RESIZE_STEP),
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') | KeyCode::Char('w') => {
+            app.state.ui.resize_mode = false;
+            app.save_layout();
+        }
+        _ => {}
+    }
+}
+
+fn resize_left_column(app: &mut App, delta: i16) {
+    if !app.state.layout.resize_left_column(delta) {
+        app.state
+            .toast_manager
+            .info("Left column is at its size limit");
+    }
+}
+
+/// Grow (positive) or shrink (negative) the focused pane: left panes and the
+/// results in height, the query editor at the expense of the results, and
+/// the SQL files column in width
+fn resize_focused_pane(app: &mut App, delta: i16) {
+    let layout = &mut app.state.layout;
+    let resized = match app.state.ui.focused_pane {
+        FocusedPane::Connections => layout.resize_left_pane(LeftPane::Connections, delta),
+        FocusedPane::Tables | FocusedPane::Routines => {
+            layout.resize_left_pane(LeftPane::Tables, delta)
+        }
+        FocusedPane::Details => layout.resize_left_pane(LeftPane::Details, delta),
+        FocusedPane::TabularOutput => layout.resize_output(delta),
+        FocusedPane::QueryWindow => layout.resize_output(-delta),
+        FocusedPane::SqlFiles => layout.resize_sql_files(delta),
+    };
+    if !resized {
+        app.state.toast_manager.info("Pane is at its size limit");
+    }
+}
+
+/// Key after the leader: `+` grows the focused pane and `-` shrinks it,
+/// keeping the leader armed so the key can repeat. False for any other key.
+pub(crate) fn handle_leader_key(app: &mut App, key: KeyEvent) -> bool {
+    let delta = match key.code {
+        KeyCode::Char('+' | '=') => LEADER_RESIZE_STEP,
+        KeyCode::Char('-' | '_') => -LEADER_RESIZE_STEP,
+        _ => return false,
+    };
+    resize_focused_pane(app, delta);
+    app.state.hotkeys.hold_leader();
+    true
+}
+
+/// Whether `key` arms the leader: the leader key in a pane, outside text
+/// input, popups and half-typed sequences
+pub(crate) fn arms_leader(app: &App, key: KeyEvent) -> bool {
+    app.state.hotkeys.is_leader(key)
+        && can_quit(app)
+        && !app.state.is_modal_open()
+        && app.state.pending_keys().is_none()
 }
 
 /// Check if quit action is allowed (not in edit/insert modes)
@@ -127,7 +414,7 @@ pub(crate) fn can_quit(app: &App) -> bool {
     // Check table viewer edit mode
     if app.state.ui.focused_pane == FocusedPane::TabularOutput {
         if let Some(tab) = app.state.table_viewer_state.current_tab() {
-            if tab.in_edit_mode || tab.in_search_mode {
+            if tab.in_edit_mode || tab.in_search_mode || tab.watch_prompt.is_some() {
                 return false;
             }
         }
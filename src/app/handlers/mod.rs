@@ -11,5 +11,6 @@ pub mod global;
 pub mod overlays;
 pub mod query_editor;
 pub mod query_results;
+pub mod routines;
 pub mod sql_files;
 pub mod tables;
@@ -7,19 +7,34 @@
 use crate::{
     app::{App, AppView, HelpMode, OverlayView},
     core::error::Result,
+    database::DatabaseType,
+    ui::components::{CopyFormatMenu, NameConfirmation, NameConfirmationKind},
 };
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Handle overlay keys (connection form, table creator/editor, debug view)
 pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
     // ESC closes overlay and returns to main, EXCEPT for Help overlay
     // Help overlay only closes with '?' key, not ESC
+    // Toast history handles ESC itself so it can leave filter input first
+    // The SQLite file picker inside the connection form closes on ESC first
     if key.code == KeyCode::Esc
+        && !app.state.ui.current_view.is_toast_history()
+        && app.state.connection_modal_state.file_picker.is_none()
         && !matches!(
             app.state.ui.current_view,
             AppView::Overlay(OverlayView::Help)
         )
     {
+        // The first ESC cancels a connection test in flight and keeps the form open
+        if matches!(
+            app.state.ui.current_view,
+            AppView::Overlay(OverlayView::ConnectionForm(_))
+        ) && app.state.test_connection_in_progress
+        {
+            super::connections::abort_test_connection(app);
+            return Ok(());
+        }
         app.state.ui.return_to_main();
         return Ok(());
     }
@@ -31,15 +46,112 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
         }
         AppView::Overlay(OverlayView::DebugView) => handle_debug_view(app, key),
         AppView::Overlay(OverlayView::Help) => handle_help(app, key),
+        AppView::Overlay(OverlayView::ToastHistory) => handle_toast_history(app, key),
+        AppView::Overlay(OverlayView::Workspaces) => handle_workspaces(app, key).await,
         _ => Ok(()),
     }
 }
 
+/// Handle toast history overlay keys
+pub(crate) fn handle_toast_history(app: &mut App, key: KeyEvent) -> Result<()> {
+    let history = &mut app.state.toast_history;
+
+    if history.filter_active {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter => {
+                history.filter_active = false;
+            }
+            KeyCode::Backspace => {
+                history.filter.pop();
+                history.selected = 0;
+            }
+            KeyCode::Char(c) => {
+                history.filter.push(c);
+                history.selected = 0;
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    let count = history.filtered_entries(&app.state.toast_manager).len();
+    let pending_yank = std::mem::take(&mut history.pending_yank);
+    match key.code {
+        KeyCode::Esc => {
+            app.state.ui.return_to_main();
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            history.select_next(count);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            history.select_previous();
+        }
+        KeyCode::Char('/') => {
+            history.filter_active = true;
+        }
+        KeyCode::Char('c') => {
+            history.clear(&mut app.state.toast_manager);
+        }
+        KeyCode::Char('y') if !pending_yank => {
+            history.pending_yank = true;
+        }
+        KeyCode::Char('y') => {
+            // Copying raises a toast itself, so resolve the result before reporting it
+            let result = history.copy_selected(&app.state.toast_manager);
+            match result {
+                Ok(()) => app
+                    .state
+                    .toast_manager
+                    .success("Message copied to clipboard"),
+                Err(e) => app.state.toast_manager.error(e),
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle workspace browser keys
+pub(crate) async fn handle_workspaces(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.state.workspace_browser.select_next();
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.state.workspace_browser.select_previous();
+        }
+        KeyCode::Enter => {
+            if let Some(name) = app.state.workspace_browser.selected_name() {
+                app.state.ui.return_to_main();
+                app.state.restore_workspace(&name).await;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
 /// Handle debug view keys
 pub(crate) fn handle_debug_view(app: &mut App, key: KeyEvent) -> Result<()> {
-    let debug_messages = crate::logging::get_debug_messages();
+    let min_level = app.state.ui.debug_view_min_level;
+    let debug_messages = crate::logging::get_debug_messages(min_level);
     let max_lines = debug_messages.len();
 
+    // Copy the line at the top of the view
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+        match debug_messages.get(app.state.ui.debug_view_scroll_offset) {
+            Some(message) => match crate::ui::components::DebugView::copy_message(message) {
+                Ok(()) => app
+                    .state
+                    .toast_manager
+                    .success("Log line copied to clipboard"),
+                Err(e) => app.state.toast_manager.error(e),
+            },
+            None => app.state.toast_manager.info("No log line selected"),
+        }
+        return Ok(());
+    }
+
     match key.code {
         KeyCode::Char('j') | KeyCode::Down => {
             app.state.ui.debug_view_scroll_down(max_lines);
@@ -65,9 +177,19 @@ pub(crate) fn handle_debug_view(app: &mut App, key: KeyEvent) -> Result<()> {
             app.state.ui.debug_view_go_to_bottom(max_lines);
         }
         KeyCode::Char('c') => {
-            crate::logging::clear_debug_messages();
+            crate::logging::clear_debug_messages(min_level);
+            app.state.ui.debug_view_go_to_top();
             app.state.toast_manager.info("Debug messages cleared");
         }
+        KeyCode::Char('f') => {
+            app.state.ui.toggle_debug_view_filter_bar();
+        }
+        KeyCode::Left if app.state.ui.debug_view_filter_bar => {
+            app.state.ui.cycle_debug_view_level(false);
+        }
+        KeyCode::Right if app.state.ui.debug_view_filter_bar => {
+            app.state.ui.cycle_debug_view_level(true);
+        }
         _ => {}
     }
     Ok(())
@@ -98,10 +220,12 @@ pub(crate) fn handle_help(app: &mut App, key: KeyEvent) -> Result<()> {
         }
         // Page navigation
         KeyCode::PageUp => {
-            app.state.ui.help_page_up(10);
+            let page = app.state.ui.help_page_size();
+            app.state.ui.help_page_up(page);
         }
         KeyCode::PageDown => {
-            app.state.ui.help_page_down(100, 10);
+            let page = app.state.ui.help_page_size();
+            app.state.ui.help_page_down(100, page);
         }
         _ => {}
     }
@@ -151,13 +275,63 @@ pub(crate) async fn handle_confirmation_modal(app: &mut App, key: KeyEvent) -> R
                             .ui
                             .update_sql_file_selection(app.state.saved_sql_files.len());
                     }
+                    crate::ui::ConfirmationAction::DropRoutine(routine) => {
+                        let routine = routine.clone();
+                        match app.state.drop_routine(&routine).await {
+                            Ok(()) => app
+                                .state
+                                .toast_manager
+                                .success(format!("Dropped {}", routine.display_name())),
+                            Err(e) => app
+                                .state
+                                .toast_manager
+                                .error(format!("Failed to drop {}: {e}", routine.name)),
+                        }
+                    }
+                    crate::ui::ConfirmationAction::DeleteTable(name) => {
+                        app.state.name_confirmation = Some(NameConfirmation::new(
+                            name.clone(),
+                            NameConfirmationKind::Drop,
+                        ));
+                    }
+                    crate::ui::ConfirmationAction::TruncateTable(name) => {
+                        app.state.name_confirmation = Some(NameConfirmation::new(
+                            name.clone(),
+                            NameConfirmationKind::Truncate,
+                        ));
+                    }
+                    crate::ui::ConfirmationAction::RefreshMaterializedView(view_name) => {
+                        let view_name = view_name.clone();
+                        match app.state.refresh_materialized_view(&view_name, true).await {
+                            Ok(()) => app
+                                .state
+                                .toast_manager
+                                .success(format!("Refreshed {view_name} concurrently")),
+                            Err(e) => app
+                                .state
+                                .toast_manager
+                                .error(format!("Failed to refresh {view_name}: {e}")),
+                        }
+                    }
+                    crate::ui::ConfirmationAction::DropIndex { table, index } => {
+                        let (table, index) = (table.clone(), index.clone());
+                        match app.state.drop_index(&table, &index).await {
+                            Ok(()) => app
+                                .state
+                                .toast_manager
+                                .success(format!("Dropped index {index}")),
+                            Err(e) => app
+                                .state
+                                .toast_manager
+                                .error(format!("Failed to drop index {index}: {e}")),
+                        }
+                    }
                     crate::ui::ConfirmationAction::ExitApplication => {
                         app.should_quit = true;
                     }
                     crate::ui::ConfirmationAction::QuitQueryEditor => {
                         // Just close the confirmation, stay in main view
                     }
-                    _ => {}
                 }
                 app.state.ui.confirmation_modal = None;
             }
@@ -170,6 +344,85 @@ pub(crate) async fn handle_confirmation_modal(app: &mut App, key: KeyEvent) -> R
     Ok(())
 }
 
+/// Type the table name to confirm a truncation or drop; Enter only runs it
+/// once the name matches
+pub(crate) async fn handle_name_confirmation(app: &mut App, key: KeyEvent) -> Result<()> {
+    let Some(confirmation) = app.state.name_confirmation.as_mut() else {
+        return Ok(());
+    };
+    match (key.code, key.modifiers) {
+        (KeyCode::Esc, _) => app.state.name_confirmation = None,
+        (KeyCode::Enter, _) => {
+            if !confirmation.is_confirmed() {
+                return Ok(());
+            }
+            let (name, kind) = (confirmation.table.clone(), confirmation.kind);
+            app.state.name_confirmation = None;
+            let (result, done, verb) = match kind {
+                NameConfirmationKind::Truncate => (
+                    app.state.truncate_table(&name).await,
+                    "Truncated",
+                    "truncate",
+                ),
+                NameConfirmationKind::Drop => {
+                    (app.state.drop_table(&name).await, "Dropped", "drop")
+                }
+            };
+            match result {
+                Ok(()) => {
+                    app.state.toast_manager.success(format!("{done} {name}"));
+                    app.refresh_object_list();
+                }
+                Err(e) => app
+                    .state
+                    .toast_manager
+                    .error(format!("Failed to {verb} {name}: {e}")),
+            }
+        }
+        (KeyCode::Backspace, _) => {
+            confirmation.input.pop();
+        }
+        (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => confirmation.input.push(c),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Type a new table name; Enter renames once it differs from the current one
+pub(crate) async fn handle_rename_prompt(app: &mut App, key: KeyEvent) -> Result<()> {
+    let Some(prompt) = app.state.rename_prompt.as_mut() else {
+        return Ok(());
+    };
+    match (key.code, key.modifiers) {
+        (KeyCode::Esc, _) => app.state.rename_prompt = None,
+        (KeyCode::Enter, _) => {
+            let Some(new_name) = prompt.new_name().map(str::to_string) else {
+                return Ok(());
+            };
+            let name = prompt.table.clone();
+            app.state.rename_prompt = None;
+            match app.state.rename_table(&name, &new_name).await {
+                Ok(renamed) => {
+                    app.state
+                        .toast_manager
+                        .success(format!("Renamed {name} to {renamed}"));
+                    app.refresh_object_list();
+                }
+                Err(e) => app
+                    .state
+                    .toast_manager
+                    .error(format!("Failed to rename {name}: {e}")),
+            }
+        }
+        (KeyCode::Backspace, _) => {
+            prompt.input.pop();
+        }
+        (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => prompt.input.push(c),
+        _ => {}
+    }
+    Ok(())
+}
+
 /// Handle table delete confirmation keys
 pub(crate) async fn handle_table_delete_confirmation(app: &mut App, key: KeyEvent) -> Result<()> {
     if let Some(confirmation) = &app.state.table_viewer_state.delete_confirmation {
@@ -225,3 +478,380 @@ pub(crate) async fn handle_set_null_confirmation(app: &mut App, key: KeyEvent) -
     }
     Ok(())
 }
+
+/// Scroll or close the hex dump over a binary cell
+pub(crate) fn handle_hex_view(app: &mut App, key: KeyEvent) {
+    let Some(view) = app.state.table_viewer_state.hex_view.as_mut() else {
+        return;
+    };
+    match (key.code, key.modifiers) {
+        (KeyCode::Char('j') | KeyCode::Down, _) => view.scroll_down(1),
+        (KeyCode::Char('k') | KeyCode::Up, _) => view.scroll_up(1),
+        (KeyCode::Char('d'), KeyModifiers::CONTROL) | (KeyCode::PageDown, _) => {
+            view.scroll_down(16)
+        }
+        (KeyCode::Char('u'), KeyModifiers::CONTROL) | (KeyCode::PageUp, _) => view.scroll_up(16),
+        (KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('K'), _) => {
+            app.state.table_viewer_state.hex_view = None;
+        }
+        _ => {}
+    }
+}
+
+/// Scroll or close the pretty-printed JSON of a cell
+pub(crate) fn handle_json_view(app: &mut App, key: KeyEvent) {
+    let Some(view) = app.state.table_viewer_state.json_view.as_mut() else {
+        return;
+    };
+    match (key.code, key.modifiers) {
+        (KeyCode::Char('j') | KeyCode::Down, _) => view.scroll_down(1),
+        (KeyCode::Char('k') | KeyCode::Up, _) => view.scroll_up(1),
+        (KeyCode::Char('d'), KeyModifiers::CONTROL) | (KeyCode::PageDown, _) => {
+            view.scroll_down(20)
+        }
+        (KeyCode::Char('u'), KeyModifiers::CONTROL) | (KeyCode::PageUp, _) => view.scroll_up(20),
+        (KeyCode::Char('g'), _) => view.scroll = 0,
+        (KeyCode::Char('G'), _) => view.scroll_down(usize::MAX / 2),
+        (KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('J'), _) => {
+            app.state.table_viewer_state.json_view = None;
+        }
+        _ => {}
+    }
+}
+
+/// Scroll or close the full value of a cell
+pub(crate) fn handle_cell_view(app: &mut App, key: KeyEvent) {
+    let Some(view) = app.state.table_viewer_state.cell_view.as_mut() else {
+        return;
+    };
+    match (key.code, key.modifiers) {
+        (KeyCode::Char('j') | KeyCode::Down, _) => view.scroll_down(1),
+        (KeyCode::Char('k') | KeyCode::Up, _) => view.scroll_up(1),
+        (KeyCode::Char('d'), KeyModifiers::CONTROL) | (KeyCode::PageDown, _) => {
+            view.scroll_down(20)
+        }
+        (KeyCode::Char('u'), KeyModifiers::CONTROL) | (KeyCode::PageUp, _) => view.scroll_up(20),
+        (KeyCode::Char('g'), _) => view.scroll = 0,
+        (KeyCode::Char('G'), _) => view.scroll = usize::MAX,
+        (KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char(' ') | KeyCode::Enter, _) => {
+            app.state.table_viewer_state.cell_view = None;
+        }
+        _ => {}
+    }
+}
+
+/// Pick a format from the `yf` menu and copy the selected row in it
+pub(crate) fn handle_copy_format_menu(app: &mut App, key: KeyEvent) {
+    let Some(menu) = app.state.table_viewer_state.copy_format_menu.as_mut() else {
+        return;
+    };
+    let format = match key.code {
+        KeyCode::Char('j') | KeyCode::Down => {
+            menu.select_next();
+            return;
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            menu.select_previous();
+            return;
+        }
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.state.table_viewer_state.copy_format_menu = None;
+            return;
+        }
+        KeyCode::Enter => menu.selected_format(),
+        KeyCode::Char(c) => match CopyFormatMenu::format_for_key(c) {
+            Some(format) => format,
+            None => return,
+        },
+        _ => return,
+    };
+    app.state.table_viewer_state.copy_format_menu = None;
+
+    let database_type = app
+        .state
+        .get_selected_connection()
+        .map_or(DatabaseType::PostgreSQL, |connection| {
+            connection.database_type.clone()
+        });
+    match app
+        .state
+        .table_viewer_state
+        .copy_row_as(format, &database_type)
+    {
+        Ok(()) => app.state.toast_manager.success(format!(
+            "Row copied to clipboard ({} format)",
+            format.label()
+        )),
+        Err(e) => app
+            .state
+            .toast_manager
+            .error(format!("Failed to copy row: {e}")),
+    }
+}
+
+/// Type into the array or JSON cell editor; `Ctrl+S` saves it
+pub(crate) async fn handle_cell_editor(app: &mut App, key: KeyEvent) -> Result<()> {
+    let Some(editor) = app.state.table_viewer_state.cell_editor.as_mut() else {
+        return Ok(());
+    };
+    match (key.code, key.modifiers) {
+        (KeyCode::Esc, _) => {
+            app.state.table_viewer_state.cell_editor = None;
+        }
+        (KeyCode::Char('s'), KeyModifiers::CONTROL) => {
+            match app.state.table_viewer_state.finish_cell_editor() {
+                Ok(Some(update)) => {
+                    if let Err(e) = app.state.update_table_cell(update).await {
+                        app.state
+                            .toast_manager
+                            .error(format!("Failed to update cell: {e}"));
+                    } else {
+                        app.state.toast_manager.success("Cell updated successfully");
+                    }
+                }
+                Ok(None) => {}
+                // The editor stays open with the error and the cursor on it
+                Err(e) => app.state.toast_manager.error(e),
+            }
+        }
+        (KeyCode::Char('d'), KeyModifiers::CONTROL) => editor.delete_line(),
+        (KeyCode::Enter, _) => editor.insert_newline(),
+        (KeyCode::Backspace, _) => editor.backspace(),
+        (KeyCode::Left, _) => editor.move_left(),
+        (KeyCode::Right, _) => editor.move_right(),
+        (KeyCode::Up, _) => editor.move_up(),
+        (KeyCode::Down, _) => editor.move_down(),
+        (KeyCode::Home, _) => editor.move_home(),
+        (KeyCode::End, _) => editor.move_end(),
+        (KeyCode::Tab, _) => {
+            editor.insert_char(' ');
+            editor.insert_char(' ');
+        }
+        (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => editor.insert_char(c),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle the system connection import checklist
+pub(crate) async fn handle_connection_import(app: &mut App, key: KeyEvent) -> Result<()> {
+    let Some(modal) = app.state.connection_import.as_mut() else {
+        return Ok(());
+    };
+
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => modal.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => modal.select_previous(),
+        KeyCode::Char(' ') => modal.toggle_selected(),
+        KeyCode::Char('a') => modal.toggle_all(),
+        KeyCode::Enter => {
+            let configs = modal.checked_configs();
+            app.state.connection_import = None;
+
+            let mut imported = 0;
+            for mut config in configs {
+                // Keep names unique; add_connection rejects duplicates
+                let base_name = config.name.clone();
+                let mut suffix = 2;
+                while app
+                    .state
+                    .db
+                    .connections
+                    .connections
+                    .iter()
+                    .any(|c| c.name == config.name)
+                {
+                    config.name = format!("{base_name} ({suffix})");
+                    suffix += 1;
+                }
+
+                let name = config.name.clone();
+                match app.state.db.connections.add_connection(config).await {
+                    Ok(()) => imported += 1,
+                    Err(e) => app
+                        .state
+                        .toast_manager
+                        .error(format!("Failed to import {name}: {e}")),
+                }
+            }
+
+            app.state.clamp_connection_selection();
+            if imported > 0 {
+                app.state
+                    .toast_manager
+                    .success(format!("Imported {imported} connection(s)"));
+            }
+        }
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.state.connection_import = None;
+            app.state.toast_manager.info("Connection import cancelled");
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle the create-index modal opened from the Details pane
+pub(crate) async fn handle_index_modal(app: &mut App, key: KeyEvent) -> Result<()> {
+    use crate::ui::components::IndexField;
+
+    let Some(modal) = app.state.index_modal.as_mut() else {
+        return Ok(());
+    };
+
+    match key.code {
+        KeyCode::Esc => {
+            app.state.index_modal = None;
+            app.state.toast_manager.info("Index creation cancelled");
+        }
+        KeyCode::Tab => modal.next_field(),
+        KeyCode::BackTab => modal.previous_field(),
+        KeyCode::Enter => match modal.statement() {
+            Ok(statement) => {
+                let table_name = modal.table_name.clone();
+                let index_name = modal.name.trim().to_string();
+                match app.state.create_index(&table_name, &statement).await {
+                    Ok(()) => {
+                        app.state.index_modal = None;
+                        app.state
+                            .toast_manager
+                            .success(format!("Created index {index_name}"));
+                    }
+                    Err(e) => {
+                        if let Some(modal) = app.state.index_modal.as_mut() {
+                            modal.error = Some(e);
+                        }
+                    }
+                }
+            }
+            Err(e) => modal.error = Some(e),
+        },
+        code => {
+            modal.error = None;
+            match (modal.field, code) {
+                (IndexField::Columns, KeyCode::Char('j') | KeyCode::Down) => modal.select_next(),
+                (IndexField::Columns, KeyCode::Char('k') | KeyCode::Up) => modal.select_previous(),
+                (IndexField::Columns, KeyCode::Char(' ')) => modal.toggle_column(),
+                (IndexField::Type, KeyCode::Char('l' | ' ') | KeyCode::Right) => {
+                    modal.cycle_type(true)
+                }
+                (IndexField::Type, KeyCode::Char('h') | KeyCode::Left) => modal.cycle_type(false),
+                (IndexField::Unique, KeyCode::Char(' ')) => modal.unique = !modal.unique,
+                (IndexField::Name, KeyCode::Char(c)) => modal.insert_name_char(c),
+                (IndexField::Name, KeyCode::Backspace) => modal.backspace_name(),
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Handle the CSV import modal (path entry, then column mapping)
+pub(crate) async fn handle_csv_import(app: &mut App, key: KeyEvent) -> Result<()> {
+    use crate::ui::components::CsvImportStage;
+
+    let Some(modal) = app.state.csv_import.as_mut() else {
+        return Ok(());
+    };
+
+    if key.code == KeyCode::Esc {
+        app.state.csv_import = None;
+        app.state.toast_manager.info("CSV import cancelled");
+        return Ok(());
+    }
+
+    match modal.stage {
+        CsvImportStage::Path => match key.code {
+            KeyCode::Char(c) => modal.path_input.push(c),
+            KeyCode::Backspace => {
+                modal.path_input.pop();
+            }
+            KeyCode::Enter => {
+                let path = crate::ui::components::expand_tilde(modal.path_input.trim());
+                let preview = match read_csv_preview(&path) {
+                    Ok(preview) => preview,
+                    Err(e) => {
+                        modal.error = Some(format!("Cannot read {}: {e}", path.display()));
+                        return Ok(());
+                    }
+                };
+                let mut records = crate::database::csv_import::parse_csv(&preview).into_iter();
+                let Some(headers) = records.next() else {
+                    modal.error = Some("The CSV file is empty".to_string());
+                    return Ok(());
+                };
+                let sample = records.next().unwrap_or_default();
+
+                let connection_id = modal.connection_id.clone();
+                let table_name = modal.table_name.clone();
+                match app
+                    .state
+                    .connection_manager
+                    .get_table_columns(&connection_id, &table_name)
+                    .await
+                {
+                    Ok(columns) => {
+                        if let Some(modal) = app.state.csv_import.as_mut() {
+                            modal.load_columns(headers, sample, columns);
+                        }
+                    }
+                    Err(e) => {
+                        if let Some(modal) = app.state.csv_import.as_mut() {
+                            modal.error = Some(format!("Failed to load columns: {e}"));
+                        }
+                    }
+                }
+            }
+            _ => {}
+        },
+        CsvImportStage::Mapping => match key.code {
+            KeyCode::Char('j') | KeyCode::Down => modal.select_next(),
+            KeyCode::Char('k') | KeyCode::Up => modal.select_previous(),
+            KeyCode::Char('l') | KeyCode::Right | KeyCode::Char(' ') => modal.cycle_target(true),
+            KeyCode::Char('h') | KeyCode::Left => modal.cycle_target(false),
+            KeyCode::Enter => {
+                if let Err(e) = modal.validate() {
+                    modal.error = Some(e);
+                    return Ok(());
+                }
+                if app.csv_import_task.is_some() {
+                    modal.error = Some("Another CSV import is still running".to_string());
+                    return Ok(());
+                }
+
+                let statement = modal.copy_statement();
+                let keep_columns = modal.keep_columns();
+                let connection_id = modal.connection_id.clone();
+                let table_name = modal.table_name.clone();
+                let path = crate::ui::components::expand_tilde(modal.path_input.trim());
+                app.state.csv_import = None;
+
+                app.state
+                    .toast_manager
+                    .info(format!("Importing {} into {table_name}...", path.display()));
+
+                // COPY runs in the background; the tick loop reports the row count
+                let connection_manager = app.state.connection_manager.clone();
+                let handle = tokio::spawn(async move {
+                    connection_manager
+                        .copy_in_csv(&connection_id, &statement, path, keep_columns)
+                        .await
+                });
+                app.csv_import_task = Some((table_name, handle));
+            }
+            _ => {}
+        },
+    }
+    Ok(())
+}
+
+/// Read the start of a CSV file, enough for its header and first row
+fn read_csv_preview(path: &std::path::Path) -> std::io::Result<String> {
+    use std::io::Read;
+
+    let mut buffer = Vec::new();
+    std::fs::File::open(path)?
+        .take(crate::database::csv_import::CSV_PREVIEW_BYTES as u64)
+        .read_to_end(&mut buffer)?;
+    Ok(String::from_utf8_lossy(&buffer).into_owned())
+}
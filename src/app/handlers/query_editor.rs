@@ -4,11 +4,34 @@
 
 #![forbid(unsafe_code)]
 
-use crate::{app::App, core::error::Result};
+use crate::{
+    app::{App, ScriptScope},
+    commands::{parse_workspace_command, CommandId},
+    core::error::Result,
+    ui::components::{QueryVarsPanel, VisualMode},
+};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Handle Query Editor pane keys - ONLY PANE WITH VIM INSERT MODE
 pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
+    // Ctrl+Shift+V - Toggle the query variables panel from any editor mode
+    if matches!(key.code, KeyCode::Char('v' | 'V'))
+        && key
+            .modifiers
+            .contains(KeyModifiers::CONTROL | KeyModifiers::SHIFT)
+    {
+        app.state.query_vars_panel = match app.state.query_vars_panel {
+            Some(_) => None,
+            None => Some(QueryVarsPanel::new()),
+        };
+        return Ok(());
+    }
+
+    if app.state.query_vars_panel.is_some() {
+        handle_vars_panel(app, key);
+        return Ok(());
+    }
+
     // Check if in command mode
     if app.state.query_editor.is_in_command_mode() {
         return handle_command_mode(app, key).await;
@@ -19,23 +42,51 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
         return handle_insert_mode(app, key).await;
     }
 
+    if app.state.query_editor.visual_mode().is_some() {
+        return handle_visual_mode(app, key);
+    }
+
+    // Complete a pending dd/dw/d$/yy/gg or "+; any other key abandons it
+    if app.state.query_editor.has_pending_command() {
+        if let KeyCode::Char(c) = key.code {
+            if app.state.query_editor.handle_vim_command(c) {
+                sync_editor(app);
+                return Ok(());
+            }
+        } else {
+            app.state.query_editor.cancel_pending_command();
+        }
+    }
+
+    // Digits build a count for `G`; `0` with no count is still line start
+    if let KeyCode::Char(c) = key.code {
+        if let Some(digit) = c.to_digit(10) {
+            if digit != 0 || app.state.query_editor.has_count() {
+                app.state.query_editor.push_count_digit(digit);
+                return Ok(());
+            }
+        }
+    }
+    // Any other key uses the count or drops it
+    let count = app.state.query_editor.take_count();
+
     // Normal mode - vim keybindings
     match key.code {
         // Shift+E - Execute query at cursor (PRIMARY binding, vim-style)
         KeyCode::Char('E') => {
-            if let Err(e) = app.state.execute_query_at_cursor().await {
-                app.state
-                    .toast_manager
-                    .error(format!("Query execution failed: {e}"));
-            }
+            app.start_query_at_cursor();
+        }
+        // Ctrl+Shift+Enter - Run every statement in the buffer
+        KeyCode::Enter
+            if key
+                .modifiers
+                .contains(KeyModifiers::CONTROL | KeyModifiers::SHIFT) =>
+        {
+            app.start_batch(ScriptScope::All);
         }
         // Ctrl+Enter - Execute query at cursor (SECONDARY binding, familiar to SQL tool users)
         KeyCode::Enter if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            if let Err(e) = app.state.execute_query_at_cursor().await {
-                app.state
-                    .toast_manager
-                    .error(format!("Query execution failed: {e}"));
-            }
+            app.start_query_at_cursor();
         }
         // 'i' - Enter insert mode at cursor
         KeyCode::Char('i') => {
@@ -74,8 +125,9 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Char('w') => {
             app.state.query_editor.move_to_next_word();
         }
-        KeyCode::Char('b') => {
-            app.state.query_editor.move_to_prev_word();
+        // 'b' - Previous word; Ctrl+B stays with the global debug toggle
+        KeyCode::Char('b') if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.state.query_editor.move_to_previous_word();
         }
         KeyCode::Char('e') => {
             app.state.query_editor.move_to_end_of_word();
@@ -86,16 +138,27 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Char('$') => {
             app.state.query_editor.move_to_line_end();
         }
+        // 'g' - First press arms gg, a second press within the timeout jumps to file start
         KeyCode::Char('g') => {
-            if app.state.ui.pending_gg_command {
-                app.state.query_editor.move_to_file_start();
-                app.state.ui.pending_gg_command = false;
-            } else {
-                app.state.ui.pending_gg_command = true;
-            }
+            app.state.query_editor.handle_vim_command('g');
+        }
+        // 'G' - Last line, or line <count> for `42G`
+        KeyCode::Char('G') => match count {
+            Some(line) => app.state.query_editor.go_to_line(line),
+            None => app.state.query_editor.move_to_file_end(),
+        },
+        // 'x'/'X' delete a character; 'd' starts dd, dw or d$; 'yy' yanks the line;
+        // 'p'/'P' paste below/after or above/before; '"+' targets the system clipboard
+        KeyCode::Char(c @ ('x' | 'X' | 'd' | 'y' | 'p' | 'P' | '"')) => {
+            app.state.query_editor.handle_vim_command(c);
+            sync_editor(app);
         }
-        KeyCode::Char('G') => {
-            app.state.query_editor.move_to_file_end();
+        // 'v'/'V' - Characterwise / linewise Visual mode
+        KeyCode::Char('v') => {
+            app.state.query_editor.toggle_visual_mode(VisualMode::Char);
+        }
+        KeyCode::Char('V') => {
+            app.state.query_editor.toggle_visual_mode(VisualMode::Line);
         }
         // ':' - Enter command mode
         KeyCode::Char(':') => {
@@ -113,6 +176,75 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
     Ok(())
 }
 
+/// Handle query editor Visual mode: motions extend the selection, d/x delete it, y yanks it
+fn handle_visual_mode(app: &mut App, key: KeyEvent) -> Result<()> {
+    // Only gg and "+ span two keys in Visual mode
+    if app.state.query_editor.has_pending_command()
+        && !matches!(key.code, KeyCode::Char('g' | '+' | '*'))
+    {
+        app.state.query_editor.cancel_pending_command();
+    }
+
+    // Ctrl+Enter or Ctrl+Shift+Enter - Run the statements in the selection
+    if key.code == KeyCode::Enter && key.modifiers.contains(KeyModifiers::CONTROL) {
+        app.start_batch(ScriptScope::Selection);
+        return Ok(());
+    }
+
+    let editor = &mut app.state.query_editor;
+    match key.code {
+        KeyCode::Esc => editor.exit_visual_mode(),
+        // ':' keeps the selection for commands such as `:snip add <name>`
+        KeyCode::Char(':') => editor.enter_command_mode(),
+        KeyCode::Char('v') => editor.toggle_visual_mode(VisualMode::Char),
+        KeyCode::Char('V') => editor.toggle_visual_mode(VisualMode::Line),
+        KeyCode::Char('h') | KeyCode::Left => editor.move_cursor_left(),
+        KeyCode::Char('j') | KeyCode::Down => editor.move_cursor_down(),
+        KeyCode::Char('k') | KeyCode::Up => editor.move_cursor_up(),
+        KeyCode::Char('l') | KeyCode::Right => editor.move_cursor_right(),
+        KeyCode::Char('w') => editor.move_to_next_word(),
+        KeyCode::Char('b') if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            editor.move_to_previous_word()
+        }
+        KeyCode::Char('e') => editor.move_to_end_of_word(),
+        KeyCode::Char('0') => editor.move_to_line_start(),
+        KeyCode::Char('$') => editor.move_to_line_end(),
+        KeyCode::Char('G') => editor.move_to_file_end(),
+        KeyCode::Char(c @ ('g' | '"' | '+' | '*')) => {
+            editor.handle_vim_command(c);
+        }
+        KeyCode::Char('d') | KeyCode::Char('x') => {
+            editor.delete_selection();
+            sync_editor(app);
+        }
+        KeyCode::Char('y') => {
+            if let Some(text) = editor.yank_selection() {
+                let lines = text.trim_end_matches('\n').lines().count().max(1);
+                app.state.toast_manager.success(if text.contains('\n') {
+                    format!("Yanked {lines} line(s)")
+                } else {
+                    format!("Yanked {} characters", text.chars().count())
+                });
+            }
+            sync_editor(app);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Copy editor edits back to the app state, flag the buffer as modified
+/// and report clipboard register failures
+fn sync_editor(app: &mut App) {
+    if app.state.query_content != app.state.query_editor.get_content() {
+        app.state.query_content = app.state.query_editor.get_content().to_string();
+        app.state.ui.query_modified = true;
+    }
+    if let Some(error) = app.state.query_editor.take_register_error() {
+        app.state.toast_manager.error(error);
+    }
+}
+
 /// Handle query editor insert mode
 async fn handle_insert_mode(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
@@ -124,23 +256,24 @@ async fn handle_insert_mode(app: &mut App, key: KeyEvent) -> Result<()> {
                 app.state.query_editor.set_insert_mode(false);
             }
         }
-        // Enter - Insert newline (Ctrl+Enter does NOT work in insert mode - use normal mode)
+        // Enter - Accept suggestion if active, otherwise insert newline
+        // (Ctrl+Enter does NOT work in insert mode - use normal mode)
         KeyCode::Enter => {
-            app.state.query_editor.insert_newline();
+            if app.state.query_editor.are_suggestions_active() {
+                app.state.query_editor.accept_suggestion();
+            } else {
+                app.state.query_editor.insert_newline();
+            }
             app.state.query_content = app.state.query_editor.get_content().to_string();
             app.state.ui.query_modified = true;
         }
         // Ctrl+p - Navigate suggestions up (vim-style) - MUST come before Char(c) pattern
         KeyCode::Char('p') if key.modifiers == KeyModifiers::CONTROL => {
-            if app.state.query_editor.are_suggestions_active() {
-                app.state.query_editor.move_suggestion_up();
-            }
+            app.state.query_editor.move_suggestion_up();
         }
         // Ctrl+n - Navigate suggestions down (vim-style) - MUST come before Char(c) pattern
         KeyCode::Char('n') if key.modifiers == KeyModifiers::CONTROL => {
-            if app.state.query_editor.are_suggestions_active() {
-                app.state.query_editor.move_suggestion_down();
-            }
+            app.state.query_editor.move_suggestion_down();
         }
         // Regular typing
         KeyCode::Char(c) => {
@@ -154,12 +287,15 @@ async fn handle_insert_mode(app: &mut App, key: KeyEvent) -> Result<()> {
             app.state.query_content = app.state.query_editor.get_content().to_string();
             app.state.ui.query_modified = true;
         }
-        // Tab - Accept suggestion if active, otherwise insert tab character
+        // Tab - Accept suggestion if active, jump to the next snippet stop,
+        // otherwise insert tab character
         KeyCode::Tab => {
             if app.state.query_editor.are_suggestions_active() {
                 app.state.query_editor.accept_suggestion();
                 app.state.query_content = app.state.query_editor.get_content().to_string();
                 app.state.ui.query_modified = true;
+            } else if app.state.query_editor.has_snippet_stops() {
+                app.state.query_editor.jump_to_next_snippet_stop();
             } else {
                 app.state.query_editor.insert_char('\t');
                 app.state.query_content = app.state.query_editor.get_content().to_string();
@@ -193,6 +329,7 @@ async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<()> {
         // Esc - Exit command mode
         KeyCode::Esc => {
             app.state.query_editor.exit_command_mode();
+            app.state.query_editor.exit_visual_mode();
         }
         // Backspace - Remove character from command buffer
         KeyCode::Backspace => {
@@ -202,6 +339,26 @@ async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Enter => {
             let command = app.state.query_editor.get_command_buffer().to_string();
             app.state.query_editor.exit_command_mode();
+            // Commands typed from Visual mode see the selection, then leave Visual mode
+            let selection = app.state.query_editor.selected_text();
+            app.state.query_editor.exit_visual_mode();
+
+            // Workspace commands take a name argument
+            if let Some((id, name)) = parse_workspace_command(&command) {
+                match (id, name) {
+                    (CommandId::SaveWorkspace, Some(name)) => app.state.save_workspace(&name),
+                    (CommandId::SaveWorkspace, None) => {
+                        // Re-save the workspace last saved or restored
+                        match app.state.current_workspace.clone() {
+                            Some(name) => app.state.save_workspace(&name),
+                            None => app.state.toast_manager.warning("Usage: :ws <name>"),
+                        }
+                    }
+                    (_, Some(name)) => app.state.restore_workspace(&name).await,
+                    (_, None) => app.state.open_workspace_browser(),
+                }
+                return Ok(());
+            }
 
             // Parse and execute command
             match command.trim() {
@@ -227,6 +384,18 @@ async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<()> {
                         app.state.toast_manager.info("Editor cleared");
                     }
                 }
+                ":run all" => {
+                    app.start_batch(ScriptScope::All);
+                }
+                ":run paragraph" | ":run ip" => {
+                    app.start_batch(ScriptScope::Paragraph);
+                }
+                ":backup schema" => {
+                    app.start_schema_backup();
+                }
+                ":backup" => {
+                    app.start_table_backup();
+                }
                 ":q!" => {
                     // Force clear editor
                     app.state.query_editor.reset();
@@ -246,17 +415,25 @@ async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<()> {
                             .success("File saved and editor cleared");
                     }
                 }
-                cmd if cmd.starts_with(":w ") => {
-                    // Save with filename - future enhancement
-                    app.state
-                        .toast_manager
-                        .warning("Save with filename not yet implemented");
+                // `:42` jumps to line 42
+                cmd if cmd.len() > 1 && cmd[1..].bytes().all(|b| b.is_ascii_digit()) => {
+                    let line = cmd[1..].parse().unwrap_or(usize::MAX);
+                    app.state.query_editor.go_to_line(line);
+                }
+                cmd if cmd == ":snip" || cmd.starts_with(":snip ") => {
+                    handle_snip_command(app, &cmd[5..], selection);
                 }
-                _ => {
-                    app.state
-                        .toast_manager
-                        .error(format!("Unknown command: {}", command));
+                cmd if cmd.starts_with(":w ") => {
+                    // `:w name` is `:save name`
+                    let args = crate::commands::split_command_line(&cmd[3..]);
+                    app.execute_command_with_args(CommandId::SaveQuery, args)
+                        .await?;
                 }
+                // Anything else goes to the command registry by name
+                cmd => match app.command_registry.parse_command_line(cmd) {
+                    Ok((id, args)) => app.execute_command_with_args(id, args).await?,
+                    Err(message) => app.state.toast_manager.error(message),
+                },
             }
         }
         // Regular typing - add to command buffer
@@ -267,3 +444,158 @@ async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<()> {
     }
     Ok(())
 }
+
+/// `:snip` opens the snippet picker; `:snip add <name> [description]` saves
+/// the Visual selection
+fn handle_snip_command(app: &mut App, args: &str, selection: Option<String>) {
+    let args = args.trim();
+    if args.is_empty() {
+        app.state.open_snippet_picker();
+        return;
+    }
+    let Some(rest) = args.strip_prefix("add ") else {
+        app.state
+            .toast_manager
+            .warning("Usage: :snip add <name> [description]");
+        return;
+    };
+    let (name, description) = rest
+        .trim()
+        .split_once(char::is_whitespace)
+        .unwrap_or((rest.trim(), ""));
+    match selection.filter(|text| !text.trim().is_empty()) {
+        Some(body) => app.state.save_snippet(name, description.trim(), body),
+        None => app
+            .state
+            .toast_manager
+            .warning("Select text in Visual mode (v/V), then :snip add <name>"),
+    }
+}
+
+/// Handle keys while the snippet picker is open: typing filters, Enter
+/// inserts the highlighted snippet at the cursor, Esc closes
+pub(crate) fn handle_snippet_picker(app: &mut App, key: KeyEvent) {
+    let Some(picker) = app.state.snippet_picker.as_mut() else {
+        return;
+    };
+    match (key.modifiers, key.code) {
+        (_, KeyCode::Esc) => app.state.snippet_picker = None,
+        (_, KeyCode::Enter) => {
+            let body = picker.selected_snippet().map(|s| s.body.clone());
+            app.state.snippet_picker = None;
+            if let Some(body) = body {
+                app.state.query_editor.insert_snippet(&body);
+                sync_editor(app);
+            }
+        }
+        (_, KeyCode::Down) | (KeyModifiers::CONTROL, KeyCode::Char('n' | 'j')) => {
+            picker.select_next()
+        }
+        (_, KeyCode::Up) | (KeyModifiers::CONTROL, KeyCode::Char('p' | 'k')) => {
+            picker.select_previous()
+        }
+        (_, KeyCode::Backspace) => picker.backspace(),
+        (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => picker.insert_char(c),
+        _ => {}
+    }
+}
+
+/// Handle keys while the query variables panel is open. Values are written to
+/// `query_vars` as they are typed, so closing the panel never loses them.
+fn handle_vars_panel(app: &mut App, key: KeyEvent) {
+    let rows = QueryVarsPanel::rows(&app.state.query_vars, &app.state.query_content);
+    let Some(panel) = app.state.query_vars_panel.as_mut() else {
+        return;
+    };
+
+    // Typing the name of a new variable
+    if let Some(name) = panel.new_name.as_mut() {
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_alphanumeric() || c == '_' => name.push(c),
+            KeyCode::Backspace => {
+                name.pop();
+            }
+            KeyCode::Enter if !name.is_empty() => {
+                let name = name.clone();
+                app.state.query_vars.entry(name.clone()).or_default();
+                panel.new_name = None;
+                panel.selected =
+                    QueryVarsPanel::rows(&app.state.query_vars, &app.state.query_content)
+                        .iter()
+                        .position(|row| row.name == name)
+                        .unwrap_or(0);
+                panel.editing = Some(name);
+            }
+            KeyCode::Enter | KeyCode::Esc => panel.new_name = None,
+            _ => {}
+        }
+        return;
+    }
+
+    // Typing a value
+    if let Some(name) = panel.editing.clone() {
+        match key.code {
+            KeyCode::Enter | KeyCode::Esc => panel.editing = None,
+            KeyCode::Backspace => {
+                if let Some(value) = app.state.query_vars.get_mut(&name) {
+                    value.pop();
+                }
+            }
+            KeyCode::Char(c) => app.state.query_vars.entry(name).or_default().push(c),
+            _ => {}
+        }
+        return;
+    }
+
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => app.state.query_vars_panel = None,
+        KeyCode::Char('j') | KeyCode::Down if panel.selected + 1 < rows.len() => {
+            panel.selected += 1;
+        }
+        KeyCode::Char('k') | KeyCode::Up => panel.selected = panel.selected.saturating_sub(1),
+        KeyCode::Enter | KeyCode::Char('e') | KeyCode::Char('i') => {
+            if let Some(row) = rows.get(panel.selected) {
+                app.state.query_vars.entry(row.name.clone()).or_default();
+                panel.editing = Some(row.name.clone());
+            }
+        }
+        KeyCode::Char('a') => panel.new_name = Some(String::new()),
+        KeyCode::Char('d') => {
+            if let Some(row) = rows.get(panel.selected) {
+                app.state.query_vars.remove(&row.name);
+                panel.selected = panel.selected.min(rows.len().saturating_sub(2));
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[tokio::test]
+    async fn test_w_name_saves_from_command_mode() {
+        // Commands run inside the key handler's runtime, so file I/O must
+        // be awaited rather than blocked on
+        let home = tempfile::tempdir().unwrap();
+        std::env::set_var("HOME", home.path());
+        let mut app = App::new(Config::default()).await.unwrap();
+        app.state.query_editor.set_content("SELECT 1;".to_string());
+        sync_editor(&mut app);
+
+        for c in ":w report".chars() {
+            handle(&mut app, press(KeyCode::Char(c))).await.unwrap();
+        }
+        handle(&mut app, press(KeyCode::Enter)).await.unwrap();
+
+        let saved = home.path().join(".lazytables/sql_files/report.sql");
+        assert_eq!(std::fs::read_to_string(saved).unwrap(), "SELECT 1;");
+        assert_eq!(app.state.ui.current_sql_file.as_deref(), Some("report.sql"));
+    }
+}
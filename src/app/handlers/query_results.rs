@@ -4,7 +4,17 @@
 
 #![forbid(unsafe_code)]
 
-use crate::{app::App, core::error::Result};
+use crate::{
+    app::{
+        shortcuts::{Action, ActionContext, KeyResolution},
+        App, FocusedPane,
+    },
+    core::error::Result,
+    ui::components::{
+        parse_watch_interval, table_viewer::ellipsize, CopyFormatMenu, TabWatch,
+        DEFAULT_WATCH_INTERVAL,
+    },
+};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Handle Query Results pane keys - has its own edit mode
@@ -17,20 +27,28 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
         if tab.in_search_mode {
             return handle_search_mode(app, key).await;
         }
+        if tab.watch_prompt.is_some() {
+            handle_watch_prompt(app, key);
+            return Ok(());
+        }
     }
 
-    // Normal navigation mode
-    match key.code {
-        // 'i' or Enter - Start editing current cell
-        KeyCode::Char('i') | KeyCode::Enter => {
-            if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
-                tab.start_edit();
-            }
+    // Pane actions go through the configurable hotkey table
+    match app.state.hotkeys.resolve(ActionContext::TableViewer, key) {
+        KeyResolution::Action(action) => return handle_action(app, action).await,
+        KeyResolution::Pending(hint) => {
+            app.state.toast_manager.info(hint);
+            return Ok(());
         }
-        // Ctrl+d - Page down (must come before plain 'd')
+        KeyResolution::Unbound => {}
+    }
+
+    // Fixed motions
+    match key.code {
+        // Ctrl+d - Page down
         KeyCode::Char('d') if key.modifiers == KeyModifiers::CONTROL => {
             if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
-                if tab.view_mode == crate::ui::components::table_viewer::TableViewMode::Schema {
+                if !tab.view_mode.shows_rows() {
                     tab.page_down_schema();
                 } else {
                     // In data view, page down through data pages
@@ -45,212 +63,391 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
                 }
             }
         }
-        // 'd' - Delete current row (double-tap within 500ms)
-        KeyCode::Char('d') => {
-            let now = std::time::Instant::now();
-            let should_delete = if let Some(last_press) = app.state.table_viewer_state.last_d_press
-            {
-                // Check if within 500ms window
-                now.duration_since(last_press).as_millis() < 500
+        // Ctrl+u - Page up
+        KeyCode::Char('u') if key.modifiers == KeyModifiers::CONTROL => {
+            if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
+                if !tab.view_mode.shows_rows() {
+                    tab.page_up_schema();
+                } else {
+                    // In data view, page up through data pages
+                    if tab.page_up() {
+                        let tab_idx = app.state.table_viewer_state.active_tab;
+                        if let Err(e) = app.state.load_table_data(tab_idx).await {
+                            app.state
+                                .toast_manager
+                                .error(format!("Failed to load page: {e}"));
+                        }
+                    }
+                }
+            }
+        }
+        // h/j/k/l - Navigate cells
+        KeyCode::Char('h') | KeyCode::Left => {
+            app.state.move_left();
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.state.move_down();
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.state.move_up();
+        }
+        KeyCode::Char('l') | KeyCode::Right => {
+            app.state.move_right();
+        }
+        // 'g' - First press of gg (jump to top)
+        KeyCode::Char('g') => {
+            if app.state.ui.pending_gg_command {
+                // Second 'g' press - jump to top
+                if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
+                    if !tab.view_mode.shows_rows() {
+                        tab.jump_to_top_schema();
+                    } else {
+                        tab.jump_to_first();
+                    }
+                }
+                app.state.ui.pending_gg_command = false;
             } else {
-                false
-            };
-
-            if should_delete {
-                // Double-tap detected - prepare delete confirmation
-                if let Some(confirmation) =
-                    app.state.table_viewer_state.prepare_delete_confirmation()
-                {
-                    app.state.table_viewer_state.delete_confirmation = Some(confirmation);
+                // First 'g' press - set pending
+                app.state.ui.pending_gg_command = true;
+            }
+        }
+        // 'G' - Jump to bottom
+        KeyCode::Char('G') => {
+            if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
+                if !tab.view_mode.shows_rows() {
+                    tab.jump_to_bottom_schema();
                 } else {
+                    tab.jump_to_last();
+                }
+            }
+            app.state.ui.cancel_pending_gg();
+        }
+        // 'e' - Jump to the reported error position in the query editor
+        KeyCode::Char('e') => {
+            let position = app
+                .state
+                .table_viewer_state
+                .current_tab()
+                .and_then(|tab| tab.query_error.as_ref())
+                .map(|error| error.editor_position());
+            match position {
+                Some(Some((line, col))) => {
+                    app.state.query_editor.set_cursor_position(line, col);
+                    app.state.ui.focused_pane = FocusedPane::QueryWindow;
+                }
+                Some(None) => {
                     app.state
                         .toast_manager
-                        .error("Cannot delete row: no primary key found");
+                        .info("The database did not report an error position");
                 }
-                // Reset the last press
-                app.state.table_viewer_state.last_d_press = None;
-            } else {
-                // First 'd' press - record timestamp
-                app.state.table_viewer_state.last_d_press = Some(now);
-                app.state
-                    .toast_manager
-                    .info("Press 'd' again to delete row, or 'c' to set NULL");
+                None => {}
             }
         }
-        // 'c' - Set cell to NULL (after 'd' press) or Copy cell (after 'y' press)
-        KeyCode::Char('c') => {
-            let now = std::time::Instant::now();
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Run a table viewer action resolved from the hotkey table
+async fn handle_action(app: &mut App, action: Action) -> Result<()> {
+    // Views can't take row edits; say so instead of failing on save
+    if matches!(
+        action,
+        Action::EditCell | Action::DeleteRow | Action::SetCellNull
+    ) {
+        if let Some(message) = app
+            .state
+            .table_viewer_state
+            .current_tab()
+            .and_then(|tab| tab.read_only_message())
+        {
+            app.state.toast_manager.warning(message);
+            return Ok(());
+        }
+    }
 
-            // Check for 'yc' sequence - copy cell
-            let should_copy_cell =
-                if let Some(last_press) = app.state.table_viewer_state.last_y_press {
-                    // Check if within 500ms window after 'y' press
-                    now.duration_since(last_press).as_millis() < 500
-                } else {
-                    false
-                };
+    // Binary cells only show a placeholder, so there is nothing to edit inline
+    if action == Action::EditCell
+        && app
+            .state
+            .table_viewer_state
+            .current_tab()
+            .is_some_and(|tab| tab.is_binary_column(tab.selected_col))
+    {
+        app.state
+            .toast_manager
+            .warning("Binary cells can't be edited inline - press K to inspect");
+        return Ok(());
+    }
 
-            // Check for 'dc' sequence - set NULL
-            let should_set_null =
-                if let Some(last_press) = app.state.table_viewer_state.last_d_press {
-                    // Check if within 500ms window after 'd' press
-                    now.duration_since(last_press).as_millis() < 500
-                } else {
-                    false
-                };
+    // Values cut for display are fetched in full before they are edited,
+    // copied or kept for undo
+    let full_cells = match action {
+        Action::EditCell
+        | Action::CopyCell
+        | Action::SetCellNull
+        | Action::ViewJson
+        | Action::ViewCell => Some(true),
+        Action::CopyRow | Action::CopyRowAs | Action::DeleteRow => Some(false),
+        _ => None,
+    };
+    if let Some(single_cell) = full_cells {
+        if let Some((row, col)) = app
+            .state
+            .table_viewer_state
+            .current_tab()
+            .map(|tab| (tab.selected_row, tab.selected_col))
+        {
+            let col = single_cell.then_some(col);
+            if let Err(e) = app.state.load_full_cells(row, col).await {
+                app.state.toast_manager.error(e);
+                return Ok(());
+            }
+        }
+    }
 
-            if should_copy_cell {
-                // 'yc' sequence detected - copy cell to clipboard
-                match app.state.table_viewer_state.copy_cell() {
-                    Ok(()) => {
-                        app.state.toast_manager.success("Cell copied to clipboard");
-                    }
-                    Err(e) => {
-                        app.state
-                            .toast_manager
-                            .error(format!("Failed to copy cell: {e}"));
-                    }
-                }
-                // Reset the last press
-                app.state.table_viewer_state.last_y_press = None;
-            } else if should_set_null {
-                // 'dc' sequence detected - prepare set NULL confirmation
-                if let Some(confirmation) =
-                    app.state.table_viewer_state.prepare_set_null_confirmation()
-                {
-                    app.state.table_viewer_state.set_null_confirmation = Some(confirmation);
-                } else {
-                    // Check why we can't set NULL
-                    if let Some(tab) = app.state.table_viewer_state.current_tab() {
-                        if tab.selected_col < tab.columns.len() {
-                            let column = &tab.columns[tab.selected_col];
-                            if !column.is_nullable {
-                                app.state.toast_manager.error(format!(
-                                    "Cannot set NULL: column '{}' is NOT NULL",
-                                    column.name
-                                ));
-                            } else if tab.primary_key_columns.is_empty() {
-                                app.state
-                                    .toast_manager
-                                    .error("Cannot set NULL: no primary key found");
-                            } else {
-                                app.state
-                                    .toast_manager
-                                    .error("Cannot set NULL on current cell");
-                            }
-                        }
-                    }
+    match action {
+        Action::EditCell => match app.state.table_viewer_state.open_cell_editor() {
+            Ok(true) => {}
+            Ok(false) => {
+                if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
+                    tab.start_edit();
                 }
-                // Reset the last press
-                app.state.table_viewer_state.last_d_press = None;
             }
-            // If 'c' pressed without prior 'd' or 'y', do nothing (ignore)
+            Err(e) => app.state.toast_manager.warning(e),
+        },
+        Action::InspectBinary => {
+            if let Err(e) = app.state.table_viewer_state.open_hex_view() {
+                app.state.toast_manager.info(e);
+            }
+        }
+        Action::ViewJson => {
+            if let Err(e) = app.state.table_viewer_state.open_json_view() {
+                app.state.toast_manager.info(e);
+            }
+        }
+        Action::ViewCell => {
+            if let Err(e) = app.state.table_viewer_state.open_cell_view() {
+                app.state.toast_manager.info(e);
+            }
         }
-        // 'y' - Copy current row (double-tap within 500ms)
-        KeyCode::Char('y') => {
-            let now = std::time::Instant::now();
-            let should_copy = if let Some(last_press) = app.state.table_viewer_state.last_y_press {
-                // Check if within 500ms window
-                now.duration_since(last_press).as_millis() < 500
+        Action::DeleteRow => {
+            if let Some(confirmation) = app.state.table_viewer_state.prepare_delete_confirmation() {
+                app.state.table_viewer_state.delete_confirmation = Some(confirmation);
             } else {
-                false
-            };
-
-            if should_copy {
-                // Double-tap detected - copy row to clipboard
-                match app.state.table_viewer_state.copy_row_csv() {
-                    Ok(()) => {
-                        app.state
-                            .toast_manager
-                            .success("Row copied to clipboard (CSV format)");
-                    }
-                    Err(e) => {
-                        app.state
-                            .toast_manager
-                            .error(format!("Failed to copy row: {e}"));
+                app.state
+                    .toast_manager
+                    .error("Cannot delete row: no primary key found");
+            }
+        }
+        Action::SetCellNull => {
+            if let Some(confirmation) = app.state.table_viewer_state.prepare_set_null_confirmation()
+            {
+                app.state.table_viewer_state.set_null_confirmation = Some(confirmation);
+            } else {
+                // Check why we can't set NULL
+                if let Some(tab) = app.state.table_viewer_state.current_tab() {
+                    if tab.selected_col < tab.columns.len() {
+                        let column = &tab.columns[tab.selected_col];
+                        if !column.is_nullable {
+                            app.state.toast_manager.error(format!(
+                                "Cannot set NULL: column '{}' is NOT NULL",
+                                column.name
+                            ));
+                        } else if tab.primary_key_columns.is_empty() {
+                            app.state
+                                .toast_manager
+                                .error("Cannot set NULL: no primary key found");
+                        } else {
+                            app.state
+                                .toast_manager
+                                .error("Cannot set NULL on current cell");
+                        }
                     }
                 }
-                // Reset the last press
-                app.state.table_viewer_state.last_y_press = None;
-            } else {
-                // First 'y' press - record timestamp
-                app.state.table_viewer_state.last_y_press = Some(now);
+            }
+        }
+        Action::CopyRow => match app.state.table_viewer_state.copy_row_csv() {
+            Ok(()) => {
+                app.state
+                    .toast_manager
+                    .success("Row copied to clipboard (CSV format)");
+            }
+            Err(e) => {
                 app.state
                     .toast_manager
-                    .info("Press 'y' again to copy row, or 'c' to copy cell");
+                    .error(format!("Failed to copy row: {e}"));
+            }
+        },
+        Action::CopyRowAs => {
+            let has_row = app
+                .state
+                .table_viewer_state
+                .current_tab()
+                .is_some_and(|tab| tab.selected_row < tab.rows.len());
+            if has_row {
+                app.state.table_viewer_state.copy_format_menu = Some(CopyFormatMenu::default());
+            } else {
+                app.state.toast_manager.warning("No row selected");
             }
         }
-        // '/' - Enter search mode
-        KeyCode::Char('/') => {
+        Action::CopyCell => match app.state.table_viewer_state.copy_cell() {
+            Ok(value) => {
+                app.state
+                    .toast_manager
+                    .success(format!("Copied `{}`", ellipsize(&value, 60)));
+            }
+            Err(e) => {
+                app.state
+                    .toast_manager
+                    .error(format!("Failed to copy cell: {e}"));
+            }
+        },
+        Action::CopyPrimaryKey => match app.state.table_viewer_state.copy_primary_key() {
+            Ok(fragment) => {
+                app.state
+                    .toast_manager
+                    .success(format!("Copied `{}`", ellipsize(&fragment, 60)));
+            }
+            Err(e) => {
+                app.state
+                    .toast_manager
+                    .error(format!("Failed to copy primary key: {e}"));
+            }
+        },
+        Action::SearchRows => {
             if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
                 tab.start_search();
             }
         }
-        // 't' - Toggle between Data and Schema view
-        KeyCode::Char('t') => {
+        Action::ToggleTableView => {
             if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
                 tab.toggle_view_mode();
                 let mode = match tab.view_mode {
                     crate::ui::components::table_viewer::TableViewMode::Data => "Data",
                     crate::ui::components::table_viewer::TableViewMode::Schema => "Schema",
+                    crate::ui::components::table_viewer::TableViewMode::Source
+                    | crate::ui::components::table_viewer::TableViewMode::Vertical => {
+                        return Ok(());
+                    }
                 };
                 app.state
                     .toast_manager
                     .info(format!("Switched to {} view", mode));
             }
         }
-        // 'r' - Refresh table data (works with or without Ctrl)
-        KeyCode::Char('r') => {
-            let tab_idx = app.state.table_viewer_state.active_tab;
-            if let Err(e) = app.state.load_table_data(tab_idx).await {
-                app.state
-                    .toast_manager
-                    .error(format!("Failed to refresh: {e}"));
-            } else {
-                app.state.toast_manager.success("Table data refreshed");
+        Action::SortByColumn => {
+            if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
+                if !tab.sort_by_selected_column() {
+                    app.state
+                        .toast_manager
+                        .info("Only query results can be sorted - use ORDER BY for tables");
+                }
             }
         }
-        // Ctrl+u - Page up
-        KeyCode::Char('u') if key.modifiers == KeyModifiers::CONTROL => {
+        Action::ToggleVerticalView => {
             if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
-                if tab.view_mode == crate::ui::components::table_viewer::TableViewMode::Schema {
-                    tab.page_up_schema();
+                if !tab.toggle_vertical_view() {
+                    app.state
+                        .toast_manager
+                        .info("Vertical view is only available for table data");
+                }
+            }
+        }
+        Action::ToggleWatch => {
+            if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
+                if tab.watch.take().is_some() {
+                    app.state
+                        .toast_manager
+                        .info(format!("Stopped watching {}", tab.table_name));
+                } else if tab.watch_source().is_some() {
+                    tab.watch_prompt = Some(DEFAULT_WATCH_INTERVAL.to_string());
                 } else {
-                    // In data view, page up through data pages
-                    if tab.page_up() {
-                        let tab_idx = app.state.table_viewer_state.active_tab;
-                        if let Err(e) = app.state.load_table_data(tab_idx).await {
-                            app.state
-                                .toast_manager
-                                .error(format!("Failed to load page: {e}"));
-                        }
-                    }
+                    app.state
+                        .toast_manager
+                        .warning("Only table tabs and query results can be watched");
                 }
             }
         }
-        // h/j/k/l - Navigate cells
-        KeyCode::Char('h') | KeyCode::Left => {
-            app.state.move_left();
+        Action::RefreshTableData
+            if app
+                .state
+                .table_viewer_state
+                .current_tab()
+                .map(|tab| tab.view_mode)
+                == Some(crate::ui::components::table_viewer::TableViewMode::Source) =>
+        {
+            app.state
+                .toast_manager
+                .info("Reopen the routine from the Routines pane to refresh its source");
         }
-        KeyCode::Char('j') | KeyCode::Down => {
-            app.state.move_down();
+        Action::RefreshTableData
+            if app
+                .state
+                .table_viewer_state
+                .current_tab()
+                .is_some_and(|tab| tab.origin.is_some()) =>
+        {
+            let tab_idx = app.state.table_viewer_state.active_tab;
+            if let Err(e) = app.state.rerun_tab_query(tab_idx).await {
+                app.state
+                    .toast_manager
+                    .error(format!("Failed to refresh: {e}"));
+            } else {
+                app.state.toast_manager.success("Query re-run");
+            }
         }
-        KeyCode::Char('k') | KeyCode::Up => {
-            app.state.move_up();
+        Action::RefreshTableData => {
+            if let Err(e) = app.state.reload_current_table_tab().await {
+                app.state
+                    .toast_manager
+                    .error(format!("Failed to refresh: {e}"));
+            } else {
+                app.state.toast_manager.success("Table data refreshed");
+            }
         }
-        KeyCode::Char('l') | KeyCode::Right => {
-            app.state.move_right();
+        Action::PinResults => match app.state.table_viewer_state.pin_current_tab() {
+            Ok(row_count) => {
+                app.state.toast_manager.success(format!(
+                    "Pinned {row_count} rows - run a query and press {} to diff",
+                    app.state.hotkeys.label(Action::DiffResults)
+                ));
+            }
+            Err(e) => {
+                app.state.toast_manager.error(e);
+            }
+        },
+        Action::DiffResults => {
+            let max_entries = app.config.results.max_diff_entries;
+            match app.state.table_viewer_state.open_diff_tab(max_entries) {
+                Ok(diff) if diff.total() == 0 => {
+                    app.state
+                        .toast_manager
+                        .info("No differences between the two results");
+                }
+                Ok(diff) => {
+                    let matching = if diff.duplicate_keys {
+                        " (key values repeat, so rows were compared whole)"
+                    } else {
+                        ""
+                    };
+                    app.state.toast_manager.success(format!(
+                        "{} added, {} removed, {} changed{matching}",
+                        diff.added, diff.removed, diff.changed
+                    ));
+                }
+                Err(e) => {
+                    app.state.toast_manager.error(e);
+                }
+            }
         }
-        // 'H' - Switch to previous tab
-        KeyCode::Char('H') => {
+        Action::PreviousTab => {
             app.state.table_viewer_state.prev_tab();
         }
-        // 'L' - Switch to next tab
-        KeyCode::Char('L') => {
+        Action::NextTab => {
             app.state.table_viewer_state.next_tab();
         }
-        // 'x' - Close current tab
-        KeyCode::Char('x') => {
+        Action::CloseTab => {
             let table_name = app
                 .state
                 .table_viewer_state
@@ -265,60 +462,172 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
                     .info(format!("Closed tab: {}", name));
             }
         }
-        // 'g' - First press of gg (jump to top)
-        KeyCode::Char('g') => {
-            if app.state.ui.pending_gg_command {
-                // Second 'g' press - jump to top
-                if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
-                    if tab.view_mode == crate::ui::components::table_viewer::TableViewMode::Schema {
-                        tab.jump_to_top_schema();
-                    } else {
-                        tab.jump_to_first();
-                    }
+        Action::FirstColumn => {
+            if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
+                if tab.view_mode.shows_rows() {
+                    tab.jump_to_first_col();
                 }
-                app.state.ui.pending_gg_command = false;
-            } else {
-                // First 'g' press - set pending
-                app.state.ui.pending_gg_command = true;
             }
         }
-        // 'G' - Jump to bottom
-        KeyCode::Char('G') => {
+        Action::UndoChange => match app.state.undo_data_change().await {
+            Ok(Some(description)) => {
+                app.state
+                    .toast_manager
+                    .success(format!("Undid {description}"));
+            }
+            Ok(None) => app.state.toast_manager.info("Nothing to undo"),
+            Err(e) => {
+                app.state
+                    .toast_manager
+                    .error(format!("Failed to undo: {e}"));
+            }
+        },
+        Action::RedoChange => match app.state.redo_data_change().await {
+            Ok(Some(description)) => {
+                app.state
+                    .toast_manager
+                    .success(format!("Redid {description}"));
+            }
+            Ok(None) => app.state.toast_manager.info("Nothing to redo"),
+            Err(e) => {
+                app.state
+                    .toast_manager
+                    .error(format!("Failed to redo: {e}"));
+            }
+        },
+        Action::LastColumn => {
             if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
-                if tab.view_mode == crate::ui::components::table_viewer::TableViewMode::Schema {
-                    tab.jump_to_bottom_schema();
-                } else {
-                    tab.jump_to_last();
+                if tab.view_mode.shows_rows() {
+                    tab.jump_to_last_col();
+                }
+            }
+        }
+        Action::FreezeColumns => {
+            if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
+                if tab.view_mode == crate::ui::components::table_viewer::TableViewMode::Data
+                    && !tab.columns.is_empty()
+                {
+                    let message = match tab.toggle_freeze_columns() {
+                        0 => "Unfroze columns".to_string(),
+                        1 => format!("Froze {}", tab.columns[0].name),
+                        count => format!("Froze the first {count} columns"),
+                    };
+                    app.state.toast_manager.info(message);
                 }
             }
-            app.state.ui.cancel_pending_gg();
         }
-        // '0' - Jump to first column (only in data view)
-        KeyCode::Char('0') => {
+        Action::FreezeMoreColumns | Action::FreezeFewerColumns => {
             if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
                 if tab.view_mode == crate::ui::components::table_viewer::TableViewMode::Data {
-                    tab.jump_to_first_col();
+                    let count = if action == Action::FreezeMoreColumns {
+                        tab.freeze_more_columns()
+                    } else {
+                        tab.freeze_fewer_columns()
+                    };
+                    let message = match count {
+                        0 => "No columns frozen".to_string(),
+                        1 => format!("Froze {}", tab.columns[0].name),
+                        count => format!("Froze the first {count} columns"),
+                    };
+                    app.state.toast_manager.info(message);
                 }
             }
         }
-        // '$' - Jump to last column (only in data view)
-        KeyCode::Char('$') => {
+        Action::ToggleRowNumbers => {
             if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
                 if tab.view_mode == crate::ui::components::table_viewer::TableViewMode::Data {
-                    tab.jump_to_last_col();
+                    let message = if tab.toggle_row_numbers() {
+                        "Showing row numbers"
+                    } else {
+                        "Row numbers hidden"
+                    };
+                    app.state.toast_manager.info(message);
+                }
+            }
+        }
+        Action::AutoFitColumns => {
+            if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
+                if tab.view_mode == crate::ui::components::table_viewer::TableViewMode::Data {
+                    let message = if tab.toggle_auto_fit() {
+                        "Columns fitted to their content"
+                    } else {
+                        "Columns back to default widths"
+                    };
+                    app.state.toast_manager.info(message);
                 }
             }
         }
+        Action::WidenColumn | Action::NarrowColumn => {
+            let step = crate::ui::components::table_viewer::COLUMN_RESIZE_STEP as isize;
+            let delta = if action == Action::WidenColumn {
+                step
+            } else {
+                -step
+            };
+            if app
+                .state
+                .table_viewer_state
+                .current_tab()
+                .is_some_and(|tab| {
+                    tab.view_mode == crate::ui::components::table_viewer::TableViewMode::Data
+                })
+            {
+                app.state.resize_selected_column(delta);
+            }
+        }
         _ => {}
     }
     Ok(())
 }
 
+/// Handle the interval prompt opened by `W`
+fn handle_watch_prompt(app: &mut App, key: KeyEvent) {
+    let Some(tab) = app.state.table_viewer_state.current_tab_mut() else {
+        return;
+    };
+    let Some(input) = tab.watch_prompt.as_mut() else {
+        return;
+    };
+    match key.code {
+        KeyCode::Esc => {
+            tab.watch_prompt = None;
+        }
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        KeyCode::Char(c) if c.is_ascii_digit() || matches!(c, '.' | 's') => {
+            input.push(c);
+        }
+        KeyCode::Enter => match parse_watch_interval(input) {
+            Ok(interval) => {
+                tab.watch_prompt = None;
+                if let Some(source) = tab.watch_source() {
+                    let watch = TabWatch::new(interval, source);
+                    app.state.toast_manager.success(format!(
+                        "Watching {} ({})",
+                        tab.table_name,
+                        watch.label()
+                    ));
+                    tab.watch = Some(watch);
+                }
+            }
+            // Keep the prompt open so the interval can be fixed
+            Err(e) => app.state.toast_manager.error(e),
+        },
+        _ => {}
+    }
+}
+
 /// Handle table viewer edit mode keys
 async fn handle_edit_mode(app: &mut App, key: KeyEvent) -> Result<()> {
     if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
         match key.code {
             KeyCode::Esc | KeyCode::Enter => {
+                // Keep editing when the column cannot take the pending value
+                if let Err(e) = tab.validate_edit() {
+                    app.state.toast_manager.error(e);
+                    return Ok(());
+                }
                 // Save edit
                 if let Some(update) = tab.save_edit() {
                     if let Err(e) = app.state.update_table_cell(update).await {
@@ -334,11 +643,15 @@ async fn handle_edit_mode(app: &mut App, key: KeyEvent) -> Result<()> {
                 // Cancel edit
                 tab.cancel_edit();
             }
+            KeyCode::Char('n') if key.modifiers == KeyModifiers::CONTROL => {
+                // Set the pending value to SQL NULL
+                tab.set_edit_null();
+            }
             KeyCode::Char(c) => {
-                tab.edit_buffer.push(c);
+                tab.push_edit_char(c);
             }
             KeyCode::Backspace => {
-                tab.edit_buffer.pop();
+                tab.pop_edit_char();
             }
             _ => {}
         }
@@ -375,3 +688,65 @@ async fn handle_search_mode(app: &mut App, key: KeyEvent) -> Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    /// App with one result tab holding `rows` under `columns`
+    async fn app_with_result(columns: &[&str], rows: Vec<Vec<Option<String>>>) -> App {
+        let mut app = App::new(Config::default()).await.unwrap();
+        let viewer = &mut app.state.table_viewer_state;
+        let index = viewer.add_tab("Query Result".to_string());
+        let columns: Vec<String> = columns.iter().map(|c| c.to_string()).collect();
+        viewer.tabs[index].set_result_rows(&columns, rows, usize::MAX);
+        app
+    }
+
+    #[tokio::test]
+    async fn test_binary_cells_open_a_hex_dump_instead_of_editing() {
+        let mut app = app_with_result(
+            &["id", "avatar"],
+            vec![vec![Some("1".to_string()), Some("0x89504E47".to_string())]],
+        )
+        .await;
+        app.state.table_viewer_state.tabs[0].selected_col = 1;
+
+        handle(&mut app, press(KeyCode::Char('i'))).await.unwrap();
+        assert!(!app.state.table_viewer_state.tabs[0].in_edit_mode);
+        let toast = app.state.toast_manager.history().back().unwrap();
+        assert!(toast.message.starts_with("Binary cells can't be edited"));
+
+        handle(&mut app, press(KeyCode::Char('K'))).await.unwrap();
+        assert!(app.state.table_viewer_state.hex_view.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_ctrl_f_steps_frozen_columns_up_to_the_cursor() {
+        let row = ["1", "ada", "ada@example.com"].map(|v| Some(v.to_string()));
+        let mut app = app_with_result(&["id", "name", "email"], vec![row.to_vec()]).await;
+        app.state.table_viewer_state.tabs[0].selected_col = 1;
+
+        let ctrl_f = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL);
+        for _ in 0..3 {
+            handle(&mut app, ctrl_f).await.unwrap();
+        }
+        assert_eq!(app.state.table_viewer_state.tabs[0].frozen_count(), 2);
+        let toast = app.state.toast_manager.history().back().unwrap();
+        assert_eq!(toast.message, "Froze the first 2 columns");
+
+        let ctrl_shift_f = KeyEvent::new(
+            KeyCode::Char('F'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        );
+        handle(&mut app, ctrl_shift_f).await.unwrap();
+        assert_eq!(app.state.table_viewer_state.tabs[0].frozen_count(), 1);
+        let toast = app.state.toast_manager.history().back().unwrap();
+        assert_eq!(toast.message, "Froze id");
+    }
+}
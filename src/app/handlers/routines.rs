@@ -0,0 +1,94 @@
+// FilePath: src/app/handlers/routines.rs
+//
+// Event handler for the Routines pane (stored function/procedure browser)
+
+#![forbid(unsafe_code)]
+
+use crate::{
+    app::{
+        shortcuts::{Action, ActionContext, KeyResolution},
+        App, FocusedPane,
+    },
+    core::error::Result,
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Handle Routines pane keys
+pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
+    match app.state.hotkeys.resolve(ActionContext::Routines, key) {
+        KeyResolution::Action(Action::OpenRoutine) => {
+            match app.state.open_routine_source().await {
+                Ok(()) => app.state.ui.focused_pane = FocusedPane::TabularOutput,
+                Err(e) => app
+                    .state
+                    .toast_manager
+                    .error(format!("Failed to load routine source: {e}")),
+            }
+            return Ok(());
+        }
+        KeyResolution::Action(Action::DropRoutine) => {
+            if let Some(routine) = app.state.db.routines.get(app.state.ui.selected_routine) {
+                let kind = routine.kind.to_lowercase();
+                app.state.ui.confirmation_modal = Some(crate::ui::ConfirmationModal {
+                    title: format!("Drop {kind}"),
+                    message: format!(
+                        "Drop {kind} '{}'?\n\nThis cannot be undone.",
+                        routine.display_name()
+                    ),
+                    action: crate::ui::ConfirmationAction::DropRoutine(routine.clone()),
+                });
+            }
+            return Ok(());
+        }
+        KeyResolution::Action(Action::RefreshRoutines) => {
+            match app.state.load_routines().await {
+                Ok(()) => app
+                    .state
+                    .toast_manager
+                    .success(format!("Loaded {} routines", app.state.db.routines.len())),
+                Err(e) => app
+                    .state
+                    .toast_manager
+                    .error(format!("Failed to load routines: {e}")),
+            }
+            return Ok(());
+        }
+        KeyResolution::Pending(hint) => {
+            app.state.toast_manager.info(hint);
+            return Ok(());
+        }
+        KeyResolution::Action(_) | KeyResolution::Unbound => {}
+    }
+
+    // Fixed motions
+    let count = app.state.db.routines.len();
+    match key.code {
+        // Esc or Ctrl+R - Back to the tables list
+        KeyCode::Esc => {
+            app.state.ui.focused_pane = FocusedPane::Tables;
+        }
+        KeyCode::Char('r') if key.modifiers == KeyModifiers::CONTROL => {
+            app.state.ui.focused_pane = FocusedPane::Tables;
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.state.move_down();
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.state.move_up();
+        }
+        KeyCode::Char('g') => {
+            if app.state.ui.pending_gg_command {
+                app.state.ui.select_routine(0, count);
+                app.state.ui.pending_gg_command = false;
+            } else {
+                app.state.ui.pending_gg_command = true;
+            }
+        }
+        KeyCode::Char('G') => {
+            app.state.ui.select_routine(count.saturating_sub(1), count);
+            app.state.ui.cancel_pending_gg();
+        }
+        _ => {}
+    }
+    Ok(())
+}
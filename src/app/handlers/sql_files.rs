@@ -4,7 +4,14 @@
 
 #![forbid(unsafe_code)]
 
-use crate::{app::App, core::error::Result};
+use crate::{
+    app::{
+        shortcuts::{Action, ActionContext, KeyResolution},
+        App,
+    },
+    commands::CommandId,
+    core::error::Result,
+};
 use crossterm::event::{KeyCode, KeyEvent};
 
 /// Handle SQL Files pane keys - DIRECT KEY BINDINGS
@@ -20,10 +27,34 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
         return handle_create_mode(app, key).await;
     }
 
-    // Normal mode
+    // Pane actions go through the configurable hotkey table
+    match app.state.hotkeys.resolve(ActionContext::SqlFiles, key) {
+        KeyResolution::Action(action) => return handle_action(app, action).await,
+        KeyResolution::Pending(hint) => {
+            app.state.toast_manager.info(hint);
+            return Ok(());
+        }
+        KeyResolution::Unbound => {}
+    }
+
+    // Fixed motions
     match key.code {
-        // Enter - Load selected SQL file
-        KeyCode::Enter => {
+        // j/k - Navigate files
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.state.update_sql_file_selection_for_filtered(1);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.state.update_sql_file_selection_for_filtered(-1);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Run a SQL files pane action resolved from the hotkey table
+async fn handle_action(app: &mut App, action: Action) -> Result<()> {
+    match action {
+        Action::LoadSqlFile => {
             if let Err(e) = app.state.load_selected_sql_file() {
                 app.state
                     .toast_manager
@@ -32,43 +63,33 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
                 app.state.toast_manager.success("SQL file loaded");
             }
         }
-        // 'n' - Create new file
-        KeyCode::Char('n') => {
+        Action::NewSqlFile => {
             app.state.ui.enter_sql_files_create();
         }
-        // 'r' - Rename file
-        KeyCode::Char('r') => {
+        Action::RenameSqlFile => {
             if let Some(filename) = app.state.get_selected_sql_file() {
                 app.state.ui.enter_sql_files_rename(&filename);
             }
         }
-        // 'd' - Delete file
-        KeyCode::Char('d') => {
-            if !app.state.saved_sql_files.is_empty() {
-                let index = app.state.get_filtered_sql_file_selection();
-                app.state.ui.confirmation_modal = Some(crate::ui::ConfirmationModal {
-                    title: "Delete SQL File".to_string(),
-                    message: format!(
-                        "Are you sure you want to delete '{}'?",
-                        app.state
-                            .saved_sql_files
-                            .get(index)
-                            .unwrap_or(&String::new())
-                    ),
-                    action: crate::ui::ConfirmationAction::DeleteSqlFile(index),
-                });
-            }
-        }
-        // '/' - Enter search mode
-        KeyCode::Char('/') => {
+        Action::DeleteSqlFile if !app.state.saved_sql_files.is_empty() => {
+            let index = app.state.get_filtered_sql_file_selection();
+            app.state.ui.confirmation_modal = Some(crate::ui::ConfirmationModal {
+                title: "Delete SQL File".to_string(),
+                message: format!(
+                    "Are you sure you want to delete '{}'?",
+                    app.state
+                        .saved_sql_files
+                        .get(index)
+                        .unwrap_or(&String::new())
+                ),
+                action: crate::ui::ConfirmationAction::DeleteSqlFile(index),
+            });
+        }
+        Action::SearchSqlFiles => {
             app.state.ui.enter_sql_files_search();
         }
-        // j/k - Navigate files
-        KeyCode::Char('j') | KeyCode::Down => {
-            app.state.update_sql_file_selection_for_filtered(1);
-        }
-        KeyCode::Char('k') | KeyCode::Up => {
-            app.state.update_sql_file_selection_for_filtered(-1);
+        Action::BookmarkSqlFile => {
+            app.execute_command(CommandId::ToggleBookmark).await?;
         }
         _ => {}
     }
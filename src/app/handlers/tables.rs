@@ -4,7 +4,14 @@
 
 #![forbid(unsafe_code)]
 
-use crate::{app::App, core::error::Result};
+use crate::{
+    app::{
+        shortcuts::{Action, ActionContext, KeyResolution},
+        App, FocusedPane,
+    },
+    commands::CommandId,
+    core::error::Result,
+};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Handle Tables pane keys - DIRECT KEY BINDINGS
@@ -19,8 +26,15 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
                 app.state.ui.backspace_tables_search();
             }
             KeyCode::Enter => {
+                let name = app.state.ui.get_selected_table_name();
                 app.state.open_table_for_viewing().await;
                 app.state.ui.exit_tables_search();
+                // Keep the opened match selected, even from a collapsed group
+                if let Some(name) = name {
+                    app.state
+                        .ui
+                        .reveal_table(&name, &app.state.db.database_objects);
+                }
             }
             KeyCode::Down => {
                 app.state.ui.table_search_selection_down();
@@ -36,21 +50,86 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
         return Ok(());
     }
 
-    // Normal mode
-    match key.code {
-        // Enter or Space - Open table for viewing
-        KeyCode::Enter | KeyCode::Char(' ') => {
+    // Pane actions go through the configurable hotkey table
+    match app.state.hotkeys.resolve(ActionContext::Tables, key) {
+        // Enter on a group header collapses or expands it
+        KeyResolution::Action(Action::OpenTable)
+            if app
+                .state
+                .ui
+                .get_selected_item_raw()
+                .is_some_and(|item| item.is_header()) =>
+        {
+            toggle_selected_group(app);
+            return Ok(());
+        }
+        KeyResolution::Action(Action::OpenTable) => {
             app.state.open_table_for_viewing().await;
+            return Ok(());
+        }
+        KeyResolution::Action(Action::ToggleTableGroup) => {
+            toggle_selected_group(app);
+            return Ok(());
+        }
+        KeyResolution::Action(Action::CycleTableFilter) => {
+            let filter = app
+                .state
+                .ui
+                .cycle_tables_type_filter(&app.state.db.database_objects);
+            app.state
+                .toast_manager
+                .info(format!("Showing {}", filter.label()));
+            return Ok(());
+        }
+        KeyResolution::Action(Action::RefreshTables) => {
+            app.execute_command(CommandId::RefreshTables).await?;
+            return Ok(());
         }
-        // 'r' - Refresh tables list
-        KeyCode::Char('r') => {
-            app.state.connect_to_selected_database().await;
-            app.state.toast_manager.info("Tables refreshed");
+        KeyResolution::Action(Action::ImportCsv) => {
+            app.execute_command(CommandId::ImportCsv).await?;
+            return Ok(());
         }
-        // '/' - Enter search mode
-        KeyCode::Char('/') => {
+        KeyResolution::Action(Action::ShowRoutines) => {
+            app.state.show_routines().await;
+            return Ok(());
+        }
+        KeyResolution::Action(Action::BackupTable) => {
+            app.start_table_backup();
+            return Ok(());
+        }
+        KeyResolution::Action(Action::BookmarkTable) => {
+            app.execute_command(CommandId::ToggleBookmark).await?;
+            return Ok(());
+        }
+        KeyResolution::Action(Action::TruncateTable) => {
+            if let Err(e) = app.state.confirm_truncate_table() {
+                app.state.toast_manager.info(e);
+            }
+            return Ok(());
+        }
+        KeyResolution::Action(Action::SearchTables) => {
             app.state.ui.enter_tables_search();
+            return Ok(());
+        }
+        KeyResolution::Action(Action::TableActions) => {
+            match app.state.ui.get_selected_table_name() {
+                Some(name) => {
+                    app.state.table_context_menu =
+                        Some(crate::ui::components::ContextMenu::for_table(name));
+                }
+                None => app.state.toast_manager.info("Select a table first"),
+            }
+            return Ok(());
+        }
+        KeyResolution::Pending(hint) => {
+            app.state.toast_manager.info(hint);
+            return Ok(());
         }
+        KeyResolution::Action(_) | KeyResolution::Unbound => {}
+    }
+
+    // Fixed motions
+    match key.code {
         // j/k - Navigate
         KeyCode::Char('j') | KeyCode::Down => {
             app.state.ui.table_search_selection_down();
@@ -80,39 +159,149 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> Result<()> {
                 app.state.ui.table_search_selection_up();
             }
         }
-        // Tab - Toggle group expansion (when on a header)
-        KeyCode::Tab if key.modifiers == KeyModifiers::NONE => {
-            if let Some(item) = app.state.ui.get_selected_item_raw() {
-                if !item.is_selectable {
-                    // It's a group header - extract group name and toggle expansion
-                    let group_name = item
-                        .display_name
-                        .trim_start_matches("▼ ")
-                        .trim_start_matches("▶ ")
-                        .trim()
-                        .to_string();
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Collapse or expand the group whose header is under the cursor
+fn toggle_selected_group(app: &mut App) {
+    match app
+        .state
+        .ui
+        .toggle_selected_table_group(&app.state.db.database_objects)
+    {
+        Some((group, expanded)) => app.state.toast_manager.info(format!(
+            "{} {group}",
+            if expanded { "Expanded" } else { "Collapsed" }
+        )),
+        None => app
+            .state
+            .toast_manager
+            .info("Move to a group header to collapse or expand it"),
+    }
+}
+
+/// Handle keys while the `M` / `Ctrl+Space` menu is open: a shortcut letter or
+/// Enter runs the entry, Esc closes the menu
+pub(crate) async fn handle_context_menu(app: &mut App, key: KeyEvent) -> Result<()> {
+    let Some(menu) = app.state.table_context_menu.as_mut() else {
+        return Ok(());
+    };
+    let command = match key.code {
+        KeyCode::Esc => {
+            app.state.table_context_menu = None;
+            return Ok(());
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            menu.select_next();
+            None
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            menu.select_previous();
+            None
+        }
+        KeyCode::Enter => menu.selected_command(),
+        KeyCode::Char(c) => menu.command_for_key(c),
+        _ => None,
+    };
+
+    if let Some(command) = command {
+        let target = menu.target.clone();
+        app.state.table_context_menu = None;
+        run_table_command(app, command, &target).await;
+    }
+    Ok(())
+}
 
-                    if !group_name.is_empty() {
-                        let is_expanded_before = app.state.ui.is_object_group_expanded(&group_name);
-                        app.state.ui.toggle_object_group_expansion(&group_name);
-                        app.state
-                            .ui
-                            .build_selectable_table_items(&app.state.db.database_objects);
-                        app.state.toast_manager.info(format!(
-                            "{} {}",
-                            if !is_expanded_before {
-                                "Expanded"
-                            } else {
-                                "Collapsed"
-                            },
-                            group_name
-                        ));
-                    }
+/// Run a table command picked from the context menu against `target`
+async fn run_table_command(app: &mut App, command: CommandId, target: &str) {
+    match command {
+        CommandId::ShowTableData => {
+            app.state.open_table_for_viewing().await;
+        }
+        CommandId::OpenTableInNewTab => {
+            app.state.open_table_in_new_tab().await;
+        }
+        CommandId::CopyTableName | CommandId::CopyQualifiedTableName => {
+            let Some(item) = app.state.ui.get_selected_table_item() else {
+                return;
+            };
+            let text = if command == CommandId::CopyTableName {
+                item.object_name.clone()
+            } else {
+                item.qualified_name()
+            };
+            match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(&text)) {
+                Ok(()) => app.state.toast_manager.success(format!("Copied {text}")),
+                Err(e) => app
+                    .state
+                    .toast_manager
+                    .error(format!("Failed to copy to clipboard: {e}")),
+            }
+        }
+        CommandId::RenameTable => {
+            app.state.rename_prompt =
+                Some(crate::ui::components::RenamePrompt::new(target.to_string()));
+        }
+        CommandId::ShowTableStructure => {
+            app.state.open_table_for_viewing().await;
+            if let Some(tab) = app.state.table_viewer_state.current_tab_mut() {
+                if tab.view_mode == crate::ui::components::TableViewMode::Data {
+                    tab.toggle_view_mode();
                 }
-                // If it's not a header, Tab is handled by global keys for pane cycling
             }
         }
+        CommandId::RefreshTableMetadata => match app.state.load_table_metadata(target).await {
+            Ok(()) => app
+                .state
+                .toast_manager
+                .success(format!("Refreshed metadata for {target}")),
+            Err(e) => app
+                .state
+                .toast_manager
+                .error(format!("Failed to load table metadata: {e}")),
+        },
+        CommandId::DropTable => {
+            let kind = app
+                .state
+                .ui
+                .get_selected_table_item()
+                .map_or("table".to_string(), |item| {
+                    item.object_type.display_name().to_lowercase()
+                });
+            app.state.ui.confirmation_modal = Some(crate::ui::ConfirmationModal {
+                title: format!("Drop {kind}"),
+                message: format!(
+                    "Drop {kind} '{target}'? This cannot be undone.\n\nType its name to confirm."
+                ),
+                action: crate::ui::ConfirmationAction::DeleteTable(target.to_string()),
+            });
+        }
+        CommandId::TruncateTable => {
+            if let Err(e) = app.state.confirm_truncate_table() {
+                app.state.toast_manager.info(e);
+            }
+        }
+        CommandId::ExportTableDdl => match app.state.table_ddl(target).await {
+            Ok(ddl) => {
+                app.state
+                    .table_viewer_state
+                    .add_source_tab(format!("{target} DDL"), &ddl);
+                app.state.ui.focused_pane = FocusedPane::TabularOutput;
+            }
+            Err(e) => app
+                .state
+                .toast_manager
+                .error(format!("Failed to build DDL for {target}: {e}")),
+        },
+        CommandId::ProfileTable => match app.state.load_table_metadata(target).await {
+            Ok(()) => app.start_column_profile(),
+            Err(e) => app
+                .state
+                .toast_manager
+                .error(format!("Failed to load table metadata: {e}")),
+        },
         _ => {}
     }
-    Ok(())
 }
@@ -6,26 +6,38 @@ use crate::{
     commands::{CommandAction, CommandContext, CommandId, CommandRegistry, CommandResult},
     config::Config,
     core::error::Result,
+    database::{BackendSignal, ColumnProfileView, LockInfo},
     event::{Event, EventHandler},
-    ui::UI,
+    ui::{
+        components::{
+            reports_rows_affected, CommandPalette, PaletteEntry, QueryOutput, StatementResult,
+            StatementStatus, ThemeGallery, WatchSource,
+        },
+        theme::{Theme, ThemeLoader},
+        UI,
+    },
 };
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{DefaultTerminal, Frame};
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 pub mod handlers;
+pub mod shortcuts;
 pub mod state;
 
 pub use state::{
     AppState, AppView, ConnectionFormMode, FocusedPane, HelpMode, OverlayView, TextInputMode,
 };
 
+/// Background re-run of a watched query, yielding its columns and rows
+type WatchRefresh = tokio::task::JoinHandle<Result<(Vec<String>, Vec<Vec<Option<String>>>)>>;
+type BackendSignalTask = (BackendSignal, i32, tokio::task::JoinHandle<Result<bool>>);
+
 /// Connection event sent from background tasks to main event loop
 #[derive(Debug)]
 enum ConnectionEvent {
     Success {
         connection_index: usize,
-        objects: crate::database::DatabaseObjectList,
     },
     Failed {
         connection_index: usize,
@@ -33,6 +45,33 @@ enum ConnectionEvent {
     },
 }
 
+/// Object listing progress sent from the background listing task
+#[derive(Debug)]
+enum ObjectListEvent {
+    Batch {
+        generation: u64,
+        objects: crate::database::DatabaseObjectList,
+    },
+    Done {
+        generation: u64,
+    },
+    Failed {
+        generation: u64,
+        error: String,
+    },
+}
+
+/// Part of the editor buffer a "run all" executes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScriptScope {
+    /// The whole buffer
+    All,
+    /// The Visual mode selection
+    Selection,
+    /// The blank-line-delimited block around the cursor
+    Paragraph,
+}
+
 /// Test connection event sent from background tasks to main event loop
 #[derive(Debug)]
 enum TestConnectionEvent {
@@ -66,16 +105,84 @@ pub struct App {
     test_connection_events_tx: tokio::sync::mpsc::UnboundedSender<TestConnectionEvent>,
     /// Task handle for ongoing test connection (for abort capability)
     test_connection_task_handle: Option<tokio::task::JoinHandle<()>>,
+    /// Channel receiver for streamed object list batches
+    object_list_events_rx: tokio::sync::mpsc::UnboundedReceiver<ObjectListEvent>,
+    /// Channel sender for object list batches (cloned for the listing task)
+    object_list_events_tx: tokio::sync::mpsc::UnboundedSender<ObjectListEvent>,
+    /// Task handle for the ongoing object listing (for abort capability)
+    object_list_task_handle: Option<tokio::task::JoinHandle<()>>,
+    /// Incremented for every listing so stale batches can be told apart
+    object_list_generation: u64,
+    /// Running CSV import: target table and the COPY task
+    csv_import_task: Option<(String, tokio::task::JoinHandle<Result<u64>>)>,
+    /// Query started from the editor, timed by `AppState::running_query`
+    query_task: Option<tokio::task::JoinHandle<Result<QueryOutput>>>,
+    /// Column profiling started from the details pane
+    profile_task: Option<tokio::task::JoinHandle<Result<ColumnProfileView>>>,
+    /// SQL dump started with `B` or `:backup schema`; progress is in `AppState::backup`
+    backup_task: Option<tokio::task::JoinHandle<Result<u64>>>,
+    /// Re-runs of watched query result tabs, keyed by `TabWatch::id`
+    watch_tasks: Vec<(u64, WatchRefresh)>,
+    /// Reload of the `:locks` monitor
+    lock_task: Option<tokio::task::JoinHandle<Result<Vec<LockInfo>>>>,
+    /// Cancel or terminate sent from the `:locks` monitor
+    backend_signal_task: Option<BackendSignalTask>,
+    /// Clear the terminal before the next draw so every cell is repainted
+    needs_full_redraw: bool,
 }
 
 impl App {
     /// Create a new application instance
     pub async fn new(config: Config) -> Result<Self> {
-        let state = AppState::new().await;
-        let event_handler = EventHandler::new(Duration::from_millis(250));
+        let mut state = AppState::new().await;
+        let mut event_handler = EventHandler::new(Duration::from_millis(250));
         let ui = UI::new(&config)?;
+        if let Some(path) = ui.theme_path.clone() {
+            if let Err(e) = event_handler.watch_theme(path) {
+                tracing::warn!("Theme hot-reload disabled: {}", e);
+            }
+        }
         let command_registry = CommandRegistry::new();
 
+        // Apply keybinding overrides; report bad entries and collisions
+        let (hotkeys, warnings) = shortcuts::HotkeyManager::from_config(&config.keybindings);
+        state.hotkeys = hotkeys;
+        state.test_timeout_seconds = config.connections.test_timeout_secs;
+        state.query_editor.set_tab_size(config.editor.tab_size);
+        state.query_editor.set_line_numbers(
+            config.editor.show_line_numbers,
+            config.editor.relative_line_numbers,
+        );
+        state
+            .query_editor
+            .set_highlight_current_line(config.editor.highlight_current_line);
+        state.layout = crate::ui::layout::LayoutManager::from_config(&config.layout);
+        state.toast_manager.configure(&config.toasts);
+        state.table_viewer_state.max_cell_bytes = config.results.max_cell_bytes;
+        state.table_viewer_state.frozen_columns = config.results.frozen_columns;
+        state.table_viewer_state.auto_fit_columns = config.results.auto_fit_columns;
+        if state
+            .db
+            .connections
+            .connections
+            .iter()
+            .any(|connection| connection.requires_encryption_key())
+        {
+            state.open_unlock_prompt(crate::ui::components::UnlockThen::Nothing);
+        }
+        if !warnings.is_empty() {
+            state
+                .toast_manager
+                .warning(format!("Keybinding problems: {}", warnings.join("; ")));
+        }
+        let unknown_modules = config.status_bar.unknown_modules();
+        if !unknown_modules.is_empty() {
+            state.toast_manager.warning(format!(
+                "Unknown status bar modules: {}",
+                unknown_modules.join(", ")
+            ));
+        }
+
         // Create channel for connection events
         let (connection_events_tx, connection_events_rx) = tokio::sync::mpsc::unbounded_channel();
 
@@ -83,6 +190,9 @@ impl App {
         let (test_connection_events_tx, test_connection_events_rx) =
             tokio::sync::mpsc::unbounded_channel();
 
+        // Create channel for streamed object listings
+        let (object_list_events_tx, object_list_events_rx) = tokio::sync::mpsc::unbounded_channel();
+
         Ok(Self {
             state,
             event_handler,
@@ -96,6 +206,18 @@ impl App {
             test_connection_events_rx,
             test_connection_events_tx,
             test_connection_task_handle: None,
+            object_list_events_rx,
+            object_list_events_tx,
+            object_list_task_handle: None,
+            object_list_generation: 0,
+            csv_import_task: None,
+            query_task: None,
+            profile_task: None,
+            backup_task: None,
+            watch_tasks: Vec::new(),
+            lock_task: None,
+            backend_signal_task: None,
+            needs_full_redraw: false,
         })
     }
 
@@ -111,6 +233,9 @@ impl App {
 
         while !self.should_quit {
             // Draw UI
+            if std::mem::take(&mut self.needs_full_redraw) {
+                terminal.clear()?;
+            }
             terminal.draw(|frame| self.draw(frame))?;
 
             // Handle events
@@ -119,9 +244,32 @@ impl App {
             }
         }
 
+        self.save_layout();
         Ok(())
     }
 
+    /// Write resized pane proportions back to the config file's `[layout]` section
+    pub(crate) fn save_layout(&mut self) {
+        let layout = self.state.layout.to_config();
+        if layout == self.config.layout {
+            return;
+        }
+        self.config.layout = layout;
+        self.save_config("layout");
+    }
+
+    /// Write the config back to the file it was loaded from
+    fn save_config(&self, what: &str) {
+        let path = self
+            .config
+            .source_path
+            .clone()
+            .unwrap_or_else(Config::default_path);
+        if let Err(e) = self.config.save(&path) {
+            tracing::warn!("Failed to save {} to {}: {}", what, path.display(), e);
+        }
+    }
+
     /// Draw the user interface
     fn draw(&mut self, frame: &mut Frame) {
         self.ui.draw(frame, &mut self.state);
@@ -134,25 +282,101 @@ impl App {
             Event::Mouse(_) => {
                 // Mouse events will be handled in future
             }
-            Event::Resize(_, _) => {
-                // Terminal resize is handled automatically by ratatui
-            }
+            Event::Resize(width, height) => self.state.handle_resize(width, height),
             Event::Tick => {
                 // Handle periodic updates
                 self.tick().await?;
             }
+            Event::ThemeReload(path) => self.reload_theme(&path),
         }
         Ok(())
     }
 
+    /// Swap in the edited theme file and repaint everything with it
+    fn reload_theme(&mut self, path: &std::path::Path) {
+        match crate::ui::theme::Theme::load_from_file(path) {
+            Ok(theme) => {
+                self.ui.theme = theme;
+                self.needs_full_redraw = true;
+                self.state
+                    .toast_manager
+                    .success(format!("Theme reloaded from {}", path.display()));
+            }
+            Err(e) => {
+                self.state.toast_manager.error(format!(
+                    "Failed to reload theme {}: {}",
+                    path.display(),
+                    e
+                ));
+            }
+        }
+    }
+
+    /// Draw with `theme` without saving it, e.g. while previewing in the gallery
+    pub(crate) fn preview_theme(&mut self, theme: Theme) {
+        self.ui.theme = theme;
+        self.needs_full_redraw = true;
+    }
+
+    /// Switch to `theme`, watch its file for edits and save it to `[theme]`
+    pub(crate) fn apply_theme(&mut self, theme: Theme, path: Option<PathBuf>) {
+        let name = theme.name.clone();
+        self.preview_theme(theme);
+        self.ui.theme_path = path.clone();
+        match path {
+            Some(path) => {
+                if let Err(e) = self.event_handler.watch_theme(path) {
+                    tracing::warn!("Theme hot-reload disabled: {}", e);
+                }
+            }
+            None => self.event_handler.unwatch_theme(),
+        }
+
+        if self.config.theme.name != name {
+            self.config.theme.name = name.clone();
+            self.save_config("theme");
+        }
+        self.state.toast_manager.success(format!("Theme: {name}"));
+    }
+
+    /// `:theme <name>`: built-in or installed theme, ignoring case
+    fn set_theme(&mut self, name: &str) {
+        match ThemeLoader::find_theme(name) {
+            Some((theme, path)) => self.apply_theme(theme, path),
+            None => self.state.toast_manager.error(format!(
+                "Theme '{name}' not found (:theme list shows the available ones)"
+            )),
+        }
+    }
+
+    /// `:theme list`: gallery over every theme, starting on the active one
+    fn open_theme_gallery(&mut self) {
+        let active = (self.ui.theme.clone(), self.ui.theme_path.clone());
+        self.state.theme_gallery = Some(ThemeGallery::new(ThemeLoader::all_themes(), active));
+    }
+
     /// Execute a command by ID
-    fn execute_command(&mut self, command_id: CommandId) -> Result<()> {
+    async fn execute_command(&mut self, command_id: CommandId) -> Result<()> {
+        self.execute_command_with_args(command_id, Vec::new()).await
+    }
+
+    /// Execute a command with arguments typed on the `:` command line
+    async fn execute_command_with_args(
+        &mut self,
+        command_id: CommandId,
+        args: Vec<String>,
+    ) -> Result<()> {
         let mut context = CommandContext {
             state: &mut self.state,
             config: &self.config,
+            args,
         };
 
-        match self.command_registry.execute(command_id, &mut context)? {
+        match self
+            .command_registry
+            .execute(command_id, &mut context)
+            .await?
+        {
             CommandResult::Success => {}
             CommandResult::SuccessWithMessage(msg) => {
                 self.state.toast_manager.success(&msg);
@@ -165,15 +389,30 @@ impl App {
             }
             CommandResult::Cancelled => {}
             CommandResult::Action(action) => {
-                self.handle_command_action(action)?;
+                self.handle_command_action(action).await?;
             }
         }
 
         Ok(())
     }
 
+    /// Open the `Ctrl+P` palette over every registered command
+    pub(crate) fn open_command_palette(&mut self) {
+        let entries = self
+            .command_registry
+            .commands()
+            .map(|command| PaletteEntry {
+                id: command.id(),
+                description: command.description().to_string(),
+                category: command.category(),
+                shortcut: command.shortcut(),
+            })
+            .collect();
+        self.state.command_palette = Some(CommandPalette::new(entries));
+    }
+
     /// Handle command actions
-    fn handle_command_action(&mut self, action: CommandAction) -> Result<()> {
+    async fn handle_command_action(&mut self, action: CommandAction) -> Result<()> {
         match action {
             CommandAction::Quit => {
                 self.should_quit = true;
@@ -182,7 +421,7 @@ impl App {
                 use crate::commands::ModalType;
                 match modal_type {
                     ModalType::Help => {
-                        self.execute_command(CommandId::Help)?;
+                        Box::pin(self.execute_command(CommandId::Help)).await?;
                     }
                     ModalType::Connection => {
                         // Handled by overlay system;
@@ -198,7 +437,7 @@ impl App {
             }
             CommandAction::ExecuteQuery(query) => {
                 // Note: Query execution is handled directly by key handlers (handle_query_editor_keys)
-                // via execute_query_at_cursor(). This action is kept for command system compatibility
+                // via start_query_at_cursor(). This action is kept for command system compatibility
                 // but actual execution happens in the async key handler.
                 self.state.toast_manager.info(format!(
                     "Query submitted: {}",
@@ -217,7 +456,7 @@ impl App {
                 connection_name,
             } => {
                 // Note: Query execution is handled directly by key handlers (handle_query_editor_keys)
-                // via execute_query_at_cursor(). This action is kept for command system compatibility
+                // via start_query_at_cursor(). This action is kept for command system compatibility
                 // but actual execution happens in the async key handler.
                 self.state.toast_manager.info(format!(
                     "Query submitted to {} ({})",
@@ -241,14 +480,118 @@ impl App {
                     self.state.ui.focused_pane = pane;
                 }
             }
+            CommandAction::RefreshObjectList => {
+                self.refresh_object_list();
+            }
+            CommandAction::SetTheme(name) => self.set_theme(&name),
+            CommandAction::OpenThemeGallery => self.open_theme_gallery(),
         }
         Ok(())
     }
 
-    /// Handle application keyboard events
+    /// Handle application keyboard events. The leader key waits for the next
+    /// key: `+` or `-` resize the focused pane, anything else gets the leader
+    /// press handed to the pane first.
     async fn handle_key_event(&mut self, key: KeyEvent) -> Result<()> {
+        if let Some(unused) = self.state.hotkeys.take_leader() {
+            if handlers::global::handle_leader_key(self, key) {
+                return Ok(());
+            }
+            if unused {
+                let leader = self.state.hotkeys.leader_event();
+                self.dispatch_key_event(leader).await?;
+            }
+        }
+        if handlers::global::arms_leader(self, key) {
+            self.state.hotkeys.arm_leader();
+            return Ok(());
+        }
+        self.dispatch_key_event(key).await
+    }
+
+    /// Route a key to whatever has it: overlays first, then global keys, then the focused pane
+    async fn dispatch_key_event(&mut self, key: KeyEvent) -> Result<()> {
+        // 0. The unlock prompt takes every key, above the form waiting on it
+        if self.state.unlock_prompt.is_some() {
+            handlers::connections::handle_unlock_prompt(self, key).await;
+            return Ok(());
+        }
+
+        // 0'. The Tables pane context menu takes every key while open
+        if self.state.table_context_menu.is_some() {
+            return handlers::tables::handle_context_menu(self, key).await;
+        }
+
+        // 0a. The command palette takes every key while open
+        if self.state.command_palette.is_some() {
+            return handlers::global::handle_command_palette(self, key).await;
+        }
+
+        // 0a'. So does the theme gallery, previewing as the cursor moves
+        if self.state.theme_gallery.is_some() {
+            handlers::global::handle_theme_gallery(self, key);
+            return Ok(());
+        }
+
+        // 0a''. And the query editor's snippet picker
+        if self.state.snippet_picker.is_some() {
+            handlers::query_editor::handle_snippet_picker(self, key);
+            return Ok(());
+        }
+
+        // 0a'''. And the Ctrl+T quick switcher, which may open a table
+        if self.state.quick_switcher.is_some() {
+            return handlers::global::handle_quick_switcher(self, key).await;
+        }
+
+        // 0a''''. And the `:locks` monitor
+        if self.state.lock_monitor.is_some() {
+            handlers::global::handle_lock_monitor(self, key);
+            return Ok(());
+        }
+
+        // 0a'''''. And the `'` bookmarks, which may connect and open a table
+        if self.state.bookmark_list.is_some() {
+            return handlers::global::handle_bookmark_list(self, key).await;
+        }
+
+        // 0a''''''. And the `A` connection templates, which open the add form
+        if self.state.connection_template_list.is_some() {
+            handlers::connections::handle_template_list(self, key);
+            return Ok(());
+        }
+
+        // 0b. Resize mode (`Ctrl+w`) takes every key until it is left
+        if self.state.ui.resize_mode {
+            handlers::global::handle_resize_mode(self, key);
+            return Ok(());
+        }
+
+        // 0c. Ctrl+T jumps to a table from any pane
+        if key.modifiers == KeyModifiers::CONTROL
+            && key.code == KeyCode::Char('t')
+            && self.state.ui.is_in_main()
+            && self.state.ui.confirmation_modal.is_none()
+            && self.state.connection_import.is_none()
+            && self.state.csv_import.is_none()
+            && self.state.index_modal.is_none()
+        {
+            self.state.open_quick_switcher().await;
+            return Ok(());
+        }
+
+        // 0d. Ctrl+M shows MySQL server status and variables from any pane
+        if key.modifiers == KeyModifiers::CONTROL
+            && key.code == KeyCode::Char('m')
+            && self.state.ui.is_in_main()
+            && self.state.ui.confirmation_modal.is_none()
+        {
+            self.state.open_server_status().await;
+            return Ok(());
+        }
+
         // 1. Handle global keys first (work everywhere)
-        if handlers::global::handle(self, key)?.is_some() {
+        if handlers::global::handle(self, key).await?.is_some() {
             return Ok(());
         }
 
@@ -262,11 +605,50 @@ impl App {
             return handlers::overlays::handle_confirmation_modal(self, key).await;
         }
 
+        // 3a. And the table name typed to confirm a truncation or drop
+        if self.state.name_confirmation.is_some() {
+            return handlers::overlays::handle_name_confirmation(self, key).await;
+        }
+
+        // 3b. And the new name of a table being renamed
+        if self.state.rename_prompt.is_some() {
+            return handlers::overlays::handle_rename_prompt(self, key).await;
+        }
+
         // 4. Handle table viewer delete confirmation
         if self.state.table_viewer_state.delete_confirmation.is_some() {
             return handlers::overlays::handle_table_delete_confirmation(self, key).await;
         }
 
+        // 4a. Handle the hex dump over a binary cell
+        if self.state.table_viewer_state.hex_view.is_some() {
+            handlers::overlays::handle_hex_view(self, key);
+            return Ok(());
+        }
+
+        // 4a'. Handle the pretty-printed JSON of a cell
+        if self.state.table_viewer_state.json_view.is_some() {
+            handlers::overlays::handle_json_view(self, key);
+            return Ok(());
+        }
+
+        // 4a''. Handle the full value of a cell
+        if self.state.table_viewer_state.cell_view.is_some() {
+            handlers::overlays::handle_cell_view(self, key);
+            return Ok(());
+        }
+
+        // 4a''''. Handle the row copy format menu
+        if self.state.table_viewer_state.copy_format_menu.is_some() {
+            handlers::overlays::handle_copy_format_menu(self, key);
+            return Ok(());
+        }
+
+        // 4a'''. Handle the array or JSON cell editor
+        if self.state.table_viewer_state.cell_editor.is_some() {
+            return handlers::overlays::handle_cell_editor(self, key).await;
+        }
+
         // 4b. Handle table viewer set NULL confirmation
         if self
             .state
@@ -277,27 +659,850 @@ impl App {
             return handlers::overlays::handle_set_null_confirmation(self, key).await;
         }
 
-        // 5. Route to focused pane handler (main view)
+        // 4c. Handle the system connection import checklist
+        if self.state.connection_import.is_some() {
+            return handlers::overlays::handle_connection_import(self, key).await;
+        }
+
+        // 4d. Handle the CSV import modal
+        if self.state.csv_import.is_some() {
+            return handlers::overlays::handle_csv_import(self, key).await;
+        }
+
+        // 4e. Handle the create-index modal
+        if self.state.index_modal.is_some() {
+            return handlers::overlays::handle_index_modal(self, key).await;
+        }
+
+        // 5. Ctrl+C, or Esc outside editing modes, cancels a running query
+        if self.state.running_query.is_some() && self.is_query_cancel_key(key) {
+            self.cancel_running_query();
+            return Ok(());
+        }
+
+        // 5a. Esc in the Tables pane stops a running backup
+        if key.code == crossterm::event::KeyCode::Esc
+            && self.state.ui.focused_pane == FocusedPane::Tables
+            && !self.state.ui.tables_search_active
+        {
+            if let Some(backup) = self.state.backup.as_ref().filter(|b| !b.is_cancelled()) {
+                backup.cancel();
+                self.state.toast_manager.warning("Cancelling backup...");
+                return Ok(());
+            }
+        }
+
+        // 6. Route to focused pane handler (main view)
         match self.state.ui.focused_pane {
             FocusedPane::Connections => handlers::connections::handle(self, key).await,
             FocusedPane::Tables => handlers::tables::handle(self, key).await,
-            FocusedPane::Details => handlers::details::handle(self, key),
+            FocusedPane::Routines => handlers::routines::handle(self, key).await,
+            FocusedPane::Details => handlers::details::handle(self, key).await,
             FocusedPane::TabularOutput => handlers::query_results::handle(self, key).await,
             FocusedPane::SqlFiles => handlers::sql_files::handle(self, key).await,
             FocusedPane::QueryWindow => handlers::query_editor::handle(self, key).await,
         }
     }
 
+    /// Whether a key cancels the running query in the query or results pane
+    fn is_query_cancel_key(&self, key: KeyEvent) -> bool {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let is_ctrl_c =
+            key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c');
+        match self.state.ui.focused_pane {
+            FocusedPane::QueryWindow => {
+                let editor = &self.state.query_editor;
+                is_ctrl_c
+                    || (key.code == KeyCode::Esc
+                        && !editor.is_insert_mode()
+                        && !editor.is_in_command_mode()
+                        && editor.visual_mode().is_none())
+            }
+            FocusedPane::TabularOutput => {
+                let editing = self
+                    .state
+                    .table_viewer_state
+                    .current_tab()
+                    .is_some_and(|tab| {
+                        tab.in_edit_mode || tab.in_search_mode || tab.watch_prompt.is_some()
+                    });
+                is_ctrl_c || (key.code == KeyCode::Esc && !editing)
+            }
+            _ => false,
+        }
+    }
+
+    /// Start streaming the object list for a connection, replacing any listing in flight.
+    /// The selected table is restored by name once it shows up again; on a
+    /// `refresh` the cursor otherwise stays on the same row.
+    fn start_object_listing(&mut self, connection_id: String, refresh: bool) {
+        use crate::database::objects::OBJECT_LIST_BATCH_SIZE;
+
+        if let Some(handle) = self.object_list_task_handle.take() {
+            handle.abort();
+        }
+        self.object_list_generation += 1;
+        let generation = self.object_list_generation;
+
+        let restore_selection = self.state.ui.get_selected_table_name();
+        let restore_index = refresh.then_some(self.state.ui.selected_table_item_index);
+        self.state.db.database_objects = Some(crate::database::DatabaseObjectList::default());
+        self.state.db.tables.clear();
+        self.state.db.table_load_error = None;
+        self.state.db.object_listing = Some(crate::state::ObjectListing {
+            connection_id: connection_id.clone(),
+            generation,
+            loaded: 0,
+            restore_selection,
+            restore_index,
+        });
+        self.state.update_table_selection();
+
+        let connection_manager = self.state.connection_manager.clone();
+        let tx = self.object_list_events_tx.clone();
+        self.object_list_task_handle = Some(tokio::spawn(async move {
+            let mut offset = 0;
+            loop {
+                match connection_manager
+                    .list_database_objects_page(&connection_id, OBJECT_LIST_BATCH_SIZE, offset)
+                    .await
+                {
+                    Ok(objects) => {
+                        let fetched = objects.total_count;
+                        let failed = objects.error.is_some();
+                        if tx
+                            .send(ObjectListEvent::Batch {
+                                generation,
+                                objects,
+                            })
+                            .is_err()
+                            || failed
+                            || fetched < OBJECT_LIST_BATCH_SIZE
+                        {
+                            break;
+                        }
+                        offset += fetched;
+                    }
+                    Err(e) => {
+                        let _ = tx.send(ObjectListEvent::Failed {
+                            generation,
+                            error: e.to_string(),
+                        });
+                        return;
+                    }
+                }
+            }
+            let _ = tx.send(ObjectListEvent::Done { generation });
+        }));
+    }
+
+    /// Re-list objects for the connected database through the streaming path
+    fn refresh_object_list(&mut self) {
+        let connection_id = self
+            .state
+            .db
+            .connections
+            .connections
+            .iter()
+            .find(|c| c.is_connected())
+            .map(|c| c.id.clone());
+
+        match connection_id {
+            Some(connection_id) => {
+                self.start_object_listing(connection_id, true);
+                self.state.toast_manager.info("Refreshing tables...");
+            }
+            None => self
+                .state
+                .toast_manager
+                .warning("Connect to a database to refresh tables"),
+        }
+    }
+
+    /// Open a bookmarked table or SQL file. A bookmark on another connection,
+    /// or one not connected yet, connects first and opens from `tick`.
+    pub(crate) async fn open_bookmark(&mut self, bookmark: crate::state::Bookmark) {
+        let Some(index) = self
+            .state
+            .db
+            .connections
+            .connections
+            .iter()
+            .position(|c| c.id == bookmark.connection_id)
+        else {
+            self.state.toast_manager.warning(format!(
+                "Connection '{}' no longer exists",
+                bookmark.connection_name
+            ));
+            return;
+        };
+
+        let ready = index == self.state.ui.selected_connection
+            && self.state.db.connections.connections[index].is_connected();
+        self.state.ui.selected_connection = index;
+        self.state.ui.connections_list_state.select(Some(index));
+
+        if ready && self.state.db.object_listing.is_none() {
+            self.state.open_bookmark_now(bookmark).await;
+            return;
+        }
+        self.state.pending_bookmark = Some(bookmark);
+        if !ready {
+            handlers::connections::connect_selected(self);
+        }
+    }
+
+    /// Select the saved connection with this name or id and connect to it,
+    /// for `--connection` on launch; the Tables pane fills in once it's up,
+    /// then `table` opens
+    pub fn connect_on_startup(&mut self, name: &str, table: Option<&str>) {
+        let Some(index) = self
+            .state
+            .db
+            .connections
+            .connections
+            .iter()
+            .position(|c| c.name == name || c.id == name)
+        else {
+            self.state
+                .toast_manager
+                .error(format!("Connection '{name}' not found"));
+            return;
+        };
+        self.state.ui.selected_connection = index;
+        self.state.ui.connections_list_state.select(Some(index));
+        if let Some(table) = table {
+            // Opened like a bookmark once the connection has listed its tables
+            let connection = &self.state.db.connections.connections[index];
+            self.state.pending_bookmark = Some(crate::state::Bookmark {
+                kind: crate::state::BookmarkKind::Table,
+                name: table.to_string(),
+                connection_id: connection.id.clone(),
+                connection_name: connection.name.clone(),
+                label: String::new(),
+            });
+        }
+        handlers::connections::connect_selected(self);
+    }
+
+    /// Open the pending bookmark once its connection is up and, for a table,
+    /// the Tables pane has finished listing
+    async fn poll_pending_bookmark(&mut self) {
+        use crate::database::ConnectionStatus;
+
+        let Some(bookmark) = &self.state.pending_bookmark else {
+            return;
+        };
+        let status = self
+            .state
+            .get_selected_connection()
+            .filter(|connection| connection.id == bookmark.connection_id)
+            .map(|connection| connection.status.clone());
+        let listed = bookmark.kind == crate::state::BookmarkKind::SqlFile
+            || self.state.db.object_listing.is_none();
+
+        match status {
+            Some(ConnectionStatus::Connecting) => {}
+            // Connecting waits for the session key
+            Some(_) if self.state.unlock_prompt.is_some() => {}
+            Some(ConnectionStatus::Connected) if !listed => {}
+            Some(ConnectionStatus::Connected) => {
+                if let Some(bookmark) = self.state.pending_bookmark.take() {
+                    self.state.open_bookmark_now(bookmark).await;
+                }
+            }
+            // Another connection was selected, or connecting failed and said so
+            _ => self.state.pending_bookmark = None,
+        }
+    }
+
+    /// Drain object list events; abort the listing task once it was cancelled
+    fn poll_object_listing(&mut self) {
+        if self.state.db.object_listing.is_none() {
+            if let Some(handle) = self.object_list_task_handle.take() {
+                handle.abort();
+            }
+        }
+
+        while let Ok(event) = self.object_list_events_rx.try_recv() {
+            let current = self
+                .state
+                .db
+                .object_listing
+                .as_ref()
+                .map(|listing| listing.generation);
+
+            match event {
+                ObjectListEvent::Batch {
+                    generation,
+                    objects,
+                } if Some(generation) == current => {
+                    self.state.apply_object_batch(objects);
+                }
+                ObjectListEvent::Done { generation } if Some(generation) == current => {
+                    self.state.cancel_object_listing();
+                    self.object_list_task_handle = None;
+                }
+                ObjectListEvent::Failed { generation, error } if Some(generation) == current => {
+                    self.state.cancel_object_listing();
+                    self.object_list_task_handle = None;
+                    self.state.db.table_load_error = Some(error.clone());
+                    self.state
+                        .toast_manager
+                        .error(format!("Failed to load database objects: {error}"));
+                }
+                // Batches from a cancelled or replaced listing
+                _ => {}
+            }
+        }
+    }
+
+    /// Run the statement at the editor cursor in the background so the
+    /// status bar timer keeps ticking; `tick` picks up the result
+    pub(crate) fn start_query_at_cursor(&mut self) {
+        let Ok((connection_id, query)) = self.state.start_query_at_cursor() else {
+            return;
+        };
+
+        let connection_manager = self.state.connection_manager.clone();
+        self.query_task = Some(tokio::spawn(async move {
+            if reports_rows_affected(&query) {
+                connection_manager
+                    .execute_statement(&connection_id, &query)
+                    .await
+                    .map(QueryOutput::Affected)
+            } else {
+                connection_manager
+                    .execute_raw_query(&connection_id, &query)
+                    .await
+                    .map(|(columns, rows)| QueryOutput::Rows(columns, rows))
+            }
+        }));
+    }
+
+    /// Run every statement of `scope` in turn on the selected connection and
+    /// list the outcomes in a summary tab. Stops at the first failure unless
+    /// `[query] continue_on_error` is set.
+    pub(crate) fn start_batch(&mut self, scope: ScriptScope) {
+        let editor = &mut self.state.query_editor;
+        let (script, first_line) = match scope {
+            ScriptScope::All => (editor.get_content().to_string(), 0),
+            ScriptScope::Selection => {
+                let selection = editor.selected_text().zip(editor.selection_start_line());
+                editor.exit_visual_mode();
+                match selection {
+                    Some(selection) => selection,
+                    None => return,
+                }
+            }
+            ScriptScope::Paragraph => match editor.paragraph_at_cursor() {
+                Some(paragraph) => paragraph,
+                None => {
+                    self.state
+                        .toast_manager
+                        .warning("No paragraph at the cursor");
+                    return;
+                }
+            },
+        };
+
+        let separator = self.config.query.statement_separator.clone();
+        let Ok((connection_id, statements)) =
+            self.state.start_batch(&script, first_line, &separator)
+        else {
+            return;
+        };
+
+        let continue_on_error = self.config.query.continue_on_error;
+        let connection_manager = self.state.connection_manager.clone();
+        self.query_task = Some(tokio::spawn(async move {
+            let mut results = Vec::with_capacity(statements.len());
+            let mut stopped = false;
+            for statement in statements {
+                let started = std::time::Instant::now();
+                let status = if stopped {
+                    StatementStatus::Skipped
+                } else if reports_rows_affected(&statement.text) {
+                    match connection_manager
+                        .execute_statement(&connection_id, &statement.text)
+                        .await
+                    {
+                        Ok(count) => StatementStatus::Affected(count),
+                        Err(e) => StatementStatus::Failed(e.to_string()),
+                    }
+                } else {
+                    match connection_manager
+                        .execute_raw_query(&connection_id, &statement.text)
+                        .await
+                    {
+                        Ok((_, rows)) => StatementStatus::Rows(rows.len() as u64),
+                        Err(e) => StatementStatus::Failed(e.to_string()),
+                    }
+                };
+                stopped |= matches!(status, StatementStatus::Failed(_)) && !continue_on_error;
+                results.push(StatementResult {
+                    line: statement.line,
+                    statement: statement.text,
+                    status,
+                    elapsed: started.elapsed(),
+                });
+            }
+            Ok(QueryOutput::Batch(results))
+        }));
+    }
+
+    /// Cancel the query started from the editor. PostgreSQL and MySQL stop
+    /// it server-side so the connection stays usable; otherwise, or when the
+    /// server cancel fails, the task is dropped.
+    pub(crate) fn cancel_running_query(&mut self) {
+        let Some(running) = self.state.running_query.as_mut() else {
+            return;
+        };
+        let Some(handle) = self.query_task.as_ref() else {
+            return;
+        };
+        if running.cancelling {
+            return;
+        }
+        running.cancelling = true;
+        self.state.toast_manager.info("Cancelling query…");
+
+        let connection_id = running.connection_id.clone();
+        let abort = handle.abort_handle();
+        let connection_manager = self.state.connection_manager.clone();
+        tokio::spawn(async move {
+            if !matches!(
+                connection_manager
+                    .cancel_running_query(&connection_id)
+                    .await,
+                Ok(true)
+            ) {
+                abort.abort();
+            }
+        });
+    }
+
+    /// Fetch column statistics for the table shown in the details pane
+    pub(crate) fn start_column_profile(&mut self) {
+        if self.profile_task.is_some() {
+            self.state
+                .toast_manager
+                .warning("Profiling already in progress");
+            return;
+        }
+        let Some(metadata) = self.state.db.current_table_metadata.clone() else {
+            self.state
+                .toast_manager
+                .warning("Load table metadata before profiling");
+            return;
+        };
+        let Some(connection) = self
+            .state
+            .db
+            .connections
+            .connections
+            .get(self.state.ui.selected_connection)
+            .filter(|connection| connection.is_connected())
+            .cloned()
+        else {
+            self.state
+                .toast_manager
+                .error("No active database connection");
+            return;
+        };
+
+        self.state
+            .toast_manager
+            .info(format!("Profiling {}...", metadata.table_name));
+        let connection_manager = self.state.connection_manager.clone();
+        self.profile_task = Some(tokio::spawn(async move {
+            crate::database::profile::profile_table(
+                &connection_manager,
+                &connection.id,
+                &connection.database_type,
+                &metadata,
+            )
+            .await
+        }));
+    }
+
+    /// Dump the table selected in the Tables pane to the backups directory
+    pub(crate) fn start_table_backup(&mut self) {
+        let Some(table_name) = self.state.ui.get_selected_table_name() else {
+            self.state.toast_manager.info("Select a table to back up");
+            return;
+        };
+        self.start_backup(table_name.clone(), table_name.clone(), vec![table_name]);
+    }
+
+    /// Dump every table in the schema of the selected table (or all listed
+    /// tables when nothing is selected) into one file
+    pub(crate) fn start_schema_backup(&mut self) {
+        let schema = self
+            .state
+            .ui
+            .get_selected_table_item()
+            .and_then(|item| item.schema.clone());
+        let tables: Vec<String> = self
+            .state
+            .db
+            .database_objects
+            .as_ref()
+            .map(|objects| {
+                objects
+                    .tables
+                    .iter()
+                    .filter(|table| !table.is_system())
+                    .filter(|table| schema.is_none() || table.schema == schema)
+                    .map(|table| table.qualified_name())
+                    .collect()
+            })
+            .unwrap_or_default();
+        if tables.is_empty() {
+            self.state.toast_manager.warning("No tables to back up");
+            return;
+        }
+
+        let name = schema.unwrap_or_else(|| "all_tables".to_string());
+        self.start_backup(format!("schema {name}"), name, tables);
+    }
+
+    fn start_backup(&mut self, label: String, file_name: String, tables: Vec<String>) {
+        if self.backup_task.is_some() {
+            self.state
+                .toast_manager
+                .warning("A backup is already running");
+            return;
+        }
+        let Some(connection) = self
+            .state
+            .db
+            .connections
+            .connections
+            .get(self.state.ui.selected_connection)
+            .filter(|connection| connection.is_connected())
+            .cloned()
+        else {
+            self.state
+                .toast_manager
+                .error("No active database connection");
+            return;
+        };
+
+        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S").to_string();
+        let path = crate::database::backup::backup_file_path(
+            &Config::backups_dir(),
+            &connection.name,
+            &file_name,
+            &timestamp,
+        );
+        let progress = crate::database::backup::BackupProgress::new(label, path, tables.len());
+        self.state.toast_manager.info(format!(
+            "Backing up {} (Esc in Tables to cancel)...",
+            progress.label
+        ));
+
+        let connection_manager = self.state.connection_manager.clone();
+        let task_progress = progress.clone();
+        self.state.backup = Some(progress);
+        self.backup_task = Some(tokio::spawn(async move {
+            crate::database::backup::dump_tables(
+                &connection_manager,
+                &connection.id,
+                &connection.database_type,
+                &tables,
+                &task_progress.path,
+                &task_progress,
+            )
+            .await
+        }));
+    }
+
+    /// Apply finished refreshes of watched tabs and start the ones that are
+    /// due. A refresh still running when the next one is due is not doubled
+    /// up; watches pause while a modal is open or their tab is being edited.
+    async fn poll_tab_watches(&mut self) {
+        let (finished, running): (Vec<_>, Vec<_>) = std::mem::take(&mut self.watch_tasks)
+            .into_iter()
+            .partition(|(_, handle)| handle.is_finished());
+        self.watch_tasks = running;
+        for (watch_id, handle) in finished {
+            let output = handle
+                .await
+                .unwrap_or_else(|e| Err(crate::core::error::LazyTablesError::Other(e.to_string())));
+            match output {
+                Ok((columns, rows)) => {
+                    self.state.apply_watch_rows(watch_id, &columns, rows);
+                    self.state.finish_watch_refresh(watch_id, Ok(()));
+                }
+                Err(e) => self
+                    .state
+                    .finish_watch_refresh(watch_id, Err(e.to_string())),
+            }
+        }
+
+        if self.state.is_modal_open() {
+            return;
+        }
+        let now = std::time::Instant::now();
+        let due: Vec<(usize, u64, WatchSource)> = self
+            .state
+            .table_viewer_state
+            .tabs
+            .iter()
+            .enumerate()
+            .filter(|(_, tab)| !tab.in_edit_mode && tab.watch_prompt.is_none())
+            .filter_map(|(idx, tab)| {
+                let watch = tab.watch.as_ref().filter(|watch| watch.is_due(now))?;
+                Some((idx, watch.id, watch.source.clone()))
+            })
+            .collect();
+
+        for (idx, watch_id, source) in due {
+            if let Some(watch) = self.state.table_viewer_state.tabs[idx].watch.as_mut() {
+                watch.begin(now);
+            }
+            match source {
+                WatchSource::Table => {
+                    let outcome = self.state.refresh_watched_table(idx).await;
+                    self.state.finish_watch_refresh(watch_id, outcome);
+                }
+                WatchSource::Query(origin) => {
+                    let connection_manager = self.state.connection_manager.clone();
+                    self.watch_tasks.push((
+                        watch_id,
+                        tokio::spawn(async move {
+                            connection_manager
+                                .execute_raw_query(&origin.connection_id, &origin.query)
+                                .await
+                        }),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Reload the `:locks` monitor when due and report finished backend signals
+    async fn poll_lock_monitor(&mut self) {
+        if self
+            .lock_task
+            .as_ref()
+            .is_some_and(|handle| handle.is_finished())
+        {
+            if let Some(handle) = self.lock_task.take() {
+                let output = handle.await.unwrap_or_else(|e| {
+                    Err(crate::core::error::LazyTablesError::Other(e.to_string()))
+                });
+                if let Some(monitor) = self.state.lock_monitor.as_mut() {
+                    monitor.finish(output.map_err(|e| e.to_string()));
+                }
+            }
+        }
+
+        if self
+            .backend_signal_task
+            .as_ref()
+            .is_some_and(|(_, _, handle)| handle.is_finished())
+        {
+            if let Some((signal, pid, handle)) = self.backend_signal_task.take() {
+                let output = handle.await.unwrap_or_else(|e| {
+                    Err(crate::core::error::LazyTablesError::Other(e.to_string()))
+                });
+                let function = signal.function_name();
+                match output {
+                    Ok(true) => self
+                        .state
+                        .toast_manager
+                        .success(format!("{function}({pid}) sent")),
+                    Ok(false) => self
+                        .state
+                        .toast_manager
+                        .warning(format!("{function}({pid}) found no such backend")),
+                    Err(e) => self
+                        .state
+                        .toast_manager
+                        .error(format!("{function}({pid}) failed: {e}")),
+                }
+                if let Some(monitor) = self.state.lock_monitor.as_mut() {
+                    monitor.refresh_now();
+                }
+            }
+        }
+
+        let now = std::time::Instant::now();
+        let Some(monitor) = self
+            .state
+            .lock_monitor
+            .as_mut()
+            .filter(|monitor| monitor.is_due(now))
+        else {
+            return;
+        };
+        monitor.begin(now);
+        let connection_manager = self.state.connection_manager.clone();
+        let connection_id = monitor.connection_id.clone();
+        self.lock_task = Some(tokio::spawn(async move {
+            connection_manager.list_locks(&connection_id).await
+        }));
+    }
+
+    /// Send `pg_cancel_backend` or `pg_terminate_backend` from the `:locks` monitor
+    pub(crate) fn start_backend_signal(&mut self, signal: BackendSignal, pid: i32) {
+        let Some(monitor) = &self.state.lock_monitor else {
+            return;
+        };
+        if self.backend_signal_task.is_some() {
+            self.state
+                .toast_manager
+                .warning("Still waiting for the previous signal");
+            return;
+        }
+        let connection_manager = self.state.connection_manager.clone();
+        let connection_id = monitor.connection_id.clone();
+        self.backend_signal_task = Some((
+            signal,
+            pid,
+            tokio::spawn(async move {
+                connection_manager
+                    .signal_backend(&connection_id, pid, signal)
+                    .await
+            }),
+        ));
+    }
+
     /// Handle periodic updates
     async fn tick(&mut self) -> Result<()> {
         // Increment tick counter
         self.tick_counter = self.tick_counter.wrapping_add(1);
 
+        // A leader nothing followed counts as a plain key press
+        if self.state.hotkeys.leader_timed_out() && self.state.hotkeys.take_leader() == Some(true) {
+            let leader = self.state.hotkeys.leader_event();
+            self.dispatch_key_event(leader).await?;
+        }
+
+        // Apply streamed object list batches
+        self.poll_object_listing();
+
+        // Open a bookmark that was waiting for its connection
+        self.poll_pending_bookmark().await;
+
+        // Auto-refresh watched tabs
+        self.poll_tab_watches().await;
+
+        // Reload the lock monitor
+        self.poll_lock_monitor().await;
+
+        // Show the results of a finished query
+        if self
+            .query_task
+            .as_ref()
+            .is_some_and(|handle| handle.is_finished())
+        {
+            if let Some(handle) = self.query_task.take() {
+                let output = handle.await.unwrap_or_else(|e| {
+                    Err(crate::core::error::LazyTablesError::Other(e.to_string()))
+                });
+                // Failures are already reported by a toast
+                let _ = self.state.finish_query(output);
+            }
+        }
+
+        // Show finished column statistics
+        if self
+            .profile_task
+            .as_ref()
+            .is_some_and(|handle| handle.is_finished())
+        {
+            if let Some(handle) = self.profile_task.take() {
+                match handle.await {
+                    Ok(Ok(profile)) => {
+                        self.state.toast_manager.success(format!(
+                            "Profiled {} columns of {}",
+                            profile.columns.len(),
+                            profile.table_name
+                        ));
+                        self.state.db.column_profile = Some(profile);
+                    }
+                    Ok(Err(e)) => self
+                        .state
+                        .toast_manager
+                        .error(format!("Profiling failed: {e}")),
+                    Err(e) => self
+                        .state
+                        .toast_manager
+                        .error(format!("Profiling failed: {e}")),
+                }
+            }
+        }
+
+        // Report a finished backup
+        if self
+            .backup_task
+            .as_ref()
+            .is_some_and(|handle| handle.is_finished())
+        {
+            if let (Some(handle), Some(backup)) =
+                (self.backup_task.take(), self.state.backup.take())
+            {
+                match handle.await {
+                    Ok(Ok(rows)) => self.state.toast_manager.success(format!(
+                        "Backed up {rows} rows of {} to {}",
+                        backup.label,
+                        backup.path.display()
+                    )),
+                    Ok(Err(e)) => self
+                        .state
+                        .toast_manager
+                        .error(format!("Backup of {} stopped: {e}", backup.label)),
+                    Err(e) => self
+                        .state
+                        .toast_manager
+                        .error(format!("Backup of {} failed: {e}", backup.label)),
+                }
+            }
+        }
+
+        // Report a finished CSV import
+        if self
+            .csv_import_task
+            .as_ref()
+            .is_some_and(|(_, handle)| handle.is_finished())
+        {
+            if let Some((table_name, handle)) = self.csv_import_task.take() {
+                match handle.await {
+                    Ok(Ok(rows)) => self
+                        .state
+                        .toast_manager
+                        .success(format!("Imported {rows} rows into {table_name}")),
+                    Ok(Err(e)) => self
+                        .state
+                        .toast_manager
+                        .error(format!("CSV import into {table_name} failed: {e}")),
+                    Err(e) => self
+                        .state
+                        .toast_manager
+                        .error(format!("CSV import into {table_name} failed: {e}")),
+                }
+            }
+        }
+
+        // Advance the spinner while any connection is connecting (250ms interval)
+        if self
+            .state
+            .db
+            .connections
+            .connections
+            .iter()
+            .any(|conn| matches!(conn.status, crate::database::ConnectionStatus::Connecting))
+        {
+            self.state.connecting_animation_frame =
+                self.state.connecting_animation_frame.wrapping_add(1);
+        }
+
         // Handle ongoing connection attempt
         if let Some(connecting_index) = self.state.connecting_in_progress {
-            // Animate loading dots every tick (250ms interval)
-            self.state.connecting_animation_frame = (self.state.connecting_animation_frame + 1) % 3;
-
             // Check for timeout
             if let Some(start_time) = self.state.connection_start_time {
                 let elapsed = start_time.elapsed().as_secs();
@@ -326,10 +1531,7 @@ impl App {
             // Check for completion events (NON-BLOCKING)
             if let Ok(event) = self.connection_events_rx.try_recv() {
                 match event {
-                    ConnectionEvent::Success {
-                        connection_index,
-                        objects,
-                    } => {
+                    ConnectionEvent::Success { connection_index } => {
                         // Connection succeeded! Update state
                         if let Some(conn) = self
                             .state
@@ -341,25 +1543,13 @@ impl App {
                             conn.status = crate::database::ConnectionStatus::Connected;
                         }
 
-                        // Update database state
-                        self.state.db.database_objects = Some(objects.clone());
-                        self.state.db.tables = objects
-                            .tables
-                            .iter()
-                            .map(|t| {
-                                if t.schema.as_deref() == Some("public") || t.schema.is_none() {
-                                    t.name.clone()
-                                } else {
-                                    t.qualified_name()
-                                }
-                            })
-                            .collect();
-
-                        // Update UI
-                        self.state
-                            .ui
-                            .build_selectable_table_items(&self.state.db.database_objects);
-                        self.state.update_table_selection();
+                        // Stream the object list in the background
+                        if let Some(conn) =
+                            self.state.db.connections.connections.get(connection_index)
+                        {
+                            let connection_id = conn.id.clone();
+                            self.start_object_listing(connection_id, false);
+                        }
 
                         // Show success message
                         if let Some(conn) =
@@ -414,27 +1604,9 @@ impl App {
 
         // Handle ongoing test connection attempt
         if self.state.test_connection_in_progress {
-            // Animate loading dots every tick (250ms interval)
-            self.state.test_animation_frame = (self.state.test_animation_frame + 1) % 3;
-
-            // Check for timeout
-            if let Some(start_time) = self.state.test_start_time {
-                let elapsed = start_time.elapsed().as_secs();
-                if elapsed >= 30 {
-                    // 30 second timeout for test connections
-                    use crate::ui::components::TestConnectionStatus;
-                    self.state.connection_modal_state.test_status =
-                        Some(TestConnectionStatus::Failed(format!(
-                            "Test timeout after {} seconds",
-                            elapsed
-                        )));
-                    self.state.test_connection_in_progress = false;
-                    self.state.test_start_time = None;
-                    self.test_connection_task_handle = None;
-                    self.state.toast_manager.error("Test connection timeout");
-                    return Ok(());
-                }
-            }
+            // Advance the countdown spinner every tick (250ms interval)
+            self.state.test_animation_frame = (self.state.test_animation_frame + 1)
+                % crate::ui::components::SPINNER_FRAMES.len() as u8;
 
             // Check for test completion events (NON-BLOCKING)
             if let Ok(event) = self.test_connection_events_rx.try_recv() {
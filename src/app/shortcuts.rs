@@ -0,0 +1,1112 @@
+// FilePath: src/app/shortcuts.rs
+
+#![forbid(unsafe_code)]
+
+use crate::config::{BindingSpec, KeybindingsConfig};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How long the first key of a two-key sequence stays armed
+const SEQUENCE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Pane an action belongs to; bindings only collide within the same context
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionContext {
+    Connections,
+    Tables,
+    Routines,
+    TableViewer,
+    SqlFiles,
+    /// Pane focus keys, checked in every pane of the main view
+    Navigation,
+}
+
+/// Pane-local actions that can be rebound from `[keybindings.actions]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    // Connections pane
+    AddConnection,
+    EditConnection,
+    DeleteConnection,
+    Connect,
+    Disconnect,
+    RefreshConnections,
+    SearchConnections,
+    ImportConnections,
+    ToggleFavoriteConnection,
+    DuplicateConnection,
+    AddConnectionFromTemplate,
+
+    // Tables pane
+    OpenTable,
+    RefreshTables,
+    SearchTables,
+    ImportCsv,
+    ShowRoutines,
+    BackupTable,
+    BookmarkTable,
+    ToggleTableGroup,
+    CycleTableFilter,
+    TruncateTable,
+    TableActions,
+
+    // Routines pane
+    OpenRoutine,
+    DropRoutine,
+    RefreshRoutines,
+
+    // Table viewer
+    EditCell,
+    DeleteRow,
+    SetCellNull,
+    CopyRow,
+    CopyRowAs,
+    CopyCell,
+    CopyPrimaryKey,
+    InspectBinary,
+    ViewJson,
+    ViewCell,
+    SearchRows,
+    SortByColumn,
+    ToggleTableView,
+    ToggleVerticalView,
+    ToggleWatch,
+    RefreshTableData,
+    PreviousTab,
+    NextTab,
+    CloseTab,
+    PinResults,
+    DiffResults,
+    FirstColumn,
+    LastColumn,
+    FreezeColumns,
+    FreezeMoreColumns,
+    FreezeFewerColumns,
+    ToggleRowNumbers,
+    AutoFitColumns,
+    WidenColumn,
+    NarrowColumn,
+    UndoChange,
+    RedoChange,
+
+    // SQL files pane
+    LoadSqlFile,
+    NewSqlFile,
+    RenameSqlFile,
+    DeleteSqlFile,
+    SearchSqlFiles,
+    BookmarkSqlFile,
+
+    // Pane focus (main view)
+    FocusLeft,
+    FocusDown,
+    FocusUp,
+    FocusRight,
+    NextPane,
+    PreviousPane,
+    FocusConnections,
+    FocusTables,
+    FocusDetails,
+    FocusResults,
+    FocusQueryEditor,
+    FocusSqlFiles,
+
+    // Pane sizes (main view)
+    NarrowLeftColumn,
+    WidenLeftColumn,
+    GrowPane,
+    ShrinkPane,
+    ResizeMode,
+}
+
+impl Action {
+    /// All actions, in help display order
+    pub const ALL: [Action; 80] = [
+        Action::AddConnection,
+        Action::EditConnection,
+        Action::DeleteConnection,
+        Action::Connect,
+        Action::Disconnect,
+        Action::RefreshConnections,
+        Action::SearchConnections,
+        Action::ImportConnections,
+        Action::ToggleFavoriteConnection,
+        Action::DuplicateConnection,
+        Action::AddConnectionFromTemplate,
+        Action::OpenTable,
+        Action::RefreshTables,
+        Action::SearchTables,
+        Action::ImportCsv,
+        Action::ShowRoutines,
+        Action::BackupTable,
+        Action::BookmarkTable,
+        Action::ToggleTableGroup,
+        Action::CycleTableFilter,
+        Action::TruncateTable,
+        Action::TableActions,
+        Action::OpenRoutine,
+        Action::DropRoutine,
+        Action::RefreshRoutines,
+        Action::EditCell,
+        Action::DeleteRow,
+        Action::SetCellNull,
+        Action::CopyRow,
+        Action::CopyRowAs,
+        Action::CopyCell,
+        Action::CopyPrimaryKey,
+        Action::InspectBinary,
+        Action::ViewJson,
+        Action::ViewCell,
+        Action::SearchRows,
+        Action::SortByColumn,
+        Action::ToggleTableView,
+        Action::ToggleVerticalView,
+        Action::ToggleWatch,
+        Action::RefreshTableData,
+        Action::PreviousTab,
+        Action::NextTab,
+        Action::CloseTab,
+        Action::PinResults,
+        Action::DiffResults,
+        Action::FirstColumn,
+        Action::LastColumn,
+        Action::FreezeColumns,
+        Action::FreezeMoreColumns,
+        Action::FreezeFewerColumns,
+        Action::ToggleRowNumbers,
+        Action::AutoFitColumns,
+        Action::WidenColumn,
+        Action::NarrowColumn,
+        Action::UndoChange,
+        Action::RedoChange,
+        Action::LoadSqlFile,
+        Action::NewSqlFile,
+        Action::RenameSqlFile,
+        Action::DeleteSqlFile,
+        Action::SearchSqlFiles,
+        Action::BookmarkSqlFile,
+        Action::FocusLeft,
+        Action::FocusDown,
+        Action::FocusUp,
+        Action::FocusRight,
+        Action::NextPane,
+        Action::PreviousPane,
+        Action::FocusConnections,
+        Action::FocusTables,
+        Action::FocusDetails,
+        Action::FocusResults,
+        Action::FocusQueryEditor,
+        Action::FocusSqlFiles,
+        Action::NarrowLeftColumn,
+        Action::WidenLeftColumn,
+        Action::GrowPane,
+        Action::ShrinkPane,
+        Action::ResizeMode,
+    ];
+
+    /// Name used in `[keybindings.actions]`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::AddConnection => "add_connection",
+            Action::EditConnection => "edit_connection",
+            Action::DeleteConnection => "delete_connection",
+            Action::Connect => "connect",
+            Action::Disconnect => "disconnect",
+            Action::RefreshConnections => "refresh_connections",
+            Action::SearchConnections => "search_connections",
+            Action::ImportConnections => "import_connections",
+            Action::ToggleFavoriteConnection => "toggle_favorite_connection",
+            Action::DuplicateConnection => "duplicate_connection",
+            Action::AddConnectionFromTemplate => "add_connection_from_template",
+            Action::OpenTable => "open_table",
+            Action::RefreshTables => "refresh_tables",
+            Action::SearchTables => "search_tables",
+            Action::ImportCsv => "import_csv",
+            Action::ShowRoutines => "show_routines",
+            Action::BackupTable => "backup_table",
+            Action::BookmarkTable => "bookmark_table",
+            Action::ToggleTableGroup => "toggle_table_group",
+            Action::CycleTableFilter => "cycle_table_filter",
+            Action::TruncateTable => "truncate_table",
+            Action::TableActions => "table_actions",
+            Action::OpenRoutine => "open_routine",
+            Action::DropRoutine => "drop_routine",
+            Action::RefreshRoutines => "refresh_routines",
+            Action::EditCell => "edit_cell",
+            Action::DeleteRow => "delete_row",
+            Action::SetCellNull => "set_cell_null",
+            Action::CopyRow => "copy_row",
+            Action::CopyRowAs => "copy_row_as",
+            Action::CopyCell => "copy_cell",
+            Action::CopyPrimaryKey => "copy_primary_key",
+            Action::InspectBinary => "inspect_binary",
+            Action::ViewJson => "view_json",
+            Action::ViewCell => "view_cell",
+            Action::SearchRows => "search_rows",
+            Action::SortByColumn => "sort_by_column",
+            Action::ToggleTableView => "toggle_table_view",
+            Action::ToggleVerticalView => "toggle_vertical_view",
+            Action::ToggleWatch => "toggle_watch",
+            Action::RefreshTableData => "refresh_table_data",
+            Action::PreviousTab => "previous_tab",
+            Action::NextTab => "next_tab",
+            Action::CloseTab => "close_tab",
+            Action::PinResults => "pin_results",
+            Action::DiffResults => "diff_results",
+            Action::FirstColumn => "first_column",
+            Action::LastColumn => "last_column",
+            Action::FreezeColumns => "freeze_columns",
+            Action::FreezeMoreColumns => "freeze_more_columns",
+            Action::FreezeFewerColumns => "freeze_fewer_columns",
+            Action::ToggleRowNumbers => "toggle_row_numbers",
+            Action::AutoFitColumns => "auto_fit_columns",
+            Action::WidenColumn => "widen_column",
+            Action::NarrowColumn => "narrow_column",
+            Action::UndoChange => "undo_change",
+            Action::RedoChange => "redo_change",
+            Action::LoadSqlFile => "load_sql_file",
+            Action::NewSqlFile => "new_sql_file",
+            Action::RenameSqlFile => "rename_sql_file",
+            Action::DeleteSqlFile => "delete_sql_file",
+            Action::SearchSqlFiles => "search_sql_files",
+            Action::BookmarkSqlFile => "bookmark_sql_file",
+            Action::FocusLeft => "focus_left",
+            Action::FocusDown => "focus_down",
+            Action::FocusUp => "focus_up",
+            Action::FocusRight => "focus_right",
+            Action::NextPane => "next_pane",
+            Action::PreviousPane => "previous_pane",
+            Action::FocusConnections => "focus_connections",
+            Action::FocusTables => "focus_tables",
+            Action::FocusDetails => "focus_details",
+            Action::FocusResults => "focus_results",
+            Action::FocusQueryEditor => "focus_query_editor",
+            Action::FocusSqlFiles => "focus_sql_files",
+            Action::NarrowLeftColumn => "narrow_left_column",
+            Action::WidenLeftColumn => "widen_left_column",
+            Action::GrowPane => "grow_pane",
+            Action::ShrinkPane => "shrink_pane",
+            Action::ResizeMode => "resize_mode",
+        }
+    }
+
+    /// Look up an action by its config name
+    pub fn from_name(name: &str) -> Option<Action> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|action| action.name() == name)
+    }
+
+    /// Short description shown in help and sequence hints
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::AddConnection => "Add new connection",
+            Action::EditConnection => "Edit selected connection",
+            Action::DeleteConnection => "Delete connection (with confirmation)",
+            Action::Connect => "Connect to selected database",
+            Action::Disconnect => "Disconnect current connection",
+            Action::RefreshConnections => "Refresh connections list",
+            Action::SearchConnections => "Start search mode",
+            Action::ImportConnections => "Import from pgpass/my.cnf",
+            Action::ToggleFavoriteConnection => "Pin/unpin connection as favorite",
+            Action::DuplicateConnection => "Duplicate connection",
+            Action::AddConnectionFromTemplate => "Add connection from template",
+            Action::OpenTable => "Open table for viewing",
+            Action::RefreshTables => "Refresh tables list",
+            Action::SearchTables => "Start search mode",
+            Action::ImportCsv => "Import CSV (PostgreSQL)",
+            Action::ShowRoutines => "Browse functions/procedures (PostgreSQL)",
+            Action::BackupTable => "Dump table to a SQL backup file",
+            Action::BookmarkTable => "Bookmark table, or remove its bookmark",
+            Action::ToggleTableGroup => "Collapse/expand the group under the cursor",
+            Action::CycleTableFilter => "Cycle all / tables only / views only",
+            Action::TruncateTable => "Truncate the selected table (type its name to confirm)",
+            Action::TableActions => "Action menu for the selected table",
+            Action::OpenRoutine => "Open routine source",
+            Action::DropRoutine => "Drop routine (with confirmation)",
+            Action::RefreshRoutines => "Refresh routines list",
+            Action::EditCell => "Edit current cell",
+            Action::DeleteRow => "Delete current row (with confirmation)",
+            Action::SetCellNull => "Set current cell to NULL",
+            Action::CopyRow => "Copy row data to clipboard (CSV format)",
+            Action::CopyRowAs => "Copy row as CSV, JSON, INSERT or TSV",
+            Action::CopyCell => "Copy current cell to clipboard",
+            Action::CopyPrimaryKey => "Copy current row's primary key as a WHERE fragment",
+            Action::InspectBinary => "Show a hex dump of the current binary cell",
+            Action::ViewJson => "Show the current JSON cell pretty-printed",
+            Action::ViewCell => "Show the full value of the current cell, wrapped",
+            Action::SearchRows => "Search in table data",
+            Action::SortByColumn => "Sort query results by the selected column",
+            Action::ToggleTableView => "Toggle between Data and Schema view",
+            Action::ToggleVerticalView => "Toggle vertical record view (one field per line)",
+            Action::ToggleWatch => "Auto-refresh the tab on an interval (again to stop)",
+            Action::RefreshTableData => "Refresh/reload current table data",
+            Action::PreviousTab => "Switch to previous tab",
+            Action::NextTab => "Switch to next tab",
+            Action::CloseTab => "Close current tab",
+            Action::PinResults => "Pin current results for diffing",
+            Action::DiffResults => "Diff current results against pinned",
+            Action::FirstColumn => "Jump to first column",
+            Action::LastColumn => "Jump to last column",
+            Action::FreezeColumns => "Freeze columns through the cursor, or unfreeze them",
+            Action::FreezeMoreColumns => "Freeze one more column, up to the cursor",
+            Action::FreezeFewerColumns => "Unfreeze the rightmost frozen column",
+            Action::ToggleRowNumbers => "Show or hide absolute row numbers",
+            Action::AutoFitColumns => "Size columns to their content, or back to the default",
+            Action::WidenColumn => "Widen the selected column",
+            Action::NarrowColumn => "Narrow the selected column",
+            Action::UndoChange => "Undo last row delete or cell update",
+            Action::RedoChange => "Redo last undone change",
+            Action::LoadSqlFile => "Load selected file into editor",
+            Action::NewSqlFile => "Create new SQL file",
+            Action::RenameSqlFile => "Rename selected file",
+            Action::DeleteSqlFile => "Delete selected file (with confirmation)",
+            Action::SearchSqlFiles => "Start search mode",
+            Action::BookmarkSqlFile => "Bookmark file, or remove its bookmark",
+            Action::FocusLeft => "Focus pane to the left",
+            Action::FocusDown => "Focus pane below",
+            Action::FocusUp => "Focus pane above",
+            Action::FocusRight => "Focus pane to the right",
+            Action::NextPane => "Next pane",
+            Action::PreviousPane => "Previous pane",
+            Action::FocusConnections => "[1] Connections pane",
+            Action::FocusTables => "[2] Tables pane",
+            Action::FocusDetails => "[3] Table Details pane",
+            Action::FocusResults => "[4] Query Results pane",
+            Action::FocusQueryEditor => "[5] SQL Query Editor pane",
+            Action::FocusSqlFiles => "[6] SQL Files pane",
+            Action::NarrowLeftColumn => "Narrow the left column",
+            Action::WidenLeftColumn => "Widen the left column",
+            Action::GrowPane => "Grow the focused pane",
+            Action::ShrinkPane => "Shrink the focused pane",
+            Action::ResizeMode => "Resize mode (h/l width, j/k height, v/s split, Esc to leave)",
+        }
+    }
+
+    /// Pane the action is active in
+    pub fn context(&self) -> ActionContext {
+        match self {
+            Action::AddConnection
+            | Action::EditConnection
+            | Action::DeleteConnection
+            | Action::Connect
+            | Action::Disconnect
+            | Action::RefreshConnections
+            | Action::SearchConnections
+            | Action::ImportConnections
+            | Action::ToggleFavoriteConnection
+            | Action::DuplicateConnection
+            | Action::AddConnectionFromTemplate => ActionContext::Connections,
+            Action::OpenTable
+            | Action::RefreshTables
+            | Action::SearchTables
+            | Action::ImportCsv
+            | Action::ShowRoutines
+            | Action::BackupTable
+            | Action::BookmarkTable
+            | Action::ToggleTableGroup
+            | Action::CycleTableFilter
+            | Action::TruncateTable
+            | Action::TableActions => ActionContext::Tables,
+            Action::OpenRoutine | Action::DropRoutine | Action::RefreshRoutines => {
+                ActionContext::Routines
+            }
+            Action::LoadSqlFile
+            | Action::NewSqlFile
+            | Action::RenameSqlFile
+            | Action::DeleteSqlFile
+            | Action::SearchSqlFiles
+            | Action::BookmarkSqlFile => ActionContext::SqlFiles,
+            Action::FocusLeft
+            | Action::FocusDown
+            | Action::FocusUp
+            | Action::FocusRight
+            | Action::NextPane
+            | Action::PreviousPane
+            | Action::FocusConnections
+            | Action::FocusTables
+            | Action::FocusDetails
+            | Action::FocusResults
+            | Action::FocusQueryEditor
+            | Action::FocusSqlFiles
+            | Action::NarrowLeftColumn
+            | Action::WidenLeftColumn
+            | Action::GrowPane
+            | Action::ShrinkPane
+            | Action::ResizeMode => ActionContext::Navigation,
+            _ => ActionContext::TableViewer,
+        }
+    }
+
+    /// Built-in bindings used when the config doesn't override the action
+    fn default_bindings(&self) -> &'static [&'static str] {
+        match self {
+            Action::AddConnection => &["a"],
+            Action::EditConnection => &["e"],
+            Action::DeleteConnection => &["d"],
+            Action::Connect => &["<Enter>", "<Space>"],
+            Action::Disconnect => &["x"],
+            Action::RefreshConnections => &["r"],
+            Action::SearchConnections => &["/"],
+            Action::ImportConnections => &["I"],
+            Action::ToggleFavoriteConnection => &["f"],
+            Action::DuplicateConnection => &["y"],
+            Action::AddConnectionFromTemplate => &["A"],
+            Action::OpenTable => &["<Enter>", "<Space>"],
+            Action::RefreshTables => &["r", "<C-o>"],
+            Action::SearchTables => &["/"],
+            Action::ImportCsv => &["I"],
+            Action::ShowRoutines => &["<C-r>"],
+            Action::BackupTable => &["B"],
+            Action::BookmarkTable => &["m"],
+            Action::ToggleTableGroup => &["zt"],
+            Action::CycleTableFilter => &["T"],
+            Action::TruncateTable => &["X"],
+            Action::TableActions => &["M", "<C-Space>"],
+            Action::OpenRoutine => &["<Enter>", "<Space>"],
+            Action::DropRoutine => &["x"],
+            Action::RefreshRoutines => &["r"],
+            Action::EditCell => &["i", "<Enter>"],
+            Action::DeleteRow => &["dd"],
+            Action::SetCellNull => &["dc"],
+            Action::CopyRow => &["yy"],
+            Action::CopyRowAs => &["yf"],
+            Action::CopyCell => &["yc"],
+            Action::CopyPrimaryKey => &["yp"],
+            Action::InspectBinary => &["K"],
+            Action::ViewJson => &["J"],
+            Action::ViewCell => &["<Space>"],
+            Action::SearchRows => &["/"],
+            Action::SortByColumn => &["s"],
+            Action::ToggleTableView => &["t"],
+            Action::ToggleVerticalView => &["V"],
+            Action::ToggleWatch => &["W"],
+            Action::RefreshTableData => &["r"],
+            Action::PreviousTab => &["H"],
+            Action::NextTab => &["L"],
+            Action::CloseTab => &["x"],
+            Action::PinResults => &["P"],
+            Action::DiffResults => &["D"],
+            Action::FirstColumn => &["0"],
+            Action::LastColumn => &["$"],
+            Action::FreezeColumns => &["zf"],
+            Action::FreezeMoreColumns => &["<C-f>"],
+            Action::FreezeFewerColumns => &["<C-F>"],
+            Action::ToggleRowNumbers => &["#"],
+            Action::AutoFitColumns => &["="],
+            Action::WidenColumn => &[">"],
+            Action::NarrowColumn => &["<lt>"],
+            Action::UndoChange => &["u"],
+            Action::RedoChange => &["<C-r>"],
+            Action::LoadSqlFile => &["<Enter>"],
+            Action::NewSqlFile => &["n"],
+            Action::RenameSqlFile => &["r"],
+            Action::DeleteSqlFile => &["d"],
+            Action::SearchSqlFiles => &["/"],
+            Action::BookmarkSqlFile => &["m"],
+            Action::FocusLeft => &["<C-h>"],
+            Action::FocusDown => &["<C-j>"],
+            Action::FocusUp => &["<C-k>"],
+            Action::FocusRight => &["<C-l>"],
+            Action::NextPane => &["<Tab>"],
+            Action::PreviousPane => &["<S-Tab>"],
+            Action::FocusConnections => &["1", "<F1>"],
+            Action::FocusTables => &["2", "<F2>"],
+            Action::FocusDetails => &["3", "<F3>"],
+            Action::FocusResults => &["4", "<F4>"],
+            Action::FocusQueryEditor => &["5", "<F5>"],
+            Action::FocusSqlFiles => &["6", "<F6>"],
+            Action::NarrowLeftColumn => &["<C-Left>"],
+            Action::WidenLeftColumn => &["<C-Right>"],
+            Action::GrowPane => &["<A-j>"],
+            Action::ShrinkPane => &["<A-k>"],
+            Action::ResizeMode => &["<C-w>"],
+        }
+    }
+}
+
+/// A single key press, normalized so that Shift is implied by the character
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyStroke {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyStroke {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers = if matches!(code, KeyCode::Char(_) | KeyCode::BackTab) {
+            modifiers.difference(KeyModifiers::SHIFT)
+        } else {
+            modifiers
+        };
+        Self { code, modifiers }
+    }
+
+    /// Vim-style label for the key
+    fn label(&self) -> String {
+        let base = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char('<') => "lt".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::BackTab => "S-Tab".to_string(),
+            KeyCode::Backspace => "BS".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::Up => "Up".to_string(),
+            KeyCode::Down => "Down".to_string(),
+            KeyCode::Left => "Left".to_string(),
+            KeyCode::Right => "Right".to_string(),
+            KeyCode::F(n) => format!("F{n}"),
+            other => format!("{other:?}"),
+        };
+
+        let mut prefix = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            prefix.push_str("C-");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            prefix.push_str("A-");
+        }
+
+        if prefix.is_empty() && matches!(self.code, KeyCode::Char(c) if c != ' ' && c != '<') {
+            base
+        } else {
+            format!("<{prefix}{base}>")
+        }
+    }
+}
+
+impl From<KeyEvent> for KeyStroke {
+    fn from(key: KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+/// A binding: one key or a two-key sequence such as `dd`
+pub type KeySequence = Vec<KeyStroke>;
+
+/// Parse vim-style key notation: plain characters are keys, `<...>` names
+/// special keys and modifiers (`<Enter>`, `<Space>`, `<C-r>`, `<F5>`)
+pub fn parse_key_sequence(notation: &str) -> Result<KeySequence, String> {
+    let mut sequence = Vec::new();
+    let mut chars = notation.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '<' {
+            sequence.push(KeyStroke::new(KeyCode::Char(ch), KeyModifiers::NONE));
+            continue;
+        }
+
+        let mut name = String::new();
+        let mut closed = false;
+        for c in chars.by_ref() {
+            if c == '>' {
+                closed = true;
+                break;
+            }
+            name.push(c);
+        }
+        if !closed {
+            return Err(format!("Unclosed '<' in key binding '{notation}'"));
+        }
+        sequence.push(
+            parse_named_key(&name)
+                .ok_or_else(|| format!("Unknown key '<{name}>' in key binding '{notation}'"))?,
+        );
+    }
+
+    match sequence.len() {
+        0 => Err("Empty key binding".to_string()),
+        1 | 2 => Ok(sequence),
+        _ => Err(format!(
+            "Key binding '{notation}' is too long (at most two keys)"
+        )),
+    }
+}
+
+/// Parse the inside of a `<...>` key name
+fn parse_named_key(name: &str) -> Option<KeyStroke> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = name;
+    loop {
+        if let Some(stripped) = rest.strip_prefix("C-") {
+            modifiers |= KeyModifiers::CONTROL;
+            rest = stripped;
+        } else if let Some(stripped) = rest.strip_prefix("A-") {
+            modifiers |= KeyModifiers::ALT;
+            rest = stripped;
+        } else {
+            break;
+        }
+    }
+
+    let code = match rest.to_ascii_lowercase().as_str() {
+        "enter" | "cr" => KeyCode::Enter,
+        "esc" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        "tab" => KeyCode::Tab,
+        "s-tab" | "backtab" => KeyCode::BackTab,
+        "bs" | "backspace" => KeyCode::Backspace,
+        "del" | "delete" => KeyCode::Delete,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "lt" => KeyCode::Char('<'),
+        lower => {
+            if let Some(n) = lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                KeyCode::F(n)
+            } else {
+                let mut chars = rest.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return None,
+                }
+            }
+        }
+    };
+
+    Some(KeyStroke::new(code, modifiers))
+}
+
+/// Outcome of feeding a key press to the hotkey manager
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyResolution {
+    /// The key completed a binding
+    Action(Action),
+    /// The key started a sequence; the hint lists possible continuations
+    Pending(String),
+    /// The key isn't bound in this context
+    Unbound,
+}
+
+/// Resolves key presses to pane actions using defaults plus user overrides
+#[derive(Debug, Clone)]
+pub struct HotkeyManager {
+    bindings: HashMap<Action, Vec<KeySequence>>,
+    pending: Option<(ActionContext, KeyStroke, Instant)>,
+    /// `leader_key` from the config, Space by default
+    leader: KeyStroke,
+    /// When the leader was armed, and whether it still stands for its own
+    /// key press (false once a leader sequence has used it)
+    leader_pending: Option<(Instant, bool)>,
+}
+
+impl HotkeyManager {
+    /// Manager with the built-in bindings only
+    pub fn new() -> Self {
+        let bindings = Action::ALL
+            .iter()
+            .map(|action| {
+                let sequences = action
+                    .default_bindings()
+                    .iter()
+                    .filter_map(|notation| parse_key_sequence(notation).ok())
+                    .collect();
+                (*action, sequences)
+            })
+            .collect();
+
+        Self {
+            bindings,
+            pending: None,
+            leader: KeyStroke::new(KeyCode::Char(' '), KeyModifiers::NONE),
+            leader_pending: None,
+        }
+    }
+
+    /// Build the manager from config; actions not listed keep their defaults.
+    /// Returns warnings for invalid entries and conflicting bindings.
+    pub fn from_config(config: &KeybindingsConfig) -> (Self, Vec<String>) {
+        let mut manager = Self::new();
+        let mut warnings = Vec::new();
+
+        match parse_key_sequence(&config.leader_key).as_deref() {
+            Ok([leader]) => manager.leader = *leader,
+            _ => warnings.push(format!(
+                "leader_key '{}' must be a single key",
+                config.leader_key
+            )),
+        }
+
+        let mut names: Vec<&String> = config.actions.keys().collect();
+        names.sort();
+
+        for name in names {
+            let Some(action) = Action::from_name(name) else {
+                warnings.push(format!("Unknown action '{name}'"));
+                continue;
+            };
+
+            let mut sequences = Vec::new();
+            for notation in config.actions[name].notations() {
+                match parse_key_sequence(notation) {
+                    Ok(sequence) => sequences.push(sequence),
+                    Err(e) => warnings.push(format!("{name}: {e}")),
+                }
+            }
+            if !sequences.is_empty() {
+                manager.bindings.insert(action, sequences);
+            }
+        }
+
+        warnings.extend(manager.conflicts());
+        (manager, warnings)
+    }
+
+    /// Describe bindings that shadow each other within the same pane
+    pub fn conflicts(&self) -> Vec<String> {
+        let mut conflicts = Vec::new();
+
+        for (i, first) in Action::ALL.iter().enumerate() {
+            for second in &Action::ALL[i + 1..] {
+                if first.context() != second.context() {
+                    continue;
+                }
+                for a in &self.bindings[first] {
+                    for b in &self.bindings[second] {
+                        if a.starts_with(b) || b.starts_with(a) {
+                            conflicts.push(format!(
+                                "{} ({}) collides with {} ({})",
+                                first.name(),
+                                sequence_label(a),
+                                second.name(),
+                                sequence_label(b)
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        conflicts
+    }
+
+    /// Feed a key press from a pane. Two-key sequences arm on the first key
+    /// and must be completed within the sequence timeout.
+    pub fn resolve(&mut self, context: ActionContext, key: KeyEvent) -> KeyResolution {
+        let stroke = KeyStroke::from(key);
+
+        if let Some((pending_context, first, started)) = self.pending.take() {
+            if pending_context == context && started.elapsed() < SEQUENCE_TIMEOUT {
+                if let Some(action) = self.find(context, &[first, stroke]) {
+                    return KeyResolution::Action(action);
+                }
+            }
+        }
+
+        if let Some(action) = self.find(context, &[stroke]) {
+            return KeyResolution::Action(action);
+        }
+
+        let continuations: Vec<String> = self
+            .actions_in(context)
+            .flat_map(|(action, sequences)| {
+                sequences
+                    .iter()
+                    .filter(|sequence| sequence.len() == 2 && sequence[0] == stroke)
+                    .map(move |sequence| {
+                        format!("{}: {}", sequence_label(sequence), action.description())
+                    })
+            })
+            .collect();
+
+        if continuations.is_empty() {
+            KeyResolution::Unbound
+        } else {
+            self.pending = Some((context, stroke, Instant::now()));
+            KeyResolution::Pending(continuations.join(" • "))
+        }
+    }
+
+    /// Action bound to this single key, leaving any half-typed pane sequence armed
+    pub fn lookup(&self, context: ActionContext, key: KeyEvent) -> Option<Action> {
+        self.find(context, &[KeyStroke::from(key)])
+    }
+
+    /// First key of a sequence still waiting for its second key
+    pub fn pending_keys(&self) -> Option<String> {
+        if self.leader_pending.is_some() {
+            return Some(self.leader_label());
+        }
+        self.pending
+            .as_ref()
+            .filter(|(_, _, started)| started.elapsed() < SEQUENCE_TIMEOUT)
+            .map(|(_, stroke, _)| sequence_label(&[*stroke]))
+    }
+
+    /// Help label for the leader key, e.g. `<Space>`
+    pub fn leader_label(&self) -> String {
+        self.leader.label()
+    }
+
+    pub fn is_leader(&self, key: KeyEvent) -> bool {
+        KeyStroke::from(key) == self.leader
+    }
+
+    /// The leader key as an event, for handing a lone press to the pane
+    pub fn leader_event(&self) -> KeyEvent {
+        KeyEvent::new(self.leader.code, self.leader.modifiers)
+    }
+
+    /// Wait for the key after the leader
+    pub fn arm_leader(&mut self) {
+        self.leader_pending = Some((Instant::now(), true));
+    }
+
+    /// Keep waiting after a leader sequence, so its last key can repeat
+    pub fn hold_leader(&mut self) {
+        self.leader_pending = Some((Instant::now(), false));
+    }
+
+    pub fn is_leader_active(&self) -> bool {
+        self.leader_pending.is_some()
+    }
+
+    /// Whether the leader has waited longer than a sequence may take
+    pub fn leader_timed_out(&self) -> bool {
+        self.leader_pending
+            .is_some_and(|(started, _)| started.elapsed() >= SEQUENCE_TIMEOUT)
+    }
+
+    /// Stop waiting after the leader. Returns whether the leader press itself
+    /// still has to be handled, or None when it wasn't armed.
+    pub fn take_leader(&mut self) -> Option<bool> {
+        self.leader_pending.take().map(|(_, unused)| unused)
+    }
+
+    /// Drop a half-typed sequence
+    pub fn cancel_pending(&mut self) {
+        self.pending = None;
+    }
+
+    /// Help label for an action, e.g. `i/<Enter>`
+    pub fn label(&self, action: Action) -> String {
+        self.bindings
+            .get(&action)
+            .map(|sequences| {
+                sequences
+                    .iter()
+                    .map(|sequence| sequence_label(sequence))
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+            .unwrap_or_default()
+    }
+
+    fn actions_in(
+        &self,
+        context: ActionContext,
+    ) -> impl Iterator<Item = (Action, &Vec<KeySequence>)> {
+        Action::ALL
+            .iter()
+            .filter(move |action| action.context() == context)
+            .filter_map(move |action| self.bindings.get(action).map(|b| (*action, b)))
+    }
+
+    fn find(&self, context: ActionContext, keys: &[KeyStroke]) -> Option<Action> {
+        self.actions_in(context)
+            .find(|(_, sequences)| sequences.iter().any(|sequence| sequence == keys))
+            .map(|(action, _)| action)
+    }
+}
+
+impl Default for HotkeyManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Label for a whole sequence, e.g. `dd` or `<C-r>`
+fn sequence_label(sequence: &[KeyStroke]) -> String {
+    sequence.iter().map(KeyStroke::label).collect()
+}
+
+impl BindingSpec {
+    /// All key notations of this spec
+    fn notations(&self) -> Vec<&str> {
+        match self {
+            BindingSpec::One(notation) => vec![notation.as_str()],
+            BindingSpec::Many(notations) => notations.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    fn config(actions: &[(&str, &str)]) -> KeybindingsConfig {
+        KeybindingsConfig {
+            leader_key: " ".to_string(),
+            actions: actions
+                .iter()
+                .map(|(name, keys)| (name.to_string(), BindingSpec::One(keys.to_string())))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_key_sequence() {
+        assert_eq!(parse_key_sequence("dd").unwrap().len(), 2);
+        assert_eq!(
+            parse_key_sequence("<C-r>").unwrap(),
+            vec![KeyStroke::new(KeyCode::Char('r'), KeyModifiers::CONTROL)]
+        );
+        assert_eq!(
+            parse_key_sequence("<Enter>").unwrap(),
+            vec![KeyStroke::new(KeyCode::Enter, KeyModifiers::NONE)]
+        );
+        assert!(parse_key_sequence("<Nope>").is_err());
+        assert!(parse_key_sequence("abc").is_err());
+        assert!(parse_key_sequence("").is_err());
+    }
+
+    #[test]
+    fn test_defaults_have_no_conflicts() {
+        assert!(HotkeyManager::new().conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_override_keeps_other_defaults() {
+        let (mut manager, warnings) = HotkeyManager::from_config(&config(&[("close_tab", "X")]));
+        assert!(warnings.is_empty());
+
+        assert_eq!(
+            manager.resolve(ActionContext::TableViewer, key('X')),
+            KeyResolution::Action(Action::CloseTab)
+        );
+        assert_eq!(
+            manager.resolve(ActionContext::TableViewer, key('x')),
+            KeyResolution::Unbound
+        );
+        assert_eq!(
+            manager.resolve(ActionContext::TableViewer, key('t')),
+            KeyResolution::Action(Action::ToggleTableView)
+        );
+    }
+
+    #[test]
+    fn test_sequence_resolution() {
+        let mut manager = HotkeyManager::new();
+
+        assert!(matches!(
+            manager.resolve(ActionContext::TableViewer, key('d')),
+            KeyResolution::Pending(_)
+        ));
+        assert_eq!(
+            manager.resolve(ActionContext::TableViewer, key('c')),
+            KeyResolution::Action(Action::SetCellNull)
+        );
+
+        // Single 'd' is a plain action in the connections pane
+        assert_eq!(
+            manager.resolve(ActionContext::Connections, key('d')),
+            KeyResolution::Action(Action::DeleteConnection)
+        );
+    }
+
+    #[test]
+    fn test_conflicts_are_reported() {
+        let (_, warnings) =
+            HotkeyManager::from_config(&config(&[("close_tab", "t"), ("unknown", "z")]));
+
+        assert!(warnings
+            .iter()
+            .any(|w| w.contains("Unknown action 'unknown'")));
+        assert!(warnings
+            .iter()
+            .any(|w| w.contains("toggle_table_view") && w.contains("close_tab")));
+    }
+
+    #[test]
+    fn test_leader_key() {
+        let mut manager = HotkeyManager::new();
+        assert!(manager.is_leader(key(' ')));
+        assert_eq!(manager.take_leader(), None);
+
+        manager.arm_leader();
+        assert_eq!(manager.pending_keys().as_deref(), Some("<Space>"));
+        assert!(!manager.leader_timed_out());
+        assert_eq!(manager.take_leader(), Some(true));
+        // Held after a leader sequence, the press itself is used up
+        manager.hold_leader();
+        assert_eq!(manager.take_leader(), Some(false));
+        assert!(!manager.is_leader_active());
+
+        let mut comma = config(&[]);
+        comma.leader_key = ",".to_string();
+        let (manager, warnings) = HotkeyManager::from_config(&comma);
+        assert!(warnings.is_empty());
+        assert!(manager.is_leader(key(',')) && !manager.is_leader(key(' ')));
+
+        comma.leader_key = "ab".to_string();
+        let (manager, warnings) = HotkeyManager::from_config(&comma);
+        assert!(warnings.iter().any(|w| w.contains("leader_key 'ab'")));
+        assert!(manager.is_leader(key(' ')));
+    }
+
+    #[test]
+    fn test_remapped_focus_left() {
+        let ctrl_h = KeyEvent::new(KeyCode::Char('h'), KeyModifiers::CONTROL);
+        let alt_h = KeyEvent::new(KeyCode::Char('h'), KeyModifiers::ALT);
+        assert_eq!(
+            HotkeyManager::new().lookup(ActionContext::Navigation, ctrl_h),
+            Some(Action::FocusLeft)
+        );
+
+        let (manager, warnings) = HotkeyManager::from_config(&config(&[("focus_left", "<A-h>")]));
+        assert!(warnings.is_empty());
+        assert_eq!(
+            manager.lookup(ActionContext::Navigation, alt_h),
+            Some(Action::FocusLeft)
+        );
+        assert_eq!(manager.lookup(ActionContext::Navigation, ctrl_h), None);
+        // Unset navigation bindings keep their defaults
+        assert_eq!(
+            manager.lookup(
+                ActionContext::Navigation,
+                KeyEvent::new(KeyCode::F(2), KeyModifiers::NONE)
+            ),
+            Some(Action::FocusTables)
+        );
+        assert_eq!(
+            manager.lookup(
+                ActionContext::Navigation,
+                KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT)
+            ),
+            Some(Action::PreviousPane)
+        );
+    }
+
+    #[test]
+    fn test_lookup_keeps_pending_sequence() {
+        let mut manager = HotkeyManager::new();
+        assert!(matches!(
+            manager.resolve(ActionContext::TableViewer, key('d')),
+            KeyResolution::Pending(_)
+        ));
+        assert_eq!(manager.lookup(ActionContext::Navigation, key('d')), None);
+        assert_eq!(manager.pending_keys().as_deref(), Some("d"));
+        assert_eq!(
+            manager.resolve(ActionContext::TableViewer, key('d')),
+            KeyResolution::Action(Action::DeleteRow)
+        );
+        assert_eq!(manager.pending_keys(), None);
+    }
+
+    #[test]
+    fn test_labels() {
+        let manager = HotkeyManager::new();
+        assert_eq!(manager.label(Action::EditCell), "i/<Enter>");
+        assert_eq!(manager.label(Action::DeleteRow), "dd");
+    }
+}
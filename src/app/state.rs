@@ -4,18 +4,32 @@
 
 use crate::{
     config::Config,
-    database::{AppStateDb, ConnectionConfig, ConnectionManager, ConnectionStatus},
-    state::{ui::UIState, DatabaseState},
+    database::{
+        sql_split::{split_statements, SqlStatement},
+        AppStateDb, ConnectionConfig, ConnectionManager, ConnectionStatus, DatabaseType,
+    },
+    security::{PasswordManager, PasswordSource, SessionKey},
+    state::{
+        ui::UIState, Bookmark, BookmarkKind, BookmarkStore, ColumnWidthStore, ConnectionTemplate,
+        ConnectionTemplateStore, DatabaseState, Snippet, SnippetStore, TomlStore, Workspace,
+    },
     ui::components::{
-        ConnectionModalState, ConnectionMode, DebugView, QueryEditor, TableViewerState,
-        ToastManager,
+        batch_summary, format_elapsed, substitute_query_vars, ColumnInfo, ConnectionField,
+        ConnectionModalState, ConnectionMode, DataChange, DebugView, PasswordStorageType,
+        QueryEditor, QueryError, QueryOutput, QueryStats, RunningQuery, StatementResult,
+        StatementStatus, TableViewMode, TableViewerState, ToastHistoryPane, ToastManager,
+        UnlockPrompt, UnlockThen, WatchFailure, WatchedQuery, WorkspaceBrowser, MAX_WATCH_FAILURES,
     },
+    ui::layout::SplitMode,
 };
 
 // Re-export for backward compatibility
 pub use crate::state::ui::{FocusedPane, HelpMode, HelpPaneFocus};
 pub use crate::state::view::{AppView, ConnectionFormMode, OverlayView, TextInputMode};
 
+/// Column names and rows of a result; `None` cells are NULL
+type ResultSet = (Vec<String>, Vec<Vec<Option<String>>>);
+
 /// Query editor movement directions
 #[derive(Debug, Clone, Copy)]
 pub enum QueryEditorMovement {
@@ -46,6 +60,46 @@ pub struct AppState {
     pub query_editor: QueryEditor,
     /// Debug view component
     pub debug_view: DebugView,
+    /// Toast history overlay component
+    pub toast_history: ToastHistoryPane,
+    /// Configurable pane action bindings
+    pub hotkeys: crate::app::shortcuts::HotkeyManager,
+    /// Saved workspaces overlay component
+    pub workspace_browser: WorkspaceBrowser,
+    /// Name of the workspace last saved or restored
+    pub current_workspace: Option<String>,
+    /// Checklist of connections discovered by the system import
+    pub connection_import: Option<crate::ui::components::ConnectionImportModal>,
+    /// CSV import modal for the selected table
+    pub csv_import: Option<crate::ui::components::CsvImportModal>,
+    /// Create-index form opened with `I` in the Details pane
+    pub index_modal: Option<crate::ui::components::IndexModal>,
+    /// `M` / `Ctrl+Space` action menu for the selected Tables pane entry
+    pub table_context_menu: Option<crate::ui::components::ContextMenu>,
+    /// `Ctrl+P` fuzzy list of registered commands
+    pub command_palette: Option<crate::ui::components::CommandPalette>,
+    /// `Ctrl+T` jump-to-table popup
+    pub quick_switcher: Option<crate::ui::components::QuickSwitcher>,
+    /// `Ctrl+J` snippet picker in the query editor
+    pub snippet_picker: Option<crate::ui::components::SnippetPicker>,
+    /// `:theme list` gallery previewing themes live
+    pub theme_gallery: Option<crate::ui::components::ThemeGallery>,
+    /// `:locks` monitor over `pg_locks`
+    pub lock_monitor: Option<crate::ui::components::LockMonitor>,
+    /// `'` list of bookmarked tables and SQL files
+    pub bookmark_list: Option<crate::ui::components::BookmarkList>,
+    /// `A` list of connection templates to fill the add form from
+    pub connection_template_list: Option<crate::ui::components::ConnectionTemplateList>,
+    /// Table name typed to confirm `TRUNCATE TABLE` or `DROP TABLE`
+    pub name_confirmation: Option<crate::ui::components::NameConfirmation>,
+    /// New name for a table renamed from the Tables pane action menu
+    pub rename_prompt: Option<crate::ui::components::RenamePrompt>,
+    /// Bookmark to open once its connection is up and its tables are listed
+    pub pending_bookmark: Option<Bookmark>,
+    /// Prompt for the key that decrypts `Encrypted` connection passwords
+    pub unlock_prompt: Option<crate::ui::components::UnlockPrompt>,
+    /// Pane proportions, adjustable at runtime and saved to `[layout]`
+    pub layout: crate::ui::layout::LayoutManager,
     /// Connection mode component (for full-screen connection management)
     pub connection_mode: Option<ConnectionMode>,
     /// Application state database
@@ -54,7 +108,7 @@ pub struct AppState {
     pub connection_manager: ConnectionManager,
     /// Connection attempt in progress (stores connection index being attempted)
     pub connecting_in_progress: Option<usize>,
-    /// Animation frame counter for loading dots (0-2)
+    /// Tick counter for the connecting spinner and loading dots; wraps
     pub connecting_animation_frame: u8,
     /// Connection attempt start time for timeout tracking
     pub connection_start_time: Option<std::time::Instant>,
@@ -62,10 +116,39 @@ pub struct AppState {
     pub connection_timeout_seconds: u64,
     /// Test connection in progress (modal test button)
     pub test_connection_in_progress: bool,
-    /// Animation frame counter for test connection loading dots (0-2)
+    /// Spinner frame for the test connection countdown
     pub test_animation_frame: u8,
+    /// Seconds before a connection test times out (`connections.test_timeout_secs`)
+    pub test_timeout_seconds: u64,
     /// Test connection start time for timeout tracking
     pub test_start_time: Option<std::time::Instant>,
+    /// Query executing in the background, shown with a live timer
+    pub running_query: Option<RunningQuery>,
+    /// Uncut columns and rows of the last statement that returned rows,
+    /// written out by `:copy`
+    pub last_query_result: Option<ResultSet>,
+    /// SQL dump being written in the background, shown in the status bar
+    pub backup: Option<crate::database::backup::BackupProgress>,
+    /// Values substituted for `{{name}}` placeholders when a query runs
+    pub query_vars: std::collections::BTreeMap<String, String>,
+    /// Variables panel below the query editor, toggled with `Ctrl+Shift+V`
+    pub query_vars_panel: Option<crate::ui::components::QueryVarsPanel>,
+    /// Results, editor and connection of the split without focus, while
+    /// `main_content` is split with `Ctrl+W v` / `Ctrl+W s`
+    pub split: Option<SplitPane>,
+}
+
+/// What each results/editor split keeps for itself. The focused split lives in
+/// the `AppState` fields; the other one waits here until focus moves to it.
+#[derive(Debug, Clone)]
+pub struct SplitPane {
+    pub table_viewer_state: TableViewerState,
+    pub query_editor: QueryEditor,
+    pub query_content: String,
+    pub current_sql_file: Option<String>,
+    pub query_modified: bool,
+    /// Connection the split's queries run against
+    pub selected_connection: usize,
 }
 
 impl AppState {
@@ -96,6 +179,26 @@ impl AppState {
             toast_manager: ToastManager::new(),
             query_editor: QueryEditor::new(),
             debug_view: DebugView::new(),
+            toast_history: ToastHistoryPane::new(),
+            hotkeys: crate::app::shortcuts::HotkeyManager::new(),
+            workspace_browser: WorkspaceBrowser::new(),
+            current_workspace: None,
+            connection_import: None,
+            csv_import: None,
+            index_modal: None,
+            table_context_menu: None,
+            command_palette: None,
+            snippet_picker: None,
+            quick_switcher: None,
+            theme_gallery: None,
+            lock_monitor: None,
+            bookmark_list: None,
+            connection_template_list: None,
+            name_confirmation: None,
+            rename_prompt: None,
+            unlock_prompt: None,
+            pending_bookmark: None,
+            layout: crate::ui::layout::LayoutManager::new(),
             connection_mode: None,
             app_state_db: AppStateDb::new(),
             connection_manager: ConnectionManager::new(),
@@ -105,7 +208,14 @@ impl AppState {
             connection_timeout_seconds: 30, // 30 seconds timeout
             test_connection_in_progress: false,
             test_animation_frame: 0,
+            test_timeout_seconds: 10,
             test_start_time: None,
+            running_query: None,
+            last_query_result: None,
+            backup: None,
+            query_vars: std::collections::BTreeMap::new(),
+            query_vars_panel: None,
+            split: None,
         }
     }
 
@@ -207,6 +317,10 @@ impl AppState {
             FocusedPane::Tables => {
                 self.table_up();
             }
+            FocusedPane::Routines => {
+                let index = self.ui.selected_routine.saturating_sub(1);
+                self.ui.select_routine(index, self.db.routines.len());
+            }
             FocusedPane::TabularOutput => {
                 if let Some(tab) = self.table_viewer_state.current_tab_mut() {
                     if !tab.in_edit_mode {
@@ -243,6 +357,10 @@ impl AppState {
             FocusedPane::Tables => {
                 self.table_down();
             }
+            FocusedPane::Routines => {
+                let index = self.ui.selected_routine + 1;
+                self.ui.select_routine(index, self.db.routines.len());
+            }
             FocusedPane::TabularOutput => {
                 if let Some(tab) = self.table_viewer_state.current_tab_mut() {
                     if !tab.in_edit_mode {
@@ -378,6 +496,102 @@ impl AppState {
         self.connection_modal_state = ConnectionModalState::new(); // Reset state
     }
 
+    /// Open the add connection modal filled in from a copy of the selected
+    /// connection, named "<name> (copy)". Keychain and encrypted passwords
+    /// carry over; a plain-text password has to be typed again.
+    pub fn open_duplicate_connection_modal(&mut self) {
+        let Some(connection) = self.get_selected_connection().cloned() else {
+            return;
+        };
+        let mut copy = connection.clone();
+        copy.name = format!("{} (copy)", connection.name);
+        self.open_add_connection_modal();
+        self.fill_connection_form(&copy);
+        if connection.database_type == DatabaseType::SQLite {
+            return;
+        }
+        if self.connection_modal_state.password_storage_type == PasswordStorageType::PlainText
+            && !self.connection_modal_state.password.is_empty()
+        {
+            self.connection_modal_state.password.clear();
+            self.connection_modal_state.focused_field = ConnectionField::Password;
+            self.toast_manager
+                .info(format!("Enter the password for '{}'", copy.name));
+        }
+    }
+
+    /// Open the add connection modal filled in from a saved template
+    pub fn open_add_connection_from_template(&mut self, template: &ConnectionTemplate) {
+        self.open_add_connection_modal();
+        self.fill_connection_form(&template.to_connection());
+        self.connection_modal_state.focused_field = ConnectionField::Name;
+    }
+
+    /// Fill the add form from `connection`, keeping its keychain or encrypted
+    /// password for when the password field is left blank
+    fn fill_connection_form(&mut self, connection: &ConnectionConfig) {
+        let state = &mut self.connection_modal_state;
+        state.populate_from_connection(connection);
+        state.copied_password_source = connection.password_source.clone().filter(|source| {
+            matches!(
+                source,
+                PasswordSource::Keychain { .. } | PasswordSource::Encrypted(_)
+            )
+        });
+        if state.is_field_visible(ConnectionField::Database) {
+            state.focused_field = ConnectionField::Database;
+        }
+    }
+
+    /// Save the selected connection as a template for `A`. Returns the
+    /// message to show.
+    pub fn save_connection_template(&mut self, name: &str) -> Result<String, String> {
+        let name = name.trim();
+        let connection = self
+            .get_selected_connection()
+            .ok_or("No connection selected")?;
+        let template = ConnectionTemplate::from_connection(name, connection);
+        let mut store = ConnectionTemplateStore::load()
+            .map_err(|e| format!("Failed to load templates: {e}"))?;
+        let replaced = store.upsert(template).map_err(|e| e.to_string())?;
+        store
+            .save()
+            .map_err(|e| format!("Failed to save templates: {e}"))?;
+        Ok(if replaced {
+            format!("Template '{name}' updated")
+        } else {
+            format!("Template '{name}' saved")
+        })
+    }
+
+    /// Open the `A` template list over `~/.lazytables/connection_templates.toml`
+    pub fn open_connection_template_list(&mut self) {
+        match ConnectionTemplateStore::load() {
+            Ok(store) => {
+                self.connection_template_list = Some(
+                    crate::ui::components::ConnectionTemplateList::new(store.templates),
+                )
+            }
+            Err(e) => self
+                .toast_manager
+                .error(format!("Failed to load templates: {e}")),
+        }
+    }
+
+    /// Write the template list back after a removal
+    pub fn save_connection_template_list(&mut self) {
+        let Some(list) = &self.connection_template_list else {
+            return;
+        };
+        let store = ConnectionTemplateStore {
+            templates: list.templates.clone(),
+        };
+        if let Err(e) = store.save() {
+            self.toast_manager
+                .error(format!("Failed to save templates: {e}"));
+        }
+    }
+
     /// Close the add connection modal
     pub fn close_add_connection_modal(&mut self) {
         self.ui.return_to_main();
@@ -437,13 +651,25 @@ impl AppState {
                     .get(self.ui.selected_connection)
                 {
                     connection.id = existing.id.clone();
+                    connection.favorite = existing.favorite;
+                    self.store_keychain_password(
+                        &mut connection,
+                        existing.password_source.as_ref(),
+                    )?;
+                    self.store_encrypted_password(
+                        &mut connection,
+                        existing.password_source.as_ref(),
+                    )?;
                     if let Err(e) = self.db.connections.update_connection(connection).await {
                         return Err(format!("Failed to update connection: {e}"));
                     }
                 }
                 self.close_edit_connection_modal();
             } else {
-                // Add new connection
+                // Add new connection, keeping a copied keychain or encrypted password
+                let copied = self.connection_modal_state.copied_password_source.clone();
+                self.store_keychain_password(&mut connection, copied.as_ref())?;
+                self.store_encrypted_password(&mut connection, copied.as_ref())?;
                 if let Err(e) = self.db.connections.add_connection(connection).await {
                     return Err(format!("Failed to add connection: {e}"));
                 }
@@ -455,6 +681,144 @@ impl AppState {
         Ok(())
     }
 
+    /// Write the form's password to the OS keychain under the connection id
+    /// when "OS Keychain" storage is chosen. A blank password keeps the entry
+    /// an edited connection already has, or copies the entry of the
+    /// connection the form was copied from.
+    fn store_keychain_password(
+        &self,
+        connection: &mut ConnectionConfig,
+        previous: Option<&PasswordSource>,
+    ) -> Result<(), String> {
+        if self.connection_modal_state.password_storage_type != PasswordStorageType::Keychain
+            || connection.database_type == DatabaseType::SQLite
+        {
+            return Ok(());
+        }
+        let password = self.connection_modal_state.password.trim();
+        if !password.is_empty() {
+            let source = PasswordManager::store_in_keychain(&connection.id, password)?;
+            connection.set_password_source(source);
+        } else if let Some(PasswordSource::Keychain { account }) = previous {
+            let source = if *account == connection.id {
+                PasswordSource::Keychain {
+                    account: account.clone(),
+                }
+            } else {
+                let password = PasswordManager::read_keychain(account)?;
+                PasswordManager::store_in_keychain(&connection.id, &password)?
+            };
+            connection.set_password_source(source);
+        } else {
+            return Err("Enter a password to store in the keychain".to_string());
+        }
+        Ok(())
+    }
+
+    /// Encrypt the form's password with the session key when "Encrypted"
+    /// storage is chosen. A blank password keeps the one an edited
+    /// connection already has, with the new hint.
+    fn store_encrypted_password(
+        &self,
+        connection: &mut ConnectionConfig,
+        previous: Option<&PasswordSource>,
+    ) -> Result<(), String> {
+        if self.connection_modal_state.password_storage_type != PasswordStorageType::Encrypted
+            || connection.database_type == DatabaseType::SQLite
+        {
+            return Ok(());
+        }
+        let hint = self.connection_modal_state.encryption_hint.trim();
+        let hint = (!hint.is_empty()).then(|| hint.to_string());
+        let password = self.connection_modal_state.password.trim();
+        if password.is_empty() {
+            let Some(PasswordSource::Encrypted(encrypted)) = previous else {
+                return Err("Enter a password to encrypt".to_string());
+            };
+            let mut encrypted = encrypted.clone();
+            encrypted.hint = hint;
+            connection.set_password_source(PasswordSource::Encrypted(encrypted));
+            return Ok(());
+        }
+        let key = self
+            .connection_manager
+            .session_key()
+            .ok_or("Unlock encrypted passwords first")?;
+        let source = PasswordManager::create_encrypted(password, key.expose(), hint)
+            .map_err(|e| format!("Failed to encrypt password: {e}"))?;
+        connection.set_password_source(source);
+        Ok(())
+    }
+
+    /// Keychain or encrypted source of the connection being edited, or of the
+    /// one a new connection was copied from, if it has one
+    pub fn edited_password_source(&self) -> Option<PasswordSource> {
+        match self.ui.current_view.overlay() {
+            Some(OverlayView::ConnectionForm(ConnectionFormMode::Add)) => {
+                self.connection_modal_state.copied_password_source.clone()
+            }
+            Some(OverlayView::ConnectionForm(ConnectionFormMode::Edit(_))) => self
+                .db
+                .connections
+                .connections
+                .get(self.ui.selected_connection)
+                .and_then(|existing| existing.password_source.clone())
+                .filter(|source| {
+                    matches!(
+                        source,
+                        PasswordSource::Keychain { .. } | PasswordSource::Encrypted(_)
+                    )
+                }),
+            _ => None,
+        }
+    }
+
+    /// Ask for the session key; `then` is carried on once it is accepted
+    pub fn open_unlock_prompt(&mut self, then: UnlockThen) {
+        let checked = self.unlock_check_connection(then);
+        let hint = checked.and_then(ConnectionConfig::get_password_hint);
+        let new_key = checked.is_none();
+        self.unlock_prompt = Some(UnlockPrompt::new(then, hint, new_key));
+    }
+
+    /// Connection a typed key is checked against: the one being connected
+    /// to, otherwise the first with an encrypted password
+    fn unlock_check_connection(&self, then: UnlockThen) -> Option<&ConnectionConfig> {
+        let connections = &self.db.connections.connections;
+        let selected = self
+            .ui
+            .get_selected_connection_index(connections)
+            .and_then(|index| connections.get(index))
+            .filter(|connection| {
+                then == UnlockThen::Connect && connection.requires_encryption_key()
+            });
+        selected.or_else(|| connections.iter().find(|c| c.requires_encryption_key()))
+    }
+
+    /// Keep the typed key for the session if it decrypts the checked
+    /// password. Returns what the prompt was waiting to do; a wrong key
+    /// leaves the prompt open with an error.
+    pub fn submit_unlock(&mut self) -> Option<UnlockThen> {
+        let prompt = self.unlock_prompt.as_ref()?;
+        let key = prompt.input.trim().to_string();
+        let error = if key.is_empty() {
+            Some("Enter the key".to_string())
+        } else {
+            self.unlock_check_connection(prompt.then)
+                .filter(|connection| connection.resolve_password(Some(&key)).is_err())
+                .map(|connection| format!("Wrong key - it doesn't decrypt '{}'", connection.name))
+        };
+        if let Some(error) = error {
+            if let Some(prompt) = &mut self.unlock_prompt {
+                prompt.input.clear();
+                prompt.error = Some(error);
+            }
+            return None;
+        }
+        self.connection_manager.unlock(SessionKey::new(key));
+        self.unlock_prompt.take().map(|prompt| prompt.then)
+    }
+
     /// Ensure selected connection index is within bounds
     pub fn clamp_connection_selection(&mut self) {
         if !self.db.connections.connections.is_empty() {
@@ -505,6 +869,7 @@ impl AppState {
         self.db.current_table_metadata = None;
         // Reset details pane scroll position for new table
         self.ui.details_viewport_offset = 0;
+        self.ui.details_selected_column = 0;
 
         if let Some(table_name) = self.ui.get_selected_table_name() {
             crate::log_debug!("Selected table: {}", table_name);
@@ -521,6 +886,7 @@ impl AppState {
         self.db.current_table_metadata = None;
         // Reset details pane scroll position for new table
         self.ui.details_viewport_offset = 0;
+        self.ui.details_selected_column = 0;
 
         if let Some(table_name) = self.ui.get_selected_table_name() {
             crate::log_debug!("Selected table: {}", table_name);
@@ -538,6 +904,60 @@ impl AppState {
             .build_selectable_table_items(&self.db.database_objects);
     }
 
+    /// Stop tracking the streamed object listing; its late batches are dropped
+    pub fn cancel_object_listing(&mut self) {
+        self.db.object_listing = None;
+    }
+
+    /// Merge a streamed batch of objects, keeping the selected table where
+    /// possible. Until it arrives, or if it is gone, a refresh keeps the
+    /// cursor on the row it was on, clamped to the end of the list.
+    pub fn apply_object_batch(&mut self, batch: crate::database::DatabaseObjectList) {
+        let Some(listing) = self.db.object_listing.as_mut() else {
+            return;
+        };
+        listing.loaded += batch.total_count;
+        let restore = listing.restore_selection.clone();
+        let restore_index = listing.restore_index;
+        let current = self.ui.get_selected_table_name();
+
+        let objects = self
+            .db
+            .database_objects
+            .get_or_insert_with(Default::default);
+        objects.append(batch);
+        if let Some(error) = &objects.error {
+            self.db.table_load_error = Some(error.clone());
+        }
+        self.db.tables = objects
+            .tables
+            .iter()
+            .map(|t| {
+                if t.schema.as_deref() == Some("public") || t.schema.is_none() {
+                    t.name.clone()
+                } else {
+                    t.qualified_name()
+                }
+            })
+            .collect();
+
+        self.ui
+            .build_selectable_table_items(&self.db.database_objects);
+        if restore
+            .as_deref()
+            .is_some_and(|name| self.ui.select_table_by_name(name))
+        {
+            if let Some(listing) = self.db.object_listing.as_mut() {
+                listing.restore_selection = None;
+                listing.restore_index = None;
+            }
+        } else if let Some(index) = restore_index {
+            self.ui.select_table_near(index);
+        } else if let Some(name) = current {
+            self.ui.select_table_by_name(&name);
+        }
+    }
+
     /// Disconnect all connections except the one at the given index
     pub fn disconnect_all_except(&mut self, except_index: usize) {
         for (index, connection) in self.db.connections.connections.iter_mut().enumerate() {
@@ -571,7 +991,9 @@ impl AppState {
             }
 
             // Clear previous tables and errors
+            self.cancel_object_listing();
             self.db.tables.clear();
+            self.db.routines.clear();
             self.db.table_load_error = None;
 
             // Reset table viewer state when switching connections
@@ -683,6 +1105,8 @@ impl AppState {
             .get_mut(self.ui.selected_connection)
         {
             connection.status = ConnectionStatus::Disconnected;
+            self.db.object_listing = None;
+            self.db.routines.clear();
             self.db.database_objects = None;
             self.db.tables.clear();
             self.db.table_load_error = None;
@@ -974,6 +1398,22 @@ impl AppState {
         self.save_query_as(filename).await
     }
 
+    /// Open SQL given with `--file` in the query editor. The buffer is not
+    /// tied to a saved query file, so saving it creates a new one.
+    pub fn preload_query(&mut self, content: String) {
+        self.query_content = content.clone();
+        self.ui.current_sql_file = None;
+        self.ui.query_modified = true;
+        self.ui.query_cursor_line = 0;
+        self.ui.query_cursor_column = 0;
+        self.ui.query_viewport_offset = 0;
+        self.ui.focused_pane = FocusedPane::QueryWindow;
+
+        self.query_editor.set_content(content);
+        self.query_editor.set_current_file(None);
+        self.update_query_editor_context();
+    }
+
     /// Record SQL file activity when a file is opened
     pub async fn record_sql_file_activity(&self, filename: &str, file_path: &str) {
         if let Some(connection) = self
@@ -1685,6 +2125,15 @@ impl AppState {
 
     /// Open a table for viewing
     pub async fn open_table_for_viewing(&mut self) {
+        self.open_selected_table(false).await;
+    }
+
+    /// Open the selected table in a tab of its own, next to any already open on it
+    pub async fn open_table_in_new_tab(&mut self) {
+        self.open_selected_table(true).await;
+    }
+
+    async fn open_selected_table(&mut self, new_tab: bool) {
         crate::log_info!("Attempting to open table for viewing");
 
         // Check connection health before attempting to open table
@@ -1698,7 +2147,15 @@ impl AppState {
         if let Some(table_name) = self.ui.get_selected_table_name() {
             crate::log_info!("Opening table '{}' for viewing", table_name);
             // Add tab to viewer
-            let tab_idx = self.table_viewer_state.add_tab(table_name.clone());
+            let tab_idx = if new_tab {
+                self.table_viewer_state.add_new_tab(table_name.clone())
+            } else {
+                self.table_viewer_state.add_tab(table_name.clone())
+            };
+            if let Some(item) = self.ui.get_selected_table_item() {
+                self.table_viewer_state.tabs[tab_idx].object_type = item.object_type.clone();
+            }
+            self.record_recent_table(&table_name).await;
             crate::log_debug!(
                 "Created new tab with index {} for table '{}'",
                 tab_idx,
@@ -1743,6 +2200,324 @@ impl AppState {
         }
     }
 
+    /// Switch the tables pane to the routines browser (PostgreSQL only)
+    pub async fn show_routines(&mut self) {
+        let is_postgres = self
+            .db
+            .connections
+            .connections
+            .get(self.ui.selected_connection)
+            .is_some_and(|c| {
+                c.is_connected() && c.database_type == crate::database::DatabaseType::PostgreSQL
+            });
+        if !is_postgres {
+            self.toast_manager
+                .warning("The routines browser needs a connected PostgreSQL database");
+            return;
+        }
+
+        match self.load_routines().await {
+            Ok(()) => self.ui.focused_pane = FocusedPane::Routines,
+            Err(e) => self
+                .toast_manager
+                .error(format!("Failed to load routines: {e}")),
+        }
+    }
+
+    /// Load stored functions and procedures for the routines pane
+    pub async fn load_routines(&mut self) -> Result<(), String> {
+        let (_, rows) = self
+            .db
+            .execute_query(
+                crate::database::routines::LIST_ROUTINES_SQL,
+                self.ui.selected_connection,
+                &self.connection_manager,
+            )
+            .await?;
+        self.db.routines = crate::database::RoutineInfo::from_rows(&rows);
+        self.ui
+            .select_routine(self.ui.selected_routine, self.db.routines.len());
+        Ok(())
+    }
+
+    /// Open the selected routine's source in a read-only table viewer tab
+    pub async fn open_routine_source(&mut self) -> Result<(), String> {
+        let Some(routine) = self.db.routines.get(self.ui.selected_routine).cloned() else {
+            return Err("No routine selected".to_string());
+        };
+        let (_, rows) = self
+            .db
+            .execute_query(
+                &routine.definition_query(),
+                self.ui.selected_connection,
+                &self.connection_manager,
+            )
+            .await?;
+        let source = rows
+            .into_iter()
+            .next()
+            .and_then(|row| row.into_iter().next().flatten())
+            .ok_or_else(|| format!("No source returned for {}", routine.name))?;
+
+        self.table_viewer_state
+            .add_source_tab(format!("ƒ {}", routine.display_name()), &source);
+        Ok(())
+    }
+
+    /// Drop a routine and refresh the routines list
+    pub async fn drop_routine(
+        &mut self,
+        routine: &crate::database::RoutineInfo,
+    ) -> Result<(), String> {
+        let database_type = self
+            .get_selected_connection()
+            .map(|connection| connection.database_type.clone())
+            .ok_or_else(|| "No connection selected".to_string())?;
+        self.db
+            .execute_query(
+                &routine.drop_statement(&database_type),
+                self.ui.selected_connection,
+                &self.connection_manager,
+            )
+            .await?;
+        self.load_routines().await
+    }
+
+    /// Refresh a materialized view, then reload its details
+    pub async fn refresh_materialized_view(
+        &mut self,
+        view_name: &str,
+        concurrently: bool,
+    ) -> Result<(), String> {
+        self.db
+            .execute_query(
+                &crate::database::ddl::refresh_materialized_view_sql(view_name, concurrently),
+                self.ui.selected_connection,
+                &self.connection_manager,
+            )
+            .await?;
+        self.load_table_metadata(view_name).await
+    }
+
+    /// Run a `CREATE INDEX` built by the index modal, then reload the table's details
+    pub async fn create_index(&mut self, table_name: &str, statement: &str) -> Result<(), String> {
+        let connection = self
+            .get_selected_connection()
+            .filter(|connection| connection.is_connected())
+            .cloned()
+            .ok_or("No active database connection")?;
+        self.connection_manager
+            .execute_statement(&connection.id, statement)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.load_table_metadata(table_name).await
+    }
+
+    /// Run an exact `COUNT(*)` for the table shown in Details
+    pub async fn count_table_rows(&mut self) -> Result<usize, String> {
+        self.db
+            .count_table_rows(self.ui.selected_connection, &self.connection_manager)
+            .await
+    }
+
+    /// Fetch usage statistics of `table_name` for the Details pane
+    pub async fn load_table_statistics(&mut self, table_name: &str) -> Result<(), String> {
+        let connection = self
+            .get_selected_connection()
+            .filter(|connection| connection.is_connected())
+            .cloned()
+            .ok_or("No active database connection")?;
+        let statistics = self
+            .connection_manager
+            .get_table_statistics(&connection.id, table_name)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.db.table_statistics = Some(statistics);
+        self.ui.details_viewport_offset = 0;
+        Ok(())
+    }
+
+    /// Drop an index of `table_name` on the selected connection, then reload its details
+    pub async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<(), String> {
+        let connection = self
+            .get_selected_connection()
+            .filter(|connection| connection.is_connected())
+            .cloned()
+            .ok_or("No active database connection")?;
+        let statement =
+            crate::database::ddl::drop_index_sql(table_name, index_name, &connection.database_type);
+        self.connection_manager
+            .execute_statement(&connection.id, &statement)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.load_table_metadata(table_name).await
+    }
+
+    /// Drop a table, view or materialized view on the selected connection
+    pub async fn drop_table(&mut self, name: &str) -> Result<(), String> {
+        let connection = self
+            .get_selected_connection()
+            .filter(|connection| connection.is_connected())
+            .cloned()
+            .ok_or("No active database connection")?;
+        let object_type = self
+            .db
+            .database_objects
+            .as_ref()
+            .and_then(|objects| objects.object_type_of(name))
+            .unwrap_or(crate::database::DatabaseObjectType::Table);
+        let statement =
+            crate::database::ddl::drop_object_sql(name, &object_type, &connection.database_type);
+        self.connection_manager
+            .execute_statement(&connection.id, &statement)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.forget_table(name);
+        Ok(())
+    }
+
+    /// Rename a table or view within its schema; returns its new qualified name
+    pub async fn rename_table(&mut self, name: &str, new_name: &str) -> Result<String, String> {
+        let connection = self
+            .get_selected_connection()
+            .filter(|connection| connection.is_connected())
+            .cloned()
+            .ok_or("No active database connection")?;
+        if matches!(
+            connection.database_type,
+            DatabaseType::Redis | DatabaseType::MongoDB
+        ) {
+            return Err(format!(
+                "{} connections can't rename tables",
+                connection.database_type.display_name()
+            ));
+        }
+        let object_type = self
+            .db
+            .database_objects
+            .as_ref()
+            .and_then(|objects| objects.object_type_of(name))
+            .unwrap_or(crate::database::DatabaseObjectType::Table);
+        let statement = crate::database::ddl::rename_object_sql(
+            name,
+            new_name,
+            &object_type,
+            &connection.database_type,
+        );
+        self.connection_manager
+            .execute_statement(&connection.id, &statement)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.forget_table(name);
+        Ok(match name.split_once('.') {
+            Some((schema, _)) => format!("{schema}.{new_name}"),
+            None => new_name.to_string(),
+        })
+    }
+
+    /// Drop the Details metadata and viewer tabs of a table that no longer
+    /// exists under `name`
+    fn forget_table(&mut self, name: &str) {
+        if self
+            .db
+            .current_table_metadata
+            .as_ref()
+            .is_some_and(|metadata| metadata.display_name() == name)
+        {
+            self.db.current_table_metadata = None;
+        }
+        self.table_viewer_state.close_tabs_for(name);
+    }
+
+    /// Ask to truncate the selected table: a confirmation first, then its
+    /// name typed out. Only plain tables on SQL connections qualify.
+    pub fn confirm_truncate_table(&mut self) -> Result<(), String> {
+        let connection = self
+            .get_selected_connection()
+            .filter(|connection| connection.is_connected())
+            .ok_or("No active database connection")?;
+        if matches!(
+            connection.database_type,
+            DatabaseType::Redis | DatabaseType::MongoDB
+        ) {
+            return Err(format!(
+                "{} connections can't truncate tables",
+                connection.database_type.display_name()
+            ));
+        }
+        let item = self
+            .ui
+            .get_selected_table_item()
+            .ok_or("Select a table first")?;
+        if item.object_type != crate::database::DatabaseObjectType::Table {
+            return Err(format!(
+                "Only tables can be truncated, not a {}",
+                item.object_type.display_name().to_lowercase()
+            ));
+        }
+        let name = item.qualified_name();
+        self.ui.confirmation_modal = Some(crate::ui::ConfirmationModal {
+            title: "Truncate table".to_string(),
+            message: format!(
+                "Delete every row of '{name}'? This cannot be undone.\n\nType the table name to confirm truncation."
+            ),
+            action: crate::ui::ConfirmationAction::TruncateTable(name),
+        });
+        Ok(())
+    }
+
+    /// Delete every row of `name` with `TRUNCATE TABLE` (`DELETE FROM` on SQLite)
+    pub async fn truncate_table(&mut self, name: &str) -> Result<(), String> {
+        let connection = self
+            .get_selected_connection()
+            .filter(|connection| connection.is_connected())
+            .cloned()
+            .ok_or("No active database connection")?;
+        let statement = crate::database::ddl::truncate_table_sql(name, &connection.database_type);
+        self.connection_manager
+            .execute_statement(&connection.id, &statement)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// `CREATE` statement for a table or view, rebuilt from its columns or definition
+    pub async fn table_ddl(&self, name: &str) -> Result<String, String> {
+        let connection = self
+            .get_selected_connection()
+            .filter(|connection| connection.is_connected())
+            .ok_or("No active database connection")?;
+        let metadata = self
+            .connection_manager
+            .get_table_metadata(&connection.id, name)
+            .await
+            .map_err(|e| e.to_string())?;
+        if let Some(definition) = metadata.view_definition {
+            return Ok(crate::database::ddl::create_view_sql(
+                name,
+                &metadata.table_type,
+                &definition,
+                &connection.database_type,
+            ));
+        }
+
+        let columns = self
+            .connection_manager
+            .get_table_columns(&connection.id, name)
+            .await
+            .map_err(|e| e.to_string())?;
+        let definitions: Vec<crate::database::ColumnDefinition> =
+            columns.iter().map(Into::into).collect();
+        let table = name.rsplit_once('.').map_or(name, |(_, table)| table);
+        Ok(crate::database::ddl::create_table_sql(
+            table,
+            &definitions,
+            &connection.database_type,
+        ))
+    }
+
     /// Load table data for a specific tab
     pub async fn load_table_data(&mut self, tab_idx: usize) -> Result<(), String> {
         self.db
@@ -1752,7 +2527,48 @@ impl AppState {
                 tab_idx,
                 &self.connection_manager,
             )
-            .await
+            .await?;
+
+        // Bring back the column widths last set on this table
+        let connection_id = self.get_selected_connection().map(|c| c.id.clone());
+        if let (Some(connection_id), Some(tab)) =
+            (connection_id, self.table_viewer_state.tabs.get_mut(tab_idx))
+        {
+            match ColumnWidthStore::load() {
+                Ok(store) => {
+                    tab.width_overrides = store.widths(&connection_id, &tab.table_name);
+                    tab.remember_widths = true;
+                    tab.refresh_column_widths();
+                }
+                Err(e) => crate::log_warn!("Failed to load column widths: {}", e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Widen (positive `delta`) or narrow the selected column of the current
+    /// tab, saving the width for table tabs so it is kept next time
+    pub fn resize_selected_column(&mut self, delta: isize) {
+        let connection_id = self.get_selected_connection().map(|c| c.id.clone());
+        let Some(tab) = self.table_viewer_state.current_tab_mut() else {
+            return;
+        };
+        let Some((column, width)) = tab.resize_selected_column(delta) else {
+            return;
+        };
+        if let (true, Some(connection_id)) = (tab.remember_widths, connection_id) {
+            let table = tab.table_name.clone();
+            let saved = ColumnWidthStore::load().and_then(|mut store| {
+                store.set(&connection_id, &table, &column, width);
+                store.save()
+            });
+            if let Err(e) = saved {
+                self.toast_manager
+                    .error(format!("Failed to save column width: {e}"));
+                return;
+            }
+        }
+        self.toast_manager.info(format!("{column}: {width} wide"));
     }
 
     /// Load table metadata for the details pane
@@ -1800,60 +2616,405 @@ impl AppState {
         }
     }
 
-    /// Update a cell in the database
+    /// Update a cell in the database, recording the previous value for undo
     pub async fn update_table_cell(
         &mut self,
         update: crate::ui::components::table_viewer::CellUpdate,
     ) -> Result<(), String> {
+        let change =
+            self.current_tab_column(&update.column_name)
+                .map(|column| DataChange::CellUpdated {
+                    table_name: update.table_name.clone(),
+                    column,
+                    primary_key_values: update.primary_key_values.clone(),
+                    old_value: update.old_value.clone(),
+                    new_value: (!update.is_null).then(|| update.new_value.clone()),
+                });
+
         self.db
             .update_table_cell(
                 update,
                 self.ui.selected_connection,
                 &self.connection_manager,
             )
-            .await
+            .await?;
+        self.record_data_change(change);
+        Ok(())
     }
 
-    /// Delete a row from the database
+    /// Delete a row from the database, keeping its values so it can be re-inserted
     pub async fn delete_table_row(
         &mut self,
         confirmation: crate::ui::components::table_viewer::DeleteConfirmation,
     ) -> Result<(), String> {
+        let change = self
+            .table_viewer_state
+            .current_tab()
+            .filter(|tab| confirmation.row_index < tab.rows.len())
+            .map(|tab| DataChange::RowDeleted {
+                table_name: confirmation.table_name.clone(),
+                columns: tab.columns.clone(),
+                values: (0..tab.columns.len())
+                    .map(|col| tab.get_cell_value(confirmation.row_index, col))
+                    .collect(),
+                primary_key_values: confirmation.primary_key_values.clone(),
+            });
+
         self.db
             .delete_table_row(
                 confirmation,
                 self.ui.selected_connection,
                 &self.connection_manager,
             )
-            .await
+            .await?;
+        self.record_data_change(change);
+        Ok(())
     }
 
-    /// Set a cell to NULL in the database
+    /// Set a cell to NULL in the database, recording the previous value for undo
     pub async fn set_cell_to_null(
         &mut self,
         confirmation: crate::ui::components::table_viewer::SetNullConfirmation,
     ) -> Result<(), String> {
+        let change = self
+            .current_tab_column(&confirmation.column_name)
+            .map(|column| DataChange::CellUpdated {
+                table_name: confirmation.table_name.clone(),
+                column,
+                primary_key_values: confirmation.primary_key_values.clone(),
+                old_value: confirmation.current_value.clone(),
+                new_value: None,
+            });
+
         self.db
             .set_cell_to_null(
                 confirmation,
                 self.ui.selected_connection,
                 &self.connection_manager,
             )
-            .await
+            .await?;
+        self.record_data_change(change);
+        Ok(())
     }
 
-    /// Reload current table tab data
-    pub async fn reload_current_table_tab(&mut self) -> Result<(), String> {
-        if let Some(tab_idx) = self
-            .table_viewer_state
-            .tabs
-            .get(self.table_viewer_state.active_tab)
-            .map(|_| self.table_viewer_state.active_tab)
-        {
-            self.load_table_data(tab_idx).await
-        } else {
-            Ok(())
-        }
+    /// Column of the active tab named `name`, with its type
+    fn current_tab_column(&self, name: &str) -> Option<ColumnInfo> {
+        self.table_viewer_state
+            .current_tab()?
+            .columns
+            .iter()
+            .find(|column| column.name == name)
+            .cloned()
+    }
+
+    /// Push a completed change onto the active tab's undo stack
+    fn record_data_change(&mut self, change: Option<DataChange>) {
+        if let (Some(change), Some(tab)) = (change, self.table_viewer_state.current_tab_mut()) {
+            tab.undo_stack.record(change);
+        }
+    }
+
+    /// Reverse the most recent data change in the active tab.
+    /// Returns a description of what was undone, or `None` if there was nothing to undo.
+    pub async fn undo_data_change(&mut self) -> Result<Option<String>, String> {
+        let Some(change) = self
+            .table_viewer_state
+            .current_tab()
+            .and_then(|tab| tab.undo_stack.peek_undo().cloned())
+        else {
+            return Ok(None);
+        };
+        let database_type = self
+            .get_selected_connection()
+            .map(|connection| connection.database_type.clone())
+            .ok_or_else(|| "No connection selected".to_string())?;
+
+        let sql = change
+            .undo_sql(&database_type)
+            .ok_or_else(|| "the row has no primary key to find it by".to_string())?;
+        self.db
+            .execute_query(&sql, self.ui.selected_connection, &self.connection_manager)
+            .await?;
+
+        let tab_idx = self.table_viewer_state.active_tab;
+        if let Some(tab) = self.table_viewer_state.tabs.get_mut(tab_idx) {
+            tab.undo_stack.commit_undo();
+            tab.modified_cells.clear();
+        }
+        let _ = self.load_table_data(tab_idx).await;
+        Ok(Some(change.description()))
+    }
+
+    /// Re-apply the most recently undone data change in the active tab
+    pub async fn redo_data_change(&mut self) -> Result<Option<String>, String> {
+        let Some(change) = self
+            .table_viewer_state
+            .current_tab()
+            .and_then(|tab| tab.undo_stack.peek_redo().cloned())
+        else {
+            return Ok(None);
+        };
+        let database_type = self
+            .get_selected_connection()
+            .map(|connection| connection.database_type.clone())
+            .ok_or_else(|| "No connection selected".to_string())?;
+
+        let sql = change
+            .redo_sql(&database_type)
+            .ok_or_else(|| "the row has no primary key to find it by".to_string())?;
+        self.db
+            .execute_query(&sql, self.ui.selected_connection, &self.connection_manager)
+            .await?;
+
+        let tab_idx = self.table_viewer_state.active_tab;
+        if let Some(tab) = self.table_viewer_state.tabs.get_mut(tab_idx) {
+            tab.undo_stack.commit_redo();
+            tab.modified_cells.clear();
+        }
+        let _ = self.load_table_data(tab_idx).await;
+        Ok(Some(change.description()))
+    }
+
+    /// Run the statement behind a query result tab again (`r`), keeping its
+    /// sort and search
+    pub async fn rerun_tab_query(&mut self, tab_idx: usize) -> Result<(), String> {
+        let Some(origin) = self
+            .table_viewer_state
+            .tabs
+            .get(tab_idx)
+            .and_then(|tab| tab.origin.clone())
+        else {
+            return Ok(());
+        };
+        let (columns, rows) = self
+            .connection_manager
+            .execute_raw_query(&origin.connection_id, &origin.query)
+            .await
+            .map_err(|e| e.to_string())?;
+        let max_cell_bytes = self.table_viewer_state.max_cell_bytes;
+        if let Some(tab) = self.table_viewer_state.tabs.get_mut(tab_idx) {
+            tab.set_result_rows(&columns, rows, max_cell_bytes);
+            if let Some(stats) = tab.query_stats.as_mut() {
+                stats.rows = tab.total_rows as u64;
+            }
+        }
+        Ok(())
+    }
+
+    /// `Ctrl+M` on a MySQL or MariaDB connection: open `SHOW GLOBAL STATUS`
+    /// and `SHOW GLOBAL VARIABLES` in tabs of their own, or refresh them
+    pub async fn open_server_status(&mut self) {
+        let Some(connection) = self.get_selected_connection().cloned() else {
+            self.toast_manager.warning("No connection selected");
+            return;
+        };
+        if !matches!(
+            connection.database_type,
+            DatabaseType::MySQL | DatabaseType::MariaDB
+        ) {
+            self.toast_manager
+                .warning("Server status is only available for MySQL and MariaDB");
+            return;
+        }
+        if !connection.is_connected() {
+            self.toast_manager
+                .error(format!("Not connected to {}", connection.name));
+            return;
+        }
+
+        let mut first_tab = None;
+        for (title, query, counters) in crate::database::mysql::SERVER_STATUS_VIEWS {
+            let tab_idx = self
+                .table_viewer_state
+                .add_tab(format!("{title} ({})", connection.name));
+            let tab = &mut self.table_viewer_state.tabs[tab_idx];
+            tab.origin = Some(WatchedQuery {
+                connection_id: connection.id.clone(),
+                query: query.to_string(),
+            });
+            tab.highlighted_names = counters;
+            first_tab.get_or_insert(tab_idx);
+            if let Err(e) = self.rerun_tab_query(tab_idx).await {
+                self.toast_manager.error(format!("{query} failed: {e}"));
+                return;
+            }
+        }
+        // Status first: it holds the counters worth watching
+        if let Some(tab_idx) = first_tab {
+            self.table_viewer_state.active_tab = tab_idx;
+        }
+        self.ui.focused_pane = FocusedPane::TabularOutput;
+    }
+
+    /// Whether a popup, confirmation or overlay is taking keys; watched
+    /// tabs pause while one is open
+    pub fn is_modal_open(&self) -> bool {
+        self.ui.is_in_overlay()
+            || self.ui.confirmation_modal.is_some()
+            || self.table_viewer_state.delete_confirmation.is_some()
+            || self.table_viewer_state.set_null_confirmation.is_some()
+            || self.connection_import.is_some()
+            || self.csv_import.is_some()
+            || self.index_modal.is_some()
+            || self.table_context_menu.is_some()
+            || self.command_palette.is_some()
+            || self.quick_switcher.is_some()
+            || self.snippet_picker.is_some()
+            || self.theme_gallery.is_some()
+            || self.lock_monitor.is_some()
+            || self.bookmark_list.is_some()
+            || self.connection_template_list.is_some()
+            || self.name_confirmation.is_some()
+            || self.rename_prompt.is_some()
+            || self.unlock_prompt.is_some()
+            || self.table_viewer_state.hex_view.is_some()
+            || self.table_viewer_state.json_view.is_some()
+            || self.table_viewer_state.cell_view.is_some()
+            || self.table_viewer_state.cell_editor.is_some()
+            || self.table_viewer_state.copy_format_menu.is_some()
+    }
+
+    /// Refit cached viewports and scroll offsets to a terminal of this size.
+    /// Below the minimum size only the size warning is drawn, so nothing is
+    /// refitted until it grows back.
+    pub fn handle_resize(&mut self, width: u16, height: u16) {
+        let area = ratatui::layout::Rect::new(0, 0, width, height);
+        if !self.layout.is_size_valid(area) {
+            return;
+        }
+        let areas = self.layout.calculate_layout(area);
+        self.ui
+            .reflow_details(areas.details.height.saturating_sub(2) as usize);
+        self.table_viewer_state.reflow(areas.tabular_output);
+    }
+
+    /// Reload a watched table tab, keeping the cursor where it was
+    pub async fn refresh_watched_table(&mut self, tab_idx: usize) -> Result<(), String> {
+        if let Some(tab) = self.table_viewer_state.tabs.get_mut(tab_idx) {
+            // Recorded changes may no longer match the reloaded rows
+            tab.undo_stack.clear();
+        }
+        let result = self.load_table_data(tab_idx).await;
+        if let Some(tab) = self.table_viewer_state.tabs.get_mut(tab_idx) {
+            tab.clamp_selection();
+        }
+        result
+    }
+
+    /// Fetch the full values of cells in `row` of the current tab that were cut
+    /// for display: just `col`, or every cut cell of the row when None
+    pub async fn load_full_cells(&mut self, row: usize, col: Option<usize>) -> Result<(), String> {
+        let Some(tab) = self.table_viewer_state.current_tab() else {
+            return Ok(());
+        };
+        let mut cut: Vec<(usize, usize)> = tab
+            .truncated_cells
+            .iter()
+            .filter(|(&(cut_row, cut_col), _)| cut_row == row && col.is_none_or(|c| c == cut_col))
+            .map(|(&(_, cut_col), &full_len)| (cut_col, full_len))
+            .collect();
+        if cut.is_empty() {
+            return Ok(());
+        }
+        cut.sort_unstable();
+
+        let table_name = tab.table_name.clone();
+        let primary_key_values = tab.get_primary_key_values(row);
+        let column_names: Vec<String> = tab.columns.iter().map(|c| c.name.clone()).collect();
+        for (cut_col, full_len) in cut {
+            let value = self
+                .db
+                .fetch_cell_value(
+                    &table_name,
+                    &column_names[cut_col],
+                    &primary_key_values,
+                    self.ui.selected_connection,
+                    &self.connection_manager,
+                )
+                .await
+                .map_err(|e| {
+                    format!(
+                        "'{}' is cut at {} of {} bytes and {e}",
+                        column_names[cut_col], self.table_viewer_state.max_cell_bytes, full_len
+                    )
+                })?;
+            if let Some(tab) = self.table_viewer_state.current_tab_mut() {
+                tab.restore_full_value(row, cut_col, value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Show the rows of a re-run query in the tab watched by `watch_id`
+    pub fn apply_watch_rows(
+        &mut self,
+        watch_id: u64,
+        columns: &[String],
+        rows: Vec<Vec<Option<String>>>,
+    ) {
+        let max_cell_bytes = self.table_viewer_state.max_cell_bytes;
+        if let Some(tab) = self
+            .table_viewer_state
+            .tabs
+            .iter_mut()
+            .find(|tab| tab.watch.as_ref().is_some_and(|watch| watch.id == watch_id))
+        {
+            tab.set_result_rows(columns, rows, max_cell_bytes);
+            if let Some(stats) = tab.query_stats.as_mut() {
+                stats.rows = tab.total_rows as u64;
+            }
+        }
+    }
+
+    /// Record how a watch refresh went. Only the first failure in a row gets
+    /// a toast; `MAX_WATCH_FAILURES` in a row stop the watch.
+    pub fn finish_watch_refresh(&mut self, watch_id: u64, outcome: Result<(), String>) {
+        // The watch may have been turned off or its tab closed meanwhile
+        let Some(tab) = self
+            .table_viewer_state
+            .tabs
+            .iter_mut()
+            .find(|tab| tab.watch.as_ref().is_some_and(|watch| watch.id == watch_id))
+        else {
+            return;
+        };
+        let Some(watch) = tab.watch.as_mut() else {
+            return;
+        };
+
+        match outcome {
+            Ok(()) => watch.succeed(),
+            Err(e) => match watch.fail() {
+                WatchFailure::Report => {
+                    self.toast_manager
+                        .error(format!("Refreshing {} failed: {e}", tab.table_name));
+                }
+                WatchFailure::Quiet => {}
+                WatchFailure::Stop => {
+                    tab.watch = None;
+                    self.toast_manager.error(format!(
+                        "Stopped watching {} after {MAX_WATCH_FAILURES} failed refreshes: {e}",
+                        tab.table_name
+                    ));
+                }
+            },
+        }
+    }
+
+    /// Reload current table tab data
+    pub async fn reload_current_table_tab(&mut self) -> Result<(), String> {
+        if let Some(tab_idx) = self
+            .table_viewer_state
+            .tabs
+            .get(self.table_viewer_state.active_tab)
+            .map(|_| self.table_viewer_state.active_tab)
+        {
+            // Recorded changes may no longer match the reloaded rows
+            self.table_viewer_state.tabs[tab_idx].undo_stack.clear();
+            self.load_table_data(tab_idx).await
+        } else {
+            Ok(())
+        }
     }
 
     /// Get the SQL statement under the cursor
@@ -1945,6 +3106,97 @@ impl AppState {
         self.ui.query_viewport_offset = 0;
     }
 
+    /// Split `main_content` in two with an empty editor and no results tabs
+    /// in the new split. Asking for the current orientation again joins the
+    /// splits back, keeping the focused one.
+    pub fn toggle_split(&mut self, mode: SplitMode) {
+        if self.split.is_some() {
+            if self.layout.split_mode() == mode {
+                self.close_other_split();
+            } else {
+                self.layout.set_split_mode(mode);
+            }
+            return;
+        }
+
+        let mut query_editor = self.query_editor.clone();
+        query_editor.set_content(String::new());
+        query_editor.set_current_file(None);
+        query_editor.set_insert_mode(false);
+        query_editor.set_focused(false);
+        self.split = Some(SplitPane {
+            table_viewer_state: TableViewerState::new(),
+            query_editor,
+            query_content: String::new(),
+            current_sql_file: None,
+            query_modified: false,
+            selected_connection: self.ui.selected_connection,
+        });
+        self.layout.set_split_mode(mode);
+        self.toast_manager
+            .info("Split: Ctrl+W Ctrl+W switches, Ctrl+W q closes the other split");
+    }
+
+    /// Move focus to the other split, bringing back its tabs, editor and connection
+    pub fn focus_other_split(&mut self) {
+        if self.split.is_none() {
+            self.toast_manager
+                .info("No split open (Ctrl+W v or Ctrl+W s)");
+            return;
+        }
+        self.query_content = self.query_editor.get_content().to_string();
+        self.query_editor.set_focused(false);
+        self.swap_split();
+        self.layout.toggle_split_focus();
+        self.query_editor
+            .set_focused(self.ui.focused_pane == FocusedPane::QueryWindow);
+        self.update_query_editor_context();
+    }
+
+    /// Close the split without focus, dropping its results tabs
+    pub fn close_other_split(&mut self) {
+        if self.split.take().is_none() {
+            self.toast_manager.info("No split open");
+            return;
+        }
+        // A query still running in the closed split reports here instead
+        if let Some(running) = &mut self.running_query {
+            running.split = 0;
+        }
+        self.layout.set_split_mode(SplitMode::None);
+    }
+
+    /// Run `f` with the split without focus swapped in, e.g. to draw it.
+    /// None when `main_content` isn't split.
+    pub fn with_other_split<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> Option<R> {
+        self.split.as_ref()?;
+        self.swap_split();
+        // Neither pane of the other split draws as focused
+        let focused_pane = std::mem::replace(&mut self.ui.focused_pane, FocusedPane::Connections);
+        let result = f(self);
+        self.ui.focused_pane = focused_pane;
+        self.swap_split();
+        Some(result)
+    }
+
+    /// Exchange the focused split's state with the stored one
+    fn swap_split(&mut self) {
+        let Some(split) = self.split.as_mut() else {
+            return;
+        };
+        std::mem::swap(&mut self.table_viewer_state, &mut split.table_viewer_state);
+        std::mem::swap(&mut self.query_editor, &mut split.query_editor);
+        std::mem::swap(&mut self.query_content, &mut split.query_content);
+        std::mem::swap(&mut self.ui.current_sql_file, &mut split.current_sql_file);
+        std::mem::swap(&mut self.ui.query_modified, &mut split.query_modified);
+        std::mem::swap(
+            &mut self.ui.selected_connection,
+            &mut split.selected_connection,
+        );
+        self.ui
+            .update_connection_selection(self.db.connections.connections.len());
+    }
+
     /// Update query editor database context when connection changes
     pub fn update_query_editor_context(&mut self) {
         if let Some(connection) = self.get_selected_connection() {
@@ -2011,22 +3263,22 @@ impl AppState {
         Ok(())
     }
 
-    /// Execute the SQL statement at cursor position
-    pub async fn execute_query_at_cursor(&mut self) -> Result<(), String> {
-        // First, ensure we have a connected database
-        let selected_connection_idx = self.ui.selected_connection;
-
-        // Check if we have a valid connection
-        if selected_connection_idx >= self.db.connections.connections.len() {
-            self.toast_manager.error("No connection selected");
-            return Err("No connection selected".to_string());
+    /// Half-typed key sequence for the status bar, like Vim's `showcmd`
+    pub fn pending_keys(&self) -> Option<String> {
+        if self.ui.focused_pane == FocusedPane::QueryWindow {
+            if let Some(keys) = self.query_editor.pending_keys() {
+                return Some(keys);
+            }
         }
+        self.hotkeys
+            .pending_keys()
+            .or_else(|| self.ui.pending_gg_command.then(|| "g".to_string()))
+    }
 
-        let connection = &self.db.connections.connections[selected_connection_idx];
-        if !connection.is_connected() {
-            self.toast_manager.error("Not connected to database");
-            return Err("Not connected to database".to_string());
-        }
+    /// Validate the SQL statement at the cursor and mark it as running.
+    /// Returns the connection ID and query for the background task.
+    pub fn start_query_at_cursor(&mut self) -> Result<(String, String), String> {
+        let (connection_id, connection_name) = self.query_connection()?;
 
         // Get the SQL statement at cursor position
         let query = match self.query_editor.get_statement_at_cursor() {
@@ -2043,10 +3295,18 @@ impl AppState {
             return Err("Empty query".to_string());
         }
 
-        // Get the active connection from the connection manager
-        let connection_id = &connection.id;
+        let query = match substitute_query_vars(&query, &self.query_vars) {
+            Ok(query) => query,
+            Err(missing) => {
+                let message = format!(
+                    "Query variables not set: {} (Ctrl+Shift+V to set them)",
+                    missing.join(", ")
+                );
+                self.toast_manager.error(&message);
+                return Err(message);
+            }
+        };
 
-        // Execute the query
         self.toast_manager.info(format!(
             "Executing query: {}",
             if query.len() > 50 {
@@ -2063,52 +3323,262 @@ impl AppState {
             format!("Starting query execution: {}", query),
         );
 
-        match self
-            .connection_manager
-            .execute_raw_query(connection_id, &query)
-            .await
-        {
-            Ok((columns, rows)) => {
+        let mut running = RunningQuery::new(query.clone(), connection_id.clone(), connection_name);
+        running.split = self.layout.focused_split();
+        running.editor_origin = self
+            .query_editor
+            .statement_start_at_cursor()
+            .unwrap_or_default();
+        self.running_query = Some(running);
+        Ok((connection_id, query))
+    }
+
+    /// Split `script`, which starts on editor line `first_line`, into
+    /// statements and mark it as running. Returns the connection ID and
+    /// statements for the background task.
+    pub fn start_batch(
+        &mut self,
+        script: &str,
+        first_line: usize,
+        separator: &str,
+    ) -> Result<(String, Vec<SqlStatement>), String> {
+        let (connection_id, connection_name) = self.query_connection()?;
+
+        let script = match substitute_query_vars(script, &self.query_vars) {
+            Ok(script) => script,
+            Err(missing) => {
+                let message = format!(
+                    "Query variables not set: {} (Ctrl+Shift+V to set them)",
+                    missing.join(", ")
+                );
+                self.toast_manager.error(&message);
+                return Err(message);
+            }
+        };
+        let mut statements = split_statements(&script, separator);
+        for statement in &mut statements {
+            statement.line += first_line;
+        }
+        if statements.is_empty() {
+            self.toast_manager.warning("No SQL statements to run");
+            return Err("No SQL statements to run".to_string());
+        }
+
+        self.toast_manager
+            .info(format!("Running {} statements", statements.len()));
+        crate::logging::add_debug_message(
+            "INFO",
+            "query_execution",
+            format!("Starting script of {} statements", statements.len()),
+        );
+
+        let mut running = RunningQuery::new(script, connection_id.clone(), connection_name);
+        running.split = self.layout.focused_split();
+        self.running_query = Some(running);
+        Ok((connection_id, statements))
+    }
+
+    /// Connection ID and name to run editor queries on, once no other query
+    /// is running
+    fn query_connection(&mut self) -> Result<(String, String), String> {
+        if let Some(running) = &self.running_query {
+            let elapsed = format_elapsed(running.started_at.elapsed());
+            self.toast_manager
+                .warning(format!("A query is already running ({elapsed})"));
+            return Err("A query is already running".to_string());
+        }
+
+        // First, ensure we have a connected database
+        let selected_connection_idx = self.ui.selected_connection;
+
+        // Check if we have a valid connection
+        if selected_connection_idx >= self.db.connections.connections.len() {
+            self.toast_manager.error("No connection selected");
+            return Err("No connection selected".to_string());
+        }
+
+        let connection = &self.db.connections.connections[selected_connection_idx];
+        if !connection.is_connected() {
+            self.toast_manager.error("Not connected to database");
+            return Err("Not connected to database".to_string());
+        }
+
+        Ok((connection.id.clone(), connection.name.clone()))
+    }
+
+    /// List each statement of a finished script in a summary tab
+    fn show_batch_results(
+        &mut self,
+        results: Vec<StatementResult>,
+        elapsed: std::time::Duration,
+        connection_name: String,
+    ) {
+        let (columns, rows) = batch_summary(&results);
+        let tab_name = format!("Run All ({})", chrono::Local::now().format("%H:%M:%S"));
+        let tab_index = self.table_viewer_state.add_tab(tab_name);
+        if let Some(tab) = self.table_viewer_state.tabs.get_mut(tab_index) {
+            tab.columns = columns
+                .iter()
+                .map(|name| crate::ui::components::ColumnInfo {
+                    name: name.clone(),
+                    data_type: "TEXT".to_string(),
+                    is_nullable: true,
+                    is_primary_key: false,
+                    max_display_width: if name == "Statement" || name == "Status" {
+                        40
+                    } else {
+                        name.len().clamp(6, 12)
+                    },
+                })
+                .collect();
+            tab.total_rows = rows.len();
+            tab.rows = rows;
+            tab.loading = false;
+            tab.refresh_column_widths();
+            tab.error = None;
+            tab.query_stats = Some(QueryStats {
+                elapsed,
+                rows: results.len() as u64,
+                affected: false,
+                estimated_rows: None,
+                connection_name,
+            });
+        }
+        self.ui.focused_pane = FocusedPane::TabularOutput;
+
+        let ran = results
+            .iter()
+            .filter(|result| result.status != StatementStatus::Skipped)
+            .count();
+        match results.iter().position(StatementResult::is_failed) {
+            Some(index) => {
+                let failures = results.iter().filter(|result| result.is_failed()).count();
+                self.toast_manager.error(format!(
+                    "{failures} of {ran} statements failed (first: #{}) in {}",
+                    index + 1,
+                    format_elapsed(elapsed)
+                ));
+            }
+            None => self.toast_manager.success(format!(
+                "Ran {ran} statements in {}",
+                format_elapsed(elapsed)
+            )),
+        }
+        crate::logging::add_debug_message(
+            "INFO",
+            "query_execution",
+            format!(
+                "Script finished in {}: {ran} of {} statements run",
+                format_elapsed(elapsed),
+                results.len()
+            ),
+        );
+    }
+
+    /// Show the outcome of the query started by `start_query_at_cursor`
+    pub fn finish_query(
+        &mut self,
+        output: crate::core::error::Result<QueryOutput>,
+    ) -> Result<(), String> {
+        // Results belong to the split the query was run from
+        let elsewhere = self.split.is_some()
+            && self
+                .running_query
+                .as_ref()
+                .is_some_and(|running| running.split != self.layout.focused_split());
+        if !elsewhere {
+            return self.finish_query_in_focused_split(output);
+        }
+        self.swap_split();
+        let focused_pane = self.ui.focused_pane;
+        let result = self.finish_query_in_focused_split(output);
+        self.ui.focused_pane = focused_pane;
+        self.swap_split();
+        result
+    }
+
+    fn finish_query_in_focused_split(
+        &mut self,
+        output: crate::core::error::Result<QueryOutput>,
+    ) -> Result<(), String> {
+        let Some(running) = self.running_query.take() else {
+            return Ok(());
+        };
+        let elapsed = running.started_at.elapsed();
+        let query = running.query;
+        self.last_query_result = None;
+
+        match output {
+            Ok(QueryOutput::Batch(results)) => {
+                self.show_batch_results(results, elapsed, running.connection_name);
+                Ok(())
+            }
+            Ok(output) => {
+                let (columns, rows, affected) = match output {
+                    QueryOutput::Rows(columns, rows) => {
+                        // Display cuts long values, so `:copy` keeps its own copy
+                        self.last_query_result = Some((columns.clone(), rows.clone()));
+                        (columns, rows, None)
+                    }
+                    QueryOutput::Affected(count) => (Vec::new(), Vec::new(), Some(count)),
+                    QueryOutput::Batch(_) => unreachable!("scripts are handled above"),
+                };
+
                 // Create a new table tab or update existing one
                 let tab_name =
                     format!("Query Result ({})", chrono::Local::now().format("%H:%M:%S"));
 
                 let tab_index = self.table_viewer_state.add_tab(tab_name);
+                let max_cell_bytes = self.table_viewer_state.max_cell_bytes;
 
                 if let Some(tab) = self.table_viewer_state.tabs.get_mut(tab_index) {
-                    // Convert string columns to ColumnInfo
-                    tab.columns = columns
-                        .iter()
-                        .map(|col_name| {
-                            crate::ui::components::ColumnInfo {
-                                name: col_name.clone(),
-                                data_type: "TEXT".to_string(), // Default type
-                                is_nullable: true,
-                                is_primary_key: false,
-                                max_display_width: col_name.len().clamp(10, 30),
-                            }
-                        })
-                        .collect();
-
-                    tab.rows = rows;
-                    tab.total_rows = tab.rows.len();
-                    tab.loading = false;
-                    tab.error = None;
+                    tab.set_result_rows(&columns, rows, max_cell_bytes);
+                    // Only row-returning statements are safe to re-run from watch mode
+                    tab.origin = affected.is_none().then(|| WatchedQuery {
+                        connection_id: running.connection_id.clone(),
+                        query: query.clone(),
+                    });
+                    tab.query_stats = Some(QueryStats {
+                        elapsed,
+                        rows: affected.unwrap_or(tab.total_rows as u64),
+                        affected: affected.is_some(),
+                        estimated_rows: None,
+                        connection_name: running.connection_name,
+                    });
                 }
 
                 // Switch focus to the results pane
                 self.ui.focused_pane = FocusedPane::TabularOutput;
 
+                if let Some(pinned) = &self.table_viewer_state.pinned_result {
+                    self.toast_manager.info(format!(
+                        "Press D to compare with pinned results from {}",
+                        pinned.source
+                    ));
+                } else if let Some((previous, _)) =
+                    self.table_viewer_state.other_run_of_current_query()
+                {
+                    self.toast_manager.info(format!(
+                        "Press D to compare with the previous run in {}",
+                        previous.table_name
+                    ));
+                }
+
                 let row_count = self
                     .table_viewer_state
                     .tabs
                     .get(tab_index)
                     .map(|t| t.total_rows)
                     .unwrap_or(0);
+                let outcome = match affected {
+                    Some(count) => format!("{count} rows affected"),
+                    None => format!("{row_count} rows returned"),
+                };
 
                 self.toast_manager.success(format!(
-                    "Query executed successfully ({} rows returned): {}",
-                    row_count,
+                    "Query executed successfully ({}, {}): {}",
+                    outcome,
+                    format_elapsed(elapsed),
                     if query.len() > 40 {
                         format!("{}...", &query[..40])
                     } else {
@@ -2121,8 +3591,9 @@ impl AppState {
                     "INFO",
                     "query_execution",
                     format!(
-                        "Query executed successfully: {} rows returned, {} columns | Query: {}",
-                        row_count,
+                        "Query executed successfully in {}: {}, {} columns | Query: {}",
+                        format_elapsed(elapsed),
+                        outcome,
                         columns.len(),
                         query
                     ),
@@ -2130,28 +3601,442 @@ impl AppState {
 
                 Ok(())
             }
+            Err(e) if running.cancelling => {
+                self.toast_manager
+                    .info(format!("Query cancelled after {}", format_elapsed(elapsed)));
+                crate::logging::add_debug_message(
+                    "INFO",
+                    "query_execution",
+                    format!(
+                        "Query cancelled after {}: {} | Query: {}",
+                        format_elapsed(elapsed),
+                        e,
+                        query
+                    ),
+                );
+                Err("Query cancelled".to_string())
+            }
             Err(e) => {
-                self.toast_manager.error(format!(
-                    "Query execution failed: {} | Query: {}",
-                    e,
-                    if query.len() > 30 {
-                        format!("{}...", &query[..30])
-                    } else {
-                        query.clone()
-                    }
-                ));
+                // The full text goes to an error tab; the toast only points at it
+                let error = QueryError::new(&e, query.clone(), running.editor_origin);
+                let title = error.title();
+                self.table_viewer_state.add_error_tab(error);
+                self.ui.focused_pane = FocusedPane::TabularOutput;
+                self.toast_manager.error(format!("Query failed - {title}"));
 
                 // Add debug message for failed query execution
                 crate::logging::add_debug_message(
                     "ERROR",
                     "query_execution",
-                    format!("Query execution failed: {} | Query: {}", e, query),
+                    format!(
+                        "Query execution failed after {}: {} | Query: {}",
+                        format_elapsed(elapsed),
+                        e,
+                        query
+                    ),
                 );
 
                 Err(e.to_string())
             }
         }
     }
+
+    // === WORKSPACES ===
+
+    /// Capture the current session as a workspace
+    pub fn capture_workspace(&self, name: &str) -> Workspace {
+        Workspace {
+            name: name.to_string(),
+            created_at: chrono::Local::now(),
+            connections: self
+                .db
+                .connections
+                .connections
+                .iter()
+                .filter(|c| c.is_connected())
+                .map(|c| c.name.clone())
+                .collect(),
+            // Query results, diffs and routine sources can't be reopened, only table tabs are kept
+            tables: self
+                .table_viewer_state
+                .tabs
+                .iter()
+                .filter(|tab| {
+                    tab.diff_kinds.is_empty()
+                        && tab.view_mode != TableViewMode::Source
+                        && !tab.table_name.starts_with("Query Result (")
+                        && !tab.table_name.starts_with("Diff (")
+                })
+                .map(|tab| tab.table_name.clone())
+                .collect(),
+            active_tab: self.table_viewer_state.active_tab,
+            sql_file: self.query_editor.get_current_file().cloned(),
+            query_content: self.query_editor.get_content().to_string(),
+            focused_pane: match self.ui.focused_pane {
+                FocusedPane::Routines => FocusedPane::Tables,
+                pane => pane,
+            },
+        }
+    }
+
+    /// Save the current session as a named workspace
+    pub fn save_workspace(&mut self, name: &str) {
+        match self.capture_workspace(name).save() {
+            Ok(()) => {
+                self.current_workspace = Some(name.to_string());
+                self.toast_manager
+                    .success(format!("Workspace '{name}' saved"));
+            }
+            Err(e) => {
+                self.toast_manager
+                    .error(format!("Failed to save workspace: {e}"));
+            }
+        }
+    }
+
+    /// Remember `table_name` in the connection's recently opened tables
+    async fn record_recent_table(&self, table_name: &str) {
+        if let Some(connection) = self
+            .get_selected_connection()
+            .filter(|connection| connection.is_connected())
+        {
+            let _ = self
+                .app_state_db
+                .record_recent_table(&connection.id, table_name)
+                .await;
+        }
+    }
+
+    /// Open the `Ctrl+T` quick switcher: open tabs, the Tables pane listing,
+    /// then the connection's recently opened tables
+    pub async fn open_quick_switcher(&mut self) {
+        use crate::ui::components::{QuickSwitcher, SwitcherEntry, SwitcherSource};
+
+        let open_tabs = self
+            .table_viewer_state
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(index, tab)| SwitcherEntry {
+                name: tab.table_name.clone(),
+                object_type: tab.object_type.clone(),
+                source: SwitcherSource::OpenTab {
+                    index,
+                    row_count: tab.total_rows,
+                },
+            })
+            .collect();
+        let tables = self
+            .ui
+            .selectable_table_items
+            .iter()
+            .filter(|item| item.is_selectable)
+            .map(|item| SwitcherEntry {
+                name: item.qualified_name(),
+                object_type: item.object_type.clone(),
+                source: SwitcherSource::Table,
+            })
+            .collect();
+
+        let recent_names = match self
+            .get_selected_connection()
+            .filter(|connection| connection.is_connected())
+        {
+            Some(connection) => self
+                .app_state_db
+                .get_recent_tables(&connection.id)
+                .await
+                .unwrap_or_default(),
+            None => Vec::new(),
+        };
+        let recent = recent_names
+            .into_iter()
+            .map(|name| SwitcherEntry {
+                object_type: self
+                    .db
+                    .database_objects
+                    .as_ref()
+                    .and_then(|objects| objects.object_type_of(&name))
+                    .unwrap_or(crate::database::DatabaseObjectType::Table),
+                name,
+                source: SwitcherSource::Recent,
+            })
+            .collect();
+
+        self.quick_switcher = Some(QuickSwitcher::new(open_tabs, tables, recent));
+    }
+
+    /// Focus an open tab, or open a table the same way as Enter in the Tables pane
+    pub async fn open_switcher_entry(&mut self, entry: crate::ui::components::SwitcherEntry) {
+        use crate::ui::components::SwitcherSource;
+
+        if let SwitcherSource::OpenTab { index, .. } = entry.source {
+            if index < self.table_viewer_state.tabs.len() {
+                self.table_viewer_state.active_tab = index;
+                self.ui.focused_pane = FocusedPane::TabularOutput;
+            }
+            return;
+        }
+
+        self.open_listed_table(&entry.name).await;
+    }
+
+    /// Select `name` in the Tables pane and open it as Enter there would
+    pub async fn open_listed_table(&mut self, name: &str) {
+        if self.ui.tables_search_active {
+            self.ui.exit_tables_search();
+        }
+        if self.ui.reveal_table(name, &self.db.database_objects) {
+            self.open_table_for_viewing().await;
+        } else {
+            self.toast_manager
+                .warning(format!("'{name}' is not listed in the Tables pane"));
+        }
+    }
+
+    /// Open the `Ctrl+J` snippet picker over `~/.lazytables/snippets.toml`
+    pub fn open_snippet_picker(&mut self) {
+        match SnippetStore::load() {
+            Ok(store) => {
+                self.snippet_picker =
+                    Some(crate::ui::components::SnippetPicker::new(store.snippets))
+            }
+            Err(e) => self
+                .toast_manager
+                .error(format!("Failed to load snippets: {e}")),
+        }
+    }
+
+    /// Save `body` as a snippet, replacing one with the same name
+    pub fn save_snippet(&mut self, name: &str, description: &str, body: String) {
+        let result = SnippetStore::load().and_then(|mut store| {
+            let replaced = store.upsert(Snippet {
+                name: name.to_string(),
+                description: description.to_string(),
+                body,
+            })?;
+            store.save()?;
+            Ok(replaced)
+        });
+        match result {
+            Ok(true) => self
+                .toast_manager
+                .success(format!("Snippet '{name}' updated")),
+            Ok(false) => self
+                .toast_manager
+                .success(format!("Snippet '{name}' saved")),
+            Err(e) => self
+                .toast_manager
+                .error(format!("Failed to save snippet: {e}")),
+        }
+    }
+
+    /// Open the `'` bookmarks overlay over `~/.lazytables/bookmarks.toml`
+    pub fn open_bookmark_list(&mut self) {
+        match BookmarkStore::load() {
+            Ok(store) => {
+                self.bookmark_list = Some(crate::ui::components::BookmarkList::new(store.bookmarks))
+            }
+            Err(e) => self
+                .toast_manager
+                .error(format!("Failed to load bookmarks: {e}")),
+        }
+    }
+
+    /// Bookmark the table or SQL file selected in the focused pane, or remove
+    /// its bookmark. Returns the message to show.
+    pub fn toggle_bookmark(&mut self) -> Result<String, String> {
+        let connection = self
+            .get_selected_connection()
+            .ok_or("No connection selected")?;
+        let (kind, name) = match self.ui.focused_pane {
+            FocusedPane::Tables => {
+                if !connection.is_connected() {
+                    return Err(format!("Not connected to {}", connection.name));
+                }
+                let name = self
+                    .ui
+                    .get_selected_table_name()
+                    .ok_or("No table selected")?;
+                (BookmarkKind::Table, name)
+            }
+            FocusedPane::SqlFiles => {
+                let name = self.get_selected_sql_file().ok_or("No SQL file selected")?;
+                (BookmarkKind::SqlFile, name)
+            }
+            _ => return Err("Select a table or SQL file to bookmark".to_string()),
+        };
+        let bookmark = Bookmark {
+            kind,
+            name: name.clone(),
+            connection_id: connection.id.clone(),
+            connection_name: connection.name.clone(),
+            label: String::new(),
+        };
+
+        let mut store =
+            BookmarkStore::load().map_err(|e| format!("Failed to load bookmarks: {e}"))?;
+        let added = store.toggle(bookmark);
+        store
+            .save()
+            .map_err(|e| format!("Failed to save bookmarks: {e}"))?;
+        Ok(if added {
+            format!("Bookmarked {name}")
+        } else {
+            format!("Removed bookmark {name}")
+        })
+    }
+
+    /// Write the overlay's bookmarks back after a label change or removal
+    pub fn save_bookmark_list(&mut self) {
+        let Some(list) = &self.bookmark_list else {
+            return;
+        };
+        let store = BookmarkStore {
+            bookmarks: list.bookmarks.clone(),
+        };
+        if let Err(e) = store.save() {
+            self.toast_manager
+                .error(format!("Failed to save bookmarks: {e}"));
+        }
+    }
+
+    /// Open a bookmark on the selected connection, which must be connected
+    /// (and listed, for a table)
+    pub async fn open_bookmark_now(&mut self, bookmark: Bookmark) {
+        match bookmark.kind {
+            BookmarkKind::Table => self.open_listed_table(&bookmark.name).await,
+            BookmarkKind::SqlFile => match self.load_query_file(&bookmark.name) {
+                Ok(()) => {
+                    self.ui.focused_pane = FocusedPane::QueryWindow;
+                    self.toast_manager
+                        .success(format!("Opened {}", bookmark.display_name()));
+                }
+                Err(e) => self
+                    .toast_manager
+                    .error(format!("Failed to load SQL file: {e}")),
+            },
+        }
+    }
+
+    /// Open the workspace browser overlay
+    pub fn open_workspace_browser(&mut self) {
+        self.workspace_browser.refresh();
+        self.ui
+            .show_overlay(crate::state::view::OverlayView::Workspaces);
+    }
+
+    /// Disconnect everything and restore a saved workspace
+    pub async fn restore_workspace(&mut self, name: &str) {
+        let workspace = match Workspace::load(name) {
+            Ok(workspace) => workspace,
+            Err(e) => {
+                self.toast_manager
+                    .error(format!("Failed to load workspace: {e}"));
+                return;
+            }
+        };
+
+        // Close every open connection so nothing leaks into the restored session
+        let connected: Vec<String> = self
+            .db
+            .connections
+            .connections
+            .iter()
+            .filter(|c| c.is_connected())
+            .map(|c| c.id.clone())
+            .collect();
+        for connection_id in &connected {
+            let _ = self.connection_manager.disconnect(connection_id).await;
+        }
+        for connection in self.db.connections.connections.iter_mut() {
+            if connection.is_connected() {
+                connection.status = ConnectionStatus::Disconnected;
+            }
+        }
+        self.db.database_objects = None;
+        self.db.tables.clear();
+        self.db.table_load_error = None;
+        self.db.current_table_metadata = None;
+        self.ui.build_selectable_table_items(&None);
+        self.table_viewer_state = TableViewerState::new();
+        self.reset_query_editor();
+        let _ = self.app_state_db.clear_active_connection().await;
+
+        let mut skipped = Vec::new();
+
+        // Only one connection can be active at a time
+        if let Some(connection_name) = workspace.connections.first() {
+            match self
+                .db
+                .connections
+                .connections
+                .iter()
+                .position(|c| &c.name == connection_name)
+            {
+                Some(index) => {
+                    self.ui.exit_connections_search();
+                    self.ui.selected_connection = index;
+                    self.ui
+                        .update_connection_selection(self.db.connections.connections.len());
+                    self.connect_to_selected_database().await;
+                }
+                None => skipped.push(format!("connection '{connection_name}'")),
+            }
+        }
+
+        let connected = self
+            .get_selected_connection()
+            .is_some_and(|c| c.is_connected());
+
+        if connected {
+            for table_name in &workspace.tables {
+                let tab_idx = self.table_viewer_state.add_tab(table_name.clone());
+                if let Err(e) = self.load_table_data(tab_idx).await {
+                    if let Some(tab) = self.table_viewer_state.tabs.get_mut(tab_idx) {
+                        tab.error = Some(format!("Failed to load table: {e}"));
+                        tab.loading = false;
+                    }
+                }
+            }
+            if !self.table_viewer_state.tabs.is_empty() {
+                self.table_viewer_state.active_tab = workspace
+                    .active_tab
+                    .min(self.table_viewer_state.tabs.len() - 1);
+                let active_table = self.table_viewer_state.tabs[self.table_viewer_state.active_tab]
+                    .table_name
+                    .clone();
+                let _ = self.load_table_metadata(&active_table).await;
+            }
+
+            if let Some(file) = &workspace.sql_file {
+                if self.load_sql_file_into_editor(file).is_err() {
+                    skipped.push(format!("SQL file '{file}'"));
+                }
+            }
+        } else {
+            skipped.extend(workspace.tables.iter().map(|t| format!("table '{t}'")));
+        }
+
+        // Saved content wins over the file on disk so unsaved edits come back too
+        if self.query_editor.get_content() != workspace.query_content {
+            self.set_query_content(workspace.query_content.clone());
+        }
+
+        self.ui.focused_pane = workspace.focused_pane;
+        self.current_workspace = Some(workspace.name.clone());
+
+        if skipped.is_empty() {
+            self.toast_manager
+                .success(format!("Workspace '{}' restored", workspace.name));
+        } else {
+            self.toast_manager.warning(format!(
+                "Workspace '{}' restored without {}",
+                workspace.name,
+                skipped.join(", ")
+            ));
+        }
+    }
 }
 
 impl Default for AppState {
@@ -2181,6 +4066,26 @@ impl Default for AppState {
             toast_manager: ToastManager::new(),
             query_editor: QueryEditor::new(),
             debug_view: DebugView::new(),
+            toast_history: ToastHistoryPane::new(),
+            hotkeys: crate::app::shortcuts::HotkeyManager::new(),
+            workspace_browser: WorkspaceBrowser::new(),
+            current_workspace: None,
+            connection_import: None,
+            csv_import: None,
+            index_modal: None,
+            table_context_menu: None,
+            command_palette: None,
+            snippet_picker: None,
+            quick_switcher: None,
+            theme_gallery: None,
+            lock_monitor: None,
+            bookmark_list: None,
+            connection_template_list: None,
+            name_confirmation: None,
+            rename_prompt: None,
+            unlock_prompt: None,
+            pending_bookmark: None,
+            layout: crate::ui::layout::LayoutManager::new(),
             connection_mode: None,
             app_state_db: AppStateDb::new(),
             connection_manager: ConnectionManager::new(),
@@ -2190,7 +4095,86 @@ impl Default for AppState {
             connection_timeout_seconds: 30,
             test_connection_in_progress: false,
             test_animation_frame: 0,
+            test_timeout_seconds: 10,
             test_start_time: None,
+            running_query: None,
+            last_query_result: None,
+            backup: None,
+            query_vars: std::collections::BTreeMap::new(),
+            query_vars_panel: None,
+            split: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::error::LazyTablesError;
+    use crate::ui::components::ToastType;
+
+    #[test]
+    fn test_cancelled_query_drops_its_result() {
+        let mut state = AppState::default();
+        let mut running = RunningQuery::new(
+            "SELECT pg_sleep(60)".to_string(),
+            "4f1c".to_string(),
+            "prod".to_string(),
+        );
+        running.cancelling = true;
+        state.running_query = Some(running);
+        let tabs = state.table_viewer_state.tabs.len();
+
+        // The server reports the cancel as an error, which is not shown as one
+        let result = state.finish_query(Err(LazyTablesError::Other(
+            "canceling statement due to user request".to_string(),
+        )));
+        assert_eq!(result, Err("Query cancelled".to_string()));
+        assert!(state.running_query.is_none());
+        assert!(state.last_query_result.is_none());
+        assert_eq!(state.table_viewer_state.tabs.len(), tabs);
+
+        let toast = state.toast_manager.history().back().unwrap();
+        assert_eq!(toast.toast_type, ToastType::Info);
+        assert!(toast.message.starts_with("Query cancelled after"));
+    }
+
+    #[test]
+    fn test_pending_keys_follow_the_focused_pane() {
+        use crate::app::shortcuts::ActionContext;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut state = AppState::default();
+        assert_eq!(state.pending_keys(), None);
+
+        state.ui.pending_gg_command = true;
+        assert_eq!(state.pending_keys().as_deref(), Some("g"));
+        state.ui.pending_gg_command = false;
+
+        // A pane sequence such as `dd` waiting for its second key
+        let d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE);
+        state.hotkeys.resolve(ActionContext::TableViewer, d);
+        assert_eq!(state.pending_keys().as_deref(), Some("d"));
+        state.hotkeys.cancel_pending();
+
+        // The editor's own pending command only shows while it has focus
+        state.query_editor.handle_vim_command('g');
+        state.ui.focused_pane = FocusedPane::Connections;
+        assert_eq!(state.pending_keys(), None);
+        state.ui.focused_pane = FocusedPane::QueryWindow;
+        assert_eq!(state.pending_keys().as_deref(), Some("g"));
+    }
+
+    #[test]
+    fn test_preloaded_file_opens_as_an_unsaved_query() {
+        let mut state = AppState::default();
+        state.ui.current_sql_file = Some("report.sql".to_string());
+
+        state.preload_query("SELECT 1;\nSELECT 2;".to_string());
+        assert_eq!(state.query_editor.get_content(), "SELECT 1;\nSELECT 2;");
+        assert_eq!(state.query_content, "SELECT 1;\nSELECT 2;");
+        assert_eq!(state.ui.current_sql_file, None);
+        assert!(state.ui.query_modified);
+        assert_eq!(state.ui.focused_pane, FocusedPane::QueryWindow);
+    }
+}
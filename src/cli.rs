@@ -2,9 +2,15 @@
 
 #![forbid(unsafe_code)]
 
+mod connections_command;
+mod list_command;
+mod run_command;
 mod theme_commands;
 
 use clap::{Parser, Subcommand, ValueEnum};
+pub use connections_command::ConnectionsCommand;
+pub use list_command::{format_connections, list_connections};
+pub use run_command::{format_results, run_query, run_script, run_sql_file, HeadlessError};
 use std::path::PathBuf;
 pub use theme_commands::ThemeCommand;
 
@@ -21,25 +27,39 @@ pub struct Cli {
     #[arg(short, long, value_enum, default_value = "info")]
     pub log_level: LogLevel,
 
-    /// Connection string to connect immediately
-    #[arg(long)]
+    /// Saved connection (name or id) to connect to on launch, or that
+    /// `--execute` runs against
+    #[arg(long, visible_alias = "connect")]
     pub connection: Option<String>,
 
+    /// Run a query against `--connection`, print the result as CSV and exit
+    #[arg(short = 'e', long, value_name = "SQL", requires = "connection")]
+    pub execute: Option<String>,
+
+    /// SQL file to open in the query editor on launch
+    #[arg(
+        long,
+        visible_alias = "query",
+        value_name = "FILE",
+        conflicts_with = "execute"
+    )]
+    pub file: Option<PathBuf>,
+
     /// Database to select on startup
     #[arg(short = 'd', long)]
     pub database: Option<String>,
 
-    /// Table to view on startup
-    #[arg(short = 't', long)]
+    /// Table to open once `--connection` is up
+    #[arg(short = 't', long, requires = "connection")]
     pub table: Option<String>,
 
     /// Start in read-only mode
     #[arg(short = 'r', long)]
     pub read_only: bool,
 
-    /// Theme management commands
+    /// Subcommand to run instead of the TUI (theme, run, query, connections, list)
     #[command(subcommand)]
-    pub theme: Option<Commands>,
+    pub command: Option<Commands>,
 }
 
 #[derive(Debug, Subcommand)]
@@ -49,9 +69,68 @@ pub enum Commands {
         #[command(subcommand)]
         command: ThemeCommand,
     },
+
+    /// Execute a SQL file against a saved connection and exit
+    Run {
+        /// Path to the SQL file to execute
+        file: PathBuf,
+
+        /// Name of the saved connection to use
+        #[arg(short, long)]
+        connection: String,
+
+        /// Output format for result sets
+        #[arg(short, long, value_enum, default_value = "table")]
+        format: OutputFormat,
+    },
+
+    /// Run SQL against a saved connection and print the results, without the TUI.
+    /// Exits 2 when the connection fails and 3 when a statement fails
+    Query {
+        /// Name or id of the saved connection to use
+        #[arg(short, long)]
+        connection: String,
+
+        /// SQL file to run, split into statements like the query editor's "Run all"
+        #[arg(short, long, value_name = "FILE", required_unless_present = "execute")]
+        file: Option<PathBuf>,
+
+        /// SQL to run instead of a file
+        #[arg(short = 'e', long, value_name = "SQL", conflicts_with = "file")]
+        execute: Option<String>,
+
+        /// Output format for result sets
+        #[arg(short, long, value_enum, default_value = "table")]
+        output: OutputFormat,
+
+        /// Fail instead of asking on the terminal for an encryption key
+        #[arg(long)]
+        no_input: bool,
+    },
+
+    /// Export or import saved connections
+    Connections {
+        #[command(subcommand)]
+        command: ConnectionsCommand,
+    },
+
+    /// Print saved connections (passwords redacted) and exit
+    List {
+        /// Output format; `table` prints `name: type://user@host:port/db [status]`
+        #[arg(short, long, value_enum, default_value = "table")]
+        format: OutputFormat,
+    },
 }
 
-#[derive(Debug, Clone, Copy, ValueEnum)]
+/// Output format for headless command results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Csv,
+    Json,
+    Table,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum LogLevel {
     Trace,
     Debug,
@@ -71,3 +150,49 @@ impl From<LogLevel> for tracing::Level {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_startup_flags() {
+        let cli = Cli::try_parse_from([
+            "lazytables",
+            "--connect",
+            "prod",
+            "--table",
+            "public.users",
+            "--query",
+            "report.sql",
+        ])
+        .unwrap();
+        assert_eq!(cli.connection.as_deref(), Some("prod"));
+        assert_eq!(cli.table.as_deref(), Some("public.users"));
+        assert_eq!(cli.file, Some(PathBuf::from("report.sql")));
+
+        // A table needs a connection to open it on
+        assert!(Cli::try_parse_from(["lazytables", "--table", "users"]).is_err());
+    }
+
+    #[test]
+    fn test_execute_flags() {
+        let cli =
+            Cli::try_parse_from(["lazytables", "-e", "SELECT 1", "--connection", "prod"]).unwrap();
+        assert_eq!(cli.execute.as_deref(), Some("SELECT 1"));
+        assert_eq!(cli.connection.as_deref(), Some("prod"));
+
+        // A headless query needs a connection and doesn't open the editor
+        assert!(Cli::try_parse_from(["lazytables", "-e", "SELECT 1"]).is_err());
+        assert!(Cli::try_parse_from([
+            "lazytables",
+            "-e",
+            "SELECT 1",
+            "--connection",
+            "prod",
+            "--file",
+            "report.sql",
+        ])
+        .is_err());
+    }
+}
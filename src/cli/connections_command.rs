@@ -0,0 +1,295 @@
+// FilePath: src/cli/connections_command.rs
+
+#![forbid(unsafe_code)]
+
+use crate::core::error::{LazyTablesError, Result};
+use crate::database::{ConnectionConfig, ConnectionStorage};
+use crate::security::PasswordSource;
+use clap::Subcommand;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Subcommand)]
+pub enum ConnectionsCommand {
+    /// Write saved connections to a JSON file
+    Export {
+        /// File to write
+        file: PathBuf,
+
+        /// Also export plain-text passwords. Environment variable references
+        /// and encrypted passwords are always exported.
+        #[arg(long)]
+        include_secrets: bool,
+    },
+
+    /// Merge connections from a file written by `connections export`
+    Import {
+        /// File to read
+        file: PathBuf,
+    },
+}
+
+/// What to do with an imported connection whose name is already saved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportConflict {
+    Skip,
+    Overwrite,
+    Rename,
+}
+
+/// Counts reported after an import
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub added: usize,
+    pub overwritten: usize,
+    pub renamed: usize,
+    pub skipped: usize,
+}
+
+impl ConnectionsCommand {
+    pub async fn execute(&self) -> Result<()> {
+        match self {
+            ConnectionsCommand::Export {
+                file,
+                include_secrets,
+            } => {
+                let storage = ConnectionStorage::load().await?;
+                let export = export_storage(&storage, *include_secrets);
+                write_export(file, &export).await?;
+                println!(
+                    "Exported {} connections to {}",
+                    export.connections.len(),
+                    file.display()
+                );
+                if !include_secrets
+                    && storage
+                        .connections
+                        .iter()
+                        .any(|c| c.password.is_some() || is_plain_text(c))
+                {
+                    println!(
+                        "Plain-text passwords were left out; pass --include-secrets to keep them"
+                    );
+                }
+            }
+
+            ConnectionsCommand::Import { file } => {
+                let contents = crate::io::async_fs::read_to_string(file).await?;
+                let incoming: ConnectionStorage = serde_json::from_str(&contents).map_err(|e| {
+                    LazyTablesError::Config(format!("Invalid connections file: {e}"))
+                })?;
+
+                let mut storage = ConnectionStorage::load().await?;
+                let summary =
+                    merge_connections(&mut storage, incoming.connections, prompt_conflict);
+                storage.sort_favorites_first();
+                storage.save().await?;
+                println!(
+                    "Imported {} connections ({} overwritten, {} renamed, {} skipped)",
+                    summary.added + summary.overwritten + summary.renamed,
+                    summary.overwritten,
+                    summary.renamed,
+                    summary.skipped
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_plain_text(connection: &ConnectionConfig) -> bool {
+    matches!(
+        connection.password_source,
+        Some(PasswordSource::PlainText(_))
+    )
+}
+
+/// Copy of `storage` safe to hand to another machine: plain-text passwords
+/// (including the legacy `password` field) are dropped unless `include_secrets`
+pub fn export_storage(storage: &ConnectionStorage, include_secrets: bool) -> ConnectionStorage {
+    let mut export = storage.clone();
+    if !include_secrets {
+        for connection in &mut export.connections {
+            connection.password = None;
+            if is_plain_text(connection) {
+                connection.password_source = None;
+            }
+        }
+    }
+    export
+}
+
+async fn write_export(file: &Path, storage: &ConnectionStorage) -> Result<()> {
+    let json = serde_json::to_string_pretty(storage)
+        .map_err(|e| LazyTablesError::Config(format!("Failed to serialize connections: {e}")))?;
+    crate::io::async_fs::write(file, json).await?;
+    Ok(())
+}
+
+/// Merge `incoming` into `storage`, matching connections by name. `resolve`
+/// decides each name conflict. An overwrite keeps the saved connection's id,
+/// and its password when the imported one has none.
+pub fn merge_connections(
+    storage: &mut ConnectionStorage,
+    incoming: Vec<ConnectionConfig>,
+    mut resolve: impl FnMut(&ConnectionConfig) -> ImportConflict,
+) -> ImportSummary {
+    let mut summary = ImportSummary::default();
+
+    for mut connection in incoming {
+        let existing = storage
+            .connections
+            .iter()
+            .position(|c| c.name == connection.name);
+
+        if let Some(index) = existing {
+            match resolve(&connection) {
+                ImportConflict::Skip => {
+                    summary.skipped += 1;
+                    continue;
+                }
+                ImportConflict::Overwrite => {
+                    let saved = &storage.connections[index];
+                    connection.id = saved.id.clone();
+                    if connection.password_source.is_none() && connection.password.is_none() {
+                        connection.password_source = saved.password_source.clone();
+                        connection.password = saved.password.clone();
+                    }
+                    storage.connections[index] = connection;
+                    summary.overwritten += 1;
+                    continue;
+                }
+                ImportConflict::Rename => {
+                    connection.name = unused_name(storage, &connection.name);
+                    summary.renamed += 1;
+                }
+            }
+        } else {
+            summary.added += 1;
+        }
+
+        if storage.connections.iter().any(|c| c.id == connection.id) {
+            connection.id = uuid::Uuid::new_v4().to_string();
+        }
+        storage.connections.push(connection);
+    }
+
+    summary
+}
+
+/// `name (2)`, `name (3)`, ... whichever is free first
+fn unused_name(storage: &ConnectionStorage, name: &str) -> String {
+    (2..)
+        .map(|n| format!("{name} ({n})"))
+        .find(|candidate| !storage.connections.iter().any(|c| &c.name == candidate))
+        .unwrap_or_else(|| name.to_string())
+}
+
+/// Ask on the terminal; anything but `o` or `r` (including end of input) skips
+fn prompt_conflict(connection: &ConnectionConfig) -> ImportConflict {
+    print!(
+        "Connection '{}' already exists: [s]kip, [o]verwrite, [r]ename? ",
+        connection.name
+    );
+    let _ = std::io::stdout().flush();
+
+    let mut answer = String::new();
+    let _ = std::io::stdin().lock().read_line(&mut answer);
+    match answer.trim().to_lowercase().as_str() {
+        "o" | "overwrite" => ImportConflict::Overwrite,
+        "r" | "rename" => ImportConflict::Rename,
+        _ => ImportConflict::Skip,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::DatabaseType;
+
+    fn connection(name: &str, password: Option<PasswordSource>) -> ConnectionConfig {
+        let mut config = ConnectionConfig::new(
+            name.to_string(),
+            DatabaseType::PostgreSQL,
+            "localhost".to_string(),
+            5432,
+            "postgres".to_string(),
+        );
+        config.password_source = password;
+        config
+    }
+
+    #[test]
+    fn test_export_drops_plain_text_passwords() {
+        let storage = ConnectionStorage {
+            connections: vec![
+                connection(
+                    "plain",
+                    Some(PasswordSource::PlainText("hunter2".to_string())),
+                ),
+                connection(
+                    "env",
+                    Some(PasswordSource::Environment {
+                        var_name: "PGPASSWORD".to_string(),
+                    }),
+                ),
+            ],
+            ..Default::default()
+        };
+
+        let export = export_storage(&storage, false);
+        assert!(export.connections[0].password_source.is_none());
+        assert!(export.connections[1].password_source.is_some());
+        assert!(!serde_json::to_string(&export).unwrap().contains("hunter2"));
+
+        let export = export_storage(&storage, true);
+        assert!(is_plain_text(&export.connections[0]));
+    }
+
+    #[test]
+    fn test_merge_by_name() {
+        let mut storage = ConnectionStorage {
+            connections: vec![
+                connection("prod", Some(PasswordSource::PlainText("local".to_string()))),
+                connection("dev", None),
+            ],
+            ..Default::default()
+        };
+        let prod_id = storage.connections[0].id.clone();
+
+        let mut incoming_prod = connection("prod", None);
+        incoming_prod.host = "db.internal".to_string();
+        let incoming = vec![
+            incoming_prod,
+            connection("dev", None),
+            connection("staging", None),
+        ];
+
+        let summary = merge_connections(&mut storage, incoming, |c| {
+            if c.name == "prod" {
+                ImportConflict::Overwrite
+            } else {
+                ImportConflict::Rename
+            }
+        });
+        assert_eq!(
+            summary,
+            ImportSummary {
+                added: 1,
+                overwritten: 1,
+                renamed: 1,
+                skipped: 0,
+            }
+        );
+
+        let names: Vec<&str> = storage
+            .connections
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["prod", "dev", "dev (2)", "staging"]);
+        assert_eq!(storage.connections[0].id, prod_id);
+        assert_eq!(storage.connections[0].host, "db.internal");
+        assert!(is_plain_text(&storage.connections[0]));
+    }
+}
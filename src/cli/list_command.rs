@@ -0,0 +1,99 @@
+// FilePath: src/cli/list_command.rs
+
+#![forbid(unsafe_code)]
+
+use super::OutputFormat;
+use crate::core::error::Result;
+use crate::database::{ConnectionConfig, ConnectionStorage, DatabaseType};
+use crate::security::REDACTED;
+
+/// Print every saved connection without starting the TUI
+pub async fn list_connections(format: OutputFormat) -> Result<()> {
+    let storage = ConnectionStorage::load().await?;
+    let output = format_connections(&storage.connections, format);
+    if !output.is_empty() {
+        println!("{output}");
+    }
+    Ok(())
+}
+
+/// Lowercase engine name used as the URL scheme and the JSON `type`, e.g. `postgresql`
+fn type_name(database_type: &DatabaseType) -> String {
+    format!("{database_type:?}").to_lowercase()
+}
+
+/// `type://user@host:port/db`, or `sqlite://path` for file databases
+fn connection_url(connection: &ConnectionConfig) -> String {
+    let database = connection.database.as_deref().unwrap_or_default();
+    if connection.database_type == DatabaseType::SQLite {
+        return format!("sqlite://{database}");
+    }
+    format!(
+        "{}://{}@{}:{}/{}",
+        type_name(&connection.database_type),
+        connection.username,
+        connection.host,
+        connection.port,
+        database
+    )
+}
+
+/// Render the connection list; passwords are always redacted
+pub fn format_connections(connections: &[ConnectionConfig], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Table => connections
+            .iter()
+            .map(|c| {
+                format!(
+                    "{}: {} [{}]",
+                    c.name,
+                    connection_url(c),
+                    c.status_text().to_lowercase()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => {
+            let records: Vec<serde_json::Value> = connections
+                .iter()
+                .map(|c| {
+                    serde_json::json!({
+                        "name": c.name,
+                        "type": type_name(&c.database_type),
+                        "host": c.host,
+                        "port": c.port,
+                        "database": c.database,
+                        "username": c.username,
+                        "password": REDACTED,
+                        "status": c.status_text().to_lowercase(),
+                    })
+                })
+                .collect();
+            serde_json::to_string_pretty(&records).unwrap_or_else(|_| "[]".to_string())
+        }
+        OutputFormat::Csv => {
+            let columns: Vec<String> = [
+                "name", "type", "host", "port", "database", "username", "password", "status",
+            ]
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+            let rows: Vec<Vec<Option<String>>> = connections
+                .iter()
+                .map(|c| {
+                    vec![
+                        Some(c.name.clone()),
+                        Some(type_name(&c.database_type)),
+                        Some(c.host.clone()),
+                        Some(c.port.to_string()),
+                        c.database.clone(),
+                        Some(c.username.clone()),
+                        Some(REDACTED.to_string()),
+                        Some(c.status_text().to_lowercase()),
+                    ]
+                })
+                .collect();
+            super::format_results(&columns, &rows, OutputFormat::Csv)
+        }
+    }
+}
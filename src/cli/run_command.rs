@@ -0,0 +1,302 @@
+// FilePath: src/cli/run_command.rs
+
+#![forbid(unsafe_code)]
+
+use super::OutputFormat;
+use crate::core::error::LazyTablesError;
+use crate::database::{
+    factory::AdapterFactory, sql_split::split_statements, Connection, ConnectionStorage,
+};
+use std::path::Path;
+use thiserror::Error;
+
+/// Why a headless run failed; each kind exits with its own code
+#[derive(Error, Debug)]
+pub enum HeadlessError {
+    /// The saved connection is missing, locked or unreachable
+    #[error("{0}")]
+    Connection(LazyTablesError),
+
+    /// A statement failed; later ones were not run
+    #[error("Statement failed: {statement}\n{error}")]
+    Sql {
+        statement: String,
+        error: LazyTablesError,
+    },
+
+    #[error("{0}")]
+    Other(#[from] LazyTablesError),
+}
+
+impl HeadlessError {
+    /// Exit code for `query` and `--execute`: 2 for connection failures, 3
+    /// for SQL errors, 1 for anything else. `run` exits 1 for every failure.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Connection(_) => 2,
+            Self::Sql { .. } => 3,
+            Self::Other(_) => 1,
+        }
+    }
+}
+
+/// Execute a SQL file against a saved connection without starting the TUI.
+///
+/// Statements are split on `separator` and executed one by one; execution
+/// stops at the first failing statement and the error is returned to the
+/// caller.
+pub async fn run_sql_file(
+    file: &Path,
+    connection_name: &str,
+    separator: &str,
+    format: OutputFormat,
+) -> Result<(), HeadlessError> {
+    let sql = crate::io::async_fs::read_to_string(file).await?;
+    run_script(&sql, connection_name, separator, format, true).await
+}
+
+/// Split `sql` on `separator` the way the query editor's "Run all" does and
+/// run each statement in turn, printing every result set to stdout.
+///
+/// With `interactive`, an encrypted password's key is asked for on the
+/// terminal; without it such a connection fails.
+pub async fn run_script(
+    sql: &str,
+    connection_name: &str,
+    separator: &str,
+    format: OutputFormat,
+    interactive: bool,
+) -> Result<(), HeadlessError> {
+    let mut connection = connect_saved(connection_name, interactive).await?;
+
+    let mut result = Ok(());
+    for statement in split_statements(sql, separator) {
+        match connection.execute_raw_query(&statement.text).await {
+            Ok((columns, rows)) => {
+                if !columns.is_empty() {
+                    println!("{}", format_results(&columns, &rows, format));
+                }
+            }
+            Err(error) => {
+                result = Err(HeadlessError::Sql {
+                    statement: statement.text,
+                    error,
+                });
+                break;
+            }
+        }
+    }
+
+    let _ = connection.disconnect().await;
+    result
+}
+
+/// Run a single query against a saved connection and print its result set
+/// as CSV, for `--execute`
+pub async fn run_query(sql: &str, connection_name: &str) -> Result<(), HeadlessError> {
+    let mut connection = connect_saved(connection_name, true).await?;
+    let result = connection.execute_raw_query(sql.trim()).await;
+    let _ = connection.disconnect().await;
+
+    let (columns, rows) = result.map_err(|error| HeadlessError::Sql {
+        statement: sql.trim().to_string(),
+        error,
+    })?;
+    if !columns.is_empty() {
+        println!("{}", format_results(&columns, &rows, OutputFormat::Csv));
+    }
+    Ok(())
+}
+
+/// Connect to the saved connection with this name or id
+async fn connect_saved(
+    connection_name: &str,
+    interactive: bool,
+) -> Result<Box<dyn Connection>, HeadlessError> {
+    let storage = ConnectionStorage::load().await?;
+    let config = storage
+        .connections
+        .iter()
+        .find(|c| c.name == connection_name || c.id == connection_name)
+        .cloned()
+        .ok_or_else(|| {
+            HeadlessError::Connection(LazyTablesError::ConnectionNotFound(
+                connection_name.to_string(),
+            ))
+        })?;
+
+    let key = if config.requires_encryption_key() {
+        if !interactive {
+            return Err(HeadlessError::Connection(LazyTablesError::PasswordError(
+                format!(
+                    "'{}' has an encrypted password; drop --no-input to enter its key",
+                    config.name
+                ),
+            )));
+        }
+        let prompt = match config.get_password_hint() {
+            Some(hint) => format!("Encryption key for '{}' (hint: {hint}): ", config.name),
+            None => format!("Encryption key for '{}': ", config.name),
+        };
+        let key = read_secret(&prompt).map_err(|e| {
+            HeadlessError::Connection(LazyTablesError::PasswordError(format!(
+                "Couldn't read the encryption key: {e}"
+            )))
+        })?;
+        Some(crate::security::SessionKey::new(key))
+    } else {
+        None
+    };
+
+    let mut connection =
+        AdapterFactory::create_connection(config).map_err(HeadlessError::Connection)?;
+    connection
+        .connect_with_key(key.as_ref().map(|key| key.expose()))
+        .await
+        .map_err(HeadlessError::Connection)?;
+    Ok(connection)
+}
+
+/// Read a line from the terminal without echoing it, prompting on stderr so
+/// redirected stdout stays clean
+fn read_secret(prompt: &str) -> std::io::Result<String> {
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+    use std::io::Write;
+
+    eprint!("{prompt}");
+    std::io::stderr().flush()?;
+    crossterm::terminal::enable_raw_mode()?;
+
+    let mut secret = String::new();
+    let result = loop {
+        let key = match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => key,
+            Ok(_) => continue,
+            Err(e) => break Err(e),
+        };
+        match key.code {
+            KeyCode::Enter => break Ok(()),
+            KeyCode::Esc => break Err(std::io::ErrorKind::Interrupted.into()),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                break Err(std::io::ErrorKind::Interrupted.into())
+            }
+            KeyCode::Backspace => {
+                secret.pop();
+            }
+            KeyCode::Char(c) => secret.push(c),
+            _ => {}
+        }
+    };
+
+    let _ = crossterm::terminal::disable_raw_mode();
+    eprintln!();
+    result.map(|()| secret)
+}
+
+/// Render a result set in the requested output format. NULL is an empty
+/// CSV field (an empty string is `""`), JSON `null` and `NULL` in a table.
+pub fn format_results(
+    columns: &[String],
+    rows: &[Vec<Option<String>>],
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Csv => {
+            // Same encoding as `:copy`; the caller prints the last newline
+            let mut csv = crate::ui::components::results_to_csv(columns, rows);
+            csv.pop();
+            csv
+        }
+        OutputFormat::Json => {
+            let records: Vec<serde_json::Map<String, serde_json::Value>> = rows
+                .iter()
+                .map(|row| {
+                    columns
+                        .iter()
+                        .zip(row.iter())
+                        .map(|(c, v)| {
+                            let value = v.clone().map_or(serde_json::Value::Null, Into::into);
+                            (c.clone(), value)
+                        })
+                        .collect()
+                })
+                .collect();
+            serde_json::to_string_pretty(&records).unwrap_or_else(|_| "[]".to_string())
+        }
+        OutputFormat::Table => {
+            let text_rows: Vec<Vec<&str>> = rows
+                .iter()
+                .map(|row| row.iter().map(|v| v.as_deref().unwrap_or("NULL")).collect())
+                .collect();
+            let mut widths: Vec<usize> = columns.iter().map(|c| c.chars().count()).collect();
+            for row in &text_rows {
+                for (i, value) in row.iter().enumerate() {
+                    if let Some(width) = widths.get_mut(i) {
+                        *width = (*width).max(value.chars().count());
+                    }
+                }
+            }
+
+            let format_row = |values: &[&str]| {
+                values
+                    .iter()
+                    .zip(widths.iter())
+                    .map(|(v, w)| format!("{:<width$}", v, width = *w))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            };
+
+            let header: Vec<&str> = columns.iter().map(String::as_str).collect();
+            let mut lines = vec![format_row(&header)];
+            lines.push(
+                widths
+                    .iter()
+                    .map(|w| "-".repeat(*w))
+                    .collect::<Vec<_>>()
+                    .join("-+-"),
+            );
+            for row in &text_rows {
+                lines.push(format_row(row));
+            }
+            lines.push(format!("({} rows)", rows.len()));
+            lines.join("\n")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        let connection =
+            HeadlessError::Connection(LazyTablesError::ConnectionNotFound("prod".to_string()));
+        assert_eq!(connection.exit_code(), 2);
+        assert_eq!(connection.to_string(), "Connection 'prod' not found");
+
+        let sql = HeadlessError::Sql {
+            statement: "SELEC 1".to_string(),
+            error: LazyTablesError::Other("syntax error".to_string()),
+        };
+        assert_eq!(sql.exit_code(), 3);
+        assert!(sql.to_string().starts_with("Statement failed: SELEC 1\n"));
+
+        let other = HeadlessError::from(LazyTablesError::Config("bad".to_string()));
+        assert_eq!(other.exit_code(), 1);
+    }
+
+    #[test]
+    fn test_csv_keeps_null_and_empty_apart() {
+        let columns = vec!["id".to_string(), "note".to_string()];
+        let rows = vec![
+            vec![Some("1".to_string()), None],
+            vec![Some("2".to_string()), Some(String::new())],
+            vec![Some("3".to_string()), Some("a\rb".to_string())],
+        ];
+        assert_eq!(
+            format_results(&columns, &rows, OutputFormat::Csv),
+            "id,note\n1,\n2,\"\"\n3,\"a\rb\""
+        );
+    }
+}
@@ -2,7 +2,7 @@
 
 #![forbid(unsafe_code)]
 
-use crate::ui::theme::ThemeLoader;
+use crate::{config::Config, ui::theme::ThemeLoader};
 use clap::Subcommand;
 use std::path::PathBuf;
 
@@ -26,6 +26,12 @@ pub enum ThemeCommand {
 
     /// Show theme directories
     Dirs,
+
+    /// Make a theme the default, saved to the config file
+    Set {
+        /// Theme name as shown by `theme list`
+        name: String,
+    },
 }
 
 impl ThemeCommand {
@@ -81,6 +87,19 @@ impl ThemeCommand {
                 println!();
                 println!("Legend: ✓ = exists, ✗ = does not exist");
             }
+
+            ThemeCommand::Set { name } => {
+                let (theme, _) = ThemeLoader::find_theme(name)
+                    .ok_or_else(|| format!("Theme '{name}' not found"))?;
+                let mut config = Config::load(None)?;
+                config.theme.name = theme.name.clone();
+                let path = config
+                    .source_path
+                    .clone()
+                    .unwrap_or_else(Config::default_path);
+                config.save(&path)?;
+                println!("✓ Theme set to '{}' in {}", theme.name, path.display());
+            }
         }
 
         Ok(())
@@ -4,12 +4,14 @@
 
 use super::{Command, CommandAction, CommandCategory, CommandContext, CommandId, CommandResult};
 use crate::core::error::Result;
+use async_trait::async_trait;
 
 /// Quit command - exits the application
 pub struct QuitCommand;
 
+#[async_trait]
 impl Command for QuitCommand {
-    fn execute(&self, _context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, _context: &mut CommandContext<'_>) -> Result<CommandResult> {
         Ok(CommandResult::Action(CommandAction::Quit))
     }
 
@@ -21,6 +23,10 @@ impl Command for QuitCommand {
         CommandId::Quit
     }
 
+    fn names(&self) -> &'static [&'static str] {
+        &["quit", "qa"]
+    }
+
     fn shortcut(&self) -> Option<String> {
         Some("q".to_string())
     }
@@ -33,8 +39,9 @@ impl Command for QuitCommand {
 /// Force quit command - exits without confirmation
 pub struct ForceQuitCommand;
 
+#[async_trait]
 impl Command for ForceQuitCommand {
-    fn execute(&self, _context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, _context: &mut CommandContext<'_>) -> Result<CommandResult> {
         Ok(CommandResult::Action(CommandAction::Quit))
     }
 
@@ -58,15 +65,16 @@ impl Command for ForceQuitCommand {
 /// Help command - shows help overlay
 pub struct HelpCommand;
 
+#[async_trait]
 impl Command for HelpCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         use crate::app::state::HelpMode;
         use crate::state::view::OverlayView;
 
         // Set help mode based on current pane
         context.state.ui.help_mode = match context.state.ui.focused_pane {
             crate::app::FocusedPane::Connections => HelpMode::Connections,
-            crate::app::FocusedPane::Tables => HelpMode::Tables,
+            crate::app::FocusedPane::Tables | crate::app::FocusedPane::Routines => HelpMode::Tables,
             crate::app::FocusedPane::Details => HelpMode::Details,
             crate::app::FocusedPane::TabularOutput => HelpMode::TabularOutput,
             crate::app::FocusedPane::QueryWindow => HelpMode::QueryWindow,
@@ -92,6 +100,10 @@ impl Command for HelpCommand {
         CommandId::Help
     }
 
+    fn names(&self) -> &'static [&'static str] {
+        &["help"]
+    }
+
     fn shortcut(&self) -> Option<String> {
         Some("?".to_string())
     }
@@ -104,8 +116,9 @@ impl Command for HelpCommand {
 /// Toggle help command
 pub struct ToggleHelpCommand;
 
+#[async_trait]
 impl Command for ToggleHelpCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         use crate::app::state::HelpMode;
 
         if context.state.ui.help_mode != HelpMode::None {
@@ -117,7 +130,7 @@ impl Command for ToggleHelpCommand {
             ))
         } else {
             // Delegate to HelpCommand
-            HelpCommand.execute(context)
+            HelpCommand.execute(context).await
         }
     }
 
@@ -134,11 +147,46 @@ impl Command for ToggleHelpCommand {
     }
 }
 
+/// Messages command - opens the notification history
+pub struct MessagesCommand;
+
+#[async_trait]
+impl Command for MessagesCommand {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
+        use crate::state::view::OverlayView;
+
+        context.state.toast_history.reset();
+        context.state.ui.show_overlay(OverlayView::ToastHistory);
+        Ok(CommandResult::Success)
+    }
+
+    fn description(&self) -> &str {
+        "Show the notification history"
+    }
+
+    fn id(&self) -> CommandId {
+        CommandId::ShowMessages
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        &["messages"]
+    }
+
+    fn shortcut(&self) -> Option<String> {
+        Some("~".to_string())
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::View
+    }
+}
+
 /// Save command - saves current content
 pub struct SaveCommand;
 
+#[async_trait]
 impl Command for SaveCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         // Check what needs to be saved based on current focus
         match context.state.ui.focused_pane {
             crate::app::FocusedPane::QueryWindow => {
@@ -157,14 +205,10 @@ impl Command for SaveCommand {
 
                     let filepath = sql_dir.join(&filename);
 
-                    // Use async file I/O with block_on (Command trait doesn't support async)
-                    // TODO: Move to background task with event notification for truly non-blocking operation
-                    tokio::runtime::Handle::current().block_on(async {
-                        // Ensure directory exists
-                        crate::io::async_fs::create_dir_all(&sql_dir).await?;
-                        // Write file
-                        crate::io::async_fs::write(&filepath, query).await
-                    })?;
+                    // Ensure directory exists
+                    crate::io::async_fs::create_dir_all(&sql_dir).await?;
+                    // Write file
+                    crate::io::async_fs::write(&filepath, query).await?;
 
                     // Add success toast
                     context
@@ -225,11 +269,12 @@ impl Command for SaveCommand {
 /// Save As command - saves with a specific filename
 pub struct SaveAsCommand;
 
+#[async_trait]
 impl Command for SaveAsCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         // This would typically open a file dialog or prompt
         // For now, delegate to SaveCommand
-        SaveCommand.execute(context)
+        SaveCommand.execute(context).await
     }
 
     fn description(&self) -> &str {
@@ -252,8 +297,9 @@ impl Command for SaveAsCommand {
 /// Open command - opens a file
 pub struct OpenCommand;
 
+#[async_trait]
 impl Command for OpenCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         match context.state.ui.focused_pane {
             crate::app::FocusedPane::SqlFiles => {
                 // Load selected SQL file
@@ -264,28 +310,22 @@ impl Command for OpenCommand {
                         .join(".lazytables")
                         .join("sql_files");
 
-                    // Use async file I/O with block_on (Command trait doesn't support async)
-                    // TODO: Move to background task with event notification for truly non-blocking operation
-                    let files = tokio::runtime::Handle::current().block_on(async {
-                        let entries = crate::io::async_fs::read_dir(&sql_dir).await?;
-                        let filtered: Vec<_> = entries
-                            .into_iter()
-                            .filter(|entry| {
-                                entry
-                                    .path()
-                                    .extension()
-                                    .and_then(|ext| ext.to_str())
-                                    .map(|ext| ext == "sql")
-                                    .unwrap_or(false)
-                            })
-                            .collect();
-                        Ok::<_, crate::core::error::LazyTablesError>(filtered)
-                    })?;
+                    let files: Vec<_> = crate::io::async_fs::read_dir(&sql_dir)
+                        .await?
+                        .into_iter()
+                        .filter(|entry| {
+                            entry
+                                .path()
+                                .extension()
+                                .and_then(|ext| ext.to_str())
+                                .map(|ext| ext == "sql")
+                                .unwrap_or(false)
+                        })
+                        .collect();
 
                     if selected < files.len() {
-                        let content = tokio::runtime::Handle::current().block_on(async {
-                            crate::io::async_fs::read_to_string(files[selected].path()).await
-                        })?;
+                        let content =
+                            crate::io::async_fs::read_to_string(files[selected].path()).await?;
                         context.state.query_content = content;
                         context.state.ui.current_sql_file =
                             Some(files[selected].file_name().to_string_lossy().to_string());
@@ -321,3 +361,33 @@ impl Command for OpenCommand {
         CommandCategory::File
     }
 }
+
+/// Theme command - `:theme <name>` switches theme, `:theme list` opens the gallery
+pub struct ThemeCommand;
+
+#[async_trait]
+impl Command for ThemeCommand {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
+        let name = context.args.join(" ");
+        if name.is_empty() || name == "list" {
+            return Ok(CommandResult::Action(CommandAction::OpenThemeGallery));
+        }
+        Ok(CommandResult::Action(CommandAction::SetTheme(name)))
+    }
+
+    fn description(&self) -> &str {
+        "Switch the UI theme"
+    }
+
+    fn id(&self) -> CommandId {
+        CommandId::SetTheme
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        &["theme", "colorscheme"]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Settings
+    }
+}
@@ -6,12 +6,14 @@ use super::{
     Command, CommandAction, CommandCategory, CommandContext, CommandId, CommandResult, ModalType,
 };
 use crate::core::error::Result;
+use async_trait::async_trait;
 
 /// Connect command - establishes database connection
 pub struct ConnectCommand;
 
+#[async_trait]
 impl Command for ConnectCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         // Check if we're in the connections pane
         if context.state.ui.focused_pane != crate::app::FocusedPane::Connections {
             return Ok(CommandResult::Error(
@@ -39,9 +41,9 @@ impl Command for ConnectCommand {
                     .toast_manager
                     .info(format!("Connecting to {}...", connection.name));
 
-                // Spawn async connection task using the connection manager
+                // Connect through the connection manager
                 let connection_config = connection.clone();
-                let result = tokio::runtime::Handle::current().block_on(async {
+                let result = async {
                     // First establish the persistent connection
                     match context
                         .state
@@ -78,7 +80,8 @@ impl Command for ConnectCommand {
                             "Connection failed: {e}"
                         ))),
                     }
-                });
+                }
+                .await;
 
                 match result {
                     Ok(_database_objects) => {
@@ -133,6 +136,10 @@ impl Command for ConnectCommand {
         CommandId::Connect
     }
 
+    fn names(&self) -> &'static [&'static str] {
+        &["connect"]
+    }
+
     fn shortcut(&self) -> Option<String> {
         Some("Enter".to_string())
     }
@@ -150,8 +157,9 @@ impl Command for ConnectCommand {
 /// Add Connection command - opens connection creation modal
 pub struct AddConnectionCommand;
 
+#[async_trait]
 impl Command for AddConnectionCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         // Initialize connection modal state
         use crate::ui::components::connection_modal::ConnectionModalState;
 
@@ -171,6 +179,10 @@ impl Command for AddConnectionCommand {
         CommandId::AddConnection
     }
 
+    fn names(&self) -> &'static [&'static str] {
+        &["add-connection"]
+    }
+
     fn shortcut(&self) -> Option<String> {
         Some("a".to_string())
     }
@@ -188,8 +200,9 @@ impl Command for AddConnectionCommand {
 /// Edit Connection command
 pub struct EditConnectionCommand;
 
+#[async_trait]
 impl Command for EditConnectionCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         if !context.state.db.connections.connections.is_empty() {
             let selected = context.state.ui.selected_connection;
 
@@ -248,8 +261,9 @@ impl Command for EditConnectionCommand {
 /// Delete Connection command
 pub struct DeleteConnectionCommand;
 
+#[async_trait]
 impl Command for DeleteConnectionCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         if !context.state.db.connections.connections.is_empty() {
             let selected = context.state.ui.selected_connection;
             Ok(CommandResult::RequiresConfirmation(format!(
@@ -285,8 +299,9 @@ impl Command for DeleteConnectionCommand {
 /// Disconnect command
 pub struct DisconnectCommand;
 
+#[async_trait]
 impl Command for DisconnectCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         // Get the currently selected connection
         let selected = context.state.ui.selected_connection;
         if let Some(connection) = context
@@ -301,16 +316,14 @@ impl Command for DisconnectCommand {
             let connection_id = connection.id.clone();
             let connection_name = connection.name.clone();
 
-            tokio::runtime::Handle::current().block_on(async {
-                let _ = context
-                    .state
-                    .connection_manager
-                    .disconnect(&connection_id)
-                    .await;
+            let _ = context
+                .state
+                .connection_manager
+                .disconnect(&connection_id)
+                .await;
 
-                // Update UI state
-                context.state.disconnect_from_database_sync().await;
-            });
+            // Update UI state
+            context.state.disconnect_from_database_sync().await;
 
             context
                 .state
@@ -338,6 +351,10 @@ impl Command for DisconnectCommand {
         CommandId::Disconnect
     }
 
+    fn names(&self) -> &'static [&'static str] {
+        &["disconnect"]
+    }
+
     fn category(&self) -> CommandCategory {
         CommandCategory::Connection
     }
@@ -346,12 +363,11 @@ impl Command for DisconnectCommand {
 /// Refresh Connections command
 pub struct RefreshConnectionsCommand;
 
+#[async_trait]
 impl Command for RefreshConnectionsCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
-        // Reload connections from storage (using block_on temporarily - will be replaced with background task)
-        match tokio::runtime::Handle::current()
-            .block_on(crate::database::connection::ConnectionStorage::load())
-        {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
+        // Reload connections from storage
+        match crate::database::connection::ConnectionStorage::load().await {
             Ok(storage) => {
                 let old_count = context.state.db.connections.connections.len();
                 context.state.db.connections = storage;
@@ -404,8 +420,9 @@ impl Command for RefreshConnectionsCommand {
 /// Test Connection command
 pub struct TestConnectionCommand;
 
+#[async_trait]
 impl Command for TestConnectionCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         if !context.state.db.connections.connections.is_empty() {
             let selected = context.state.ui.selected_connection;
 
@@ -419,16 +436,11 @@ impl Command for TestConnectionCommand {
                 let connection_config = connection.clone();
                 let start_time = std::time::Instant::now();
 
-                let result = tokio::runtime::Handle::current().block_on(async {
-                    context
-                        .state
-                        .db
-                        .try_connect_to_database(
-                            &connection_config,
-                            &context.state.connection_manager,
-                        )
-                        .await
-                });
+                let result = context
+                    .state
+                    .db
+                    .try_connect_to_database(&connection_config, &context.state.connection_manager)
+                    .await;
 
                 let test_duration = start_time.elapsed();
 
@@ -492,6 +504,10 @@ impl Command for TestConnectionCommand {
         CommandId::TestConnection
     }
 
+    fn names(&self) -> &'static [&'static str] {
+        &["test-connection"]
+    }
+
     fn shortcut(&self) -> Option<String> {
         Some("t".to_string())
     }
@@ -505,3 +521,140 @@ impl Command for TestConnectionCommand {
             && !context.state.db.connections.connections.is_empty()
     }
 }
+
+/// Import Connections command - scans system credential files for new connections
+pub struct ImportConnectionsFromSystemCommand;
+
+#[async_trait]
+impl Command for ImportConnectionsFromSystemCommand {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
+        use crate::ui::components::connection_import::ConnectionImportModal;
+
+        let candidates =
+            crate::database::system_import::discover(&context.state.db.connections.connections);
+
+        if candidates.is_empty() {
+            context
+                .state
+                .toast_manager
+                .info("No new connections found in ~/.pgpass, ~/.my.cnf or known_hosts");
+            return Ok(CommandResult::Success);
+        }
+
+        context.state.connection_import = Some(ConnectionImportModal::new(candidates));
+        Ok(CommandResult::Success)
+    }
+
+    fn description(&self) -> &str {
+        "Import connections from pgpass, my.cnf and known_hosts"
+    }
+
+    fn id(&self) -> CommandId {
+        CommandId::ImportConnectionsFromSystem
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        &["import-connections"]
+    }
+
+    fn shortcut(&self) -> Option<String> {
+        Some("I".to_string())
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Connection
+    }
+
+    fn can_execute(&self, context: &CommandContext) -> bool {
+        context.state.ui.focused_pane == crate::app::FocusedPane::Connections
+    }
+}
+
+/// Show locks command - opens the `pg_locks` monitor for the selected connection
+pub struct ShowLocksCommand;
+
+#[async_trait]
+impl Command for ShowLocksCommand {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
+        use crate::ui::components::LockMonitor;
+
+        let Some(connection) = context.state.get_selected_connection() else {
+            return Ok(CommandResult::Error("No connection selected".to_string()));
+        };
+        if connection.database_type != crate::database::DatabaseType::PostgreSQL {
+            return Ok(CommandResult::Error(
+                "Lock monitoring is only available for PostgreSQL".to_string(),
+            ));
+        }
+        if !connection.is_connected() {
+            return Ok(CommandResult::Error(format!(
+                "Not connected to {}",
+                connection.name
+            )));
+        }
+
+        context.state.lock_monitor = Some(LockMonitor::new(
+            connection.id.clone(),
+            connection.name.clone(),
+        ));
+        Ok(CommandResult::Success)
+    }
+
+    fn description(&self) -> &str {
+        "Show locks and blocking sessions (PostgreSQL)"
+    }
+
+    fn id(&self) -> CommandId {
+        CommandId::ShowLocks
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        &["locks"]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Connection
+    }
+}
+
+/// `:connection template save <name>` - save the selected connection as a
+/// template for `A`, leaving out its name and database
+pub struct SaveConnectionTemplateCommand;
+
+#[async_trait]
+impl Command for SaveConnectionTemplateCommand {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
+        let name = match context.args.as_slice() {
+            [template, save, name @ ..]
+                if template == "template" && save == "save" && !name.is_empty() =>
+            {
+                name.join(" ")
+            }
+            _ => {
+                return Ok(CommandResult::Error(
+                    "Usage: :connection template save <name>".to_string(),
+                ))
+            }
+        };
+        Ok(match context.state.save_connection_template(&name) {
+            Ok(message) => CommandResult::SuccessWithMessage(message),
+            Err(e) => CommandResult::Error(e),
+        })
+    }
+
+    fn description(&self) -> &str {
+        "Save the selected connection as a template"
+    }
+
+    fn id(&self) -> CommandId {
+        CommandId::SaveConnectionTemplate
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        &["connection"]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Connection
+    }
+}
@@ -4,12 +4,14 @@
 
 use super::{Command, CommandCategory, CommandContext, CommandId, CommandResult};
 use crate::core::error::Result;
+use async_trait::async_trait;
 
 /// Start insert mode command
 pub struct StartInsertModeCommand;
 
+#[async_trait]
 impl Command for StartInsertModeCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         match context.state.ui.focused_pane {
             crate::app::FocusedPane::QueryWindow => {
                 // Query editor manages its own insert mode now
@@ -42,8 +44,9 @@ impl Command for StartInsertModeCommand {
 /// Exit insert mode command
 pub struct ExitInsertModeCommand;
 
+#[async_trait]
 impl Command for ExitInsertModeCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         // Query editor manages its own insert mode now
         context.state.query_editor.set_insert_mode(false);
         Ok(CommandResult::SuccessWithMessage("Normal mode".to_string()))
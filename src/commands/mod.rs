@@ -4,6 +4,7 @@
 
 use crate::app::AppState;
 use crate::core::error::Result;
+use async_trait::async_trait;
 use std::collections::HashMap;
 use std::fmt;
 
@@ -12,12 +13,16 @@ pub mod connection;
 pub mod editing;
 pub mod navigation;
 pub mod query;
+pub mod table;
+pub mod workspace;
 
 pub use basic::*;
 pub use connection::*;
 pub use editing::*;
 pub use navigation::*;
 pub use query::*;
+pub use table::*;
+pub use workspace::*;
 
 /// Unique identifier for each command
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -27,6 +32,7 @@ pub enum CommandId {
     ForceQuit,
     Help,
     ToggleHelp,
+    ShowMessages,
 
     // Navigation commands
     NavigateUp,
@@ -41,6 +47,8 @@ pub enum CommandId {
     FocusQueryPane,
     FocusResultsPane,
     FocusSqlFilesPane,
+    ToggleBookmark,
+    ShowBookmarks,
 
     // Connection commands
     Connect,
@@ -50,11 +58,15 @@ pub enum CommandId {
     DeleteConnection,
     RefreshConnections,
     TestConnection,
+    ImportConnectionsFromSystem,
+    ShowLocks,
+    SaveConnectionTemplate,
 
     // Query commands
     ExecuteQuery,
     ExecuteCurrentStatement,
     SaveQuery,
+    CopyResults,
     LoadQuery,
     NewQuery,
     ClearQuery,
@@ -62,11 +74,19 @@ pub enum CommandId {
     // Table commands
     DropTable,
     TruncateTable,
+    RenameTable,
+    CopyTableName,
+    CopyQualifiedTableName,
+    OpenTableInNewTab,
     RefreshTables,
     ShowTableStructure,
     ShowTableData,
     ExportTable,
+    ExportTableDdl,
     ImportTable,
+    ImportCsv,
+    RefreshTableMetadata,
+    ProfileTable,
 
     // Editing commands
     StartInsertMode,
@@ -78,6 +98,13 @@ pub enum CommandId {
     SaveAs,
     Open,
 
+    // Workspace commands
+    SaveWorkspace,
+    LoadWorkspace,
+
+    // Settings commands
+    SetTheme,
+
     // Custom command for extensions
     Custom(String),
 }
@@ -89,9 +116,20 @@ impl fmt::Display for CommandId {
             CommandId::ForceQuit => write!(f, "Force Quit"),
             CommandId::Help => write!(f, "Help"),
             CommandId::ToggleHelp => write!(f, "Toggle Help"),
+            CommandId::ShowMessages => write!(f, "Show Messages"),
             CommandId::Connect => write!(f, "Connect"),
+            CommandId::ImportConnectionsFromSystem => write!(f, "Import Connections"),
+            CommandId::ShowLocks => write!(f, "Show Locks"),
+            CommandId::SaveConnectionTemplate => write!(f, "Save Connection Template"),
+            CommandId::ToggleBookmark => write!(f, "Toggle Bookmark"),
+            CommandId::ShowBookmarks => write!(f, "Show Bookmarks"),
             CommandId::Save => write!(f, "Save"),
             CommandId::ExecuteQuery => write!(f, "Execute Query"),
+            CommandId::CopyResults => write!(f, "Copy Results to CSV"),
+            CommandId::SaveWorkspace => write!(f, "Save Workspace"),
+            CommandId::LoadWorkspace => write!(f, "Load Workspace"),
+            CommandId::ImportCsv => write!(f, "Import CSV"),
+            CommandId::SetTheme => write!(f, "Set Theme"),
             CommandId::Custom(name) => write!(f, "Custom: {name}"),
             _ => write!(f, "{self:?}"),
         }
@@ -102,6 +140,8 @@ impl fmt::Display for CommandId {
 pub struct CommandContext<'a> {
     pub state: &'a mut AppState,
     pub config: &'a crate::config::Config,
+    /// Words typed after the command name on the `:` command line
+    pub args: Vec<String>,
 }
 
 /// Result of command execution
@@ -136,6 +176,12 @@ pub enum CommandAction {
     LoadFile(String),
     SaveFile(String),
     Navigate(NavigationTarget),
+    /// Re-list database objects through the streaming listing
+    RefreshObjectList,
+    /// Switch to the named theme and save the choice
+    SetTheme(String),
+    /// Open the `:theme list` gallery
+    OpenThemeGallery,
 }
 
 #[derive(Debug, Clone)]
@@ -155,9 +201,11 @@ pub enum NavigationTarget {
 }
 
 /// Main trait for all commands
+#[async_trait]
 pub trait Command: Send + Sync {
-    /// Execute the command
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult>;
+    /// Execute the command; file and database I/O is awaited here, on the
+    /// runtime that drives the key handlers
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult>;
 
     /// Undo the command (if supported)
     fn undo(&self, _context: &mut CommandContext) -> Result<CommandResult> {
@@ -187,6 +235,11 @@ pub trait Command: Send + Sync {
         None
     }
 
+    /// Names that run this command from the `:` command line, e.g. `quit`
+    fn names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
     /// Get category for grouping commands
     fn category(&self) -> CommandCategory {
         CommandCategory::General
@@ -229,6 +282,8 @@ impl fmt::Display for CommandCategory {
 pub struct CommandRegistry {
     commands: HashMap<CommandId, Box<dyn Command>>,
     shortcuts: HashMap<String, CommandId>,
+    /// Command-line names and aliases
+    names: HashMap<&'static str, CommandId>,
     history: Vec<CommandId>,
     undo_stack: Vec<CommandId>,
     redo_stack: Vec<CommandId>,
@@ -240,6 +295,7 @@ impl CommandRegistry {
         let mut registry = Self {
             commands: HashMap::new(),
             shortcuts: HashMap::new(),
+            names: HashMap::new(),
             history: Vec::new(),
             undo_stack: Vec::new(),
             redo_stack: Vec::new(),
@@ -257,14 +313,17 @@ impl CommandRegistry {
         if let Some(shortcut) = command.shortcut() {
             self.shortcuts.insert(shortcut, id.clone());
         }
+        for name in command.names() {
+            self.names.insert(name, id.clone());
+        }
         self.commands.insert(id, command);
     }
 
     /// Execute a command by ID
-    pub fn execute(
+    pub async fn execute(
         &mut self,
         id: CommandId,
-        context: &mut CommandContext,
+        context: &mut CommandContext<'_>,
     ) -> Result<CommandResult> {
         if let Some(command) = self.commands.get(&id) {
             // Check if command can be executed
@@ -275,7 +334,7 @@ impl CommandRegistry {
             }
 
             // Execute the command
-            let result = command.execute(context)?;
+            let result = command.execute(context).await?;
 
             // Add to history
             self.history.push(id.clone());
@@ -310,10 +369,10 @@ impl CommandRegistry {
     }
 
     /// Redo the last undone command
-    pub fn redo(&mut self, context: &mut CommandContext) -> Result<CommandResult> {
+    pub async fn redo(&mut self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         if let Some(id) = self.redo_stack.pop() {
             if let Some(command) = self.commands.get(&id) {
-                let result = command.execute(context)?;
+                let result = command.execute(context).await?;
                 self.undo_stack.push(id);
                 Ok(result)
             } else {
@@ -339,6 +398,28 @@ impl CommandRegistry {
             .map(|c| c.as_ref())
     }
 
+    /// Resolve a `:` command line such as `:save report.sql` to a command and
+    /// its arguments, or the message to show when no command has that name
+    pub fn parse_command_line(
+        &self,
+        line: &str,
+    ) -> std::result::Result<(CommandId, Vec<String>), String> {
+        let mut words = split_command_line(line.trim().trim_start_matches(':'));
+        if words.is_empty() {
+            return Err("Empty command".to_string());
+        }
+        let name = words.remove(0);
+        match self.names.get(name.to_lowercase().as_str()) {
+            Some(id) => Ok((id.clone(), words)),
+            None => Err(format!("Unknown command: {name}")),
+        }
+    }
+
+    /// Iterate over every registered command
+    pub fn commands(&self) -> impl Iterator<Item = &dyn Command> {
+        self.commands.values().map(|c| c.as_ref())
+    }
+
     /// Get all commands in a category
     pub fn get_by_category(&self, category: CommandCategory) -> Vec<&dyn Command> {
         self.commands
@@ -366,13 +447,18 @@ impl CommandRegistry {
         self.register(Box::new(basic::QuitCommand));
         self.register(Box::new(basic::HelpCommand));
         self.register(Box::new(basic::ToggleHelpCommand));
+        self.register(Box::new(basic::MessagesCommand));
         self.register(Box::new(basic::SaveCommand));
+        self.register(Box::new(basic::ThemeCommand));
 
         // Register connection commands
         self.register(Box::new(connection::ConnectCommand));
         self.register(Box::new(connection::DisconnectCommand));
         self.register(Box::new(connection::AddConnectionCommand));
         self.register(Box::new(connection::TestConnectionCommand));
+        self.register(Box::new(connection::ImportConnectionsFromSystemCommand));
+        self.register(Box::new(connection::ShowLocksCommand));
+        self.register(Box::new(connection::SaveConnectionTemplateCommand));
 
         // Register navigation commands
         self.register(Box::new(navigation::NavigateUpCommand));
@@ -380,10 +466,17 @@ impl CommandRegistry {
         self.register(Box::new(navigation::NavigateLeftCommand));
         self.register(Box::new(navigation::NavigateRightCommand));
         self.register(Box::new(navigation::NextPaneCommand));
+        self.register(Box::new(navigation::ToggleBookmarkCommand));
+        self.register(Box::new(navigation::ShowBookmarksCommand));
 
         // Register query commands
         self.register(Box::new(query::ExecuteQueryCommand));
         self.register(Box::new(query::SaveQueryCommand));
+        self.register(Box::new(query::CopyResultsCommand));
+
+        // Register table commands
+        self.register(Box::new(table::RefreshTablesCommand));
+        self.register(Box::new(table::ImportCsvCommand));
     }
 }
 
@@ -392,3 +485,57 @@ impl Default for CommandRegistry {
         Self::new()
     }
 }
+
+/// Split a command line on whitespace; double quotes keep spaces in one argument
+pub fn split_command_line(line: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_quotes = false;
+    let mut quoted = false;
+
+    for c in line.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                quoted = true;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !word.is_empty() || quoted {
+                    words.push(std::mem::take(&mut word));
+                }
+                quoted = false;
+            }
+            c => word.push(c),
+        }
+    }
+    if !word.is_empty() || quoted {
+        words.push(word);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command_line() {
+        let registry = CommandRegistry::new();
+        assert_eq!(
+            registry.parse_command_line(":save \"daily report.sql\""),
+            Ok((CommandId::SaveQuery, vec!["daily report.sql".to_string()]))
+        );
+        assert_eq!(
+            registry.parse_command_line(":Quit"),
+            Ok((CommandId::Quit, Vec::new()))
+        );
+        assert_eq!(
+            registry.parse_command_line(":frobnicate now"),
+            Err("Unknown command: frobnicate".to_string())
+        );
+        assert_eq!(
+            split_command_line("export csv  \"\" out.csv"),
+            vec!["export", "csv", "", "out.csv"]
+        );
+    }
+}
@@ -5,45 +5,41 @@
 use super::{Command, CommandCategory, CommandContext, CommandId, CommandResult};
 use crate::app::FocusedPane;
 use crate::core::error::Result;
+use async_trait::async_trait;
 
 /// Navigate up command
 pub struct NavigateUpCommand;
 
+#[async_trait]
 impl Command for NavigateUpCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         match context.state.ui.focused_pane {
-            FocusedPane::Connections => {
-                if context.state.ui.selected_connection > 0 {
-                    context.state.ui.selected_connection -= 1;
-                    context
-                        .state
-                        .ui
-                        .connections_list_state
-                        .select(Some(context.state.ui.selected_connection));
-                }
+            FocusedPane::Connections if context.state.ui.selected_connection > 0 => {
+                context.state.ui.selected_connection -= 1;
+                context
+                    .state
+                    .ui
+                    .connections_list_state
+                    .select(Some(context.state.ui.selected_connection));
             }
             FocusedPane::Tables => {
                 context.state.ui.table_selection_up();
             }
-            FocusedPane::SqlFiles => {
-                if context.state.ui.selected_sql_file > 0 {
-                    context.state.ui.selected_sql_file -= 1;
-                }
+            FocusedPane::SqlFiles if context.state.ui.selected_sql_file > 0 => {
+                context.state.ui.selected_sql_file -= 1;
             }
-            FocusedPane::QueryWindow => {
-                if context.state.ui.query_cursor_line > 0 {
-                    context.state.ui.query_cursor_line -= 1;
-                    // Adjust column if line is shorter
-                    let line_len = context
-                        .state
-                        .query_content
-                        .lines()
-                        .nth(context.state.ui.query_cursor_line)
-                        .map(|l| l.len())
-                        .unwrap_or(0);
-                    context.state.ui.query_cursor_column =
-                        context.state.ui.query_cursor_column.min(line_len);
-                }
+            FocusedPane::QueryWindow if context.state.ui.query_cursor_line > 0 => {
+                context.state.ui.query_cursor_line -= 1;
+                // Adjust column if line is shorter
+                let line_len = context
+                    .state
+                    .query_content
+                    .lines()
+                    .nth(context.state.ui.query_cursor_line)
+                    .map(|l| l.len())
+                    .unwrap_or(0);
+                context.state.ui.query_cursor_column =
+                    context.state.ui.query_cursor_column.min(line_len);
             }
             _ => {}
         }
@@ -70,8 +66,9 @@ impl Command for NavigateUpCommand {
 /// Navigate down command
 pub struct NavigateDownCommand;
 
+#[async_trait]
 impl Command for NavigateDownCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         match context.state.ui.focused_pane {
             FocusedPane::Connections => {
                 let max = context.state.db.connections.connections.len();
@@ -134,8 +131,9 @@ impl Command for NavigateDownCommand {
 /// Navigate left command
 pub struct NavigateLeftCommand;
 
+#[async_trait]
 impl Command for NavigateLeftCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         match context.state.ui.focused_pane {
             FocusedPane::QueryWindow => {
                 if context.state.ui.query_cursor_column > 0 {
@@ -144,7 +142,7 @@ impl Command for NavigateLeftCommand {
             }
             _ => {
                 // Navigate to previous pane
-                PreviousPaneCommand.execute(context)?;
+                PreviousPaneCommand.execute(context).await?;
             }
         }
         Ok(CommandResult::Success)
@@ -170,8 +168,9 @@ impl Command for NavigateLeftCommand {
 /// Navigate right command
 pub struct NavigateRightCommand;
 
+#[async_trait]
 impl Command for NavigateRightCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         match context.state.ui.focused_pane {
             FocusedPane::QueryWindow => {
                 let line_len = context
@@ -187,7 +186,7 @@ impl Command for NavigateRightCommand {
             }
             _ => {
                 // Navigate to next pane
-                NextPaneCommand.execute(context)?;
+                NextPaneCommand.execute(context).await?;
             }
         }
         Ok(CommandResult::Success)
@@ -213,15 +212,16 @@ impl Command for NavigateRightCommand {
 /// Next pane command
 pub struct NextPaneCommand;
 
+#[async_trait]
 impl Command for NextPaneCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         use FocusedPane::*;
 
         let sql_panes_enabled = context.state.are_sql_panes_enabled();
         let query_editor_enabled = context.state.is_query_editor_enabled();
         let mut new_pane = match context.state.ui.focused_pane {
             Connections => Tables,
-            Tables => Details,
+            Tables | Routines => Details,
             Details => TabularOutput,
             TabularOutput => SqlFiles,
             SqlFiles => QueryWindow,
@@ -233,7 +233,7 @@ impl Command for NextPaneCommand {
             while matches!(new_pane, SqlFiles | QueryWindow) {
                 new_pane = match new_pane {
                     Connections => Tables,
-                    Tables => Details,
+                    Tables | Routines => Details,
                     Details => TabularOutput,
                     TabularOutput => Connections, // Skip SQL panes, go to connections
                     SqlFiles => Connections,      // Skip to connections
@@ -249,7 +249,7 @@ impl Command for NextPaneCommand {
             while matches!(new_pane, QueryWindow) {
                 new_pane = match new_pane {
                     Connections => Tables,
-                    Tables => Details,
+                    Tables | Routines => Details,
                     Details => TabularOutput,
                     TabularOutput => SqlFiles,
                     SqlFiles => Connections, // Skip query window, go to connections
@@ -274,6 +274,10 @@ impl Command for NextPaneCommand {
         CommandId::NextPane
     }
 
+    fn names(&self) -> &'static [&'static str] {
+        &["next-pane"]
+    }
+
     fn shortcut(&self) -> Option<String> {
         Some("Tab".to_string())
     }
@@ -286,15 +290,16 @@ impl Command for NextPaneCommand {
 /// Previous pane command
 pub struct PreviousPaneCommand;
 
+#[async_trait]
 impl Command for PreviousPaneCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         use FocusedPane::*;
 
         let sql_panes_enabled = context.state.are_sql_panes_enabled();
         let query_editor_enabled = context.state.is_query_editor_enabled();
         let mut new_pane = match context.state.ui.focused_pane {
             Connections => QueryWindow,
-            Tables => Connections,
+            Tables | Routines => Connections,
             Details => Tables,
             TabularOutput => Details,
             SqlFiles => TabularOutput,
@@ -306,7 +311,7 @@ impl Command for PreviousPaneCommand {
             while matches!(new_pane, SqlFiles | QueryWindow) {
                 new_pane = match new_pane {
                     Connections => TabularOutput, // Skip SQL panes, go to tabular output
-                    Tables => Connections,
+                    Tables | Routines => Connections,
                     Details => Tables,
                     TabularOutput => Details,
                     SqlFiles => TabularOutput,    // Skip to tabular output
@@ -322,7 +327,7 @@ impl Command for PreviousPaneCommand {
             while matches!(new_pane, QueryWindow) {
                 new_pane = match new_pane {
                     Connections => TabularOutput, // Skip query window, go to tabular output
-                    Tables => Connections,
+                    Tables | Routines => Connections,
                     Details => Tables,
                     TabularOutput => Details,
                     SqlFiles => TabularOutput, // Skip query window, go to tabular output
@@ -359,8 +364,9 @@ impl Command for PreviousPaneCommand {
 /// Focus Connections pane command
 pub struct FocusConnectionsPaneCommand;
 
+#[async_trait]
 impl Command for FocusConnectionsPaneCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         context.state.ui.focused_pane = FocusedPane::Connections;
         Ok(CommandResult::Success)
     }
@@ -381,3 +387,67 @@ impl Command for FocusConnectionsPaneCommand {
         CommandCategory::Navigation
     }
 }
+
+/// Bookmark the selected table or SQL file, or remove its bookmark
+pub struct ToggleBookmarkCommand;
+
+#[async_trait]
+impl Command for ToggleBookmarkCommand {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
+        Ok(match context.state.toggle_bookmark() {
+            Ok(message) => CommandResult::SuccessWithMessage(message),
+            Err(e) => CommandResult::Error(e),
+        })
+    }
+
+    fn description(&self) -> &str {
+        "Bookmark the selected table or SQL file"
+    }
+
+    fn id(&self) -> CommandId {
+        CommandId::ToggleBookmark
+    }
+
+    fn shortcut(&self) -> Option<String> {
+        Some("m".to_string())
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        &["bookmark"]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Navigation
+    }
+}
+
+/// List bookmarked tables and SQL files
+pub struct ShowBookmarksCommand;
+
+#[async_trait]
+impl Command for ShowBookmarksCommand {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
+        context.state.open_bookmark_list();
+        Ok(CommandResult::Success)
+    }
+
+    fn description(&self) -> &str {
+        "Jump to a bookmarked table or SQL file"
+    }
+
+    fn id(&self) -> CommandId {
+        CommandId::ShowBookmarks
+    }
+
+    fn shortcut(&self) -> Option<String> {
+        Some("'".to_string())
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        &["bookmarks"]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Navigation
+    }
+}
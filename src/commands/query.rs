@@ -5,12 +5,14 @@
 use super::{Command, CommandAction, CommandCategory, CommandContext, CommandId, CommandResult};
 use crate::core::error::Result;
 use crate::database::DatabaseType;
+use async_trait::async_trait;
 
 /// Execute query command
 pub struct ExecuteQueryCommand;
 
+#[async_trait]
 impl Command for ExecuteQueryCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         // Get the query to execute from the current query editor
         let query = if !context.state.query_content.is_empty() {
             context.state.query_content.clone()
@@ -79,6 +81,10 @@ impl Command for ExecuteQueryCommand {
         CommandId::ExecuteQuery
     }
 
+    fn names(&self) -> &'static [&'static str] {
+        &["run", "execute"]
+    }
+
     fn shortcut(&self) -> Option<String> {
         Some("Ctrl+Enter".to_string())
     }
@@ -106,16 +112,23 @@ impl Command for ExecuteQueryCommand {
 /// Save query command
 pub struct SaveQueryCommand;
 
+#[async_trait]
 impl Command for SaveQueryCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         if context.state.query_content.is_empty() {
             return Ok(CommandResult::Error("No query to save".to_string()));
         }
         let query = &context.state.query_content;
 
-        // Generate filename with timestamp
+        // A name given on the command line wins, then the open file, then a timestamp
         let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
-        let filename = if let Some(ref current_file) = context.state.ui.current_sql_file {
+        let filename = if let Some(name) = context.args.first() {
+            if name.ends_with(".sql") {
+                name.clone()
+            } else {
+                format!("{name}.sql")
+            }
+        } else if let Some(ref current_file) = context.state.ui.current_sql_file {
             current_file.clone()
         } else {
             format!("query_{timestamp}.sql")
@@ -129,14 +142,10 @@ impl Command for SaveQueryCommand {
 
         let filepath = sql_dir.join(&filename);
 
-        // Use async file I/O with block_on (Command trait doesn't support async)
-        // TODO: Move to background task with event notification for truly non-blocking operation
-        tokio::runtime::Handle::current().block_on(async {
-            // Ensure directory exists
-            crate::io::async_fs::create_dir_all(&sql_dir).await?;
-            // Write file
-            crate::io::async_fs::write(&filepath, query).await
-        })?;
+        // Ensure directory exists
+        crate::io::async_fs::create_dir_all(&sql_dir).await?;
+        // Write file
+        crate::io::async_fs::write(&filepath, query).await?;
 
         // Update state
         context.state.ui.current_sql_file = Some(filename.clone());
@@ -162,6 +171,10 @@ impl Command for SaveQueryCommand {
         CommandId::SaveQuery
     }
 
+    fn names(&self) -> &'static [&'static str] {
+        &["save"]
+    }
+
     fn shortcut(&self) -> Option<String> {
         Some("Ctrl+s".to_string())
     }
@@ -175,6 +188,57 @@ impl Command for SaveQueryCommand {
     }
 }
 
+/// `:copy <file>` - write the last query result to a CSV file, quoted like
+/// psql's `\copy ... TO ... WITH (FORMAT csv, HEADER, NULL '')`. Rows are
+/// already text, so MySQL and SQLite results come out the same way.
+pub struct CopyResultsCommand;
+
+#[async_trait]
+impl Command for CopyResultsCommand {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
+        let Some(filename) = context.args.first() else {
+            return Ok(CommandResult::Error("Usage: :copy <filename>".to_string()));
+        };
+        let Some((columns, rows)) = &context.state.last_query_result else {
+            return Ok(CommandResult::Error(
+                "No query result to copy - run a query that returns rows first".to_string(),
+            ));
+        };
+
+        let csv = crate::ui::components::results_to_csv(columns, rows);
+        let path = crate::ui::components::expand_tilde(filename);
+        if let Err(e) = crate::io::async_fs::write(&path, &csv).await {
+            return Ok(CommandResult::Error(format!(
+                "Failed to write {}: {e}",
+                path.display()
+            )));
+        }
+
+        Ok(CommandResult::SuccessWithMessage(format!(
+            "Copied {} rows to {} ({})",
+            rows.len(),
+            path.display(),
+            crate::database::TableMetadata::format_size(csv.len() as i64)
+        )))
+    }
+
+    fn description(&self) -> &str {
+        "Write the last query result to a CSV file"
+    }
+
+    fn id(&self) -> CommandId {
+        CommandId::CopyResults
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        &["copy"]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Query
+    }
+}
+
 /// Validate SQL query syntax for specific database types
 fn validate_query_syntax(
     query: &str,
@@ -226,6 +290,20 @@ fn validate_query_syntax(
                 return Err("UNSIGNED modifier is not supported in SQLite".to_string());
             }
         }
+        DatabaseType::MsSql => {
+            if query_upper.contains("AUTO_INCREMENT") {
+                return Err(
+                    "AUTO_INCREMENT is not supported in SQL Server. Use IDENTITY instead"
+                        .to_string(),
+                );
+            }
+            if query_upper.contains("LIMIT ") {
+                return Err(
+                    "LIMIT is not supported in SQL Server. Use TOP or OFFSET ... FETCH instead"
+                        .to_string(),
+                );
+            }
+        }
         DatabaseType::Oracle | DatabaseType::Redis | DatabaseType::MongoDB => {
             // Basic validation for other database types
             // Can be expanded as support is added
@@ -259,8 +337,9 @@ fn validate_query_syntax(
 /// New query command
 pub struct NewQueryCommand;
 
+#[async_trait]
 impl Command for NewQueryCommand {
-    fn execute(&self, context: &mut CommandContext) -> Result<CommandResult> {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
         // Clear current query
         context.state.query_content.clear();
         context.state.ui.current_sql_file = None;
@@ -292,3 +371,50 @@ impl Command for NewQueryCommand {
         CommandCategory::Query
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::AppState;
+    use crate::config::Config;
+
+    async fn copy(state: &mut AppState, args: &[&str]) -> CommandResult {
+        let config = Config::default();
+        let mut context = CommandContext {
+            state,
+            config: &config,
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        };
+        CopyResultsCommand.execute(&mut context).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_copy_results_to_csv() {
+        let mut state = AppState::default();
+        let result = copy(&mut state, &[]).await;
+        assert!(matches!(result, CommandResult::Error(msg) if msg == "Usage: :copy <filename>"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.csv");
+        let path_arg = path.display().to_string();
+        let result = copy(&mut state, &[&path_arg]).await;
+        assert!(matches!(result, CommandResult::Error(msg) if msg.starts_with("No query result")));
+        assert!(!path.exists());
+
+        state.last_query_result = Some((
+            vec!["id".to_string(), "note".to_string()],
+            vec![
+                vec![Some("1".to_string()), Some("a, b".to_string())],
+                vec![Some("2".to_string()), None],
+            ],
+        ));
+        let result = copy(&mut state, &[&path_arg]).await;
+        assert!(
+            matches!(result, CommandResult::SuccessWithMessage(msg) if msg.starts_with("Copied 2 rows"))
+        );
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "id,note\n1,\"a, b\"\n2,\n"
+        );
+    }
+}
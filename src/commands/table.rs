@@ -0,0 +1,105 @@
+// FilePath: src/commands/table.rs
+
+#![forbid(unsafe_code)]
+
+use super::{Command, CommandAction, CommandCategory, CommandContext, CommandId, CommandResult};
+use crate::core::error::Result;
+use async_trait::async_trait;
+
+/// Refresh Tables command - re-lists database objects in the background
+pub struct RefreshTablesCommand;
+
+#[async_trait]
+impl Command for RefreshTablesCommand {
+    async fn execute(&self, _context: &mut CommandContext<'_>) -> Result<CommandResult> {
+        Ok(CommandResult::Action(CommandAction::RefreshObjectList))
+    }
+
+    fn description(&self) -> &str {
+        "Refresh the tables list"
+    }
+
+    fn id(&self) -> CommandId {
+        CommandId::RefreshTables
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        &["refresh"]
+    }
+
+    fn shortcut(&self) -> Option<String> {
+        Some("r".to_string())
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Table
+    }
+}
+
+/// Import CSV command - opens the CSV import modal for the selected table
+pub struct ImportCsvCommand;
+
+#[async_trait]
+impl Command for ImportCsvCommand {
+    async fn execute(&self, context: &mut CommandContext<'_>) -> Result<CommandResult> {
+        use crate::database::DatabaseType;
+        use crate::ui::components::CsvImportModal;
+
+        let Some(connection) = context
+            .state
+            .db
+            .connections
+            .connections
+            .iter()
+            .find(|c| c.is_connected())
+        else {
+            return Ok(CommandResult::Error(
+                "Connect to a PostgreSQL database first".to_string(),
+            ));
+        };
+        if connection.database_type != DatabaseType::PostgreSQL {
+            return Ok(CommandResult::Error(
+                "CSV import uses COPY and needs a PostgreSQL connection".to_string(),
+            ));
+        }
+        let connection_id = connection.id.clone();
+        let database_type = connection.database_type.clone();
+
+        let Some(table_name) = context.state.ui.get_selected_table_name() else {
+            return Ok(CommandResult::Error(
+                "Select a table to import into".to_string(),
+            ));
+        };
+
+        context.state.csv_import = Some(CsvImportModal::new(
+            connection_id,
+            database_type,
+            table_name,
+        ));
+        Ok(CommandResult::Success)
+    }
+
+    fn description(&self) -> &str {
+        "Import a CSV file into the selected table"
+    }
+
+    fn id(&self) -> CommandId {
+        CommandId::ImportCsv
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        &["import-csv"]
+    }
+
+    fn shortcut(&self) -> Option<String> {
+        Some("I".to_string())
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Table
+    }
+
+    fn can_execute(&self, context: &CommandContext) -> bool {
+        context.state.ui.focused_pane == crate::app::FocusedPane::Tables
+    }
+}
@@ -0,0 +1,43 @@
+// FilePath: src/commands/workspace.rs
+
+#![forbid(unsafe_code)]
+
+use super::CommandId;
+
+/// Parse a workspace `:` command into its command ID and optional name.
+///
+/// `:ws <name>` saves the session, `:wl <name>` restores one; `:wl` alone
+/// opens the workspace browser. Returns `None` for any other input.
+pub fn parse_workspace_command(input: &str) -> Option<(CommandId, Option<String>)> {
+    let mut parts = input.trim().splitn(2, char::is_whitespace);
+    let id = match parts.next()? {
+        ":ws" => CommandId::SaveWorkspace,
+        ":wl" => CommandId::LoadWorkspace,
+        _ => return None,
+    };
+    let name = parts
+        .next()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string);
+    Some((id, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_workspace_command() {
+        assert_eq!(
+            parse_workspace_command(":ws daily report"),
+            Some((CommandId::SaveWorkspace, Some("daily report".to_string())))
+        );
+        assert_eq!(
+            parse_workspace_command(":wl  "),
+            Some((CommandId::LoadWorkspace, None))
+        );
+        assert_eq!(parse_workspace_command(":w"), None);
+        assert_eq!(parse_workspace_command(":wq"), None);
+    }
+}
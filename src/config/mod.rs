@@ -5,7 +5,7 @@
 use crate::core::error::Result;
 // Removed directories crate, using dirs crate for home_dir
 use serde::{Deserialize, Serialize};
-use std::{fs, path::PathBuf};
+use std::{collections::HashMap, fs, path::PathBuf};
 
 /// Application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,6 +18,24 @@ pub struct Config {
     pub connections: ConnectionsConfig,
     /// Keybindings
     pub keybindings: KeybindingsConfig,
+    /// Query results settings
+    #[serde(default)]
+    pub results: ResultsConfig,
+    /// Script execution settings
+    #[serde(default)]
+    pub query: QueryConfig,
+    /// Pane proportions, written back when they are resized
+    #[serde(default)]
+    pub layout: LayoutConfig,
+    /// Toast display durations and history size
+    #[serde(default)]
+    pub toasts: ToastConfig,
+    /// Modules shown in each part of the status bar
+    #[serde(default)]
+    pub status_bar: StatusBarConfig,
+    /// File this configuration was loaded from
+    #[serde(skip)]
+    pub source_path: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +48,10 @@ pub struct ThemeConfig {
 pub struct EditorConfig {
     pub tab_size: usize,
     pub show_line_numbers: bool,
+    /// Number query editor lines by distance from the cursor, Vim's
+    /// `relativenumber`
+    #[serde(default)]
+    pub relative_line_numbers: bool,
     pub highlight_current_line: bool,
     pub auto_complete: bool,
 }
@@ -39,20 +61,227 @@ pub struct ConnectionsConfig {
     pub auto_reconnect: bool,
     pub connection_timeout: u64,
     pub max_connections: usize,
+    /// Seconds before "Test connection" in the connection form gives up
+    #[serde(default = "default_test_timeout_secs")]
+    pub test_timeout_secs: u64,
+}
+
+/// Older config files predate `test_timeout_secs`
+fn default_test_timeout_secs() -> u64 {
+    10
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeybindingsConfig {
     pub leader_key: String,
+    /// Pane action overrides from `[keybindings.actions]`, e.g. `delete_row = "dd"`.
+    /// Actions not listed keep their default bindings.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub actions: HashMap<String, BindingSpec>,
+}
+
+/// One key binding or a list of alternatives for an action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BindingSpec {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultsConfig {
+    /// Maximum number of differences displayed in a results diff tab
+    pub max_diff_entries: usize,
+    /// Cell values longer than this many bytes are cut for display; editing
+    /// and copying fetch the full value
+    #[serde(default = "default_max_cell_bytes")]
+    pub max_cell_bytes: usize,
+    /// Leftmost columns of each new results tab that stay in view while
+    /// scrolling right
+    #[serde(default)]
+    pub frozen_columns: usize,
+    /// Size the columns of each new results tab to their content instead of
+    /// a fixed width
+    #[serde(default)]
+    pub auto_fit_columns: bool,
+}
+
+/// Older config files predate `max_cell_bytes`
+fn default_max_cell_bytes() -> usize {
+    8192
+}
+
+impl Default for ResultsConfig {
+    fn default() -> Self {
+        Self {
+            max_diff_entries: 500,
+            max_cell_bytes: default_max_cell_bytes(),
+            frozen_columns: 0,
+            auto_fit_columns: false,
+        }
+    }
+}
+
+/// How "run all" splits and executes the editor buffer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QueryConfig {
+    /// Ends a statement; a word such as `GO` must stand alone on its line
+    pub statement_separator: String,
+    /// Keep running the remaining statements after one fails
+    pub continue_on_error: bool,
+}
+
+impl Default for QueryConfig {
+    fn default() -> Self {
+        Self {
+            statement_separator: crate::database::sql_split::DEFAULT_SEPARATOR.to_string(),
+            continue_on_error: false,
+        }
+    }
+}
+
+/// Proportions of the left column, in percent
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LayoutConfig {
+    /// Width of the left column (Connections, Tables, Details)
+    pub left_width_percent: u16,
+    pub connections_height_percent: u16,
+    /// Details gets the rest of the left column
+    pub tables_height_percent: u16,
+    /// Height of the results, above the query editor
+    pub output_height_percent: u16,
+    /// Width of the SQL files column beside the query editor
+    pub sql_files_width_percent: u16,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            left_width_percent: 25,
+            connections_height_percent: 40,
+            tables_height_percent: 40,
+            output_height_percent: 65,
+            sql_files_width_percent: 25,
+        }
+    }
+}
+
+/// How long each toast severity stays on screen, in seconds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToastConfig {
+    pub info_secs: u64,
+    pub success_secs: u64,
+    pub warning_secs: u64,
+    pub error_secs: u64,
+    /// Toasts kept for the history overlay (`~` or `:messages`)
+    pub history_size: usize,
+}
+
+impl Default for ToastConfig {
+    fn default() -> Self {
+        Self {
+            info_secs: 3,
+            success_secs: 3,
+            warning_secs: 4,
+            error_secs: 5,
+            history_size: 200,
+        }
+    }
+}
+
+/// Piece of information the status bar can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusModule {
+    /// App name and version
+    Brand,
+    /// Selected connection's host, database and state
+    Connection,
+    /// Focused pane and the cursor position in it
+    Position,
+    /// Editor or table viewer mode, e.g. INSERT or READ-ONLY
+    Mode,
+    Datetime,
+    /// `GIT_BRANCH` environment variable
+    GitBranch,
+    Encoding,
+    /// Lines in the query editor
+    LineCount,
+}
+
+impl StatusModule {
+    /// Module for a name used in `[status_bar]`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "brand" => Self::Brand,
+            "connection" => Self::Connection,
+            "position" => Self::Position,
+            "mode" => Self::Mode,
+            "datetime" => Self::Datetime,
+            "git_branch" => Self::GitBranch,
+            "encoding" => Self::Encoding,
+            "line_count" => Self::LineCount,
+            _ => return None,
+        })
+    }
+}
+
+/// Module names shown in each status bar slot, e.g.
+/// `left = ["brand", "connection"]`. Unknown names are skipped.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatusBarConfig {
+    pub left: Vec<String>,
+    /// Cut first when the bar is too narrow
+    pub center: Vec<String>,
+    pub right: Vec<String>,
+}
+
+impl StatusBarConfig {
+    /// Modules of a slot, in order
+    pub fn modules(slot: &[String]) -> Vec<StatusModule> {
+        slot.iter()
+            .filter_map(|name| StatusModule::from_name(name))
+            .collect()
+    }
+
+    /// Names in any slot that aren't modules
+    pub fn unknown_modules(&self) -> Vec<&str> {
+        self.left
+            .iter()
+            .chain(&self.center)
+            .chain(&self.right)
+            .map(String::as_str)
+            .filter(|name| StatusModule::from_name(name).is_none())
+            .collect()
+    }
+}
+
+impl Default for StatusBarConfig {
+    fn default() -> Self {
+        let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        Self {
+            left: names(&["brand", "connection", "position", "mode"]),
+            center: Vec::new(),
+            right: names(&["datetime"]),
+        }
+    }
 }
 
 impl Config {
     /// Load configuration from file or create default
     pub fn load(path: Option<PathBuf>) -> Result<Self> {
         let config_path = path.unwrap_or_else(Self::default_path);
+        let mut config = Self::load_from(&config_path)?;
+        config.source_path = Some(config_path);
+        Ok(config)
+    }
 
+    fn load_from(config_path: &PathBuf) -> Result<Self> {
         if config_path.exists() {
-            let contents = fs::read_to_string(&config_path)?;
+            let contents = fs::read_to_string(config_path)?;
 
             // Try to parse as new format first
             match toml::from_str::<Config>(&contents) {
@@ -63,18 +292,18 @@ impl Config {
 
                     // Backup old config
                     let backup_path = config_path.with_extension("toml.backup");
-                    let _ = fs::copy(&config_path, &backup_path);
+                    let _ = fs::copy(config_path, &backup_path);
 
                     // Create new default config
                     let config = Self::default();
-                    let _ = config.save(&config_path);
+                    let _ = config.save(config_path);
                     Ok(config)
                 }
             }
         } else {
             let config = Self::default();
             // Try to save default config
-            let _ = config.save(&config_path);
+            let _ = config.save(config_path);
             Ok(config)
         }
     }
@@ -203,6 +432,7 @@ impl Default for Config {
             editor: EditorConfig {
                 tab_size: 4,
                 show_line_numbers: true,
+                relative_line_numbers: false,
                 highlight_current_line: true,
                 auto_complete: true,
             },
@@ -210,10 +440,65 @@ impl Default for Config {
                 auto_reconnect: true,
                 connection_timeout: 5000,
                 max_connections: 10,
+                test_timeout_secs: default_test_timeout_secs(),
             },
             keybindings: KeybindingsConfig {
                 leader_key: " ".to_string(),
+                actions: HashMap::new(),
             },
+            results: ResultsConfig::default(),
+            query: QueryConfig::default(),
+            layout: LayoutConfig::default(),
+            toasts: ToastConfig::default(),
+            status_bar: StatusBarConfig::default(),
+            source_path: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connections_config_defaults_test_timeout() {
+        let config: ConnectionsConfig = toml::from_str(
+            "auto_reconnect = true\nconnection_timeout = 5000\nmax_connections = 10\n",
+        )
+        .unwrap();
+        assert_eq!(config.test_timeout_secs, 10);
+    }
+
+    #[test]
+    fn test_layout_section_is_optional() {
+        let mut contents = toml::to_string(&Config::default()).unwrap();
+        let start = contents.find("[layout]").unwrap();
+        contents.truncate(start);
+        let config: Config = toml::from_str(&contents).unwrap();
+        assert_eq!(config.layout, LayoutConfig::default());
+
+        let layout: LayoutConfig = toml::from_str("left_width_percent = 30\n").unwrap();
+        assert_eq!(layout.left_width_percent, 30);
+        assert_eq!(layout.tables_height_percent, 40);
+    }
+
+    #[test]
+    fn test_toasts_section_fills_missing_fields() {
+        let toasts: ToastConfig = toml::from_str("error_secs = 12\n").unwrap();
+        assert_eq!(toasts.error_secs, 12);
+        assert_eq!(toasts.info_secs, 3);
+        assert_eq!(toasts.history_size, 200);
+    }
+
+    #[test]
+    fn test_status_bar_slots_skip_unknown_modules() {
+        let status_bar: StatusBarConfig =
+            toml::from_str("center = [\"git_branch\", \"clock\", \"line_count\"]\n").unwrap();
+        assert_eq!(
+            StatusBarConfig::modules(&status_bar.center),
+            vec![StatusModule::GitBranch, StatusModule::LineCount]
+        );
+        assert_eq!(status_bar.unknown_modules(), vec!["clock"]);
+        assert_eq!(status_bar.left, StatusBarConfig::default().left);
+    }
+}
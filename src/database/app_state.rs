@@ -9,6 +9,9 @@ use sqlx::sqlite::SqlitePool;
 use sqlx::Row;
 use std::path::PathBuf;
 
+/// Recently opened tables remembered per connection
+pub const RECENT_TABLES_LIMIT: usize = 20;
+
 /// Application state stored in local SQLite database
 #[derive(Debug, Clone)]
 pub struct AppStateDb {
@@ -119,6 +122,21 @@ impl AppStateDb {
             )
             .execute(pool)
             .await?;
+
+            // Create recent_tables table for the Ctrl+T quick switcher's MRU list
+            sqlx::query(
+                r#"
+                CREATE TABLE IF NOT EXISTS recent_tables (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    connection_id TEXT NOT NULL,
+                    table_name TEXT NOT NULL,
+                    last_opened DATETIME NOT NULL,
+                    UNIQUE(connection_id, table_name)
+                )
+                "#,
+            )
+            .execute(pool)
+            .await?;
         }
 
         Ok(())
@@ -293,6 +311,63 @@ impl AppStateDb {
         Ok(Vec::new())
     }
 
+    /// Record that a table was opened, keeping the newest `RECENT_TABLES_LIMIT` per connection
+    pub async fn record_recent_table(&self, connection_id: &str, table_name: &str) -> Result<()> {
+        if let Some(ref pool) = self.pool {
+            sqlx::query(
+                r#"
+                INSERT INTO recent_tables (connection_id, table_name, last_opened)
+                VALUES (?, ?, ?)
+                ON CONFLICT(connection_id, table_name) DO UPDATE SET
+                    last_opened = excluded.last_opened
+                "#,
+            )
+            .bind(connection_id)
+            .bind(table_name)
+            .bind(chrono::Utc::now())
+            .execute(pool)
+            .await?;
+
+            sqlx::query(
+                r#"
+                DELETE FROM recent_tables
+                WHERE connection_id = ?1 AND id NOT IN (
+                    SELECT id FROM recent_tables
+                    WHERE connection_id = ?1
+                    ORDER BY last_opened DESC
+                    LIMIT ?2
+                )
+                "#,
+            )
+            .bind(connection_id)
+            .bind(RECENT_TABLES_LIMIT as i64)
+            .execute(pool)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Recently opened tables of a connection, newest first
+    pub async fn get_recent_tables(&self, connection_id: &str) -> Result<Vec<String>> {
+        if let Some(ref pool) = self.pool {
+            let rows = sqlx::query(
+                r#"
+                SELECT table_name FROM recent_tables
+                WHERE connection_id = ?
+                ORDER BY last_opened DESC
+                "#,
+            )
+            .bind(connection_id)
+            .fetch_all(pool)
+            .await?;
+
+            return Ok(rows.into_iter().map(|row| row.get("table_name")).collect());
+        }
+
+        Ok(Vec::new())
+    }
+
     /// Get connection session history
     pub async fn get_connection_history(
         &self,
@@ -0,0 +1,373 @@
+// FilePath: src/database/backup.rs
+
+#![forbid(unsafe_code)]
+
+use crate::core::error::{LazyTablesError, Result};
+use crate::database::{
+    ddl::{create_table_sql, quote_identifier, quote_qualified_identifier},
+    ColumnDefinition, ConnectionManager, DatabaseType, TableColumn,
+};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncWriteExt, BufWriter};
+
+/// Rows fetched and written per `INSERT` statement
+pub const BACKUP_BATCH_ROWS: usize = 1000;
+
+/// Live counters of a running dump, shared between the task and the UI
+#[derive(Debug, Clone)]
+pub struct BackupProgress {
+    /// What is being dumped, e.g. `users` or `schema public`
+    pub label: String,
+    pub path: PathBuf,
+    pub table_count: usize,
+    tables_done: Arc<AtomicUsize>,
+    rows: Arc<AtomicU64>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl BackupProgress {
+    pub fn new(label: String, path: PathBuf, table_count: usize) -> Self {
+        Self {
+            label,
+            path,
+            table_count,
+            tables_done: Arc::new(AtomicUsize::new(0)),
+            rows: Arc::new(AtomicU64::new(0)),
+            cancel: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn rows(&self) -> u64 {
+        self.rows.load(Ordering::Relaxed)
+    }
+
+    pub fn tables_done(&self) -> usize {
+        self.tables_done.load(Ordering::Relaxed)
+    }
+
+    /// Ask the task to stop after the batch it is writing
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+
+    /// Status bar text, e.g. `users: 12000 rows` or `schema public: 3/8 tables, 12000 rows`
+    pub fn summary(&self) -> String {
+        if self.table_count > 1 {
+            format!(
+                "{}: {}/{} tables, {} rows",
+                self.label,
+                self.tables_done(),
+                self.table_count,
+                self.rows()
+            )
+        } else {
+            format!("{}: {} rows", self.label, self.rows())
+        }
+    }
+}
+
+/// `<backups>/<connection>/<name>_<timestamp>.sql`, with path-unsafe characters replaced
+pub fn backup_file_path(
+    backups_dir: &Path,
+    connection: &str,
+    name: &str,
+    timestamp: &str,
+) -> PathBuf {
+    let sanitize = |part: &str| -> String {
+        part.chars()
+            .map(|c| {
+                if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    };
+    backups_dir
+        .join(sanitize(connection))
+        .join(format!("{}_{timestamp}.sql", sanitize(name)))
+}
+
+/// Where an interrupted dump is moved so it can't be mistaken for a full one
+pub fn partial_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".partial");
+    path.with_file_name(name)
+}
+
+/// `SELECT` returning each value as a ready-made SQL literal (`NULL` unquoted),
+/// so the server does the escaping for its own dialect
+pub fn select_literals_sql(
+    table_name: &str,
+    columns: &[TableColumn],
+    database_type: &DatabaseType,
+    limit: usize,
+    offset: usize,
+) -> Result<String> {
+    let quote = match database_type {
+        DatabaseType::PostgreSQL => "quote_nullable",
+        DatabaseType::MySQL | DatabaseType::MariaDB => "QUOTE",
+        DatabaseType::SQLite => "quote",
+        other => {
+            return Err(LazyTablesError::Unsupported(format!(
+                "SQL backups are not supported for {}",
+                other.display_name()
+            )))
+        }
+    };
+    let cast = if *database_type == DatabaseType::PostgreSQL {
+        "::text"
+    } else {
+        ""
+    };
+
+    let select_list = columns
+        .iter()
+        .map(|column| {
+            format!(
+                "{quote}({}{cast})",
+                quote_identifier(&column.name, database_type)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    // Page in primary key order so OFFSET is stable while the dump runs
+    let primary_keys: Vec<String> = columns
+        .iter()
+        .filter(|column| column.is_primary_key)
+        .map(|column| quote_identifier(&column.name, database_type))
+        .collect();
+    let order_by = if primary_keys.is_empty() {
+        String::new()
+    } else {
+        format!(" ORDER BY {}", primary_keys.join(", "))
+    };
+
+    Ok(format!(
+        "SELECT {select_list} FROM {}{order_by} LIMIT {limit} OFFSET {offset}",
+        quote_qualified_identifier(table_name, database_type)
+    ))
+}
+
+/// Multi-row `INSERT` for rows of SQL literals; a missing literal is `NULL`
+pub fn insert_sql(
+    table_name: &str,
+    columns: &[TableColumn],
+    rows: &[Vec<Option<String>>],
+    database_type: &DatabaseType,
+) -> String {
+    let names = columns
+        .iter()
+        .map(|column| quote_identifier(&column.name, database_type))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let values = rows
+        .iter()
+        .map(|row| {
+            let literals: Vec<&str> = row
+                .iter()
+                .map(|literal| literal.as_deref().unwrap_or("NULL"))
+                .collect();
+            format!("    ({})", literals.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+    format!(
+        "INSERT INTO {} ({names}) VALUES\n{values};\n",
+        quote_identifier(table_name, database_type)
+    )
+}
+
+/// Write `CREATE TABLE` plus batched `INSERT`s for each table to `path`.
+/// Rows are fetched a page at a time so memory stays flat however big the
+/// table is. A cancelled or failed dump is closed with a marker comment and
+/// renamed to `*.sql.partial`.
+pub async fn dump_tables(
+    connection_manager: &ConnectionManager,
+    connection_id: &str,
+    database_type: &DatabaseType,
+    tables: &[String],
+    path: &Path,
+    progress: &BackupProgress,
+) -> Result<u64> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let mut writer = BufWriter::new(tokio::fs::File::create(path).await?);
+
+    let result = write_dump(
+        &mut writer,
+        connection_manager,
+        connection_id,
+        database_type,
+        tables,
+        progress,
+    )
+    .await;
+
+    match result {
+        Ok(rows) => {
+            writer.flush().await?;
+            Ok(rows)
+        }
+        Err(e) => {
+            let marker = format!("\n-- INCOMPLETE BACKUP: {e}\n");
+            let _ = writer.write_all(marker.as_bytes()).await;
+            let _ = writer.flush().await;
+            drop(writer);
+
+            let partial = partial_path(path);
+            tokio::fs::rename(path, &partial).await?;
+            Err(LazyTablesError::Other(format!(
+                "{e}; partial dump kept at {}",
+                partial.display()
+            )))
+        }
+    }
+}
+
+async fn write_dump(
+    writer: &mut BufWriter<tokio::fs::File>,
+    connection_manager: &ConnectionManager,
+    connection_id: &str,
+    database_type: &DatabaseType,
+    tables: &[String],
+    progress: &BackupProgress,
+) -> Result<u64> {
+    let header = format!(
+        "-- LazyTables SQL dump of {}\n-- Database type: {}\n-- Created: {}\n\n",
+        progress.label,
+        database_type.display_name(),
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+    );
+    writer.write_all(header.as_bytes()).await?;
+
+    for table_name in tables {
+        let columns = connection_manager
+            .get_table_columns(connection_id, table_name)
+            .await?;
+        let definitions: Vec<ColumnDefinition> = columns.iter().map(Into::into).collect();
+        // Bare names, so the dump replays into whatever schema is current
+        let table = table_name
+            .rsplit_once('.')
+            .map_or(table_name.as_str(), |(_, table)| table);
+
+        let ddl = format!(
+            "-- Table: {table_name}\n{};\n\n",
+            create_table_sql(table, &definitions, database_type)
+        );
+        writer.write_all(ddl.as_bytes()).await?;
+
+        let mut offset = 0;
+        loop {
+            if progress.is_cancelled() {
+                return Err(LazyTablesError::Other("Backup cancelled".to_string()));
+            }
+
+            let query = select_literals_sql(
+                table_name,
+                &columns,
+                database_type,
+                BACKUP_BATCH_ROWS,
+                offset,
+            )?;
+            let (_, rows) = connection_manager
+                .execute_raw_query(connection_id, &query)
+                .await?;
+            if rows.is_empty() {
+                break;
+            }
+
+            writer
+                .write_all(insert_sql(table, &columns, &rows, database_type).as_bytes())
+                .await?;
+            progress
+                .rows
+                .fetch_add(rows.len() as u64, Ordering::Relaxed);
+            offset += rows.len();
+            if rows.len() < BACKUP_BATCH_ROWS {
+                break;
+            }
+        }
+
+        writer.write_all(b"\n").await?;
+        progress.tables_done.fetch_add(1, Ordering::Relaxed);
+    }
+
+    Ok(progress.rows())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::DataType;
+
+    fn column(name: &str, is_primary_key: bool) -> TableColumn {
+        TableColumn {
+            name: name.to_string(),
+            data_type: DataType::Text,
+            is_nullable: !is_primary_key,
+            default_value: None,
+            is_primary_key,
+        }
+    }
+
+    #[test]
+    fn test_dump_statements_per_engine() {
+        let columns = vec![column("id", true), column("note", false)];
+
+        assert_eq!(
+            select_literals_sql(
+                "sales.orders",
+                &columns,
+                &DatabaseType::PostgreSQL,
+                1000,
+                2000
+            )
+            .unwrap(),
+            "SELECT quote_nullable(\"id\"::text), quote_nullable(\"note\"::text) \
+             FROM \"sales\".\"orders\" ORDER BY \"id\" LIMIT 1000 OFFSET 2000"
+        );
+        assert_eq!(
+            select_literals_sql("orders", &columns[1..], &DatabaseType::MySQL, 10, 0).unwrap(),
+            "SELECT QUOTE(`note`) FROM `orders` LIMIT 10 OFFSET 0"
+        );
+        assert!(select_literals_sql("t", &columns, &DatabaseType::Oracle, 10, 0).is_err());
+
+        let rows = vec![
+            vec![Some("'1'".to_string()), Some("'it''s'".to_string())],
+            vec![Some("'2'".to_string()), Some("NULL".to_string())],
+            vec![Some("'3'".to_string()), None],
+        ];
+        assert_eq!(
+            insert_sql("orders", &columns, &rows, &DatabaseType::SQLite),
+            "INSERT INTO \"orders\" (\"id\", \"note\") VALUES\n    ('1', 'it''s'),\n    ('2', NULL),\n    ('3', NULL);\n"
+        );
+    }
+
+    #[test]
+    fn test_backup_paths() {
+        let path = backup_file_path(
+            Path::new("/backups"),
+            "prod db",
+            "public.users",
+            "20240101_120000",
+        );
+        assert_eq!(
+            path,
+            PathBuf::from("/backups/prod_db/public.users_20240101_120000.sql")
+        );
+        assert_eq!(
+            partial_path(&path),
+            PathBuf::from("/backups/prod_db/public.users_20240101_120000.sql.partial")
+        );
+    }
+}
@@ -4,7 +4,7 @@
 
 use crate::config::Config;
 use crate::core::error::Result;
-use crate::security::{PasswordManager, PasswordSource};
+use crate::security::{PasswordManager, PasswordSource, REDACTED};
 use serde::{Deserialize, Serialize};
 // Removed: use std::fs; (now using async file I/O)
 
@@ -16,11 +16,20 @@ pub enum DatabaseType {
     MariaDB,
     SQLite,
     Oracle,
+    MsSql,
     Redis,
     MongoDB,
 }
 
 impl DatabaseType {
+    /// Whether a connection can be scoped to a `default_schema`
+    pub fn supports_default_schema(&self) -> bool {
+        matches!(
+            self,
+            Self::PostgreSQL | Self::MySQL | Self::MariaDB | Self::MsSql
+        )
+    }
+
     /// Get display name for the database type
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -29,6 +38,7 @@ impl DatabaseType {
             Self::MariaDB => "mariadb",
             Self::SQLite => "sqlite",
             Self::Oracle => "oracle",
+            Self::MsSql => "mssql",
             Self::Redis => "redis",
             Self::MongoDB => "mongodb",
         }
@@ -36,20 +46,105 @@ impl DatabaseType {
 }
 
 /// SSL/TLS mode for database connections
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub enum SslMode {
     Disable,
     Allow,
+    #[default]
     Prefer,
     Require,
     VerifyCA,
     VerifyFull,
+    /// TLS required with the certificate files given on the connection; the
+    /// server certificate is checked only when a CA file is set
+    Custom,
+}
+
+impl SslMode {
+    /// Modes in the order the connection form lists them
+    pub const ALL: [SslMode; 7] = [
+        SslMode::Disable,
+        SslMode::Allow,
+        SslMode::Prefer,
+        SslMode::Require,
+        SslMode::VerifyCA,
+        SslMode::VerifyFull,
+        SslMode::Custom,
+    ];
+
+    /// Whether the CA and client certificate files apply
+    pub fn uses_certificates(&self) -> bool {
+        matches!(
+            self,
+            SslMode::VerifyCA | SslMode::VerifyFull | SslMode::Custom
+        )
+    }
+}
+
+/// Why a TLS handshake failed, told apart by the driver's message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsFailure {
+    /// The server doesn't accept TLS connections at all
+    Unsupported,
+    /// The server's certificate didn't verify against the CA or host name
+    CertificateRejected,
+    /// A certificate or key file couldn't be used, or the server wanted one
+    CertificateFile,
+}
+
+impl TlsFailure {
+    pub fn classify(error: &str) -> Option<Self> {
+        let error = error.to_lowercase();
+        if error.contains("does not support tls") || error.contains("does not support ssl") {
+            Some(TlsFailure::Unsupported)
+        } else if error.contains("invalid peer certificate")
+            || error.contains("certificate verify failed")
+            || error.contains("unknownissuer")
+            || error.contains("notvalidforname")
+        {
+            Some(TlsFailure::CertificateRejected)
+        } else if error.contains("key and certs must be given together")
+            || error.contains("pem file")
+            || error.contains("invalid certificate")
+            || error.contains("certificaterequired")
+        {
+            Some(TlsFailure::CertificateFile)
+        } else {
+            None
+        }
+    }
+
+    /// What to change to get past the failure
+    pub fn hint(self) -> &'static str {
+        match self {
+            TlsFailure::Unsupported => {
+                "The server does not support SSL: enable it on the server or use SSL mode Prefer or Disable"
+            }
+            TlsFailure::CertificateRejected => {
+                "Certificate verify failed: check the CA certificate file, and for Verify Full that the host matches the server certificate"
+            }
+            TlsFailure::CertificateFile => {
+                "Check the certificate and key files: they must be PEM, and a client certificate needs its key"
+            }
+        }
+    }
+}
+
+/// `Failed to connect to {engine}: {error}`, followed by what to change when
+/// the TLS handshake was the problem
+pub fn connect_error(engine: &str, error: impl std::fmt::Display) -> String {
+    let error = error.to_string();
+    match TlsFailure::classify(&error) {
+        Some(failure) => format!("Failed to connect to {engine}: {error}. {}", failure.hint()),
+        None => format!("Failed to connect to {engine}: {error}"),
+    }
 }
 
 /// Connection status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub enum ConnectionStatus {
     /// Not connected
+    #[default]
     Disconnected,
     /// Currently connecting
     Connecting,
@@ -59,20 +154,8 @@ pub enum ConnectionStatus {
     Failed(String), // Error message
 }
 
-impl Default for ConnectionStatus {
-    fn default() -> Self {
-        Self::Disconnected
-    }
-}
-
-impl Default for SslMode {
-    fn default() -> Self {
-        Self::Prefer
-    }
-}
-
 /// Database connection configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ConnectionConfig {
     /// Unique identifier for this connection
     pub id: String,
@@ -84,6 +167,15 @@ pub struct ConnectionConfig {
     pub host: String,
     /// Port number
     pub port: u16,
+    /// Port given as an environment reference (e.g. `${DB_PORT}`), overrides `port` when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port_env: Option<String>,
+    /// Oracle service name or TNS alias, used instead of `database` when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_name: Option<String>,
+    /// SQL Server named instance, resolved through the SQL Browser service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance_name: Option<String>,
     /// Database name (optional for some database types)
     pub database: Option<String>,
     /// Username for authentication
@@ -96,13 +188,60 @@ pub struct ConnectionConfig {
     pub password: Option<String>,
     /// SSL/TLS configuration
     pub ssl_mode: SslMode,
+    /// CA bundle the server certificate is verified against (PEM path)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssl_ca_cert: Option<String>,
+    /// Client certificate for mutual TLS (PEM path)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssl_client_cert: Option<String>,
+    /// Key of the client certificate (PEM path)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssl_client_key: Option<String>,
     /// Connection timeout in seconds
     pub timeout: Option<u64>,
+    /// Schema the Tables pane lists and unqualified names resolve in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_schema: Option<String>,
+    /// Statements run in order on every new session, before any metadata query
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub init_sql: Vec<String>,
+    /// Pinned to the top of the Connections pane
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub favorite: bool,
     /// Connection status (not persisted, always starts as Disconnected)
     #[serde(skip)]
     pub status: ConnectionStatus,
 }
 
+/// Written by hand so the legacy password never reaches logs
+impl std::fmt::Debug for ConnectionConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectionConfig")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("database_type", &self.database_type)
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("port_env", &self.port_env)
+            .field("service_name", &self.service_name)
+            .field("instance_name", &self.instance_name)
+            .field("database", &self.database)
+            .field("username", &self.username)
+            .field("password_source", &self.password_source)
+            .field("password", &self.password.as_ref().map(|_| REDACTED))
+            .field("ssl_mode", &self.ssl_mode)
+            .field("ssl_ca_cert", &self.ssl_ca_cert)
+            .field("ssl_client_cert", &self.ssl_client_cert)
+            .field("ssl_client_key", &self.ssl_client_key)
+            .field("timeout", &self.timeout)
+            .field("default_schema", &self.default_schema)
+            .field("init_sql", &self.init_sql)
+            .field("favorite", &self.favorite)
+            .field("status", &self.status)
+            .finish()
+    }
+}
+
 impl ConnectionConfig {
     /// Create a new connection configuration
     pub fn new(
@@ -118,16 +257,80 @@ impl ConnectionConfig {
             database_type,
             host,
             port,
+            port_env: None,
+            service_name: None,
+            instance_name: None,
             database: None,
             username,
             password: None,
             password_source: None,
             ssl_mode: SslMode::default(),
+            ssl_ca_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
             timeout: Some(30),
+            default_schema: None,
+            init_sql: Vec::new(),
+            favorite: false,
             status: ConnectionStatus::default(),
         }
     }
 
+    /// CA, client certificate and client key files for the TLS handshake;
+    /// None where unset or when the SSL mode doesn't use them
+    pub fn certificate_paths(&self) -> [Option<std::path::PathBuf>; 3] {
+        let path = |file: &Option<String>| {
+            file.as_deref()
+                .filter(|_| self.ssl_mode.uses_certificates())
+                .map(std::path::PathBuf::from)
+        };
+        [
+            path(&self.ssl_ca_cert),
+            path(&self.ssl_client_cert),
+            path(&self.ssl_client_key),
+        ]
+    }
+
+    /// Check that the certificate files exist and that a client certificate
+    /// comes with its key
+    pub fn validate_certificate_files(&self) -> std::result::Result<(), String> {
+        let [ca, cert, key] = self.certificate_paths();
+        if cert.is_some() != key.is_some() {
+            return Err("Client certificate and key must be given together".to_string());
+        }
+        for (label, path) in [
+            ("CA certificate", ca),
+            ("Client certificate", cert),
+            ("Client key", key),
+        ] {
+            if let Some(path) = path.filter(|path| !path.is_file()) {
+                return Err(format!("{label} '{}' does not exist", path.display()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Statements each new session runs before anything else: switching to
+    /// `default_schema`, then `init_sql`. SQL Server has no session schema,
+    /// so there the default schema only scopes the Tables pane.
+    pub fn session_sql(&self) -> Vec<String> {
+        let mut statements = Vec::new();
+        if let Some(schema) = self.default_schema.as_deref() {
+            let quoted = crate::database::ddl::quote_identifier(schema, &self.database_type);
+            match self.database_type {
+                DatabaseType::PostgreSQL => {
+                    statements.push(format!("SET search_path TO {quoted}, public"))
+                }
+                DatabaseType::MySQL | DatabaseType::MariaDB => {
+                    statements.push(format!("USE {quoted}"))
+                }
+                _ => {}
+            }
+        }
+        statements.extend(self.init_sql.iter().cloned());
+        statements
+    }
+
     /// Get connection display string (e.g., "jatayu (postgres)")
     pub fn display_string(&self) -> String {
         format!("{} ({})", self.name, self.database_type.display_name())
@@ -177,6 +380,32 @@ impl ConnectionConfig {
         }
     }
 
+    /// Return a copy of this configuration with `${VAR}` references in the host,
+    /// port, database, service name and username expanded from the process
+    /// environment.
+    /// The stored configuration keeps the literal references.
+    pub fn with_env_expanded(&self) -> Result<ConnectionConfig> {
+        let mut expanded = self.clone();
+        expanded.host = expand_env_vars(&self.host)?;
+        expanded.username = expand_env_vars(&self.username)?;
+        if let Some(ref database) = self.database {
+            expanded.database = Some(expand_env_vars(database)?);
+        }
+        if let Some(ref service_name) = self.service_name {
+            expanded.service_name = Some(expand_env_vars(service_name)?);
+        }
+        if let Some(ref port_env) = self.port_env {
+            let port = expand_env_vars(port_env)?;
+            expanded.port = port.trim().parse().map_err(|_| {
+                crate::core::error::LazyTablesError::Config(format!(
+                    "Port '{port}' resolved from '{port_env}' is not a valid port number"
+                ))
+            })?;
+            expanded.port_env = None;
+        }
+        Ok(expanded)
+    }
+
     /// Resolve the actual password for this connection
     /// Takes an optional encryption key for encrypted passwords
     pub fn resolve_password(&self, encryption_key: Option<&str>) -> Result<String> {
@@ -241,6 +470,38 @@ impl ConnectionConfig {
     }
 }
 
+/// Connect error for a session statement that failed, naming the statement
+pub fn init_sql_error(statement: &str, error: impl std::fmt::Display) -> String {
+    format!("Init SQL `{statement}` failed: {error}")
+}
+
+/// Expand `${VAR}` references in a connection field from the process environment
+pub fn expand_env_vars(value: &str) -> Result<String> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find('}').ok_or_else(|| {
+            crate::core::error::LazyTablesError::Config(format!(
+                "Unterminated environment reference in '{value}'"
+            ))
+        })?;
+        let name = &after[..end];
+        let resolved = std::env::var(name).map_err(|_| {
+            crate::core::error::LazyTablesError::Config(format!(
+                "Environment variable '{name}' is not set"
+            ))
+        })?;
+        result.push_str(&resolved);
+        rest = &after[end + 1..];
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
+
 /// Container for all saved connections
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ConnectionStorage {
@@ -261,7 +522,8 @@ impl ConnectionStorage {
 
         if path.exists() {
             let contents = crate::io::async_fs::read_to_string(&path).await?;
-            let storage: ConnectionStorage = toml::from_str(&contents)?;
+            let mut storage: ConnectionStorage = toml::from_str(&contents)?;
+            storage.sort_favorites_first();
             Ok(storage)
         } else {
             Ok(Self::default())
@@ -284,8 +546,7 @@ impl ConnectionStorage {
 
     /// Add a new connection asynchronously
     pub async fn add_connection(&mut self, connection: ConnectionConfig) -> Result<()> {
-        // Check for duplicate names
-        if self.connections.iter().any(|c| c.name == connection.name) {
+        if self.name_taken(&connection.name, &connection.id) {
             return Err(crate::core::error::LazyTablesError::ConnectionExists(
                 connection.name,
             ));
@@ -297,12 +558,30 @@ impl ConnectionStorage {
 
     /// Remove a connection by ID asynchronously
     pub async fn remove_connection(&mut self, id: &str) -> Result<()> {
+        let keychain_account = self
+            .connections
+            .iter()
+            .find(|c| c.id == id)
+            .and_then(|c| match &c.password_source {
+                Some(PasswordSource::Keychain { account }) => Some(account.clone()),
+                _ => None,
+            });
+        if let Some(account) = keychain_account {
+            if let Err(e) = PasswordManager::delete_from_keychain(&account) {
+                crate::log_warn!("{e}");
+            }
+        }
         self.connections.retain(|c| c.id != id);
         self.save().await
     }
 
     /// Update a connection asynchronously
     pub async fn update_connection(&mut self, connection: ConnectionConfig) -> Result<()> {
+        if self.name_taken(&connection.name, &connection.id) {
+            return Err(crate::core::error::LazyTablesError::ConnectionExists(
+                connection.name,
+            ));
+        }
         if let Some(index) = self.connections.iter().position(|c| c.id == connection.id) {
             self.connections[index] = connection;
             self.save().await
@@ -313,6 +592,39 @@ impl ConnectionStorage {
         }
     }
 
+    /// Whether a connection other than `id` is already called `name`; names
+    /// must stay unique so saving one never shadows another
+    pub fn name_taken(&self, name: &str, id: &str) -> bool {
+        self.connections
+            .iter()
+            .any(|c| c.id != id && c.name == name)
+    }
+
+    /// Move favorites to the top; the sort is stable so everything else keeps
+    /// its saved order
+    pub fn sort_favorites_first(&mut self) {
+        self.connections.sort_by_key(|c| !c.favorite);
+    }
+
+    /// Flip the favorite flag of the connection at `index`, re-sort and save.
+    /// Returns the connection's new index.
+    pub async fn toggle_favorite(&mut self, index: usize) -> Result<usize> {
+        let Some(connection) = self.connections.get_mut(index) else {
+            return Err(crate::core::error::LazyTablesError::ConnectionNotFound(
+                index.to_string(),
+            ));
+        };
+        connection.favorite = !connection.favorite;
+        let id = connection.id.clone();
+        self.sort_favorites_first();
+        self.save().await?;
+        Ok(self
+            .connections
+            .iter()
+            .position(|c| c.id == id)
+            .unwrap_or(index))
+    }
+
     /// Get connection by ID
     pub fn get_connection(&self, id: &str) -> Option<&ConnectionConfig> {
         self.connections.iter().find(|c| c.id == id)
@@ -344,7 +656,16 @@ pub trait Connection: Send + Sync {
 
     // Query execution capabilities (AC1 requirement)
     /// Execute a raw SQL query and return columns and data
-    async fn execute_raw_query(&self, query: &str) -> Result<(Vec<String>, Vec<Vec<String>>)>;
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)>;
+
+    /// Cancel the statement currently running on this connection. Returns
+    /// `false` when the engine has no server-side cancel.
+    async fn cancel_running_query(&self) -> Result<bool> {
+        Ok(false)
+    }
 
     // Metadata operations (AC1 & AC2 requirements)
     /// List all tables in the current database
@@ -368,7 +689,18 @@ pub trait Connection: Send + Sync {
         table_name: &str,
         limit: usize,
         offset: usize,
-    ) -> Result<Vec<Vec<String>>>;
+    ) -> Result<Vec<Vec<Option<String>>>>;
+
+    /// Access and maintenance counters of a table and its indexes
+    async fn get_table_statistics(
+        &self,
+        _table_name: &str,
+    ) -> Result<crate::database::TableStatistics> {
+        Err(crate::core::error::LazyTablesError::Unsupported(format!(
+            "Table statistics are not available for {}",
+            self.config().database_type.display_name()
+        )))
+    }
 
     // Database-specific capabilities (AC1 & AC2 requirement)
     /// Get database-specific capabilities and features
@@ -467,3 +799,185 @@ pub struct FormattedError {
     pub is_syntax_error: bool,
     pub is_permission_error: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_env_vars() {
+        std::env::set_var("LAZYTABLES_TEST_HOST", "db.internal");
+        assert_eq!(
+            expand_env_vars("${LAZYTABLES_TEST_HOST}").unwrap(),
+            "db.internal"
+        );
+        assert_eq!(
+            expand_env_vars("pg-${LAZYTABLES_TEST_HOST}:1").unwrap(),
+            "pg-db.internal:1"
+        );
+        assert_eq!(expand_env_vars("localhost").unwrap(), "localhost");
+    }
+
+    #[test]
+    fn test_expand_env_vars_unset_variable() {
+        let err = expand_env_vars("${LAZYTABLES_TEST_UNSET_VAR}").unwrap_err();
+        assert!(err.to_string().contains("LAZYTABLES_TEST_UNSET_VAR"));
+    }
+
+    #[test]
+    fn test_with_env_expanded_keeps_stored_config() {
+        std::env::set_var("LAZYTABLES_TEST_PORT", "6543");
+        std::env::set_var("LAZYTABLES_TEST_USER", "reporter");
+        let mut config = ConnectionConfig::new(
+            "test".to_string(),
+            DatabaseType::PostgreSQL,
+            "localhost".to_string(),
+            0,
+            "${LAZYTABLES_TEST_USER}".to_string(),
+        );
+        config.port_env = Some("${LAZYTABLES_TEST_PORT}".to_string());
+
+        let expanded = config.with_env_expanded().unwrap();
+        assert_eq!(expanded.port, 6543);
+        assert_eq!(expanded.username, "reporter");
+        assert_eq!(config.username, "${LAZYTABLES_TEST_USER}");
+        assert_eq!(config.port_env.as_deref(), Some("${LAZYTABLES_TEST_PORT}"));
+    }
+
+    #[test]
+    fn test_favorites_sort_first_and_keep_order() {
+        let mut storage = ConnectionStorage::default();
+        for name in ["alpha", "beta", "gamma", "delta"] {
+            storage.connections.push(ConnectionConfig::new(
+                name.to_string(),
+                DatabaseType::PostgreSQL,
+                "localhost".to_string(),
+                5432,
+                "postgres".to_string(),
+            ));
+        }
+        storage.connections[2].favorite = true;
+        storage.connections[3].favorite = true;
+        storage.sort_favorites_first();
+
+        let names: Vec<&str> = storage
+            .connections
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["gamma", "delta", "alpha", "beta"]);
+
+        // Older files have no flag; only favorites write one
+        let toml = toml::to_string(&storage).unwrap();
+        assert_eq!(toml.matches("favorite = true").count(), 2);
+        assert!(!toml.contains("favorite = false"));
+        let loaded: ConnectionStorage = toml::from_str(&toml).unwrap();
+        assert!(!loaded.connections[2].favorite);
+    }
+
+    #[tokio::test]
+    async fn test_renaming_onto_another_name_is_rejected() {
+        let mut storage = ConnectionStorage::default();
+        for name in ["prod", "prod (copy)"] {
+            storage.connections.push(ConnectionConfig::new(
+                name.to_string(),
+                DatabaseType::PostgreSQL,
+                "localhost".to_string(),
+                5432,
+                "postgres".to_string(),
+            ));
+        }
+        let mut copy = storage.connections[1].clone();
+        assert!(!storage.name_taken(&copy.name, &copy.id));
+
+        copy.name = "prod".to_string();
+        assert!(storage.name_taken(&copy.name, &copy.id));
+        let err = storage.update_connection(copy).await.unwrap_err();
+        assert_eq!(err.to_string(), "Connection 'prod' already exists");
+        assert_eq!(storage.connections[1].name, "prod (copy)");
+    }
+
+    #[test]
+    fn test_session_sql_and_roundtrip() {
+        let mut config = ConnectionConfig::new(
+            "app".to_string(),
+            DatabaseType::PostgreSQL,
+            "localhost".to_string(),
+            5432,
+            "app".to_string(),
+        );
+        assert!(config.session_sql().is_empty());
+
+        config.default_schema = Some("billing".to_string());
+        config.init_sql = vec!["SET ROLE app_rw".to_string()];
+        assert_eq!(
+            config.session_sql(),
+            vec![
+                "SET search_path TO \"billing\", public".to_string(),
+                "SET ROLE app_rw".to_string(),
+            ]
+        );
+
+        let storage = ConnectionStorage {
+            connections: vec![config.clone()],
+            ..Default::default()
+        };
+        let loaded: ConnectionStorage =
+            toml::from_str(&toml::to_string(&storage).unwrap()).unwrap();
+        assert_eq!(loaded.connections[0].default_schema, config.default_schema);
+        assert_eq!(loaded.connections[0].init_sql, config.init_sql);
+
+        config.database_type = DatabaseType::MySQL;
+        assert_eq!(config.session_sql()[0], "USE `billing`");
+        config.database_type = DatabaseType::MsSql;
+        assert_eq!(config.session_sql(), vec!["SET ROLE app_rw".to_string()]);
+    }
+
+    #[test]
+    fn test_certificate_files_and_tls_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let ca = dir.path().join("ca.pem");
+        std::fs::write(&ca, "").unwrap();
+        let mut config = ConnectionConfig::new(
+            "app".to_string(),
+            DatabaseType::PostgreSQL,
+            "db.internal".to_string(),
+            5432,
+            "app".to_string(),
+        );
+        config.ssl_ca_cert = Some(ca.display().to_string());
+        config.ssl_client_key = Some(dir.path().join("client.key").display().to_string());
+
+        // Prefer doesn't use certificate files at all
+        assert_eq!(config.certificate_paths(), [None, None, None]);
+        assert!(config.validate_certificate_files().is_ok());
+
+        config.ssl_mode = SslMode::VerifyFull;
+        assert_eq!(
+            config.validate_certificate_files().unwrap_err(),
+            "Client certificate and key must be given together"
+        );
+        config.ssl_client_cert = Some(ca.display().to_string());
+        assert!(config
+            .validate_certificate_files()
+            .unwrap_err()
+            .starts_with("Client key '"));
+        config.ssl_client_key = None;
+        config.ssl_client_cert = None;
+        assert!(config.validate_certificate_files().is_ok());
+
+        assert_eq!(
+            TlsFailure::classify("error occurred while attempting to establish a TLS connection: server does not support TLS"),
+            Some(TlsFailure::Unsupported)
+        );
+        assert_eq!(
+            TlsFailure::classify("invalid peer certificate: UnknownIssuer"),
+            Some(TlsFailure::CertificateRejected)
+        );
+        assert_eq!(TlsFailure::classify("password authentication failed"), None);
+        assert!(
+            connect_error("MySQL", "invalid peer certificate: NotValidForName")
+                .ends_with(TlsFailure::CertificateRejected.hint())
+        );
+    }
+}
@@ -3,7 +3,10 @@
 #![forbid(unsafe_code)]
 
 use crate::core::error::{LazyTablesError, Result};
+use crate::database::locks::{BackendSignal, LockInfo, POSTGRES_LOCKS_QUERY};
 use crate::database::{connection::Connection, ConnectionConfig};
+use crate::security::SessionKey;
+use sqlx::{mysql::MySqlPool, postgres::PgPool};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -11,6 +14,85 @@ use tokio::sync::Mutex;
 /// Type alias for the complex connection storage type
 type ConnectionStorage = Arc<Mutex<HashMap<String, Arc<Mutex<Box<dyn ManagedConnection>>>>>>;
 
+/// Server-side id of the session running the current statement, if any
+pub type RunningBackend = Arc<std::sync::Mutex<Option<u64>>>;
+
+/// Records a backend id for the lifetime of a statement
+pub struct BackendGuard<'a>(&'a RunningBackend);
+
+impl<'a> BackendGuard<'a> {
+    pub fn record(backend: &'a RunningBackend, id: u64) -> Self {
+        if let Ok(mut running) = backend.lock() {
+            *running = Some(id);
+        }
+        Self(backend)
+    }
+}
+
+impl Drop for BackendGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut running) = self.0.lock() {
+            *running = None;
+        }
+    }
+}
+
+/// Cancels the statement running on a connection. It holds its own pool
+/// handle so a cancel never waits for the connection that is busy.
+#[derive(Debug, Clone)]
+pub enum QueryCanceller {
+    /// `pg_cancel_backend` on the recorded backend pid
+    Postgres {
+        pool: PgPool,
+        backend: RunningBackend,
+    },
+    /// `KILL QUERY` on the recorded connection id
+    MySql {
+        pool: MySqlPool,
+        backend: RunningBackend,
+    },
+    /// `OCIBreak` on the Oracle session
+    #[cfg(feature = "oracle")]
+    Oracle(crate::database::oracle::OracleBreak),
+    /// No server-side cancel; the caller drops the running task instead
+    Abort,
+}
+
+impl QueryCanceller {
+    /// Ask the server to stop the running statement. Returns `false` when
+    /// nothing could be cancelled server-side.
+    pub async fn cancel_running_query(&self) -> Result<bool> {
+        match self {
+            QueryCanceller::Postgres { pool, backend } => {
+                let Some(pid) = running_backend(backend) else {
+                    return Ok(false);
+                };
+                let cancelled: bool = sqlx::query_scalar("SELECT pg_cancel_backend($1)")
+                    .bind(pid as i32)
+                    .fetch_one(pool)
+                    .await?;
+                Ok(cancelled)
+            }
+            QueryCanceller::MySql { pool, backend } => {
+                let Some(id) = running_backend(backend) else {
+                    return Ok(false);
+                };
+                sqlx::query(&format!("KILL QUERY {id}"))
+                    .execute(pool)
+                    .await?;
+                Ok(true)
+            }
+            #[cfg(feature = "oracle")]
+            QueryCanceller::Oracle(session) => session.cancel().await,
+            QueryCanceller::Abort => Ok(false),
+        }
+    }
+}
+
+fn running_backend(backend: &RunningBackend) -> Option<u64> {
+    backend.lock().ok().and_then(|running| *running)
+}
+
 /// Connection manager that maintains persistent database connections
 /// to prevent the connection churning issue where connections are
 /// constantly created and destroyed for each operation
@@ -18,26 +100,68 @@ type ConnectionStorage = Arc<Mutex<HashMap<String, Arc<Mutex<Box<dyn ManagedConn
 /// This trait needs to be object-safe, so all methods use &self and no generics
 #[async_trait::async_trait]
 pub trait ManagedConnection: Send + Sync + std::fmt::Debug {
-    async fn execute_raw_query(&self, query: &str) -> Result<(Vec<String>, Vec<Vec<String>>)>;
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)>;
+    /// Run a statement that returns no rows, reporting the rows it affected
+    async fn execute_statement(&self, statement: &str) -> Result<u64>;
     async fn get_table_data(
         &self,
         table_name: &str,
         limit: usize,
         offset: usize,
-    ) -> Result<Vec<Vec<String>>>;
+    ) -> Result<Vec<Vec<Option<String>>>>;
     async fn get_table_columns(
         &self,
         table_name: &str,
     ) -> Result<Vec<crate::database::TableColumn>>;
     async fn get_table_metadata(&self, table_name: &str) -> Result<crate::database::TableMetadata>;
     async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList>;
+    async fn list_database_objects_page(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList>;
     fn is_connected(&self) -> bool;
+
+    /// Handle for cancelling a running statement without taking the connection lock
+    fn query_canceller(&self) -> QueryCanceller {
+        QueryCanceller::Abort
+    }
+
+    /// Access and maintenance counters of a table and its indexes
+    async fn get_table_statistics(
+        &self,
+        _table_name: &str,
+    ) -> Result<crate::database::TableStatistics> {
+        Err(LazyTablesError::Unsupported(
+            "Table statistics are only available for PostgreSQL and MySQL".to_string(),
+        ))
+    }
+
+    /// Bulk-load a CSV file with `COPY ... FROM STDIN`, returning rows inserted.
+    /// `keep_columns` drops CSV columns that are not mapped to the table.
+    async fn copy_in_csv(
+        &self,
+        _statement: &str,
+        _path: std::path::PathBuf,
+        _keep_columns: Option<Vec<usize>>,
+    ) -> Result<u64> {
+        Err(LazyTablesError::Connection(
+            "CSV import is only supported for PostgreSQL".to_string(),
+        ))
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ConnectionManager {
     /// Active connections keyed by connection ID
     connections: ConnectionStorage,
+    /// Cancel handles keyed by connection ID, usable while a query holds the connection
+    cancellers: Arc<Mutex<HashMap<String, QueryCanceller>>>,
+    /// Key for encrypted passwords, set by the unlock prompt; never persisted
+    session_key: Arc<std::sync::RwLock<Option<SessionKey>>>,
 }
 
 impl ConnectionManager {
@@ -45,9 +169,27 @@ impl ConnectionManager {
     pub fn new() -> Self {
         Self {
             connections: Arc::new(Mutex::new(HashMap::new())),
+            cancellers: Arc::new(Mutex::new(HashMap::new())),
+            session_key: Arc::new(std::sync::RwLock::new(None)),
         }
     }
 
+    /// Use `key` to decrypt encrypted passwords for the rest of the session
+    pub fn unlock(&self, key: SessionKey) {
+        if let Ok(mut session_key) = self.session_key.write() {
+            *session_key = Some(key);
+        }
+    }
+
+    /// Key set by `unlock`, if any
+    pub fn session_key(&self) -> Option<SessionKey> {
+        self.session_key.read().ok().and_then(|key| key.clone())
+    }
+
+    pub fn is_unlocked(&self) -> bool {
+        self.session_key().is_some()
+    }
+
     /// Establish a persistent connection to a database
     /// This replaces the problematic pattern of creating/destroying connections per operation
     pub async fn connect(&self, config: &ConnectionConfig) -> Result<()> {
@@ -64,28 +206,64 @@ impl ConnectionManager {
             connections.remove(&config.id);
         }
 
+        // Encrypted passwords are decrypted with the session key
+        let session_key = self.session_key();
+        let key = session_key.as_ref().map(SessionKey::expose);
+
         // Create new connection based on database type
         let connection: Box<dyn ManagedConnection> = match config.database_type {
             crate::database::DatabaseType::PostgreSQL => {
                 let mut pg_conn =
                     crate::database::postgres::PostgresConnection::new(config.clone());
                 // Establish the connection
-                Connection::connect(&mut pg_conn).await?;
+                Connection::connect_with_key(&mut pg_conn, key).await?;
                 Box::new(pg_conn)
             }
             crate::database::DatabaseType::MySQL | crate::database::DatabaseType::MariaDB => {
                 let mut mysql_conn = crate::database::mysql::MySqlConnection::new(config.clone());
                 // Establish the connection
-                Connection::connect(&mut mysql_conn).await?;
+                Connection::connect_with_key(&mut mysql_conn, key).await?;
                 Box::new(mysql_conn)
             }
             crate::database::DatabaseType::SQLite => {
                 let mut sqlite_conn =
                     crate::database::sqlite::SqliteConnection::new(config.clone());
                 // Establish the connection
-                Connection::connect(&mut sqlite_conn).await?;
+                Connection::connect_with_key(&mut sqlite_conn, key).await?;
                 Box::new(sqlite_conn)
             }
+            #[cfg(feature = "oracle")]
+            crate::database::DatabaseType::Oracle => {
+                let mut oracle_conn =
+                    crate::database::oracle::OracleConnection::new(config.clone());
+                // Establish the connection
+                Connection::connect_with_key(&mut oracle_conn, key).await?;
+                Box::new(oracle_conn)
+            }
+            #[cfg(feature = "mssql")]
+            crate::database::DatabaseType::MsSql => {
+                let mut mssql_conn = crate::database::mssql::MsSqlConnection::new(config.clone());
+                // Establish the connection
+                Connection::connect_with_key(&mut mssql_conn, key).await?;
+                Box::new(mssql_conn)
+            }
+            #[cfg(feature = "redis")]
+            crate::database::DatabaseType::Redis => {
+                let mut redis_conn = crate::database::redis::RedisConnection::new(config.clone());
+                // Establish the connection
+                Connection::connect_with_key(&mut redis_conn, key).await?;
+                Box::new(redis_conn)
+            }
+            #[cfg(feature = "mongodb")]
+            crate::database::DatabaseType::MongoDB => {
+                let mut mongo_conn =
+                    crate::database::mongodb::MongoDbConnection::new(config.clone());
+                // Establish the connection
+                Connection::connect_with_key(&mut mongo_conn, key).await?;
+                Box::new(mongo_conn)
+            }
+            // Reached only for adapters left out of the build
+            #[allow(unreachable_patterns)]
             _ => {
                 return Err(LazyTablesError::Connection(format!(
                     "Database type {} not supported yet",
@@ -94,6 +272,11 @@ impl ConnectionManager {
             }
         };
 
+        self.cancellers
+            .lock()
+            .await
+            .insert(config.id.clone(), connection.query_canceller());
+
         // Store the connected instance
         tracing::debug!("Storing connection with ID: '{}'", config.id);
         connections.insert(config.id.clone(), Arc::new(Mutex::new(connection)));
@@ -131,6 +314,7 @@ impl ConnectionManager {
     /// Disconnect from a specific database
     pub async fn disconnect(&self, connection_id: &str) -> Result<()> {
         let mut connections = self.connections.lock().await;
+        self.cancellers.lock().await.remove(connection_id);
 
         if let Some(_connection_ref) = connections.remove(connection_id) {
             // The connection will be dropped automatically when removed from the map
@@ -146,6 +330,7 @@ impl ConnectionManager {
 
         // Simply clear all connections - they will be automatically dropped
         connections.clear();
+        self.cancellers.lock().await.clear();
 
         Ok(())
     }
@@ -167,12 +352,19 @@ impl ConnectionManager {
         &self,
         connection_id: &str,
         query: &str,
-    ) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
         let connection_ref = self.get_connection(connection_id).await?;
         let connection = connection_ref.lock().await;
         connection.execute_raw_query(query).await
     }
 
+    /// Execute a data-modifying statement, returning rows affected
+    pub async fn execute_statement(&self, connection_id: &str, statement: &str) -> Result<u64> {
+        let connection_ref = self.get_connection(connection_id).await?;
+        let connection = connection_ref.lock().await;
+        connection.execute_statement(statement).await
+    }
+
     /// Get table data using the persistent connection
     pub async fn get_table_data(
         &self,
@@ -180,7 +372,7 @@ impl ConnectionManager {
         table_name: &str,
         limit: usize,
         offset: usize,
-    ) -> Result<Vec<Vec<String>>> {
+    ) -> Result<Vec<Vec<Option<String>>>> {
         let connection_ref = self.get_connection(connection_id).await?;
         let connection = connection_ref.lock().await;
         connection.get_table_data(table_name, limit, offset).await
@@ -218,6 +410,100 @@ impl ConnectionManager {
         connection.list_database_objects().await
     }
 
+    /// List one page of database objects; the connection lock is held only for this page
+    pub async fn list_database_objects_page(
+        &self,
+        connection_id: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        let connection_ref = self.get_connection(connection_id).await?;
+        let connection = connection_ref.lock().await;
+        connection.list_database_objects_page(limit, offset).await
+    }
+
+    /// Get table and index usage statistics using the persistent connection
+    pub async fn get_table_statistics(
+        &self,
+        connection_id: &str,
+        table_name: &str,
+    ) -> Result<crate::database::TableStatistics> {
+        let connection_ref = self.get_connection(connection_id).await?;
+        let connection = connection_ref.lock().await;
+        connection.get_table_statistics(table_name).await
+    }
+
+    /// Bulk-load a CSV file into a table using the persistent connection
+    pub async fn copy_in_csv(
+        &self,
+        connection_id: &str,
+        statement: &str,
+        path: std::path::PathBuf,
+        keep_columns: Option<Vec<usize>>,
+    ) -> Result<u64> {
+        let connection_ref = self.get_connection(connection_id).await?;
+        let connection = connection_ref.lock().await;
+        connection.copy_in_csv(statement, path, keep_columns).await
+    }
+
+    /// Cancel the statement running on a connection. Returns `false` when the
+    /// engine has no server-side cancel and the caller must drop the task.
+    pub async fn cancel_running_query(&self, connection_id: &str) -> Result<bool> {
+        let canceller = self.cancellers.lock().await.get(connection_id).cloned();
+        match canceller {
+            Some(canceller) => canceller.cancel_running_query().await,
+            None => Ok(false),
+        }
+    }
+
+    /// Pool of a PostgreSQL connection that does not wait for the connection
+    /// lock, so sessions can be inspected while a query of ours is blocked
+    async fn postgres_side_pool(&self, connection_id: &str) -> Result<PgPool> {
+        match self.cancellers.lock().await.get(connection_id) {
+            Some(QueryCanceller::Postgres { pool, .. }) => Ok(pool.clone()),
+            Some(_) => Err(LazyTablesError::Unsupported(
+                "Lock monitoring is only available for PostgreSQL".to_string(),
+            )),
+            None => Err(LazyTablesError::Connection(format!(
+                "Connection {connection_id} not found"
+            ))),
+        }
+    }
+
+    /// Locks held or awaited by other sessions, from `pg_locks`
+    pub async fn list_locks(&self, connection_id: &str) -> Result<Vec<LockInfo>> {
+        let pool = self.postgres_side_pool(connection_id).await?;
+        let rows = sqlx::query(POSTGRES_LOCKS_QUERY).fetch_all(&pool).await?;
+        rows.iter()
+            .map(|row| {
+                use sqlx::Row;
+                Ok(LockInfo {
+                    pid: row.try_get("pid")?,
+                    application_name: row.try_get("application_name")?,
+                    lock_type: row.try_get("locktype")?,
+                    relation: row.try_get("relation")?,
+                    granted: row.try_get("granted")?,
+                    blocking_pids: row.try_get("blocking_pids")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Cancel or terminate another backend. Returns whether the server sent the signal.
+    pub async fn signal_backend(
+        &self,
+        connection_id: &str,
+        pid: i32,
+        signal: BackendSignal,
+    ) -> Result<bool> {
+        let pool = self.postgres_side_pool(connection_id).await?;
+        let sent: bool = sqlx::query_scalar(&format!("SELECT {}($1)", signal.function_name()))
+            .bind(pid)
+            .fetch_one(&pool)
+            .await?;
+        Ok(sent)
+    }
+
     /// Check if a connection is healthy by trying to execute a simple query
     pub async fn health_check(&self, connection_id: &str) -> Result<bool> {
         match self.execute_raw_query(connection_id, "SELECT 1").await {
@@ -0,0 +1,281 @@
+// FilePath: src/database/csv_import.rs
+
+#![forbid(unsafe_code)]
+
+use crate::database::ddl::{quote_identifier, quote_qualified_identifier};
+use crate::database::{DataType, DatabaseType, TableColumn};
+
+/// Bytes read from the start of a CSV file to find its header and first row
+pub const CSV_PREVIEW_BYTES: usize = 64 * 1024;
+
+/// One CSV field. `quoted` keeps `""` apart from an empty unquoted field,
+/// which `COPY ... csv` reads as NULL rather than an empty string.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CsvField {
+    pub value: Vec<u8>,
+    pub quoted: bool,
+}
+
+/// Incremental CSV reader that accepts the input in arbitrary chunks,
+/// honouring quoted fields with embedded commas, doubled quotes and newlines.
+/// It works on bytes, so chunks may split multi-byte UTF-8 characters.
+#[derive(Debug, Default)]
+pub struct CsvReader {
+    record: Vec<CsvField>,
+    field: CsvField,
+    in_quotes: bool,
+    /// A quote seen inside a quoted field, either doubled or closing
+    quote_pending: bool,
+}
+
+impl CsvReader {
+    /// Consume a chunk, appending every record it completes to `out`
+    pub fn feed(&mut self, chunk: &[u8], out: &mut Vec<Vec<CsvField>>) {
+        for &b in chunk {
+            if self.quote_pending {
+                self.quote_pending = false;
+                if b == b'"' {
+                    self.field.value.push(b'"');
+                    continue;
+                }
+                self.in_quotes = false;
+            }
+
+            if self.in_quotes {
+                match b {
+                    b'"' => self.quote_pending = true,
+                    _ => self.field.value.push(b),
+                }
+                continue;
+            }
+
+            match b {
+                b'"' => {
+                    self.in_quotes = true;
+                    self.field.quoted = true;
+                }
+                b',' => self.record.push(std::mem::take(&mut self.field)),
+                b'\r' => {}
+                b'\n' => {
+                    self.record.push(std::mem::take(&mut self.field));
+                    out.push(std::mem::take(&mut self.record));
+                }
+                _ => self.field.value.push(b),
+            }
+        }
+    }
+
+    /// The trailing record when the input does not end with a newline
+    pub fn finish(mut self) -> Option<Vec<CsvField>> {
+        if self.field.value.is_empty() && !self.field.quoted && self.record.is_empty() {
+            return None;
+        }
+        self.record.push(self.field);
+        Some(self.record)
+    }
+}
+
+/// Split CSV text into records
+pub fn parse_csv(contents: &str) -> Vec<Vec<String>> {
+    let mut reader = CsvReader::default();
+    let mut records = Vec::new();
+    reader.feed(contents.as_bytes(), &mut records);
+    records.extend(reader.finish());
+    records
+        .into_iter()
+        .map(|record| {
+            record
+                .into_iter()
+                .map(|field| String::from_utf8_lossy(&field.value).into_owned())
+                .collect()
+        })
+        .collect()
+}
+
+/// Encode one field, quoting it when needed
+pub fn encode_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Re-encode one parsed field, keeping its quoting so `""` stays an empty string
+fn encode_csv_field(field: &CsvField, out: &mut Vec<u8>) {
+    let needs_quotes = field.quoted
+        || field
+            .value
+            .iter()
+            .any(|b| matches!(b, b',' | b'"' | b'\n' | b'\r'));
+    if !needs_quotes {
+        out.extend_from_slice(&field.value);
+        return;
+    }
+    out.push(b'"');
+    for &b in &field.value {
+        if b == b'"' {
+            out.push(b'"');
+        }
+        out.push(b);
+    }
+    out.push(b'"');
+}
+
+/// Streams CSV chunks through, keeping only the given columns in the given order
+#[derive(Debug)]
+pub struct CsvProjector {
+    reader: CsvReader,
+    keep: Vec<usize>,
+    records: Vec<Vec<CsvField>>,
+}
+
+impl CsvProjector {
+    pub fn new(keep: &[usize]) -> Self {
+        Self {
+            reader: CsvReader::default(),
+            keep: keep.to_vec(),
+            records: Vec::new(),
+        }
+    }
+
+    /// Re-encode the records completed by this chunk
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<u8> {
+        self.reader.feed(chunk, &mut self.records);
+        let mut out = Vec::with_capacity(chunk.len());
+        for record in self.records.drain(..) {
+            encode_projected(&record, &self.keep, &mut out);
+        }
+        out
+    }
+
+    /// Re-encode the trailing record, if any
+    pub fn finish(self) -> Vec<u8> {
+        let mut out = Vec::new();
+        if let Some(record) = self.reader.finish() {
+            encode_projected(&record, &self.keep, &mut out);
+        }
+        out
+    }
+}
+
+fn encode_projected(record: &[CsvField], keep: &[usize], out: &mut Vec<u8>) {
+    let missing = CsvField::default();
+    for (n, &i) in keep.iter().enumerate() {
+        if n > 0 {
+            out.push(b',');
+        }
+        encode_csv_field(record.get(i).unwrap_or(&missing), out);
+    }
+    out.push(b'\n');
+}
+
+/// Re-encode CSV text keeping only the given columns, in the given order
+pub fn project_columns(contents: &str, keep: &[usize]) -> String {
+    let mut projector = CsvProjector::new(keep);
+    let mut out = projector.feed(contents.as_bytes());
+    out.extend(projector.finish());
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// `COPY ... FROM STDIN` statement for the mapped target columns.
+/// The file is streamed from the client, so it need not be on the server.
+pub fn copy_statement(
+    table_name: &str,
+    columns: &[String],
+    database_type: &DatabaseType,
+) -> String {
+    format!(
+        "COPY {} ({}) FROM STDIN WITH (FORMAT csv, HEADER true)",
+        quote_qualified_identifier(table_name, database_type),
+        columns
+            .iter()
+            .map(|c| quote_identifier(c, database_type))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// Map each CSV header to the table column of the same name (case-insensitive)
+pub fn auto_map(headers: &[String], columns: &[TableColumn]) -> Vec<Option<usize>> {
+    headers
+        .iter()
+        .map(|header| {
+            columns
+                .iter()
+                .position(|c| c.name.eq_ignore_ascii_case(header.trim()))
+        })
+        .collect()
+}
+
+/// Warn when a sample value will not coerce to the target column type
+pub fn coercion_hint(sample: &str, data_type: &DataType) -> Option<String> {
+    let value = sample.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    let ok = match data_type {
+        DataType::Integer | DataType::BigInt | DataType::SmallInt => value.parse::<i64>().is_ok(),
+        DataType::Decimal | DataType::Float | DataType::Double => value.parse::<f64>().is_ok(),
+        DataType::Boolean => matches!(
+            value.to_lowercase().as_str(),
+            "t" | "f" | "true" | "false" | "y" | "n" | "yes" | "no" | "1" | "0" | "on" | "off"
+        ),
+        DataType::Uuid => uuid::Uuid::parse_str(value).is_ok(),
+        DataType::Date => chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok(),
+        DataType::Json => value.starts_with('{') || value.starts_with('['),
+        DataType::Varchar(Some(max)) | DataType::Char(Some(max)) => value.chars().count() <= *max,
+        _ => true,
+    };
+
+    (!ok).then(|| format!("'{value}' may not cast to {}", data_type.to_sql()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_quotes() {
+        let records = parse_csv("id,name\r\n1,\"Smith, \"\"J\"\"\"\n2,\"multi\nline\"\n");
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1], vec!["1", "Smith, \"J\""]);
+        assert_eq!(records[2], vec!["2", "multi\nline"]);
+    }
+
+    #[test]
+    fn test_project_columns() {
+        let projected = project_columns("a,b,c\n1,\"x,y\",3\n", &[2, 1]);
+        assert_eq!(projected, "c,b\n3,\"x,y\"\n");
+
+        // A quoted empty string must not turn into an unquoted (NULL) field
+        let projected = project_columns("a,b\n\"\",\n", &[1, 0]);
+        assert_eq!(projected, "b,a\n,\"\"\n");
+
+        // Chunk boundaries may fall inside quotes and doubled quotes
+        let input = "id,name\n1,\"Smith, \"\"J\"\"\"\n2,\"\"\n";
+        let mut projector = CsvProjector::new(&[1]);
+        let mut out = Vec::new();
+        for chunk in input.as_bytes().chunks(3) {
+            out.extend(projector.feed(chunk));
+        }
+        out.extend(projector.finish());
+        assert_eq!(out, b"name\n\"Smith, \"\"J\"\"\"\n\"\"\n");
+    }
+
+    #[test]
+    fn test_copy_statement_and_hints() {
+        assert_eq!(
+            copy_statement(
+                "sales.orders",
+                &["id".to_string(), "total".to_string()],
+                &DatabaseType::PostgreSQL
+            ),
+            "COPY \"sales\".\"orders\" (\"id\", \"total\") FROM STDIN WITH (FORMAT csv, HEADER true)"
+        );
+        assert!(coercion_hint("42", &DataType::Integer).is_none());
+        assert!(coercion_hint("4.2", &DataType::Integer).is_some());
+        assert!(coercion_hint("", &DataType::Uuid).is_none());
+    }
+}
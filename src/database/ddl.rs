@@ -0,0 +1,635 @@
+// FilePath: src/database/ddl.rs
+
+#![forbid(unsafe_code)]
+
+use crate::database::{ColumnDefinition, DataType, DatabaseObjectType, DatabaseType};
+
+/// Column types worth offering when defining a column on each engine
+pub fn common_column_types(database_type: &DatabaseType) -> &'static [&'static str] {
+    match database_type {
+        DatabaseType::PostgreSQL => &[
+            "INTEGER",
+            "BIGINT",
+            "SMALLINT",
+            "SERIAL",
+            "BIGSERIAL",
+            "NUMERIC",
+            "REAL",
+            "DOUBLE PRECISION",
+            "BOOLEAN",
+            "TEXT",
+            "VARCHAR(255)",
+            "CHAR(1)",
+            "DATE",
+            "TIME",
+            "TIMESTAMP",
+            "TIMESTAMPTZ",
+            "JSON",
+            "JSONB",
+            "UUID",
+            "BYTEA",
+        ],
+        DatabaseType::MySQL | DatabaseType::MariaDB => &[
+            "INT",
+            "BIGINT",
+            "SMALLINT",
+            "TINYINT",
+            "DECIMAL(10,2)",
+            "FLOAT",
+            "DOUBLE",
+            "BOOLEAN",
+            "VARCHAR(255)",
+            "CHAR(1)",
+            "TEXT",
+            "DATE",
+            "TIME",
+            "DATETIME",
+            "TIMESTAMP",
+            "JSON",
+            "ENUM('a','b')",
+            "BLOB",
+        ],
+        DatabaseType::SQLite => &["INTEGER", "REAL", "TEXT", "BLOB", "NUMERIC"],
+        DatabaseType::MsSql => &[
+            "INT",
+            "BIGINT",
+            "SMALLINT",
+            "TINYINT",
+            "BIT",
+            "DECIMAL(18,2)",
+            "FLOAT",
+            "NVARCHAR(255)",
+            "NVARCHAR(MAX)",
+            "VARCHAR(255)",
+            "DATE",
+            "TIME",
+            "DATETIME2",
+            "DATETIMEOFFSET",
+            "UNIQUEIDENTIFIER",
+            "VARBINARY(MAX)",
+        ],
+        DatabaseType::Oracle | DatabaseType::Redis | DatabaseType::MongoDB => &[],
+    }
+}
+
+impl DataType {
+    /// SQL type name as spelled by a specific engine
+    pub fn to_sql_for(&self, database_type: &DatabaseType) -> String {
+        match database_type {
+            DatabaseType::PostgreSQL => match self {
+                DataType::Float => "REAL".to_string(),
+                DataType::Double => "DOUBLE PRECISION".to_string(),
+                _ => self.to_sql(),
+            },
+            DatabaseType::MySQL | DatabaseType::MariaDB => match self {
+                DataType::Integer => "INT".to_string(),
+                DataType::Varchar(None) => "VARCHAR(255)".to_string(),
+                DataType::Timestamp => "DATETIME".to_string(),
+                DataType::Uuid => "CHAR(36)".to_string(),
+                DataType::Bytea => "BLOB".to_string(),
+                DataType::Array(_) => "JSON".to_string(),
+                _ => self.to_sql(),
+            },
+            DatabaseType::SQLite => match self {
+                DataType::Integer | DataType::BigInt | DataType::SmallInt | DataType::Boolean => {
+                    "INTEGER".to_string()
+                }
+                DataType::Decimal => "NUMERIC".to_string(),
+                DataType::Float | DataType::Double => "REAL".to_string(),
+                DataType::Bytea => "BLOB".to_string(),
+                _ => "TEXT".to_string(),
+            },
+            DatabaseType::MsSql => match self {
+                DataType::Integer => "INT".to_string(),
+                DataType::Boolean => "BIT".to_string(),
+                DataType::Varchar(None) | DataType::Text => "NVARCHAR(MAX)".to_string(),
+                DataType::Timestamp => "DATETIME2".to_string(),
+                DataType::Uuid => "UNIQUEIDENTIFIER".to_string(),
+                DataType::Bytea => "VARBINARY(MAX)".to_string(),
+                DataType::Json | DataType::Array(_) => "NVARCHAR(MAX)".to_string(),
+                _ => self.to_sql(),
+            },
+            DatabaseType::Oracle | DatabaseType::Redis | DatabaseType::MongoDB => self.to_sql(),
+        }
+    }
+}
+
+/// Quote an identifier for the engine (backticks on MySQL, brackets on SQL
+/// Server, double quotes elsewhere)
+pub fn quote_identifier(name: &str, database_type: &DatabaseType) -> String {
+    match database_type {
+        DatabaseType::MySQL | DatabaseType::MariaDB => format!("`{}`", name.replace('`', "``")),
+        DatabaseType::MsSql => format!("[{}]", name.replace(']', "]]")),
+        _ => format!("\"{}\"", name.replace('"', "\"\"")),
+    }
+}
+
+/// Quote a plain or `schema.name` identifier part by part
+pub fn quote_qualified_identifier(name: &str, database_type: &DatabaseType) -> String {
+    name.splitn(2, '.')
+        .map(|part| quote_identifier(part, database_type))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// `DROP` statement for a table, view or materialized view
+pub fn drop_object_sql(
+    name: &str,
+    object_type: &DatabaseObjectType,
+    database_type: &DatabaseType,
+) -> String {
+    let kind = match object_type {
+        DatabaseObjectType::View => "VIEW",
+        DatabaseObjectType::MaterializedView => "MATERIALIZED VIEW",
+        DatabaseObjectType::ForeignTable => "FOREIGN TABLE",
+        DatabaseObjectType::Table | DatabaseObjectType::SystemTable => "TABLE",
+    };
+    format!(
+        "DROP {kind} {}",
+        quote_qualified_identifier(name, database_type)
+    )
+}
+
+/// Statement that renames a table or view to `new_name` within its schema:
+/// `RENAME TABLE` on MySQL, `sp_rename` on SQL Server, `ALTER ... RENAME TO`
+/// elsewhere
+pub fn rename_object_sql(
+    name: &str,
+    new_name: &str,
+    object_type: &DatabaseObjectType,
+    database_type: &DatabaseType,
+) -> String {
+    let old = quote_qualified_identifier(name, database_type);
+    let new = quote_identifier(new_name, database_type);
+    match database_type {
+        DatabaseType::MySQL | DatabaseType::MariaDB => {
+            let new = match name.split_once('.') {
+                Some((schema, _)) => format!("{}.{new}", quote_identifier(schema, database_type)),
+                None => new,
+            };
+            format!("RENAME TABLE {old} TO {new}")
+        }
+        DatabaseType::MsSql => format!(
+            "EXEC sp_rename N'{}', N'{}'",
+            old.replace('\'', "''"),
+            new_name.replace('\'', "''")
+        ),
+        _ => {
+            let kind = match object_type {
+                DatabaseObjectType::View => "VIEW",
+                DatabaseObjectType::MaterializedView => "MATERIALIZED VIEW",
+                DatabaseObjectType::ForeignTable => "FOREIGN TABLE",
+                DatabaseObjectType::Table | DatabaseObjectType::SystemTable => "TABLE",
+            };
+            format!("ALTER {kind} {old} RENAME TO {new}")
+        }
+    }
+}
+
+/// Statement that empties a table: `TRUNCATE TABLE`, or `DELETE FROM` on
+/// SQLite, which has no `TRUNCATE`
+pub fn truncate_table_sql(name: &str, database_type: &DatabaseType) -> String {
+    let name = quote_qualified_identifier(name, database_type);
+    match database_type {
+        DatabaseType::SQLite => format!("DELETE FROM {name}"),
+        _ => format!("TRUNCATE TABLE {name}"),
+    }
+}
+
+/// `CREATE VIEW` statement from a view definition. MySQL and SQLite already
+/// return the whole statement; PostgreSQL only returns the query.
+pub fn create_view_sql(
+    name: &str,
+    table_type: &str,
+    definition: &str,
+    database_type: &DatabaseType,
+) -> String {
+    let definition = definition.trim();
+    if definition
+        .get(..6)
+        .is_some_and(|head| head.eq_ignore_ascii_case("CREATE"))
+    {
+        return definition.to_string();
+    }
+    format!(
+        "CREATE {table_type} {} AS\n{definition}",
+        quote_qualified_identifier(name, database_type)
+    )
+}
+
+/// `REFRESH MATERIALIZED VIEW` statement (PostgreSQL) for a plain or `schema.name` view
+pub fn refresh_materialized_view_sql(view_name: &str, concurrently: bool) -> String {
+    let quoted = quote_qualified_identifier(view_name, &DatabaseType::PostgreSQL);
+    if concurrently {
+        format!("REFRESH MATERIALIZED VIEW CONCURRENTLY {quoted}")
+    } else {
+        format!("REFRESH MATERIALIZED VIEW {quoted}")
+    }
+}
+
+/// Index methods offered when creating an index; empty when the engine has no choice
+pub fn index_types(database_type: &DatabaseType) -> &'static [&'static str] {
+    match database_type {
+        DatabaseType::PostgreSQL => &["BTREE", "HASH", "GIN", "BRIN"],
+        DatabaseType::MySQL | DatabaseType::MariaDB => &["BTREE", "HASH"],
+        _ => &[],
+    }
+}
+
+/// Default index name for `table` (plain or `schema.name`) over `columns`,
+/// kept within PostgreSQL's 63-byte identifier limit
+pub fn default_index_name(table: &str, columns: &[String]) -> String {
+    let table = table.rsplit('.').next().unwrap_or(table);
+    let mut name = format!("idx_{table}");
+    for column in columns {
+        name.push('_');
+        name.push_str(column);
+    }
+    while name.len() > 63 {
+        name.pop();
+    }
+    name
+}
+
+/// `CREATE INDEX` statement. PostgreSQL builds it `CONCURRENTLY` so writes
+/// to the table are not blocked; the index lands in the table's schema.
+pub fn create_index_sql(
+    table: &str,
+    index_name: &str,
+    columns: &[String],
+    index_type: Option<&str>,
+    unique: bool,
+    database_type: &DatabaseType,
+) -> String {
+    let unique = if unique { "UNIQUE " } else { "" };
+    let name = quote_identifier(index_name, database_type);
+    let table = quote_qualified_identifier(table, database_type);
+    let columns = columns
+        .iter()
+        .map(|column| quote_identifier(column, database_type))
+        .collect::<Vec<_>>()
+        .join(", ");
+    match (database_type, index_type) {
+        (DatabaseType::PostgreSQL, Some(method)) => format!(
+            "CREATE {unique}INDEX CONCURRENTLY {name} ON {table} USING {} ({columns})",
+            method.to_lowercase()
+        ),
+        (DatabaseType::PostgreSQL, None) => {
+            format!("CREATE {unique}INDEX CONCURRENTLY {name} ON {table} ({columns})")
+        }
+        (DatabaseType::MySQL | DatabaseType::MariaDB, Some(method)) => {
+            format!("CREATE {unique}INDEX {name} ON {table} ({columns}) USING {method}")
+        }
+        _ => format!("CREATE {unique}INDEX {name} ON {table} ({columns})"),
+    }
+}
+
+/// `DROP INDEX` statement for an index on `table` (plain or `schema.name`)
+pub fn drop_index_sql(table: &str, index_name: &str, database_type: &DatabaseType) -> String {
+    let name = quote_identifier(index_name, database_type);
+    match database_type {
+        DatabaseType::PostgreSQL => match table.split_once('.') {
+            Some((schema, _)) => format!(
+                "DROP INDEX CONCURRENTLY {}.{name}",
+                quote_identifier(schema, database_type)
+            ),
+            None => format!("DROP INDEX CONCURRENTLY {name}"),
+        },
+        DatabaseType::MySQL | DatabaseType::MariaDB => format!(
+            "DROP INDEX {name} ON {}",
+            quote_qualified_identifier(table, database_type)
+        ),
+        _ => format!("DROP INDEX {name}"),
+    }
+}
+
+/// `CREATE TABLE` statement for the engine. An auto-increment primary key
+/// becomes `SERIAL`/`BIGSERIAL` on PostgreSQL, `AUTO_INCREMENT` on MySQL and
+/// `INTEGER PRIMARY KEY AUTOINCREMENT` on SQLite.
+pub fn create_table_sql(
+    table_name: &str,
+    columns: &[ColumnDefinition],
+    database_type: &DatabaseType,
+) -> String {
+    let primary_keys: Vec<&ColumnDefinition> =
+        columns.iter().filter(|col| col.is_primary_key).collect();
+    let inline_primary_key = primary_keys.len() == 1;
+
+    let mut definitions: Vec<String> = columns
+        .iter()
+        .map(|col| column_sql(col, inline_primary_key, database_type))
+        .collect();
+
+    if !inline_primary_key && !primary_keys.is_empty() {
+        let names: Vec<String> = primary_keys
+            .iter()
+            .map(|col| quote_identifier(&col.name, database_type))
+            .collect();
+        definitions.push(format!("PRIMARY KEY ({})", names.join(", ")));
+    }
+
+    format!(
+        "CREATE TABLE {} (\n    {}\n)",
+        quote_qualified_identifier(table_name, database_type),
+        definitions.join(",\n    ")
+    )
+}
+
+fn column_sql(
+    col: &ColumnDefinition,
+    inline_primary_key: bool,
+    database_type: &DatabaseType,
+) -> String {
+    let name = quote_identifier(&col.name, database_type);
+    let primary_key = col.is_primary_key && inline_primary_key;
+
+    if col.is_auto_increment && primary_key {
+        return match database_type {
+            DatabaseType::PostgreSQL => {
+                let serial = if col.data_type == DataType::BigInt {
+                    "BIGSERIAL"
+                } else {
+                    "SERIAL"
+                };
+                format!("{name} {serial} PRIMARY KEY")
+            }
+            DatabaseType::MySQL | DatabaseType::MariaDB => format!(
+                "{name} {} NOT NULL AUTO_INCREMENT PRIMARY KEY",
+                col.data_type.to_sql_for(database_type)
+            ),
+            // Only an INTEGER PRIMARY KEY aliases the rowid
+            DatabaseType::SQLite => format!("{name} INTEGER PRIMARY KEY AUTOINCREMENT"),
+            _ => format!(
+                "{name} {} PRIMARY KEY",
+                col.data_type.to_sql_for(database_type)
+            ),
+        };
+    }
+
+    let mut sql = format!("{name} {}", col.data_type.to_sql_for(database_type));
+    if primary_key {
+        sql.push_str(" PRIMARY KEY");
+    } else if !col.is_nullable {
+        sql.push_str(" NOT NULL");
+    }
+    if col.is_unique && !primary_key {
+        sql.push_str(" UNIQUE");
+    }
+    if let Some(default) = &col.default_value {
+        sql.push_str(&format!(" DEFAULT {default}"));
+    }
+    sql
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, data_type: DataType) -> ColumnDefinition {
+        ColumnDefinition {
+            name: name.to_string(),
+            data_type,
+            is_nullable: true,
+            is_primary_key: false,
+            is_unique: false,
+            is_auto_increment: false,
+            default_value: None,
+        }
+    }
+
+    fn users_columns() -> Vec<ColumnDefinition> {
+        let mut id = column("id", DataType::Integer);
+        id.is_primary_key = true;
+        id.is_auto_increment = true;
+        let mut email = column("email", DataType::Varchar(None));
+        email.is_nullable = false;
+        email.is_unique = true;
+        let mut created = column("created_at", DataType::Timestamp);
+        created.default_value = Some("CURRENT_TIMESTAMP".to_string());
+        vec![
+            id,
+            email,
+            created,
+            column("avatar", DataType::Bytea),
+            column("score", DataType::Double),
+        ]
+    }
+
+    #[test]
+    fn test_refresh_materialized_view_sql() {
+        assert_eq!(
+            refresh_materialized_view_sql("reporting.daily_sales", false),
+            "REFRESH MATERIALIZED VIEW \"reporting\".\"daily_sales\""
+        );
+        assert_eq!(
+            refresh_materialized_view_sql("daily_sales", true),
+            "REFRESH MATERIALIZED VIEW CONCURRENTLY \"daily_sales\""
+        );
+    }
+
+    #[test]
+    fn test_drop_and_create_view_sql() {
+        assert_eq!(
+            drop_object_sql(
+                "public.active_users",
+                &DatabaseObjectType::View,
+                &DatabaseType::PostgreSQL
+            ),
+            "DROP VIEW \"public\".\"active_users\""
+        );
+        assert_eq!(
+            drop_object_sql("users", &DatabaseObjectType::Table, &DatabaseType::MySQL),
+            "DROP TABLE `users`"
+        );
+        assert_eq!(
+            create_view_sql(
+                "active_users",
+                "VIEW",
+                " SELECT id FROM users;",
+                &DatabaseType::PostgreSQL
+            ),
+            "CREATE VIEW \"active_users\" AS\nSELECT id FROM users;"
+        );
+        let sqlite = "CREATE VIEW active_users AS SELECT id FROM users";
+        assert_eq!(
+            create_view_sql("active_users", "VIEW", sqlite, &DatabaseType::SQLite),
+            sqlite
+        );
+    }
+
+    #[test]
+    fn test_rename_object_sql() {
+        assert_eq!(
+            rename_object_sql(
+                "public.Orders",
+                "order history",
+                &DatabaseObjectType::Table,
+                &DatabaseType::PostgreSQL
+            ),
+            "ALTER TABLE \"public\".\"Orders\" RENAME TO \"order history\""
+        );
+        assert_eq!(
+            rename_object_sql(
+                "public.active_users",
+                "users_active",
+                &DatabaseObjectType::MaterializedView,
+                &DatabaseType::PostgreSQL
+            ),
+            "ALTER MATERIALIZED VIEW \"public\".\"active_users\" RENAME TO \"users_active\""
+        );
+        assert_eq!(
+            rename_object_sql(
+                "shop.orders",
+                "orders_old",
+                &DatabaseObjectType::Table,
+                &DatabaseType::MySQL
+            ),
+            "RENAME TABLE `shop`.`orders` TO `shop`.`orders_old`"
+        );
+        assert_eq!(
+            rename_object_sql(
+                "dbo.orders",
+                "o'rders",
+                &DatabaseObjectType::Table,
+                &DatabaseType::MsSql
+            ),
+            "EXEC sp_rename N'[dbo].[orders]', N'o''rders'"
+        );
+        assert_eq!(
+            rename_object_sql(
+                "orders",
+                "orders_old",
+                &DatabaseObjectType::Table,
+                &DatabaseType::SQLite
+            ),
+            "ALTER TABLE \"orders\" RENAME TO \"orders_old\""
+        );
+    }
+
+    #[test]
+    fn test_truncate_table_sql() {
+        assert_eq!(
+            truncate_table_sql("public.orders", &DatabaseType::PostgreSQL),
+            "TRUNCATE TABLE \"public\".\"orders\""
+        );
+        assert_eq!(
+            truncate_table_sql("orders", &DatabaseType::MsSql),
+            "TRUNCATE TABLE [orders]"
+        );
+        assert_eq!(
+            truncate_table_sql("orders", &DatabaseType::SQLite),
+            "DELETE FROM \"orders\""
+        );
+    }
+
+    #[test]
+    fn test_index_sql_per_engine() {
+        let columns = vec!["email".to_string(), "created_at".to_string()];
+        assert_eq!(
+            default_index_name("public.users", &columns),
+            "idx_users_email_created_at"
+        );
+        assert_eq!(
+            create_index_sql(
+                "public.users",
+                "users_email",
+                &columns,
+                Some("GIN"),
+                true,
+                &DatabaseType::PostgreSQL
+            ),
+            "CREATE UNIQUE INDEX CONCURRENTLY \"users_email\" ON \"public\".\"users\" \
+             USING gin (\"email\", \"created_at\")"
+        );
+        assert_eq!(
+            create_index_sql(
+                "users",
+                "users_email",
+                &columns[..1],
+                None,
+                false,
+                &DatabaseType::MySQL
+            ),
+            "CREATE INDEX `users_email` ON `users` (`email`)"
+        );
+        assert_eq!(
+            drop_index_sql("public.users", "users_email", &DatabaseType::PostgreSQL),
+            "DROP INDEX CONCURRENTLY \"public\".\"users_email\""
+        );
+        assert_eq!(
+            drop_index_sql("users", "users_email", &DatabaseType::MySQL),
+            "DROP INDEX `users_email` ON `users`"
+        );
+        assert!(index_types(&DatabaseType::SQLite).is_empty());
+    }
+
+    #[test]
+    fn test_create_table_per_engine() {
+        let columns = users_columns();
+
+        assert_eq!(
+            create_table_sql("users", &columns, &DatabaseType::PostgreSQL),
+            "CREATE TABLE \"users\" (\n    \"id\" SERIAL PRIMARY KEY,\n    \
+             \"email\" VARCHAR NOT NULL UNIQUE,\n    \
+             \"created_at\" TIMESTAMP DEFAULT CURRENT_TIMESTAMP,\n    \
+             \"avatar\" BYTEA,\n    \"score\" DOUBLE PRECISION\n)"
+        );
+        assert_eq!(
+            create_table_sql("users", &columns, &DatabaseType::MySQL),
+            "CREATE TABLE `users` (\n    `id` INT NOT NULL AUTO_INCREMENT PRIMARY KEY,\n    \
+             `email` VARCHAR(255) NOT NULL UNIQUE,\n    \
+             `created_at` DATETIME DEFAULT CURRENT_TIMESTAMP,\n    \
+             `avatar` BLOB,\n    `score` DOUBLE\n)"
+        );
+        assert_eq!(
+            create_table_sql("users", &columns, &DatabaseType::SQLite),
+            "CREATE TABLE \"users\" (\n    \"id\" INTEGER PRIMARY KEY AUTOINCREMENT,\n    \
+             \"email\" TEXT NOT NULL UNIQUE,\n    \
+             \"created_at\" TEXT DEFAULT CURRENT_TIMESTAMP,\n    \
+             \"avatar\" BLOB,\n    \"score\" REAL\n)"
+        );
+    }
+
+    #[test]
+    fn test_composite_primary_key() {
+        let mut user_id = column("user_id", DataType::BigInt);
+        user_id.is_primary_key = true;
+        let mut role_id = column("role_id", DataType::BigInt);
+        role_id.is_primary_key = true;
+
+        assert_eq!(
+            create_table_sql("user_roles", &[user_id, role_id], &DatabaseType::MySQL),
+            "CREATE TABLE `user_roles` (\n    `user_id` BIGINT,\n    `role_id` BIGINT,\n    \
+             PRIMARY KEY (`user_id`, `role_id`)\n)"
+        );
+    }
+
+    #[test]
+    fn test_common_types_are_engine_specific() {
+        let mysql = common_column_types(&DatabaseType::MySQL);
+        assert!(mysql.contains(&"DATETIME"));
+        assert!(!mysql.contains(&"BYTEA"));
+        assert!(!mysql.contains(&"UUID"));
+        assert_eq!(
+            common_column_types(&DatabaseType::SQLite),
+            &["INTEGER", "REAL", "TEXT", "BLOB", "NUMERIC"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_ddl_executes() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let ddl = create_table_sql("users", &users_columns(), &DatabaseType::SQLite);
+        sqlx::query(&ddl).execute(&pool).await.unwrap();
+
+        sqlx::query("INSERT INTO users (email) VALUES ('a@example.com')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let id: i64 = sqlx::query_scalar("SELECT id FROM users")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(id, 1);
+    }
+}
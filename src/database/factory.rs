@@ -21,14 +21,37 @@ impl AdapterFactory {
                 Ok(Box::new(MySqlConnection::new(config)))
             }
             DatabaseType::SQLite => Ok(Box::new(SqliteConnection::new(config))),
+            #[cfg(feature = "oracle")]
+            DatabaseType::Oracle => Ok(Box::new(crate::database::oracle::OracleConnection::new(
+                config,
+            ))),
+            #[cfg(not(feature = "oracle"))]
             DatabaseType::Oracle => Err(LazyTablesError::Unsupported(
-                "Oracle support not yet implemented".to_string(),
+                "Oracle support requires building with the `oracle` feature".to_string(),
             )),
+            #[cfg(feature = "mssql")]
+            DatabaseType::MsSql => Ok(Box::new(crate::database::mssql::MsSqlConnection::new(
+                config,
+            ))),
+            #[cfg(not(feature = "mssql"))]
+            DatabaseType::MsSql => Err(LazyTablesError::Unsupported(
+                "SQL Server support requires building with the `mssql` feature".to_string(),
+            )),
+            #[cfg(feature = "redis")]
+            DatabaseType::Redis => Ok(Box::new(crate::database::redis::RedisConnection::new(
+                config,
+            ))),
+            #[cfg(not(feature = "redis"))]
             DatabaseType::Redis => Err(LazyTablesError::Unsupported(
-                "Redis support not yet implemented".to_string(),
+                "Redis support requires building with the `redis` feature".to_string(),
+            )),
+            #[cfg(feature = "mongodb")]
+            DatabaseType::MongoDB => Ok(Box::new(
+                crate::database::mongodb::MongoDbConnection::new(config),
             )),
+            #[cfg(not(feature = "mongodb"))]
             DatabaseType::MongoDB => Err(LazyTablesError::Unsupported(
-                "MongoDB support not yet implemented".to_string(),
+                "MongoDB support requires building with the `mongodb` feature".to_string(),
             )),
         }
     }
@@ -51,6 +74,8 @@ impl AdapterFactory {
             Ok(DatabaseType::SQLite)
         } else if lower.starts_with("oracle://") {
             Ok(DatabaseType::Oracle)
+        } else if lower.starts_with("mssql://") || lower.starts_with("sqlserver://") {
+            Ok(DatabaseType::MsSql)
         } else if lower.starts_with("redis://") {
             Ok(DatabaseType::Redis)
         } else if lower.starts_with("mongodb://") {
@@ -134,6 +159,7 @@ impl AdapterFactory {
                     DatabaseType::PostgreSQL => 5432,
                     DatabaseType::MySQL | DatabaseType::MariaDB => 3306,
                     DatabaseType::Oracle => 1521,
+                    DatabaseType::MsSql => 1433,
                     DatabaseType::Redis => 6379,
                     DatabaseType::MongoDB => 27017,
                     DatabaseType::SQLite => 0, // SQLite doesn't use ports
@@ -253,13 +279,14 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "oracle"))]
     fn test_create_connection_unsupported() {
         let config = ConnectionConfig::new(
             "test".to_string(),
             DatabaseType::Oracle,
             "localhost".to_string(),
             1521,
-            "oracle".to_string(),
+            "system".to_string(),
         );
 
         let connection = AdapterFactory::create_connection(config);
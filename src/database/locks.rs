@@ -0,0 +1,48 @@
+// FilePath: src/database/locks.rs
+
+#![forbid(unsafe_code)]
+
+/// Every lock held or awaited by another session, with the sessions blocking it.
+/// Ungranted locks come first.
+pub const POSTGRES_LOCKS_QUERY: &str = "\
+SELECT l.pid,
+       COALESCE(a.application_name, '') AS application_name,
+       l.locktype,
+       l.relation::regclass::text AS relation,
+       l.granted,
+       pg_blocking_pids(l.pid) AS blocking_pids
+FROM pg_locks l
+LEFT JOIN pg_stat_activity a ON a.pid = l.pid
+WHERE l.pid IS NOT NULL AND l.pid <> pg_backend_pid()
+ORDER BY l.granted, l.pid, l.locktype";
+
+/// One row of `pg_locks` joined with `pg_stat_activity`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockInfo {
+    pub pid: i32,
+    pub application_name: String,
+    pub lock_type: String,
+    /// Locked relation, for relation-level locks
+    pub relation: Option<String>,
+    pub granted: bool,
+    /// Sessions this one waits for (`pg_blocking_pids`)
+    pub blocking_pids: Vec<i32>,
+}
+
+/// How to stop another backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackendSignal {
+    /// `pg_cancel_backend`: stop the running statement
+    Cancel,
+    /// `pg_terminate_backend`: end the whole session
+    Terminate,
+}
+
+impl BackendSignal {
+    pub fn function_name(self) -> &'static str {
+        match self {
+            BackendSignal::Cancel => "pg_cancel_backend",
+            BackendSignal::Terminate => "pg_terminate_backend",
+        }
+    }
+}
@@ -4,14 +4,31 @@
 #![forbid(unsafe_code)]
 
 pub mod app_state;
+pub mod backup;
 pub mod connection;
 pub mod connection_manager;
+pub mod csv_import;
+pub mod ddl;
 pub mod factory;
+pub mod locks;
+#[cfg(feature = "mongodb")]
+pub mod mongodb;
+#[cfg(feature = "mssql")]
+pub mod mssql;
 pub mod mysql;
 pub mod objects;
+#[cfg(feature = "oracle")]
+pub mod oracle;
 pub mod postgres;
+pub mod profile;
 pub mod query_history;
+#[cfg(feature = "redis")]
+pub mod redis;
+pub mod routines;
+pub mod sql_split;
 pub mod sqlite;
+pub mod statistics;
+pub mod system_import;
 
 pub use connection::{
     ConnectionConfig, ConnectionStatus, ConnectionStorage, DatabaseCapabilities, DatabaseType,
@@ -30,6 +47,18 @@ pub use connection_manager::ConnectionManager;
 // Re-export database object types
 pub use objects::{DatabaseObject, DatabaseObjectList, DatabaseObjectType};
 
+// Re-export profiling types
+pub use profile::{ColumnProfile, ColumnProfileView};
+
+// Re-export table statistics types
+pub use statistics::{IndexUsage, TableStatistics};
+
+// Re-export lock monitor types
+pub use locks::{BackendSignal, LockInfo};
+
+// Re-export routine types
+pub use routines::RoutineInfo;
+
 // Re-export query history types
 pub use query_history::{QueryHistoryEntry, QueryHistoryManager};
 
@@ -56,9 +85,25 @@ pub struct ColumnDefinition {
     pub is_nullable: bool,
     pub is_primary_key: bool,
     pub is_unique: bool,
+    /// Generated by the engine (SERIAL, AUTO_INCREMENT, ...); only honoured on a sole primary key
+    pub is_auto_increment: bool,
     pub default_value: Option<String>,
 }
 
+impl From<&TableColumn> for ColumnDefinition {
+    fn from(column: &TableColumn) -> Self {
+        Self {
+            name: column.name.clone(),
+            data_type: column.data_type.clone(),
+            is_nullable: column.is_nullable,
+            is_primary_key: column.is_primary_key,
+            is_unique: false,
+            is_auto_increment: false,
+            default_value: column.default_value.clone(),
+        }
+    }
+}
+
 /// Supported data types
 #[derive(Debug, Clone, PartialEq)]
 pub enum DataType {
@@ -118,6 +163,17 @@ impl DataType {
     }
 }
 
+/// Binary value as cell text: `0x` and two hex digits per byte, so raw bytes
+/// never reach the terminal
+pub fn hex_literal(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(2 + bytes.len() * 2);
+    text.push_str("0x");
+    for byte in bytes {
+        text.push_str(&format!("{byte:02X}"));
+    }
+    text
+}
+
 /// Represents detailed metadata about a database table
 #[derive(Debug, Clone)]
 pub struct TableMetadata {
@@ -126,6 +182,8 @@ pub struct TableMetadata {
     pub schema_name: Option<String>,
     pub table_type: String, // TABLE, VIEW, MATERIALIZED VIEW, etc.
     pub row_count: usize,
+    /// False when `row_count` is a planner estimate rather than a `COUNT(*)`
+    pub row_count_exact: bool,
     pub column_count: usize,
     pub comment: Option<String>,
 
@@ -156,6 +214,9 @@ pub struct TableMetadata {
 
     // Column summary for quick reference
     pub columns_summary: Vec<ColumnSummary>,
+
+    // Defining query for views and materialized views
+    pub view_definition: Option<String>,
 }
 
 /// Foreign key relationship information
@@ -169,6 +230,20 @@ pub struct ForeignKeyInfo {
     pub on_update: Option<String>,
 }
 
+impl ForeignKeyInfo {
+    /// Referential actions the engine reported, e.g. `ON DELETE CASCADE ON UPDATE NO ACTION`
+    pub fn actions(&self) -> Option<String> {
+        let actions: Vec<String> = [
+            ("ON DELETE", &self.on_delete),
+            ("ON UPDATE", &self.on_update),
+        ]
+        .into_iter()
+        .filter_map(|(label, action)| action.as_ref().map(|action| format!("{label} {action}")))
+        .collect();
+        (!actions.is_empty()).then(|| actions.join(" "))
+    }
+}
+
 /// Index information
 #[derive(Debug, Clone)]
 pub struct IndexInfo {
@@ -196,10 +271,45 @@ pub struct ColumnSummary {
     pub data_type: String,
     pub is_nullable: bool,
     pub is_primary_key: bool,
-    pub has_default: bool,
+    /// Default expression as the engine reports it
+    pub default_value: Option<String>,
     pub max_length: Option<i32>,
 }
 
+impl From<&TableColumn> for ColumnSummary {
+    fn from(column: &TableColumn) -> Self {
+        let max_length = match column.data_type {
+            DataType::Varchar(Some(len)) | DataType::Char(Some(len)) => i32::try_from(len).ok(),
+            _ => None,
+        };
+        Self {
+            name: column.name.clone(),
+            data_type: column.data_type.to_sql(),
+            is_nullable: column.is_nullable,
+            is_primary_key: column.is_primary_key,
+            default_value: column.default_value.clone(),
+            max_length,
+        }
+    }
+}
+
+impl ColumnSummary {
+    /// One line of the details pane column list, e.g. `id INTEGER PK NOT NULL DEFAULT`
+    pub fn describe(&self) -> String {
+        let mut text = format!("{} {}", self.name, self.data_type);
+        if self.is_primary_key {
+            text.push_str(" PK");
+        }
+        if !self.is_nullable {
+            text.push_str(" NOT NULL");
+        }
+        if self.default_value.is_some() {
+            text.push_str(" DEFAULT");
+        }
+        text
+    }
+}
+
 /// Database-specific metadata
 #[derive(Debug, Clone)]
 pub enum DatabaseSpecificMetadata {
@@ -250,6 +360,7 @@ impl TableMetadata {
             schema_name: None,
             table_type: "TABLE".to_string(),
             row_count,
+            row_count_exact: true,
             column_count,
             comment,
             total_size,
@@ -288,7 +399,60 @@ impl TableMetadata {
             created_at: None,
             modified_at: None,
             columns_summary: vec![],
+            view_definition: None,
+        }
+    }
+
+    /// Attach the column list shown in the details pane
+    pub fn with_columns(mut self, columns: &[TableColumn]) -> Self {
+        self.columns_summary = columns.iter().map(ColumnSummary::from).collect();
+        self
+    }
+
+    /// Mark `row_count` as exact or as a planner estimate
+    pub fn with_row_count_exact(mut self, exact: bool) -> Self {
+        self.row_count_exact = exact;
+        self
+    }
+
+    /// Store the result of an exact `COUNT(*)`, kept until the metadata is reloaded
+    pub fn set_exact_row_count(&mut self, count: usize) {
+        self.row_count = count;
+        self.row_count_exact = true;
+    }
+
+    /// Row count for the Details pane, with `~` in front of an estimate
+    pub fn row_count_label(&self) -> String {
+        if self.row_count_exact {
+            self.row_count.to_string()
+        } else {
+            format!("~{}", self.row_count)
+        }
+    }
+
+    /// Mark this object as a view (`VIEW` or `MATERIALIZED VIEW`) with its definition SQL;
+    /// `None` leaves it a table
+    pub fn with_view_type(mut self, view_type: Option<&str>, definition: Option<String>) -> Self {
+        if let Some(view_type) = view_type {
+            self.table_type = view_type.to_string();
+            self.view_definition = definition;
         }
+        self
+    }
+
+    /// Whether this object is a view of either kind
+    pub fn is_view(&self) -> bool {
+        self.table_type.contains("VIEW")
+    }
+
+    /// Whether this object is a materialized view
+    pub fn is_materialized_view(&self) -> bool {
+        self.table_type == "MATERIALIZED VIEW"
+    }
+
+    /// Whether indexes can be listed and created; plain views have none
+    pub fn supports_indexes(&self) -> bool {
+        !self.is_view() || self.is_materialized_view()
     }
 
     /// Get display name including schema if available
@@ -345,3 +509,79 @@ impl TableMetadata {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_summary_from_table_column() {
+        let id = TableColumn {
+            name: "id".to_string(),
+            data_type: DataType::Integer,
+            is_nullable: false,
+            default_value: Some("nextval('users_id_seq')".to_string()),
+            is_primary_key: true,
+        };
+        let email = TableColumn {
+            name: "email".to_string(),
+            data_type: DataType::Varchar(Some(255)),
+            is_nullable: true,
+            default_value: None,
+            is_primary_key: false,
+        };
+
+        let summaries: Vec<ColumnSummary> = [&id, &email].into_iter().map(Into::into).collect();
+        assert_eq!(summaries[1].max_length, Some(255));
+        assert_eq!(summaries[0].describe(), "id INTEGER PK NOT NULL DEFAULT");
+        assert_eq!(summaries[1].describe(), "email VARCHAR(255)");
+        assert_eq!(
+            summaries[0].default_value.as_deref(),
+            Some("nextval('users_id_seq')")
+        );
+    }
+
+    #[test]
+    fn test_foreign_key_actions() {
+        let mut fk = ForeignKeyInfo {
+            constraint_name: "orders_user_id_fkey".to_string(),
+            column_names: vec!["user_id".to_string()],
+            referenced_table: "users".to_string(),
+            referenced_columns: vec!["id".to_string()],
+            on_delete: None,
+            on_update: None,
+        };
+        assert_eq!(fk.actions(), None);
+        fk.on_update = Some("NO ACTION".to_string());
+        assert_eq!(fk.actions().as_deref(), Some("ON UPDATE NO ACTION"));
+        fk.on_delete = Some("CASCADE".to_string());
+        assert_eq!(
+            fk.actions().as_deref(),
+            Some("ON DELETE CASCADE ON UPDATE NO ACTION")
+        );
+    }
+
+    #[test]
+    fn test_estimated_row_count_until_counted() {
+        let mut metadata = TableMetadata::basic(
+            "events".to_string(),
+            2_500_000,
+            4,
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![],
+            None,
+        );
+        assert!(metadata.row_count_exact);
+        assert_eq!(metadata.row_count_label(), "2500000");
+
+        metadata = metadata.with_row_count_exact(false);
+        assert_eq!(metadata.row_count_label(), "~2500000");
+        metadata.set_exact_row_count(2_498_113);
+        assert!(metadata.row_count_exact);
+        assert_eq!(metadata.row_count_label(), "2498113");
+    }
+}
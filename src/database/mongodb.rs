@@ -0,0 +1,973 @@
+// FilePath: src/database/mongodb.rs
+
+#![forbid(unsafe_code)]
+
+use crate::core::error::{LazyTablesError, Result};
+use crate::database::{
+    connection::{ConnectionConfig, SslMode},
+    connection_manager::QueryCanceller,
+    Connection, DataType, TableColumn, TableMetadata,
+};
+use async_trait::async_trait;
+use mongodb::bson::{doc, oid::ObjectId, Bson, Document};
+use mongodb::options::{ClientOptions, Credential, ServerAddress, Tls, TlsOptions};
+
+/// Database used when the connection doesn't name one, as in `mongosh`
+const DEFAULT_DATABASE: &str = "test";
+
+/// Documents read to guess a collection's columns
+const SAMPLE_SIZE: i64 = 100;
+
+/// MongoDB connection on top of the `mongodb` crate. Collections are listed
+/// as tables; documents are rows, with the columns taken from the union of
+/// the field names on the page shown.
+pub struct MongoDbConnection {
+    config: ConnectionConfig,
+    client: Option<mongodb::Client>,
+}
+
+impl std::fmt::Debug for MongoDbConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MongoDbConnection")
+            .field("config", &self.config)
+            .field("connected", &self.client.is_some())
+            .finish()
+    }
+}
+
+/// Query typed in the editor, in `mongosh` form
+#[derive(Debug, Clone, PartialEq)]
+enum MongoQuery {
+    /// `db.orders.find({...}).limit(n).skip(n)`
+    Find {
+        collection: String,
+        filter: Document,
+        limit: Option<i64>,
+        skip: Option<u64>,
+    },
+    /// `db.orders.countDocuments({...})`
+    Count {
+        collection: String,
+        filter: Document,
+    },
+    /// A bare command document such as `{"dbStats": 1}`
+    Command(Document),
+}
+
+impl MongoDbConnection {
+    /// Create a new MongoDB connection instance
+    pub fn new(config: ConnectionConfig) -> Self {
+        Self {
+            config,
+            client: None,
+        }
+    }
+
+    /// The database collections are listed from
+    fn database(&self) -> Result<mongodb::Database> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| LazyTablesError::Connection("Not connected to database".to_string()))?;
+        let name = self
+            .config
+            .database
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .unwrap_or(DEFAULT_DATABASE);
+        Ok(client.database(name))
+    }
+
+    /// Documents of a `find`, as columns and rows
+    async fn find(
+        &self,
+        collection: &str,
+        filter: Document,
+        limit: Option<i64>,
+        skip: Option<u64>,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        let collection = self.database()?.collection::<Document>(collection);
+        let mut find = collection.find(filter);
+        if let Some(limit) = limit {
+            find = find.limit(limit);
+        }
+        if let Some(skip) = skip {
+            find = find.skip(skip);
+        }
+        let mut cursor = find.await.map_err(driver_error)?;
+        let mut documents = Vec::new();
+        while cursor.advance().await.map_err(driver_error)? {
+            documents.push(cursor.deserialize_current().map_err(driver_error)?);
+        }
+        Ok(document_rows(&documents))
+    }
+
+    /// Run a query typed in the query editor: `db.<collection>.find({...})`
+    /// with optional `.limit(n)` and `.skip(n)`, `countDocuments({...})`,
+    /// or a command document run against the database
+    pub async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        match parse_query(query)? {
+            MongoQuery::Find {
+                collection,
+                filter,
+                limit,
+                skip,
+            } => self.find(&collection, filter, limit, skip).await,
+            MongoQuery::Count { collection, filter } => {
+                let count = self
+                    .database()?
+                    .collection::<Document>(&collection)
+                    .count_documents(filter)
+                    .await
+                    .map_err(driver_error)?;
+                Ok((
+                    vec!["count".to_string()],
+                    vec![vec![Some(count.to_string())]],
+                ))
+            }
+            MongoQuery::Command(command) => {
+                let reply = self
+                    .database()?
+                    .run_command(command)
+                    .await
+                    .map_err(driver_error)?;
+                let rows = reply
+                    .iter()
+                    .map(|(field, value)| vec![Some(field.clone()), cell_value(value)])
+                    .collect();
+                Ok((vec!["field".to_string(), "value".to_string()], rows))
+            }
+        }
+    }
+
+    /// Run a query, reporting the command's `n` (documents written or
+    /// removed) or the rows returned as the rows affected
+    pub async fn execute_statement(&self, statement: &str) -> Result<u64> {
+        if let MongoQuery::Command(command) = parse_query(statement)? {
+            let reply = self
+                .database()?
+                .run_command(command)
+                .await
+                .map_err(driver_error)?;
+            return Ok(u64::try_from(number(&reply, "n")).unwrap_or(0));
+        }
+        let (_, rows) = self.execute_raw_query(statement).await?;
+        Ok(rows.len() as u64)
+    }
+
+    /// Collection names as tables
+    pub async fn list_tables(&self) -> Result<Vec<String>> {
+        let mut names = self
+            .database()?
+            .list_collection_names()
+            .await
+            .map_err(driver_error)?;
+        names.sort();
+        Ok(names)
+    }
+
+    /// Collections as tables and views as views, one page at a time
+    pub async fn list_database_objects_page(
+        &self,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        use crate::database::{DatabaseObject, DatabaseObjectList, DatabaseObjectType};
+        use mongodb::results::CollectionType;
+
+        let mut cursor = self
+            .database()?
+            .list_collections()
+            .await
+            .map_err(driver_error)?;
+        let mut specs = Vec::new();
+        while cursor.advance().await.map_err(driver_error)? {
+            specs.push(cursor.deserialize_current().map_err(driver_error)?);
+        }
+        specs.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut result = DatabaseObjectList::default();
+        for spec in specs
+            .into_iter()
+            .skip(offset)
+            .take(limit.unwrap_or(usize::MAX))
+        {
+            let (object_type, comment) = match spec.collection_type {
+                CollectionType::View => (DatabaseObjectType::View, None),
+                CollectionType::Timeseries => {
+                    (DatabaseObjectType::Table, Some("time series".to_string()))
+                }
+                _ => (DatabaseObjectType::Table, None),
+            };
+            let object = DatabaseObject {
+                name: spec.name,
+                schema: None,
+                object_type: object_type.clone(),
+                row_count: None,
+                size_bytes: None,
+                comment,
+            };
+            match object_type {
+                DatabaseObjectType::View => result.views.push(object),
+                _ => result.tables.push(object),
+            }
+            result.total_count += 1;
+        }
+        Ok(result)
+    }
+
+    /// Columns found in the first documents of a collection
+    pub async fn get_table_columns(&self, collection: &str) -> Result<Vec<TableColumn>> {
+        let documents = {
+            let collection = self.database()?.collection::<Document>(collection);
+            let mut cursor = collection
+                .find(doc! {})
+                .limit(SAMPLE_SIZE)
+                .await
+                .map_err(driver_error)?;
+            let mut documents = Vec::new();
+            while cursor.advance().await.map_err(driver_error)? {
+                documents.push(cursor.deserialize_current().map_err(driver_error)?);
+            }
+            documents
+        };
+        Ok(infer_columns(&documents)
+            .into_iter()
+            .map(|name| {
+                let data_type = documents
+                    .iter()
+                    .find_map(|document| document.get(&name).and_then(bson_data_type))
+                    .unwrap_or(DataType::Text);
+                TableColumn {
+                    is_primary_key: name == "_id",
+                    is_nullable: name != "_id",
+                    name,
+                    data_type,
+                    default_value: None,
+                }
+            })
+            .collect())
+    }
+
+    /// Document count, sizes and index count from `collStats`
+    pub async fn get_table_metadata(&self, collection: &str) -> Result<TableMetadata> {
+        let database = self.database()?;
+        let row_count = database
+            .collection::<Document>(collection)
+            .estimated_document_count()
+            .await
+            .map_err(driver_error)?;
+        // collStats may be refused to users without dbAdmin; sizes are then unknown
+        let stats = database
+            .run_command(doc! { "collStats": collection })
+            .await
+            .unwrap_or_default();
+        let columns = self.get_table_columns(collection).await?;
+        let size = number(&stats, "size");
+        let indexes = number(&stats, "nindexes");
+
+        Ok(TableMetadata::basic(
+            collection.to_string(),
+            usize::try_from(row_count).unwrap_or(usize::MAX),
+            columns.len(),
+            size + number(&stats, "totalIndexSize"),
+            size,
+            number(&stats, "totalIndexSize"),
+            vec!["_id".to_string()],
+            Vec::new(),
+            Vec::new(),
+            Some(format!("MongoDB collection, {indexes} indexes")),
+        )
+        .with_columns(&columns))
+    }
+
+    /// One page of documents in natural order, one column per field name on
+    /// the page
+    pub async fn get_table_data(
+        &self,
+        collection: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+        let (_, rows) = self
+            .find(
+                collection,
+                doc! {},
+                Some(i64::try_from(limit).unwrap_or(i64::MAX)),
+                Some(offset as u64),
+            )
+            .await?;
+        Ok(rows)
+    }
+}
+
+#[async_trait]
+impl Connection for MongoDbConnection {
+    async fn connect(&mut self) -> Result<()> {
+        self.connect_with_key(None).await
+    }
+
+    async fn connect_with_key(&mut self, encryption_key: Option<&str>) -> Result<()> {
+        let config = self.config.with_env_expanded()?;
+        let password = self
+            .config
+            .resolve_password(encryption_key)
+            .ok()
+            .filter(|password| !password.is_empty());
+        let username = config.username.trim();
+        let credential = (!username.is_empty()).then(|| {
+            Credential::builder()
+                .username(username.to_string())
+                .password(password)
+                .build()
+        });
+        let tls = match config.ssl_mode {
+            SslMode::Require | SslMode::VerifyCA | SslMode::VerifyFull | SslMode::Custom => {
+                Some(Tls::Enabled(TlsOptions::default()))
+            }
+            _ => None,
+        };
+        let timeout = std::time::Duration::from_secs(config.timeout.unwrap_or(30));
+        let options = ClientOptions::builder()
+            .hosts(vec![ServerAddress::Tcp {
+                host: config.host.trim().to_string(),
+                port: Some(config.port),
+            }])
+            .app_name("LazyTables".to_string())
+            .credential(credential)
+            .tls(tls)
+            .connect_timeout(timeout)
+            .server_selection_timeout(timeout)
+            .build();
+        let client = mongodb::Client::with_options(options)
+            .map_err(|e| LazyTablesError::Connection(format!("Invalid MongoDB settings: {e}")))?;
+
+        // The driver connects lazily; ping so bad hosts and passwords fail here
+        self.client = Some(client);
+        if let Err(e) = self.database()?.run_command(doc! { "ping": 1 }).await {
+            self.client = None;
+            return Err(LazyTablesError::Connection(format!(
+                "Failed to connect to MongoDB: {e}"
+            )));
+        }
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> Result<()> {
+        if let Some(client) = self.client.take() {
+            client.shutdown().await;
+        }
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.client.is_some()
+    }
+
+    fn config(&self) -> &ConnectionConfig {
+        &self.config
+    }
+
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        MongoDbConnection::execute_raw_query(self, query).await
+    }
+
+    async fn list_tables(&self) -> Result<Vec<String>> {
+        MongoDbConnection::list_tables(self).await
+    }
+
+    async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        self.list_database_objects_page(None, 0).await
+    }
+
+    async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
+        MongoDbConnection::get_table_metadata(self, table_name).await
+    }
+
+    async fn get_table_columns(&self, table_name: &str) -> Result<Vec<TableColumn>> {
+        MongoDbConnection::get_table_columns(self, table_name).await
+    }
+
+    async fn get_table_data(
+        &self,
+        table_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        MongoDbConnection::get_table_data(self, table_name, limit, offset).await
+    }
+
+    async fn get_database_capabilities(&self) -> Result<crate::database::DatabaseCapabilities> {
+        Ok(crate::database::DatabaseCapabilities {
+            supports_schemas: false,
+            supports_transactions: true, // On replica sets
+            supports_foreign_keys: false,
+            supports_json: true,
+            supports_arrays: true,
+            supports_stored_procedures: false,
+            supports_triggers: false,
+            supports_views: true,
+            supports_materialized_views: false,
+            supports_window_functions: false,
+            supports_cte: false,
+            max_identifier_length: 255, // Namespace length limit
+            max_query_length: Some(16 * 1024 * 1024), // Maximum BSON document size
+            supported_isolation_levels: Vec::new(),
+        })
+    }
+
+    async fn health_check(&self) -> Result<crate::database::HealthStatus> {
+        let start = std::time::Instant::now();
+        let ping = match self.database() {
+            Ok(database) => database
+                .run_command(doc! { "ping": 1 })
+                .await
+                .map_err(driver_error),
+            Err(e) => Err(e),
+        };
+        Ok(crate::database::HealthStatus {
+            is_healthy: ping.is_ok(),
+            response_time_ms: start.elapsed().as_millis() as u64,
+            last_error: ping.err().map(|e| e.to_string()),
+            database_version: None,
+            active_connections: u32::from(self.client.is_some()),
+            max_connections: 1,
+            uptime_seconds: None,
+        })
+    }
+
+    async fn get_server_info(&self) -> Result<crate::database::ServerInfo> {
+        let database = self.database()?;
+        let build = database
+            .run_command(doc! { "buildInfo": 1 })
+            .await
+            .map_err(driver_error)?;
+        // serverStatus needs the clusterMonitor role
+        let status = database.run_command(doc! { "serverStatus": 1 }).await.ok();
+
+        Ok(crate::database::ServerInfo {
+            version: build.get_str("version").unwrap_or_default().to_string(),
+            build_info: build.get_str("gitVersion").ok().map(str::to_string),
+            server_name: status
+                .as_ref()
+                .and_then(|status| status.get_str("host").ok())
+                .map(str::to_string),
+            charset: None,
+            timezone: None,
+            uptime_seconds: status
+                .as_ref()
+                .map(|status| number(status, "uptime"))
+                .and_then(|uptime| u64::try_from(uptime).ok()),
+            current_database: Some(database.name().to_string()),
+            current_user: Some(self.config.username.clone()).filter(|u| !u.is_empty()),
+        })
+    }
+
+    fn get_pool_status(&self) -> Option<crate::database::PoolStatus> {
+        self.client.as_ref().map(|_| crate::database::PoolStatus {
+            size: 1,
+            active: 1,
+            idle: 0,
+            waiting: 0,
+            max_size: 1,
+            min_size: 1,
+        })
+    }
+
+    fn max_connections(&self) -> u32 {
+        1 // The driver pools internally
+    }
+
+    fn active_connections(&self) -> u32 {
+        u32::from(self.client.is_some())
+    }
+
+    fn format_error(&self, error: &str) -> crate::database::FormattedError {
+        let mut recovery_suggestions = Vec::new();
+        let mut is_connection_error = false;
+        let mut is_syntax_error = false;
+        let mut is_permission_error = false;
+
+        let user_message = if error.contains("Authentication failed") {
+            is_permission_error = true;
+            recovery_suggestions.push("Check the connection's username and password".to_string());
+            "Authentication failed."
+        } else if error.contains("not authorized") || error.contains("Unauthorized") {
+            is_permission_error = true;
+            recovery_suggestions
+                .push("The user needs a role that allows this on the database".to_string());
+            "Permission denied."
+        } else if error.contains("Expected db.") || error.contains("JSON document") {
+            is_syntax_error = true;
+            recovery_suggestions
+                .push("Write queries as db.orders.find({\"status\": \"paid\"})".to_string());
+            "Invalid MongoDB query."
+        } else if error.contains("Server selection timeout") || error.contains("Connection refused")
+        {
+            is_connection_error = true;
+            recovery_suggestions.push(format!(
+                "Check that MongoDB listens on {}:{}",
+                self.config.host, self.config.port
+            ));
+            "Cannot reach MongoDB."
+        } else {
+            "MongoDB error occurred."
+        };
+
+        crate::database::FormattedError {
+            original_error: error.to_string(),
+            user_message: user_message.to_string(),
+            error_code: None,
+            recovery_suggestions,
+            is_connection_error,
+            is_syntax_error,
+            is_permission_error,
+        }
+    }
+
+    fn get_keywords(&self) -> Vec<String> {
+        [
+            "db",
+            "getCollection",
+            "find",
+            "countDocuments",
+            "limit",
+            "skip",
+            "$eq",
+            "$ne",
+            "$gt",
+            "$gte",
+            "$lt",
+            "$lte",
+            "$in",
+            "$nin",
+            "$and",
+            "$or",
+            "$not",
+            "$exists",
+            "$regex",
+            "$options",
+            "$elemMatch",
+            "$size",
+            "$oid",
+            "$date",
+        ]
+        .iter()
+        .map(|keyword| keyword.to_string())
+        .collect()
+    }
+
+    fn get_functions(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+#[async_trait]
+impl crate::database::connection_manager::ManagedConnection for MongoDbConnection {
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        MongoDbConnection::execute_raw_query(self, query).await
+    }
+
+    async fn execute_statement(&self, statement: &str) -> Result<u64> {
+        MongoDbConnection::execute_statement(self, statement).await
+    }
+
+    async fn get_table_data(
+        &self,
+        table_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        MongoDbConnection::get_table_data(self, table_name, limit, offset).await
+    }
+
+    async fn get_table_columns(&self, table_name: &str) -> Result<Vec<TableColumn>> {
+        MongoDbConnection::get_table_columns(self, table_name).await
+    }
+
+    async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
+        MongoDbConnection::get_table_metadata(self, table_name).await
+    }
+
+    async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        self.list_database_objects_page(None, 0).await
+    }
+
+    async fn list_database_objects_page(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        MongoDbConnection::list_database_objects_page(self, Some(limit), offset).await
+    }
+
+    fn is_connected(&self) -> bool {
+        Connection::is_connected(self)
+    }
+
+    fn query_canceller(&self) -> QueryCanceller {
+        QueryCanceller::Abort
+    }
+}
+
+fn driver_error(error: mongodb::error::Error) -> LazyTablesError {
+    LazyTablesError::Other(format!("MongoDB error: {error}"))
+}
+
+/// An integer field of a command reply, whichever BSON number type it came as
+fn number(document: &Document, key: &str) -> i64 {
+    match document.get(key) {
+        Some(Bson::Int32(n)) => i64::from(*n),
+        Some(Bson::Int64(n)) => *n,
+        Some(Bson::Double(n)) => *n as i64,
+        _ => 0,
+    }
+}
+
+/// Field names across `documents` in order of first appearance
+fn infer_columns(documents: &[Document]) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for document in documents {
+        for key in document.keys() {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+    }
+    columns
+}
+
+/// Documents as rows under the union of their field names; a field missing
+/// from a document shows as NULL
+fn document_rows(documents: &[Document]) -> (Vec<String>, Vec<Vec<Option<String>>>) {
+    let columns = infer_columns(documents);
+    let rows = documents
+        .iter()
+        .map(|document| {
+            columns
+                .iter()
+                .map(|column| document.get(column).and_then(cell_value))
+                .collect()
+        })
+        .collect();
+    (columns, rows)
+}
+
+/// A value as a result cell; null and undefined are NULL cells
+fn cell_value(value: &Bson) -> Option<String> {
+    match value {
+        Bson::Null | Bson::Undefined => None,
+        other => Some(cell_text(other)),
+    }
+}
+
+/// Text shown for a value: ObjectIds as hex, dates as RFC 3339 and
+/// sub-documents and arrays as JSON
+fn cell_text(value: &Bson) -> String {
+    match value {
+        Bson::Null | Bson::Undefined => "NULL".to_string(),
+        Bson::ObjectId(id) => id.to_hex(),
+        Bson::String(text) | Bson::Symbol(text) => text.clone(),
+        Bson::Boolean(value) => value.to_string(),
+        Bson::Int32(n) => n.to_string(),
+        Bson::Int64(n) => n.to_string(),
+        Bson::Double(n) => n.to_string(),
+        Bson::Decimal128(n) => n.to_string(),
+        Bson::DateTime(date) => date
+            .try_to_rfc3339_string()
+            .unwrap_or_else(|_| date.to_string()),
+        other => other.clone().into_relaxed_extjson().to_string(),
+    }
+}
+
+/// Column type for a value, if it has a useful one
+fn bson_data_type(value: &Bson) -> Option<DataType> {
+    Some(match value {
+        Bson::Null | Bson::Undefined => return None,
+        Bson::Int32(_) => DataType::Integer,
+        Bson::Int64(_) => DataType::BigInt,
+        Bson::Double(_) => DataType::Double,
+        Bson::Decimal128(_) => DataType::Decimal,
+        Bson::Boolean(_) => DataType::Boolean,
+        Bson::DateTime(_) => DataType::Timestamp,
+        Bson::Binary(_) => DataType::Bytea,
+        Bson::Document(_) | Bson::Array(_) => DataType::Json,
+        _ => DataType::Text,
+    })
+}
+
+/// Query document for the values typed in a column filter row. Numbers,
+/// booleans, `null` and ObjectId hex match exactly; other text matches
+/// case-insensitively anywhere in the field.
+pub fn filter_document(filters: &[(String, String)]) -> Document {
+    let mut filter = Document::new();
+    for (column, text) in filters {
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let value = if text == "null" {
+            Bson::Null
+        } else if let Ok(value) = text.parse::<bool>() {
+            Bson::Boolean(value)
+        } else if let Ok(n) = text.parse::<i64>() {
+            Bson::Int64(n)
+        } else if let Ok(n) = text.parse::<f64>() {
+            Bson::Double(n)
+        } else if let Some(id) = (text.len() == 24)
+            .then(|| ObjectId::parse_str(text).ok())
+            .flatten()
+        {
+            Bson::ObjectId(id)
+        } else {
+            Bson::Document(doc! { "$regex": regex_escape(text), "$options": "i" })
+        };
+        filter.insert(column.clone(), value);
+    }
+    filter
+}
+
+/// Escape regex metacharacters so typed text matches literally
+fn regex_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\^$.|?*+()[]{}".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// A JSON argument as a document; empty means match everything
+fn json_document(text: &str) -> Result<Document> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(Document::new());
+    }
+    let invalid = || {
+        LazyTablesError::InvalidInput(format!(
+            "Expected a JSON document such as {{\"status\": \"paid\"}}, got {text}"
+        ))
+    };
+    let json: serde_json::Value = serde_json::from_str(text).map_err(|_| invalid())?;
+    // Extended JSON, so {"$oid": "..."} and {"$date": "..."} keep their types
+    match Bson::try_from(json).map_err(|_| invalid())? {
+        Bson::Document(document) => Ok(document),
+        _ => Err(invalid()),
+    }
+}
+
+/// Index just past the `)` closing the call whose arguments start at `start`,
+/// skipping parentheses inside strings
+fn closing_paren(text: &str, start: usize) -> Option<usize> {
+    let mut depth = 1;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in text[start..].char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(start + i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse `db.<collection>.<method>(...)...` or a command document
+fn parse_query(query: &str) -> Result<MongoQuery> {
+    let query = query.trim().trim_end_matches(';').trim();
+    if query.starts_with('{') {
+        return Ok(MongoQuery::Command(json_document(query)?));
+    }
+    let invalid = |detail: &str| {
+        LazyTablesError::InvalidInput(format!(
+            "Expected db.<collection>.find({{...}}) or a command document: {detail}"
+        ))
+    };
+    let rest = query
+        .strip_prefix("db.")
+        .ok_or_else(|| invalid("missing db."))?;
+
+    // Collection names may contain dots, so look for the first known call
+    let (collection, mut rest) = if let Some(args) = rest.strip_prefix("getCollection(") {
+        let end = closing_paren(args, 0).ok_or_else(|| invalid("unclosed getCollection("))?;
+        let name = args[..end - 1].trim();
+        let name = serde_json::from_str::<String>(name)
+            .ok()
+            .or_else(|| {
+                name.strip_prefix('\'')
+                    .and_then(|name| name.strip_suffix('\''))
+                    .map(str::to_string)
+            })
+            .ok_or_else(|| invalid("getCollection takes a quoted name"))?;
+        (name, &args[end..])
+    } else {
+        let end = [".find(", ".countDocuments("]
+            .iter()
+            .filter_map(|call| rest.find(call))
+            .min()
+            .ok_or_else(|| invalid("only find and countDocuments are supported"))?;
+        (rest[..end].to_string(), &rest[end..])
+    };
+
+    let mut parsed = None;
+    let mut limit = None;
+    let mut skip = None;
+    while !rest.trim().is_empty() {
+        let call = rest
+            .trim_start()
+            .strip_prefix('.')
+            .ok_or_else(|| invalid("expected .method(...)"))?;
+        let open = call.find('(').ok_or_else(|| invalid("expected ("))?;
+        let close = closing_paren(call, open + 1).ok_or_else(|| invalid("unclosed ("))?;
+        let args = &call[open + 1..close - 1];
+        match (call[..open].trim(), &parsed) {
+            ("find", None) => parsed = Some((true, json_document(args)?)),
+            ("countDocuments", None) => parsed = Some((false, json_document(args)?)),
+            ("limit", Some((true, _))) => {
+                limit = Some(args.trim().parse().map_err(|_| invalid("bad limit"))?);
+            }
+            ("skip", Some((true, _))) => {
+                skip = Some(args.trim().parse().map_err(|_| invalid("bad skip"))?);
+            }
+            (method, _) => return Err(invalid(&format!("unsupported call {method}()"))),
+        }
+        rest = &call[close..];
+    }
+
+    match parsed {
+        Some((true, filter)) => Ok(MongoQuery::Find {
+            collection,
+            filter,
+            limit,
+            skip,
+        }),
+        Some((false, filter)) => Ok(MongoQuery::Count { collection, filter }),
+        None => Err(invalid("missing find or countDocuments")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_query() {
+        assert_eq!(
+            parse_query("db.orders.find({\"status\": \"paid\"}).limit(50).skip(100);").unwrap(),
+            MongoQuery::Find {
+                collection: "orders".to_string(),
+                filter: doc! { "status": "paid" },
+                limit: Some(50),
+                skip: Some(100),
+            }
+        );
+        assert_eq!(
+            parse_query("db.getCollection(\"system.profile\").find({}).limit(10)").unwrap(),
+            MongoQuery::Find {
+                collection: "system.profile".to_string(),
+                filter: Document::new(),
+                limit: Some(10),
+                skip: None,
+            }
+        );
+        assert_eq!(
+            parse_query("db.audit.log.countDocuments({\"note\": \"a (b)\"})").unwrap(),
+            MongoQuery::Count {
+                collection: "audit.log".to_string(),
+                filter: doc! { "note": "a (b)" },
+            }
+        );
+        assert_eq!(
+            parse_query("{\"dbStats\": 1}").unwrap(),
+            MongoQuery::Command(doc! { "dbStats": 1 })
+        );
+        assert!(parse_query("SELECT * FROM orders").is_err());
+        assert!(parse_query("db.orders.find({status: 1})").is_err());
+        assert!(parse_query("db.orders.find().sort({})").is_err());
+    }
+
+    #[test]
+    fn test_document_rows_union_columns() {
+        let id = ObjectId::parse_str("64b7f0c2a1b2c3d4e5f60718").unwrap();
+        let documents = vec![
+            doc! { "_id": id, "name": "Ada", "address": { "city": "London" } },
+            doc! { "_id": 2, "name": "Linus", "tags": ["kernel", "git"], "age": 54 },
+        ];
+        let (columns, rows) = document_rows(&documents);
+        assert_eq!(columns, ["_id", "name", "address", "tags", "age"]);
+        fn cells(row: &[Option<String>]) -> Vec<Option<&str>> {
+            row.iter().map(Option::as_deref).collect()
+        }
+        assert_eq!(
+            cells(&rows[0]),
+            [
+                Some("64b7f0c2a1b2c3d4e5f60718"),
+                Some("Ada"),
+                Some("{\"city\":\"London\"}"),
+                None,
+                None
+            ]
+        );
+        assert_eq!(
+            cells(&rows[1]),
+            [
+                Some("2"),
+                Some("Linus"),
+                None,
+                Some("[\"kernel\",\"git\"]"),
+                Some("54")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_document() {
+        let filter = filter_document(&[
+            ("_id".to_string(), "64b7f0c2a1b2c3d4e5f60718".to_string()),
+            ("age".to_string(), "54".to_string()),
+            ("active".to_string(), "true".to_string()),
+            ("name".to_string(), "a.b".to_string()),
+            ("empty".to_string(), " ".to_string()),
+        ]);
+        assert_eq!(
+            filter,
+            doc! {
+                "_id": ObjectId::parse_str("64b7f0c2a1b2c3d4e5f60718").unwrap(),
+                "age": 54_i64,
+                "active": true,
+                "name": { "$regex": "a\\.b", "$options": "i" },
+            }
+        );
+    }
+}
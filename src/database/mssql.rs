@@ -0,0 +1,960 @@
+// FilePath: src/database/mssql.rs
+
+#![forbid(unsafe_code)]
+
+use crate::core::error::{LazyTablesError, Result};
+use crate::database::{
+    connection::{init_sql_error, ConnectionConfig, SslMode},
+    connection_manager::QueryCanceller,
+    ddl::quote_qualified_identifier,
+    Connection, DataType, DatabaseType, TableColumn, TableMetadata,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tiberius::{AuthMethod, Client, ColumnData, Config, EncryptionLevel, FromSql, SqlBrowser};
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+use tokio_util::compat::{Compat, TokioAsyncWriteCompatExt};
+
+type MsSqlClient = Client<Compat<TcpStream>>;
+
+/// SQL Server connection implementation on top of `tiberius`. A TDS session
+/// runs one request at a time, so the client sits behind a mutex.
+pub struct MsSqlConnection {
+    config: ConnectionConfig,
+    client: Option<Arc<Mutex<MsSqlClient>>>,
+}
+
+impl std::fmt::Debug for MsSqlConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MsSqlConnection")
+            .field("config", &self.config)
+            .field("connected", &self.client.is_some())
+            .finish()
+    }
+}
+
+impl MsSqlConnection {
+    /// Create a new SQL Server connection instance
+    pub fn new(config: ConnectionConfig) -> Self {
+        Self {
+            config,
+            client: None,
+        }
+    }
+
+    fn client(&self) -> Result<Arc<Mutex<MsSqlClient>>> {
+        self.client
+            .clone()
+            .ok_or_else(|| LazyTablesError::Connection("Not connected to database".to_string()))
+    }
+
+    /// Run a query and return column names and the first result set as text
+    pub async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        let client = self.client()?;
+        let mut client = client.lock().await;
+        let mut stream = client.simple_query(query).await.map_err(query_error)?;
+        let columns: Vec<String> = stream
+            .columns()
+            .await
+            .map_err(query_error)?
+            .map(|columns| columns.iter().map(|c| c.name().to_string()).collect())
+            .unwrap_or_default();
+        let rows = stream.into_first_result().await.map_err(query_error)?;
+        let data = rows
+            .iter()
+            .map(|row| row.cells().map(|(_, value)| cell_text(value)).collect())
+            .collect();
+        Ok((columns, data))
+    }
+
+    /// Run a statement that returns no rows, reporting the rows it affected
+    pub async fn execute_statement(&self, statement: &str) -> Result<u64> {
+        let client = self.client()?;
+        let mut client = client.lock().await;
+        let result = client.execute(statement, &[]).await.map_err(query_error)?;
+        Ok(result.total())
+    }
+
+    /// Run a parameterized query, returning its rows as text
+    async fn query_text(&self, sql: &str, param: &str) -> Result<Vec<Vec<Option<String>>>> {
+        let client = self.client()?;
+        let mut client = client.lock().await;
+        let rows = client
+            .query(sql, &[&param])
+            .await
+            .map_err(query_error)?
+            .into_first_result()
+            .await
+            .map_err(query_error)?;
+        Ok(rows
+            .iter()
+            .map(|row| row.cells().map(|(_, value)| cell_text(value)).collect())
+            .collect())
+    }
+
+    /// `AND s.name = ...` limiting listings to the connection's default schema
+    fn schema_filter(&self) -> String {
+        self.config
+            .default_schema
+            .as_deref()
+            .map(|schema| format!("AND s.name = N'{}'", schema.replace('\'', "''")))
+            .unwrap_or_default()
+    }
+
+    /// List user tables as `schema.name`
+    pub async fn list_tables(&self) -> Result<Vec<String>> {
+        let (_, rows) = self
+            .execute_raw_query(&format!(
+                "SELECT s.name, t.name FROM sys.tables t
+                 JOIN sys.schemas s ON s.schema_id = t.schema_id
+                 WHERE t.is_ms_shipped = 0 {}
+                 ORDER BY s.name, t.name",
+                self.schema_filter()
+            ))
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| row.into_iter().flatten().collect::<Vec<_>>().join("."))
+            .collect())
+    }
+
+    /// List tables and views across all schemas one page at a time; `None` lists everything
+    pub async fn list_database_objects_page(
+        &self,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        use crate::database::{DatabaseObject, DatabaseObjectList, DatabaseObjectType};
+
+        let page = match limit {
+            Some(limit) => format!("OFFSET {offset} ROWS FETCH NEXT {limit} ROWS ONLY"),
+            None => String::new(),
+        };
+        let query = format!(
+            "SELECT s.name, o.name, o.type,
+                    (SELECT SUM(p.rows) FROM sys.partitions p
+                     WHERE p.object_id = o.object_id AND p.index_id IN (0, 1)),
+                    CAST(ep.value AS NVARCHAR(4000))
+             FROM sys.objects o
+             JOIN sys.schemas s ON s.schema_id = o.schema_id
+             LEFT JOIN sys.extended_properties ep
+               ON ep.major_id = o.object_id AND ep.minor_id = 0
+              AND ep.class = 1 AND ep.name = 'MS_Description'
+             WHERE o.type IN ('U', 'V') AND o.is_ms_shipped = 0 {}
+             ORDER BY s.name, o.type, o.name {page}",
+            self.schema_filter()
+        );
+        let (_, rows) = self.execute_raw_query(&query).await?;
+
+        let mut result = DatabaseObjectList::default();
+        for row in rows {
+            let [schema, name, kind, row_count, comment] = <[Option<String>; 5]>::try_from(row)
+                .map_err(|_| LazyTablesError::Other("Unexpected object list shape".to_string()))?;
+            let object_type = if kind.as_deref().map(str::trim) == Some("V") {
+                DatabaseObjectType::View
+            } else {
+                DatabaseObjectType::Table
+            };
+            let object = DatabaseObject {
+                name: name.unwrap_or_default(),
+                schema,
+                object_type: object_type.clone(),
+                row_count: row_count.and_then(|count| count.parse().ok()),
+                size_bytes: None,
+                comment,
+            };
+            match object_type {
+                DatabaseObjectType::View => result.views.push(object),
+                _ => result.tables.push(object),
+            }
+            result.total_count += 1;
+        }
+        Ok(result)
+    }
+
+    /// Column information from `sys.columns`, with primary keys from `sys.indexes`
+    pub async fn get_table_columns(&self, table_name: &str) -> Result<Vec<TableColumn>> {
+        let quoted = quote_mssql_identifier(table_name)?;
+        let rows = self
+            .query_text(
+                "SELECT c.name, ty.name, c.max_length, c.is_nullable,
+                        OBJECT_DEFINITION(c.default_object_id),
+                        CASE WHEN EXISTS (
+                            SELECT 1 FROM sys.indexes i
+                            JOIN sys.index_columns ic
+                              ON ic.object_id = i.object_id AND ic.index_id = i.index_id
+                            WHERE i.is_primary_key = 1
+                              AND i.object_id = c.object_id
+                              AND ic.column_id = c.column_id
+                        ) THEN 1 ELSE 0 END
+                 FROM sys.columns c
+                 JOIN sys.types ty ON ty.user_type_id = c.user_type_id
+                 WHERE c.object_id = OBJECT_ID(@P1)
+                 ORDER BY c.column_id",
+                &quoted,
+            )
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter(|row| row.len() == 6)
+            .map(|row| TableColumn {
+                data_type: parse_mssql_type(
+                    row[1].as_deref().unwrap_or_default(),
+                    row[2].as_deref().and_then(|length| length.parse().ok()),
+                ),
+                is_nullable: row[3].as_deref() == Some("1"),
+                default_value: row[4].clone(),
+                is_primary_key: row[5].as_deref() == Some("1"),
+                name: row[0].clone().unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    /// Table details from the catalog views (`sys.indexes`, `sys.foreign_keys`, ...)
+    pub async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
+        let columns = self.get_table_columns(table_name).await.unwrap_or_default();
+        let quoted = quote_mssql_identifier(table_name)?;
+
+        let view_definition = self
+            .query_text(
+                "SELECT OBJECT_DEFINITION(OBJECT_ID(@P1))
+                 FROM sys.views WHERE object_id = OBJECT_ID(@P1)",
+                &quoted,
+            )
+            .await?
+            .into_iter()
+            .next()
+            .and_then(|row| row.into_iter().next().flatten());
+
+        let row_count = if view_definition.is_some() {
+            0
+        } else {
+            let (_, rows) = self
+                .execute_raw_query(&format!("SELECT COUNT_BIG(*) FROM {quoted}"))
+                .await?;
+            first_number(&rows)
+        };
+
+        let primary_keys = self
+            .query_text(
+                "SELECT c.name FROM sys.indexes i
+                 JOIN sys.index_columns ic
+                   ON ic.object_id = i.object_id AND ic.index_id = i.index_id
+                 JOIN sys.columns c
+                   ON c.object_id = ic.object_id AND c.column_id = ic.column_id
+                 WHERE i.is_primary_key = 1 AND i.object_id = OBJECT_ID(@P1)
+                 ORDER BY ic.key_ordinal",
+                &quoted,
+            )
+            .await?
+            .into_iter()
+            .filter_map(|row| row.into_iter().next().flatten())
+            .collect();
+
+        let foreign_keys = self
+            .query_text(
+                "SELECT pc.name, OBJECT_SCHEMA_NAME(fkc.referenced_object_id),
+                        OBJECT_NAME(fkc.referenced_object_id), rc.name
+                 FROM sys.foreign_key_columns fkc
+                 JOIN sys.columns pc
+                   ON pc.object_id = fkc.parent_object_id AND pc.column_id = fkc.parent_column_id
+                 JOIN sys.columns rc
+                   ON rc.object_id = fkc.referenced_object_id
+                  AND rc.column_id = fkc.referenced_column_id
+                 WHERE fkc.parent_object_id = OBJECT_ID(@P1)
+                 ORDER BY fkc.constraint_object_id, fkc.constraint_column_id",
+                &quoted,
+            )
+            .await?
+            .into_iter()
+            .filter(|row| row.len() == 4)
+            .map(|row| {
+                let [column, schema, table, referenced] =
+                    [0, 1, 2, 3].map(|i| row[i].as_deref().unwrap_or_default());
+                format!("{column} → {schema}.{table}.{referenced}")
+            })
+            .collect();
+
+        let indexes = self
+            .query_text(
+                "SELECT name FROM sys.indexes
+                 WHERE object_id = OBJECT_ID(@P1) AND name IS NOT NULL
+                 ORDER BY name",
+                &quoted,
+            )
+            .await?
+            .into_iter()
+            .filter_map(|row| row.into_iter().next().flatten())
+            .collect();
+
+        let table_size = self
+            .query_text(
+                "SELECT SUM(used_page_count) * 8192 FROM sys.dm_db_partition_stats
+                 WHERE object_id = OBJECT_ID(@P1)",
+                &quoted,
+            )
+            .await
+            .map(|rows| first_number(&rows))
+            .unwrap_or(0);
+
+        let comment = self
+            .query_text(
+                "SELECT CAST(value AS NVARCHAR(4000)) FROM sys.extended_properties
+                 WHERE major_id = OBJECT_ID(@P1) AND minor_id = 0
+                   AND class = 1 AND name = 'MS_Description'",
+                &quoted,
+            )
+            .await
+            .ok()
+            .and_then(|rows| rows.into_iter().next())
+            .and_then(|row| row.into_iter().next().flatten());
+
+        let view_type = view_definition.as_ref().map(|_| "VIEW");
+        Ok(TableMetadata::basic(
+            table_name.to_string(),
+            row_count as usize,
+            columns.len(),
+            table_size,
+            table_size,
+            0,
+            primary_keys,
+            foreign_keys,
+            indexes,
+            comment,
+        )
+        .with_columns(&columns)
+        .with_view_type(view_type, view_definition))
+    }
+
+    /// Get table data with pagination. `OFFSET ... FETCH` needs an `ORDER BY`;
+    /// `(SELECT NULL)` keeps the server's own order.
+    pub async fn get_table_data(
+        &self,
+        table_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        let quoted = quote_mssql_identifier(table_name)?;
+        let (_, rows) = self
+            .execute_raw_query(&format!(
+                "SELECT * FROM {quoted} ORDER BY (SELECT NULL) \
+                 OFFSET {offset} ROWS FETCH NEXT {limit} ROWS ONLY"
+            ))
+            .await?;
+        Ok(rows)
+    }
+}
+
+#[async_trait]
+impl Connection for MsSqlConnection {
+    async fn connect(&mut self) -> Result<()> {
+        self.connect_with_key(None).await
+    }
+
+    async fn connect_with_key(&mut self, encryption_key: Option<&str>) -> Result<()> {
+        let config = self.config.with_env_expanded()?;
+        let password = self
+            .config
+            .resolve_password(encryption_key)
+            .unwrap_or_default();
+        let tds_config = tds_config(&config, password);
+        let timeout = std::time::Duration::from_secs(config.timeout.unwrap_or(30));
+
+        let mut client = tokio::time::timeout(timeout, open_client(tds_config))
+            .await
+            .map_err(|_| {
+                LazyTablesError::Connection(format!(
+                    "Timed out connecting to SQL Server at {}:{}",
+                    config.host, config.port
+                ))
+            })?
+            .map_err(|e| {
+                LazyTablesError::Connection(format!("Failed to connect to SQL Server: {e}"))
+            })?;
+
+        // A batch of its own, since SET options made inside sp_executesql
+        // end with it
+        for statement in self.config.session_sql() {
+            let result = match client.simple_query(statement.as_str()).await {
+                Ok(stream) => stream.into_results().await.map(drop),
+                Err(e) => Err(e),
+            };
+            result.map_err(|e| LazyTablesError::Connection(init_sql_error(&statement, e)))?;
+        }
+
+        self.client = Some(Arc::new(Mutex::new(client)));
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> Result<()> {
+        if let Some(client) = self.client.take() {
+            if let Ok(client) = Arc::try_unwrap(client) {
+                let _ = client.into_inner().close().await;
+            }
+        }
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.client.is_some()
+    }
+
+    fn config(&self) -> &ConnectionConfig {
+        &self.config
+    }
+
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        MsSqlConnection::execute_raw_query(self, query).await
+    }
+
+    async fn list_tables(&self) -> Result<Vec<String>> {
+        MsSqlConnection::list_tables(self).await
+    }
+
+    async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        self.list_database_objects_page(None, 0).await
+    }
+
+    async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
+        MsSqlConnection::get_table_metadata(self, table_name).await
+    }
+
+    async fn get_table_columns(&self, table_name: &str) -> Result<Vec<TableColumn>> {
+        MsSqlConnection::get_table_columns(self, table_name).await
+    }
+
+    async fn get_table_data(
+        &self,
+        table_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        MsSqlConnection::get_table_data(self, table_name, limit, offset).await
+    }
+
+    async fn get_database_capabilities(&self) -> Result<crate::database::DatabaseCapabilities> {
+        Ok(crate::database::DatabaseCapabilities {
+            supports_schemas: true,
+            supports_transactions: true,
+            supports_foreign_keys: true,
+            supports_json: true, // JSON functions over NVARCHAR, 2016+
+            supports_arrays: false,
+            supports_stored_procedures: true,
+            supports_triggers: true,
+            supports_views: true,
+            supports_materialized_views: false, // indexed views instead
+            supports_window_functions: true,
+            supports_cte: true,
+            max_identifier_length: 128,
+            max_query_length: None,
+            supported_isolation_levels: vec![
+                "READ UNCOMMITTED".to_string(),
+                "READ COMMITTED".to_string(),
+                "REPEATABLE READ".to_string(),
+                "SNAPSHOT".to_string(),
+                "SERIALIZABLE".to_string(),
+            ],
+        })
+    }
+
+    async fn health_check(&self) -> Result<crate::database::HealthStatus> {
+        let start = std::time::Instant::now();
+        let ping = self.execute_raw_query("SELECT 1").await;
+        Ok(crate::database::HealthStatus {
+            is_healthy: ping.is_ok(),
+            response_time_ms: start.elapsed().as_millis() as u64,
+            last_error: ping.err().map(|e| e.to_string()),
+            database_version: None,
+            active_connections: u32::from(self.client.is_some()),
+            max_connections: 1,
+            uptime_seconds: None,
+        })
+    }
+
+    async fn get_server_info(&self) -> Result<crate::database::ServerInfo> {
+        let (_, rows) = self
+            .execute_raw_query(
+                "SELECT CAST(SERVERPROPERTY('ProductVersion') AS NVARCHAR(128)), @@VERSION,
+                        @@SERVERNAME, DB_NAME(), SUSER_SNAME(),
+                        CAST(SERVERPROPERTY('Collation') AS NVARCHAR(128))",
+            )
+            .await?;
+        let row = rows.into_iter().next().unwrap_or_default();
+        let field = |i: usize| row.get(i).cloned().flatten();
+
+        Ok(crate::database::ServerInfo {
+            version: field(0).unwrap_or_default(),
+            build_info: field(1),
+            server_name: field(2),
+            charset: field(5),
+            timezone: None,
+            uptime_seconds: None,
+            current_database: field(3),
+            current_user: field(4),
+        })
+    }
+
+    fn get_pool_status(&self) -> Option<crate::database::PoolStatus> {
+        self.client.as_ref().map(|_| crate::database::PoolStatus {
+            size: 1,
+            active: 1,
+            idle: 0,
+            waiting: 0,
+            max_size: 1,
+            min_size: 1,
+        })
+    }
+
+    fn max_connections(&self) -> u32 {
+        1 // One TDS session
+    }
+
+    fn active_connections(&self) -> u32 {
+        u32::from(self.client.is_some())
+    }
+
+    fn format_error(&self, error: &str) -> crate::database::FormattedError {
+        let error_code = server_error_code(error);
+        let mut recovery_suggestions = Vec::new();
+        let mut is_connection_error = false;
+        let mut is_syntax_error = false;
+        let mut is_permission_error = false;
+
+        let user_message = match error_code {
+            Some(208) => {
+                recovery_suggestions.push("Check the table name and its schema".to_string());
+                recovery_suggestions
+                    .push("Tables outside dbo need a schema prefix, e.g. sales.orders".to_string());
+                "Invalid object name."
+            }
+            Some(207) => {
+                recovery_suggestions.push("Check the column name spelling".to_string());
+                "Invalid column name."
+            }
+            Some(102 | 156 | 170) => {
+                is_syntax_error = true;
+                recovery_suggestions.push("Check SQL syntax for typos".to_string());
+                recovery_suggestions
+                    .push("SQL Server uses TOP or OFFSET ... FETCH instead of LIMIT".to_string());
+                "SQL syntax error."
+            }
+            Some(18456) => {
+                is_connection_error = true;
+                recovery_suggestions.push("Verify the username and password".to_string());
+                recovery_suggestions
+                    .push("Check that SQL Server authentication is enabled".to_string());
+                "Login failed."
+            }
+            Some(4060) => {
+                is_connection_error = true;
+                recovery_suggestions.push("Check the database name".to_string());
+                "Cannot open the requested database."
+            }
+            Some(229 | 230 | 262 | 297) => {
+                is_permission_error = true;
+                recovery_suggestions.push("Ask for the needed permission".to_string());
+                "Permission denied."
+            }
+            Some(2627 | 2601) => {
+                recovery_suggestions.push("A row with this key already exists".to_string());
+                "Duplicate key."
+            }
+            Some(547) => {
+                recovery_suggestions.push("Check the referenced rows exist".to_string());
+                "Constraint violation."
+            }
+            _ if error.contains("SQL browser") => {
+                is_connection_error = true;
+                recovery_suggestions.push(
+                    "Check the instance name and that SQL Browser runs on UDP 1434".to_string(),
+                );
+                "Cannot resolve the named instance."
+            }
+            _ if error.contains("TLS") || error.contains("certificate") => {
+                is_connection_error = true;
+                recovery_suggestions.push(
+                    "Use SSL mode Require to trust the server certificate without checking it"
+                        .to_string(),
+                );
+                "TLS handshake failed."
+            }
+            _ if error.contains("Timed out") || error.contains("I/O") => {
+                is_connection_error = true;
+                recovery_suggestions.push(format!(
+                    "Check that SQL Server listens on {}:{}",
+                    self.config.host, self.config.port
+                ));
+                "Cannot reach SQL Server."
+            }
+            _ => {
+                recovery_suggestions.push("Check the SQL Server error number".to_string());
+                "SQL Server error occurred."
+            }
+        };
+
+        crate::database::FormattedError {
+            original_error: error.to_string(),
+            user_message: user_message.to_string(),
+            error_code: error_code.map(|code| code.to_string()),
+            recovery_suggestions,
+            is_connection_error,
+            is_syntax_error,
+            is_permission_error,
+        }
+    }
+
+    fn get_keywords(&self) -> Vec<String> {
+        [
+            "SELECT",
+            "TOP",
+            "FROM",
+            "WHERE",
+            "INSERT",
+            "UPDATE",
+            "DELETE",
+            "MERGE",
+            "OUTPUT",
+            "CREATE",
+            "DROP",
+            "ALTER",
+            "TABLE",
+            "INDEX",
+            "VIEW",
+            "SCHEMA",
+            "PROCEDURE",
+            "FUNCTION",
+            "TRIGGER",
+            "EXEC",
+            "DECLARE",
+            "SET",
+            "BEGIN",
+            "END",
+            "TRY",
+            "CATCH",
+            "TRANSACTION",
+            "COMMIT",
+            "ROLLBACK",
+            "IDENTITY",
+            "NOLOCK",
+            "APPLY",
+            "PIVOT",
+            "UNPIVOT",
+            "OFFSET",
+            "FETCH",
+            "ROWS",
+            "ONLY",
+            "GO",
+            "GRANT",
+            "REVOKE",
+        ]
+        .iter()
+        .map(|keyword| keyword.to_string())
+        .collect()
+    }
+
+    fn get_functions(&self) -> Vec<String> {
+        [
+            "COUNT",
+            "COUNT_BIG",
+            "SUM",
+            "AVG",
+            "MIN",
+            "MAX",
+            "ISNULL",
+            "COALESCE",
+            "NULLIF",
+            "IIF",
+            "CAST",
+            "CONVERT",
+            "TRY_CAST",
+            "TRY_CONVERT",
+            "FORMAT",
+            "GETDATE",
+            "SYSDATETIME",
+            "DATEADD",
+            "DATEDIFF",
+            "DATEPART",
+            "EOMONTH",
+            "LEN",
+            "SUBSTRING",
+            "CHARINDEX",
+            "REPLACE",
+            "UPPER",
+            "LOWER",
+            "TRIM",
+            "STRING_AGG",
+            "STRING_SPLIT",
+            "JSON_VALUE",
+            "JSON_QUERY",
+            "NEWID",
+            "ROW_NUMBER",
+            "RANK",
+            "DENSE_RANK",
+        ]
+        .iter()
+        .map(|function| function.to_string())
+        .collect()
+    }
+}
+
+#[async_trait]
+impl crate::database::connection_manager::ManagedConnection for MsSqlConnection {
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        MsSqlConnection::execute_raw_query(self, query).await
+    }
+
+    async fn execute_statement(&self, statement: &str) -> Result<u64> {
+        MsSqlConnection::execute_statement(self, statement).await
+    }
+
+    async fn get_table_data(
+        &self,
+        table_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        MsSqlConnection::get_table_data(self, table_name, limit, offset).await
+    }
+
+    async fn get_table_columns(&self, table_name: &str) -> Result<Vec<TableColumn>> {
+        MsSqlConnection::get_table_columns(self, table_name).await
+    }
+
+    async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
+        MsSqlConnection::get_table_metadata(self, table_name).await
+    }
+
+    async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        self.list_database_objects_page(None, 0).await
+    }
+
+    async fn list_database_objects_page(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        MsSqlConnection::list_database_objects_page(self, Some(limit), offset).await
+    }
+
+    fn is_connected(&self) -> bool {
+        Connection::is_connected(self)
+    }
+
+    fn query_canceller(&self) -> QueryCanceller {
+        QueryCanceller::Abort
+    }
+}
+
+/// TDS settings for a connection. SSL modes map onto TDS encryption levels:
+/// `Disable` sends only the login encrypted, `Allow`/`Prefer`/`Require`
+/// trust the server certificate, and the verify modes check it.
+fn tds_config(config: &ConnectionConfig, password: String) -> Config {
+    let mut tds = Config::new();
+    tds.host(config.host.trim());
+    tds.port(config.port);
+    if let Some(instance) = config
+        .instance_name
+        .as_deref()
+        .map(str::trim)
+        .filter(|instance| !instance.is_empty())
+    {
+        tds.instance_name(instance);
+    }
+    if let Some(database) = config
+        .database
+        .as_deref()
+        .filter(|database| !database.is_empty())
+    {
+        tds.database(database);
+    }
+    tds.application_name("LazyTables");
+    tds.authentication(AuthMethod::sql_server(&config.username, password));
+
+    match config.ssl_mode {
+        SslMode::Disable => tds.encryption(EncryptionLevel::NotSupported),
+        SslMode::Allow | SslMode::Prefer => {
+            tds.encryption(EncryptionLevel::On);
+            tds.trust_cert();
+        }
+        SslMode::Require | SslMode::Custom => {
+            tds.encryption(EncryptionLevel::Required);
+            tds.trust_cert();
+        }
+        SslMode::VerifyCA | SslMode::VerifyFull => tds.encryption(EncryptionLevel::Required),
+    }
+    tds
+}
+
+/// Open the TCP stream (through SQL Browser for a named instance) and log in,
+/// following one routing redirect as Azure SQL gateways send
+async fn open_client(mut config: Config) -> tiberius::Result<MsSqlClient> {
+    let tcp = TcpStream::connect_named(&config).await?;
+    tcp.set_nodelay(true)?;
+    match Client::connect(config.clone(), tcp.compat_write()).await {
+        Err(tiberius::error::Error::Routing { host, port }) => {
+            config.host(&host);
+            config.port(port);
+            let tcp = TcpStream::connect(config.get_addr()).await?;
+            tcp.set_nodelay(true)?;
+            Client::connect(config, tcp.compat_write()).await
+        }
+        result => result,
+    }
+}
+
+fn query_error(error: tiberius::error::Error) -> LazyTablesError {
+    LazyTablesError::Other(error.to_string())
+}
+
+/// Server error number from a tiberius message (`... (code: 208, state: 1, ...)`)
+fn server_error_code(error: &str) -> Option<u32> {
+    let start = error.find("(code: ")? + "(code: ".len();
+    error[start..]
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Quote a plain or `schema.table` name with brackets
+fn quote_mssql_identifier(name: &str) -> Result<String> {
+    if name.is_empty() || name.contains('\0') {
+        return Err(LazyTablesError::Connection(
+            "Invalid table name: contains null bytes or is empty".to_string(),
+        ));
+    }
+    Ok(quote_qualified_identifier(name, &DatabaseType::MsSql))
+}
+
+fn first_number(rows: &[Vec<Option<String>>]) -> i64 {
+    rows.first()
+        .and_then(|row| row.first()?.as_deref())
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Cell text as the other adapters give it: `None` for nulls, `1`/`0` for
+/// bits and `0x...` for binary, the way SQL Server tools print them
+fn cell_text(value: &ColumnData<'static>) -> Option<String> {
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+
+    match value {
+        ColumnData::U8(v) => v.map(|v| v.to_string()),
+        ColumnData::I16(v) => v.map(|v| v.to_string()),
+        ColumnData::I32(v) => v.map(|v| v.to_string()),
+        ColumnData::I64(v) => v.map(|v| v.to_string()),
+        ColumnData::F32(v) => v.map(|v| v.to_string()),
+        ColumnData::F64(v) => v.map(|v| v.to_string()),
+        ColumnData::Bit(v) => v.map(|v| if v { "1" } else { "0" }.to_string()),
+        ColumnData::String(v) => v.as_ref().map(|v| v.to_string()),
+        ColumnData::Guid(v) => v.map(|v| v.to_string().to_uppercase()),
+        ColumnData::Binary(v) => v.as_ref().map(|bytes| crate::database::hex_literal(bytes)),
+        ColumnData::Numeric(v) => v.map(|v| v.to_string()),
+        ColumnData::Xml(v) => v.as_ref().map(|v| v.to_string()),
+        ColumnData::DateTime(_) | ColumnData::SmallDateTime(_) | ColumnData::DateTime2(_) => {
+            NaiveDateTime::from_sql(value)
+                .ok()
+                .flatten()
+                .map(|v| v.to_string())
+        }
+        ColumnData::Date(_) => NaiveDate::from_sql(value)
+            .ok()
+            .flatten()
+            .map(|v| v.to_string()),
+        ColumnData::Time(_) => NaiveTime::from_sql(value)
+            .ok()
+            .flatten()
+            .map(|v| v.to_string()),
+        ColumnData::DateTimeOffset(_) => DateTime::<FixedOffset>::from_sql(value)
+            .ok()
+            .flatten()
+            .map(|v| v.to_string()),
+    }
+}
+
+/// Parse a `sys.types` name to the internal DataType enum. `max_length` is in
+/// bytes, so N-types hold half as many characters; `-1` means `MAX`.
+fn parse_mssql_type(type_name: &str, max_length: Option<i64>) -> DataType {
+    let chars = |bytes_per_char: i64| {
+        max_length
+            .filter(|&length| length > 0)
+            .map(|length| (length / bytes_per_char) as usize)
+    };
+    match type_name.to_lowercase().as_str() {
+        "int" => DataType::Integer,
+        "bigint" => DataType::BigInt,
+        "smallint" | "tinyint" => DataType::SmallInt,
+        "bit" => DataType::Boolean,
+        "decimal" | "numeric" | "money" | "smallmoney" => DataType::Decimal,
+        "real" => DataType::Float,
+        "float" => DataType::Double,
+        "varchar" => DataType::Varchar(chars(1)),
+        "nvarchar" => DataType::Varchar(chars(2)),
+        "char" => DataType::Char(chars(1)),
+        "nchar" => DataType::Char(chars(2)),
+        "text" | "ntext" | "xml" => DataType::Text,
+        "date" => DataType::Date,
+        "time" => DataType::Time,
+        "datetime" | "datetime2" | "smalldatetime" | "datetimeoffset" => DataType::Timestamp,
+        "uniqueidentifier" => DataType::Uuid,
+        "binary" | "varbinary" | "image" | "timestamp" | "rowversion" => DataType::Bytea,
+        _ => DataType::Text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_quoting() {
+        assert_eq!(
+            quote_mssql_identifier("sales.Order Lines").unwrap(),
+            "[sales].[Order Lines]"
+        );
+        assert_eq!(quote_mssql_identifier("odd]name").unwrap(), "[odd]]name]");
+        assert!(quote_mssql_identifier("").is_err());
+    }
+
+    #[test]
+    fn test_cell_text_and_types() {
+        assert_eq!(
+            cell_text(&ColumnData::Bit(Some(true))).as_deref(),
+            Some("1")
+        );
+        assert_eq!(cell_text(&ColumnData::I32(None)), None);
+        assert_eq!(
+            cell_text(&ColumnData::Binary(Some(Cow::Owned(vec![0xde, 0x01])))).as_deref(),
+            Some("0xDE01")
+        );
+        assert_eq!(
+            parse_mssql_type("nvarchar", Some(100)),
+            DataType::Varchar(Some(50))
+        );
+        assert_eq!(
+            parse_mssql_type("varchar", Some(-1)),
+            DataType::Varchar(None)
+        );
+        assert_eq!(parse_mssql_type("datetime2", None), DataType::Timestamp);
+    }
+
+    #[test]
+    fn test_server_error_code() {
+        let error = "Token error: 'Invalid object name 'x'.' on server db executing  \
+                     on line 1 (code: 208, state: 1, class: 16)";
+        assert_eq!(server_error_code(error), Some(208));
+        assert_eq!(server_error_code("Timed out"), None);
+    }
+}
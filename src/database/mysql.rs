@@ -4,31 +4,60 @@
 
 use crate::core::error::{LazyTablesError, Result};
 use crate::database::{
-    connection::ConnectionConfig, Connection, DataType, TableColumn, TableMetadata,
+    connection::{connect_error, init_sql_error, ConnectionConfig, SslMode},
+    connection_manager::{BackendGuard, QueryCanceller, RunningBackend},
+    Connection, DataType, TableColumn, TableMetadata,
 };
 use async_trait::async_trait;
-use sqlx::mysql::{MySqlPool, MySqlPoolOptions};
+use sqlx::mysql::{MySqlConnectOptions, MySqlPool, MySqlPoolOptions, MySqlSslMode};
 use sqlx::{Column, Row};
 
+/// Tabs `Ctrl+M` opens: title, `SHOW` statement and the counters highlighted
+pub const SERVER_STATUS_VIEWS: [(&str, &str, &[&str]); 2] = [
+    (
+        "Status",
+        "SHOW GLOBAL STATUS",
+        &["Threads_connected", "Questions", "Innodb_buffer_pool_reads"],
+    ),
+    ("Variables", "SHOW GLOBAL VARIABLES", &[]),
+];
+
 /// MySQL database connection implementation
 #[derive(Debug)]
 pub struct MySqlConnection {
     config: ConnectionConfig,
     pool: Option<MySqlPool>,
+    running_backend: RunningBackend,
 }
 
 impl MySqlConnection {
     /// Create a new MySQL connection instance
     pub fn new(config: ConnectionConfig) -> Self {
-        Self { config, pool: None }
+        Self {
+            config,
+            pool: None,
+            running_backend: RunningBackend::default(),
+        }
+    }
+
+    /// Cancel handle that stays usable while a query holds this connection
+    pub fn query_canceller(&self) -> QueryCanceller {
+        match &self.pool {
+            Some(pool) => QueryCanceller::MySql {
+                pool: pool.clone(),
+                backend: self.running_backend.clone(),
+            },
+            None => QueryCanceller::Abort,
+        }
     }
 
     /// Build MySQL connection string
     fn build_connection_string(&self, encryption_key: Option<&str>) -> Result<String> {
-        let host = &self.config.host;
-        let port = self.config.port;
-        let database = self.config.database.as_deref().unwrap_or("mysql");
-        let username = &self.config.username;
+        let config = self.config.with_env_expanded()?;
+        let host = &config.host;
+        let port = config.port;
+        let database = config.database.as_deref().unwrap_or("mysql");
+        let username = &config.username;
 
         // Try to resolve password from various sources
         let password = self
@@ -45,6 +74,36 @@ impl MySqlConnection {
         }
     }
 
+    /// Connect options for the connection string with the SSL mode and
+    /// certificate files applied
+    fn connect_options(&self, encryption_key: Option<&str>) -> Result<MySqlConnectOptions> {
+        let options: MySqlConnectOptions = self
+            .build_connection_string(encryption_key)?
+            .parse()
+            .map_err(|e| LazyTablesError::Connection(connect_error("MySQL", e)))?;
+        let [ca_cert, client_cert, client_key] = self.config.certificate_paths();
+        let ssl_mode = match self.config.ssl_mode {
+            SslMode::Disable => MySqlSslMode::Disabled,
+            SslMode::Allow | SslMode::Prefer => MySqlSslMode::Preferred,
+            SslMode::Require => MySqlSslMode::Required,
+            SslMode::VerifyCA => MySqlSslMode::VerifyCa,
+            SslMode::VerifyFull => MySqlSslMode::VerifyIdentity,
+            SslMode::Custom if ca_cert.is_some() => MySqlSslMode::VerifyCa,
+            SslMode::Custom => MySqlSslMode::Required,
+        };
+        let mut options = options.ssl_mode(ssl_mode);
+        if let Some(path) = ca_cert {
+            options = options.ssl_ca(path);
+        }
+        if let Some(path) = client_cert {
+            options = options.ssl_client_cert(path);
+        }
+        if let Some(path) = client_key {
+            options = options.ssl_client_key(path);
+        }
+        Ok(options)
+    }
+
     /// Parse SQLx error into structured ConnectionError with helpful suggestions
     pub fn parse_connection_error(
         &self,
@@ -161,13 +220,28 @@ impl Connection for MySqlConnection {
     }
 
     async fn connect_with_key(&mut self, encryption_key: Option<&str>) -> Result<()> {
-        let connection_string = self.build_connection_string(encryption_key)?;
+        let options = self.connect_options(encryption_key)?;
+        let session_sql = std::sync::Arc::new(self.config.session_sql());
 
         let pool = MySqlPoolOptions::new()
             .max_connections(5)
-            .connect(&connection_string)
+            .after_connect(move |conn, _| {
+                // Every pooled session gets the default schema and init SQL
+                let session_sql = std::sync::Arc::clone(&session_sql);
+                Box::pin(async move {
+                    for statement in session_sql.iter() {
+                        sqlx::Executor::execute(&mut *conn, statement.as_str())
+                            .await
+                            .map_err(|e| {
+                                sqlx::Error::Configuration(init_sql_error(statement, e).into())
+                            })?;
+                    }
+                    Ok(())
+                })
+            })
+            .connect_with(options)
             .await
-            .map_err(|e| LazyTablesError::Connection(format!("Failed to connect to MySQL: {e}")))?;
+            .map_err(|e| LazyTablesError::Connection(connect_error("MySQL", e)))?;
 
         self.pool = Some(pool);
         Ok(())
@@ -189,10 +263,19 @@ impl Connection for MySqlConnection {
     }
 
     // Query execution capabilities (AC1 requirement)
-    async fn execute_raw_query(&self, query: &str) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
         MySqlConnection::execute_raw_query(self, query).await
     }
 
+    async fn cancel_running_query(&self) -> Result<bool> {
+        MySqlConnection::query_canceller(self)
+            .cancel_running_query()
+            .await
+    }
+
     // Metadata operations (AC1 & AC2 requirements)
     async fn list_tables(&self) -> Result<Vec<String>> {
         MySqlConnection::list_tables(self).await
@@ -218,10 +301,17 @@ impl Connection for MySqlConnection {
         table_name: &str,
         limit: usize,
         offset: usize,
-    ) -> Result<Vec<Vec<String>>> {
+    ) -> Result<Vec<Vec<Option<String>>>> {
         MySqlConnection::get_table_data(self, table_name, limit, offset).await
     }
 
+    async fn get_table_statistics(
+        &self,
+        table_name: &str,
+    ) -> Result<crate::database::TableStatistics> {
+        MySqlConnection::get_table_statistics(self, table_name).await
+    }
+
     // Database-specific capabilities (AC1 & AC2 requirement)
     async fn get_database_capabilities(&self) -> Result<crate::database::DatabaseCapabilities> {
         Ok(crate::database::DatabaseCapabilities {
@@ -532,6 +622,15 @@ impl MySqlConnection {
 
     /// List all database objects (tables, views) with comprehensive metadata
     pub async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        self.list_database_objects_page(None, 0).await
+    }
+
+    /// List database objects one page at a time; `None` lists everything
+    pub async fn list_database_objects_page(
+        &self,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
         use crate::database::{DatabaseObject, DatabaseObjectList, DatabaseObjectType};
 
         if let Some(pool) = &self.pool {
@@ -551,7 +650,12 @@ impl MySqlConnection {
                 ORDER BY t.table_type, t.table_name
             ";
 
-            match sqlx::query(query).fetch_all(pool).await {
+            let query = format!(
+                "{query} {}",
+                crate::database::objects::page_clause(limit, offset)
+            );
+
+            match sqlx::query(&query).fetch_all(pool).await {
                 Ok(rows) => {
                     for row in rows {
                         let name: String = row.get("table_name");
@@ -617,6 +721,8 @@ impl MySqlConnection {
     /// Get metadata for a specific table
     pub async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
         if let Some(pool) = &self.pool {
+            let columns = self.get_table_columns(table_name).await.unwrap_or_default();
+
             // Validate and escape table name
             let safe_name = validate_mysql_identifier(table_name)?;
 
@@ -705,8 +811,8 @@ impl MySqlConnection {
                 .map(|row| row.get::<String, _>(0))
                 .collect();
 
-            // Get table comment
-            let comment_query = "SELECT table_comment
+            // Get table comment and type
+            let comment_query = "SELECT table_comment, table_type
                                 FROM information_schema.tables
                                 WHERE table_schema = DATABASE()
                                 AND table_name = ?";
@@ -716,13 +822,26 @@ impl MySqlConnection {
                 .fetch_one(pool)
                 .await?;
 
+            let is_view = comment_row.get::<String, _>(1) == "VIEW";
+            // Views report the literal comment "VIEW"
             let comment: String = comment_row.get(0);
-            let comment = if comment.is_empty() {
+            let comment = if comment.is_empty() || is_view {
                 None
             } else {
                 Some(comment)
             };
 
+            let (view_type, view_definition) = if is_view {
+                let definition = sqlx::query(&format!("SHOW CREATE VIEW {}", safe_name))
+                    .fetch_one(pool)
+                    .await
+                    .ok()
+                    .map(|row| row.get::<String, _>(1));
+                (Some("VIEW"), definition)
+            } else {
+                (None, None)
+            };
+
             Ok(TableMetadata::basic(
                 table_name.to_string(),
                 row_count as usize,
@@ -734,7 +853,9 @@ impl MySqlConnection {
                 foreign_keys,
                 indexes,
                 comment,
-            ))
+            )
+            .with_columns(&columns)
+            .with_view_type(view_type, view_definition))
         } else {
             Err(LazyTablesError::Connection(
                 "Not connected to database".to_string(),
@@ -807,7 +928,7 @@ impl MySqlConnection {
         table_name: &str,
         limit: usize,
         offset: usize,
-    ) -> Result<Vec<Vec<String>>> {
+    ) -> Result<Vec<Vec<Option<String>>>> {
         if let Some(pool) = &self.pool {
             // Get column names first to maintain order using parameterized query
             let columns_query = "SELECT column_name
@@ -850,9 +971,7 @@ impl MySqlConnection {
             for row in rows {
                 let mut row_data = Vec::new();
                 for (idx, _col_name) in column_names.iter().enumerate() {
-                    // Try to get the value as string, handle NULL values
-                    let value: Option<String> = row.try_get(idx).ok();
-                    row_data.push(value.unwrap_or_else(|| "NULL".to_string()));
+                    row_data.push(cell_text(&row, idx));
                 }
                 result.push(row_data);
             }
@@ -943,10 +1062,18 @@ impl MySqlConnection {
     }
 
     /// Execute a raw SQL query and return columns and rows
-    pub async fn execute_raw_query(&self, query: &str) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    pub async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
         if let Some(pool) = &self.pool {
-            // Try to execute the query
-            let rows = sqlx::query(query).fetch_all(pool).await?;
+            // Run on a dedicated connection whose connection id is recorded for cancellation
+            let mut conn = pool.acquire().await?;
+            let pid: u64 = sqlx::query_scalar("SELECT CONNECTION_ID()")
+                .fetch_one(&mut *conn)
+                .await?;
+            let _running = BackendGuard::record(&self.running_backend, pid);
+            let rows = sqlx::query(query).fetch_all(&mut *conn).await?;
 
             if rows.is_empty() {
                 return Ok((Vec::new(), Vec::new()));
@@ -964,9 +1091,7 @@ impl MySqlConnection {
             for row in &rows {
                 let mut row_data = Vec::new();
                 for col in columns {
-                    // Try to get value as string
-                    let value: Option<String> = row.try_get(col.ordinal()).ok();
-                    row_data.push(value.unwrap_or_else(|| "NULL".to_string()));
+                    row_data.push(cell_text(row, col.ordinal()));
                 }
                 result_rows.push(row_data);
             }
@@ -978,6 +1103,131 @@ impl MySqlConnection {
             ))
         }
     }
+
+    /// Usage counters from the `sys` schema views (`schema_table_statistics`,
+    /// `schema_index_statistics`). Without `sys` only row estimates and the
+    /// index list from `information_schema` are shown.
+    pub async fn get_table_statistics(
+        &self,
+        table_name: &str,
+    ) -> Result<crate::database::TableStatistics> {
+        let Some(pool) = &self.pool else {
+            return Err(LazyTablesError::Connection(
+                "Not connected to database".to_string(),
+            ));
+        };
+
+        let mut stats = crate::database::TableStatistics {
+            table_name: table_name.to_string(),
+            source: "information_schema",
+            ..Default::default()
+        };
+        stats.live_rows = sqlx::query_scalar::<_, Option<i64>>(
+            "SELECT CAST(table_rows AS SIGNED) FROM information_schema.tables
+             WHERE table_schema = DATABASE() AND table_name = ?",
+        )
+        .bind(table_name)
+        .fetch_optional(pool)
+        .await?
+        .flatten();
+
+        let index_rows = sqlx::query(
+            "SELECT index_name, CAST(MIN(non_unique) AS SIGNED)
+             FROM information_schema.statistics
+             WHERE table_schema = DATABASE() AND table_name = ?
+             GROUP BY index_name
+             ORDER BY index_name",
+        )
+        .bind(table_name)
+        .fetch_all(pool)
+        .await?;
+        stats.indexes = index_rows
+            .iter()
+            .map(|row| crate::database::IndexUsage {
+                name: row.get(0),
+                scans: None,
+                size_bytes: None,
+                is_unique: row.get::<i64, _>(1) == 0,
+            })
+            .collect();
+
+        let has_sys: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM information_schema.schemata WHERE schema_name = 'sys'",
+        )
+        .fetch_one(pool)
+        .await?;
+        if has_sys == 0 {
+            stats.note = Some("sys schema not installed; usage counters unavailable".to_string());
+            return Ok(stats);
+        }
+
+        // The sys views read performance_schema, which may be off or not granted
+        let table_row = sqlx::query(
+            "SELECT CAST(rows_inserted AS SIGNED), CAST(rows_updated AS SIGNED),
+                    CAST(rows_deleted AS SIGNED)
+             FROM sys.schema_table_statistics
+             WHERE table_schema = DATABASE() AND table_name = ?",
+        )
+        .bind(table_name)
+        .fetch_optional(pool)
+        .await;
+        let table_row = match table_row {
+            Ok(row) => row,
+            Err(e) => {
+                stats.note = Some(format!("sys schema not readable: {e}"));
+                return Ok(stats);
+            }
+        };
+        stats.source = "sys.schema_table_statistics";
+        if let Some(row) = table_row {
+            stats.rows_inserted = row.get(0);
+            stats.rows_updated = row.get(1);
+            stats.rows_deleted = row.get(2);
+        }
+
+        stats.seq_rows_read = sqlx::query_scalar::<_, Option<i64>>(
+            "SELECT CAST(rows_full_scanned AS SIGNED)
+             FROM sys.schema_tables_with_full_table_scans
+             WHERE object_schema = DATABASE() AND object_name = ?",
+        )
+        .bind(table_name)
+        .fetch_optional(pool)
+        .await
+        .ok()
+        .flatten()
+        .flatten()
+        .or(Some(0));
+
+        let usage_rows = sqlx::query(
+            "SELECT index_name, CAST(rows_selected AS SIGNED)
+             FROM sys.schema_index_statistics
+             WHERE table_schema = DATABASE() AND table_name = ?",
+        )
+        .bind(table_name)
+        .fetch_all(pool)
+        .await
+        .unwrap_or_default();
+        for row in &usage_rows {
+            let name: String = row.get(0);
+            if let Some(index) = stats.indexes.iter_mut().find(|index| index.name == name) {
+                index.scans = row.get(1);
+            }
+        }
+        Ok(stats)
+    }
+}
+
+/// Cell text for the viewer: `None` for nulls and for values that are not
+/// text, binary values as a `0x...` literal
+fn cell_text(row: &sqlx::mysql::MySqlRow, idx: usize) -> Option<String> {
+    match row.try_get::<Option<String>, _>(idx) {
+        Ok(text) => text,
+        Err(_) => row
+            .try_get::<Option<Vec<u8>>, _>(idx)
+            .ok()
+            .flatten()
+            .map(|bytes| crate::database::hex_literal(&bytes)),
+    }
 }
 
 /// Validate and escape MySQL identifiers to prevent SQL injection
@@ -996,6 +1246,7 @@ fn validate_mysql_identifier(name: &str) -> Result<String> {
     Ok(format!("`{}`", escaped))
 }
 
+/// Parse MySQL data type string to internal DataType enum
 fn parse_mysql_type(type_str: &str) -> DataType {
     let type_lower = type_str.to_lowercase();
 
@@ -1019,7 +1270,85 @@ fn parse_mysql_type(type_str: &str) -> DataType {
     }
 }
 
-/// Parse MySQL data type string to internal DataType enum
+/// Implement ManagedConnection trait for MySqlConnection to work with ConnectionManager
+#[async_trait::async_trait]
+impl crate::database::connection_manager::ManagedConnection for MySqlConnection {
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        MySqlConnection::execute_raw_query(self, query).await
+    }
+
+    async fn execute_statement(&self, statement: &str) -> Result<u64> {
+        let Some(pool) = &self.pool else {
+            return Err(LazyTablesError::Connection(
+                "Not connected to database".to_string(),
+            ));
+        };
+        // Run on a dedicated connection whose connection id is recorded for cancellation
+        let mut conn = pool.acquire().await?;
+        let pid: u64 = sqlx::query_scalar("SELECT CONNECTION_ID()")
+            .fetch_one(&mut *conn)
+            .await?;
+        let _running = BackendGuard::record(&self.running_backend, pid);
+        Ok(sqlx::query(statement)
+            .execute(&mut *conn)
+            .await?
+            .rows_affected())
+    }
+
+    async fn get_table_data(
+        &self,
+        table_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        MySqlConnection::get_table_data(self, table_name, limit, offset).await
+    }
+
+    async fn get_table_statistics(
+        &self,
+        table_name: &str,
+    ) -> Result<crate::database::TableStatistics> {
+        MySqlConnection::get_table_statistics(self, table_name).await
+    }
+
+    async fn get_table_columns(
+        &self,
+        table_name: &str,
+    ) -> Result<Vec<crate::database::TableColumn>> {
+        MySqlConnection::get_table_columns(self, table_name).await
+    }
+
+    async fn get_table_metadata(&self, table_name: &str) -> Result<crate::database::TableMetadata> {
+        MySqlConnection::get_table_metadata(self, table_name).await
+    }
+
+    async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        MySqlConnection::list_database_objects(self).await
+    }
+
+    async fn list_database_objects_page(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        MySqlConnection::list_database_objects_page(self, Some(limit), offset).await
+    }
+
+    fn is_connected(&self) -> bool {
+        Connection::is_connected(self)
+    }
+
+    fn query_canceller(&self) -> QueryCanceller {
+        MySqlConnection::query_canceller(self)
+    }
+}
+
+// Drop implementation removed - connection pools are closed explicitly via disconnect() method
+// to avoid spawning background tasks that may not complete before app shutdown
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1192,42 +1521,3 @@ mod tests {
         assert!(functions.contains(&"JSON_EXTRACT".to_string()));
     }
 }
-
-/// Implement ManagedConnection trait for MySqlConnection to work with ConnectionManager
-#[async_trait::async_trait]
-impl crate::database::connection_manager::ManagedConnection for MySqlConnection {
-    async fn execute_raw_query(&self, query: &str) -> Result<(Vec<String>, Vec<Vec<String>>)> {
-        MySqlConnection::execute_raw_query(self, query).await
-    }
-
-    async fn get_table_data(
-        &self,
-        table_name: &str,
-        limit: usize,
-        offset: usize,
-    ) -> Result<Vec<Vec<String>>> {
-        MySqlConnection::get_table_data(self, table_name, limit, offset).await
-    }
-
-    async fn get_table_columns(
-        &self,
-        table_name: &str,
-    ) -> Result<Vec<crate::database::TableColumn>> {
-        MySqlConnection::get_table_columns(self, table_name).await
-    }
-
-    async fn get_table_metadata(&self, table_name: &str) -> Result<crate::database::TableMetadata> {
-        MySqlConnection::get_table_metadata(self, table_name).await
-    }
-
-    async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
-        MySqlConnection::list_database_objects(self).await
-    }
-
-    fn is_connected(&self) -> bool {
-        Connection::is_connected(self)
-    }
-}
-
-// Drop implementation removed - connection pools are closed explicitly via disconnect() method
-// to avoid spawning background tasks that may not complete before app shutdown
@@ -26,6 +26,11 @@ impl DatabaseObjectType {
         }
     }
 
+    /// Whether rows of this object can't be edited in place
+    pub fn is_read_only(&self) -> bool {
+        matches!(self, Self::View | Self::MaterializedView)
+    }
+
     /// Get display name for this type
     pub fn display_name(&self) -> &str {
         match self {
@@ -36,6 +41,17 @@ impl DatabaseObjectType {
             Self::SystemTable => "System Table",
         }
     }
+
+    /// Tables pane group header for objects of this type
+    pub fn group_name(&self) -> &'static str {
+        match self {
+            Self::Table => "Tables",
+            Self::View => "Views",
+            Self::MaterializedView => "Materialized Views",
+            Self::ForeignTable => "Foreign Tables",
+            Self::SystemTable => "System Tables",
+        }
+    }
 }
 
 /// Represents a database object (table, view, etc.)
@@ -82,6 +98,19 @@ pub struct DatabaseObjectList {
 }
 
 impl DatabaseObjectList {
+    /// Objects grouped as the Tables pane lists them
+    pub fn groups(&self) -> [(DatabaseObjectType, &[DatabaseObject]); 4] {
+        [
+            (DatabaseObjectType::Table, &self.tables),
+            (DatabaseObjectType::View, &self.views),
+            (
+                DatabaseObjectType::MaterializedView,
+                &self.materialized_views,
+            ),
+            (DatabaseObjectType::ForeignTable, &self.foreign_tables),
+        ]
+    }
+
     /// Get all objects as a flat list
     pub fn all_objects(&self) -> Vec<&DatabaseObject> {
         self.tables
@@ -101,6 +130,14 @@ impl DatabaseObjectList {
             .collect()
     }
 
+    /// Object type of the object with this plain or qualified name
+    pub fn object_type_of(&self, name: &str) -> Option<DatabaseObjectType> {
+        self.all_objects()
+            .into_iter()
+            .find(|obj| obj.name == name || obj.qualified_name() == name)
+            .map(|obj| obj.object_type.clone())
+    }
+
     /// Check if the list is empty
     pub fn is_empty(&self) -> bool {
         self.tables.is_empty()
@@ -109,3 +146,82 @@ impl DatabaseObjectList {
             && self.foreign_tables.is_empty()
     }
 }
+
+/// Number of objects fetched per page when streaming the object list
+pub const OBJECT_LIST_BATCH_SIZE: usize = 500;
+
+/// `LIMIT`/`OFFSET` suffix for paged object listings; empty when unpaged
+pub fn page_clause(limit: Option<usize>, offset: usize) -> String {
+    match limit {
+        Some(limit) => format!("LIMIT {limit} OFFSET {offset}"),
+        None => String::new(),
+    }
+}
+
+impl DatabaseObjectList {
+    /// Append a streamed batch, keeping the first error seen
+    pub fn append(&mut self, batch: DatabaseObjectList) {
+        self.total_count += batch.total_count;
+        self.tables.extend(batch.tables);
+        self.views.extend(batch.views);
+        self.materialized_views.extend(batch.materialized_views);
+        self.foreign_tables.extend(batch.foreign_tables);
+        if self.error.is_none() {
+            self.error = batch.error;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(name: &str) -> DatabaseObject {
+        DatabaseObject {
+            name: name.to_string(),
+            schema: Some("public".to_string()),
+            object_type: DatabaseObjectType::Table,
+            row_count: None,
+            size_bytes: None,
+            comment: None,
+        }
+    }
+
+    #[test]
+    fn test_append_batches() {
+        let mut list = DatabaseObjectList::default();
+        for names in [["a", "b"], ["c", "d"]] {
+            list.append(DatabaseObjectList {
+                tables: names.iter().map(|n| table(n)).collect(),
+                total_count: names.len(),
+                ..Default::default()
+            });
+        }
+        assert_eq!(list.total_count, 4);
+        assert_eq!(list.tables.last().map(|t| t.name.as_str()), Some("d"));
+        assert_eq!(page_clause(Some(500), 1000), "LIMIT 500 OFFSET 1000");
+        assert_eq!(page_clause(None, 0), "");
+    }
+
+    #[test]
+    fn test_object_type_of() {
+        let mut view = table("active_users");
+        view.object_type = DatabaseObjectType::View;
+        let list = DatabaseObjectList {
+            tables: vec![table("users")],
+            views: vec![view],
+            ..Default::default()
+        };
+        assert_eq!(
+            list.object_type_of("users"),
+            Some(DatabaseObjectType::Table)
+        );
+        assert_eq!(
+            list.object_type_of("active_users"),
+            Some(DatabaseObjectType::View)
+        );
+        assert_eq!(list.object_type_of("missing"), None);
+        assert!(DatabaseObjectType::View.is_read_only());
+        assert!(!DatabaseObjectType::Table.is_read_only());
+    }
+}
@@ -0,0 +1,866 @@
+// FilePath: src/database/oracle.rs
+
+#![forbid(unsafe_code)]
+
+use crate::core::error::{LazyTablesError, Result};
+use crate::database::{
+    connection::{init_sql_error, ConnectionConfig},
+    connection_manager::QueryCanceller,
+    Connection, DataType, TableColumn, TableMetadata,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Oracle database connection implementation, on top of the ODPI-C based
+/// `oracle` crate. Its calls block, so each one runs on the blocking pool.
+pub struct OracleConnection {
+    config: ConnectionConfig,
+    conn: Option<Arc<::oracle::Connection>>,
+}
+
+impl std::fmt::Debug for OracleConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OracleConnection")
+            .field("config", &self.config)
+            .field("connected", &self.conn.is_some())
+            .finish()
+    }
+}
+
+/// Interrupts the statement running on an Oracle session (`OCIBreak`)
+#[derive(Clone)]
+pub struct OracleBreak(Arc<::oracle::Connection>);
+
+impl std::fmt::Debug for OracleBreak {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("OracleBreak")
+    }
+}
+
+impl OracleBreak {
+    pub async fn cancel(&self) -> Result<bool> {
+        let conn = self.0.clone();
+        tokio::task::spawn_blocking(move || conn.break_execution())
+            .await
+            .map_err(|e| LazyTablesError::Other(format!("Oracle task failed: {e}")))?
+            .map_err(|e| LazyTablesError::Other(e.to_string()))?;
+        Ok(true)
+    }
+}
+
+impl OracleConnection {
+    /// Create a new Oracle connection instance
+    pub fn new(config: ConnectionConfig) -> Self {
+        Self { config, conn: None }
+    }
+
+    /// Cancel handle that stays usable while a query holds this connection
+    pub fn query_canceller(&self) -> QueryCanceller {
+        match &self.conn {
+            Some(conn) => QueryCanceller::Oracle(OracleBreak(conn.clone())),
+            None => QueryCanceller::Abort,
+        }
+    }
+
+    /// Run `f` against the session on the blocking thread pool
+    async fn blocking<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&::oracle::Connection) -> ::oracle::Result<T> + Send + 'static,
+    {
+        let conn = self
+            .conn
+            .clone()
+            .ok_or_else(|| LazyTablesError::Connection("Not connected to database".to_string()))?;
+        tokio::task::spawn_blocking(move || f(&conn))
+            .await
+            .map_err(|e| LazyTablesError::Other(format!("Oracle task failed: {e}")))?
+            .map_err(|e| LazyTablesError::Other(e.to_string()))
+    }
+
+    /// Run a query and return column names and rows as text
+    pub async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        let sql = trim_statement(query).to_string();
+        self.blocking(move |conn| {
+            let mut stmt = conn.statement(&sql).build()?;
+            if !stmt.is_query() {
+                stmt.execute(&[])?;
+                return Ok((Vec::new(), Vec::new()));
+            }
+
+            let rows = stmt.query(&[])?;
+            let columns: Vec<String> = rows
+                .column_info()
+                .iter()
+                .map(|column| column.name().to_string())
+                .collect();
+            let mut data = Vec::new();
+            for row in rows {
+                let row = row?;
+                data.push(row.sql_values().iter().map(cell_text).collect());
+            }
+            Ok((columns, data))
+        })
+        .await
+    }
+
+    /// Run a statement that returns no rows, reporting the rows it affected
+    pub async fn execute_statement(&self, statement: &str) -> Result<u64> {
+        let sql = trim_statement(statement).to_string();
+        self.blocking(move |conn| {
+            let stmt = conn.execute(&sql, &[])?;
+            stmt.row_count()
+        })
+        .await
+    }
+
+    /// List the tables owned by the connected user
+    pub async fn list_tables(&self) -> Result<Vec<String>> {
+        self.blocking(|conn| {
+            conn.query_as::<String>(
+                "SELECT table_name FROM user_tables ORDER BY table_name",
+                &[],
+            )?
+            .collect()
+        })
+        .await
+    }
+
+    /// List database objects one page at a time; `None` lists everything
+    pub async fn list_database_objects_page(
+        &self,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        use crate::database::{DatabaseObject, DatabaseObjectList, DatabaseObjectType};
+
+        let page = match limit {
+            Some(limit) => format!("OFFSET {offset} ROWS FETCH NEXT {limit} ROWS ONLY"),
+            None => String::new(),
+        };
+        let query = format!(
+            "SELECT name, kind, num_rows, comments FROM (
+                 SELECT t.table_name AS name, 'TABLE' AS kind, t.num_rows, c.comments
+                 FROM user_tables t
+                 LEFT JOIN user_tab_comments c ON c.table_name = t.table_name
+                 WHERE t.table_name NOT IN (SELECT mview_name FROM user_mviews)
+                 UNION ALL
+                 SELECT view_name, 'VIEW', NULL, NULL FROM user_views
+                 UNION ALL
+                 SELECT mview_name, 'MATERIALIZED VIEW', NULL, NULL FROM user_mviews
+             )
+             ORDER BY kind, name {page}"
+        );
+
+        let rows = self
+            .blocking(move |conn| {
+                conn.query_as::<(String, String, Option<i64>, Option<String>)>(&query, &[])?
+                    .collect::<::oracle::Result<Vec<_>>>()
+            })
+            .await?;
+
+        let mut result = DatabaseObjectList::default();
+        for (name, kind, row_count, comment) in rows {
+            let object_type = match kind.as_str() {
+                "VIEW" => DatabaseObjectType::View,
+                "MATERIALIZED VIEW" => DatabaseObjectType::MaterializedView,
+                _ => DatabaseObjectType::Table,
+            };
+            let object = DatabaseObject {
+                name,
+                schema: None,
+                object_type: object_type.clone(),
+                row_count,
+                size_bytes: None,
+                comment,
+            };
+            match object_type {
+                DatabaseObjectType::View => result.views.push(object),
+                DatabaseObjectType::MaterializedView => result.materialized_views.push(object),
+                _ => result.tables.push(object),
+            }
+            result.total_count += 1;
+        }
+        Ok(result)
+    }
+
+    /// Column information from `ALL_TAB_COLUMNS`, with primary keys from `ALL_CONSTRAINTS`
+    pub async fn get_table_columns(&self, table_name: &str) -> Result<Vec<TableColumn>> {
+        let table = table_name.to_string();
+        let rows = self
+            .blocking(move |conn| {
+                conn.query_as::<(String, String, Option<u32>, String, Option<String>, i64)>(
+                    "SELECT c.column_name, c.data_type, c.char_length, c.nullable,
+                            c.data_default,
+                            CASE WHEN EXISTS (
+                                SELECT 1 FROM all_constraints k
+                                JOIN all_cons_columns kc
+                                  ON kc.owner = k.owner
+                                 AND kc.constraint_name = k.constraint_name
+                                WHERE k.constraint_type = 'P'
+                                  AND k.owner = c.owner
+                                  AND k.table_name = c.table_name
+                                  AND kc.column_name = c.column_name
+                            ) THEN 1 ELSE 0 END
+                     FROM all_tab_columns c
+                     WHERE c.owner = USER AND c.table_name = :1
+                     ORDER BY c.column_id",
+                    &[&table],
+                )?
+                .collect::<::oracle::Result<Vec<_>>>()
+            })
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(name, data_type, length, nullable, default_value, is_pk)| TableColumn {
+                    name,
+                    data_type: parse_oracle_type(&data_type, length),
+                    is_nullable: nullable == "Y",
+                    default_value: default_value.map(|value| value.trim().to_string()),
+                    is_primary_key: is_pk == 1,
+                },
+            )
+            .collect())
+    }
+
+    /// Table details from the data dictionary (`ALL_CONSTRAINTS`, `ALL_INDEXES`, ...)
+    pub async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
+        let columns = self.get_table_columns(table_name).await.unwrap_or_default();
+        let quoted = quote_oracle_identifier(table_name)?;
+        let table = table_name.to_string();
+
+        let details = self
+            .blocking(move |conn| {
+                let view_definition = conn
+                    .query_row_as::<Option<String>>(
+                        "SELECT text FROM user_views WHERE view_name = :1",
+                        &[&table],
+                    )
+                    .ok()
+                    .flatten();
+                let row_count = if view_definition.is_some() {
+                    0
+                } else {
+                    conn.query_row_as::<i64>(&format!("SELECT COUNT(*) FROM {quoted}"), &[])?
+                };
+
+                let primary_keys = conn
+                    .query_as::<String>(
+                        "SELECT kc.column_name
+                         FROM all_constraints k
+                         JOIN all_cons_columns kc
+                           ON kc.owner = k.owner AND kc.constraint_name = k.constraint_name
+                         WHERE k.constraint_type = 'P' AND k.owner = USER AND k.table_name = :1
+                         ORDER BY kc.position",
+                        &[&table],
+                    )?
+                    .collect::<::oracle::Result<Vec<_>>>()?;
+
+                let foreign_keys = conn
+                    .query_as::<(String, String, String)>(
+                        "SELECT kc.column_name, r.table_name, rc.column_name
+                         FROM all_constraints k
+                         JOIN all_cons_columns kc
+                           ON kc.owner = k.owner AND kc.constraint_name = k.constraint_name
+                         JOIN all_constraints r
+                           ON r.owner = k.r_owner AND r.constraint_name = k.r_constraint_name
+                         JOIN all_cons_columns rc
+                           ON rc.owner = r.owner AND rc.constraint_name = r.constraint_name
+                          AND rc.position = kc.position
+                         WHERE k.constraint_type = 'R' AND k.owner = USER AND k.table_name = :1
+                         ORDER BY k.constraint_name, kc.position",
+                        &[&table],
+                    )?
+                    .map(|row| row.map(|(from, table, to)| format!("{from} → {table}.{to}")))
+                    .collect::<::oracle::Result<Vec<_>>>()?;
+
+                let indexes = conn
+                    .query_as::<String>(
+                        "SELECT index_name FROM all_indexes
+                         WHERE table_owner = USER AND table_name = :1
+                         ORDER BY index_name",
+                        &[&table],
+                    )?
+                    .collect::<::oracle::Result<Vec<_>>>()?;
+
+                let table_size = conn
+                    .query_row_as::<i64>(
+                        "SELECT NVL(SUM(bytes), 0) FROM user_segments WHERE segment_name = :1",
+                        &[&table],
+                    )
+                    .unwrap_or(0);
+                let comment = conn
+                    .query_row_as::<Option<String>>(
+                        "SELECT comments FROM all_tab_comments
+                         WHERE owner = USER AND table_name = :1",
+                        &[&table],
+                    )
+                    .ok()
+                    .flatten();
+
+                Ok((
+                    row_count,
+                    primary_keys,
+                    foreign_keys,
+                    indexes,
+                    table_size,
+                    comment,
+                    view_definition,
+                ))
+            })
+            .await?;
+        let (row_count, primary_keys, foreign_keys, indexes, table_size, comment, view_definition) =
+            details;
+
+        let view_type = view_definition.as_ref().map(|_| "VIEW");
+        Ok(TableMetadata::basic(
+            table_name.to_string(),
+            row_count as usize,
+            columns.len(),
+            table_size,
+            table_size,
+            0,
+            primary_keys,
+            foreign_keys,
+            indexes,
+            comment,
+        )
+        .with_columns(&columns)
+        .with_view_type(view_type, view_definition))
+    }
+
+    /// Get table data with pagination (Oracle 12c+ row limiting)
+    pub async fn get_table_data(
+        &self,
+        table_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        let quoted = quote_oracle_identifier(table_name)?;
+        let (_, rows) = self
+            .execute_raw_query(&format!(
+                "SELECT * FROM {quoted} OFFSET {offset} ROWS FETCH NEXT {limit} ROWS ONLY"
+            ))
+            .await?;
+        Ok(rows)
+    }
+}
+
+#[async_trait]
+impl Connection for OracleConnection {
+    async fn connect(&mut self) -> Result<()> {
+        self.connect_with_key(None).await
+    }
+
+    async fn connect_with_key(&mut self, encryption_key: Option<&str>) -> Result<()> {
+        let config = self.config.with_env_expanded()?;
+        let connect_string = connect_string(&config);
+        let username = config.username.clone();
+        let password = self
+            .config
+            .resolve_password(encryption_key)
+            .unwrap_or_default();
+
+        let conn = tokio::task::spawn_blocking(move || {
+            let mut conn = ::oracle::Connection::connect(&username, &password, &connect_string)?;
+            // Match the other adapters: each statement commits on its own
+            conn.set_autocommit(true);
+            Ok::<_, ::oracle::Error>(conn)
+        })
+        .await
+        .map_err(|e| LazyTablesError::Connection(format!("Oracle task failed: {e}")))?
+        .map_err(|e| LazyTablesError::Connection(format!("Failed to connect to Oracle: {e}")))?;
+
+        let session_sql = self.config.session_sql();
+        let conn = tokio::task::spawn_blocking(move || {
+            for statement in &session_sql {
+                conn.execute(statement, &[])
+                    .map_err(|e| init_sql_error(statement, e))?;
+            }
+            Ok::<_, String>(conn)
+        })
+        .await
+        .map_err(|e| LazyTablesError::Connection(format!("Oracle task failed: {e}")))?
+        .map_err(LazyTablesError::Connection)?;
+
+        self.conn = Some(Arc::new(conn));
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> Result<()> {
+        if let Some(conn) = self.conn.take() {
+            // Other clones (a cancel handle) keep the session until they drop
+            if let Ok(conn) = Arc::try_unwrap(conn) {
+                let _ = tokio::task::spawn_blocking(move || conn.close()).await;
+            }
+        }
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.conn.is_some()
+    }
+
+    fn config(&self) -> &ConnectionConfig {
+        &self.config
+    }
+
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        OracleConnection::execute_raw_query(self, query).await
+    }
+
+    async fn cancel_running_query(&self) -> Result<bool> {
+        match &self.conn {
+            Some(conn) => OracleBreak(conn.clone()).cancel().await,
+            None => Ok(false),
+        }
+    }
+
+    async fn list_tables(&self) -> Result<Vec<String>> {
+        OracleConnection::list_tables(self).await
+    }
+
+    async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        self.list_database_objects_page(None, 0).await
+    }
+
+    async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
+        OracleConnection::get_table_metadata(self, table_name).await
+    }
+
+    async fn get_table_columns(&self, table_name: &str) -> Result<Vec<TableColumn>> {
+        OracleConnection::get_table_columns(self, table_name).await
+    }
+
+    async fn get_table_data(
+        &self,
+        table_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        OracleConnection::get_table_data(self, table_name, limit, offset).await
+    }
+
+    async fn get_database_capabilities(&self) -> Result<crate::database::DatabaseCapabilities> {
+        Ok(crate::database::DatabaseCapabilities {
+            supports_schemas: true,
+            supports_transactions: true,
+            supports_foreign_keys: true,
+            supports_json: true, // 12c+
+            supports_arrays: false,
+            supports_stored_procedures: true,
+            supports_triggers: true,
+            supports_views: true,
+            supports_materialized_views: true,
+            supports_window_functions: true,
+            supports_cte: true,
+            max_identifier_length: 128, // 12.2+, 30 before
+            max_query_length: None,
+            supported_isolation_levels: vec![
+                "READ COMMITTED".to_string(),
+                "SERIALIZABLE".to_string(),
+            ],
+        })
+    }
+
+    async fn health_check(&self) -> Result<crate::database::HealthStatus> {
+        let start = std::time::Instant::now();
+        let ping = self.blocking(|conn| conn.ping()).await;
+        Ok(crate::database::HealthStatus {
+            is_healthy: ping.is_ok(),
+            response_time_ms: start.elapsed().as_millis() as u64,
+            last_error: ping.err().map(|e| e.to_string()),
+            database_version: None,
+            active_connections: u32::from(self.conn.is_some()),
+            max_connections: 1,
+            uptime_seconds: None,
+        })
+    }
+
+    async fn get_server_info(&self) -> Result<crate::database::ServerInfo> {
+        let (version, banner, user) = self
+            .blocking(|conn| {
+                let (version, banner) = conn.server_version()?;
+                let user = conn.query_row_as::<String>("SELECT USER FROM dual", &[])?;
+                Ok((version.to_string(), banner, user))
+            })
+            .await?;
+
+        Ok(crate::database::ServerInfo {
+            version,
+            build_info: Some(banner),
+            server_name: Some("Oracle Database".to_string()),
+            charset: None,
+            timezone: None,
+            uptime_seconds: None,
+            current_database: self
+                .config
+                .service_name
+                .clone()
+                .or_else(|| self.config.database.clone()),
+            current_user: Some(user),
+        })
+    }
+
+    fn get_pool_status(&self) -> Option<crate::database::PoolStatus> {
+        self.conn.as_ref().map(|_| crate::database::PoolStatus {
+            size: 1,
+            active: 1,
+            idle: 0,
+            waiting: 0,
+            max_size: 1,
+            min_size: 1,
+        })
+    }
+
+    fn max_connections(&self) -> u32 {
+        1 // One dedicated session
+    }
+
+    fn active_connections(&self) -> u32 {
+        u32::from(self.conn.is_some())
+    }
+
+    fn format_error(&self, error: &str) -> crate::database::FormattedError {
+        let error_code = error
+            .find("ORA-")
+            .map(|start| error[start..].chars().take(9).collect::<String>());
+        let mut recovery_suggestions = Vec::new();
+        let mut is_connection_error = false;
+        let mut is_syntax_error = false;
+        let mut is_permission_error = false;
+
+        let user_message = match error_code.as_deref() {
+            Some("ORA-00942") => {
+                recovery_suggestions.push("Check the table name and its owner".to_string());
+                recovery_suggestions.push("Unquoted names are stored in upper case".to_string());
+                "Table or view does not exist."
+            }
+            Some("ORA-00904") => {
+                recovery_suggestions.push("Check the column name spelling".to_string());
+                "Invalid identifier."
+            }
+            Some("ORA-00900" | "ORA-00933" | "ORA-00936" | "ORA-00911") => {
+                is_syntax_error = true;
+                recovery_suggestions.push("Check SQL syntax for typos".to_string());
+                recovery_suggestions
+                    .push("Oracle doesn't accept a trailing ; outside PL/SQL blocks".to_string());
+                "SQL syntax error."
+            }
+            Some("ORA-01017") => {
+                is_connection_error = true;
+                recovery_suggestions.push("Verify the username and password".to_string());
+                "Invalid username or password."
+            }
+            Some("ORA-12514" | "ORA-12505") => {
+                is_connection_error = true;
+                recovery_suggestions.push("Check the service name or SID".to_string());
+                recovery_suggestions.push("List services with: lsnrctl services".to_string());
+                "The listener doesn't know the requested service."
+            }
+            Some("ORA-12541" | "ORA-12170") => {
+                is_connection_error = true;
+                recovery_suggestions.push(format!(
+                    "Check that a listener runs on {}:{}",
+                    self.config.host, self.config.port
+                ));
+                "Cannot reach the Oracle listener."
+            }
+            Some("ORA-01031") => {
+                is_permission_error = true;
+                recovery_suggestions.push("Ask for the needed privilege".to_string());
+                "Insufficient privileges."
+            }
+            _ if error.contains("DPI-1047") => {
+                is_connection_error = true;
+                recovery_suggestions.push(
+                    "Install Oracle Instant Client and add it to the library path".to_string(),
+                );
+                "Oracle client libraries not found."
+            }
+            _ => {
+                recovery_suggestions.push("Check the Oracle error code documentation".to_string());
+                "Oracle database error occurred."
+            }
+        };
+
+        crate::database::FormattedError {
+            original_error: error.to_string(),
+            user_message: user_message.to_string(),
+            error_code,
+            recovery_suggestions,
+            is_connection_error,
+            is_syntax_error,
+            is_permission_error,
+        }
+    }
+
+    fn get_keywords(&self) -> Vec<String> {
+        [
+            "SELECT",
+            "FROM",
+            "WHERE",
+            "INSERT",
+            "UPDATE",
+            "DELETE",
+            "MERGE",
+            "CREATE",
+            "DROP",
+            "ALTER",
+            "TABLE",
+            "INDEX",
+            "VIEW",
+            "SEQUENCE",
+            "SYNONYM",
+            "PACKAGE",
+            "PROCEDURE",
+            "FUNCTION",
+            "TRIGGER",
+            "BEGIN",
+            "END",
+            "DECLARE",
+            "EXCEPTION",
+            "CONNECT",
+            "START",
+            "WITH",
+            "PRIOR",
+            "ROWNUM",
+            "ROWID",
+            "DUAL",
+            "FETCH",
+            "OFFSET",
+            "ROWS",
+            "ONLY",
+            "COMMIT",
+            "ROLLBACK",
+            "GRANT",
+            "REVOKE",
+        ]
+        .iter()
+        .map(|keyword| keyword.to_string())
+        .collect()
+    }
+
+    fn get_functions(&self) -> Vec<String> {
+        [
+            "COUNT",
+            "SUM",
+            "AVG",
+            "MIN",
+            "MAX",
+            "NVL",
+            "NVL2",
+            "DECODE",
+            "COALESCE",
+            "NULLIF",
+            "TO_CHAR",
+            "TO_DATE",
+            "TO_NUMBER",
+            "TO_TIMESTAMP",
+            "SYSDATE",
+            "SYSTIMESTAMP",
+            "TRUNC",
+            "ROUND",
+            "SUBSTR",
+            "INSTR",
+            "LENGTH",
+            "UPPER",
+            "LOWER",
+            "TRIM",
+            "LPAD",
+            "RPAD",
+            "LISTAGG",
+            "REGEXP_LIKE",
+            "REGEXP_SUBSTR",
+            "ADD_MONTHS",
+            "MONTHS_BETWEEN",
+        ]
+        .iter()
+        .map(|function| function.to_string())
+        .collect()
+    }
+}
+
+#[async_trait]
+impl crate::database::connection_manager::ManagedConnection for OracleConnection {
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        OracleConnection::execute_raw_query(self, query).await
+    }
+
+    async fn execute_statement(&self, statement: &str) -> Result<u64> {
+        OracleConnection::execute_statement(self, statement).await
+    }
+
+    async fn get_table_data(
+        &self,
+        table_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        OracleConnection::get_table_data(self, table_name, limit, offset).await
+    }
+
+    async fn get_table_columns(&self, table_name: &str) -> Result<Vec<TableColumn>> {
+        OracleConnection::get_table_columns(self, table_name).await
+    }
+
+    async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
+        OracleConnection::get_table_metadata(self, table_name).await
+    }
+
+    async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        self.list_database_objects_page(None, 0).await
+    }
+
+    async fn list_database_objects_page(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        OracleConnection::list_database_objects_page(self, Some(limit), offset).await
+    }
+
+    fn is_connected(&self) -> bool {
+        Connection::is_connected(self)
+    }
+
+    fn query_canceller(&self) -> QueryCanceller {
+        OracleConnection::query_canceller(self)
+    }
+}
+
+/// EZConnect string (`//host:port/service`). The service name wins over the
+/// database field; with no host, the service is taken as a `tnsnames.ora` alias.
+fn connect_string(config: &ConnectionConfig) -> String {
+    let service = config
+        .service_name
+        .as_deref()
+        .or(config.database.as_deref())
+        .unwrap_or_default()
+        .trim();
+    let host = config.host.trim();
+    if host.is_empty() {
+        service.to_string()
+    } else if service.is_empty() {
+        format!("//{host}:{}", config.port)
+    } else {
+        format!("//{host}:{}/{service}", config.port)
+    }
+}
+
+/// Oracle rejects a trailing `;` on SQL statements but PL/SQL blocks need theirs
+fn trim_statement(sql: &str) -> &str {
+    let sql = sql.trim();
+    let upper = sql.to_uppercase();
+    if upper.ends_with("END;") || !sql.ends_with(';') {
+        sql
+    } else {
+        sql.trim_end_matches(';').trim_end()
+    }
+}
+
+/// Cell text as the other adapters show it; `None` is NULL
+fn cell_text(value: &::oracle::SqlValue) -> Option<String> {
+    if value.is_null().unwrap_or(false) {
+        return None;
+    }
+    Some(value.get::<String>().unwrap_or_else(|_| value.to_string()))
+}
+
+/// Quote an identifier for Oracle, keeping a `schema.table` qualifier
+fn quote_oracle_identifier(name: &str) -> Result<String> {
+    if name.is_empty() || name.contains('\0') {
+        return Err(LazyTablesError::Connection(
+            "Invalid table name: contains null bytes or is empty".to_string(),
+        ));
+    }
+    Ok(name
+        .split('.')
+        .map(|part| format!("\"{}\"", part.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join("."))
+}
+
+/// Parse an `ALL_TAB_COLUMNS.DATA_TYPE` value to the internal DataType enum
+fn parse_oracle_type(type_str: &str, length: Option<u32>) -> DataType {
+    let upper = type_str.to_uppercase();
+    let base = upper.split('(').next().unwrap_or_default().trim();
+    match base {
+        "NUMBER" | "FLOAT" => DataType::Decimal,
+        "BINARY_FLOAT" => DataType::Float,
+        "BINARY_DOUBLE" => DataType::Double,
+        "VARCHAR2" | "NVARCHAR2" | "VARCHAR" => DataType::Varchar(length.map(|l| l as usize)),
+        "CHAR" | "NCHAR" => DataType::Char(length.map(|l| l as usize)),
+        "CLOB" | "NCLOB" | "LONG" => DataType::Text,
+        "DATE" => DataType::Date,
+        "JSON" => DataType::Json,
+        "BLOB" | "RAW" | "LONG RAW" | "BFILE" => DataType::Bytea,
+        "BOOLEAN" => DataType::Boolean,
+        _ if base.starts_with("TIMESTAMP") => DataType::Timestamp,
+        _ => DataType::Text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::DatabaseType;
+
+    fn config(host: &str, database: Option<&str>, service: Option<&str>) -> ConnectionConfig {
+        let mut config = ConnectionConfig::new(
+            "test".to_string(),
+            DatabaseType::Oracle,
+            host.to_string(),
+            1521,
+            "scott".to_string(),
+        );
+        config.database = database.map(str::to_string);
+        config.service_name = service.map(str::to_string);
+        config
+    }
+
+    #[test]
+    fn test_connect_string_prefers_service_name() {
+        assert_eq!(
+            connect_string(&config("db", Some("ORCL"), Some("FREEPDB1"))),
+            "//db:1521/FREEPDB1"
+        );
+        assert_eq!(
+            connect_string(&config("db", Some("ORCL"), None)),
+            "//db:1521/ORCL"
+        );
+        assert_eq!(connect_string(&config("db", None, None)), "//db:1521");
+        // No host: the service is a tnsnames.ora alias
+        assert_eq!(connect_string(&config("", None, Some("PROD"))), "PROD");
+    }
+
+    #[test]
+    fn test_trim_statement_and_quoting() {
+        assert_eq!(trim_statement("SELECT 1 FROM dual; "), "SELECT 1 FROM dual");
+        assert_eq!(trim_statement("BEGIN NULL; END;"), "BEGIN NULL; END;");
+        assert_eq!(
+            quote_oracle_identifier("hr.Employees").unwrap(),
+            "\"hr\".\"Employees\""
+        );
+        assert!(quote_oracle_identifier("").is_err());
+        assert_eq!(
+            parse_oracle_type("TIMESTAMP(6) WITH TIME ZONE", None),
+            DataType::Timestamp
+        );
+        assert_eq!(
+            parse_oracle_type("VARCHAR2", Some(40)),
+            DataType::Varchar(Some(40))
+        );
+    }
+}
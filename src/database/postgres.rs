@@ -4,33 +4,56 @@
 
 use crate::core::error::{LazyTablesError, Result};
 use crate::database::{
-    connection::ConnectionConfig, Connection, DataType, TableColumn, TableMetadata,
+    connection::{connect_error, init_sql_error, ConnectionConfig, SslMode},
+    connection_manager::{BackendGuard, QueryCanceller, RunningBackend},
+    ddl::quote_identifier,
+    Connection, DataType, DatabaseType, TableColumn, TableMetadata,
 };
 use async_trait::async_trait;
 use serde_json;
-use sqlx::postgres::{PgPool, PgPoolOptions};
+use sqlx::postgres::{PgConnectOptions, PgPool, PgPoolCopyExt, PgPoolOptions, PgSslMode};
 use sqlx::{Column, Row};
 use uuid;
 
+/// Planner estimates at or above this many rows are shown instead of running `COUNT(*)`
+const EXACT_COUNT_MAX_ESTIMATE: i64 = 100_000;
+
 /// PostgreSQL database connection implementation
 #[derive(Debug)]
 pub struct PostgresConnection {
     config: ConnectionConfig,
     pub pool: Option<PgPool>,
+    running_backend: RunningBackend,
 }
 
 impl PostgresConnection {
     /// Create a new PostgreSQL connection instance
     pub fn new(config: ConnectionConfig) -> Self {
-        Self { config, pool: None }
+        Self {
+            config,
+            pool: None,
+            running_backend: RunningBackend::default(),
+        }
+    }
+
+    /// Cancel handle that stays usable while a query holds this connection
+    pub fn query_canceller(&self) -> QueryCanceller {
+        match &self.pool {
+            Some(pool) => QueryCanceller::Postgres {
+                pool: pool.clone(),
+                backend: self.running_backend.clone(),
+            },
+            None => QueryCanceller::Abort,
+        }
     }
 
     /// Build PostgreSQL connection string
     fn build_connection_string(&self, encryption_key: Option<&str>) -> Result<String> {
-        let host = &self.config.host;
-        let port = self.config.port;
-        let database = self.config.database.as_deref().unwrap_or("postgres");
-        let username = &self.config.username;
+        let config = self.config.with_env_expanded()?;
+        let host = &config.host;
+        let port = config.port;
+        let database = config.database.as_deref().unwrap_or("postgres");
+        let username = &config.username;
 
         // Try to resolve password from various sources
         let password = self
@@ -47,6 +70,37 @@ impl PostgresConnection {
         }
     }
 
+    /// Connect options for the connection string with the SSL mode and
+    /// certificate files applied
+    fn connect_options(&self, encryption_key: Option<&str>) -> Result<PgConnectOptions> {
+        let options: PgConnectOptions = self
+            .build_connection_string(encryption_key)?
+            .parse()
+            .map_err(|e| LazyTablesError::Connection(connect_error("PostgreSQL", e)))?;
+        let [ca_cert, client_cert, client_key] = self.config.certificate_paths();
+        let ssl_mode = match self.config.ssl_mode {
+            SslMode::Disable => PgSslMode::Disable,
+            SslMode::Allow => PgSslMode::Allow,
+            SslMode::Prefer => PgSslMode::Prefer,
+            SslMode::Require => PgSslMode::Require,
+            SslMode::VerifyCA => PgSslMode::VerifyCa,
+            SslMode::VerifyFull => PgSslMode::VerifyFull,
+            SslMode::Custom if ca_cert.is_some() => PgSslMode::VerifyCa,
+            SslMode::Custom => PgSslMode::Require,
+        };
+        let mut options = options.ssl_mode(ssl_mode);
+        if let Some(path) = ca_cert {
+            options = options.ssl_root_cert(path);
+        }
+        if let Some(path) = client_cert {
+            options = options.ssl_client_cert(path);
+        }
+        if let Some(path) = client_key {
+            options = options.ssl_client_key(path);
+        }
+        Ok(options)
+    }
+
     /// Parse SQLx error into structured ConnectionError with helpful suggestions
     pub fn parse_connection_error(
         &self,
@@ -166,15 +220,28 @@ impl Connection for PostgresConnection {
     }
 
     async fn connect_with_key(&mut self, encryption_key: Option<&str>) -> Result<()> {
-        let connection_string = self.build_connection_string(encryption_key)?;
+        let options = self.connect_options(encryption_key)?;
+        let session_sql = std::sync::Arc::new(self.config.session_sql());
 
         let pool = PgPoolOptions::new()
             .max_connections(5)
-            .connect(&connection_string)
+            .after_connect(move |conn, _| {
+                // Every pooled session gets the default schema and init SQL
+                let session_sql = std::sync::Arc::clone(&session_sql);
+                Box::pin(async move {
+                    for statement in session_sql.iter() {
+                        sqlx::Executor::execute(&mut *conn, statement.as_str())
+                            .await
+                            .map_err(|e| {
+                                sqlx::Error::Configuration(init_sql_error(statement, e).into())
+                            })?;
+                    }
+                    Ok(())
+                })
+            })
+            .connect_with(options)
             .await
-            .map_err(|e| {
-                LazyTablesError::Connection(format!("Failed to connect to PostgreSQL: {e}"))
-            })?;
+            .map_err(|e| LazyTablesError::Connection(connect_error("PostgreSQL", e)))?;
 
         self.pool = Some(pool);
         Ok(())
@@ -196,10 +263,19 @@ impl Connection for PostgresConnection {
     }
 
     // Query execution capabilities (AC1 requirement)
-    async fn execute_raw_query(&self, query: &str) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
         PostgresConnection::execute_raw_query(self, query).await
     }
 
+    async fn cancel_running_query(&self) -> Result<bool> {
+        PostgresConnection::query_canceller(self)
+            .cancel_running_query()
+            .await
+    }
+
     // Metadata operations (AC1 & AC2 requirements)
     async fn list_tables(&self) -> Result<Vec<String>> {
         PostgresConnection::list_tables(self).await
@@ -225,10 +301,17 @@ impl Connection for PostgresConnection {
         table_name: &str,
         limit: usize,
         offset: usize,
-    ) -> Result<Vec<Vec<String>>> {
+    ) -> Result<Vec<Vec<Option<String>>>> {
         PostgresConnection::get_table_data(self, table_name, limit, offset).await
     }
 
+    async fn get_table_statistics(
+        &self,
+        table_name: &str,
+    ) -> Result<crate::database::TableStatistics> {
+        PostgresConnection::get_table_statistics(self, table_name).await
+    }
+
     // Database-specific capabilities (AC1 & AC2 requirement)
     async fn get_database_capabilities(&self) -> Result<crate::database::DatabaseCapabilities> {
         Ok(crate::database::DatabaseCapabilities {
@@ -503,12 +586,13 @@ impl PostgresConnection {
             let query = "
                 SELECT table_name
                 FROM information_schema.tables
-                WHERE table_schema = 'public'
+                WHERE table_schema = $1
                 AND table_type = 'BASE TABLE'
                 ORDER BY table_name
             ";
 
             let rows = sqlx::query(query)
+                .bind(self.schema())
                 .fetch_all(pool)
                 .await
                 .map_err(|e| LazyTablesError::Connection(format!("Failed to list tables: {e}")))?;
@@ -526,15 +610,41 @@ impl PostgresConnection {
         }
     }
 
+    /// Schema of unqualified names: the connection's default schema, else `public`
+    fn schema(&self) -> &str {
+        self.config.default_schema.as_deref().unwrap_or("public")
+    }
+
+    /// Limit object listings to the default schema, or hide system schemas
+    fn schema_filter(&self) -> String {
+        match self.config.default_schema.as_deref() {
+            Some(schema) => format!("AND n.nspname = '{}'", schema.replace('\'', "''")),
+            None => "AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+                     AND n.nspname NOT LIKE 'pg_toast%'
+                     AND n.nspname NOT LIKE 'pg_temp%'"
+                .to_string(),
+        }
+    }
+
     /// List all database objects (tables, views, etc.)
     pub async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        self.list_database_objects_page(None, 0).await
+    }
+
+    /// List database objects one page at a time; `None` lists everything
+    pub async fn list_database_objects_page(
+        &self,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
         use crate::database::{DatabaseObject, DatabaseObjectList, DatabaseObjectType};
 
         if let Some(pool) = &self.pool {
             let mut result = DatabaseObjectList::default();
 
             // Query for all objects with proper error handling for permissions
-            let query = "
+            let query = format!(
+                "
                 WITH object_info AS (
                     SELECT
                         n.nspname AS schema_name,
@@ -557,15 +667,17 @@ impl PostgresConnection {
                     FROM pg_catalog.pg_class c
                     LEFT JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
                     WHERE c.relkind IN ('r', 'v', 'm', 'f')
-                        AND n.nspname NOT IN ('pg_catalog', 'information_schema')
-                        AND n.nspname NOT LIKE 'pg_toast%'
-                        AND n.nspname NOT LIKE 'pg_temp%'
+                        {}
                 )
                 SELECT * FROM object_info
                 ORDER BY schema_name, object_type, object_name
-            ";
+                {}
+            ",
+                self.schema_filter(),
+                crate::database::objects::page_clause(limit, offset)
+            );
 
-            match sqlx::query(query).fetch_all(pool).await {
+            match sqlx::query(&query).fetch_all(pool).await {
                 Ok(rows) => {
                     for row in rows {
                         let schema: String = row.get("schema_name");
@@ -631,6 +743,68 @@ impl PostgresConnection {
         }
     }
 
+    /// Stream a local CSV file to the server through `COPY ... FROM STDIN`
+    pub async fn copy_in_csv(
+        &self,
+        statement: &str,
+        path: &std::path::Path,
+        keep_columns: Option<&[usize]>,
+    ) -> Result<u64> {
+        use tokio::io::AsyncReadExt;
+
+        let Some(pool) = &self.pool else {
+            return Err(LazyTablesError::Connection(
+                "No active connection".to_string(),
+            ));
+        };
+
+        let mut copy = pool
+            .copy_in_raw(statement)
+            .await
+            .map_err(|e| LazyTablesError::Connection(format!("COPY failed: {e}")))?;
+
+        let mut file = match tokio::fs::File::open(path).await {
+            Ok(file) => file,
+            Err(e) => {
+                let _ = copy.abort(e.to_string()).await;
+                return Err(e.into());
+            }
+        };
+
+        // Unmapped columns have to be dropped, so each chunk is re-encoded on the way
+        let mut projector = keep_columns.map(crate::database::csv_import::CsvProjector::new);
+        let mut buffer = vec![0u8; 64 * 1024];
+        let sent = loop {
+            let n = match file.read(&mut buffer).await {
+                Ok(n) => n,
+                Err(e) => {
+                    let _ = copy.abort(e.to_string()).await;
+                    return Err(e.into());
+                }
+            };
+            let result = match (n, projector.as_mut()) {
+                (0, _) => match projector.take() {
+                    Some(projector) => copy.send(projector.finish()).await.map(|_| ()),
+                    None => Ok(()),
+                },
+                (n, Some(projector)) => copy.send(projector.feed(&buffer[..n])).await.map(|_| ()),
+                (n, None) => copy.send(&buffer[..n]).await.map(|_| ()),
+            };
+            if n == 0 || result.is_err() {
+                break result;
+            }
+        };
+
+        if let Err(e) = sent {
+            let _ = copy.abort(e.to_string()).await;
+            return Err(LazyTablesError::Connection(format!("COPY failed: {e}")));
+        }
+
+        copy.finish()
+            .await
+            .map_err(|e| LazyTablesError::Connection(format!("COPY failed: {e}")))
+    }
+
     /// List all schemas in the database
     pub async fn list_schemas(&self) -> Result<Vec<String>> {
         if let Some(pool) = &self.pool {
@@ -781,13 +955,16 @@ impl PostgresConnection {
     /// Get metadata for a specific table
     pub async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
         if let Some(pool) = &self.pool {
+            let columns = self.get_table_columns(table_name).await.unwrap_or_default();
+
             // Parse schema and table name
             let (schema, table) = if table_name.contains('.') {
                 let parts: Vec<&str> = table_name.splitn(2, '.').collect();
                 (parts[0], parts[1])
             } else {
-                ("public", table_name)
+                (self.schema(), table_name)
             };
+            let qualified_name = quoted_table_name(table_name, self.schema());
 
             // First, determine the object type
             let type_query = "SELECT c.relkind::text as relkind
@@ -801,26 +978,48 @@ impl PostgresConnection {
                 .fetch_optional(pool)
                 .await?;
 
-            let is_view = if let Some(row) = type_row {
-                let relkind: String = row.get("relkind");
-                matches!(relkind.as_str(), "v" | "m") // v = view, m = materialized view
+            let relkind: Option<String> = type_row.map(|row| row.get("relkind"));
+            let view_type = match relkind.as_deref() {
+                Some("v") => Some("VIEW"),
+                Some("m") => Some("MATERIALIZED VIEW"),
+                _ => None,
+            };
+            let is_view = view_type.is_some();
+
+            // Views carry their defining query instead of storage details
+            let view_definition = if is_view {
+                sqlx::query("SELECT pg_get_viewdef($1::regclass, true)")
+                    .bind(&qualified_name)
+                    .fetch_one(pool)
+                    .await
+                    .ok()
+                    .and_then(|row| row.get::<Option<String>, _>(0))
             } else {
-                false
+                None
             };
 
-            // Get row count (skip for regular views)
-            let row_count = if !is_view {
-                let count_query = format!(
-                    "SELECT COUNT(*) FROM {}.{}",
-                    schema.replace("'", "''"),
-                    table.replace("'", "''")
-                );
-                match sqlx::query(&count_query).fetch_one(pool).await {
-                    Ok(row) => row.get::<i64, _>(0),
-                    Err(_) => 0, // Default to 0 if we can't get count
-                }
+            // Get row count (skip for regular views). Large tables keep the
+            // planner estimate; `c` in Details asks for an exact count.
+            let estimate: Option<i64> = if !is_view {
+                sqlx::query("SELECT reltuples::bigint FROM pg_class WHERE oid = $1::regclass")
+                    .bind(&qualified_name)
+                    .fetch_one(pool)
+                    .await
+                    .ok()
+                    .map(|row| row.get::<i64, _>(0))
             } else {
-                0 // Views don't have direct row counts
+                None
+            };
+            let (row_count, row_count_exact) = match estimate {
+                _ if is_view => (0, true), // Views don't have direct row counts
+                Some(estimate) if estimate >= EXACT_COUNT_MAX_ESTIMATE => (estimate, false),
+                _ => {
+                    let count_query = format!("SELECT COUNT(*) FROM {qualified_name}");
+                    match sqlx::query(&count_query).fetch_one(pool).await {
+                        Ok(row) => (row.get::<i64, _>(0), true),
+                        Err(_) => (0, true), // Default to 0 if we can't get count
+                    }
+                }
             };
 
             // Get column count
@@ -844,9 +1043,6 @@ impl PostgresConnection {
 
             // Get size (skip for regular views as they don't have physical storage)
             let (total_size, table_size, indexes_size) = if !is_view {
-                let qualified_name =
-                    format!("{}.{}", schema.replace("'", "''"), table.replace("'", "''"));
-
                 match sqlx::query(size_query)
                     .bind(&qualified_name)
                     .fetch_one(pool)
@@ -871,9 +1067,6 @@ impl PostgresConnection {
                            WHERE i.indrelid = $1::regclass
                            AND i.indisprimary";
 
-            let qualified_name =
-                format!("{}.{}", schema.replace("'", "''"), table.replace("'", "''"));
-
             let pk_rows: Vec<sqlx::postgres::PgRow> = sqlx::query(pk_query)
                 .bind(&qualified_name)
                 .fetch_all(pool)
@@ -952,7 +1145,10 @@ impl PostgresConnection {
                         foreign_keys,
                         indexes,
                         None,
-                    ))
+                    )
+                    .with_row_count_exact(row_count_exact)
+                    .with_columns(&columns)
+                    .with_view_type(view_type, view_definition))
                 }
             };
 
@@ -969,7 +1165,10 @@ impl PostgresConnection {
                 foreign_keys,
                 indexes,
                 comment,
-            ))
+            )
+            .with_row_count_exact(row_count_exact)
+            .with_columns(&columns)
+            .with_view_type(view_type, view_definition))
         } else {
             Err(LazyTablesError::Connection(
                 "Not connected to database".to_string(),
@@ -985,7 +1184,7 @@ impl PostgresConnection {
                 let parts: Vec<&str> = table_name.splitn(2, '.').collect();
                 (parts[0], parts[1])
             } else {
-                ("public", table_name)
+                (self.schema(), table_name)
             };
 
             crate::log_debug!(
@@ -996,6 +1195,7 @@ impl PostgresConnection {
             let query = "SELECT
                 c.column_name,
                 c.data_type,
+                c.udt_name::text AS udt_name,
                 c.is_nullable,
                 c.column_default,
                 CASE
@@ -1037,13 +1237,14 @@ impl PostgresConnection {
                 .map(|row| {
                     let column_name: String = row.get("column_name");
                     let data_type_str: String = row.get("data_type");
+                    let udt_name: String = row.get("udt_name");
                     let is_nullable: String = row.get("is_nullable");
                     let column_default: Option<String> = row.get("column_default");
                     let is_primary_key: bool = row.get("is_primary_key");
 
                     TableColumn {
                         name: column_name,
-                        data_type: parse_postgres_type(&data_type_str),
+                        data_type: column_type(&data_type_str, &udt_name),
                         is_nullable: is_nullable == "YES",
                         default_value: column_default,
                         is_primary_key,
@@ -1062,14 +1263,10 @@ impl PostgresConnection {
     /// Get the row count for a table
     pub async fn get_table_row_count(&self, table_name: &str) -> Result<usize> {
         if let Some(pool) = &self.pool {
-            // Use the same qualified name logic as get_table_data
-            let qualified_name = if table_name.contains('.') {
-                table_name.to_string()
-            } else {
-                format!("public.{}", table_name)
-            };
-
-            let query = format!("SELECT COUNT(*) FROM {qualified_name}");
+            let query = format!(
+                "SELECT COUNT(*) FROM {}",
+                quoted_table_name(table_name, self.schema())
+            );
             let row = sqlx::query(&query).fetch_one(pool).await?;
             let count: i64 = row.get(0);
             Ok(count as usize)
@@ -1086,14 +1283,14 @@ impl PostgresConnection {
         table_name: &str,
         limit: usize,
         offset: usize,
-    ) -> Result<Vec<Vec<String>>> {
+    ) -> Result<Vec<Vec<Option<String>>>> {
         if let Some(pool) = &self.pool {
             // Parse schema and table name
             let (schema, table) = if table_name.contains('.') {
                 let parts: Vec<&str> = table_name.splitn(2, '.').collect();
                 (parts[0], parts[1])
             } else {
-                ("public", table_name)
+                (self.schema(), table_name)
             };
 
             // Get column names first to maintain order
@@ -1133,16 +1330,11 @@ impl PostgresConnection {
             // Build SELECT query with all columns
             let select_list = column_names
                 .iter()
-                .map(|col| format!("\"{col}\"::text"))
+                .map(|col| format!("{}::text", quote_identifier(col, &DatabaseType::PostgreSQL)))
                 .collect::<Vec<_>>()
                 .join(", ");
 
-            let qualified_name = if table_name.contains('.') {
-                table_name.to_string()
-            } else {
-                format!("public.{}", table_name)
-            };
-
+            let qualified_name = quoted_table_name(table_name, self.schema());
             let query = format!(
                 "SELECT {select_list} FROM {qualified_name} ORDER BY 1 LIMIT {limit} OFFSET {offset}"
             );
@@ -1153,8 +1345,7 @@ impl PostgresConnection {
             for row in rows {
                 let mut row_data = Vec::new();
                 for (idx, _col_name) in column_names.iter().enumerate() {
-                    let value: Option<String> = row.try_get(idx).ok();
-                    row_data.push(value.unwrap_or_else(|| "NULL".to_string()));
+                    row_data.push(row.try_get::<Option<String>, _>(idx).ok().flatten());
                 }
                 result.push(row_data);
             }
@@ -1170,12 +1361,20 @@ impl PostgresConnection {
 
 impl PostgresConnection {
     /// Execute a raw SQL query and return columns and rows
-    pub async fn execute_raw_query(&self, query: &str) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    pub async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
         if let Some(pool) = &self.pool {
             crate::log_debug!("execute_raw_query: Executing query: {}", query);
 
-            // Execute the query
-            let rows = sqlx::query(query).fetch_all(pool).await?;
+            // Run on a dedicated connection whose backend pid is recorded for cancellation
+            let mut conn = pool.acquire().await?;
+            let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+                .fetch_one(&mut *conn)
+                .await?;
+            let _running = BackendGuard::record(&self.running_backend, pid as u64);
+            let rows = sqlx::query(query).fetch_all(&mut *conn).await?;
 
             if rows.is_empty() {
                 crate::log_debug!("execute_raw_query: No rows returned");
@@ -1215,21 +1414,112 @@ impl PostgresConnection {
             ))
         }
     }
+
+    /// Access counters from `pg_stat_user_tables` and per-index usage from
+    /// `pg_stat_user_indexes`
+    pub async fn get_table_statistics(
+        &self,
+        table_name: &str,
+    ) -> Result<crate::database::TableStatistics> {
+        let Some(pool) = &self.pool else {
+            return Err(LazyTablesError::Connection(
+                "Not connected to database".to_string(),
+            ));
+        };
+        let (schema, table) = table_name
+            .split_once('.')
+            .unwrap_or((self.schema(), table_name));
+
+        let row = sqlx::query(
+            "SELECT seq_scan, seq_tup_read, idx_scan, n_live_tup, n_dead_tup,
+                    n_tup_ins, n_tup_upd, n_tup_del,
+                    to_char(GREATEST(last_autovacuum, last_vacuum), 'YYYY-MM-DD HH24:MI:SS'),
+                    to_char(GREATEST(last_autoanalyze, last_analyze), 'YYYY-MM-DD HH24:MI:SS')
+             FROM pg_stat_user_tables
+             WHERE schemaname = $1 AND relname = $2",
+        )
+        .bind(schema)
+        .bind(table)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| {
+            LazyTablesError::Other(format!("No statistics for {table_name}; is it a table?"))
+        })?;
+
+        let indexes = sqlx::query(
+            "SELECT s.indexrelname::text, s.idx_scan, pg_relation_size(s.indexrelid),
+                    i.indisunique OR i.indisprimary
+             FROM pg_stat_user_indexes s
+             JOIN pg_index i ON i.indexrelid = s.indexrelid
+             WHERE s.schemaname = $1 AND s.relname = $2
+             ORDER BY s.idx_scan, s.indexrelname",
+        )
+        .bind(schema)
+        .bind(table)
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .map(|index| crate::database::IndexUsage {
+            name: index.get(0),
+            scans: index.get(1),
+            size_bytes: index.get(2),
+            is_unique: index.get(3),
+        })
+        .collect();
+
+        Ok(crate::database::TableStatistics {
+            table_name: table_name.to_string(),
+            source: "pg_stat_user_tables",
+            seq_scans: row.get(0),
+            seq_rows_read: row.get(1),
+            index_scans: row.get(2),
+            live_rows: row.get(3),
+            dead_rows: row.get(4),
+            rows_inserted: row.get(5),
+            rows_updated: row.get(6),
+            rows_deleted: row.get(7),
+            last_vacuum: row.get(8),
+            last_analyze: row.get(9),
+            indexes,
+            note: None,
+        })
+    }
 }
 
 /// Implement ManagedConnection trait for PostgresConnection to work with ConnectionManager
 #[async_trait]
 impl crate::database::connection_manager::ManagedConnection for PostgresConnection {
-    async fn execute_raw_query(&self, query: &str) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
         PostgresConnection::execute_raw_query(self, query).await
     }
 
+    async fn execute_statement(&self, statement: &str) -> Result<u64> {
+        let Some(pool) = &self.pool else {
+            return Err(LazyTablesError::Connection(
+                "Not connected to database".to_string(),
+            ));
+        };
+        // Run on a dedicated connection whose backend pid is recorded for cancellation
+        let mut conn = pool.acquire().await?;
+        let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+            .fetch_one(&mut *conn)
+            .await?;
+        let _running = BackendGuard::record(&self.running_backend, pid as u64);
+        Ok(sqlx::query(statement)
+            .execute(&mut *conn)
+            .await?
+            .rows_affected())
+    }
+
     async fn get_table_data(
         &self,
         table_name: &str,
         limit: usize,
         offset: usize,
-    ) -> Result<Vec<Vec<String>>> {
+    ) -> Result<Vec<Vec<Option<String>>>> {
         PostgresConnection::get_table_data(self, table_name, limit, offset).await
     }
 
@@ -1248,19 +1538,50 @@ impl crate::database::connection_manager::ManagedConnection for PostgresConnecti
         PostgresConnection::list_database_objects(self).await
     }
 
+    async fn list_database_objects_page(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        PostgresConnection::list_database_objects_page(self, Some(limit), offset).await
+    }
+
+    async fn copy_in_csv(
+        &self,
+        statement: &str,
+        path: std::path::PathBuf,
+        keep_columns: Option<Vec<usize>>,
+    ) -> Result<u64> {
+        PostgresConnection::copy_in_csv(self, statement, &path, keep_columns.as_deref()).await
+    }
+
+    async fn get_table_statistics(
+        &self,
+        table_name: &str,
+    ) -> Result<crate::database::TableStatistics> {
+        PostgresConnection::get_table_statistics(self, table_name).await
+    }
+
     // Note: ManagedConnection trait doesn't have disconnect method anymore
     // Connections are cleaned up automatically when dropped from the connection manager
 
     fn is_connected(&self) -> bool {
         Connection::is_connected(self)
     }
+
+    fn query_canceller(&self) -> QueryCanceller {
+        PostgresConnection::query_canceller(self)
+    }
 }
 
 // Drop implementation removed - connection pools are closed explicitly via close() method
 // to avoid spawning background tasks that may not complete before app shutdown
 
 /// Extract a PostgreSQL value from a row and column, handling different data types robustly
-fn extract_postgres_value(row: &sqlx::postgres::PgRow, col: &sqlx::postgres::PgColumn) -> String {
+fn extract_postgres_value(
+    row: &sqlx::postgres::PgRow,
+    col: &sqlx::postgres::PgColumn,
+) -> Option<String> {
     use sqlx::{Column, Row, TypeInfo};
 
     let col_name = col.name();
@@ -1274,120 +1595,73 @@ fn extract_postgres_value(row: &sqlx::postgres::PgRow, col: &sqlx::postgres::PgC
         col_ordinal
     );
 
-    // Try to extract based on PostgreSQL type information
+    // Try to extract based on PostgreSQL type information; NULL and
+    // undecodable values come back as `None`
+    let text = || row.try_get::<Option<String>, _>(col_ordinal).ok().flatten();
     match col_type {
         // Text types
-        "TEXT" | "VARCHAR" | "CHAR" | "BPCHAR" => {
-            if let Ok(val) = row.try_get::<Option<String>, _>(col_ordinal) {
-                val.unwrap_or_else(|| "NULL".to_string())
-            } else {
-                "NULL".to_string()
-            }
-        }
+        "TEXT" | "VARCHAR" | "CHAR" | "BPCHAR" => text(),
 
         // Integer types
-        "INT2" | "SMALLINT" => {
-            if let Ok(val) = row.try_get::<Option<i16>, _>(col_ordinal) {
-                val.map(|v| v.to_string())
-                    .unwrap_or_else(|| "NULL".to_string())
-            } else {
-                "NULL".to_string()
-            }
-        }
-
-        "INT4" | "INTEGER" | "SERIAL" => {
-            if let Ok(val) = row.try_get::<Option<i32>, _>(col_ordinal) {
-                val.map(|v| v.to_string())
-                    .unwrap_or_else(|| "NULL".to_string())
-            } else {
-                "NULL".to_string()
-            }
-        }
-
-        "INT8" | "BIGINT" | "BIGSERIAL" => {
-            if let Ok(val) = row.try_get::<Option<i64>, _>(col_ordinal) {
-                val.map(|v| v.to_string())
-                    .unwrap_or_else(|| "NULL".to_string())
-            } else {
-                "NULL".to_string()
-            }
-        }
+        "INT2" | "SMALLINT" => row
+            .try_get::<Option<i16>, _>(col_ordinal)
+            .ok()
+            .flatten()
+            .map(|v| v.to_string()),
+
+        "INT4" | "INTEGER" | "SERIAL" => row
+            .try_get::<Option<i32>, _>(col_ordinal)
+            .ok()
+            .flatten()
+            .map(|v| v.to_string()),
+
+        "INT8" | "BIGINT" | "BIGSERIAL" => row
+            .try_get::<Option<i64>, _>(col_ordinal)
+            .ok()
+            .flatten()
+            .map(|v| v.to_string()),
 
         // Floating point types
-        "FLOAT4" | "REAL" => {
-            if let Ok(val) = row.try_get::<Option<f32>, _>(col_ordinal) {
-                val.map(|v| v.to_string())
-                    .unwrap_or_else(|| "NULL".to_string())
-            } else {
-                "NULL".to_string()
-            }
-        }
-
-        "FLOAT8" | "DOUBLE PRECISION" => {
-            if let Ok(val) = row.try_get::<Option<f64>, _>(col_ordinal) {
-                val.map(|v| v.to_string())
-                    .unwrap_or_else(|| "NULL".to_string())
-            } else {
-                "NULL".to_string()
-            }
-        }
+        "FLOAT4" | "REAL" => row
+            .try_get::<Option<f32>, _>(col_ordinal)
+            .ok()
+            .flatten()
+            .map(|v| v.to_string()),
+
+        "FLOAT8" | "DOUBLE PRECISION" => row
+            .try_get::<Option<f64>, _>(col_ordinal)
+            .ok()
+            .flatten()
+            .map(|v| v.to_string()),
 
         // Boolean type
-        "BOOL" | "BOOLEAN" => {
-            if let Ok(val) = row.try_get::<Option<bool>, _>(col_ordinal) {
-                val.map(|v| v.to_string())
-                    .unwrap_or_else(|| "NULL".to_string())
-            } else {
-                "NULL".to_string()
-            }
-        }
+        "BOOL" | "BOOLEAN" => row
+            .try_get::<Option<bool>, _>(col_ordinal)
+            .ok()
+            .flatten()
+            .map(|v| v.to_string()),
 
-        // UUID type
-        "UUID" => {
-            if let Ok(val) = row.try_get::<Option<uuid::Uuid>, _>(col_ordinal) {
-                val.map(|v| v.to_string())
-                    .unwrap_or_else(|| "NULL".to_string())
-            } else {
-                // Fallback: try as string
-                if let Ok(val) = row.try_get::<Option<String>, _>(col_ordinal) {
-                    val.unwrap_or_else(|| "NULL".to_string())
-                } else {
-                    "NULL".to_string()
-                }
-            }
-        }
-
-        // Date/time types
-        "DATE" | "TIME" | "TIMESTAMP" | "TIMESTAMPTZ" => {
-            // Try to get as string representation first
-            if let Ok(val) = row.try_get::<Option<String>, _>(col_ordinal) {
-                val.unwrap_or_else(|| "NULL".to_string())
-            } else {
-                "NULL".to_string()
-            }
-        }
+        // UUID type, falling back to its string form
+        "UUID" => match row.try_get::<Option<uuid::Uuid>, _>(col_ordinal) {
+            Ok(val) => val.map(|v| v.to_string()),
+            Err(_) => text(),
+        },
 
-        // Numeric/decimal types
-        "NUMERIC" | "DECIMAL" => {
-            // Try to get as string representation
-            if let Ok(val) = row.try_get::<Option<String>, _>(col_ordinal) {
-                val.unwrap_or_else(|| "NULL".to_string())
-            } else {
-                "NULL".to_string()
-            }
-        }
+        // Date/time and numeric types, as their string representation
+        "DATE" | "TIME" | "TIMESTAMP" | "TIMESTAMPTZ" | "NUMERIC" | "DECIMAL" => text(),
 
         // JSON types
-        "JSON" | "JSONB" => {
-            if let Ok(val) = row.try_get::<Option<serde_json::Value>, _>(col_ordinal) {
-                val.map(|v| v.to_string())
-                    .unwrap_or_else(|| "NULL".to_string())
-            } else if let Ok(val) = row.try_get::<Option<String>, _>(col_ordinal) {
-                val.unwrap_or_else(|| "NULL".to_string())
-            } else {
-                "NULL".to_string()
-            }
-        }
+        "JSON" | "JSONB" => match row.try_get::<Option<serde_json::Value>, _>(col_ordinal) {
+            Ok(val) => val.map(|v| v.to_string()),
+            Err(_) => text(),
+        },
+
+        // Binary data, shown as a hex literal
+        "BYTEA" => row
+            .try_get::<Option<Vec<u8>>, _>(col_ordinal)
+            .ok()
+            .flatten()
+            .map(|bytes| crate::database::hex_literal(&bytes)),
 
         // Unknown or other types - fallback to string conversion
         _ => {
@@ -1397,23 +1671,45 @@ fn extract_postgres_value(row: &sqlx::postgres::PgRow, col: &sqlx::postgres::PgC
                 col_name
             );
 
-            // Try various fallback approaches
-            if let Ok(val) = row.try_get::<Option<String>, _>(col_ordinal) {
-                val.unwrap_or_else(|| "NULL".to_string())
-            } else if let Ok(val) = row.try_get::<String, _>(col_ordinal) {
-                val
-            } else {
-                crate::log_warn!(
-                    "extract_postgres_value: Failed to extract value for column '{}' (type: {})",
-                    col_name,
-                    col_type
-                );
-                "NULL".to_string()
+            match row.try_get::<Option<String>, _>(col_ordinal) {
+                Ok(val) => val,
+                Err(_) => {
+                    crate::log_warn!(
+                        "extract_postgres_value: Failed to extract value for column '{}' (type: {})",
+                        col_name,
+                        col_type
+                    );
+                    None
+                }
             }
         }
     }
 }
 
+/// `"schema"."table"` for generated SQL, so mixed-case and reserved-word
+/// names are taken literally; names without a schema are in `default_schema`
+fn quoted_table_name(table_name: &str, default_schema: &str) -> String {
+    let (schema, table) = table_name
+        .split_once('.')
+        .unwrap_or((default_schema, table_name));
+    format!(
+        "{}.{}",
+        quote_identifier(schema, &DatabaseType::PostgreSQL),
+        quote_identifier(table, &DatabaseType::PostgreSQL)
+    )
+}
+
+/// Type of an `information_schema.columns` row. Arrays only say `ARRAY`
+/// there; their element type comes from `udt_name`, e.g. `_text`.
+fn column_type(data_type: &str, udt_name: &str) -> DataType {
+    if data_type == "ARRAY" {
+        let element = udt_name.strip_prefix('_').unwrap_or(udt_name);
+        DataType::Array(Box::new(parse_postgres_type(element)))
+    } else {
+        parse_postgres_type(data_type)
+    }
+}
+
 /// Parse PostgreSQL data type string to internal DataType enum
 fn parse_postgres_type(type_str: &str) -> DataType {
     match type_str {
@@ -1426,16 +1722,16 @@ fn parse_postgres_type(type_str: &str) -> DataType {
         "boolean" | "bool" => DataType::Boolean,
         "text" => DataType::Text,
         "character varying" | "varchar" => DataType::Varchar(None),
-        "character" | "char" => DataType::Char(None),
+        "character" | "char" | "bpchar" => DataType::Char(None),
         "date" => DataType::Date,
         "time" | "time without time zone" => DataType::Time,
-        "timestamp" | "timestamp without time zone" | "timestamp with time zone" => {
-            DataType::Timestamp
-        }
+        "timestamp"
+        | "timestamptz"
+        | "timestamp without time zone"
+        | "timestamp with time zone" => DataType::Timestamp,
         "json" | "jsonb" => DataType::Json,
         "uuid" => DataType::Uuid,
         "bytea" => DataType::Bytea,
-        s if s.starts_with("ARRAY") => DataType::Text, // Simplified for now
         _ => DataType::Text,
     }
 }
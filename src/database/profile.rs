@@ -0,0 +1,427 @@
+// FilePath: src/database/profile.rs
+
+#![forbid(unsafe_code)]
+
+use crate::core::error::{LazyTablesError, Result};
+use crate::database::{ddl::quote_identifier, ConnectionManager, DatabaseType, TableMetadata};
+
+/// Rows scanned per column when profiling a MySQL table
+pub const MYSQL_SAMPLE_ROWS: u64 = 10_000;
+
+/// Separator for values packed into a single PostgreSQL result cell
+const VALUE_SEPARATOR: char = '\u{1f}';
+
+/// Statistics for a single column
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnProfile {
+    pub name: String,
+    /// Rows the statistics cover
+    pub count: u64,
+    /// Share of NULL values, between 0 and 1
+    pub null_fraction: f64,
+    pub distinct: Option<u64>,
+    pub min: Option<String>,
+    pub max: Option<String>,
+    /// Only computed for numeric columns
+    pub avg: Option<String>,
+    /// Up to three most common values, most frequent first
+    pub top_values: Vec<String>,
+}
+
+impl ColumnProfile {
+    /// Counts line, e.g. `count 1200 • null 3.2% • distinct 50`
+    pub fn counts_summary(&self) -> String {
+        let distinct = self
+            .distinct
+            .map(|distinct| distinct.to_string())
+            .unwrap_or_else(|| "?".to_string());
+        format!(
+            "count {} • null {:.1}% • distinct {distinct}",
+            self.count,
+            self.null_fraction * 100.0
+        )
+    }
+
+    /// Range line, e.g. `min 1 • max 99 • avg 42.50`, if any bound is known
+    pub fn range_summary(&self) -> Option<String> {
+        let parts: Vec<String> = [("min", &self.min), ("max", &self.max), ("avg", &self.avg)]
+            .into_iter()
+            .filter_map(|(label, value)| value.as_ref().map(|value| format!("{label} {value}")))
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(" • "))
+    }
+}
+
+/// Column statistics of a table, shown below its metadata in the Details pane
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnProfileView {
+    pub table_name: String,
+    pub columns: Vec<ColumnProfile>,
+    /// Set when the statistics come from a sample of this many rows
+    pub sample_size: Option<u64>,
+    /// Where the numbers come from, e.g. `pg_stats`
+    pub source: &'static str,
+}
+
+/// Compute per-column statistics for a table. PostgreSQL reads the planner
+/// statistics in `pg_stats` (min/max are the histogram bounds), MySQL
+/// aggregates over a sample of the rows and SQLite scans the whole table.
+pub async fn profile_table(
+    connection_manager: &ConnectionManager,
+    connection_id: &str,
+    database_type: &DatabaseType,
+    metadata: &TableMetadata,
+) -> Result<ColumnProfileView> {
+    match database_type {
+        DatabaseType::PostgreSQL => {
+            profile_postgres(connection_manager, connection_id, metadata).await
+        }
+        DatabaseType::MySQL | DatabaseType::MariaDB => {
+            profile_mysql(connection_manager, connection_id, metadata).await
+        }
+        DatabaseType::SQLite => profile_sqlite(connection_manager, connection_id, metadata).await,
+        _ => Err(LazyTablesError::Other(format!(
+            "Column profiling is not supported for {}",
+            database_type.display_name()
+        ))),
+    }
+}
+
+async fn profile_postgres(
+    connection_manager: &ConnectionManager,
+    connection_id: &str,
+    metadata: &TableMetadata,
+) -> Result<ColumnProfileView> {
+    let (schema, table) = match metadata.table_name.split_once('.') {
+        Some((schema, table)) => (schema.to_string(), table.to_string()),
+        None => (
+            metadata
+                .schema_name
+                .clone()
+                .unwrap_or_else(|| "public".to_string()),
+            metadata.table_name.clone(),
+        ),
+    };
+
+    let stats_query = format!(
+        "SELECT attname::text, null_frac::text, n_distinct::text, \
+         array_to_string((most_common_vals::text::text[])[1:3], chr(31)), \
+         (histogram_bounds::text::text[])[1], \
+         (histogram_bounds::text::text[])[array_length(histogram_bounds::text::text[], 1)] \
+         FROM pg_stats WHERE schemaname = '{}' AND tablename = '{}'",
+        schema.replace('\'', "''"),
+        table.replace('\'', "''")
+    );
+    let (_, stats_rows) = connection_manager
+        .execute_raw_query(connection_id, &stats_query)
+        .await?;
+    if stats_rows.is_empty() {
+        return Err(LazyTablesError::Other(format!(
+            "No statistics for {table}; run ANALYZE first"
+        )));
+    }
+
+    let quoted_table = format!(
+        "{}.{}",
+        quote_identifier(&schema, &DatabaseType::PostgreSQL),
+        quote_identifier(&table, &DatabaseType::PostgreSQL)
+    );
+    let numeric_columns: Vec<&str> = metadata
+        .columns_summary
+        .iter()
+        .filter(|column| is_numeric_type(&column.data_type))
+        .map(|column| column.name.as_str())
+        .collect();
+    let averages = if numeric_columns.is_empty() {
+        Vec::new()
+    } else {
+        let select: Vec<String> = numeric_columns
+            .iter()
+            .map(|name| {
+                format!(
+                    "AVG({})::text",
+                    quote_identifier(name, &DatabaseType::PostgreSQL)
+                )
+            })
+            .collect();
+        let query = format!("SELECT {} FROM {quoted_table}", select.join(", "));
+        let (_, rows) = connection_manager
+            .execute_raw_query(connection_id, &query)
+            .await?;
+        rows.into_iter().next().unwrap_or_default()
+    };
+
+    let count = metadata.row_count as u64;
+    let columns = metadata
+        .columns_summary
+        .iter()
+        .map(|column| {
+            let stats = stats_rows
+                .iter()
+                .find(|row| value_at(row, 0).as_ref() == Some(&column.name));
+            let avg = numeric_columns
+                .iter()
+                .position(|name| *name == column.name)
+                .and_then(|index| value_at(&averages, index))
+                .map(|avg| format_average(&avg));
+            let null_fraction = stats
+                .and_then(|row| value_at(row, 1))
+                .and_then(|value| value.parse::<f64>().ok())
+                .unwrap_or(0.0);
+            // Negative n_distinct is a fraction of the row count
+            let distinct = stats
+                .and_then(|row| value_at(row, 2))
+                .and_then(|value| value.parse::<f64>().ok())
+                .map(|n_distinct| {
+                    if n_distinct < 0.0 {
+                        (-n_distinct * count as f64).round() as u64
+                    } else {
+                        n_distinct as u64
+                    }
+                });
+            ColumnProfile {
+                name: column.name.clone(),
+                count,
+                null_fraction,
+                distinct,
+                min: stats.and_then(|row| value_at(row, 4)),
+                max: stats.and_then(|row| value_at(row, 5)),
+                avg,
+                top_values: stats
+                    .and_then(|row| value_at(row, 3))
+                    .map(|values| values.split(VALUE_SEPARATOR).map(String::from).collect())
+                    .unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    Ok(ColumnProfileView {
+        table_name: metadata.table_name.clone(),
+        columns,
+        sample_size: None,
+        source: "pg_stats",
+    })
+}
+
+async fn profile_mysql(
+    connection_manager: &ConnectionManager,
+    connection_id: &str,
+    metadata: &TableMetadata,
+) -> Result<ColumnProfileView> {
+    let database_type = DatabaseType::MySQL;
+    let columns_query = format!(
+        "SELECT COLUMN_NAME, DATA_TYPE FROM information_schema.COLUMNS \
+         WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = '{}' ORDER BY ORDINAL_POSITION",
+        metadata.table_name.replace('\'', "''")
+    );
+    let (_, column_rows) = connection_manager
+        .execute_raw_query(connection_id, &columns_query)
+        .await?;
+
+    let table = quote_identifier(&metadata.table_name, &database_type);
+    let mut columns = Vec::new();
+    for row in &column_rows {
+        let Some(name) = value_at(row, 0) else {
+            continue;
+        };
+        let numeric = value_at(row, 1).is_some_and(|data_type| is_numeric_type(&data_type));
+        let column = quote_identifier(&name, &database_type);
+        let sample = format!("(SELECT {column} FROM {table} LIMIT {MYSQL_SAMPLE_ROWS}) AS sample");
+        columns.push(
+            profile_column(
+                connection_manager,
+                connection_id,
+                &name,
+                &column,
+                &sample,
+                numeric,
+            )
+            .await?,
+        );
+    }
+
+    Ok(ColumnProfileView {
+        table_name: metadata.table_name.clone(),
+        columns,
+        sample_size: Some(MYSQL_SAMPLE_ROWS),
+        source: "information_schema + sample",
+    })
+}
+
+async fn profile_sqlite(
+    connection_manager: &ConnectionManager,
+    connection_id: &str,
+    metadata: &TableMetadata,
+) -> Result<ColumnProfileView> {
+    let table = quote_identifier(&metadata.table_name, &DatabaseType::SQLite);
+    let mut columns = Vec::new();
+    for summary in &metadata.columns_summary {
+        let column = quote_identifier(&summary.name, &DatabaseType::SQLite);
+        columns.push(
+            profile_column(
+                connection_manager,
+                connection_id,
+                &summary.name,
+                &column,
+                &table,
+                is_numeric_type(&summary.data_type),
+            )
+            .await?,
+        );
+    }
+
+    Ok(ColumnProfileView {
+        table_name: metadata.table_name.clone(),
+        columns,
+        sample_size: None,
+        source: "full scan",
+    })
+}
+
+/// Aggregate one column over `source` (a table or a sampling subquery)
+async fn profile_column(
+    connection_manager: &ConnectionManager,
+    connection_id: &str,
+    name: &str,
+    column: &str,
+    source: &str,
+    numeric: bool,
+) -> Result<ColumnProfile> {
+    let (_, rows) = connection_manager
+        .execute_raw_query(connection_id, &aggregate_sql(column, source, numeric))
+        .await?;
+    let row = rows.into_iter().next().unwrap_or_default();
+    let (_, top_rows) = connection_manager
+        .execute_raw_query(connection_id, &top_values_sql(column, source))
+        .await?;
+
+    let count = value_at(&row, 0)
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(0);
+    let nulls = value_at(&row, 1)
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(0);
+    Ok(ColumnProfile {
+        name: name.to_string(),
+        count,
+        null_fraction: if count == 0 {
+            0.0
+        } else {
+            nulls as f64 / count as f64
+        },
+        distinct: value_at(&row, 2).and_then(|value| value.parse().ok()),
+        min: value_at(&row, 3),
+        max: value_at(&row, 4),
+        avg: value_at(&row, 5).map(|avg| format_average(&avg)),
+        top_values: top_rows.iter().filter_map(|row| value_at(row, 0)).collect(),
+    })
+}
+
+fn aggregate_sql(column: &str, source: &str, numeric: bool) -> String {
+    let avg = if numeric {
+        format!("AVG({column})")
+    } else {
+        "NULL".to_string()
+    };
+    format!(
+        "SELECT COUNT(*), COUNT(*) - COUNT({column}), COUNT(DISTINCT {column}), \
+         MIN({column}), MAX({column}), {avg} FROM {source}"
+    )
+}
+
+fn top_values_sql(column: &str, source: &str) -> String {
+    format!(
+        "SELECT {column}, COUNT(*) AS frequency FROM {source} WHERE {column} IS NOT NULL \
+         GROUP BY {column} ORDER BY frequency DESC LIMIT 3"
+    )
+}
+
+/// Whether a column type supports `AVG`
+pub fn is_numeric_type(data_type: &str) -> bool {
+    let upper = data_type.to_uppercase();
+    [
+        "INT", "SERIAL", "NUMERIC", "DECIMAL", "REAL", "FLOAT", "DOUBLE",
+    ]
+    .iter()
+    .any(|numeric| upper.contains(numeric))
+        && !upper.contains("INTERVAL")
+        && !upper.contains("POINT")
+}
+
+/// A result cell; NULL and a short row are both missing
+fn value_at(row: &[Option<String>], index: usize) -> Option<String> {
+    row.get(index).cloned().flatten()
+}
+
+/// Averages come back with many decimals; keep two
+fn format_average(avg: &str) -> String {
+    avg.parse::<f64>()
+        .map(|avg| format!("{avg:.2}"))
+        .unwrap_or_else(|_| avg.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_type_detection() {
+        assert!(is_numeric_type("INTEGER"));
+        assert!(is_numeric_type("bigint"));
+        assert!(is_numeric_type("DOUBLE PRECISION"));
+        assert!(is_numeric_type("decimal"));
+        assert!(!is_numeric_type("TEXT"));
+        assert!(!is_numeric_type("interval"));
+        assert!(!is_numeric_type("TIMESTAMP"));
+    }
+
+    #[test]
+    fn test_profile_summaries() {
+        let profile = ColumnProfile {
+            name: "age".to_string(),
+            count: 1200,
+            null_fraction: 0.032,
+            distinct: Some(50),
+            min: Some("1".to_string()),
+            max: Some("99".to_string()),
+            avg: Some(format_average("42.5000")),
+            top_values: vec!["30".to_string()],
+        };
+        assert_eq!(
+            profile.counts_summary(),
+            "count 1200 • null 3.2% • distinct 50"
+        );
+        assert_eq!(
+            profile.range_summary().as_deref(),
+            Some("min 1 • max 99 • avg 42.50")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_aggregates() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE t (score INTEGER)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO t VALUES (1), (3), (3), (NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let column = quote_identifier("score", &DatabaseType::SQLite);
+        let (count, nulls, distinct, min, max, avg): (i64, i64, i64, i64, i64, f64) =
+            sqlx::query_as(&aggregate_sql(&column, "t", true))
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!((count, nulls, distinct, min, max), (4, 1, 2, 1, 3));
+        assert!((avg - 7.0 / 3.0).abs() < 1e-9);
+
+        let top: Vec<(i64, i64)> = sqlx::query_as(&top_values_sql(&column, "t"))
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(top[0], (3, 2));
+    }
+}
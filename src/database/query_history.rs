@@ -176,6 +176,7 @@ impl QueryHistoryManager {
                 "mariadb" => DatabaseType::MariaDB,
                 "sqlite" => DatabaseType::SQLite,
                 "oracle" => DatabaseType::Oracle,
+                "mssql" => DatabaseType::MsSql,
                 "redis" => DatabaseType::Redis,
                 "mongodb" => DatabaseType::MongoDB,
                 _ => continue, // Skip unknown database types
@@ -261,6 +262,7 @@ impl QueryHistoryManager {
                 "mariadb" => DatabaseType::MariaDB,
                 "sqlite" => DatabaseType::SQLite,
                 "oracle" => DatabaseType::Oracle,
+                "mssql" => DatabaseType::MsSql,
                 "redis" => DatabaseType::Redis,
                 "mongodb" => DatabaseType::MongoDB,
                 _ => continue,
@@ -0,0 +1,916 @@
+// FilePath: src/database/redis.rs
+
+#![forbid(unsafe_code)]
+
+use crate::core::error::{LazyTablesError, Result};
+use crate::database::{
+    connection::ConnectionConfig, connection_manager::QueryCanceller, Connection, DataType,
+    TableColumn, TableMetadata,
+};
+use async_trait::async_trait;
+use redis::{aio::MultiplexedConnection, Value};
+
+/// Keys fetched per `SCAN` round trip
+const SCAN_COUNT: usize = 1000;
+
+/// Redis connection on top of the `redis` crate. Keys are listed as tables;
+/// opening one shows its value as rows, shaped by the key's type.
+pub struct RedisConnection {
+    config: ConnectionConfig,
+    connection: Option<MultiplexedConnection>,
+    /// Keys from the last full `SCAN`, handed out a page at a time
+    keys: std::sync::Mutex<Vec<String>>,
+}
+
+impl std::fmt::Debug for RedisConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisConnection")
+            .field("config", &self.config)
+            .field("connected", &self.connection.is_some())
+            .finish()
+    }
+}
+
+/// Value types a key can be opened as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyKind {
+    String,
+    List,
+    Set,
+    Hash,
+    SortedSet,
+}
+
+impl KeyKind {
+    /// Parse a `TYPE` reply
+    fn from_type(key: &str, type_name: &str) -> Result<Self> {
+        match type_name {
+            "string" => Ok(Self::String),
+            "list" => Ok(Self::List),
+            "set" => Ok(Self::Set),
+            "hash" => Ok(Self::Hash),
+            "zset" => Ok(Self::SortedSet),
+            "none" => Err(LazyTablesError::Other(format!(
+                "Key '{key}' does not exist"
+            ))),
+            other => Err(LazyTablesError::Unsupported(format!(
+                "Key '{key}' is a {other}, which can't be shown as rows"
+            ))),
+        }
+    }
+
+    /// Columns the value is shown in
+    fn columns(self) -> Vec<TableColumn> {
+        let names: &[(&str, DataType)] = match self {
+            Self::String => &[("value", DataType::Text)],
+            Self::List => &[("index", DataType::BigInt), ("value", DataType::Text)],
+            Self::Set => &[("member", DataType::Text)],
+            Self::Hash => &[("field", DataType::Text), ("value", DataType::Text)],
+            Self::SortedSet => &[("member", DataType::Text), ("score", DataType::Double)],
+        };
+        names
+            .iter()
+            .map(|(name, data_type)| TableColumn {
+                name: name.to_string(),
+                data_type: data_type.clone(),
+                is_nullable: false,
+                default_value: None,
+                is_primary_key: false,
+            })
+            .collect()
+    }
+
+    /// Command counting the rows the value has
+    fn length_command(self) -> &'static str {
+        match self {
+            Self::String => "EXISTS",
+            Self::List => "LLEN",
+            Self::Set => "SCARD",
+            Self::Hash => "HLEN",
+            Self::SortedSet => "ZCARD",
+        }
+    }
+}
+
+impl RedisConnection {
+    /// Create a new Redis connection instance
+    pub fn new(config: ConnectionConfig) -> Self {
+        Self {
+            config,
+            connection: None,
+            keys: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Handle to send commands on; the multiplexed connection is cheap to clone
+    fn connection(&self) -> Result<MultiplexedConnection> {
+        self.connection
+            .clone()
+            .ok_or_else(|| LazyTablesError::Connection("Not connected to database".to_string()))
+    }
+
+    /// Run one command and return its raw reply
+    async fn command(&self, cmd: &redis::Cmd) -> Result<Value> {
+        let mut connection = self.connection()?;
+        cmd.query_async(&mut connection)
+            .await
+            .map_err(command_error)
+    }
+
+    /// Run a command typed in the query editor, e.g. `HGETALL user:1`.
+    /// Arrays come back one element per row, maps as field/value rows.
+    pub async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        let reply = self.command(&parse_command(query)?).await?;
+        Ok(reply_rows(reply))
+    }
+
+    /// Run a command, reporting an integer reply as the rows affected
+    pub async fn execute_statement(&self, statement: &str) -> Result<u64> {
+        match self.command(&parse_command(statement)?).await? {
+            Value::Int(count) => Ok(u64::try_from(count).unwrap_or(0)),
+            _ => Ok(0),
+        }
+    }
+
+    /// Every key in the selected database, sorted; `SCAN` keeps the server
+    /// responsive where `KEYS *` would block it on a large keyspace
+    async fn scan_keys(&self) -> Result<Vec<String>> {
+        let mut connection = self.connection()?;
+        let mut keys = Vec::new();
+        let mut cursor: u64 = 0;
+        loop {
+            let (next, batch): (u64, Vec<Vec<u8>>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("COUNT")
+                .arg(SCAN_COUNT)
+                .query_async(&mut connection)
+                .await
+                .map_err(command_error)?;
+            keys.extend(
+                batch
+                    .into_iter()
+                    .map(|key| String::from_utf8_lossy(&key).into_owned()),
+            );
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+        keys.sort();
+        keys.dedup();
+        Ok(keys)
+    }
+
+    /// Key names as tables
+    pub async fn list_tables(&self) -> Result<Vec<String>> {
+        self.scan_keys().await
+    }
+
+    /// Keys one page at a time, with their type as the comment. The first
+    /// page rescans the keyspace; later pages come from that scan.
+    pub async fn list_database_objects_page(
+        &self,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        use crate::database::{DatabaseObject, DatabaseObjectList, DatabaseObjectType};
+
+        if offset == 0 {
+            let keys = self.scan_keys().await?;
+            if let Ok(mut cached) = self.keys.lock() {
+                *cached = keys;
+            }
+        }
+        let page: Vec<String> = self
+            .keys
+            .lock()
+            .map(|keys| {
+                keys.iter()
+                    .skip(offset)
+                    .take(limit.unwrap_or(usize::MAX))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let types: Vec<String> = if page.is_empty() {
+            Vec::new()
+        } else {
+            let mut pipe = redis::pipe();
+            for key in &page {
+                pipe.cmd("TYPE").arg(key);
+            }
+            let mut connection = self.connection()?;
+            pipe.query_async(&mut connection)
+                .await
+                .map_err(command_error)?
+        };
+
+        let mut result = DatabaseObjectList::default();
+        for (name, key_type) in page.into_iter().zip(types) {
+            result.tables.push(DatabaseObject {
+                name,
+                schema: None,
+                object_type: DatabaseObjectType::Table,
+                row_count: None,
+                size_bytes: None,
+                comment: Some(key_type),
+            });
+            result.total_count += 1;
+        }
+        Ok(result)
+    }
+
+    /// `TYPE key`, as a kind that can be shown
+    async fn key_kind(&self, key: &str) -> Result<KeyKind> {
+        let type_name: String = {
+            let mut connection = self.connection()?;
+            redis::cmd("TYPE")
+                .arg(key)
+                .query_async(&mut connection)
+                .await
+                .map_err(command_error)?
+        };
+        KeyKind::from_type(key, &type_name)
+    }
+
+    /// Columns for the key's type
+    pub async fn get_table_columns(&self, key: &str) -> Result<Vec<TableColumn>> {
+        Ok(self.key_kind(key).await?.columns())
+    }
+
+    /// Type, length, memory use and TTL of a key
+    pub async fn get_table_metadata(&self, key: &str) -> Result<TableMetadata> {
+        let kind = self.key_kind(key).await?;
+        let columns = kind.columns();
+        let length = match self
+            .command(redis::cmd(kind.length_command()).arg(key))
+            .await?
+        {
+            Value::Int(length) => usize::try_from(length).unwrap_or(0),
+            _ => 0,
+        };
+        // MEMORY USAGE needs Redis 4 and may be disabled
+        let size = match self
+            .command(redis::cmd("MEMORY").arg("USAGE").arg(key))
+            .await
+        {
+            Ok(Value::Int(bytes)) => bytes,
+            _ => 0,
+        };
+        let ttl = match self.command(redis::cmd("TTL").arg(key)).await? {
+            Value::Int(seconds) if seconds >= 0 => format!(", expires in {seconds}s"),
+            _ => String::new(),
+        };
+        let type_name = match kind {
+            KeyKind::String => "string",
+            KeyKind::List => "list",
+            KeyKind::Set => "set",
+            KeyKind::Hash => "hash",
+            KeyKind::SortedSet => "sorted set",
+        };
+
+        Ok(TableMetadata::basic(
+            key.to_string(),
+            length,
+            columns.len(),
+            size,
+            size,
+            0,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Some(format!("Redis {type_name}{ttl}")),
+        )
+        .with_columns(&columns))
+    }
+
+    /// One page of the key's value: `GET`, `LRANGE`, `SMEMBERS`, `HGETALL`
+    /// or `ZRANGE ... WITHSCORES` depending on its type. Sets and hashes are
+    /// sorted so pages stay stable.
+    pub async fn get_table_data(
+        &self,
+        key: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+        let stop = offset + limit - 1;
+        let rows = match self.key_kind(key).await? {
+            KeyKind::String => {
+                if offset > 0 {
+                    return Ok(Vec::new());
+                }
+                let value = self.command(redis::cmd("GET").arg(key)).await?;
+                vec![vec![value_cell(&value)]]
+            }
+            KeyKind::List => {
+                let reply = self
+                    .command(redis::cmd("LRANGE").arg(key).arg(offset).arg(stop))
+                    .await?;
+                reply_items(reply)
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| vec![Some((offset + i).to_string()), value_cell(value)])
+                    .collect()
+            }
+            KeyKind::Set => {
+                let reply = self.command(redis::cmd("SMEMBERS").arg(key)).await?;
+                let mut members: Vec<String> = reply_items(reply).iter().map(value_text).collect();
+                members.sort();
+                members
+                    .into_iter()
+                    .skip(offset)
+                    .take(limit)
+                    .map(|member| vec![Some(member)])
+                    .collect()
+            }
+            KeyKind::Hash => {
+                let reply = self.command(redis::cmd("HGETALL").arg(key)).await?;
+                let mut fields = reply_pairs(reply);
+                fields.sort();
+                fields
+                    .into_iter()
+                    .skip(offset)
+                    .take(limit)
+                    .map(|(field, value)| vec![Some(field), value])
+                    .collect()
+            }
+            KeyKind::SortedSet => {
+                let reply = self
+                    .command(
+                        redis::cmd("ZRANGE")
+                            .arg(key)
+                            .arg(offset)
+                            .arg(stop)
+                            .arg("WITHSCORES"),
+                    )
+                    .await?;
+                reply_pairs(reply)
+                    .into_iter()
+                    .map(|(member, score)| vec![Some(member), score])
+                    .collect()
+            }
+        };
+        Ok(rows)
+    }
+
+    /// `INFO server` as `field -> value`
+    async fn server_section(&self) -> Result<Vec<(String, String)>> {
+        let info = self.command(redis::cmd("INFO").arg("server")).await?;
+        Ok(value_text(&info)
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .map(|(field, value)| (field.to_string(), value.trim().to_string()))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl Connection for RedisConnection {
+    async fn connect(&mut self) -> Result<()> {
+        self.connect_with_key(None).await
+    }
+
+    async fn connect_with_key(&mut self, encryption_key: Option<&str>) -> Result<()> {
+        let config = self.config.with_env_expanded()?;
+        let password = self
+            .config
+            .resolve_password(encryption_key)
+            .ok()
+            .filter(|password| !password.is_empty());
+        let db = config
+            .database
+            .as_deref()
+            .map(str::trim)
+            .filter(|db| !db.is_empty())
+            .map(|db| {
+                db.parse::<i64>().map_err(|_| {
+                    LazyTablesError::Config(format!("Redis DB index '{db}' is not a number"))
+                })
+            })
+            .transpose()?
+            .unwrap_or(0);
+        let info = redis::ConnectionInfo {
+            addr: redis::ConnectionAddr::Tcp(config.host.trim().to_string(), config.port),
+            redis: redis::RedisConnectionInfo {
+                db,
+                username: Some(config.username.trim().to_string()).filter(|u| !u.is_empty()),
+                password,
+                protocol: redis::ProtocolVersion::RESP2,
+            },
+        };
+        let client = redis::Client::open(info)
+            .map_err(|e| LazyTablesError::Connection(format!("Invalid Redis settings: {e}")))?;
+        let timeout = std::time::Duration::from_secs(config.timeout.unwrap_or(30));
+
+        let connection = tokio::time::timeout(timeout, client.get_multiplexed_async_connection())
+            .await
+            .map_err(|_| {
+                LazyTablesError::Connection(format!(
+                    "Timed out connecting to Redis at {}:{}",
+                    config.host, config.port
+                ))
+            })?
+            .map_err(|e| LazyTablesError::Connection(format!("Failed to connect to Redis: {e}")))?;
+
+        self.connection = Some(connection);
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> Result<()> {
+        self.connection = None;
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connection.is_some()
+    }
+
+    fn config(&self) -> &ConnectionConfig {
+        &self.config
+    }
+
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        RedisConnection::execute_raw_query(self, query).await
+    }
+
+    async fn list_tables(&self) -> Result<Vec<String>> {
+        RedisConnection::list_tables(self).await
+    }
+
+    async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        self.list_database_objects_page(None, 0).await
+    }
+
+    async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
+        RedisConnection::get_table_metadata(self, table_name).await
+    }
+
+    async fn get_table_columns(&self, table_name: &str) -> Result<Vec<TableColumn>> {
+        RedisConnection::get_table_columns(self, table_name).await
+    }
+
+    async fn get_table_data(
+        &self,
+        table_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        RedisConnection::get_table_data(self, table_name, limit, offset).await
+    }
+
+    async fn get_database_capabilities(&self) -> Result<crate::database::DatabaseCapabilities> {
+        Ok(crate::database::DatabaseCapabilities {
+            supports_schemas: false,
+            supports_transactions: true, // MULTI/EXEC
+            supports_foreign_keys: false,
+            supports_json: false,
+            supports_arrays: false,
+            supports_stored_procedures: false,
+            supports_triggers: false,
+            supports_views: false,
+            supports_materialized_views: false,
+            supports_window_functions: false,
+            supports_cte: false,
+            max_identifier_length: 512 * 1024 * 1024, // Keys are binary-safe strings
+            max_query_length: None,
+            supported_isolation_levels: Vec::new(),
+        })
+    }
+
+    async fn health_check(&self) -> Result<crate::database::HealthStatus> {
+        let start = std::time::Instant::now();
+        let ping = self.command(&redis::cmd("PING")).await;
+        Ok(crate::database::HealthStatus {
+            is_healthy: ping.is_ok(),
+            response_time_ms: start.elapsed().as_millis() as u64,
+            last_error: ping.err().map(|e| e.to_string()),
+            database_version: None,
+            active_connections: u32::from(self.connection.is_some()),
+            max_connections: 1,
+            uptime_seconds: None,
+        })
+    }
+
+    async fn get_server_info(&self) -> Result<crate::database::ServerInfo> {
+        let server = self.server_section().await?;
+        let field = |name: &str| {
+            server
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value.clone())
+        };
+
+        Ok(crate::database::ServerInfo {
+            version: field("redis_version").unwrap_or_default(),
+            build_info: field("os"),
+            server_name: field("run_id"),
+            charset: None,
+            timezone: None,
+            uptime_seconds: field("uptime_in_seconds").and_then(|s| s.parse().ok()),
+            current_database: Some(
+                self.config
+                    .database
+                    .clone()
+                    .filter(|db| !db.trim().is_empty())
+                    .unwrap_or_else(|| "0".to_string()),
+            ),
+            current_user: Some(self.config.username.clone()).filter(|u| !u.is_empty()),
+        })
+    }
+
+    fn get_pool_status(&self) -> Option<crate::database::PoolStatus> {
+        self.connection
+            .as_ref()
+            .map(|_| crate::database::PoolStatus {
+                size: 1,
+                active: 1,
+                idle: 0,
+                waiting: 0,
+                max_size: 1,
+                min_size: 1,
+            })
+    }
+
+    fn max_connections(&self) -> u32 {
+        1 // One multiplexed connection
+    }
+
+    fn active_connections(&self) -> u32 {
+        u32::from(self.connection.is_some())
+    }
+
+    fn format_error(&self, error: &str) -> crate::database::FormattedError {
+        let error_code = error
+            .split_whitespace()
+            .find(|word| word.len() > 2 && word.chars().all(|c| c.is_ascii_uppercase()))
+            .map(str::to_string);
+        let mut recovery_suggestions = Vec::new();
+        let mut is_connection_error = false;
+        let mut is_syntax_error = false;
+        let mut is_permission_error = false;
+
+        let user_message = match error_code.as_deref() {
+            Some("NOAUTH") | Some("WRONGPASS") => {
+                is_permission_error = true;
+                recovery_suggestions.push("Check the connection's password".to_string());
+                "Authentication failed."
+            }
+            Some("NOPERM") => {
+                is_permission_error = true;
+                recovery_suggestions
+                    .push("The ACL user isn't allowed to run this command or key".to_string());
+                "Permission denied."
+            }
+            Some("WRONGTYPE") => {
+                recovery_suggestions.push("Check the key's type with TYPE key".to_string());
+                "Command doesn't match the key's type."
+            }
+            _ if error.contains("unknown command")
+                || error.contains("wrong number of arguments") =>
+            {
+                is_syntax_error = true;
+                recovery_suggestions
+                    .push("Write one command per statement, e.g. HGETALL user:1".to_string());
+                "Invalid Redis command."
+            }
+            _ if error.contains("Connection refused") || error.contains("timed out") => {
+                is_connection_error = true;
+                recovery_suggestions.push(format!(
+                    "Check that Redis listens on {}:{}",
+                    self.config.host, self.config.port
+                ));
+                "Cannot reach Redis."
+            }
+            _ => "Redis error occurred.",
+        };
+
+        crate::database::FormattedError {
+            original_error: error.to_string(),
+            user_message: user_message.to_string(),
+            error_code,
+            recovery_suggestions,
+            is_connection_error,
+            is_syntax_error,
+            is_permission_error,
+        }
+    }
+
+    fn get_keywords(&self) -> Vec<String> {
+        [
+            "GET",
+            "SET",
+            "MGET",
+            "MSET",
+            "DEL",
+            "EXISTS",
+            "EXPIRE",
+            "TTL",
+            "PERSIST",
+            "TYPE",
+            "RENAME",
+            "SCAN",
+            "KEYS",
+            "INCR",
+            "DECR",
+            "APPEND",
+            "STRLEN",
+            "LPUSH",
+            "RPUSH",
+            "LPOP",
+            "RPOP",
+            "LRANGE",
+            "LLEN",
+            "LINDEX",
+            "SADD",
+            "SREM",
+            "SMEMBERS",
+            "SISMEMBER",
+            "SCARD",
+            "HGET",
+            "HSET",
+            "HDEL",
+            "HGETALL",
+            "HKEYS",
+            "HVALS",
+            "HLEN",
+            "ZADD",
+            "ZREM",
+            "ZRANGE",
+            "ZSCORE",
+            "ZCARD",
+            "WITHSCORES",
+            "MULTI",
+            "EXEC",
+            "DISCARD",
+            "SELECT",
+            "INFO",
+            "PING",
+            "DBSIZE",
+            "MEMORY",
+            "OBJECT",
+        ]
+        .iter()
+        .map(|keyword| keyword.to_string())
+        .collect()
+    }
+
+    fn get_functions(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+#[async_trait]
+impl crate::database::connection_manager::ManagedConnection for RedisConnection {
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
+        RedisConnection::execute_raw_query(self, query).await
+    }
+
+    async fn execute_statement(&self, statement: &str) -> Result<u64> {
+        RedisConnection::execute_statement(self, statement).await
+    }
+
+    async fn get_table_data(
+        &self,
+        table_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Vec<Option<String>>>> {
+        RedisConnection::get_table_data(self, table_name, limit, offset).await
+    }
+
+    async fn get_table_columns(&self, table_name: &str) -> Result<Vec<TableColumn>> {
+        RedisConnection::get_table_columns(self, table_name).await
+    }
+
+    async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
+        RedisConnection::get_table_metadata(self, table_name).await
+    }
+
+    async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        self.list_database_objects_page(None, 0).await
+    }
+
+    async fn list_database_objects_page(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        RedisConnection::list_database_objects_page(self, Some(limit), offset).await
+    }
+
+    fn is_connected(&self) -> bool {
+        Connection::is_connected(self)
+    }
+
+    fn query_canceller(&self) -> QueryCanceller {
+        QueryCanceller::Abort
+    }
+}
+
+fn command_error(error: redis::RedisError) -> LazyTablesError {
+    LazyTablesError::Other(format!("Redis error: {error}"))
+}
+
+/// Split a command line into arguments; single and double quotes group
+/// words and `\` escapes the next character inside double quotes
+fn split_command(line: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match quote {
+            Some('"') if c == '\\' => {
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            None if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+    if in_arg {
+        args.push(current);
+    }
+    args
+}
+
+/// Build the command for a line typed in the query editor
+fn parse_command(line: &str) -> Result<redis::Cmd> {
+    let args = split_command(line.trim().trim_end_matches(';'));
+    let (name, rest) = args
+        .split_first()
+        .ok_or_else(|| LazyTablesError::InvalidInput("Empty Redis command".to_string()))?;
+    let mut cmd = redis::cmd(&name.to_uppercase());
+    for arg in rest {
+        cmd.arg(arg);
+    }
+    Ok(cmd)
+}
+
+/// Text for a reply: NULL for nil, `0x…` hex for bytes that aren't UTF-8,
+/// nested arrays joined with spaces
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::Nil => "NULL".to_string(),
+        Value::Int(n) => n.to_string(),
+        Value::BulkString(bytes) => match std::str::from_utf8(bytes) {
+            Ok(text) => text.to_string(),
+            Err(_) => {
+                let hex: String = bytes.iter().map(|b| format!("{b:02X}")).collect();
+                format!("0x{hex}")
+            }
+        },
+        Value::SimpleString(text) => text.clone(),
+        Value::Okay => "OK".to_string(),
+        Value::Double(n) => n.to_string(),
+        Value::Boolean(b) => b.to_string(),
+        Value::VerbatimString { text, .. } => text.clone(),
+        Value::Array(items) | Value::Set(items) => {
+            items.iter().map(value_text).collect::<Vec<_>>().join(" ")
+        }
+        Value::Map(pairs) => pairs
+            .iter()
+            .map(|(k, v)| format!("{}={}", value_text(k), value_text(v)))
+            .collect::<Vec<_>>()
+            .join(" "),
+        other => format!("{other:?}"),
+    }
+}
+
+/// A reply as a result cell; nil is a NULL cell
+fn value_cell(value: &Value) -> Option<String> {
+    match value {
+        Value::Nil => None,
+        other => Some(value_text(other)),
+    }
+}
+
+/// Elements of an array or set reply; anything else is a single element
+fn reply_items(reply: Value) -> Vec<Value> {
+    match reply {
+        Value::Array(items) | Value::Set(items) => items,
+        other => vec![other],
+    }
+}
+
+/// Field/value pairs from a map reply or a flat RESP2 array of them
+fn reply_pairs(reply: Value) -> Vec<(String, Option<String>)> {
+    match reply {
+        Value::Map(pairs) => pairs
+            .iter()
+            .map(|(k, v)| (value_text(k), value_cell(v)))
+            .collect(),
+        other => reply_items(other)
+            .chunks(2)
+            .map(|pair| (value_text(&pair[0]), pair.get(1).and_then(value_cell)))
+            .collect(),
+    }
+}
+
+/// Columns and rows for a command reply
+fn reply_rows(reply: Value) -> (Vec<String>, Vec<Vec<Option<String>>>) {
+    match reply {
+        Value::Map(_) => (
+            vec!["field".to_string(), "value".to_string()],
+            reply_pairs(reply)
+                .into_iter()
+                .map(|(field, value)| vec![Some(field), value])
+                .collect(),
+        ),
+        Value::Array(_) | Value::Set(_) => (
+            vec!["value".to_string()],
+            reply_items(reply)
+                .iter()
+                .map(|value| vec![value_cell(value)])
+                .collect(),
+        ),
+        scalar => (vec!["value".to_string()], vec![vec![value_cell(&scalar)]]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_command() {
+        assert_eq!(
+            split_command(r#"HSET user:1 name "Ada \"L\"" note 'a b' empty """#),
+            vec![
+                "HSET",
+                "user:1",
+                "name",
+                "Ada \"L\"",
+                "note",
+                "a b",
+                "empty",
+                ""
+            ]
+        );
+        assert!(split_command("   ").is_empty());
+        assert!(parse_command(" ; ").is_err());
+    }
+
+    #[test]
+    fn test_reply_rows() {
+        let reply = Value::Array(vec![
+            Value::BulkString(b"name".to_vec()),
+            Value::BulkString(vec![0xff, 0x01]),
+            Value::Nil,
+        ]);
+        assert_eq!(
+            reply_rows(reply.clone()),
+            (
+                vec!["value".to_string()],
+                vec![
+                    vec![Some("name".to_string())],
+                    vec![Some("0xFF01".to_string())],
+                    vec![None],
+                ]
+            )
+        );
+        assert_eq!(
+            reply_pairs(reply),
+            vec![
+                ("name".to_string(), Some("0xFF01".to_string())),
+                ("NULL".to_string(), None),
+            ]
+        );
+        assert_eq!(
+            reply_rows(Value::Int(3)),
+            (vec!["value".to_string()], vec![vec![Some("3".to_string())]])
+        );
+        assert!(KeyKind::from_type("k", "stream").is_err());
+        assert_eq!(KeyKind::from_type("k", "zset").unwrap().columns().len(), 2);
+    }
+}
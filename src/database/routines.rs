@@ -0,0 +1,119 @@
+// FilePath: src/database/routines.rs
+
+#![forbid(unsafe_code)]
+
+use crate::database::ddl::quote_identifier;
+use crate::database::DatabaseType;
+
+/// Stored functions and procedures outside the system schemas. PostgreSQL
+/// encodes the function OID in `specific_name` (`name_1234`), which links the
+/// `information_schema` row back to `pg_proc`.
+pub const LIST_ROUTINES_SQL: &str = "\
+SELECT r.routine_schema, r.routine_name, r.routine_type, \
+COALESCE(r.data_type, ''), pg_get_function_identity_arguments(p.oid), p.oid::text \
+FROM information_schema.routines r \
+JOIN pg_proc p ON p.oid = substring(r.specific_name from '_([0-9]+)$')::oid \
+WHERE r.routine_schema NOT IN ('pg_catalog', 'information_schema') \
+ORDER BY r.routine_schema, r.routine_name";
+
+/// A stored function or procedure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutineInfo {
+    pub schema: String,
+    pub name: String,
+    /// `FUNCTION` or `PROCEDURE`
+    pub kind: String,
+    /// Declared return type; empty for procedures
+    pub return_type: String,
+    /// Identity argument list, e.g. `a integer, b text`
+    pub arguments: String,
+    pub oid: String,
+}
+
+impl RoutineInfo {
+    /// Build routines from the rows of [`LIST_ROUTINES_SQL`]; NULL reads as empty
+    pub fn from_rows(rows: &[Vec<Option<String>>]) -> Vec<Self> {
+        rows.iter()
+            .filter(|row| row.len() >= 6)
+            .map(|row| {
+                let text = |idx: usize| row[idx].clone().unwrap_or_default();
+                Self {
+                    schema: text(0),
+                    name: text(1),
+                    kind: text(2),
+                    return_type: text(3),
+                    arguments: text(4),
+                    oid: text(5),
+                }
+            })
+            .collect()
+    }
+
+    /// Schema-qualified name with arguments, as shown in lists and tab titles
+    pub fn display_name(&self) -> String {
+        format!("{}.{}({})", self.schema, self.name, self.arguments)
+    }
+
+    /// Query returning the `CREATE OR REPLACE` source of the routine
+    pub fn definition_query(&self) -> String {
+        format!("SELECT pg_get_functiondef({}::oid)", self.oid)
+    }
+
+    /// `DROP FUNCTION` (or `DROP PROCEDURE`) for this exact overload
+    pub fn drop_statement(&self, database_type: &DatabaseType) -> String {
+        let kind = if self.kind.eq_ignore_ascii_case("PROCEDURE") {
+            "PROCEDURE"
+        } else {
+            "FUNCTION"
+        };
+        format!(
+            "DROP {kind} {}.{}({})",
+            quote_identifier(&self.schema, database_type),
+            quote_identifier(&self.name, database_type),
+            self.arguments
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(values: &[&str]) -> Vec<Option<String>> {
+        values.iter().map(|v| Some(v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_from_rows_and_statements() {
+        let routines = RoutineInfo::from_rows(&[
+            row(&[
+                "public",
+                "add",
+                "FUNCTION",
+                "integer",
+                "a integer, b integer",
+                "16384",
+            ]),
+            row(&["billing", "Close\"Month", "PROCEDURE", "", "", "16390"]),
+            row(&["short"]),
+        ]);
+        assert_eq!(routines.len(), 2);
+
+        assert_eq!(
+            routines[0].display_name(),
+            "public.add(a integer, b integer)"
+        );
+        assert_eq!(
+            routines[0].definition_query(),
+            "SELECT pg_get_functiondef(16384::oid)"
+        );
+        assert_eq!(
+            routines[0].drop_statement(&DatabaseType::PostgreSQL),
+            "DROP FUNCTION \"public\".\"add\"(a integer, b integer)"
+        );
+        assert_eq!(
+            routines[1].drop_statement(&DatabaseType::PostgreSQL),
+            "DROP PROCEDURE \"billing\".\"Close\"\"Month\"()"
+        );
+    }
+}
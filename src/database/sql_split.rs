@@ -0,0 +1,270 @@
+// FilePath: src/database/sql_split.rs
+
+#![forbid(unsafe_code)]
+
+/// Default statement separator
+pub const DEFAULT_SEPARATOR: &str = ";";
+
+/// One statement cut out of a script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlStatement {
+    /// Statement text without the separator, trimmed
+    pub text: String,
+    /// 0-based line of the script the statement starts on
+    pub line: usize,
+}
+
+/// Split a script into statements on `separator`, ignoring separators inside
+/// string literals, quoted identifiers, comments and dollar-quoted bodies.
+///
+/// A separator made of letters and digits, like `GO`, only counts when it is
+/// alone on its line. Pieces holding nothing but comments are dropped.
+pub fn split_statements(sql: &str, separator: &str) -> Vec<SqlStatement> {
+    let separator = match separator.trim() {
+        "" => DEFAULT_SEPARATOR,
+        separator => separator,
+    };
+    let keyword = separator.chars().all(|c| c.is_alphanumeric() || c == '_');
+    let bytes = sql.as_bytes();
+
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut has_code = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if keyword && (i == 0 || bytes[i - 1] == b'\n') {
+            let line_end = sql[i..].find('\n').map_or(sql.len(), |end| i + end);
+            if sql[i..line_end].trim().eq_ignore_ascii_case(separator) {
+                push_statement(&mut statements, sql, start..i, has_code);
+                i = (line_end + 1).min(sql.len());
+                start = i;
+                has_code = false;
+                continue;
+            }
+        }
+
+        let c = bytes[i];
+        let next = bytes.get(i + 1).copied();
+        if !keyword && bytes[i..].starts_with(separator.as_bytes()) {
+            push_statement(&mut statements, sql, start..i, has_code);
+            i += separator.len();
+            start = i;
+            has_code = false;
+        } else if c == b'-' && next == Some(b'-') {
+            i = sql[i..].find('\n').map_or(sql.len(), |end| i + end);
+        } else if c == b'/' && next == Some(b'*') {
+            i = skip_block_comment(bytes, i);
+        } else if matches!(c, b'\'' | b'"' | b'`') {
+            i = skip_quoted(bytes, i + 1, c);
+            has_code = true;
+        } else if let Some(tag) = dollar_tag(sql, i) {
+            let body = i + tag.len();
+            i = sql[body..]
+                .find(tag)
+                .map_or(sql.len(), |end| body + end + tag.len());
+            has_code = true;
+        } else {
+            has_code |= !c.is_ascii_whitespace();
+            i += 1;
+        }
+    }
+    push_statement(&mut statements, sql, start..sql.len(), has_code);
+
+    statements
+}
+
+fn push_statement(
+    statements: &mut Vec<SqlStatement>,
+    sql: &str,
+    range: std::ops::Range<usize>,
+    has_code: bool,
+) {
+    if !has_code {
+        return;
+    }
+    let piece = &sql[range.clone()];
+    let leading = piece.len() - piece.trim_start().len();
+    statements.push(SqlStatement {
+        text: piece.trim().to_string(),
+        line: sql[..range.start + leading].matches('\n').count(),
+    });
+}
+
+/// Skip past the closing `quote`, honouring doubled quotes and, in string
+/// literals, backslash escapes
+fn skip_quoted(bytes: &[u8], mut i: usize, quote: u8) -> usize {
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if quote == b'\'' => i += 2,
+            c if c == quote => {
+                if bytes.get(i + 1) == Some(&quote) {
+                    i += 2;
+                } else {
+                    return i + 1;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Skip a possibly nested `/* */` comment starting at `i`
+fn skip_block_comment(bytes: &[u8], mut i: usize) -> usize {
+    let mut depth = 0;
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"/*") {
+            depth += 1;
+            i += 2;
+        } else if bytes[i..].starts_with(b"*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    bytes.len()
+}
+
+/// `$$` or `$tag$` opening a dollar-quoted body at `i`. `$1` parameters and
+/// `$` inside identifiers don't count.
+fn dollar_tag(sql: &str, i: usize) -> Option<&str> {
+    let bytes = sql.as_bytes();
+    if bytes[i] != b'$' {
+        return None;
+    }
+    if i > 0 && (bytes[i - 1].is_ascii_alphanumeric() || matches!(bytes[i - 1], b'_' | b'$')) {
+        return None;
+    }
+    let mut end = i + 1;
+    while end < bytes.len() && (bytes[end].is_ascii_alphanumeric() || bytes[end] == b'_') {
+        end += 1;
+    }
+    let tag_is_valid = end == i + 1 || !bytes[i + 1].is_ascii_digit();
+    (end < bytes.len() && bytes[end] == b'$' && tag_is_valid).then(|| &sql[i..=end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(sql: &str, separator: &str) -> Vec<String> {
+        split_statements(sql, separator)
+            .into_iter()
+            .map(|statement| statement.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_split_plain_statements() {
+        assert_eq!(
+            texts("SELECT 1;\nSELECT 2;\n\n  SELECT 3", ";"),
+            vec!["SELECT 1", "SELECT 2", "SELECT 3"]
+        );
+        // Empty pieces between separators are dropped
+        assert_eq!(texts(";; SELECT 1 ;;\n;", ";"), vec!["SELECT 1"]);
+        assert!(texts("", ";").is_empty());
+        assert!(texts("  \n ; \n", ";").is_empty());
+    }
+
+    #[test]
+    fn test_split_ignores_separators_in_literals() {
+        assert_eq!(
+            texts(
+                "INSERT INTO t VALUES (';'); SELECT 'it''s; fine'; SELECT 2",
+                ";"
+            ),
+            vec![
+                "INSERT INTO t VALUES (';')",
+                "SELECT 'it''s; fine'",
+                "SELECT 2"
+            ]
+        );
+        assert_eq!(
+            texts(r"SELECT 'don\'t; stop'; SELECT 2", ";"),
+            vec![r"SELECT 'don\'t; stop'", "SELECT 2"]
+        );
+        assert_eq!(
+            texts("SELECT \"a;b\", `c;d` FROM t; SELECT 2", ";"),
+            vec!["SELECT \"a;b\", `c;d` FROM t", "SELECT 2"]
+        );
+        // An unterminated literal runs to the end of the script
+        assert_eq!(
+            texts("SELECT 'open; SELECT 2", ";"),
+            vec!["SELECT 'open; SELECT 2"]
+        );
+    }
+
+    #[test]
+    fn test_split_ignores_separators_in_comments() {
+        assert_eq!(
+            texts(
+                "SELECT 1; -- trailing; comment\nSELECT /* a; /* nested; */ b; */ 2;",
+                ";"
+            ),
+            vec![
+                "SELECT 1",
+                "-- trailing; comment\nSELECT /* a; /* nested; */ b; */ 2"
+            ]
+        );
+        // A piece holding only comments is not a statement
+        assert_eq!(
+            texts("SELECT 1;\n-- the end;\n/* really; */", ";"),
+            vec!["SELECT 1"]
+        );
+    }
+
+    #[test]
+    fn test_split_dollar_quoted_bodies() {
+        let sql = "CREATE FUNCTION f() RETURNS int AS $$\n\
+                   BEGIN\n  PERFORM 1;\n  RETURN 2;\nEND;\n\
+                   $$ LANGUAGE plpgsql;\n\
+                   DO $body$ BEGIN RAISE NOTICE '$$;'; END $body$;\n\
+                   SELECT $1, a$b FROM t WHERE x = $2;";
+        let statements = texts(sql, ";");
+        assert_eq!(statements.len(), 3);
+        assert!(statements[0].starts_with("CREATE FUNCTION"));
+        assert!(statements[0].ends_with("$$ LANGUAGE plpgsql"));
+        assert_eq!(
+            statements[1],
+            "DO $body$ BEGIN RAISE NOTICE '$$;'; END $body$"
+        );
+        assert_eq!(statements[2], "SELECT $1, a$b FROM t WHERE x = $2");
+    }
+
+    #[test]
+    fn test_split_custom_separators() {
+        assert_eq!(
+            texts("SELECT 1;\nSELECT 2\ngo\nSELECT 'GO'\n  GO  \n", "GO"),
+            vec!["SELECT 1;\nSELECT 2", "SELECT 'GO'"]
+        );
+        // Keyword separators only count alone on a line
+        assert_eq!(
+            texts("SELECT go FROM t\nGO", "GO"),
+            vec!["SELECT go FROM t"]
+        );
+        assert_eq!(
+            texts("SELECT 1; SELECT 2 // SELECT '//'", "//"),
+            vec!["SELECT 1; SELECT 2", "SELECT '//'"]
+        );
+        assert_eq!(
+            texts("SELECT 1; SELECT 2", " "),
+            vec!["SELECT 1", "SELECT 2"]
+        );
+        assert_eq!(
+            texts("SELECT 'ünï'; SELECT 2", ";"),
+            vec!["SELECT 'ünï'", "SELECT 2"]
+        );
+    }
+
+    #[test]
+    fn test_split_reports_start_lines() {
+        let statements = split_statements("\n-- setup\nSELECT 1;\n\n  SELECT\n 2;", ";");
+        assert_eq!(statements[0].line, 1);
+        assert_eq!(statements[1].line, 4);
+    }
+}
@@ -4,7 +4,8 @@
 
 use crate::core::error::{LazyTablesError, Result};
 use crate::database::{
-    connection::ConnectionConfig, Connection, DataType, TableColumn, TableMetadata,
+    connection::{init_sql_error, ConnectionConfig},
+    Connection, DataType, TableColumn, TableMetadata,
 };
 use async_trait::async_trait;
 use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
@@ -25,18 +26,24 @@ impl SqliteConnection {
     }
 
     /// Build SQLite connection string
-    fn build_connection_string(&self) -> String {
+    fn build_connection_string(&self) -> Result<String> {
         // For SQLite, we use the database field as the file path
-        let db_path = self.config.database.as_deref().unwrap_or(":memory:");
+        let config = self.config.with_env_expanded()?;
+        let db_path = match config.database.as_deref() {
+            None | Some(":memory:") => ":memory:".to_string(),
+            Some(path) => crate::ui::components::expand_tilde(path)
+                .display()
+                .to_string(),
+        };
 
         // Ensure the path exists if it's not in-memory
         if db_path != ":memory:" {
-            if let Some(parent) = Path::new(db_path).parent() {
+            if let Some(parent) = Path::new(&db_path).parent() {
                 let _ = std::fs::create_dir_all(parent);
             }
         }
 
-        format!("sqlite://{db_path}")
+        Ok(format!("sqlite://{db_path}"))
     }
 
     /// Parse SQLx error into structured ConnectionError with helpful suggestions
@@ -136,10 +143,25 @@ impl Connection for SqliteConnection {
 
     async fn connect_with_key(&mut self, _encryption_key: Option<&str>) -> Result<()> {
         // SQLite doesn't use passwords, so ignore encryption_key
-        let connection_string = self.build_connection_string();
+        let connection_string = self.build_connection_string()?;
+        let session_sql = std::sync::Arc::new(self.config.session_sql());
 
         let pool = SqlitePoolOptions::new()
             .max_connections(1) // SQLite works best with single connection
+            .after_connect(move |conn, _| {
+                // Every pooled session gets the default schema and init SQL
+                let session_sql = std::sync::Arc::clone(&session_sql);
+                Box::pin(async move {
+                    for statement in session_sql.iter() {
+                        sqlx::Executor::execute(&mut *conn, statement.as_str())
+                            .await
+                            .map_err(|e| {
+                                sqlx::Error::Configuration(init_sql_error(statement, e).into())
+                            })?;
+                    }
+                    Ok(())
+                })
+            })
             .connect(&connection_string)
             .await
             .map_err(|e| {
@@ -171,7 +193,10 @@ impl Connection for SqliteConnection {
     }
 
     // Query execution capabilities (AC1 requirement)
-    async fn execute_raw_query(&self, query: &str) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
         SqliteConnection::execute_raw_query(self, query).await
     }
 
@@ -200,7 +225,7 @@ impl Connection for SqliteConnection {
         table_name: &str,
         limit: usize,
         offset: usize,
-    ) -> Result<Vec<Vec<String>>> {
+    ) -> Result<Vec<Vec<Option<String>>>> {
         SqliteConnection::get_table_data(self, table_name, limit, offset).await
     }
 
@@ -475,6 +500,15 @@ impl SqliteConnection {
 
     /// List all database objects (tables, views) with metadata
     pub async fn list_database_objects(&self) -> Result<crate::database::DatabaseObjectList> {
+        self.list_database_objects_page(None, 0).await
+    }
+
+    /// List database objects one page at a time; `None` lists everything
+    pub async fn list_database_objects_page(
+        &self,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
         use crate::database::{DatabaseObject, DatabaseObjectList, DatabaseObjectType};
 
         if let Some(pool) = &self.pool {
@@ -492,7 +526,12 @@ impl SqliteConnection {
                 ORDER BY type, name
             ";
 
-            match sqlx::query(query).fetch_all(pool).await {
+            let query = format!(
+                "{query} {}",
+                crate::database::objects::page_clause(limit, offset)
+            );
+
+            match sqlx::query(&query).fetch_all(pool).await {
                 Ok(rows) => {
                     for row in rows {
                         let name: String = row.get("name");
@@ -576,6 +615,8 @@ impl SqliteConnection {
     /// Get metadata for a specific table
     pub async fn get_table_metadata(&self, table_name: &str) -> Result<TableMetadata> {
         if let Some(pool) = &self.pool {
+            let columns = self.get_table_columns(table_name).await.unwrap_or_default();
+
             // Validate and escape table name
             let safe_name = validate_sqlite_identifier(table_name)?;
 
@@ -624,6 +665,15 @@ impl SqliteConnection {
                 .map(|row| row.get::<String, _>("name"))
                 .collect();
 
+            // Views keep their CREATE VIEW statement in sqlite_master
+            let view_definition: Option<String> =
+                sqlx::query("SELECT sql FROM sqlite_master WHERE type = 'view' AND name = ?")
+                    .bind(table_name)
+                    .fetch_optional(pool)
+                    .await?
+                    .map(|row| row.get("sql"));
+            let view_type = view_definition.as_ref().map(|_| "VIEW");
+
             // SQLite doesn't track table size in the same way
             // We can estimate based on page count
             let page_count_query =
@@ -652,7 +702,9 @@ impl SqliteConnection {
                 foreign_keys,
                 indexes,
                 None,
-            ))
+            )
+            .with_columns(&columns)
+            .with_view_type(view_type, view_definition))
         } else {
             Err(LazyTablesError::Connection(
                 "Not connected to database".to_string(),
@@ -718,7 +770,7 @@ impl SqliteConnection {
         table_name: &str,
         limit: usize,
         offset: usize,
-    ) -> Result<Vec<Vec<String>>> {
+    ) -> Result<Vec<Vec<Option<String>>>> {
         if let Some(pool) = &self.pool {
             // Validate and escape table name
             let safe_table_name = validate_sqlite_identifier(table_name)?;
@@ -754,9 +806,7 @@ impl SqliteConnection {
             for row in rows {
                 let mut row_data = Vec::new();
                 for (idx, _col_name) in column_names.iter().enumerate() {
-                    // Try to get the value as string, handle NULL values
-                    let value: Option<String> = row.try_get(idx).ok();
-                    row_data.push(value.unwrap_or_else(|| "NULL".to_string()));
+                    row_data.push(cell_text(&row, idx));
                 }
                 result.push(row_data);
             }
@@ -770,7 +820,10 @@ impl SqliteConnection {
     }
 
     /// Execute a raw SQL query and return columns and rows
-    pub async fn execute_raw_query(&self, query: &str) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    pub async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
         if let Some(pool) = &self.pool {
             // Try to execute the query
             let rows = sqlx::query(query).fetch_all(pool).await?;
@@ -791,9 +844,7 @@ impl SqliteConnection {
             for row in &rows {
                 let mut row_data = Vec::new();
                 for col in columns {
-                    // Try to get value as string
-                    let value: Option<String> = row.try_get(col.ordinal()).ok();
-                    row_data.push(value.unwrap_or_else(|| "NULL".to_string()));
+                    row_data.push(cell_text(row, col.ordinal()));
                 }
                 result_rows.push(row_data);
             }
@@ -807,6 +858,19 @@ impl SqliteConnection {
     }
 }
 
+/// Cell text for the viewer: `None` for nulls and for values that are not
+/// text, binary values as a `0x...` literal
+fn cell_text(row: &sqlx::sqlite::SqliteRow, idx: usize) -> Option<String> {
+    match row.try_get::<Option<String>, _>(idx) {
+        Ok(text) => text,
+        Err(_) => row
+            .try_get::<Option<Vec<u8>>, _>(idx)
+            .ok()
+            .flatten()
+            .map(|bytes| crate::database::hex_literal(&bytes)),
+    }
+}
+
 /// Validate and escape SQLite identifiers to prevent SQL injection
 /// SQLite allows double quotes or brackets for identifiers
 fn validate_sqlite_identifier(name: &str) -> Result<String> {
@@ -859,16 +923,28 @@ fn parse_sqlite_type(type_str: &str) -> DataType {
 /// Implement ManagedConnection trait for SqliteConnection to work with ConnectionManager
 #[async_trait::async_trait]
 impl crate::database::connection_manager::ManagedConnection for SqliteConnection {
-    async fn execute_raw_query(&self, query: &str) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    async fn execute_raw_query(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>)> {
         SqliteConnection::execute_raw_query(self, query).await
     }
 
+    async fn execute_statement(&self, statement: &str) -> Result<u64> {
+        let Some(pool) = &self.pool else {
+            return Err(LazyTablesError::Connection(
+                "Not connected to database".to_string(),
+            ));
+        };
+        Ok(sqlx::query(statement).execute(pool).await?.rows_affected())
+    }
+
     async fn get_table_data(
         &self,
         table_name: &str,
         limit: usize,
         offset: usize,
-    ) -> Result<Vec<Vec<String>>> {
+    ) -> Result<Vec<Vec<Option<String>>>> {
         SqliteConnection::get_table_data(self, table_name, limit, offset).await
     }
 
@@ -887,6 +963,14 @@ impl crate::database::connection_manager::ManagedConnection for SqliteConnection
         SqliteConnection::list_database_objects(self).await
     }
 
+    async fn list_database_objects_page(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<crate::database::DatabaseObjectList> {
+        SqliteConnection::list_database_objects_page(self, Some(limit), offset).await
+    }
+
     fn is_connected(&self) -> bool {
         Connection::is_connected(self)
     }
@@ -894,3 +978,52 @@ impl crate::database::connection_manager::ManagedConnection for SqliteConnection
 
 // Drop implementation removed - connection pools are closed explicitly via disconnect() method
 // to avoid spawning background tasks that may not complete before app shutdown
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::DatabaseType;
+
+    #[tokio::test]
+    async fn test_mixed_case_table_with_reserved_word_column() {
+        let mut config = ConnectionConfig::new(
+            "local".to_string(),
+            DatabaseType::SQLite,
+            String::new(),
+            0,
+            String::new(),
+        );
+        config.database = Some(":memory:".to_string());
+        let mut connection = SqliteConnection::new(config);
+        connection.connect().await.unwrap();
+        connection
+            .execute_raw_query(
+                "CREATE TABLE \"Order Items\" (id TEXT PRIMARY KEY, \"select\" TEXT)",
+            )
+            .await
+            .unwrap();
+        connection
+            .execute_raw_query("INSERT INTO \"Order Items\" VALUES ('1', 'a'), ('2', 'b')")
+            .await
+            .unwrap();
+
+        let columns = connection.get_table_columns("Order Items").await.unwrap();
+        assert_eq!(columns[1].name, "select");
+        assert!(columns[0].is_primary_key);
+        let rows = connection
+            .get_table_data("Order Items", 1, 1)
+            .await
+            .unwrap();
+        assert_eq!(
+            rows,
+            vec![vec![Some("2".to_string()), Some("b".to_string())]]
+        );
+        assert_eq!(
+            connection.get_table_row_count("Order Items").await.unwrap(),
+            2
+        );
+        let metadata = connection.get_table_metadata("Order Items").await.unwrap();
+        assert_eq!(metadata.row_count, 2);
+        assert_eq!(metadata.primary_keys, vec!["id".to_string()]);
+    }
+}
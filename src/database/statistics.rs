@@ -0,0 +1,124 @@
+// FilePath: src/database/statistics.rs
+
+#![forbid(unsafe_code)]
+
+/// Dead rows above this share of all rows are flagged as vacuum debt
+pub const DEAD_ROW_WARNING_RATIO: f64 = 0.2;
+
+/// Sequential scans only matter once a table has this many rows
+pub const SEQ_SCAN_WARNING_ROWS: i64 = 1_000;
+
+/// Usage counters of a single index
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexUsage {
+    pub name: String,
+    /// Index scans (PostgreSQL) or rows selected through the index (MySQL `sys`)
+    pub scans: Option<i64>,
+    pub size_bytes: Option<i64>,
+    /// Unique and primary key indexes enforce constraints even when never scanned
+    pub is_unique: bool,
+}
+
+impl IndexUsage {
+    /// Never used for reads and not backing a constraint, so a candidate to drop
+    pub fn is_unused(&self) -> bool {
+        self.scans == Some(0) && !self.is_unique
+    }
+}
+
+/// Per-table access and maintenance counters, shown in the Details pane with `p`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableStatistics {
+    pub table_name: String,
+    /// Where the numbers come from, e.g. `pg_stat_user_tables`
+    pub source: &'static str,
+    pub seq_scans: Option<i64>,
+    /// Rows read by sequential (full table) scans
+    pub seq_rows_read: Option<i64>,
+    pub index_scans: Option<i64>,
+    pub live_rows: Option<i64>,
+    pub dead_rows: Option<i64>,
+    pub rows_inserted: Option<i64>,
+    pub rows_updated: Option<i64>,
+    pub rows_deleted: Option<i64>,
+    pub last_vacuum: Option<String>,
+    pub last_analyze: Option<String>,
+    pub indexes: Vec<IndexUsage>,
+    /// Why some counters are missing, e.g. no `sys` schema on MySQL
+    pub note: Option<String>,
+}
+
+impl TableStatistics {
+    /// Share of dead rows among live and dead rows
+    pub fn dead_row_ratio(&self) -> Option<f64> {
+        let dead = self.dead_rows?;
+        let total = dead + self.live_rows.unwrap_or(0);
+        (total > 0).then(|| dead as f64 / total as f64)
+    }
+
+    /// Dead rows pile up faster than autovacuum clears them
+    pub fn has_vacuum_debt(&self) -> bool {
+        self.dead_row_ratio()
+            .is_some_and(|ratio| ratio > DEAD_ROW_WARNING_RATIO)
+    }
+
+    /// A non-trivial table is read by sequential scans more often than through indexes
+    pub fn is_seq_scan_heavy(&self) -> bool {
+        match (self.seq_scans, self.index_scans) {
+            (Some(seq), index) => {
+                seq > index.unwrap_or(0) && self.live_rows.unwrap_or(0) >= SEQ_SCAN_WARNING_ROWS
+            }
+            _ => false,
+        }
+    }
+
+    /// Indexes that look safe to drop
+    pub fn unused_indexes(&self) -> impl Iterator<Item = &IndexUsage> {
+        self.indexes.iter().filter(|index| index.is_unused())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(scans: Option<i64>, is_unique: bool) -> IndexUsage {
+        IndexUsage {
+            name: "idx".to_string(),
+            scans,
+            size_bytes: Some(8192),
+            is_unique,
+        }
+    }
+
+    #[test]
+    fn test_unused_indexes_skip_constraints_and_unknown_counts() {
+        assert!(index(Some(0), false).is_unused());
+        assert!(!index(Some(0), true).is_unused());
+        assert!(!index(Some(3), false).is_unused());
+        assert!(!index(None, false).is_unused());
+    }
+
+    #[test]
+    fn test_warning_conditions() {
+        let mut stats = TableStatistics {
+            live_rows: Some(5_000),
+            dead_rows: Some(2_000),
+            seq_scans: Some(40),
+            index_scans: Some(10),
+            ..Default::default()
+        };
+        assert!(stats.has_vacuum_debt());
+        assert!(stats.is_seq_scan_heavy());
+
+        stats.dead_rows = Some(10);
+        stats.index_scans = Some(400);
+        assert!(!stats.has_vacuum_debt());
+        assert!(!stats.is_seq_scan_heavy());
+
+        // Small tables are cheap to scan sequentially
+        stats.live_rows = Some(20);
+        stats.index_scans = Some(0);
+        assert!(!stats.is_seq_scan_heavy());
+    }
+}
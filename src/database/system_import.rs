@@ -0,0 +1,286 @@
+// FilePath: src/database/system_import.rs
+
+#![forbid(unsafe_code)]
+
+use crate::database::{ConnectionConfig, DatabaseType};
+use crate::security::PasswordSource;
+use std::path::{Path, PathBuf};
+
+/// Parses one config file's contents into connections
+type EntryParser = fn(&str) -> Vec<ConnectionConfig>;
+
+/// A connection found in a system credentials file
+#[derive(Debug, Clone)]
+pub struct ImportCandidate {
+    /// File the entry was read from, for display
+    pub source: String,
+    pub config: ConnectionConfig,
+}
+
+/// Scan `~/.pgpass`, `~/.my.cnf` and `~/.config/lazytables/known_hosts`,
+/// dropping entries that duplicate an existing connection
+pub fn discover(existing: &[ConnectionConfig]) -> Vec<ImportCandidate> {
+    let Some(home) = dirs::home_dir() else {
+        return Vec::new();
+    };
+
+    let sources: [(PathBuf, EntryParser); 3] = [
+        (home.join(".pgpass"), parse_pgpass),
+        (home.join(".my.cnf"), parse_my_cnf),
+        (known_hosts_path(), parse_known_hosts),
+    ];
+
+    let mut candidates: Vec<ImportCandidate> = Vec::new();
+    for (path, parse) in sources {
+        for config in read_entries(&path, parse) {
+            let duplicate = existing
+                .iter()
+                .chain(candidates.iter().map(|c| &c.config))
+                .any(|other| duplicate_key(other) == duplicate_key(&config));
+            if !duplicate {
+                candidates.push(ImportCandidate {
+                    source: display_path(&path, &home),
+                    config,
+                });
+            }
+        }
+    }
+    candidates
+}
+
+/// SQLite paths listed one per line - ~/.config/lazytables/known_hosts
+pub fn known_hosts_path() -> PathBuf {
+    crate::config::Config::default_path()
+        .parent()
+        .map(|dir| dir.join("known_hosts"))
+        .unwrap_or_else(|| PathBuf::from("known_hosts"))
+}
+
+fn read_entries(path: &Path, parse: EntryParser) -> Vec<ConnectionConfig> {
+    std::fs::read_to_string(path)
+        .map(|contents| parse(&contents))
+        .unwrap_or_default()
+}
+
+fn display_path(path: &Path, home: &Path) -> String {
+    match path.strip_prefix(home) {
+        Ok(relative) => format!("~/{}", relative.display()),
+        Err(_) => path.display().to_string(),
+    }
+}
+
+/// Identity used for duplicate detection: (host, port, database, user)
+pub fn duplicate_key(config: &ConnectionConfig) -> (String, u16, Option<String>, String) {
+    (
+        config.host.to_lowercase(),
+        config.port,
+        config.database.clone(),
+        config.username.clone(),
+    )
+}
+
+/// Synthetic environment variable the imported password is read from,
+/// e.g. `LAZYTABLES_PG_DB_EXAMPLE_COM_5432_APP_PASSWORD`
+pub fn password_env_var(prefix: &str, config: &ConnectionConfig) -> String {
+    let raw = format!("{}_{}_{}", config.host, config.port, config.username);
+    let sanitized: String = raw
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("LAZYTABLES_{prefix}_{sanitized}_PASSWORD")
+}
+
+/// Split a pgpass line on unescaped `:`, resolving `\:` and `\\`
+fn split_pgpass_line(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    fields.last_mut().unwrap().push(next);
+                }
+            }
+            ':' => fields.push(String::new()),
+            _ => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+/// Parse `hostname:port:database:username:password` lines.
+/// Wildcard hosts and ports fall back to libpq defaults; wildcard users are skipped.
+pub fn parse_pgpass(contents: &str) -> Vec<ConnectionConfig> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let fields = split_pgpass_line(line);
+            if fields.len() != 5 || fields[3] == "*" || fields[3].is_empty() {
+                return None;
+            }
+
+            let host = match fields[0].as_str() {
+                "*" | "" => "localhost".to_string(),
+                host => host.to_string(),
+            };
+            let port = match fields[1].as_str() {
+                "*" | "" => 5432,
+                port => port.parse().ok()?,
+            };
+            let database = match fields[2].as_str() {
+                "*" | "" => None,
+                database => Some(database.to_string()),
+            };
+
+            let mut config = ConnectionConfig::new(
+                format!("{}@{}", fields[3], host),
+                DatabaseType::PostgreSQL,
+                host,
+                port,
+                fields[3].clone(),
+            );
+            config.database = database;
+            let var_name = password_env_var("PG", &config);
+            config.set_password_source(PasswordSource::Environment { var_name });
+            Some(config)
+        })
+        .collect()
+}
+
+/// Parse the `[client]` / `[mysql]` style sections of a MySQL option file.
+/// Each section naming a user becomes one connection.
+pub fn parse_my_cnf(contents: &str) -> Vec<ConnectionConfig> {
+    #[derive(Default)]
+    struct Section {
+        host: Option<String>,
+        port: Option<u16>,
+        user: Option<String>,
+        database: Option<String>,
+    }
+
+    let mut sections: Vec<(String, Section)> = Vec::new();
+    for line in contents.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.push((name.trim().to_string(), Section::default()));
+            continue;
+        }
+        let Some((_, section)) = sections.last_mut() else {
+            continue;
+        };
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+        match key.trim().replace('-', "_").as_str() {
+            "host" => section.host = Some(value.to_string()),
+            "port" => section.port = value.parse().ok(),
+            "user" => section.user = Some(value.to_string()),
+            "database" => section.database = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    sections
+        .into_iter()
+        .filter(|(name, _)| matches!(name.as_str(), "client" | "mysql"))
+        .filter_map(|(name, section)| {
+            let user = section.user?;
+            let host = section.host.unwrap_or_else(|| "localhost".to_string());
+            let mut config = ConnectionConfig::new(
+                format!("{user}@{host} ({name})"),
+                DatabaseType::MySQL,
+                host,
+                section.port.unwrap_or(3306),
+                user,
+            );
+            config.database = section.database;
+            let var_name = password_env_var("MYSQL", &config);
+            config.set_password_source(PasswordSource::Environment { var_name });
+            Some(config)
+        })
+        .collect()
+}
+
+/// Parse SQLite database paths, one per line
+pub fn parse_known_hosts(contents: &str) -> Vec<ConnectionConfig> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|path| {
+            let name = Path::new(path)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.to_string());
+            let mut config =
+                ConnectionConfig::new(name, DatabaseType::SQLite, String::new(), 0, String::new());
+            config.database = Some(path.to_string());
+            config
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pgpass() {
+        let contents = "# comment\n\
+                        db.example.com:5432:app:alice:secret\n\
+                        *:*:*:bob:pa\\:ss\n\
+                        *:*:*:*:ignored\n\
+                        broken:line\n";
+        let configs = parse_pgpass(contents);
+        assert_eq!(configs.len(), 2);
+
+        assert_eq!(configs[0].host, "db.example.com");
+        assert_eq!(configs[0].database.as_deref(), Some("app"));
+        assert!(configs[0].password.is_none());
+        assert!(matches!(
+            &configs[0].password_source,
+            Some(PasswordSource::Environment { var_name })
+                if var_name == "LAZYTABLES_PG_DB_EXAMPLE_COM_5432_ALICE_PASSWORD"
+        ));
+
+        assert_eq!(configs[1].host, "localhost");
+        assert_eq!(configs[1].port, 5432);
+        assert_eq!(configs[1].database, None);
+    }
+
+    #[test]
+    fn test_parse_my_cnf() {
+        let contents = "[client]\nuser = root\nport=3307\n\n[mysqldump]\nuser=backup\n\n[mysql]\nhost=\"db.internal\"\nuser=app\ndatabase=shop\n";
+        let configs = parse_my_cnf(contents);
+        assert_eq!(configs.len(), 2);
+        assert_eq!(
+            (configs[0].host.as_str(), configs[0].port),
+            ("localhost", 3307)
+        );
+        assert_eq!(configs[1].host, "db.internal");
+        assert_eq!(configs[1].database.as_deref(), Some("shop"));
+    }
+
+    #[test]
+    fn test_duplicate_key_matches_existing() {
+        let existing = parse_pgpass("db.example.com:5432:app:alice:x\n");
+        let found = parse_pgpass("DB.example.com:5432:app:alice:y\n");
+        assert_eq!(duplicate_key(&existing[0]), duplicate_key(&found[0]));
+
+        let sqlite = parse_known_hosts("/data/app.db\n# skipped\n");
+        assert_eq!(sqlite.len(), 1);
+        assert_eq!(sqlite[0].name, "app");
+        assert_eq!(sqlite[0].database.as_deref(), Some("/data/app.db"));
+    }
+}
@@ -5,11 +5,16 @@
 use crate::core::error::{Error, Result};
 use crossterm::event::{self, Event as CrosstermEvent, KeyEvent, MouseEvent};
 use std::{
-    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    path::PathBuf,
+    sync::mpsc::{self, Receiver, RecvTimeoutError, Sender},
     thread,
     time::Duration,
 };
 
+mod theme_watcher;
+
+pub use theme_watcher::ThemeWatcher;
+
 /// Application events
 #[derive(Debug, Clone)]
 pub enum Event {
@@ -21,18 +26,24 @@ pub enum Event {
     Resize(u16, u16),
     /// Periodic tick for updates
     Tick,
+    /// The active theme file changed on disk
+    ThemeReload(PathBuf),
 }
 
 /// Event handler that manages input events
 pub struct EventHandler {
     receiver: Receiver<Event>,
+    /// Kept so watchers can feed events into the same loop
+    sender: Sender<Event>,
     _handler: thread::JoinHandle<()>,
+    theme_watcher: Option<ThemeWatcher>,
 }
 
 impl EventHandler {
     /// Create a new event handler with specified tick rate
     pub fn new(tick_rate: Duration) -> Self {
         let (sender, receiver) = mpsc::channel();
+        let input_sender = sender.clone();
 
         let handler = thread::spawn(move || {
             let mut last_tick = std::time::Instant::now();
@@ -43,7 +54,7 @@ impl EventHandler {
 
                 // If tick is due, send it immediately without polling for events
                 if timeout.is_zero() {
-                    if sender.send(Event::Tick).is_err() {
+                    if input_sender.send(Event::Tick).is_err() {
                         break;
                     }
                     last_tick = std::time::Instant::now();
@@ -52,29 +63,24 @@ impl EventHandler {
 
                 // Poll for events with remaining time until next tick
                 if event::poll(timeout).unwrap_or(false) {
-                    match event::read() {
-                        Ok(CrosstermEvent::Key(key)) => {
-                            if sender.send(Event::Key(key)).is_err() {
-                                break;
-                            }
-                        }
-                        Ok(CrosstermEvent::Mouse(mouse)) => {
-                            if sender.send(Event::Mouse(mouse)).is_err() {
-                                break;
-                            }
-                        }
+                    let forwarded = match event::read() {
+                        Ok(CrosstermEvent::Key(key)) => Some(Event::Key(key)),
+                        Ok(CrosstermEvent::Mouse(mouse)) => Some(Event::Mouse(mouse)),
                         Ok(CrosstermEvent::Resize(width, height)) => {
-                            if sender.send(Event::Resize(width, height)).is_err() {
-                                break;
-                            }
+                            Some(Event::Resize(width, height))
+                        }
+                        _ => None,
+                    };
+                    if let Some(event) = forwarded {
+                        if input_sender.send(event).is_err() {
+                            break;
                         }
-                        _ => {}
                     }
                 }
 
                 // Check again if tick is due after processing events
                 if last_tick.elapsed() >= tick_rate {
-                    if sender.send(Event::Tick).is_err() {
+                    if input_sender.send(Event::Tick).is_err() {
                         break;
                     }
                     last_tick = std::time::Instant::now();
@@ -84,7 +90,9 @@ impl EventHandler {
 
         Self {
             receiver,
+            sender,
             _handler: handler,
+            theme_watcher: None,
         }
     }
 
@@ -93,6 +101,17 @@ impl EventHandler {
         Ok(())
     }
 
+    /// Reload the theme whenever `path` changes; replaces any earlier watch
+    pub fn watch_theme(&mut self, path: PathBuf) -> Result<()> {
+        self.theme_watcher = Some(ThemeWatcher::spawn(path, self.sender.clone())?);
+        Ok(())
+    }
+
+    /// Stop reloading the theme, e.g. after switching to a built-in one
+    pub fn unwatch_theme(&mut self) {
+        self.theme_watcher = None;
+    }
+
     /// Get the next event, blocking with timeout to allow CPU to idle
     pub fn next(&self) -> Result<Option<Event>> {
         // Use recv_timeout to block and allow CPU to enter idle states
@@ -0,0 +1,109 @@
+// FilePath: src/event/theme_watcher.rs
+
+#![forbid(unsafe_code)]
+
+use super::Event;
+use crate::core::error::{Error, Result};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Sender},
+    thread,
+    time::Duration,
+};
+
+/// Saves often arrive as several write events; wait this long for the rest
+const SETTLE_DELAY: Duration = Duration::from_millis(100);
+
+/// Watches the active theme file and forwards changes to the event loop
+pub struct ThemeWatcher {
+    _watcher: RecommendedWatcher,
+    _forwarder: thread::JoinHandle<()>,
+}
+
+impl ThemeWatcher {
+    /// Start watching `path`, sending `Event::ThemeReload` through `events`
+    pub fn spawn(path: PathBuf, events: Sender<Event>) -> Result<Self> {
+        let (notify_tx, notify_rx) = mpsc::channel::<notify::Result<notify::Event>>();
+        let mut watcher = notify::recommended_watcher(notify_tx)
+            .map_err(|e| Error::Event(format!("Failed to create theme watcher: {e}")))?;
+
+        // Watch the directory: editors that save by renaming replace the file
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."))
+            .to_path_buf();
+        watcher
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .map_err(|e| Error::Event(format!("Failed to watch {}: {e}", dir.display())))?;
+
+        let forwarder = thread::spawn(move || {
+            while let Ok(event) = notify_rx.recv() {
+                if !event.is_ok_and(|event| is_theme_change(&event, &path)) {
+                    continue;
+                }
+                // Collapse the burst of events a single save produces
+                while notify_rx.recv_timeout(SETTLE_DELAY).is_ok() {}
+                if events.send(Event::ThemeReload(path.clone())).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            _watcher: watcher,
+            _forwarder: forwarder,
+        })
+    }
+}
+
+/// Whether a file system event rewrote the theme file
+fn is_theme_change(event: &notify::Event, path: &Path) -> bool {
+    matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_))
+        && event
+            .paths
+            .iter()
+            .any(|changed| changed.file_name() == path.file_name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, ModifyKind};
+
+    #[test]
+    fn test_theme_change_events() {
+        let theme = PathBuf::from("/themes/dusk.toml");
+        let event = |kind, path: &str| notify::Event::new(kind).add_path(PathBuf::from(path));
+
+        assert!(is_theme_change(
+            &event(EventKind::Modify(ModifyKind::Any), "/themes/dusk.toml"),
+            &theme
+        ));
+        assert!(!is_theme_change(
+            &event(EventKind::Modify(ModifyKind::Any), "/themes/dawn.toml"),
+            &theme
+        ));
+        assert!(!is_theme_change(
+            &event(EventKind::Access(AccessKind::Any), "/themes/dusk.toml"),
+            &theme
+        ));
+    }
+
+    #[test]
+    fn test_saving_the_theme_sends_a_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dusk.toml");
+        std::fs::write(&path, "name = \"dusk\"").unwrap();
+
+        let (events, received) = mpsc::channel();
+        let _watcher = ThemeWatcher::spawn(path.clone(), events).unwrap();
+        std::fs::write(&path, "name = \"dusk v2\"").unwrap();
+
+        match received.recv_timeout(Duration::from_secs(5)) {
+            Ok(Event::ThemeReload(reloaded)) => assert_eq!(reloaded, path),
+            other => panic!("expected a theme reload, got {other:?}"),
+        }
+    }
+}
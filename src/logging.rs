@@ -21,6 +21,37 @@ pub struct DebugMessage {
     pub location: Option<String>,
 }
 
+impl DebugMessage {
+    /// Whether the message is at `min_level` or more severe. `None` matches
+    /// everything, including levels that aren't tracing levels.
+    pub fn is_at_least(&self, min_level: Option<LogLevel>) -> bool {
+        let Some(min_level) = min_level else {
+            return true;
+        };
+        // tracing orders more verbose levels as greater
+        self.level
+            .parse::<tracing::Level>()
+            .is_ok_and(|level| level <= tracing::Level::from(min_level))
+    }
+
+    /// Single-line plain text form, as copied to the clipboard
+    pub fn to_plain_line(&self) -> String {
+        let location = self
+            .location
+            .as_ref()
+            .map(|location| format!(" [{location}]"))
+            .unwrap_or_default();
+        format!(
+            "{} {:5} {}{}: {}",
+            self.timestamp.format("%H:%M:%S%.3f"),
+            self.level,
+            self.target,
+            location,
+            self.message
+        )
+    }
+}
+
 /// In-memory log storage for debug view
 #[derive(Debug)]
 pub struct DebugLogStorage {
@@ -46,17 +77,22 @@ impl DebugLogStorage {
         }
     }
 
-    pub fn get_messages(&self) -> Vec<DebugMessage> {
+    pub fn get_messages(&self, min_level: Option<LogLevel>) -> Vec<DebugMessage> {
         if let Ok(messages) = self.messages.lock() {
-            messages.iter().cloned().collect()
+            messages
+                .iter()
+                .filter(|message| message.is_at_least(min_level))
+                .cloned()
+                .collect()
         } else {
             Vec::new()
         }
     }
 
-    pub fn clear(&self) {
+    /// Drop messages at `min_level` or above, or all of them for `None`
+    pub fn clear(&self, min_level: Option<LogLevel>) {
         if let Ok(mut messages) = self.messages.lock() {
-            messages.clear();
+            messages.retain(|message| !message.is_at_least(min_level));
         }
     }
 }
@@ -118,14 +154,14 @@ impl tracing::field::Visit for LogVisitor {
     }
 }
 
-/// Get debug messages for the debug view
-pub fn get_debug_messages() -> Vec<DebugMessage> {
-    DEBUG_LOG_STORAGE.get_messages()
+/// Get debug messages at `min_level` or above for the debug view
+pub fn get_debug_messages(min_level: Option<LogLevel>) -> Vec<DebugMessage> {
+    DEBUG_LOG_STORAGE.get_messages(min_level)
 }
 
-/// Clear debug messages
-pub fn clear_debug_messages() {
-    DEBUG_LOG_STORAGE.clear();
+/// Clear debug messages at `min_level` or above
+pub fn clear_debug_messages(min_level: Option<LogLevel>) {
+    DEBUG_LOG_STORAGE.clear(min_level);
 }
 
 /// Initialize the logging system based on mode and level
@@ -329,8 +365,8 @@ mod tests {
     #[test]
     fn test_debug_storage_basic() {
         // Test that we can create and clear the storage
-        clear_debug_messages();
-        let messages = get_debug_messages();
+        clear_debug_messages(None);
+        let messages = get_debug_messages(None);
         println!("After clear: {} messages", messages.len());
 
         // Add a test message directly
@@ -343,7 +379,7 @@ mod tests {
         };
 
         DEBUG_LOG_STORAGE.add_message(test_message);
-        let messages = get_debug_messages();
+        let messages = get_debug_messages(None);
         assert!(!messages.is_empty(), "Should have at least one message");
 
         // Find our test message
@@ -362,7 +398,7 @@ mod tests {
     #[test]
     fn test_debug_storage_limits() {
         // Clear messages
-        clear_debug_messages();
+        clear_debug_messages(None);
 
         // Add more than the limit (1000)
         for i in 0..1500 {
@@ -376,7 +412,7 @@ mod tests {
             DEBUG_LOG_STORAGE.add_message(message);
         }
 
-        let messages = get_debug_messages();
+        let messages = get_debug_messages(None);
         assert!(
             messages.len() <= 1000,
             "Storage should be limited to 1000 messages, got {}",
@@ -396,4 +432,35 @@ mod tests {
 
         println!("SUCCESS: Debug storage limits are working correctly");
     }
+
+    #[test]
+    fn test_debug_storage_level_filter() {
+        let storage = DebugLogStorage::new(10);
+        for level in ["TRACE", "DEBUG", "INFO", "WARN", "ERROR", "TEST"] {
+            storage.add_message(DebugMessage {
+                timestamp: chrono::Utc::now(),
+                level: level.to_string(),
+                target: "test".to_string(),
+                message: level.to_lowercase(),
+                location: None,
+            });
+        }
+
+        let levels = |min_level| {
+            storage
+                .get_messages(min_level)
+                .into_iter()
+                .map(|m| m.level)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(levels(None).len(), 6);
+        assert_eq!(
+            levels(Some(LogLevel::Debug)),
+            ["DEBUG", "INFO", "WARN", "ERROR"]
+        );
+        assert_eq!(levels(Some(LogLevel::Warn)), ["WARN", "ERROR"]);
+
+        storage.clear(Some(LogLevel::Info));
+        assert_eq!(levels(None), ["TRACE", "DEBUG", "TEST"]);
+    }
 }
@@ -14,12 +14,92 @@ async fn main() -> color_eyre::Result<()> {
     let cli = Cli::parse();
 
     // Handle theme commands if present
-    if let Some(lazytables::cli::Commands::Theme { command }) = &cli.theme {
+    if let Some(lazytables::cli::Commands::Theme { command }) = &cli.command {
         return command
             .execute()
             .map_err(|e| color_eyre::eyre::eyre!("Theme command failed: {}", e));
     }
 
+    // Headless runs split statements like the editor, with its configured separator
+    let statement_separator = || {
+        Config::load(cli.config.clone())
+            .map(|config| config.query.statement_separator)
+            .unwrap_or_else(|_| lazytables::database::sql_split::DEFAULT_SEPARATOR.to_string())
+    };
+
+    // Run SQL files headlessly without initializing the terminal
+    if let Some(lazytables::cli::Commands::Run {
+        file,
+        connection,
+        format,
+    }) = &cli.command
+    {
+        let separator = statement_separator();
+        if let Err(e) = lazytables::cli::run_sql_file(file, connection, &separator, *format).await {
+            eprintln!("Error: {e}");
+            // `run` is for CI scripts: any failure exits 1
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Scripted queries: results on stdout, exit code by failure kind
+    if let Some(lazytables::cli::Commands::Query {
+        connection,
+        file,
+        execute,
+        output,
+        no_input,
+    }) = &cli.command
+    {
+        let sql = match (execute, file) {
+            (Some(sql), _) => sql.clone(),
+            (None, Some(path)) => match std::fs::read_to_string(path) {
+                Ok(sql) => sql,
+                Err(e) => {
+                    eprintln!("Error: Failed to read {}: {e}", path.display());
+                    std::process::exit(1);
+                }
+            },
+            (None, None) => unreachable!("clap requires --file or --execute"),
+        };
+        let separator = statement_separator();
+        if let Err(e) =
+            lazytables::cli::run_script(&sql, connection, &separator, *output, !no_input).await
+        {
+            eprintln!("Error: {e}");
+            std::process::exit(e.exit_code());
+        }
+        return Ok(());
+    }
+
+    // One-off query: CSV on stdout, nonzero exit on error
+    if let (Some(sql), Some(connection)) = (&cli.execute, &cli.connection) {
+        if let Err(e) = lazytables::cli::run_query(sql, connection).await {
+            eprintln!("Error: {e}");
+            std::process::exit(e.exit_code());
+        }
+        return Ok(());
+    }
+
+    // Move saved connections between machines
+    if let Some(lazytables::cli::Commands::Connections { command }) = &cli.command {
+        if let Err(e) = command.execute().await {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // List saved connections for shell scripts
+    if let Some(lazytables::cli::Commands::List { format }) = &cli.command {
+        if let Err(e) = lazytables::cli::list_connections(*format).await {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Initialize logging
     lazytables::logging::init(cli.log_level)
         .map_err(|e| color_eyre::eyre::eyre!("Failed to init logging: {}", e))?;
@@ -28,6 +108,15 @@ async fn main() -> color_eyre::Result<()> {
     let config = Config::load(cli.config)
         .map_err(|e| color_eyre::eyre::eyre!("Failed to load config: {}", e))?;
 
+    // Read the file to preload before the terminal takes over the screen
+    let preload = match &cli.file {
+        Some(path) => Some(
+            std::fs::read_to_string(path)
+                .map_err(|e| color_eyre::eyre::eyre!("Failed to read {}: {}", path.display(), e))?,
+        ),
+        None => None,
+    };
+
     // Initialize terminal
     let terminal = lazytables::terminal::init()
         .map_err(|e| color_eyre::eyre::eyre!("Failed to init terminal: {}", e))?;
@@ -39,6 +128,12 @@ async fn main() -> color_eyre::Result<()> {
     let mut app = App::new(config)
         .await
         .map_err(|e| color_eyre::eyre::eyre!("Failed to create app: {}", e))?;
+    if let Some(content) = preload {
+        app.state.preload_query(content);
+    }
+    if let Some(connection) = &cli.connection {
+        app.connect_on_startup(connection, cli.table.as_deref());
+    }
     let result = app
         .run(terminal)
         .await
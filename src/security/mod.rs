@@ -4,4 +4,7 @@
 
 mod password;
 
-pub use password::{EncryptedPassword, PasswordManager, PasswordSource};
+pub use password::{
+    mask_secret, mask_url_password, EncryptedPassword, PasswordManager, PasswordSource, SessionKey,
+    KEYCHAIN_SERVICE, REDACTED,
+};
@@ -0,0 +1,107 @@
+// FilePath: src/state/bookmarks.rs
+
+#![forbid(unsafe_code)]
+
+use crate::state::store::TomlStore;
+use serde::{Deserialize, Serialize};
+
+/// What a bookmark opens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BookmarkKind {
+    Table,
+    SqlFile,
+}
+
+/// Table or SQL file kept in the `'` bookmarks overlay
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub kind: BookmarkKind,
+    /// Table name as listed in the Tables pane, or the SQL file name
+    pub name: String,
+    pub connection_id: String,
+    /// Shown next to the bookmark; the id is what connects
+    pub connection_name: String,
+    /// Shown instead of `name` when set
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub label: String,
+}
+
+impl Bookmark {
+    /// Label if one was given, otherwise the table or file name
+    pub fn display_name(&self) -> &str {
+        if self.label.is_empty() {
+            &self.name
+        } else {
+            &self.label
+        }
+    }
+
+    /// Same table or file on the same connection, whatever the label
+    pub fn same_target(&self, other: &Bookmark) -> bool {
+        self.kind == other.kind
+            && self.name == other.name
+            && self.connection_id == other.connection_id
+    }
+}
+
+/// Bookmarks kept in `~/.lazytables/bookmarks.toml`, in the order they were added
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookmarkStore {
+    #[serde(default)]
+    pub bookmarks: Vec<Bookmark>,
+}
+
+impl TomlStore for BookmarkStore {
+    const FILE_NAME: &'static str = "bookmarks.toml";
+}
+
+impl BookmarkStore {
+    /// Add `bookmark`, or remove it if its target is already bookmarked.
+    /// Returns true when it was added.
+    pub fn toggle(&mut self, bookmark: Bookmark) -> bool {
+        match self.bookmarks.iter().position(|b| b.same_target(&bookmark)) {
+            Some(index) => {
+                self.bookmarks.remove(index);
+                false
+            }
+            None => {
+                self.bookmarks.push(bookmark);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bookmark(kind: BookmarkKind, name: &str) -> Bookmark {
+        Bookmark {
+            kind,
+            name: name.to_string(),
+            connection_id: "4f1c".to_string(),
+            connection_name: "prod".to_string(),
+            label: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_toggle_and_labels() {
+        let mut store = BookmarkStore::default();
+        assert!(store.toggle(bookmark(BookmarkKind::Table, "public.orders")));
+        assert!(store.toggle(bookmark(BookmarkKind::SqlFile, "orders.sql")));
+        assert!(store.toggle(bookmark(BookmarkKind::Table, "public.users")));
+
+        // Labels don't make a second bookmark of the same table
+        let mut labelled = bookmark(BookmarkKind::Table, "public.users");
+        labelled.label = "Users".to_string();
+        assert!(!store.toggle(labelled));
+        assert_eq!(store.bookmarks.len(), 2);
+
+        store.bookmarks[0].label = "Orders".to_string();
+        assert_eq!(store.bookmarks[0].display_name(), "Orders");
+        assert_eq!(store.bookmarks[1].display_name(), "orders.sql");
+    }
+}
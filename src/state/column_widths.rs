@@ -0,0 +1,79 @@
+// FilePath: src/state/column_widths.rs
+
+#![forbid(unsafe_code)]
+
+use crate::state::store::TomlStore;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Column widths set with `<` and `>` on one table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableColumnWidths {
+    pub connection_id: String,
+    pub table: String,
+    /// Content width in characters, by column name
+    #[serde(default)]
+    pub widths: BTreeMap<String, usize>,
+}
+
+/// Per-table width overrides kept in `~/.lazytables/column_widths.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnWidthStore {
+    #[serde(default)]
+    pub tables: Vec<TableColumnWidths>,
+}
+
+impl TomlStore for ColumnWidthStore {
+    const FILE_NAME: &'static str = "column_widths.toml";
+}
+
+impl ColumnWidthStore {
+    /// Widths saved for `table` on a connection, ready for a tab's overrides
+    pub fn widths(&self, connection_id: &str, table: &str) -> HashMap<String, usize> {
+        self.tables
+            .iter()
+            .find(|entry| entry.connection_id == connection_id && entry.table == table)
+            .map(|entry| entry.widths.clone().into_iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// Remember `width` for a column of `table`
+    pub fn set(&mut self, connection_id: &str, table: &str, column: &str, width: usize) {
+        let index = match self
+            .tables
+            .iter()
+            .position(|entry| entry.connection_id == connection_id && entry.table == table)
+        {
+            Some(index) => index,
+            None => {
+                self.tables.push(TableColumnWidths {
+                    connection_id: connection_id.to_string(),
+                    table: table.to_string(),
+                    widths: BTreeMap::new(),
+                });
+                self.tables.len() - 1
+            }
+        };
+        self.tables[index].widths.insert(column.to_string(), width);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_widths() {
+        let mut store = ColumnWidthStore::default();
+        store.set("4f1c", "public.users", "email", 40);
+        store.set("4f1c", "public.users", "email", 24);
+        store.set("4f1c", "public.users", "id", 6);
+        store.set("9a2e", "public.users", "email", 12);
+        assert_eq!(store.tables.len(), 2);
+
+        let widths = store.widths("4f1c", "public.users");
+        assert_eq!(widths.len(), 2);
+        assert_eq!(widths["email"], 24);
+        assert!(store.widths("4f1c", "public.orders").is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+// FilePath: src/state/connection_templates.rs
+
+#![forbid(unsafe_code)]
+
+use crate::core::error::{LazyTablesError, Result};
+use crate::database::connection::{ConnectionConfig, DatabaseType, SslMode};
+use crate::security::PasswordSource;
+use crate::state::store::TomlStore;
+use serde::{Deserialize, Serialize};
+
+/// Connection settings without a name or database, used to pre-fill the
+/// add-connection form with `A`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionTemplate {
+    pub name: String,
+    pub database_type: DatabaseType,
+    pub host: String,
+    pub port: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port_env: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance_name: Option<String>,
+    pub username: String,
+    /// Plain-text passwords are left out, so they are asked for again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_source: Option<PasswordSource>,
+    pub ssl_mode: SslMode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssl_ca_cert: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssl_client_cert: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssl_client_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_schema: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub init_sql: Vec<String>,
+}
+
+impl ConnectionTemplate {
+    /// Everything in `connection` except its name, database and plain-text password
+    pub fn from_connection(name: &str, connection: &ConnectionConfig) -> Self {
+        Self {
+            name: name.to_string(),
+            database_type: connection.database_type.clone(),
+            host: connection.host.clone(),
+            port: connection.port,
+            port_env: connection.port_env.clone(),
+            service_name: connection.service_name.clone(),
+            instance_name: connection.instance_name.clone(),
+            username: connection.username.clone(),
+            password_source: connection
+                .password_source
+                .clone()
+                .filter(|source| !matches!(source, PasswordSource::PlainText(_))),
+            ssl_mode: connection.ssl_mode.clone(),
+            ssl_ca_cert: connection.ssl_ca_cert.clone(),
+            ssl_client_cert: connection.ssl_client_cert.clone(),
+            ssl_client_key: connection.ssl_client_key.clone(),
+            timeout: connection.timeout,
+            default_schema: connection.default_schema.clone(),
+            init_sql: connection.init_sql.clone(),
+        }
+    }
+
+    /// New connection with the template's settings and no name or database
+    pub fn to_connection(&self) -> ConnectionConfig {
+        let mut connection = ConnectionConfig::new(
+            String::new(),
+            self.database_type.clone(),
+            self.host.clone(),
+            self.port,
+            self.username.clone(),
+        );
+        connection.port_env = self.port_env.clone();
+        connection.service_name = self.service_name.clone();
+        connection.instance_name = self.instance_name.clone();
+        connection.password_source = self.password_source.clone();
+        connection.ssl_mode = self.ssl_mode.clone();
+        connection.ssl_ca_cert = self.ssl_ca_cert.clone();
+        connection.ssl_client_cert = self.ssl_client_cert.clone();
+        connection.ssl_client_key = self.ssl_client_key.clone();
+        connection.timeout = self.timeout;
+        connection.default_schema = self.default_schema.clone();
+        connection.init_sql = self.init_sql.clone();
+        connection
+    }
+}
+
+/// Templates kept in `~/.lazytables/connection_templates.toml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConnectionTemplateStore {
+    #[serde(default)]
+    pub templates: Vec<ConnectionTemplate>,
+}
+
+impl TomlStore for ConnectionTemplateStore {
+    const FILE_NAME: &'static str = "connection_templates.toml";
+}
+
+impl ConnectionTemplateStore {
+    /// Add a template, replacing one with the same name. Returns true on replace.
+    pub fn upsert(&mut self, template: ConnectionTemplate) -> Result<bool> {
+        if template.name.trim().is_empty() {
+            return Err(LazyTablesError::InvalidInput(
+                "Template name cannot be empty".to_string(),
+            ));
+        }
+        match self.templates.iter_mut().find(|t| t.name == template.name) {
+            Some(existing) => {
+                *existing = template;
+                Ok(true)
+            }
+            None => {
+                self.templates.push(template);
+                Ok(false)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_drops_name_database_and_plain_password() {
+        let mut connection = ConnectionConfig::new(
+            "prod orders".to_string(),
+            DatabaseType::PostgreSQL,
+            "db.internal".to_string(),
+            6432,
+            "app".to_string(),
+        );
+        connection.database = Some("orders".to_string());
+        connection.set_plain_password("secret".to_string());
+        connection.ssl_mode = SslMode::Require;
+
+        let template = ConnectionTemplate::from_connection("prod", &connection);
+        assert!(template.password_source.is_none());
+
+        let mut store = ConnectionTemplateStore::default();
+        assert!(!store.upsert(template.clone()).unwrap());
+        assert!(store.upsert(template).unwrap());
+        assert!(store
+            .upsert(ConnectionTemplate::from_connection(" ", &connection))
+            .is_err());
+        assert_eq!(store.templates.len(), 1);
+
+        let filled = store.templates[0].to_connection();
+        assert_eq!(filled.name, "");
+        assert_eq!(filled.database, None);
+        assert_eq!(filled.host, "db.internal");
+        assert_eq!(filled.port, 6432);
+        assert_eq!(filled.username, "app");
+        assert!(matches!(filled.ssl_mode, SslMode::Require));
+        assert!(filled.password.is_none() && filled.password_source.is_none());
+        assert_ne!(filled.id, connection.id);
+    }
+}
@@ -5,11 +5,12 @@
 use crate::{
     database::{
         connection::{Connection, ConnectionStorage},
-        ConnectionConfig, ConnectionStatus, DatabaseObjectList, DatabaseType, TableMetadata,
+        ColumnProfileView, ConnectionConfig, ConnectionStatus, DatabaseObjectList, DatabaseType,
+        RoutineInfo, TableMetadata,
     },
     ui::components::{
         table_viewer::{CellUpdate, ColumnInfo, DeleteConfirmation, SetNullConfirmation},
-        TableViewerState,
+        QueryStats, TableViewerState,
     },
 };
 
@@ -30,6 +31,30 @@ pub struct DatabaseState {
     pub table_load_error: Option<String>,
     /// Current table metadata (for the details pane)
     pub current_table_metadata: Option<TableMetadata>,
+    /// Column statistics for the details pane, fetched on demand
+    pub column_profile: Option<ColumnProfileView>,
+    /// Table and index usage counters, shown instead of the details while set
+    pub table_statistics: Option<crate::database::TableStatistics>,
+    /// Object listing being streamed in the background, if any
+    pub object_listing: Option<ObjectListing>,
+    /// Stored functions and procedures for the routines pane
+    pub routines: Vec<RoutineInfo>,
+}
+
+/// Progress of a background object listing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectListing {
+    /// Connection the objects are listed for
+    pub connection_id: String,
+    /// Distinguishes a listing from earlier ones on the same connection
+    pub generation: u64,
+    /// Objects received so far
+    pub loaded: usize,
+    /// Table to reselect once it arrives (refresh keeps the selection)
+    pub restore_selection: Option<String>,
+    /// Row the cursor was on before a refresh, kept while the table to
+    /// reselect hasn't arrived, or when it is gone
+    pub restore_index: Option<usize>,
 }
 
 impl DatabaseState {
@@ -46,6 +71,10 @@ impl DatabaseState {
             selected_schema: None,
             table_load_error: None,
             current_table_metadata: None,
+            column_profile: None,
+            table_statistics: None,
+            object_listing: None,
+            routines: Vec::new(),
         }
     }
 
@@ -86,6 +115,30 @@ impl DatabaseState {
                                 )
                                 .await
                             }
+                            DatabaseType::Redis => {
+                                self.load_redis_key_data(
+                                    &connection,
+                                    &table_name,
+                                    limit,
+                                    offset,
+                                    table_viewer_state,
+                                    tab_idx,
+                                    connection_manager,
+                                )
+                                .await
+                            }
+                            DatabaseType::MongoDB => {
+                                self.load_mongodb_documents(
+                                    &connection,
+                                    &table_name,
+                                    limit,
+                                    offset,
+                                    table_viewer_state,
+                                    tab_idx,
+                                    connection_manager,
+                                )
+                                .await
+                            }
                             _ => Err(format!(
                                 "Database type {} not yet supported for table viewing",
                                 connection.database_type.display_name()
@@ -124,6 +177,8 @@ impl DatabaseState {
             .await
             .map_err(|e| format!("Failed to ensure connection: {e}"))?;
 
+        let started_at = std::time::Instant::now();
+
         // Get table columns using persistent connection
         let columns = connection_manager
             .get_table_columns(&connection.id, table_name)
@@ -137,7 +192,10 @@ impl DatabaseState {
         );
 
         // Get total row count using raw query
-        let count_query = format!("SELECT COUNT(*) FROM {table_name}");
+        let count_query = format!(
+            "SELECT COUNT(*) FROM {}",
+            dml_table(table_name, &connection.database_type)
+        );
         let (_, count_rows) = connection_manager
             .execute_raw_query(&connection.id, &count_query)
             .await
@@ -145,7 +203,7 @@ impl DatabaseState {
 
         let total_rows = count_rows
             .first()
-            .and_then(|row| row.first())
+            .and_then(|row| row.first()?.as_deref())
             .and_then(|count_str| count_str.parse::<usize>().ok())
             .unwrap_or(0);
 
@@ -154,6 +212,26 @@ impl DatabaseState {
             .get_table_data(&connection.id, table_name, limit, offset)
             .await
             .map_err(|e| format!("Failed to retrieve data: {e}"))?;
+        let elapsed = started_at.elapsed();
+
+        // Statistics estimate where the engine keeps one; a negative (never
+        // analyzed) or NULL estimate leaves it unset
+        let estimated_rows = match row_estimate_sql(table_name, &connection.database_type) {
+            Some(estimate_query) => match connection_manager
+                .execute_raw_query(&connection.id, &estimate_query)
+                .await
+            {
+                Ok((_, rows)) => rows
+                    .first()
+                    .and_then(|row| row.first()?.as_deref()?.parse::<i64>().ok())
+                    .and_then(|estimate| u64::try_from(estimate).ok()),
+                Err(e) => {
+                    crate::log_debug!("Row estimate for {} failed: {}", table_name, e);
+                    None
+                }
+            },
+            None => None,
+        };
 
         // Get table metadata for schema view
         let metadata = connection_manager
@@ -162,6 +240,7 @@ impl DatabaseState {
             .ok(); // Don't fail if metadata can't be loaded
 
         // Update the tab with loaded data
+        let max_cell_bytes = table_viewer_state.max_cell_bytes;
         if let Some(tab) = table_viewer_state.tabs.get_mut(tab_idx) {
             // Convert columns to ColumnInfo
             tab.columns = columns
@@ -185,17 +264,144 @@ impl DatabaseState {
                 .map(|(idx, _)| idx)
                 .collect();
 
-            tab.rows = rows;
+            tab.set_rows(rows, max_cell_bytes);
             tab.total_rows = total_rows;
             tab.loading = false;
+            tab.refresh_column_widths();
             tab.error = None;
             tab.table_metadata = metadata;
+            tab.query_stats = Some(QueryStats {
+                elapsed,
+                rows: tab.rows.len() as u64,
+                affected: false,
+                estimated_rows,
+                connection_name: connection.name.clone(),
+            });
         }
 
         // Connection is kept alive by ConnectionManager
         Ok(())
     }
 
+    /// Load one page of a Redis key's value; its length comes from the key
+    /// metadata since there is no `COUNT(*)` to run
+    #[allow(clippy::too_many_arguments)]
+    async fn load_redis_key_data(
+        &mut self,
+        connection: &ConnectionConfig,
+        key: &str,
+        limit: usize,
+        offset: usize,
+        table_viewer_state: &mut TableViewerState,
+        tab_idx: usize,
+        connection_manager: &crate::database::ConnectionManager,
+    ) -> Result<(), String> {
+        connection_manager
+            .connect(connection)
+            .await
+            .map_err(|e| format!("Failed to ensure connection: {e}"))?;
+
+        let started_at = std::time::Instant::now();
+        let columns = connection_manager
+            .get_table_columns(&connection.id, key)
+            .await
+            .map_err(|e| format!("Failed to read key: {e}"))?;
+        let rows = connection_manager
+            .get_table_data(&connection.id, key, limit, offset)
+            .await
+            .map_err(|e| format!("Failed to retrieve data: {e}"))?;
+        let elapsed = started_at.elapsed();
+        let metadata = connection_manager
+            .get_table_metadata(&connection.id, key)
+            .await
+            .ok();
+
+        let max_cell_bytes = table_viewer_state.max_cell_bytes;
+        if let Some(tab) = table_viewer_state.tabs.get_mut(tab_idx) {
+            tab.columns = columns
+                .iter()
+                .map(|col| ColumnInfo {
+                    name: col.name.clone(),
+                    data_type: col.data_type.to_sql(),
+                    is_nullable: col.is_nullable,
+                    is_primary_key: col.is_primary_key,
+                    max_display_width: col.name.len().max(15),
+                })
+                .collect();
+            tab.primary_key_columns = Vec::new();
+            tab.set_rows(rows, max_cell_bytes);
+            tab.total_rows = metadata
+                .as_ref()
+                .map_or(tab.rows.len(), |metadata| metadata.row_count);
+            tab.loading = false;
+            tab.refresh_column_widths();
+            tab.error = None;
+            tab.table_metadata = metadata;
+            tab.query_stats = Some(QueryStats {
+                elapsed,
+                rows: tab.rows.len() as u64,
+                affected: false,
+                estimated_rows: None,
+                connection_name: connection.name.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Load one page of a MongoDB collection. The columns are the field
+    /// names found on the page, so they come back with the rows from one
+    /// `find`; the document count is the collection's estimate.
+    #[allow(clippy::too_many_arguments)]
+    async fn load_mongodb_documents(
+        &mut self,
+        connection: &ConnectionConfig,
+        collection: &str,
+        limit: usize,
+        offset: usize,
+        table_viewer_state: &mut TableViewerState,
+        tab_idx: usize,
+        connection_manager: &crate::database::ConnectionManager,
+    ) -> Result<(), String> {
+        connection_manager
+            .connect(connection)
+            .await
+            .map_err(|e| format!("Failed to ensure connection: {e}"))?;
+
+        let started_at = std::time::Instant::now();
+        let query = format!(
+            "db.getCollection({}).find({{}}).limit({limit}).skip({offset})",
+            serde_json::Value::from(collection)
+        );
+        let (columns, rows) = connection_manager
+            .execute_raw_query(&connection.id, &query)
+            .await
+            .map_err(|e| format!("Failed to retrieve documents: {e}"))?;
+        let elapsed = started_at.elapsed();
+        let metadata = connection_manager
+            .get_table_metadata(&connection.id, collection)
+            .await
+            .ok();
+
+        let max_cell_bytes = table_viewer_state.max_cell_bytes;
+        if let Some(tab) = table_viewer_state.tabs.get_mut(tab_idx) {
+            tab.set_result_rows(&columns, rows, max_cell_bytes);
+            // Writes go through SQL, so documents stay read-only
+            tab.primary_key_columns = Vec::new();
+            tab.total_rows = metadata
+                .as_ref()
+                .map_or(offset + tab.rows.len(), |metadata| metadata.row_count);
+            tab.table_metadata = metadata;
+            tab.query_stats = Some(QueryStats {
+                elapsed,
+                rows: tab.rows.len() as u64,
+                affected: false,
+                estimated_rows: None,
+                connection_name: connection.name.clone(),
+            });
+        }
+        Ok(())
+    }
+
     /// Load table metadata for the details pane using persistent ConnectionManager
     pub async fn load_table_metadata(
         &mut self,
@@ -214,7 +420,12 @@ impl DatabaseState {
                 ConnectionStatus::Connected => {
                     // Load metadata based on database type
                     match connection.database_type {
-                        DatabaseType::PostgreSQL => {
+                        DatabaseType::PostgreSQL
+                        | DatabaseType::MySQL
+                        | DatabaseType::MariaDB
+                        | DatabaseType::SQLite
+                        | DatabaseType::Oracle
+                        | DatabaseType::MsSql => {
                             // Ensure we have a persistent connection
                             connection_manager
                                 .connect(&connection)
@@ -228,6 +439,8 @@ impl DatabaseState {
                                 .map_err(|e| format!("Failed to retrieve metadata: {e}"))?;
 
                             self.current_table_metadata = Some(metadata);
+                            self.column_profile = None;
+                            self.table_statistics = None;
                             Ok(())
                         }
                         _ => Err(format!(
@@ -243,6 +456,50 @@ impl DatabaseState {
         }
     }
 
+    /// Replace the row count of the table shown in Details with an exact `COUNT(*)`
+    pub async fn count_table_rows(
+        &mut self,
+        selected_connection: usize,
+        connection_manager: &crate::database::ConnectionManager,
+    ) -> Result<usize, String> {
+        let connection = self
+            .connections
+            .connections
+            .get(selected_connection)
+            .filter(|connection| connection.is_connected())
+            .cloned()
+            .ok_or("No active database connection")?;
+        let table_name = self
+            .current_table_metadata
+            .as_ref()
+            .map(|metadata| metadata.display_name())
+            .ok_or("No table selected")?;
+
+        let count_query = format!(
+            "SELECT COUNT(*) FROM {}",
+            dml_table(&table_name, &connection.database_type)
+        );
+        let (_, rows) = connection_manager
+            .execute_raw_query(&connection.id, &count_query)
+            .await
+            .map_err(|e| format!("Failed to count rows: {e}"))?;
+        let count = rows
+            .first()
+            .and_then(|row| row.first()?.as_deref())
+            .and_then(|count| count.parse::<usize>().ok())
+            .ok_or("COUNT(*) returned no number")?;
+
+        // The table may have been switched while counting
+        if let Some(metadata) = self
+            .current_table_metadata
+            .as_mut()
+            .filter(|metadata| metadata.display_name() == table_name)
+        {
+            metadata.set_exact_row_count(count);
+        }
+        Ok(count)
+    }
+
     /// Update a cell in the database using persistent ConnectionManager
     pub async fn update_table_cell(
         &mut self,
@@ -261,8 +518,8 @@ impl DatabaseState {
                 ConnectionStatus::Connected => {
                     // Update cell based on database type
                     match connection.database_type {
-                        DatabaseType::PostgreSQL => {
-                            self.update_postgres_cell(&connection, update, connection_manager)
+                        DatabaseType::PostgreSQL | DatabaseType::MsSql => {
+                            self.update_cell_by_key(&connection, update, connection_manager)
                                 .await
                         }
                         _ => Err(format!(
@@ -278,8 +535,8 @@ impl DatabaseState {
         }
     }
 
-    /// Update a cell in PostgreSQL using persistent ConnectionManager
-    async fn update_postgres_cell(
+    /// Update a cell by primary key using persistent ConnectionManager
+    async fn update_cell_by_key(
         &self,
         connection: &ConnectionConfig,
         update: CellUpdate,
@@ -291,23 +548,21 @@ impl DatabaseState {
             .await
             .map_err(|e| format!("Failed to ensure connection: {e}"))?;
 
-        // Build UPDATE SQL
-        let mut where_clauses = Vec::new();
-        for (pk_col, pk_val) in &update.primary_key_values {
-            where_clauses.push(format!("{pk_col} = '{pk_val}'"));
-        }
-
-        if where_clauses.is_empty() {
-            return Err("Cannot update row without primary key".to_string());
-        }
-
-        let sql = format!(
-            "UPDATE {} SET {} = '{}' WHERE {}",
-            update.table_name,
-            update.column_name,
-            update.new_value.replace("'", "''"), // Escape single quotes
-            where_clauses.join(" AND ")
-        );
+        let value = if update.is_null {
+            "NULL".to_string()
+        } else if let Some(sql_value) = &update.sql_value {
+            sql_value.clone()
+        } else {
+            format!("'{}'", update.new_value.replace("'", "''")) // Escape single quotes
+        };
+        let sql = update_cell_sql(
+            &update.table_name,
+            &update.column_name,
+            &value,
+            &update.primary_key_values,
+            &connection.database_type,
+        )
+        .ok_or_else(|| "Cannot update row without primary key".to_string())?;
 
         // Execute the SQL update using persistent connection
         connection_manager
@@ -336,8 +591,8 @@ impl DatabaseState {
                 ConnectionStatus::Connected => {
                     // Delete row based on database type
                     match connection.database_type {
-                        DatabaseType::PostgreSQL => {
-                            self.delete_postgres_row(&connection, confirmation, connection_manager)
+                        DatabaseType::PostgreSQL | DatabaseType::MsSql => {
+                            self.delete_row_by_key(&connection, confirmation, connection_manager)
                                 .await
                         }
                         _ => Err(format!(
@@ -371,8 +626,8 @@ impl DatabaseState {
                 ConnectionStatus::Connected => {
                     // Set NULL based on database type
                     match connection.database_type {
-                        DatabaseType::PostgreSQL => {
-                            self.set_postgres_cell_to_null(
+                        DatabaseType::PostgreSQL | DatabaseType::MsSql => {
+                            self.set_cell_to_null_by_key(
                                 &connection,
                                 confirmation,
                                 connection_manager,
@@ -392,8 +647,41 @@ impl DatabaseState {
         }
     }
 
-    /// Delete a row in PostgreSQL using persistent ConnectionManager
-    async fn delete_postgres_row(
+    /// Read a single cell in full by primary key, for values cut for display;
+    /// `None` is NULL
+    pub async fn fetch_cell_value(
+        &self,
+        table_name: &str,
+        column_name: &str,
+        primary_key_values: &[(String, String)],
+        selected_connection: usize,
+        connection_manager: &crate::database::ConnectionManager,
+    ) -> Result<Option<String>, String> {
+        let connection = self
+            .connections
+            .connections
+            .get(selected_connection)
+            .ok_or_else(|| "No connection selected".to_string())?;
+        let filter = primary_key_filter(primary_key_values, &connection.database_type)
+            .ok_or_else(|| "the row has no primary key to fetch it by".to_string())?;
+        let sql = format!(
+            "SELECT {} FROM {} WHERE {filter}",
+            dml_column(column_name, &connection.database_type),
+            dml_table(table_name, &connection.database_type),
+        );
+
+        let (_, rows) = connection_manager
+            .execute_raw_query(&connection.id, &sql)
+            .await
+            .map_err(|e| format!("fetching it failed: {e}"))?;
+        rows.into_iter()
+            .next()
+            .and_then(|row| row.into_iter().next())
+            .ok_or_else(|| "the row no longer exists".to_string())
+    }
+
+    /// Delete a row by primary key using persistent ConnectionManager
+    async fn delete_row_by_key(
         &self,
         connection: &ConnectionConfig,
         confirmation: DeleteConfirmation,
@@ -405,21 +693,12 @@ impl DatabaseState {
             .await
             .map_err(|e| format!("Failed to ensure connection: {e}"))?;
 
-        // Build DELETE SQL
-        let mut where_clauses = Vec::new();
-        for (pk_col, pk_val) in &confirmation.primary_key_values {
-            where_clauses.push(format!("{pk_col} = '{pk_val}'"));
-        }
-
-        if where_clauses.is_empty() {
-            return Err("Cannot delete row without primary key".to_string());
-        }
-
-        let sql = format!(
-            "DELETE FROM {} WHERE {}",
-            confirmation.table_name,
-            where_clauses.join(" AND ")
-        );
+        let sql = delete_row_sql(
+            &confirmation.table_name,
+            &confirmation.primary_key_values,
+            &connection.database_type,
+        )
+        .ok_or_else(|| "Cannot delete row without primary key".to_string())?;
 
         // Execute the delete query using persistent connection
         connection_manager
@@ -430,8 +709,8 @@ impl DatabaseState {
         Ok(())
     }
 
-    /// Set a cell to NULL in PostgreSQL using persistent ConnectionManager
-    async fn set_postgres_cell_to_null(
+    /// Set a cell to NULL by primary key using persistent ConnectionManager
+    async fn set_cell_to_null_by_key(
         &self,
         connection: &ConnectionConfig,
         confirmation: SetNullConfirmation,
@@ -443,22 +722,14 @@ impl DatabaseState {
             .await
             .map_err(|e| format!("Failed to ensure connection: {e}"))?;
 
-        // Build UPDATE SQL to set NULL
-        let mut where_clauses = Vec::new();
-        for (pk_col, pk_val) in &confirmation.primary_key_values {
-            where_clauses.push(format!("{pk_col} = '{pk_val}'"));
-        }
-
-        if where_clauses.is_empty() {
-            return Err("Cannot update cell without primary key".to_string());
-        }
-
-        let sql = format!(
-            "UPDATE {} SET {} = NULL WHERE {}",
-            confirmation.table_name,
-            confirmation.column_name,
-            where_clauses.join(" AND ")
-        );
+        let sql = update_cell_sql(
+            &confirmation.table_name,
+            &confirmation.column_name,
+            "NULL",
+            &confirmation.primary_key_values,
+            &connection.database_type,
+        )
+        .ok_or_else(|| "Cannot update cell without primary key".to_string())?;
 
         // Execute the update query using persistent connection
         connection_manager
@@ -475,7 +746,7 @@ impl DatabaseState {
         query: &str,
         selected_connection: usize,
         connection_manager: &crate::database::ConnectionManager,
-    ) -> Result<(Vec<String>, Vec<Vec<String>>), String> {
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>), String> {
         // Get the current connection
         if let Some(connection) = self
             .connections
@@ -510,7 +781,7 @@ impl DatabaseState {
         connection: &ConnectionConfig,
         query: &str,
         connection_manager: &crate::database::ConnectionManager,
-    ) -> Result<(Vec<String>, Vec<Vec<String>>), String> {
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>), String> {
         // Ensure we have a persistent connection
         connection_manager
             .connect(connection)
@@ -649,6 +920,50 @@ impl DatabaseState {
 
                 Ok(objects)
             }
+            DatabaseType::Oracle => {
+                connection_manager
+                    .connect(connection)
+                    .await
+                    .map_err(|e| format!("Connection failed: {e}"))?;
+
+                let objects = connection_manager
+                    .list_database_objects(&connection.id)
+                    .await
+                    .map_err(|e| format!("Failed to retrieve database objects: {e}"))?;
+
+                self.database_objects = Some(objects.clone());
+                self.tables = objects
+                    .tables
+                    .iter()
+                    .chain(&objects.views)
+                    .chain(&objects.materialized_views)
+                    .map(|t| t.name.clone())
+                    .collect();
+
+                Ok(objects)
+            }
+            DatabaseType::MsSql => {
+                connection_manager
+                    .connect(connection)
+                    .await
+                    .map_err(|e| format!("Connection failed: {e}"))?;
+
+                let objects = connection_manager
+                    .list_database_objects(&connection.id)
+                    .await
+                    .map_err(|e| format!("Failed to retrieve database objects: {e}"))?;
+
+                // Every object is schema qualified; dbo is only the default schema
+                self.database_objects = Some(objects.clone());
+                self.tables = objects
+                    .tables
+                    .iter()
+                    .chain(&objects.views)
+                    .map(|t| t.qualified_name())
+                    .collect();
+
+                Ok(objects)
+            }
             _ => Err(format!(
                 "Database type {} not yet supported",
                 connection.database_type.display_name()
@@ -656,3 +971,168 @@ impl DatabaseState {
         }
     }
 }
+
+/// Table name for generated SQL, quoted part by part so mixed-case names,
+/// reserved words and spaces survive, and `schema.table` stays qualified
+fn dml_table(name: &str, database_type: &DatabaseType) -> String {
+    crate::database::ddl::quote_qualified_identifier(name, database_type)
+}
+
+/// Column name for generated SQL, quoted like [`dml_table`]
+fn dml_column(name: &str, database_type: &DatabaseType) -> String {
+    crate::database::ddl::quote_identifier(name, database_type)
+}
+
+/// Query for the engine's stored row estimate of a table: `pg_class.reltuples`
+/// on PostgreSQL, `information_schema.TABLES.TABLE_ROWS` on MySQL and
+/// MariaDB. Other engines keep no estimate.
+fn row_estimate_sql(table_name: &str, database_type: &DatabaseType) -> Option<String> {
+    match database_type {
+        DatabaseType::PostgreSQL => Some(format!(
+            "SELECT reltuples::bigint FROM pg_class WHERE oid = to_regclass('{}')",
+            dml_table(table_name, database_type).replace('\'', "''")
+        )),
+        DatabaseType::MySQL | DatabaseType::MariaDB => {
+            let (schema, table) = match table_name.split_once('.') {
+                Some((schema, table)) => (format!("'{}'", schema.replace('\'', "''")), table),
+                None => ("DATABASE()".to_string(), table_name),
+            };
+            Some(format!(
+                "SELECT TABLE_ROWS FROM information_schema.TABLES \
+                 WHERE TABLE_SCHEMA = {schema} AND TABLE_NAME = '{}'",
+                table.replace('\'', "''")
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// `WHERE` condition matching a row by its primary key values; `None`
+/// without a key, since the row can't be told apart from the others
+fn primary_key_filter(
+    primary_key_values: &[(String, String)],
+    database_type: &DatabaseType,
+) -> Option<String> {
+    if primary_key_values.is_empty() {
+        return None;
+    }
+    let clauses: Vec<String> = primary_key_values
+        .iter()
+        .map(|(pk_col, pk_val)| {
+            format!(
+                "{} = '{}'",
+                dml_column(pk_col, database_type),
+                pk_val.replace('\'', "''")
+            )
+        })
+        .collect();
+    Some(clauses.join(" AND "))
+}
+
+/// `UPDATE` setting one column of a row to the SQL expression `value`
+pub(crate) fn update_cell_sql(
+    table_name: &str,
+    column_name: &str,
+    value: &str,
+    primary_key_values: &[(String, String)],
+    database_type: &DatabaseType,
+) -> Option<String> {
+    Some(format!(
+        "UPDATE {} SET {} = {value} WHERE {}",
+        dml_table(table_name, database_type),
+        dml_column(column_name, database_type),
+        primary_key_filter(primary_key_values, database_type)?
+    ))
+}
+
+/// `DELETE` of one row by its primary key
+pub(crate) fn delete_row_sql(
+    table_name: &str,
+    primary_key_values: &[(String, String)],
+    database_type: &DatabaseType,
+) -> Option<String> {
+    Some(format!(
+        "DELETE FROM {} WHERE {}",
+        dml_table(table_name, database_type),
+        primary_key_filter(primary_key_values, database_type)?
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{ddl::create_table_sql, ColumnDefinition, DataType};
+
+    #[test]
+    fn test_generated_sql_quotes_names_per_engine() {
+        let key = [("select".to_string(), "O'Neil".to_string())];
+        assert_eq!(
+            delete_row_sql("sales.Order Items", &key, &DatabaseType::PostgreSQL).unwrap(),
+            "DELETE FROM \"sales\".\"Order Items\" WHERE \"select\" = 'O''Neil'"
+        );
+        assert_eq!(
+            update_cell_sql("Order Items", "order", "NULL", &key, &DatabaseType::MySQL).unwrap(),
+            "UPDATE `Order Items` SET `order` = NULL WHERE `select` = 'O''Neil'"
+        );
+        assert!(delete_row_sql("Order Items", &[], &DatabaseType::SQLite).is_none());
+    }
+
+    #[test]
+    fn test_row_estimate_only_where_the_engine_keeps_one() {
+        assert_eq!(
+            row_estimate_sql("sales.Orders", &DatabaseType::PostgreSQL).unwrap(),
+            "SELECT reltuples::bigint FROM pg_class WHERE oid = to_regclass('\"sales\".\"Orders\"')"
+        );
+        assert_eq!(
+            row_estimate_sql("O'Brien", &DatabaseType::MySQL).unwrap(),
+            "SELECT TABLE_ROWS FROM information_schema.TABLES \
+             WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'O''Brien'"
+        );
+        assert!(row_estimate_sql("shop.orders", &DatabaseType::MariaDB)
+            .unwrap()
+            .contains("TABLE_SCHEMA = 'shop' AND TABLE_NAME = 'orders'"));
+        assert!(row_estimate_sql("orders", &DatabaseType::SQLite).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dml_runs_on_mixed_case_table_with_reserved_word_column() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let sqlite = DatabaseType::SQLite;
+        let column = |name: &str, data_type| ColumnDefinition {
+            name: name.to_string(),
+            data_type,
+            is_nullable: true,
+            is_primary_key: name == "id",
+            is_unique: false,
+            is_auto_increment: false,
+            default_value: None,
+        };
+        let ddl = create_table_sql(
+            "Order Items",
+            &[
+                column("id", DataType::Integer),
+                column("select", DataType::Text),
+            ],
+            &sqlite,
+        );
+        sqlx::query(&ddl).execute(&pool).await.unwrap();
+        sqlx::query("INSERT INTO \"Order Items\" VALUES (1, 'a'), (2, 'b')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let row_key = |id: &str| [("id".to_string(), id.to_string())];
+        let update = update_cell_sql("Order Items", "select", "'z'", &row_key("1"), &sqlite);
+        sqlx::query(&update.unwrap()).execute(&pool).await.unwrap();
+        let delete = delete_row_sql("Order Items", &row_key("2"), &sqlite);
+        sqlx::query(&delete.unwrap()).execute(&pool).await.unwrap();
+
+        let select = format!(
+            "SELECT {} FROM {}",
+            dml_column("select", &sqlite),
+            dml_table("Order Items", &sqlite)
+        );
+        let values: Vec<String> = sqlx::query_scalar(&select).fetch_all(&pool).await.unwrap();
+        assert_eq!(values, vec!["z".to_string()]);
+    }
+}
@@ -2,10 +2,22 @@
 
 #![forbid(unsafe_code)]
 
+pub mod bookmarks;
+pub mod column_widths;
+pub mod connection_templates;
 pub mod database;
+pub mod snippets;
+pub mod store;
 pub mod ui;
 pub mod view;
+pub mod workspace;
 
-pub use database::DatabaseState;
+pub use bookmarks::{Bookmark, BookmarkKind, BookmarkStore};
+pub use column_widths::ColumnWidthStore;
+pub use connection_templates::{ConnectionTemplate, ConnectionTemplateStore};
+pub use database::{DatabaseState, ObjectListing};
+pub use snippets::{Snippet, SnippetStore};
+pub use store::TomlStore;
 pub use ui::{FocusedPane, HelpMode, UIState};
 pub use view::{AppView, ConnectionFormMode, OverlayView, TextInputMode};
+pub use workspace::{Workspace, WorkspaceSummary};
@@ -0,0 +1,126 @@
+// FilePath: src/state/snippets.rs
+
+#![forbid(unsafe_code)]
+
+use crate::core::error::{LazyTablesError, Result};
+use crate::state::store::TomlStore;
+use serde::{Deserialize, Serialize};
+
+/// Reusable SQL fragment inserted into the query editor with `Ctrl+J`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snippet {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// SQL text; `$1`, `$2`, ... mark cursor stops visited with Tab
+    pub body: String,
+}
+
+/// Snippets kept in `~/.lazytables/snippets.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnippetStore {
+    #[serde(default)]
+    pub snippets: Vec<Snippet>,
+}
+
+impl TomlStore for SnippetStore {
+    const FILE_NAME: &'static str = "snippets.toml";
+}
+
+impl SnippetStore {
+    /// Add a snippet, replacing one with the same name. Returns true on replace.
+    pub fn upsert(&mut self, snippet: Snippet) -> Result<bool> {
+        if snippet.name.trim().is_empty() {
+            return Err(LazyTablesError::InvalidInput(
+                "Snippet name cannot be empty".to_string(),
+            ));
+        }
+        match self.snippets.iter_mut().find(|s| s.name == snippet.name) {
+            Some(existing) => {
+                *existing = snippet;
+                Ok(true)
+            }
+            None => {
+                self.snippets.push(snippet);
+                Ok(false)
+            }
+        }
+    }
+}
+
+/// Replace `$1`, `$2`, ... in `body` with nothing and return the text along
+/// with the byte offsets of the stops, in stop-number order. `$$` is a literal `$`.
+pub fn expand_placeholders(body: &str) -> (String, Vec<usize>) {
+    let mut text = String::with_capacity(body.len());
+    let mut stops: Vec<(u32, usize)> = Vec::new();
+    let mut chars = body.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            text.push(c);
+            continue;
+        }
+        if chars.peek() == Some(&'$') {
+            chars.next();
+            text.push('$');
+            continue;
+        }
+        let mut digits = String::new();
+        while let Some(d) = chars.peek().copied().filter(char::is_ascii_digit) {
+            digits.push(d);
+            chars.next();
+        }
+        match digits.parse::<u32>() {
+            Ok(number) => stops.push((number, text.len())),
+            Err(_) => text.push('$'),
+        }
+    }
+
+    // A stop number used twice keeps its first position
+    stops.sort_by_key(|&(number, _)| number);
+    stops.dedup_by_key(|&mut (number, _)| number);
+    (text, stops.into_iter().map(|(_, offset)| offset).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_orders_stops_by_number() {
+        let (text, stops) = expand_placeholders("SELECT $2 FROM $1 WHERE price > $$5");
+        assert_eq!(text, "SELECT  FROM  WHERE price > $5");
+        assert_eq!(stops, vec![13, 7]);
+
+        let (text, stops) = expand_placeholders("EXPLAIN ANALYZE");
+        assert_eq!(text, "EXPLAIN ANALYZE");
+        assert!(stops.is_empty());
+    }
+
+    #[test]
+    fn test_upsert_replaces_by_name() {
+        let mut store = SnippetStore::default();
+        let snippet = Snippet {
+            name: "cte".to_string(),
+            description: "Common table expression".to_string(),
+            body: "WITH $1 AS (\n  $2\n)\nSELECT * FROM $1;".to_string(),
+        };
+        assert!(!store.upsert(snippet.clone()).unwrap());
+        assert!(store
+            .upsert(Snippet {
+                body: "WITH x AS ($1)".to_string(),
+                ..snippet
+            })
+            .unwrap());
+        assert!(store
+            .upsert(Snippet {
+                name: " ".to_string(),
+                description: String::new(),
+                body: String::new(),
+            })
+            .is_err());
+
+        assert_eq!(store.snippets.len(), 1);
+        assert_eq!(store.snippets[0].body, "WITH x AS ($1)");
+    }
+}
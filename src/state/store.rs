@@ -0,0 +1,81 @@
+// FilePath: src/state/store.rs
+
+#![forbid(unsafe_code)]
+
+use crate::config::Config;
+use crate::core::error::Result;
+use serde::{de::DeserializeOwned, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A store kept as one TOML file in `~/.lazytables`, such as bookmarks or
+/// snippets. Implementors only name their file.
+pub trait TomlStore: Serialize + DeserializeOwned + Default {
+    /// File name inside the data directory, e.g. `bookmarks.toml`
+    const FILE_NAME: &'static str;
+
+    /// Store file - ~/.lazytables/<FILE_NAME>
+    fn default_path() -> PathBuf {
+        Config::data_dir().join(Self::FILE_NAME)
+    }
+
+    /// Load `path`; a missing file is an empty store
+    fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// Write the store to `path`, creating its directory if needed
+    fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Load from the default store file
+    fn load() -> Result<Self> {
+        Self::load_from(&Self::default_path())
+    }
+
+    /// Save to the default store file
+    fn save(&self) -> Result<()> {
+        self.save_to(&Self::default_path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Names {
+        #[serde(default)]
+        names: Vec<String>,
+    }
+
+    impl TomlStore for Names {
+        const FILE_NAME: &'static str = "names.toml";
+    }
+
+    #[test]
+    fn test_missing_file_and_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(Names::FILE_NAME);
+        assert_eq!(Names::load_from(&path).unwrap(), Names::default());
+
+        let store = Names {
+            names: vec!["orders".to_string(), "it's \"quoted\"".to_string()],
+        };
+        store.save_to(&path).unwrap();
+        assert_eq!(Names::load_from(&path).unwrap(), store);
+
+        std::fs::write(&path, "names = 3").unwrap();
+        assert!(Names::load_from(&path).is_err());
+    }
+}
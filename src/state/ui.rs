@@ -2,6 +2,7 @@
 
 #![forbid(unsafe_code)]
 
+use crate::database::objects::DatabaseObjectType;
 use ratatui::widgets::ListState;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -78,6 +79,37 @@ impl SelectableTableItem {
         }
     }
 
+    /// Create a collapsible group header such as `▼ Tables (142)`
+    pub fn new_group_header(
+        group: DatabaseObjectType,
+        count: usize,
+        expanded: bool,
+        display_index: usize,
+    ) -> Self {
+        let arrow = if expanded { "▼" } else { "▶" };
+        Self {
+            object_type: group.clone(),
+            ..Self::new_header(
+                format!("{arrow} {} ({count})", group.group_name()),
+                display_index,
+            )
+        }
+    }
+
+    /// Group header, as opposed to a table or a blank separator line
+    pub fn is_header(&self) -> bool {
+        !self.is_selectable && !self.display_name.is_empty()
+    }
+
+    /// Same table, or the header of the same group
+    fn same_entry(&self, other: &SelectableTableItem) -> bool {
+        if self.is_selectable {
+            other.is_selectable && self.qualified_name() == other.qualified_name()
+        } else {
+            self.is_header() && other.is_header() && self.object_type == other.object_type
+        }
+    }
+
     /// Get the qualified name for database operations
     pub fn qualified_name(&self) -> String {
         if let Some(ref schema) = self.schema {
@@ -88,6 +120,68 @@ impl SelectableTableItem {
     }
 }
 
+/// Append a group header and its items, with a blank line before every
+/// header but the first
+fn push_table_group(
+    list: &mut Vec<SelectableTableItem>,
+    group: &DatabaseObjectType,
+    count: usize,
+    expanded: bool,
+    items: Vec<SelectableTableItem>,
+) {
+    if !list.is_empty() {
+        list.push(SelectableTableItem::new_header(String::new(), list.len()));
+    }
+    list.push(SelectableTableItem::new_group_header(
+        group.clone(),
+        count,
+        expanded,
+        list.len(),
+    ));
+    for mut item in items {
+        item.display_index = list.len();
+        list.push(item);
+    }
+}
+
+/// Object types the Tables pane lists, cycled with `T`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TableTypeFilter {
+    #[default]
+    All,
+    /// Tables and foreign tables
+    Tables,
+    /// Views and materialized views
+    Views,
+}
+
+impl TableTypeFilter {
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Tables,
+            Self::Tables => Self::Views,
+            Self::Views => Self::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Tables => "tables only",
+            Self::Views => "views only",
+        }
+    }
+
+    /// Whether objects of this type are listed
+    pub fn shows(self, object_type: &DatabaseObjectType) -> bool {
+        match self {
+            Self::All => true,
+            Self::Tables => !object_type.is_read_only(),
+            Self::Views => object_type.is_read_only(),
+        }
+    }
+}
+
 /// Which pane currently has focus
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FocusedPane {
@@ -95,6 +189,8 @@ pub enum FocusedPane {
     Connections,
     /// Tables/Views list pane
     Tables,
+    /// Stored functions and procedures, shown in place of the tables pane
+    Routines,
     /// Table details pane
     Details,
     /// Tabular output area
@@ -110,7 +206,7 @@ impl FocusedPane {
     pub fn next(&self) -> Self {
         match self {
             Self::Connections => Self::Tables,
-            Self::Tables => Self::Details,
+            Self::Tables | Self::Routines => Self::Details,
             Self::Details => Self::TabularOutput,
             Self::TabularOutput => Self::SqlFiles,
             Self::SqlFiles => Self::QueryWindow,
@@ -122,7 +218,7 @@ impl FocusedPane {
     pub fn previous(&self) -> Self {
         match self {
             Self::Connections => Self::QueryWindow,
-            Self::Tables => Self::Connections,
+            Self::Tables | Self::Routines => Self::Connections,
             Self::Details => Self::Tables,
             Self::TabularOutput => Self::Details,
             Self::SqlFiles => Self::TabularOutput,
@@ -134,7 +230,7 @@ impl FocusedPane {
     pub fn to_number(&self) -> u8 {
         match self {
             Self::Connections => 1,
-            Self::Tables => 2,
+            Self::Tables | Self::Routines => 2,
             Self::Details => 3,
             Self::TabularOutput => 4,
             Self::QueryWindow => 5,
@@ -160,6 +256,7 @@ impl FocusedPane {
         match self {
             Self::Connections => "Connections",
             Self::Tables => "Tables",
+            Self::Routines => "Routines",
             Self::Details => "Table Details",
             Self::TabularOutput => "Query Results",
             Self::QueryWindow => "SQL Query Editor",
@@ -251,16 +348,40 @@ pub struct UIState {
     pub details_content_height: usize,
     /// Maximum scroll offset for details pane (updated during rendering)
     pub details_max_scroll_offset: usize,
+    /// Rows inside each help pane (updated during rendering)
+    #[serde(skip)]
+    pub help_viewport_height: usize,
+    /// Highlighted entry of the details pane column list
+    #[serde(skip)]
+    pub details_selected_column: usize,
+    /// Line of the first column entry in the details pane (updated during rendering)
+    #[serde(skip)]
+    pub details_columns_start: Option<usize>,
+    /// Whether 'y' was pressed in the details pane and we're waiting for the second 'y'
+    #[serde(skip)]
+    pub pending_details_yank: bool,
 
     // Overlay-specific state
     /// Debug view scroll offset
     pub debug_view_scroll_offset: usize,
+    /// Whether the level filter bar is shown in the debug view
+    #[serde(skip)]
+    pub debug_view_filter_bar: bool,
+    /// Lowest level shown in the debug view; `None` shows everything
+    #[serde(skip)]
+    pub debug_view_min_level: Option<crate::cli::LogLevel>,
     /// Connection mode scroll offset (used for connection form overlay)
     pub connection_mode_scroll_offset: usize,
 
     /// Confirmation modal state
     #[serde(skip)]
     pub confirmation_modal: Option<crate::ui::ConfirmationModal>,
+    /// Screen row of the selected Tables pane entry (updated during rendering)
+    #[serde(skip)]
+    pub tables_selected_row: Option<ratatui::layout::Rect>,
+    /// `Ctrl+w` resize mode: h/l and j/k resize panes until Esc
+    #[serde(skip)]
+    pub resize_mode: bool,
 
     // Hierarchical browsing state
     /// Expanded schemas/databases in tables pane
@@ -272,8 +393,14 @@ pub struct UIState {
     /// Flat list of selectable table items for navigation
     #[serde(skip)]
     pub selectable_table_items: Vec<SelectableTableItem>,
+    /// Every listed object with all groups expanded, searched by `/`
+    #[serde(skip)]
+    pub all_table_items: Vec<SelectableTableItem>,
     /// Index of currently selected table in the selectable items list
     pub selected_table_item_index: usize,
+    /// Object types shown, cycled with `T`
+    #[serde(default)]
+    pub tables_type_filter: TableTypeFilter,
 
     // Table search state
     /// Whether search mode is active in tables pane
@@ -312,11 +439,22 @@ pub struct UIState {
     /// New file name buffer during creation
     pub sql_files_create_buffer: String,
 
+    /// Last directory a SQLite database file was picked from
+    #[serde(default)]
+    pub last_sqlite_dir: Option<String>,
+
     // List UI states (not serialized)
     #[serde(skip)]
     pub connections_list_state: ListState,
     #[serde(skip)]
     pub tables_list_state: ListState,
+
+    // Routines pane state (not serialized)
+    /// Selected routine index in the routines pane
+    #[serde(skip)]
+    pub selected_routine: usize,
+    #[serde(skip)]
+    pub routines_list_state: ListState,
 }
 
 impl UIState {
@@ -348,9 +486,17 @@ impl UIState {
             details_viewport_height: 0,
             details_content_height: 0,
             details_max_scroll_offset: 0,
+            help_viewport_height: 0,
+            details_selected_column: 0,
+            details_columns_start: None,
+            pending_details_yank: false,
             debug_view_scroll_offset: 0,
+            debug_view_filter_bar: false,
+            debug_view_min_level: None,
             connection_mode_scroll_offset: 0,
             confirmation_modal: None,
+            tables_selected_row: None,
+            resize_mode: false,
             expanded_schemas: std::collections::HashSet::new(),
             expanded_object_groups: {
                 let mut groups = std::collections::HashSet::new();
@@ -359,7 +505,9 @@ impl UIState {
                 groups
             },
             selectable_table_items: Vec::new(),
+            all_table_items: Vec::new(),
             selected_table_item_index: 0,
+            tables_type_filter: TableTypeFilter::default(),
             tables_search_active: false,
             tables_search_query: String::new(),
             filtered_table_items: Vec::new(),
@@ -373,8 +521,11 @@ impl UIState {
             sql_files_rename_buffer: String::new(),
             sql_files_create_mode: false,
             sql_files_create_buffer: String::new(),
+            last_sqlite_dir: None,
             connections_list_state,
             tables_list_state: ListState::default(),
+            selected_routine: 0,
+            routines_list_state: ListState::default(),
         }
     }
 
@@ -405,6 +556,11 @@ impl UIState {
                 .select(Some(state.selected_connection));
         }
 
+        // The routines list is not persisted, so start back on the tables pane
+        if state.focused_pane == FocusedPane::Routines {
+            state.focused_pane = FocusedPane::Tables;
+        }
+
         state.tables_list_state = ListState::default();
         if state.selected_table > 0 {
             state
@@ -447,7 +603,7 @@ impl UIState {
             }
 
             let should_skip = match new_pane {
-                FocusedPane::Tables => !tables_enabled,
+                FocusedPane::Tables | FocusedPane::Routines => !tables_enabled,
                 FocusedPane::Details => !details_enabled,
                 FocusedPane::TabularOutput => !query_results_enabled,
                 FocusedPane::QueryWindow => !query_editor_enabled,
@@ -492,7 +648,7 @@ impl UIState {
             }
 
             let should_skip = match new_pane {
-                FocusedPane::Tables => !tables_enabled,
+                FocusedPane::Tables | FocusedPane::Routines => !tables_enabled,
                 FocusedPane::Details => !details_enabled,
                 FocusedPane::TabularOutput => !query_results_enabled,
                 FocusedPane::QueryWindow => !query_editor_enabled,
@@ -575,7 +731,7 @@ impl UIState {
                     self.focused_pane // Stay in place
                 }
             }
-            FocusedPane::Tables => {
+            FocusedPane::Tables | FocusedPane::Routines => {
                 if details_enabled {
                     FocusedPane::Details
                 } else {
@@ -599,7 +755,7 @@ impl UIState {
     /// Move focus up (Ctrl+k) (connection-aware)
     pub fn move_focus_up(&mut self, tables_enabled: bool, _details_enabled: bool) {
         let new_pane = match self.focused_pane {
-            FocusedPane::Tables => FocusedPane::Connections,
+            FocusedPane::Tables | FocusedPane::Routines => FocusedPane::Connections,
             FocusedPane::Details => {
                 if tables_enabled {
                     FocusedPane::Tables
@@ -624,7 +780,7 @@ impl UIState {
         query_results_enabled: bool,
     ) {
         let new_pane = match self.focused_pane {
-            FocusedPane::Connections | FocusedPane::Tables => {
+            FocusedPane::Connections | FocusedPane::Tables | FocusedPane::Routines => {
                 if query_results_enabled {
                     FocusedPane::TabularOutput
                 } else {
@@ -673,6 +829,13 @@ impl UIState {
         self.focused_pane = new_pane;
     }
 
+    /// Select a routine, clamped to the `count` routines listed
+    pub fn select_routine(&mut self, index: usize, count: usize) {
+        self.selected_routine = index.min(count.saturating_sub(1));
+        self.routines_list_state
+            .select((count > 0).then_some(self.selected_routine));
+    }
+
     /// Update connection list selection state
     pub fn update_connection_selection(&mut self, count: usize) {
         if count > 0 {
@@ -809,242 +972,195 @@ impl UIState {
         self.expanded_object_groups.contains(group_name)
     }
 
-    /// Build the selectable table items list from database objects
+    /// Build the selectable table items list from database objects. Collapsed
+    /// groups keep only their header, and the cursor stays on the same table
+    /// or header when it is still listed.
     pub fn build_selectable_table_items(
         &mut self,
         db_objects: &Option<crate::database::objects::DatabaseObjectList>,
     ) {
+        let previous = self.get_selected_item_raw().cloned();
         self.selectable_table_items.clear();
+        self.all_table_items.clear();
 
         if let Some(ref objects) = db_objects {
-            let mut display_index = 0;
-
-            // Add tables section
-            if !objects.tables.is_empty() {
-                let is_expanded = self.is_object_group_expanded("Tables");
-                let arrow = if is_expanded { "▼" } else { "▶" };
-                self.selectable_table_items
-                    .push(SelectableTableItem::new_header(
-                        format!("{} Tables", arrow),
-                        display_index,
-                    ));
-                display_index += 1;
-
-                if is_expanded {
-                    for table in &objects.tables {
-                        self.selectable_table_items
-                            .push(SelectableTableItem::new_selectable(
-                                format!("  📋 {}", table.name),
-                                table.name.clone(),
-                                table.schema.clone(),
-                                table.object_type.clone(),
-                                display_index,
-                            ));
-                        display_index += 1;
-                    }
-                }
-            }
-
-            // Add views section
-            if !objects.views.is_empty() {
-                if !self.selectable_table_items.is_empty() {
-                    self.selectable_table_items
-                        .push(SelectableTableItem::new_header(
-                            "".to_string(),
-                            display_index,
-                        ));
-                    display_index += 1;
-                }
-
-                let is_expanded = self.is_object_group_expanded("Views");
-                let arrow = if is_expanded { "▼" } else { "▶" };
-                self.selectable_table_items
-                    .push(SelectableTableItem::new_header(
-                        format!("{} Views", arrow),
-                        display_index,
-                    ));
-                display_index += 1;
-
-                if is_expanded {
-                    for view in &objects.views {
-                        self.selectable_table_items
-                            .push(SelectableTableItem::new_selectable(
-                                format!("  👁️ {}", view.name),
-                                view.name.clone(),
-                                view.schema.clone(),
-                                view.object_type.clone(),
-                                display_index,
-                            ));
-                        display_index += 1;
-                    }
-                }
-            }
-
-            // Add materialized views section
-            if !objects.materialized_views.is_empty() {
-                if !self.selectable_table_items.is_empty() {
-                    self.selectable_table_items
-                        .push(SelectableTableItem::new_header(
-                            "".to_string(),
-                            display_index,
-                        ));
-                    display_index += 1;
-                }
-
-                let is_expanded = self.is_object_group_expanded("Materialized Views");
-                let arrow = if is_expanded { "▼" } else { "▶" };
-                self.selectable_table_items
-                    .push(SelectableTableItem::new_header(
-                        format!("{} Materialized Views", arrow),
-                        display_index,
-                    ));
-                display_index += 1;
-
-                if is_expanded {
-                    for mv in &objects.materialized_views {
-                        self.selectable_table_items
-                            .push(SelectableTableItem::new_selectable(
-                                format!("  🔄 {}", mv.name),
-                                mv.name.clone(),
-                                mv.schema.clone(),
-                                mv.object_type.clone(),
-                                display_index,
-                            ));
-                        display_index += 1;
-                    }
-                }
-            }
-
-            // Add foreign tables section
-            if !objects.foreign_tables.is_empty() {
-                if !self.selectable_table_items.is_empty() {
-                    self.selectable_table_items
-                        .push(SelectableTableItem::new_header(
-                            "".to_string(),
-                            display_index,
-                        ));
-                    display_index += 1;
-                }
-
-                let is_expanded = self.is_object_group_expanded("Foreign Tables");
-                let arrow = if is_expanded { "▼" } else { "▶" };
-                self.selectable_table_items
-                    .push(SelectableTableItem::new_header(
-                        format!("{} Foreign Tables", arrow),
-                        display_index,
-                    ));
-                display_index += 1;
-
-                if is_expanded {
-                    for ft in &objects.foreign_tables {
-                        self.selectable_table_items
-                            .push(SelectableTableItem::new_selectable(
-                                format!("  🔗 {}", ft.name),
-                                ft.name.clone(),
-                                ft.schema.clone(),
-                                ft.object_type.clone(),
-                                display_index,
-                            ));
-                        display_index += 1;
-                    }
+            for (group, group_objects) in objects.groups() {
+                if group_objects.is_empty() || !self.tables_type_filter.shows(&group) {
+                    continue;
                 }
+                let items: Vec<SelectableTableItem> = group_objects
+                    .iter()
+                    .map(|object| {
+                        SelectableTableItem::new_selectable(
+                            format!("  {} {}", object.object_type.icon(), object.name),
+                            object.name.clone(),
+                            object.schema.clone(),
+                            object.object_type.clone(),
+                            0,
+                        )
+                    })
+                    .collect();
+                let expanded = self.is_object_group_expanded(group.group_name());
+                push_table_group(
+                    &mut self.selectable_table_items,
+                    &group,
+                    items.len(),
+                    expanded,
+                    if expanded { items.clone() } else { Vec::new() },
+                );
+                push_table_group(&mut self.all_table_items, &group, items.len(), true, items);
             }
         }
 
-        // Reset selection to first selectable item
-        self.selected_table_item_index = self.find_first_selectable_index();
+        if self.tables_search_active {
+            self.update_filtered_table_items();
+        }
+        let items = self.get_display_table_items();
+        self.selected_table_item_index = previous
+            .and_then(|previous| items.iter().position(|item| item.same_entry(&previous)))
+            .unwrap_or_else(|| self.find_first_selectable_index());
         self.update_tables_list_state_selection();
     }
 
-    /// Find the index of the first selectable item
-    fn find_first_selectable_index(&self) -> usize {
-        for (idx, item) in self.selectable_table_items.iter().enumerate() {
-            if item.is_selectable {
-                return idx;
-            }
+    /// Collapse or expand the group under the cursor, keeping the cursor on
+    /// its header. Returns the group name and whether it is now expanded.
+    pub fn toggle_selected_table_group(
+        &mut self,
+        db_objects: &Option<crate::database::objects::DatabaseObjectList>,
+    ) -> Option<(&'static str, bool)> {
+        let item = self.get_selected_item_raw()?;
+        if !item.is_header() {
+            return None;
         }
-        0
+        let group = item.object_type.group_name();
+        self.toggle_object_group_expansion(group);
+        self.build_selectable_table_items(db_objects);
+        Some((group, self.is_object_group_expanded(group)))
     }
 
-    /// Move table selection down (j key)
-    pub fn table_selection_down(&mut self) {
-        if self.selectable_table_items.is_empty() {
-            crate::log_debug!("Cannot move table selection down: no table items");
-            return;
-        }
+    /// Move to the next `T` filter and rebuild the list
+    pub fn cycle_tables_type_filter(
+        &mut self,
+        db_objects: &Option<crate::database::objects::DatabaseObjectList>,
+    ) -> TableTypeFilter {
+        self.tables_type_filter = self.tables_type_filter.next();
+        self.build_selectable_table_items(db_objects);
+        self.tables_type_filter
+    }
 
-        let old_index = self.selected_table_item_index;
-        let mut next_index = self.selected_table_item_index + 1;
+    /// Index of the first table, or of the first header when every group is
+    /// collapsed
+    fn find_first_selectable_index(&self) -> usize {
+        let items = self.get_display_table_items();
+        items
+            .iter()
+            .position(|item| item.is_selectable)
+            .or_else(|| items.iter().position(SelectableTableItem::is_header))
+            .unwrap_or(0)
+    }
 
-        // Find next selectable item
-        while next_index < self.selectable_table_items.len() {
-            if self.selectable_table_items[next_index].is_selectable {
-                self.selected_table_item_index = next_index;
-                crate::log_debug!(
-                    "Moved table selection down from {} to {} (table: '{}')",
-                    old_index,
-                    next_index,
-                    self.selectable_table_items[next_index].object_name
-                );
+    /// Select the table item with the given qualified name; false if it is not listed
+    pub fn select_table_by_name(&mut self, name: &str) -> bool {
+        match self
+            .get_display_table_items()
+            .iter()
+            .position(|item| item.is_selectable && item.qualified_name() == name)
+        {
+            Some(index) => {
+                self.selected_table_item_index = index;
                 self.update_tables_list_state_selection();
-                return;
+                true
             }
-            next_index += 1;
+            None => false,
         }
+    }
 
-        // If we reached the end, wrap to first selectable item
-        self.selected_table_item_index = self.find_first_selectable_index();
-        crate::log_debug!(
-            "Wrapped table selection to first item at index {}",
-            self.selected_table_item_index
-        );
+    /// Put the cursor on row `index`, clamped to the end of the list, or on
+    /// the nearest table or header above it when that row is neither
+    pub fn select_table_near(&mut self, index: usize) {
+        let items = self.get_display_table_items();
+        let Some(last) = items.len().checked_sub(1) else {
+            return;
+        };
+        let stops = |item: &SelectableTableItem| item.is_selectable || item.is_header();
+        self.selected_table_item_index = items[..=index.min(last)]
+            .iter()
+            .rposition(stops)
+            .or_else(|| items.iter().position(stops))
+            .unwrap_or(0);
         self.update_tables_list_state_selection();
     }
 
-    /// Move table selection up (k key)
-    pub fn table_selection_up(&mut self) {
-        if self.selectable_table_items.is_empty() {
-            crate::log_debug!("Cannot move table selection up: no table items");
-            return;
+    /// Select a table, clearing the `T` filter or expanding its group when
+    /// that is what hides it; false if it is not listed at all
+    pub fn reveal_table(
+        &mut self,
+        name: &str,
+        db_objects: &Option<crate::database::objects::DatabaseObjectList>,
+    ) -> bool {
+        if self.select_table_by_name(name) {
+            return true;
+        }
+        let is_listed = |items: &[SelectableTableItem]| {
+            items
+                .iter()
+                .position(|item| item.is_selectable && item.qualified_name() == name)
+        };
+        if is_listed(&self.all_table_items).is_none()
+            && self.tables_type_filter != TableTypeFilter::All
+        {
+            self.tables_type_filter = TableTypeFilter::All;
+            self.build_selectable_table_items(db_objects);
         }
+        let Some(index) = is_listed(&self.all_table_items) else {
+            return false;
+        };
+        if let Some(header) = self.all_table_items[..index]
+            .iter()
+            .rev()
+            .find(|item| item.is_header())
+        {
+            let group = header.object_type.group_name();
+            self.expanded_object_groups.insert(group.to_string());
+            self.build_selectable_table_items(db_objects);
+        }
+        self.select_table_by_name(name)
+    }
 
-        let old_index = self.selected_table_item_index;
-
-        // Find previous selectable item, wrapping around if needed
-        if self.selected_table_item_index > 0 {
-            // Look backwards from current position
-            let mut prev_index = self.selected_table_item_index - 1;
-            loop {
-                if self.selectable_table_items[prev_index].is_selectable {
-                    self.selected_table_item_index = prev_index;
-                    crate::log_debug!(
-                        "Moved table selection up from {} to {} (table: '{}')",
-                        old_index,
-                        prev_index,
-                        self.selectable_table_items[prev_index].object_name
-                    );
-                    self.update_tables_list_state_selection();
-                    return;
-                }
-                if prev_index == 0 {
-                    break;
+    /// Next (or previous) row the cursor can stop on, wrapping around: tables
+    /// and group headers, or only tables among search results
+    fn step_table_selection(&self, forward: bool) -> Option<usize> {
+        let items = self.get_display_table_items();
+        if items.is_empty() {
+            return None;
+        }
+        let searching = self.tables_search_active && !self.filtered_table_items.is_empty();
+        let len = items.len();
+        let current = self.selected_table_item_index.min(len - 1);
+        (1..=len)
+            .map(|step| {
+                if forward {
+                    (current + step) % len
+                } else {
+                    (current + len - step) % len
                 }
-                prev_index -= 1;
-            }
+            })
+            .find(|&index| items[index].is_selectable || (!searching && items[index].is_header()))
+    }
+
+    /// Move table selection down (j key)
+    pub fn table_selection_down(&mut self) {
+        if let Some(index) = self.step_table_selection(true) {
+            self.selected_table_item_index = index;
+            self.update_tables_list_state_selection();
         }
+    }
 
-        // If we didn't find anything above, or we're at index 0, wrap to last selectable item
-        for i in (0..self.selectable_table_items.len()).rev() {
-            if self.selectable_table_items[i].is_selectable {
-                self.selected_table_item_index = i;
-                crate::log_debug!(
-                    "Wrapped table selection to last item at index {} (table: '{}')",
-                    i,
-                    self.selectable_table_items[i].object_name
-                );
-                self.update_tables_list_state_selection();
-                return;
-            }
+    /// Move table selection up (k key)
+    pub fn table_selection_up(&mut self) {
+        if let Some(index) = self.step_table_selection(false) {
+            self.selected_table_item_index = index;
+            self.update_tables_list_state_selection();
         }
     }
 
@@ -1096,18 +1212,21 @@ impl UIState {
         self.update_filtered_table_items();
     }
 
-    /// Exit search mode for tables pane
+    /// Exit search mode for tables pane, keeping the highlighted match
+    /// selected when its group is expanded
     pub fn exit_tables_search(&mut self) {
         crate::log_debug!(
             "Exiting tables search mode (query was: '{}')",
             self.tables_search_query
         );
+        let highlighted = self.get_selected_table_name();
         self.tables_search_active = false;
         self.tables_search_query.clear();
         self.filtered_table_items.clear();
-        // Reset selection to the main list
-        self.selected_table_item_index = self.find_first_selectable_index();
-        self.update_tables_list_state_selection();
+        if !highlighted.is_some_and(|name| self.select_table_by_name(&name)) {
+            self.selected_table_item_index = self.find_first_selectable_index();
+            self.update_tables_list_state_selection();
+        }
     }
 
     /// Add character to search query
@@ -1135,34 +1254,55 @@ impl UIState {
         }
     }
 
-    /// Update filtered table items based on search query
+    /// Update filtered table items based on search query. Matches come from
+    /// every group, collapsed or not, listed under their group headers.
     fn update_filtered_table_items(&mut self) {
+        self.filtered_table_items.clear();
         if !self.tables_search_active || self.tables_search_query.is_empty() {
-            self.filtered_table_items.clear();
             return;
         }
 
         let query = self.tables_search_query.to_lowercase();
-        self.filtered_table_items.clear();
-
-        for item in &self.selectable_table_items {
-            if item.is_selectable {
+        let mut groups: Vec<(DatabaseObjectType, Vec<SelectableTableItem>)> = Vec::new();
+        for item in &self.all_table_items {
+            if item.is_header() {
+                groups.push((item.object_type.clone(), Vec::new()));
+            } else if item.is_selectable
                 // Check if the table name contains the search query characters in sequence
-                let table_name = item.object_name.to_lowercase();
-                if matches_sequence(&table_name, &query) {
-                    self.filtered_table_items.push(item.clone());
+                && matches_sequence(&item.object_name.to_lowercase(), &query)
+            {
+                if let Some((_, matches)) = groups.last_mut() {
+                    matches.push(item.clone());
                 }
             }
         }
+        for (group, matches) in groups {
+            if !matches.is_empty() {
+                push_table_group(
+                    &mut self.filtered_table_items,
+                    &group,
+                    matches.len(),
+                    true,
+                    matches,
+                );
+            }
+        }
 
         crate::log_debug!(
             "Filtered tables with query '{}': found {} matches",
             self.tables_search_query,
-            self.filtered_table_items.len()
+            self.filtered_table_items
+                .iter()
+                .filter(|item| item.is_selectable)
+                .count()
         );
 
-        // Reset selection to first filtered item
-        self.selected_table_item_index = 0;
+        // Reset selection to the first match
+        self.selected_table_item_index = self
+            .filtered_table_items
+            .iter()
+            .position(|item| item.is_selectable)
+            .unwrap_or(0);
         self.update_tables_list_state_selection();
     }
 
@@ -1177,74 +1317,35 @@ impl UIState {
 
     /// Navigate down in search results or main list
     pub fn table_search_selection_down(&mut self) {
-        let items = if self.tables_search_active && !self.filtered_table_items.is_empty() {
-            &self.filtered_table_items
-        } else {
-            &self.selectable_table_items
-        };
-
-        if items.is_empty() {
-            return;
-        }
-
-        if self.tables_search_active && !self.filtered_table_items.is_empty() {
-            // Navigate through filtered results
-            self.selected_table_item_index = (self.selected_table_item_index + 1) % items.len();
-        } else {
-            // Use existing navigation logic for main list
-            self.table_selection_down();
-            return;
-        }
-
-        self.update_tables_list_state_selection();
+        self.table_selection_down();
     }
 
     /// Navigate up in search results or main list
     pub fn table_search_selection_up(&mut self) {
-        let items = if self.tables_search_active && !self.filtered_table_items.is_empty() {
-            &self.filtered_table_items
-        } else {
-            &self.selectable_table_items
-        };
-
-        if items.is_empty() {
-            return;
-        }
-
-        if self.tables_search_active && !self.filtered_table_items.is_empty() {
-            // Navigate through filtered results
-            self.selected_table_item_index = if self.selected_table_item_index > 0 {
-                self.selected_table_item_index - 1
-            } else {
-                items.len() - 1
-            };
-        } else {
-            // Use existing navigation logic for main list
-            self.table_selection_up();
-            return;
-        }
-
-        self.update_tables_list_state_selection();
+        self.table_selection_up();
     }
 
-    /// Go to first selectable table (vim gg command)
+    /// Go to first table, or the first header when every group is collapsed (vim gg command)
     pub fn table_go_to_first(&mut self) {
-        self.selected_table_item_index = self.find_first_selectable_index();
+        let items = self.get_display_table_items();
+        self.selected_table_item_index = items
+            .iter()
+            .position(|item| item.is_selectable)
+            .or_else(|| items.iter().position(SelectableTableItem::is_header))
+            .unwrap_or(0);
         self.update_tables_list_state_selection();
         self.pending_gg_command = false;
     }
 
-    /// Go to last selectable table (vim G command)
+    /// Go to last table, or the last header when every group is collapsed (vim G command)
     pub fn table_go_to_last(&mut self) {
         let items = self.get_display_table_items();
-        if !items.is_empty() {
-            // Find the last selectable item
-            for i in (0..items.len()).rev() {
-                if items[i].is_selectable {
-                    self.selected_table_item_index = i;
-                    break;
-                }
-            }
+        if let Some(index) = items
+            .iter()
+            .rposition(|item| item.is_selectable)
+            .or_else(|| items.iter().rposition(SelectableTableItem::is_header))
+        {
+            self.selected_table_item_index = index;
         }
         self.update_tables_list_state_selection();
     }
@@ -1384,6 +1485,15 @@ impl UIState {
         }
     }
 
+    /// Toggle toast history overlay
+    pub fn toggle_toast_history(&mut self) {
+        if self.current_view.is_toast_history() {
+            self.return_to_main();
+        } else {
+            self.show_overlay(crate::state::view::OverlayView::ToastHistory);
+        }
+    }
+
     /// Scroll debug view down
     pub fn debug_view_scroll_down(&mut self, max_lines: usize) {
         if max_lines > 0 && self.debug_view_scroll_offset < max_lines.saturating_sub(1) {
@@ -1419,6 +1529,38 @@ impl UIState {
         self.debug_view_scroll_offset = max_lines.saturating_sub(1);
     }
 
+    /// Show or hide the debug view level filter; hiding it shows all levels again
+    pub fn toggle_debug_view_filter_bar(&mut self) {
+        self.debug_view_filter_bar = !self.debug_view_filter_bar;
+        if !self.debug_view_filter_bar {
+            self.debug_view_min_level = None;
+        }
+        self.debug_view_scroll_offset = 0;
+    }
+
+    /// Step the debug view level filter through ALL, DEBUG, INFO, WARN, ERROR
+    pub fn cycle_debug_view_level(&mut self, forward: bool) {
+        use crate::cli::LogLevel;
+        const LEVELS: [Option<LogLevel>; 5] = [
+            None,
+            Some(LogLevel::Debug),
+            Some(LogLevel::Info),
+            Some(LogLevel::Warn),
+            Some(LogLevel::Error),
+        ];
+        let current = LEVELS
+            .iter()
+            .position(|level| *level == self.debug_view_min_level)
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % LEVELS.len()
+        } else {
+            (current + LEVELS.len() - 1) % LEVELS.len()
+        };
+        self.debug_view_min_level = LEVELS[next];
+        self.debug_view_scroll_offset = 0;
+    }
+
     /// Enter connection form overlay for adding a new connection
     pub fn enter_add_connection_mode(&mut self) {
         self.connection_mode_scroll_offset = 0;
@@ -1668,6 +1810,44 @@ impl UIState {
         }
     }
 
+    /// Fit the details pane scroll to a viewport of `viewport_height` rows
+    /// over the last rendered content, e.g. after the terminal shrank
+    pub fn reflow_details(&mut self, viewport_height: usize) {
+        self.details_viewport_height = viewport_height;
+        self.details_max_scroll_offset =
+            self.details_content_height.saturating_sub(viewport_height);
+        self.details_viewport_offset = crate::ui::layout::clamp_scroll(
+            self.details_viewport_offset,
+            self.details_content_height,
+            viewport_height,
+        );
+    }
+
+    /// Rows Ctrl+D/Ctrl+U move the details pane: half of what it shows
+    pub fn details_half_page(&self) -> usize {
+        (self.details_viewport_height / 2).max(1)
+    }
+
+    /// Fit both help panes' scroll to `viewport_height` rows of their content
+    pub fn reflow_help(&mut self, left_rows: usize, right_rows: usize, viewport_height: usize) {
+        self.help_viewport_height = viewport_height;
+        self.help_left_scroll_offset = crate::ui::layout::clamp_scroll(
+            self.help_left_scroll_offset,
+            left_rows,
+            viewport_height,
+        );
+        self.help_right_scroll_offset = crate::ui::layout::clamp_scroll(
+            self.help_right_scroll_offset,
+            right_rows,
+            viewport_height,
+        );
+    }
+
+    /// Rows PageUp/PageDown move a help pane: what it shows, less one for context
+    pub fn help_page_size(&self) -> usize {
+        self.help_viewport_height.saturating_sub(1).max(1)
+    }
+
     /// Page up in the currently focused help pane
     pub fn help_page_up(&mut self, page_size: usize) {
         match self.help_pane_focus {
@@ -1693,6 +1873,31 @@ impl Default for UIState {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_reflow_after_resize() {
+        let mut ui = UIState::new();
+        ui.details_content_height = 60;
+        ui.reflow_details(40);
+        ui.details_viewport_offset = ui.details_max_scroll_offset;
+        assert_eq!(ui.details_viewport_offset, 20);
+        assert_eq!(ui.details_half_page(), 20);
+
+        // A taller pane shows more, so less scrolling is needed
+        ui.reflow_details(50);
+        assert_eq!(ui.details_viewport_offset, 10);
+        assert_eq!(ui.details_max_scroll_offset, 10);
+        assert_eq!(ui.details_half_page(), 25);
+        ui.reflow_details(0);
+        assert_eq!(ui.details_half_page(), 1);
+
+        ui.help_left_scroll_offset = 90;
+        ui.help_right_scroll_offset = 5;
+        ui.reflow_help(100, 30, 25);
+        assert_eq!(ui.help_left_scroll_offset, 75);
+        assert_eq!(ui.help_right_scroll_offset, 5);
+        assert_eq!(ui.help_page_size(), 24);
+    }
+
     #[test]
     fn test_matches_sequence() {
         assert!(matches_sequence("users", "usr"));
@@ -1713,12 +1918,27 @@ mod tests {
         assert!(matches_sequence("tasks", "k")); // should match 'k' in "tasks"
     }
 
+    /// Tables among the search results, leaving out group headers
+    fn match_count(ui_state: &UIState) -> usize {
+        ui_state
+            .filtered_table_items
+            .iter()
+            .filter(|item| item.is_selectable)
+            .count()
+    }
+
     #[test]
     fn test_tables_search_functionality() {
         let mut ui_state = UIState::new();
 
         // Add some mock table items
-        ui_state.selectable_table_items = vec![
+        ui_state.all_table_items = vec![
+            SelectableTableItem::new_group_header(
+                crate::database::objects::DatabaseObjectType::Table,
+                3,
+                true,
+                0,
+            ),
             SelectableTableItem::new_selectable(
                 "users".to_string(),
                 "users".to_string(),
@@ -1750,28 +1970,28 @@ mod tests {
         // Test adding search query
         ui_state.add_to_tables_search('u');
         assert_eq!(ui_state.tables_search_query, "u");
-        assert_eq!(ui_state.filtered_table_items.len(), 2); // "users" and "accounts" both match "u"
+        assert_eq!(match_count(&ui_state), 2); // "users" and "accounts" both match "u"
 
         ui_state.add_to_tables_search('s');
         assert_eq!(ui_state.tables_search_query, "us");
-        assert_eq!(ui_state.filtered_table_items.len(), 2); // both "users" and "accounts" match "us" sequence (u, s)
+        assert_eq!(match_count(&ui_state), 2); // both "users" and "accounts" match "us" sequence (u, s)
 
         ui_state.add_to_tables_search('e');
         assert_eq!(ui_state.tables_search_query, "use");
-        assert_eq!(ui_state.filtered_table_items.len(), 1); // only "users" matches "use" sequence
+        assert_eq!(match_count(&ui_state), 1); // only "users" matches "use" sequence
 
         ui_state.add_to_tables_search('r');
         assert_eq!(ui_state.tables_search_query, "user");
-        assert_eq!(ui_state.filtered_table_items.len(), 1); // only "users" matches "user" sequence
+        assert_eq!(match_count(&ui_state), 1); // only "users" matches "user" sequence
 
         ui_state.add_to_tables_search('s');
         assert_eq!(ui_state.tables_search_query, "users");
-        assert_eq!(ui_state.filtered_table_items.len(), 1); // only "users" matches "users" completely
+        assert_eq!(match_count(&ui_state), 1); // only "users" matches "users" completely
 
         // Test backspace
         ui_state.backspace_tables_search(); // remove 's'
         assert_eq!(ui_state.tables_search_query, "user");
-        assert_eq!(ui_state.filtered_table_items.len(), 1);
+        assert_eq!(match_count(&ui_state), 1);
 
         // Test exiting search
         ui_state.exit_tables_search();
@@ -1785,7 +2005,13 @@ mod tests {
         let mut ui_state = UIState::new();
 
         // Add mock table items that contain 'j' and 'k' characters
-        ui_state.selectable_table_items = vec![
+        ui_state.all_table_items = vec![
+            SelectableTableItem::new_group_header(
+                crate::database::objects::DatabaseObjectType::Table,
+                3,
+                true,
+                0,
+            ),
             SelectableTableItem::new_selectable(
                 "projects".to_string(),
                 "projects".to_string(),
@@ -1813,16 +2039,16 @@ mod tests {
         ui_state.enter_tables_search();
         ui_state.add_to_tables_search('j');
         assert_eq!(ui_state.tables_search_query, "j");
-        assert_eq!(ui_state.filtered_table_items.len(), 1); // only "projects" contains 'j'
-        assert_eq!(ui_state.filtered_table_items[0].object_name, "projects");
+        assert_eq!(match_count(&ui_state), 1); // only "projects" contains 'j'
+        assert_eq!(ui_state.filtered_table_items[1].object_name, "projects");
 
         // Clear and test searching for 'k'
         ui_state.exit_tables_search();
         ui_state.enter_tables_search();
         ui_state.add_to_tables_search('k');
         assert_eq!(ui_state.tables_search_query, "k");
-        assert_eq!(ui_state.filtered_table_items.len(), 1); // only "tasks" contains 'k'
-        assert_eq!(ui_state.filtered_table_items[0].object_name, "tasks");
+        assert_eq!(match_count(&ui_state), 1); // only "tasks" contains 'k'
+        assert_eq!(ui_state.filtered_table_items[1].object_name, "tasks");
     }
 
     #[test]
@@ -1838,12 +2064,21 @@ mod tests {
                 database_type: DatabaseType::PostgreSQL,
                 host: "localhost".to_string(),
                 port: 5432,
+                port_env: None,
+                service_name: None,
+                instance_name: None,
                 database: Some("prod".to_string()),
                 username: "user".to_string(),
                 password_source: None,
                 password: None,
                 ssl_mode: crate::database::SslMode::Prefer,
                 timeout: None,
+                default_schema: None,
+                init_sql: Vec::new(),
+                ssl_ca_cert: None,
+                ssl_client_cert: None,
+                ssl_client_key: None,
+                favorite: false,
                 status: ConnectionStatus::Disconnected,
             },
             ConnectionConfig {
@@ -1852,12 +2087,21 @@ mod tests {
                 database_type: DatabaseType::MySQL,
                 host: "localhost".to_string(),
                 port: 3306,
+                port_env: None,
+                service_name: None,
+                instance_name: None,
                 database: Some("dev".to_string()),
                 username: "user".to_string(),
                 password_source: None,
                 password: None,
                 ssl_mode: crate::database::SslMode::Prefer,
                 timeout: None,
+                default_schema: None,
+                init_sql: Vec::new(),
+                ssl_ca_cert: None,
+                ssl_client_cert: None,
+                ssl_client_key: None,
+                favorite: false,
                 status: ConnectionStatus::Disconnected,
             },
             ConnectionConfig {
@@ -1866,12 +2110,21 @@ mod tests {
                 database_type: DatabaseType::SQLite,
                 host: "test.db".to_string(),
                 port: 0,
+                port_env: None,
+                service_name: None,
+                instance_name: None,
                 database: Some("test.db".to_string()),
                 username: "".to_string(),
                 password_source: None,
                 password: None,
                 ssl_mode: crate::database::SslMode::Disable,
                 timeout: None,
+                default_schema: None,
+                init_sql: Vec::new(),
+                ssl_ca_cert: None,
+                ssl_client_cert: None,
+                ssl_client_key: None,
+                favorite: false,
                 status: ConnectionStatus::Disconnected,
             },
         ];
@@ -2016,4 +2269,200 @@ mod tests {
         assert!(!ui_state.pending_gg_command);
         assert_eq!(ui_state.selected_table_item_index, 2); // Should stay in place
     }
+
+    #[test]
+    fn test_select_table_by_name() {
+        let mut ui_state = UIState::new();
+        ui_state.selectable_table_items = vec![
+            SelectableTableItem::new_header("▼ Tables".to_string(), 0),
+            SelectableTableItem::new_selectable(
+                "users".to_string(),
+                "users".to_string(),
+                None,
+                crate::database::objects::DatabaseObjectType::Table,
+                1,
+            ),
+            SelectableTableItem::new_selectable(
+                "orders".to_string(),
+                "orders".to_string(),
+                None,
+                crate::database::objects::DatabaseObjectType::Table,
+                2,
+            ),
+        ];
+
+        assert!(ui_state.select_table_by_name("orders"));
+        assert_eq!(
+            ui_state.get_selected_table_name().as_deref(),
+            Some("orders")
+        );
+        assert!(!ui_state.select_table_by_name("missing"));
+        assert_eq!(ui_state.selected_table_item_index, 2);
+    }
+
+    #[test]
+    fn test_refresh_keeps_selection_or_clamps() {
+        use crate::database::objects::{DatabaseObject, DatabaseObjectList};
+
+        let objects = |names: &[&str]| {
+            Some(DatabaseObjectList {
+                tables: names
+                    .iter()
+                    .map(|name| DatabaseObject {
+                        name: name.to_string(),
+                        schema: None,
+                        object_type: DatabaseObjectType::Table,
+                        row_count: None,
+                        size_bytes: None,
+                        comment: None,
+                    })
+                    .collect(),
+                total_count: names.len(),
+                ..Default::default()
+            })
+        };
+
+        let mut ui = UIState::new();
+        ui.build_selectable_table_items(&objects(&["accounts", "orders", "users"]));
+        ui.select_table_by_name("users");
+
+        // The selected table was dropped: stay on its row, clamped to the end
+        let index = ui.selected_table_item_index;
+        ui.build_selectable_table_items(&objects(&["accounts", "orders"]));
+        ui.select_table_near(index);
+        assert_eq!(ui.get_selected_table_name().as_deref(), Some("orders"));
+        ui.build_selectable_table_items(&None);
+        ui.select_table_near(index);
+        assert_eq!(ui.selected_table_item_index, 0);
+
+        // A rebuild while searching keeps the highlighted match
+        ui.build_selectable_table_items(&objects(&["orders", "order_items", "users"]));
+        ui.enter_tables_search();
+        ui.add_to_tables_search('o');
+        ui.table_selection_down();
+        assert_eq!(ui.get_selected_table_name().as_deref(), Some("order_items"));
+        ui.build_selectable_table_items(&objects(&["orders", "order_items", "users"]));
+        assert_eq!(ui.get_selected_table_name().as_deref(), Some("order_items"));
+    }
+
+    #[test]
+    fn test_routines_pane_sits_in_tables_slot() {
+        assert_eq!(FocusedPane::Routines.next(), FocusedPane::Details);
+        assert_eq!(FocusedPane::Routines.previous(), FocusedPane::Connections);
+        assert_eq!(FocusedPane::Routines.to_number(), 2);
+
+        let mut ui_state = UIState::new();
+        ui_state.select_routine(5, 3);
+        assert_eq!(ui_state.selected_routine, 2);
+        assert_eq!(ui_state.routines_list_state.selected(), Some(2));
+        ui_state.select_routine(0, 0);
+        assert_eq!(ui_state.routines_list_state.selected(), None);
+    }
+
+    #[test]
+    fn test_table_groups_collapse_filter_and_search() {
+        use crate::database::objects::{DatabaseObject, DatabaseObjectList};
+
+        let object = |name: &str, object_type: DatabaseObjectType| DatabaseObject {
+            name: name.to_string(),
+            schema: None,
+            object_type,
+            row_count: None,
+            size_bytes: None,
+            comment: None,
+        };
+        let objects = Some(DatabaseObjectList {
+            tables: vec![
+                object("orders", DatabaseObjectType::Table),
+                object("users", DatabaseObjectType::Table),
+            ],
+            views: vec![object("active_users", DatabaseObjectType::View)],
+            materialized_views: vec![object("order_totals", DatabaseObjectType::MaterializedView)],
+            foreign_tables: vec![],
+            total_count: 4,
+            error: None,
+        });
+        let names = |ui: &UIState| -> Vec<String> {
+            ui.get_display_table_items()
+                .iter()
+                .map(|item| item.display_name.trim().to_string())
+                .collect()
+        };
+
+        let mut ui = UIState::new();
+        ui.build_selectable_table_items(&objects);
+        assert_eq!(
+            names(&ui),
+            [
+                "▼ Tables (2)",
+                "📋 orders",
+                "📋 users",
+                "",
+                "▼ Views (1)",
+                "👁️ active_users",
+                "",
+                "▶ Materialized Views (1)"
+            ]
+        );
+        assert_eq!(ui.get_selected_table_name().as_deref(), Some("orders"));
+
+        // j stops on headers but skips separators and collapsed groups
+        ui.select_table_by_name("active_users");
+        ui.table_selection_down();
+        assert!(ui.get_selected_table_item().is_none());
+        assert_eq!(
+            ui.toggle_selected_table_group(&objects),
+            Some(("Materialized Views", true))
+        );
+        assert_eq!(ui.selected_table_item_index, 7);
+        ui.table_selection_down();
+        assert_eq!(
+            ui.get_selected_table_name().as_deref(),
+            Some("order_totals")
+        );
+        ui.table_selection_down();
+        assert_eq!(ui.selected_table_item_index, 0);
+
+        // A rebuild, as after a refresh, keeps the selection
+        ui.select_table_by_name("users");
+        ui.build_selectable_table_items(&objects);
+        assert_eq!(ui.get_selected_table_name().as_deref(), Some("users"));
+
+        assert_eq!(
+            ui.cycle_tables_type_filter(&objects),
+            TableTypeFilter::Tables
+        );
+        assert_eq!(names(&ui), ["▼ Tables (2)", "📋 orders", "📋 users"]);
+        assert_eq!(
+            ui.cycle_tables_type_filter(&objects),
+            TableTypeFilter::Views
+        );
+        assert_eq!(ui.cycle_tables_type_filter(&objects), TableTypeFilter::All);
+
+        // Search finds tables in collapsed groups and lists them under headers
+        ui.toggle_object_group_expansion("Tables");
+        ui.build_selectable_table_items(&objects);
+        ui.enter_tables_search();
+        ui.add_to_tables_search('o');
+        ui.add_to_tables_search('r');
+        assert_eq!(
+            names(&ui),
+            [
+                "▼ Tables (1)",
+                "📋 orders",
+                "",
+                "▼ Materialized Views (1)",
+                "🔄 order_totals"
+            ]
+        );
+        assert_eq!(ui.get_selected_table_name().as_deref(), Some("orders"));
+        ui.table_selection_up();
+        assert_eq!(
+            ui.get_selected_table_name().as_deref(),
+            Some("order_totals")
+        );
+        ui.exit_tables_search();
+        assert!(ui.reveal_table("users", &objects));
+        assert!(ui.is_object_group_expanded("Tables"));
+    }
 }
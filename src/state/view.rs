@@ -6,20 +6,15 @@ use crate::database::ConnectionConfig;
 use serde::{Deserialize, Serialize};
 
 /// Main application view hierarchy
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AppView {
     /// Main six-pane database browsing interface
+    #[default]
     Main,
     /// Full-screen overlay view
     Overlay(OverlayView),
 }
 
-impl Default for AppView {
-    fn default() -> Self {
-        Self::Main
-    }
-}
-
 /// Types of full-screen overlays
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OverlayView {
@@ -29,6 +24,10 @@ pub enum OverlayView {
     DebugView,
     /// Help overlay
     Help,
+    /// Toast notification history
+    ToastHistory,
+    /// Saved workspaces browser
+    Workspaces,
 }
 
 /// Connection form mode (Add new or Edit existing)
@@ -53,20 +52,15 @@ impl PartialEq for ConnectionFormMode {
 impl Eq for ConnectionFormMode {}
 
 /// Text input mode for all text fields (vim-style)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TextInputMode {
     /// Normal navigation mode
+    #[default]
     Normal,
     /// Insert/edit mode for typing
     Insert,
 }
 
-impl Default for TextInputMode {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
 impl AppView {
     /// Check if currently in main view
     pub fn is_main(&self) -> bool {
@@ -100,6 +94,16 @@ impl AppView {
     pub fn is_help(&self) -> bool {
         matches!(self, Self::Overlay(OverlayView::Help))
     }
+
+    /// Check if in toast history overlay
+    pub fn is_toast_history(&self) -> bool {
+        matches!(self, Self::Overlay(OverlayView::ToastHistory))
+    }
+
+    /// Check if in workspace browser overlay
+    pub fn is_workspaces(&self) -> bool {
+        matches!(self, Self::Overlay(OverlayView::Workspaces))
+    }
 }
 
 impl OverlayView {
@@ -110,6 +114,8 @@ impl OverlayView {
             Self::ConnectionForm(ConnectionFormMode::Edit(_)) => "Edit Connection",
             Self::DebugView => "Debug View",
             Self::Help => "Help",
+            Self::ToastHistory => "Notification History",
+            Self::Workspaces => "Workspaces",
         }
     }
 }
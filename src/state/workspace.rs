@@ -0,0 +1,181 @@
+// FilePath: src/state/workspace.rs
+
+#![forbid(unsafe_code)]
+
+use crate::config::Config;
+use crate::core::error::{LazyTablesError, Result};
+use crate::state::ui::FocusedPane;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Saved session: open connections, table tabs, editor content and focus
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Workspace {
+    pub name: String,
+    pub created_at: DateTime<Local>,
+    /// Names of the connections that were connected
+    #[serde(default)]
+    pub connections: Vec<String>,
+    /// Tables open in the table viewer, in tab order
+    #[serde(default)]
+    pub tables: Vec<String>,
+    /// Index of the active table tab
+    #[serde(default)]
+    pub active_tab: usize,
+    /// SQL file loaded in the query editor
+    #[serde(default)]
+    pub sql_file: Option<String>,
+    /// Query editor content, including unsaved edits
+    #[serde(default)]
+    pub query_content: String,
+    pub focused_pane: FocusedPane,
+}
+
+/// Entry shown in the workspace browser
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceSummary {
+    pub name: String,
+    pub created_at: DateTime<Local>,
+}
+
+impl Workspace {
+    /// Directory holding saved workspaces - ~/.lazytables/workspaces
+    pub fn workspaces_dir() -> PathBuf {
+        Config::data_dir().join("workspaces")
+    }
+
+    /// Reject names that would escape the workspaces directory
+    pub fn validate_name(name: &str) -> Result<()> {
+        if name.is_empty()
+            || name.starts_with('.')
+            || name
+                .chars()
+                .any(|c| c == '/' || c == '\\' || c.is_control())
+        {
+            return Err(LazyTablesError::InvalidInput(format!(
+                "Invalid workspace name '{name}'"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Save to `<dir>/<name>.toml`
+    pub fn save_to(&self, dir: &Path) -> Result<()> {
+        Self::validate_name(&self.name)?;
+        fs::create_dir_all(dir)?;
+        let contents = toml::to_string_pretty(self)?;
+        fs::write(dir.join(format!("{}.toml", self.name)), contents)?;
+        Ok(())
+    }
+
+    /// Load `<dir>/<name>.toml`
+    pub fn load_from(dir: &Path, name: &str) -> Result<Self> {
+        Self::validate_name(name)?;
+        let path = dir.join(format!("{name}.toml"));
+        if !path.exists() {
+            return Err(LazyTablesError::Other(format!(
+                "Workspace '{name}' not found"
+            )));
+        }
+        let contents = fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// Delete `<dir>/<name>.toml`
+    pub fn delete_from(dir: &Path, name: &str) -> Result<()> {
+        Self::validate_name(name)?;
+        fs::remove_file(dir.join(format!("{name}.toml")))?;
+        Ok(())
+    }
+
+    /// List saved workspaces, newest first. Unreadable files are skipped.
+    pub fn list_in(dir: &Path) -> Vec<WorkspaceSummary> {
+        let mut summaries: Vec<WorkspaceSummary> = fs::read_dir(dir)
+            .map(|read_dir| {
+                read_dir
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "toml"))
+                    .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+                    .filter_map(|contents| toml::from_str::<Workspace>(&contents).ok())
+                    .map(|workspace| WorkspaceSummary {
+                        name: workspace.name,
+                        created_at: workspace.created_at,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        summaries.sort_by_key(|s| std::cmp::Reverse(s.created_at));
+        summaries
+    }
+
+    /// Save to the default workspaces directory
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&Self::workspaces_dir())
+    }
+
+    /// Load from the default workspaces directory
+    pub fn load(name: &str) -> Result<Self> {
+        Self::load_from(&Self::workspaces_dir(), name)
+    }
+
+    /// List workspaces in the default workspaces directory
+    pub fn list() -> Vec<WorkspaceSummary> {
+        Self::list_in(&Self::workspaces_dir())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(name: &str) -> Workspace {
+        Workspace {
+            name: name.to_string(),
+            created_at: Local::now(),
+            connections: vec!["local-pg".to_string()],
+            tables: vec!["users".to_string(), "orders".to_string()],
+            active_tab: 1,
+            sql_file: Some("reports".to_string()),
+            query_content: "SELECT *\nFROM users;".to_string(),
+            focused_pane: FocusedPane::QueryWindow,
+        }
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let workspace = sample("daily");
+        workspace.save_to(dir.path()).unwrap();
+
+        let loaded = Workspace::load_from(dir.path(), "daily").unwrap();
+        assert_eq!(loaded, workspace);
+        assert!(Workspace::load_from(dir.path(), "missing").is_err());
+    }
+
+    #[test]
+    fn test_list_newest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut older = sample("older");
+        older.created_at = Local::now() - chrono::Duration::hours(1);
+        older.save_to(dir.path()).unwrap();
+        sample("newer").save_to(dir.path()).unwrap();
+        fs::write(dir.path().join("broken.toml"), "not = [valid").unwrap();
+
+        let names: Vec<String> = Workspace::list_in(dir.path())
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        assert_eq!(names, vec!["newer", "older"]);
+    }
+
+    #[test]
+    fn test_rejects_path_names() {
+        assert!(Workspace::validate_name("../evil").is_err());
+        assert!(Workspace::validate_name(".hidden").is_err());
+        assert!(Workspace::validate_name("").is_err());
+        assert!(Workspace::validate_name("team-report").is_ok());
+    }
+}
@@ -0,0 +1,198 @@
+// FilePath: src/ui/components/bookmark_list.rs
+
+#![forbid(unsafe_code)]
+
+use crate::state::{Bookmark, BookmarkKind};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// `'` overlay over `~/.lazytables/bookmarks.toml`: Enter opens the
+/// highlighted table or SQL file, `e` labels it, `d` removes it
+#[derive(Debug, Clone)]
+pub struct BookmarkList {
+    pub bookmarks: Vec<Bookmark>,
+    pub selected: usize,
+    /// Label being typed for the highlighted bookmark
+    pub label_input: Option<String>,
+}
+
+impl BookmarkList {
+    pub fn new(bookmarks: Vec<Bookmark>) -> Self {
+        Self {
+            bookmarks,
+            selected: 0,
+            label_input: None,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.bookmarks.is_empty() {
+            self.selected = (self.selected + 1) % self.bookmarks.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.bookmarks.is_empty() {
+            self.selected = (self.selected + self.bookmarks.len() - 1) % self.bookmarks.len();
+        }
+    }
+
+    pub fn selected_bookmark(&self) -> Option<&Bookmark> {
+        self.bookmarks.get(self.selected)
+    }
+
+    /// Start typing a label, starting from the current one
+    pub fn start_label(&mut self) {
+        self.label_input = self.selected_bookmark().map(|b| b.label.clone());
+    }
+
+    /// Give the highlighted bookmark the typed label; an empty one clears it
+    pub fn apply_label(&mut self) {
+        if let Some(label) = self.label_input.take() {
+            if let Some(bookmark) = self.bookmarks.get_mut(self.selected) {
+                bookmark.label = label.trim().to_string();
+            }
+        }
+    }
+
+    /// Drop the highlighted bookmark
+    pub fn remove_selected(&mut self) -> Option<Bookmark> {
+        if self.selected >= self.bookmarks.len() {
+            return None;
+        }
+        let removed = self.bookmarks.remove(self.selected);
+        self.selected = self.selected.min(self.bookmarks.len().saturating_sub(1));
+        Some(removed)
+    }
+}
+
+/// Render the bookmarks centered near the top of the screen
+pub fn render_bookmark_list(frame: &mut Frame, list: &BookmarkList) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(80);
+    let height = screen.height.saturating_sub(4).min(20);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 4,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(" Bookmarks ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    if list.bookmarks.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No bookmarks yet - press m on a table or SQL file")
+                .style(Style::default().fg(Color::DarkGray)),
+            chunks[0],
+        );
+    } else {
+        let items: Vec<ListItem> = list
+            .bookmarks
+            .iter()
+            .map(|bookmark| {
+                let icon = match bookmark.kind {
+                    BookmarkKind::Table => "📋 ",
+                    BookmarkKind::SqlFile => "📄 ",
+                };
+                let mut spans = vec![
+                    Span::raw(icon),
+                    Span::styled(
+                        bookmark.display_name().to_string(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                ];
+                if !bookmark.label.is_empty() {
+                    spans.push(Span::styled(
+                        format!("  {}", bookmark.name),
+                        Style::default().fg(Color::Gray),
+                    ));
+                }
+                spans.push(Span::styled(
+                    format!("  @ {}", bookmark.connection_name),
+                    Style::default().fg(Color::DarkGray),
+                ));
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let mut list_state = ListState::default();
+        list_state.select(Some(list.selected));
+        frame.render_stateful_widget(
+            List::new(items).highlight_style(Style::default().bg(Color::DarkGray)),
+            chunks[0],
+            &mut list_state,
+        );
+    }
+
+    let footer = match &list.label_input {
+        Some(label) => Line::from(vec![
+            Span::styled("Label: ", Style::default().fg(Color::Yellow)),
+            Span::raw(label.as_str()),
+            Span::styled("█", Style::default().fg(Color::Gray)),
+            Span::styled(
+                "  Enter save  Esc cancel",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+        None => Line::styled(
+            "Enter open  e label  d remove  Esc close",
+            Style::default().fg(Color::DarkGray),
+        ),
+    };
+    frame.render_widget(Paragraph::new(footer), chunks[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bookmark(name: &str) -> Bookmark {
+        Bookmark {
+            kind: BookmarkKind::Table,
+            name: name.to_string(),
+            connection_id: "4f1c".to_string(),
+            connection_name: "prod".to_string(),
+            label: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_label_and_remove() {
+        let mut list = BookmarkList::new(vec![bookmark("orders"), bookmark("users")]);
+        list.select_previous();
+        assert_eq!(
+            list.selected_bookmark().map(|b| b.name.as_str()),
+            Some("users")
+        );
+
+        list.start_label();
+        assert_eq!(list.label_input.as_deref(), Some(""));
+        list.label_input = Some(" Users ".to_string());
+        list.apply_label();
+        assert_eq!(list.bookmarks[1].display_name(), "Users");
+
+        assert_eq!(
+            list.remove_selected().map(|b| b.name),
+            Some("users".to_string())
+        );
+        assert_eq!(list.selected, 0);
+        list.remove_selected();
+        assert!(list.remove_selected().is_none());
+    }
+}
@@ -0,0 +1,500 @@
+// FilePath: src/ui/components/cell_editor.rs
+
+#![forbid(unsafe_code)]
+
+use crate::ui::components::table_viewer::ColumnInfo;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Line standing for a NULL array element
+pub const NULL_ELEMENT: &str = "\\N";
+
+/// What the editor is editing, from the column's `data_type`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CellEditorKind {
+    /// PostgreSQL array: one element per line, saved as `ARRAY[...]::<type>`
+    Array { array_type: String },
+    /// `json`/`jsonb`: pretty-printed on open, validated on save
+    Json,
+}
+
+impl CellEditorKind {
+    /// Structured kind of a column, if it has one
+    pub fn for_column(column: &ColumnInfo) -> Option<Self> {
+        if column.data_type.ends_with("[]") {
+            Some(Self::Array {
+                array_type: column.data_type.clone(),
+            })
+        } else if column.data_type.eq_ignore_ascii_case("JSON")
+            || column.data_type.eq_ignore_ascii_case("JSONB")
+        {
+            Some(Self::Json)
+        } else {
+            None
+        }
+    }
+}
+
+/// Value written by a saved edit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditedValue {
+    /// Cell text as the database shows it, kept for display and undo
+    pub text: String,
+    /// Expression written instead of quoting `text`, e.g. `ARRAY[...]`
+    pub sql: Option<String>,
+}
+
+/// Multi-line editor over an array or JSON cell, opened by `i` instead of the
+/// single-line edit buffer
+#[derive(Debug, Clone)]
+pub struct CellEditor {
+    pub kind: CellEditorKind,
+    pub column_name: String,
+    pub row: usize,
+    pub col: usize,
+    pub lines: Vec<String>,
+    pub cursor_line: usize,
+    /// Cursor position in characters
+    pub cursor_col: usize,
+    /// Cell text before the edit; `None` is NULL
+    pub original: Option<String>,
+    /// Why the last save was refused
+    pub error: Option<String>,
+}
+
+impl CellEditor {
+    /// Open over `value` (`None` for NULL). Fails for values the editor
+    /// can't split into lines, such as multi-dimensional arrays.
+    pub fn open(
+        kind: CellEditorKind,
+        column_name: String,
+        row: usize,
+        col: usize,
+        value: Option<&str>,
+    ) -> Result<Self, String> {
+        let text = match (&kind, value) {
+            (_, None) => String::new(),
+            (CellEditorKind::Array { .. }, Some(value)) => parse_pg_array(value)
+                .ok_or_else(|| "Only one-dimensional arrays can be edited as a list".to_string())?
+                .into_iter()
+                .map(|element| element.unwrap_or_else(|| NULL_ELEMENT.to_string()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            // Values that aren't valid JSON are shown as they are
+            (CellEditorKind::Json, Some(value)) => serde_json::from_str::<serde_json::Value>(value)
+                .ok()
+                .and_then(|json| serde_json::to_string_pretty(&json).ok())
+                .unwrap_or_else(|| value.to_string()),
+        };
+        Ok(Self {
+            kind,
+            column_name,
+            row,
+            col,
+            lines: text.split('\n').map(str::to_string).collect(),
+            cursor_line: 0,
+            cursor_col: 0,
+            original: value.map(str::to_string),
+            error: None,
+        })
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    fn line_len(&self) -> usize {
+        self.lines[self.cursor_line].chars().count()
+    }
+
+    fn byte_offset(&self) -> usize {
+        let line = &self.lines[self.cursor_line];
+        line.char_indices()
+            .nth(self.cursor_col)
+            .map_or(line.len(), |(i, _)| i)
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        let at = self.byte_offset();
+        self.lines[self.cursor_line].insert(at, c);
+        self.cursor_col += 1;
+    }
+
+    /// Split the line at the cursor; in an array this starts a new element
+    pub fn insert_newline(&mut self) {
+        let at = self.byte_offset();
+        let rest = self.lines[self.cursor_line].split_off(at);
+        self.cursor_line += 1;
+        self.lines.insert(self.cursor_line, rest);
+        self.cursor_col = 0;
+    }
+
+    /// Delete before the cursor, joining with the previous line at its start
+    pub fn backspace(&mut self) {
+        if self.cursor_col > 0 {
+            self.cursor_col -= 1;
+            let at = self.byte_offset();
+            self.lines[self.cursor_line].remove(at);
+        } else if self.cursor_line > 0 {
+            let line = self.lines.remove(self.cursor_line);
+            self.cursor_line -= 1;
+            self.cursor_col = self.line_len();
+            self.lines[self.cursor_line].push_str(&line);
+        }
+    }
+
+    /// Remove the cursor line, i.e. one array element
+    pub fn delete_line(&mut self) {
+        if self.lines.len() == 1 {
+            self.lines[0].clear();
+        } else {
+            self.lines.remove(self.cursor_line);
+            self.cursor_line = self.cursor_line.min(self.lines.len() - 1);
+        }
+        self.cursor_col = self.cursor_col.min(self.line_len());
+    }
+
+    pub fn move_left(&mut self) {
+        if self.cursor_col > 0 {
+            self.cursor_col -= 1;
+        } else if self.cursor_line > 0 {
+            self.cursor_line -= 1;
+            self.cursor_col = self.line_len();
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if self.cursor_col < self.line_len() {
+            self.cursor_col += 1;
+        } else if self.cursor_line + 1 < self.lines.len() {
+            self.cursor_line += 1;
+            self.cursor_col = 0;
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        self.cursor_line = self.cursor_line.saturating_sub(1);
+        self.cursor_col = self.cursor_col.min(self.line_len());
+    }
+
+    pub fn move_down(&mut self) {
+        self.cursor_line = (self.cursor_line + 1).min(self.lines.len() - 1);
+        self.cursor_col = self.cursor_col.min(self.line_len());
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor_col = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor_col = self.line_len();
+    }
+
+    /// Value to save, or None when nothing changed. Invalid JSON is refused
+    /// with the cursor moved to where parsing stopped.
+    pub fn finish(&mut self) -> Result<Option<EditedValue>, String> {
+        let edited = match &self.kind {
+            CellEditorKind::Array { array_type } => {
+                let elements = self.array_elements();
+                EditedValue {
+                    text: format_pg_array(&elements),
+                    sql: Some(array_expression(&elements, array_type)),
+                }
+            }
+            CellEditorKind::Json => {
+                let json: serde_json::Value = match serde_json::from_str(&self.text()) {
+                    Ok(json) => json,
+                    Err(e) => {
+                        self.cursor_line = e.line().saturating_sub(1).min(self.lines.len() - 1);
+                        self.cursor_col = e.column().saturating_sub(1).min(self.line_len());
+                        let message = format!("Invalid JSON: {e}");
+                        self.error = Some(message.clone());
+                        return Err(message);
+                    }
+                };
+                // Reformatting alone is not a change
+                let original = self
+                    .original
+                    .as_deref()
+                    .and_then(|original| serde_json::from_str::<serde_json::Value>(original).ok());
+                if original.as_ref() == Some(&json) {
+                    return Ok(None);
+                }
+                EditedValue {
+                    text: json.to_string(),
+                    sql: None,
+                }
+            }
+        };
+        self.error = None;
+        Ok((self.original.as_deref() != Some(edited.text.as_str())).then_some(edited))
+    }
+
+    /// Lines as array elements; an empty editor is an empty array
+    fn array_elements(&self) -> Vec<Option<String>> {
+        if self.lines.len() == 1 && self.lines[0].is_empty() {
+            return Vec::new();
+        }
+        self.lines
+            .iter()
+            .map(|line| (line != NULL_ELEMENT).then(|| line.clone()))
+            .collect()
+    }
+}
+
+/// Split PostgreSQL array text like `{a,"b c",NULL}` into its elements.
+/// None for anything but a one-dimensional array.
+pub fn parse_pg_array(text: &str) -> Option<Vec<Option<String>>> {
+    let inner = text.trim().strip_prefix('{')?.strip_suffix('}')?;
+    let mut elements = Vec::new();
+    if inner.trim().is_empty() {
+        return Some(elements);
+    }
+
+    let mut chars = inner.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let mut element = String::new();
+        let mut quoted = false;
+        if chars.next_if_eq(&'"').is_some() {
+            quoted = true;
+            loop {
+                match chars.next()? {
+                    '\\' => element.push(chars.next()?),
+                    '"' => break,
+                    c => element.push(c),
+                }
+            }
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        } else {
+            while let Some(c) = chars.next_if(|&c| c != ',') {
+                match c {
+                    '{' | '}' | '"' => return None,
+                    '\\' => element.push(chars.next()?),
+                    c => element.push(c),
+                }
+            }
+            element.truncate(element.trim_end().len());
+        }
+
+        let null = !quoted && element.eq_ignore_ascii_case("NULL");
+        elements.push((!null).then_some(element));
+        match chars.next() {
+            Some(',') => continue,
+            None => return Some(elements),
+            Some(_) => return None,
+        }
+    }
+}
+
+/// PostgreSQL array text for the elements, quoting where the input
+/// syntax needs it
+pub fn format_pg_array(elements: &[Option<String>]) -> String {
+    let parts: Vec<String> = elements
+        .iter()
+        .map(|element| match element {
+            None => "NULL".to_string(),
+            Some(value)
+                if value.is_empty()
+                    || value.eq_ignore_ascii_case("NULL")
+                    || value
+                        .chars()
+                        .any(|c| c.is_whitespace() || "{},\"\\".contains(c)) =>
+            {
+                format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+            }
+            Some(value) => value.clone(),
+        })
+        .collect();
+    format!("{{{}}}", parts.join(","))
+}
+
+/// `ARRAY[...]` expression for the elements, cast to the column's array
+/// type so empty arrays and non-text elements are accepted
+pub fn array_expression(elements: &[Option<String>], array_type: &str) -> String {
+    let literals: Vec<String> = elements
+        .iter()
+        .map(|element| match element {
+            None => "NULL".to_string(),
+            Some(value) => format!("'{}'", value.replace('\'', "''")),
+        })
+        .collect();
+    format!("ARRAY[{}]::{array_type}", literals.join(", "))
+}
+
+/// Render the editor centered over the viewer
+pub fn render_cell_editor(frame: &mut Frame, editor: &CellEditor) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(90);
+    let height = screen.height.saturating_sub(4).min(24);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let (kind, footer) = match &editor.kind {
+        CellEditorKind::Array { array_type } => (
+            array_type.as_str(),
+            "Enter new element  Ctrl+D delete element  \\N = NULL  Ctrl+S save  Esc cancel",
+        ),
+        CellEditorKind::Json => ("JSON", "Ctrl+S save  Esc cancel"),
+    };
+    let block = Block::default()
+        .title(format!(
+            " {} - row {} ({kind}) ",
+            editor.column_name,
+            editor.row + 1
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let visible = chunks[0].height.max(1) as usize;
+    let first = (editor.cursor_line + 1).saturating_sub(visible);
+    let numbered = matches!(editor.kind, CellEditorKind::Array { .. });
+    let lines: Vec<Line> = editor
+        .lines
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(visible)
+        .map(|(index, line)| {
+            let mut spans = Vec::new();
+            if numbered {
+                spans.push(Span::styled(
+                    format!("{:>3} ", index + 1),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            if index != editor.cursor_line {
+                spans.push(Span::raw(line.as_str()));
+                return Line::from(spans);
+            }
+            let before: String = line.chars().take(editor.cursor_col).collect();
+            let at = line.chars().nth(editor.cursor_col).unwrap_or(' ');
+            let after: String = line.chars().skip(editor.cursor_col + 1).collect();
+            spans.push(Span::raw(before));
+            spans.push(Span::styled(
+                at.to_string(),
+                Style::default().add_modifier(Modifier::REVERSED),
+            ));
+            spans.push(Span::raw(after));
+            Line::from(spans)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let footer = match &editor.error {
+        Some(error) => Line::styled(error.as_str(), Style::default().fg(Color::Red)),
+        None => Line::styled(footer, Style::default().fg(Color::DarkGray)),
+    };
+    frame.render_widget(Paragraph::new(footer), chunks[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pg_array_round_trip() {
+        let elements = parse_pg_array(r#"{plain,"two words",NULL,"NULL","q\"x","",b\,c}"#);
+        assert_eq!(
+            elements,
+            Some(vec![
+                Some("plain".to_string()),
+                Some("two words".to_string()),
+                None,
+                Some("NULL".to_string()),
+                Some("q\"x".to_string()),
+                Some(String::new()),
+                Some("b,c".to_string()),
+            ])
+        );
+        assert_eq!(
+            format_pg_array(&elements.unwrap()),
+            r#"{plain,"two words",NULL,"NULL","q\"x","","b,c"}"#
+        );
+        assert_eq!(parse_pg_array("{}"), Some(Vec::new()));
+        assert_eq!(parse_pg_array("{{1,2},{3,4}}"), None);
+        assert_eq!(parse_pg_array("[0:1]={1,2}"), None);
+    }
+
+    #[test]
+    fn test_array_editor_builds_array_expression() {
+        let kind = CellEditorKind::Array {
+            array_type: "TEXT[]".to_string(),
+        };
+        let mut editor =
+            CellEditor::open(kind, "tags".to_string(), 0, 1, Some(r#"{a,"it's"}"#)).unwrap();
+        assert_eq!(editor.lines, vec!["a", "it's"]);
+
+        editor.move_down();
+        editor.move_end();
+        editor.insert_newline();
+        for c in NULL_ELEMENT.chars() {
+            editor.insert_char(c);
+        }
+        let saved = editor.finish().unwrap().unwrap();
+        assert_eq!(saved.text, r#"{a,it's,NULL}"#);
+        assert_eq!(
+            saved.sql.as_deref(),
+            Some("ARRAY['a', 'it''s', NULL]::TEXT[]")
+        );
+
+        editor.delete_line();
+        editor.delete_line();
+        editor.delete_line();
+        let emptied = editor.finish().unwrap().unwrap();
+        assert_eq!(emptied.sql.as_deref(), Some("ARRAY[]::TEXT[]"));
+    }
+
+    #[test]
+    fn test_json_editor_validates_and_points_at_error() {
+        let mut editor = CellEditor::open(
+            CellEditorKind::Json,
+            "payload".to_string(),
+            0,
+            0,
+            Some(r#"{"a":[true],"b":1}"#),
+        )
+        .unwrap();
+        assert_eq!(editor.lines.len(), 6);
+        // Pretty-printing alone saves nothing
+        assert_eq!(editor.finish(), Ok(None));
+
+        // Drop the comma after the array: parsing stops on the next line
+        editor.cursor_line = 3;
+        editor.move_end();
+        editor.backspace();
+        let error = editor.finish().unwrap_err();
+        assert!(error.starts_with("Invalid JSON"), "{error}");
+        assert_eq!((editor.cursor_line, editor.cursor_col), (4, 2));
+        assert!(editor.error.is_some());
+
+        editor.move_up();
+        editor.move_end();
+        editor.insert_char(',');
+        editor.move_down();
+        editor.move_end();
+        editor.backspace();
+        editor.insert_char('2');
+        let saved = editor.finish().unwrap().unwrap();
+        assert_eq!(saved.text, r#"{"a":[true],"b":2}"#);
+        assert_eq!(saved.sql, None);
+        assert_eq!(editor.error, None);
+    }
+}
@@ -0,0 +1,140 @@
+// FilePath: src/ui/components/cell_view.rs
+
+#![forbid(unsafe_code)]
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// `Space` popup with the full value of a cell, word-wrapped to the popup
+#[derive(Debug, Clone)]
+pub struct CellView {
+    /// Column and row the value comes from
+    pub title: String,
+    pub text: String,
+    /// First wrapped row shown; clamped when rendered
+    pub scroll: usize,
+}
+
+impl CellView {
+    pub fn new(title: String, text: String) -> Self {
+        Self {
+            title,
+            text,
+            scroll: 0,
+        }
+    }
+
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.scroll = self.scroll.saturating_add(rows);
+    }
+
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.scroll = self.scroll.saturating_sub(rows);
+    }
+}
+
+/// Break `text` into rows of at most `width` characters, at spaces where
+/// possible. Words longer than a row are split, so payloads and stack
+/// traces without spaces still fit; tabs count as four spaces.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut rows = Vec::new();
+    for line in text.replace('\t', "    ").lines() {
+        let mut row = String::new();
+        let mut row_len = 0;
+        for word in line.split_inclusive(' ') {
+            let mut word_len = word.chars().count();
+            // A trailing space may hang past the edge
+            let trailing = usize::from(word.ends_with(' '));
+            let fits = word_len - trailing;
+            // Words too long for any row start on the current one
+            if row_len >= width || row_len > 0 && fits <= width && row_len + fits > width {
+                rows.push(std::mem::take(&mut row).trim_end().to_string());
+                row_len = 0;
+            }
+            let mut word = word;
+            while word_len - trailing > width {
+                let split = word
+                    .char_indices()
+                    .nth(width - row_len)
+                    .map_or(word.len(), |(i, _)| i);
+                row.push_str(&word[..split]);
+                rows.push(std::mem::take(&mut row));
+                row_len = 0;
+                word = &word[split..];
+                word_len = word.chars().count();
+            }
+            row.push_str(word);
+            row_len += word_len;
+        }
+        rows.push(row.trim_end().to_string());
+    }
+    if rows.is_empty() {
+        rows.push(String::new());
+    }
+    rows
+}
+
+/// Render the value centered over the viewer
+pub fn render_cell_view(frame: &mut Frame, view: &mut CellView) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(100);
+    let height = screen.height.saturating_sub(4).min(40);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    let rows = wrap_text(&view.text, width.saturating_sub(2) as usize);
+    let viewport_height = height.saturating_sub(2) as usize;
+    view.scroll = view.scroll.min(rows.len().saturating_sub(viewport_height));
+
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(format!(
+            " {} - {} chars ",
+            view.title,
+            view.text.chars().count()
+        ))
+        .title_bottom(" j/k scroll  Ctrl+D/U page  Esc close ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let lines: Vec<Line> = rows
+        .into_iter()
+        .skip(view.scroll)
+        .take(viewport_height)
+        .map(Line::from)
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(
+            wrap_text("the quick brown fox", 10),
+            vec!["the quick", "brown fox"]
+        );
+        // Long words are split, continuing on the row they start on
+        assert_eq!(
+            wrap_text("at 0123456789abcdef", 8),
+            vec!["at 01234", "56789abc", "def"]
+        );
+        assert_eq!(
+            wrap_text("Error:\n\tat main()\n", 20),
+            vec!["Error:", "    at main()"]
+        );
+        assert_eq!(wrap_text("", 20), vec![""]);
+        assert_eq!(wrap_text("héllo wörld", 5), vec!["héllo", "wörld"]);
+    }
+}
@@ -0,0 +1,243 @@
+// FilePath: src/ui/components/command_palette.rs
+
+#![forbid(unsafe_code)]
+
+use crate::commands::{CommandCategory, CommandId};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// One registered command as listed in the palette
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    pub id: CommandId,
+    pub description: String,
+    pub category: CommandCategory,
+    pub shortcut: Option<String>,
+}
+
+/// `Ctrl+P` overlay listing every registered command, filtered as you type
+#[derive(Debug, Clone)]
+pub struct CommandPalette {
+    entries: Vec<PaletteEntry>,
+    /// Text typed so far
+    pub query: String,
+    /// Indices into `entries` that match `query`, best match first
+    filtered: Vec<usize>,
+    pub selected: usize,
+}
+
+impl CommandPalette {
+    /// Palette over `entries`, grouped by category
+    pub fn new(mut entries: Vec<PaletteEntry>) -> Self {
+        entries.sort_by(|a, b| {
+            a.category
+                .to_string()
+                .cmp(&b.category.to_string())
+                .then_with(|| a.description.cmp(&b.description))
+        });
+        let mut palette = Self {
+            entries,
+            query: String::new(),
+            filtered: Vec::new(),
+            selected: 0,
+        };
+        palette.refilter();
+        palette
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.query.push(c);
+        self.refilter();
+    }
+
+    pub fn backspace(&mut self) {
+        self.query.pop();
+        self.refilter();
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = (self.selected + 1) % self.filtered.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = (self.selected + self.filtered.len() - 1) % self.filtered.len();
+        }
+    }
+
+    /// Entries matching the query, in display order
+    pub fn visible(&self) -> impl Iterator<Item = &PaletteEntry> {
+        self.filtered.iter().map(|&i| &self.entries[i])
+    }
+
+    /// Command of the highlighted entry
+    pub fn selected_command(&self) -> Option<CommandId> {
+        self.filtered
+            .get(self.selected)
+            .map(|&i| self.entries[i].id.clone())
+    }
+
+    /// Rank entries against the query: substring hits before scattered
+    /// subsequence hits, earlier hits first
+    fn refilter(&mut self) {
+        let query = self.query.to_lowercase();
+        let mut scored: Vec<(usize, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                let haystack =
+                    format!("{} {} {}", entry.description, entry.id, entry.category).to_lowercase();
+                match_score(&haystack, &query).map(|score| (score, i))
+            })
+            .collect();
+        scored.sort();
+        self.filtered = scored.into_iter().map(|(_, i)| i).collect();
+        self.selected = 0;
+    }
+}
+
+/// Lower is better; None when `query` is not a subsequence of `haystack`
+pub(crate) fn match_score(haystack: &str, query: &str) -> Option<usize> {
+    if let Some(position) = haystack.find(query) {
+        return Some(position);
+    }
+
+    let mut chars = haystack.char_indices();
+    let mut first = None;
+    let mut last = 0;
+    for q in query.chars() {
+        let (index, _) = chars.by_ref().find(|&(_, c)| c == q)?;
+        first.get_or_insert(index);
+        last = index;
+    }
+    // Tighter spans rank higher, after every substring match
+    Some(haystack.len() + last - first.unwrap_or(0))
+}
+
+/// Render the palette centered near the top of the screen
+pub fn render_command_palette(frame: &mut Frame, palette: &CommandPalette) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(80);
+    let height = screen.height.saturating_sub(4).min(20);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 4,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(" Command Palette ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(inner);
+
+    let input = Paragraph::new(Line::from(vec![
+        Span::styled("> ", Style::default().fg(Color::Yellow)),
+        Span::raw(palette.query.as_str()),
+        Span::styled("█", Style::default().fg(Color::Gray)),
+    ]))
+    .block(Block::default().borders(Borders::BOTTOM));
+    frame.render_widget(input, chunks[0]);
+
+    let list_width = chunks[1].width as usize;
+    let items: Vec<ListItem> = palette
+        .visible()
+        .map(|entry| {
+            let category = format!("{:<11}", entry.category.to_string());
+            let shortcut = entry.shortcut.clone().unwrap_or_default();
+            let used = category.chars().count() + entry.description.chars().count() + 2;
+            let padding = list_width.saturating_sub(used + shortcut.chars().count() + 1);
+            ListItem::new(Line::from(vec![
+                Span::styled(category, Style::default().fg(Color::DarkGray)),
+                Span::raw(" "),
+                Span::styled(entry.description.clone(), Style::default().fg(Color::White)),
+                Span::raw(" ".repeat(padding + 1)),
+                Span::styled(shortcut, Style::default().fg(Color::Yellow)),
+            ]))
+        })
+        .collect();
+
+    if items.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No matching commands").style(Style::default().fg(Color::DarkGray)),
+            chunks[1],
+        );
+        return;
+    }
+
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut list_state = ListState::default();
+    list_state.select(Some(palette.selected));
+    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: CommandId, description: &str, category: CommandCategory) -> PaletteEntry {
+        PaletteEntry {
+            id,
+            description: description.to_string(),
+            category,
+            shortcut: None,
+        }
+    }
+
+    #[test]
+    fn test_filter_ranks_substring_before_subsequence() {
+        let mut palette = CommandPalette::new(vec![
+            entry(
+                CommandId::Quit,
+                "Quit application",
+                CommandCategory::General,
+            ),
+            entry(
+                CommandId::ExecuteQuery,
+                "Execute current SQL query",
+                CommandCategory::Query,
+            ),
+            entry(
+                CommandId::SaveQuery,
+                "Save query to file",
+                CommandCategory::File,
+            ),
+        ]);
+        assert_eq!(palette.visible().count(), 3);
+
+        for c in "query".chars() {
+            palette.insert_char(c);
+        }
+        let ids: Vec<CommandId> = palette.visible().map(|e| e.id.clone()).collect();
+        assert_eq!(ids, vec![CommandId::SaveQuery, CommandId::ExecuteQuery]);
+
+        palette.query.clear();
+        for c in "qapp".chars() {
+            palette.insert_char(c);
+        }
+        assert_eq!(palette.selected_command(), Some(CommandId::Quit));
+
+        palette.insert_char('z');
+        assert_eq!(palette.selected_command(), None);
+    }
+}
@@ -0,0 +1,156 @@
+// FilePath: src/ui/components/connection_import.rs
+
+#![forbid(unsafe_code)]
+
+use crate::{
+    database::{system_import::ImportCandidate, ConnectionConfig, DatabaseType},
+    ui::theme::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Checklist of connections discovered in system credential files
+#[derive(Debug, Clone)]
+pub struct ConnectionImportModal {
+    /// Discovered entries paired with their checked state
+    pub entries: Vec<(ImportCandidate, bool)>,
+    /// Index of the highlighted entry
+    pub selected: usize,
+}
+
+impl ConnectionImportModal {
+    /// Create a checklist with every entry checked
+    pub fn new(candidates: Vec<ImportCandidate>) -> Self {
+        Self {
+            entries: candidates.into_iter().map(|c| (c, true)).collect(),
+            selected: 0,
+        }
+    }
+
+    /// Move selection down
+    pub fn select_next(&mut self) {
+        if !self.entries.is_empty() && self.selected < self.entries.len() - 1 {
+            self.selected += 1;
+        }
+    }
+
+    /// Move selection up
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Toggle the highlighted entry
+    pub fn toggle_selected(&mut self) {
+        if let Some((_, checked)) = self.entries.get_mut(self.selected) {
+            *checked = !*checked;
+        }
+    }
+
+    /// Check every entry, or uncheck them all if they already are
+    pub fn toggle_all(&mut self) {
+        let all_checked = self.entries.iter().all(|(_, checked)| *checked);
+        for (_, checked) in &mut self.entries {
+            *checked = !all_checked;
+        }
+    }
+
+    /// Connections the user left checked
+    pub fn checked_configs(&self) -> Vec<ConnectionConfig> {
+        self.entries
+            .iter()
+            .filter(|(_, checked)| *checked)
+            .map(|(candidate, _)| candidate.config.clone())
+            .collect()
+    }
+
+    /// Render the checklist as a centered popup
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = area.width.saturating_sub(4).min(90);
+        let height = (self.entries.len() as u16 + 6).min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+        frame.render_widget(Clear, popup);
+
+        let checked_count = self.entries.iter().filter(|(_, c)| *c).count();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                " Import Connections ({checked_count}/{}) ",
+                self.entries.len()
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::default().fg(theme.get_color("active_border")))
+            .style(
+                Style::default()
+                    .bg(theme.get_color("background"))
+                    .fg(theme.get_color("foreground")),
+            );
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),    // Checklist
+                Constraint::Length(2), // Help text
+            ])
+            .split(inner);
+
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .map(|(candidate, checked)| {
+                let config = &candidate.config;
+                let target = match config.database_type {
+                    DatabaseType::SQLite => config.database.clone().unwrap_or_default(),
+                    _ => format!(
+                        "{}@{}:{}/{}",
+                        config.username,
+                        config.host,
+                        config.port,
+                        config.database.as_deref().unwrap_or("*")
+                    ),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(if *checked { "[x] " } else { "[ ] " }),
+                    Span::styled(
+                        format!("{:<10} ", config.database_type.display_name()),
+                        Style::default().fg(theme.get_color("inactive_pane")),
+                    ),
+                    Span::raw(target),
+                    Span::styled(
+                        format!("  {}", candidate.source),
+                        Style::default().fg(theme.get_color("inactive_pane")),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(theme.get_color("selection_bg"))
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut list_state = ListState::default();
+        if !self.entries.is_empty() {
+            list_state.select(Some(self.selected.min(self.entries.len() - 1)));
+        }
+        frame.render_stateful_widget(list, chunks[0], &mut list_state);
+
+        let help = Paragraph::new(
+            "j/k: Navigate • Space: Toggle • a: Toggle all • Enter: Import • Esc: Cancel",
+        )
+        .block(Block::default().borders(Borders::TOP))
+        .alignment(Alignment::Center);
+        frame.render_widget(help, chunks[1]);
+    }
+}
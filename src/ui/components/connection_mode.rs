@@ -793,6 +793,9 @@ impl ConnectionMode {
             database_type: self.form_state.database_type.clone(),
             host: self.form_state.host.clone(),
             port,
+            port_env: None,
+            service_name: None,
+            instance_name: None,
             database: if self.form_state.database.is_empty() {
                 None
             } else {
@@ -807,6 +810,12 @@ impl ConnectionMode {
             password_source: None,
             ssl_mode: self.form_state.ssl_mode.clone(),
             timeout: None,
+            default_schema: None,
+            init_sql: Vec::new(),
+            ssl_ca_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
+            favorite: false,
             status: crate::database::ConnectionStatus::Disconnected,
         })
     }
@@ -0,0 +1,167 @@
+// FilePath: src/ui/components/connection_template_list.rs
+
+#![forbid(unsafe_code)]
+
+use crate::state::ConnectionTemplate;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// `A` list over `~/.lazytables/connection_templates.toml`: Enter fills the
+/// add-connection form from the highlighted template, `d` removes it
+#[derive(Debug, Clone)]
+pub struct ConnectionTemplateList {
+    pub templates: Vec<ConnectionTemplate>,
+    pub selected: usize,
+}
+
+impl ConnectionTemplateList {
+    pub fn new(templates: Vec<ConnectionTemplate>) -> Self {
+        Self {
+            templates,
+            selected: 0,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.templates.is_empty() {
+            self.selected = (self.selected + 1) % self.templates.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.templates.is_empty() {
+            self.selected = (self.selected + self.templates.len() - 1) % self.templates.len();
+        }
+    }
+
+    pub fn selected_template(&self) -> Option<&ConnectionTemplate> {
+        self.templates.get(self.selected)
+    }
+
+    /// Drop the highlighted template
+    pub fn remove_selected(&mut self) -> Option<ConnectionTemplate> {
+        if self.selected >= self.templates.len() {
+            return None;
+        }
+        let removed = self.templates.remove(self.selected);
+        self.selected = self.selected.min(self.templates.len().saturating_sub(1));
+        Some(removed)
+    }
+}
+
+/// Render the templates centered near the top of the screen
+pub fn render_connection_template_list(frame: &mut Frame, list: &ConnectionTemplateList) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(70);
+    let height = screen.height.saturating_sub(4).min(16);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 4,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(" Add From Template ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    if list.templates.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No templates yet - use :connection template save <name>")
+                .style(Style::default().fg(Color::DarkGray)),
+            chunks[0],
+        );
+    } else {
+        let items: Vec<ListItem> = list
+            .templates
+            .iter()
+            .map(|template| {
+                let user = if template.username.is_empty() {
+                    String::new()
+                } else {
+                    format!("{}@", template.username)
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        template.name.clone(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(
+                            "  {user}{}:{} ({})",
+                            template.host,
+                            template.port,
+                            template.database_type.display_name()
+                        ),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]))
+            })
+            .collect();
+        let mut list_state = ListState::default();
+        list_state.select(Some(list.selected));
+        frame.render_stateful_widget(
+            List::new(items).highlight_style(Style::default().bg(Color::DarkGray)),
+            chunks[0],
+            &mut list_state,
+        );
+    }
+
+    frame.render_widget(
+        Paragraph::new(Line::styled(
+            "Enter use  d remove  Esc close",
+            Style::default().fg(Color::DarkGray),
+        )),
+        chunks[1],
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::connection::{ConnectionConfig, DatabaseType};
+
+    fn template(name: &str) -> ConnectionTemplate {
+        let connection = ConnectionConfig::new(
+            "source".to_string(),
+            DatabaseType::MySQL,
+            "localhost".to_string(),
+            3306,
+            "root".to_string(),
+        );
+        ConnectionTemplate::from_connection(name, &connection)
+    }
+
+    #[test]
+    fn test_select_and_remove() {
+        let mut list = ConnectionTemplateList::new(vec![template("local"), template("staging")]);
+        list.select_previous();
+        assert_eq!(
+            list.selected_template().map(|t| t.name.as_str()),
+            Some("staging")
+        );
+        assert_eq!(
+            list.remove_selected().map(|t| t.name),
+            Some("staging".to_string())
+        );
+        assert_eq!(list.selected, 0);
+        list.remove_selected();
+        assert!(list.remove_selected().is_none());
+        list.select_next();
+        assert!(list.selected_template().is_none());
+    }
+}
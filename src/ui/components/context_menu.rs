@@ -0,0 +1,170 @@
+// FilePath: src/ui/components/context_menu.rs
+
+#![forbid(unsafe_code)]
+
+use crate::commands::CommandId;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// One entry of a context menu
+#[derive(Debug, Clone)]
+pub struct ContextMenuItem {
+    pub key: char,
+    pub label: &'static str,
+    pub command: CommandId,
+}
+
+/// Popup of actions for the item under the cursor
+#[derive(Debug, Clone)]
+pub struct ContextMenu {
+    /// Object the actions apply to
+    pub target: String,
+    pub items: Vec<ContextMenuItem>,
+    pub selected: usize,
+}
+
+impl ContextMenu {
+    /// Actions for a table or view in the Tables pane
+    pub fn for_table(target: String) -> Self {
+        let item = |key, label, command| ContextMenuItem {
+            key,
+            label,
+            command,
+        };
+        Self {
+            target,
+            items: vec![
+                item('o', "Open", CommandId::ShowTableData),
+                item('n', "Open in New Tab", CommandId::OpenTableInNewTab),
+                item('e', "Edit Schema", CommandId::ShowTableStructure),
+                item('y', "Copy Name", CommandId::CopyTableName),
+                item(
+                    'Y',
+                    "Copy Qualified Name",
+                    CommandId::CopyQualifiedTableName,
+                ),
+                item('R', "Rename…", CommandId::RenameTable),
+                item('r', "Refresh Metadata", CommandId::RefreshTableMetadata),
+                item('t', "Truncate…", CommandId::TruncateTable),
+                item('d', "Drop…", CommandId::DropTable),
+                item('E', "Show DDL", CommandId::ExportTableDdl),
+                item('P', "Profile", CommandId::ProfileTable),
+            ],
+            selected: 0,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.items.is_empty() {
+            self.selected = (self.selected + 1) % self.items.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.items.is_empty() {
+            self.selected = (self.selected + self.items.len() - 1) % self.items.len();
+        }
+    }
+
+    /// Command of the highlighted entry
+    pub fn selected_command(&self) -> Option<CommandId> {
+        self.items
+            .get(self.selected)
+            .map(|item| item.command.clone())
+    }
+
+    /// Command bound to an entry's shortcut key (case-sensitive)
+    pub fn command_for_key(&self, key: char) -> Option<CommandId> {
+        self.items
+            .iter()
+            .find(|item| item.key == key)
+            .map(|item| item.command.clone())
+    }
+}
+
+/// Render the menu as a small floating list just below `anchor` (the selected row),
+/// flipping above it when there is no room below
+pub fn render_context_menu(frame: &mut Frame, menu: &ContextMenu, anchor: Rect) {
+    let screen = frame.area();
+    let width = menu
+        .items
+        .iter()
+        .map(|item| item.label.chars().count() as u16 + 6)
+        .max()
+        .unwrap_or(0)
+        .max(menu.target.chars().count() as u16 + 4)
+        .min(screen.width);
+    let height = (menu.items.len() as u16 + 2).min(screen.height);
+
+    let below = anchor.y.saturating_add(1);
+    let y = if below + height <= screen.bottom() {
+        below
+    } else {
+        anchor.y.saturating_sub(height)
+    };
+    let x = anchor.x.min(screen.right().saturating_sub(width));
+    let area = Rect {
+        x,
+        y,
+        width,
+        height,
+    };
+
+    let items: Vec<ListItem> = menu
+        .items
+        .iter()
+        .map(|item| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(" {} ", item.key),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(item.label, Style::default().fg(Color::White)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(" {} ", menu.target))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(menu.selected));
+
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_menu_keys_and_selection() {
+        let mut menu = ContextMenu::for_table("users".to_string());
+        assert_eq!(menu.command_for_key('E'), Some(CommandId::ExportTableDdl));
+        assert_eq!(menu.command_for_key('R'), Some(CommandId::RenameTable));
+        assert_eq!(menu.command_for_key('x'), None);
+
+        menu.select_previous();
+        assert_eq!(menu.selected_command(), Some(CommandId::ProfileTable));
+        menu.select_next();
+        assert_eq!(menu.selected_command(), Some(CommandId::ShowTableData));
+    }
+}
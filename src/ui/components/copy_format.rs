@@ -0,0 +1,419 @@
+// FilePath: src/ui/components/copy_format.rs
+
+#![forbid(unsafe_code)]
+
+use crate::database::ddl::{quote_identifier, quote_qualified_identifier};
+use crate::database::DatabaseType;
+use crate::ui::components::table_viewer::{row_to_csv, ColumnInfo};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// Text formats a row can be copied in with `yf`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyFormat {
+    Csv,
+    Json,
+    Insert,
+    Tsv,
+}
+
+impl CopyFormat {
+    pub const ALL: [CopyFormat; 4] = [
+        CopyFormat::Csv,
+        CopyFormat::Json,
+        CopyFormat::Insert,
+        CopyFormat::Tsv,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CopyFormat::Csv => "CSV",
+            CopyFormat::Json => "JSON",
+            CopyFormat::Insert => "INSERT",
+            CopyFormat::Tsv => "TSV",
+        }
+    }
+
+    /// Encode `row` of `table_name`. Only INSERT uses the table name and
+    /// `database_type`, for quoting identifiers and writing literals.
+    pub fn format_row(
+        self,
+        table_name: &str,
+        columns: &[ColumnInfo],
+        row: &[Option<String>],
+        database_type: &DatabaseType,
+    ) -> String {
+        match self {
+            CopyFormat::Csv => row_to_csv(row),
+            CopyFormat::Tsv => row_to_tsv(row),
+            CopyFormat::Json => row_to_json(columns, row),
+            CopyFormat::Insert => row_to_insert(table_name, columns, row, database_type),
+        }
+    }
+}
+
+/// How a column's values are written as SQL or JSON, from `ColumnInfo::data_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    Number,
+    Boolean,
+    Temporal,
+    Json,
+    Binary,
+    Text,
+}
+
+impl ValueKind {
+    fn of(data_type: &str) -> Self {
+        let data_type = data_type.to_uppercase();
+        if data_type.ends_with("[]") {
+            return ValueKind::Text;
+        }
+        let base = data_type.split('(').next().unwrap_or_default().trim();
+        match base {
+            "INTEGER" | "INT" | "BIGINT" | "SMALLINT" | "TINYINT" | "DECIMAL" | "NUMERIC"
+            | "FLOAT" | "DOUBLE" | "REAL" => ValueKind::Number,
+            "BOOLEAN" | "BOOL" => ValueKind::Boolean,
+            "DATE" | "TIME" | "TIMESTAMP" | "DATETIME" => ValueKind::Temporal,
+            "JSON" | "JSONB" => ValueKind::Json,
+            "BYTEA" | "BLOB" | "TINYBLOB" | "MEDIUMBLOB" | "LONGBLOB" | "BINARY" | "VARBINARY"
+            | "IMAGE" | "RAW" => ValueKind::Binary,
+            _ => ValueKind::Text,
+        }
+    }
+}
+
+fn boolean_value(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "true" | "t" | "1" => Some(true),
+        "false" | "f" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+fn is_number(value: &str) -> bool {
+    value.parse::<f64>().is_ok_and(f64::is_finite)
+}
+
+/// Hex digits of a binary cell shown as `0x…`, if it is one
+fn hex_digits(value: &str) -> Option<&str> {
+    value
+        .strip_prefix("0x")
+        .filter(|hex| hex.len() % 2 == 0 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// A cell as a SQL literal for `database_type`: numbers bare, booleans as
+/// TRUE/FALSE (1/0 on SQL Server), dates and times cast from a string,
+/// binary as a hex literal, everything else quoted. Values that don't fit
+/// their column's type fall back to a quoted string.
+pub(crate) fn sql_literal(
+    value: Option<&str>,
+    column: Option<&ColumnInfo>,
+    database_type: &DatabaseType,
+) -> String {
+    let Some(value) = value else {
+        return "NULL".to_string();
+    };
+    let quoted = format!("'{}'", value.replace('\'', "''"));
+    let Some(column) = column else {
+        return quoted;
+    };
+    match ValueKind::of(&column.data_type) {
+        ValueKind::Number if is_number(value) => value.to_string(),
+        ValueKind::Boolean => match (boolean_value(value), database_type) {
+            (Some(true), DatabaseType::MsSql) => "1".to_string(),
+            (Some(false), DatabaseType::MsSql) => "0".to_string(),
+            (Some(true), _) => "TRUE".to_string(),
+            (Some(false), _) => "FALSE".to_string(),
+            (None, _) => quoted,
+        },
+        ValueKind::Temporal => temporal_literal(&quoted, &column.data_type, database_type),
+        ValueKind::Binary => match hex_digits(value) {
+            Some(hex) => binary_literal(hex, database_type).unwrap_or(quoted),
+            None => quoted,
+        },
+        _ => quoted,
+    }
+}
+
+/// A date or time string cast to its column type. SQL Server's TIMESTAMP is
+/// `rowversion` and MySQL has no TIMESTAMP cast, so both use their datetime
+/// type; SQLite keeps dates as text.
+fn temporal_literal(quoted: &str, data_type: &str, database_type: &DatabaseType) -> String {
+    let is_datetime = matches!(data_type.to_uppercase().as_str(), "TIMESTAMP" | "DATETIME");
+    let target = match database_type {
+        DatabaseType::SQLite => return quoted.to_string(),
+        DatabaseType::MsSql if is_datetime => "DATETIME2",
+        DatabaseType::MySQL | DatabaseType::MariaDB if is_datetime => "DATETIME",
+        _ => data_type,
+    };
+    format!("CAST({quoted} AS {target})")
+}
+
+/// Hex digits as the engine's binary literal; `None` where it has none
+fn binary_literal(hex: &str, database_type: &DatabaseType) -> Option<String> {
+    match database_type {
+        DatabaseType::PostgreSQL => Some(format!("'\\x{hex}'::bytea")),
+        DatabaseType::MsSql => Some(format!("0x{hex}")),
+        DatabaseType::MySQL | DatabaseType::MariaDB | DatabaseType::SQLite => {
+            Some(format!("X'{hex}'"))
+        }
+        DatabaseType::Oracle => Some(format!("HEXTORAW('{hex}')")),
+        DatabaseType::Redis | DatabaseType::MongoDB => None,
+    }
+}
+
+/// `INSERT INTO table (columns) VALUES (values);` with typed literals
+pub fn row_to_insert(
+    table_name: &str,
+    columns: &[ColumnInfo],
+    row: &[Option<String>],
+    database_type: &DatabaseType,
+) -> String {
+    let names: Vec<String> = columns
+        .iter()
+        .map(|column| quote_identifier(&column.name, database_type))
+        .collect();
+    let values: Vec<String> = row
+        .iter()
+        .enumerate()
+        .map(|(i, value)| sql_literal(value.as_deref(), columns.get(i), database_type))
+        .collect();
+    format!(
+        "INSERT INTO {} ({}) VALUES ({});",
+        quote_qualified_identifier(table_name, database_type),
+        names.join(", "),
+        values.join(", ")
+    )
+}
+
+/// A JSON object keyed by column name, in column order; numbers, booleans
+/// and json columns keep their type and NULL becomes `null`
+pub fn row_to_json(columns: &[ColumnInfo], row: &[Option<String>]) -> String {
+    let mut fields = Vec::with_capacity(columns.len());
+    for (column, value) in columns.iter().zip(row) {
+        let json = if let Some(value) = value {
+            let typed = match ValueKind::of(&column.data_type) {
+                ValueKind::Number => value
+                    .parse::<serde_json::Number>()
+                    .ok()
+                    .map(serde_json::Value::Number),
+                ValueKind::Boolean => boolean_value(value).map(serde_json::Value::Bool),
+                ValueKind::Json => serde_json::from_str(value).ok(),
+                ValueKind::Temporal | ValueKind::Binary | ValueKind::Text => None,
+            };
+            typed.unwrap_or_else(|| serde_json::Value::String(value.clone()))
+        } else {
+            serde_json::Value::Null
+        };
+        fields.push(format!(
+            "{}:{json}",
+            serde_json::Value::String(column.name.clone())
+        ));
+    }
+    format!("{{{}}}", fields.join(","))
+}
+
+/// Tab-separated values for pasting into spreadsheets. NULL is an empty
+/// field; tabs and line breaks inside a value become spaces.
+pub fn row_to_tsv(row: &[Option<String>]) -> String {
+    row.iter()
+        .map(|cell| match cell {
+            Some(cell) => cell.replace(['\t', '\n', '\r'], " "),
+            None => String::new(),
+        })
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+/// `yf` menu of row copy formats
+#[derive(Debug, Clone, Default)]
+pub struct CopyFormatMenu {
+    pub selected: usize,
+}
+
+impl CopyFormatMenu {
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % CopyFormat::ALL.len();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = (self.selected + CopyFormat::ALL.len() - 1) % CopyFormat::ALL.len();
+    }
+
+    pub fn selected_format(&self) -> CopyFormat {
+        CopyFormat::ALL[self.selected]
+    }
+
+    /// Format picked with its number key, `1` to `4`
+    pub fn format_for_key(key: char) -> Option<CopyFormat> {
+        let index = key.to_digit(10)?.checked_sub(1)?;
+        CopyFormat::ALL.get(index as usize).copied()
+    }
+}
+
+/// Render the menu centered over the viewer
+pub fn render_copy_format_menu(frame: &mut Frame, menu: &CopyFormatMenu) {
+    let screen = frame.area();
+    let width = 24.min(screen.width);
+    let height = (CopyFormat::ALL.len() as u16 + 2).min(screen.height);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    };
+
+    let items: Vec<ListItem> = CopyFormat::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, format)| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(" {} ", i + 1),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format.label(), Style::default().fg(Color::White)),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(" Copy row as ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(menu.selected));
+
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, data_type: &str) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            is_nullable: true,
+            is_primary_key: false,
+            max_display_width: 10,
+        }
+    }
+
+    #[test]
+    fn test_row_formats() {
+        let columns = vec![
+            column("id", "INTEGER"),
+            column("name", "VARCHAR(255)"),
+            column("active", "BOOLEAN"),
+            column("created_at", "TIMESTAMP"),
+            column("note", "TEXT"),
+            column("prefs", "JSON"),
+        ];
+        let row = vec![
+            Some("42".to_string()),
+            Some("O'Brien".to_string()),
+            Some("true".to_string()),
+            Some("2024-05-01 10:00:00".to_string()),
+            None,
+            Some(r#"{"dark":true}"#.to_string()),
+        ];
+
+        assert_eq!(
+            CopyFormat::Insert.format_row(
+                "public.users",
+                &columns,
+                &row,
+                &DatabaseType::PostgreSQL
+            ),
+            "INSERT INTO \"public\".\"users\" (\"id\", \"name\", \"active\", \"created_at\", \
+             \"note\", \"prefs\") VALUES (42, 'O''Brien', TRUE, \
+             CAST('2024-05-01 10:00:00' AS TIMESTAMP), NULL, '{\"dark\":true}');"
+        );
+        assert_eq!(
+            CopyFormat::Json.format_row("users", &columns, &row, &DatabaseType::PostgreSQL),
+            r#"{"id":42,"name":"O'Brien","active":true,"created_at":"2024-05-01 10:00:00","note":null,"prefs":{"dark":true}}"#
+        );
+        assert_eq!(
+            CopyFormat::Tsv.format_row("users", &columns, &row, &DatabaseType::PostgreSQL),
+            "42\tO'Brien\ttrue\t2024-05-01 10:00:00\t\t{\"dark\":true}"
+        );
+        let pg = DatabaseType::PostgreSQL;
+        // A value that doesn't parse as its type stays a string
+        assert_eq!(
+            sql_literal(Some("n/a"), Some(&column("total", "DECIMAL")), &pg),
+            "'n/a'"
+        );
+        // The text 'NULL' is a string, not SQL NULL
+        assert_eq!(
+            sql_literal(Some("NULL"), Some(&column("note", "TEXT")), &pg),
+            "'NULL'"
+        );
+        assert_eq!(
+            CopyFormatMenu::format_for_key('3'),
+            Some(CopyFormat::Insert)
+        );
+        assert_eq!(CopyFormatMenu::format_for_key('5'), None);
+    }
+
+    #[test]
+    fn test_dialect_literals() {
+        let flag = column("active", "BOOLEAN");
+        let created = column("created_at", "TIMESTAMP");
+        let day = column("day", "DATE");
+        let blob = column("data", "BYTEA");
+        let literal = |value: &str, column: &ColumnInfo, database_type: DatabaseType| {
+            sql_literal(Some(value), Some(column), &database_type)
+        };
+
+        assert_eq!(literal("t", &flag, DatabaseType::MsSql), "1");
+        assert_eq!(literal("false", &flag, DatabaseType::MsSql), "0");
+        assert_eq!(literal("1", &flag, DatabaseType::MySQL), "TRUE");
+
+        let at = "2024-05-01 10:00:00";
+        assert_eq!(
+            literal(at, &created, DatabaseType::MsSql),
+            format!("CAST('{at}' AS DATETIME2)")
+        );
+        assert_eq!(
+            literal(at, &created, DatabaseType::MariaDB),
+            format!("CAST('{at}' AS DATETIME)")
+        );
+        assert_eq!(
+            literal(at, &created, DatabaseType::SQLite),
+            format!("'{at}'")
+        );
+        assert_eq!(
+            literal("2024-05-01", &day, DatabaseType::MsSql),
+            "CAST('2024-05-01' AS DATE)"
+        );
+
+        assert_eq!(
+            literal("0xDEAD", &blob, DatabaseType::PostgreSQL),
+            "'\\xDEAD'::bytea"
+        );
+        assert_eq!(literal("0xDEAD", &blob, DatabaseType::MsSql), "0xDEAD");
+        assert_eq!(literal("0xDEAD", &blob, DatabaseType::SQLite), "X'DEAD'");
+        assert_eq!(literal("0x", &blob, DatabaseType::MySQL), "X''");
+        // Not hex text, e.g. a value cut for display
+        assert_eq!(literal("0xDEA", &blob, DatabaseType::MsSql), "'0xDEA'");
+    }
+}
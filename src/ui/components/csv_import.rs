@@ -0,0 +1,311 @@
+// FilePath: src/ui/components/csv_import.rs
+
+#![forbid(unsafe_code)]
+
+use crate::{
+    database::{csv_import, DatabaseType, TableColumn},
+    ui::theme::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Step of the CSV import modal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvImportStage {
+    /// Typing the CSV file path
+    Path,
+    /// Mapping CSV columns to table columns
+    Mapping,
+}
+
+/// Modal for loading a CSV file into a PostgreSQL table with COPY
+#[derive(Debug, Clone)]
+pub struct CsvImportModal {
+    pub connection_id: String,
+    /// Engine of the connection, for identifier quoting
+    pub database_type: DatabaseType,
+    pub table_name: String,
+    pub stage: CsvImportStage,
+    pub path_input: String,
+    /// Header row of the CSV file
+    pub csv_headers: Vec<String>,
+    /// First data row, used for coercion hints
+    pub sample: Vec<String>,
+    pub table_columns: Vec<TableColumn>,
+    /// Target table column for each CSV column; `None` skips it
+    pub mapping: Vec<Option<usize>>,
+    /// Highlighted CSV column
+    pub selected: usize,
+    pub error: Option<String>,
+}
+
+impl CsvImportModal {
+    /// Create the modal at the path step
+    pub fn new(connection_id: String, database_type: DatabaseType, table_name: String) -> Self {
+        Self {
+            connection_id,
+            database_type,
+            table_name,
+            stage: CsvImportStage::Path,
+            path_input: String::new(),
+            csv_headers: Vec::new(),
+            sample: Vec::new(),
+            table_columns: Vec::new(),
+            mapping: Vec::new(),
+            selected: 0,
+            error: None,
+        }
+    }
+
+    /// Move to the mapping step with columns matched by name
+    pub fn load_columns(
+        &mut self,
+        csv_headers: Vec<String>,
+        sample: Vec<String>,
+        table_columns: Vec<TableColumn>,
+    ) {
+        self.mapping = csv_import::auto_map(&csv_headers, &table_columns);
+        self.csv_headers = csv_headers;
+        self.sample = sample;
+        self.table_columns = table_columns;
+        self.selected = 0;
+        self.error = None;
+        self.stage = CsvImportStage::Mapping;
+    }
+
+    /// Move selection down
+    pub fn select_next(&mut self) {
+        if !self.csv_headers.is_empty() && self.selected < self.csv_headers.len() - 1 {
+            self.selected += 1;
+        }
+    }
+
+    /// Move selection up
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Cycle the highlighted CSV column through the table columns and "skip"
+    pub fn cycle_target(&mut self, forward: bool) {
+        let count = self.table_columns.len();
+        let Some(target) = self.mapping.get_mut(self.selected) else {
+            return;
+        };
+        // Position `count` stands for "skip"
+        let current = target.unwrap_or(count);
+        let next = if forward {
+            (current + 1) % (count + 1)
+        } else {
+            (current + count) % (count + 1)
+        };
+        *target = (next < count).then_some(next);
+    }
+
+    /// Check the mapping before starting the copy
+    pub fn validate(&self) -> Result<(), String> {
+        let mapped: Vec<usize> = self.mapping.iter().flatten().copied().collect();
+        if mapped.is_empty() {
+            return Err("Map at least one CSV column".to_string());
+        }
+        for (i, target) in mapped.iter().enumerate() {
+            if mapped[..i].contains(target) {
+                return Err(format!(
+                    "Column '{}' is mapped more than once",
+                    self.table_columns[*target].name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// COPY statement for the current mapping
+    pub fn copy_statement(&self) -> String {
+        let columns: Vec<String> = self
+            .mapping
+            .iter()
+            .flatten()
+            .map(|&i| self.table_columns[i].name.clone())
+            .collect();
+        csv_import::copy_statement(&self.table_name, &columns, &self.database_type)
+    }
+
+    /// CSV columns to keep, or `None` when every column is mapped
+    pub fn keep_columns(&self) -> Option<Vec<usize>> {
+        if self.mapping.iter().all(Option::is_some) {
+            None
+        } else {
+            Some(
+                self.mapping
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, target)| target.is_some())
+                    .map(|(i, _)| i)
+                    .collect(),
+            )
+        }
+    }
+
+    /// Render the modal as a centered popup
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = area.width.saturating_sub(4).min(90);
+        let rows = match self.stage {
+            CsvImportStage::Path => 1,
+            CsvImportStage::Mapping => self.csv_headers.len() as u16,
+        };
+        let height = (rows + 7).min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Import CSV into {} ", self.table_name))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::default().fg(theme.get_color("active_border")))
+            .style(
+                Style::default()
+                    .bg(theme.get_color("background"))
+                    .fg(theme.get_color("foreground")),
+            );
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),    // Path input or mapping list
+                Constraint::Length(1), // Error line
+                Constraint::Length(2), // Help text
+            ])
+            .split(inner);
+
+        let help = match self.stage {
+            CsvImportStage::Path => {
+                let input = Paragraph::new(Line::from(vec![
+                    Span::styled(
+                        "CSV file: ",
+                        Style::default().fg(theme.get_color("inactive_pane")),
+                    ),
+                    Span::raw(format!("{}█", self.path_input)),
+                ]));
+                frame.render_widget(input, chunks[0]);
+                "Enter: Read columns • Esc: Cancel"
+            }
+            CsvImportStage::Mapping => {
+                self.render_mapping(frame, chunks[0], theme);
+                "j/k: Navigate • h/l or Space: Change target • Enter: Import • Esc: Cancel"
+            }
+        };
+
+        if let Some(error) = &self.error {
+            let error =
+                Paragraph::new(error.as_str()).style(Style::default().fg(theme.get_color("error")));
+            frame.render_widget(error, chunks[1]);
+        }
+
+        let help = Paragraph::new(help)
+            .block(Block::default().borders(Borders::TOP))
+            .alignment(Alignment::Center);
+        frame.render_widget(help, chunks[2]);
+    }
+
+    fn render_mapping(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let dim = Style::default().fg(theme.get_color("inactive_pane"));
+        let items: Vec<ListItem> = self
+            .csv_headers
+            .iter()
+            .zip(&self.mapping)
+            .enumerate()
+            .map(|(i, (header, target))| {
+                let mut spans = vec![Span::raw(format!("{header:<20} → "))];
+                match target.and_then(|t| self.table_columns.get(t)) {
+                    Some(column) => {
+                        spans.push(Span::raw(column.name.clone()));
+                        spans.push(Span::styled(
+                            format!(" ({})", column.data_type.to_sql().to_lowercase()),
+                            dim,
+                        ));
+                        let sample = self.sample.get(i).map(String::as_str).unwrap_or("");
+                        if let Some(hint) = csv_import::coercion_hint(sample, &column.data_type) {
+                            spans.push(Span::styled(
+                                format!("  ⚠ {hint}"),
+                                Style::default().fg(theme.get_color("warning")),
+                            ));
+                        }
+                    }
+                    None => spans.push(Span::styled("skip", dim)),
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(theme.get_color("selection_bg"))
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut list_state = ListState::default();
+        if !self.csv_headers.is_empty() {
+            list_state.select(Some(self.selected.min(self.csv_headers.len() - 1)));
+        }
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::DataType;
+
+    fn column(name: &str, data_type: DataType) -> TableColumn {
+        TableColumn {
+            name: name.to_string(),
+            data_type,
+            is_nullable: true,
+            default_value: None,
+            is_primary_key: false,
+        }
+    }
+
+    #[test]
+    fn test_mapping_and_statement() {
+        let mut modal = CsvImportModal::new(
+            "conn".to_string(),
+            DatabaseType::PostgreSQL,
+            "users".to_string(),
+        );
+        modal.load_columns(
+            vec!["Name".to_string(), "notes".to_string(), "id".to_string()],
+            vec!["Ada".to_string(), "x".to_string(), "1".to_string()],
+            vec![
+                column("id", DataType::Integer),
+                column("name", DataType::Text),
+            ],
+        );
+
+        assert_eq!(modal.mapping, vec![Some(1), None, Some(0)]);
+        assert_eq!(modal.keep_columns(), Some(vec![0, 2]));
+        assert_eq!(
+            modal.copy_statement(),
+            "COPY \"users\" (\"name\", \"id\") FROM STDIN WITH (FORMAT csv, HEADER true)"
+        );
+
+        // Cycling the skipped column wraps through every target and back to skip
+        modal.selected = 1;
+        modal.cycle_target(true);
+        assert_eq!(modal.mapping[1], Some(0));
+        assert!(modal.validate().is_err());
+        modal.cycle_target(false);
+        assert_eq!(modal.mapping[1], None);
+        assert!(modal.validate().is_ok());
+    }
+}
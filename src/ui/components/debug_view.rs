@@ -2,10 +2,10 @@
 
 #![forbid(unsafe_code)]
 
-use crate::{logging::DebugMessage, ui::theme::Theme};
+use crate::{cli::LogLevel, logging::DebugMessage, ui::theme::Theme};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span, Text},
     widgets::{
         Block, Borders, Clear, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation,
@@ -52,7 +52,17 @@ impl DebugView {
         }
     }
 
-    /// Render the debug view as a full-screen overlay
+    /// Copy a log line to the clipboard
+    pub fn copy_message(message: &DebugMessage) -> Result<(), String> {
+        let mut clipboard =
+            arboard::Clipboard::new().map_err(|e| format!("Failed to access clipboard: {e}"))?;
+        clipboard
+            .set_text(message.to_plain_line())
+            .map_err(|e| format!("Failed to copy to clipboard: {e}"))
+    }
+
+    /// Render the debug view as a full-screen overlay. `level_filter` is the
+    /// filter bar's selection, or `None` when the bar is hidden.
     pub fn render(
         &mut self,
         frame: &mut Frame,
@@ -60,6 +70,7 @@ impl DebugView {
         theme: &Theme,
         debug_messages: &[DebugMessage],
         scroll_offset: usize,
+        level_filter: Option<Option<LogLevel>>,
     ) {
         // Clear the background
         frame.render_widget(Clear, area);
@@ -84,6 +95,7 @@ impl DebugView {
             .constraints([
                 Constraint::Length(8), // Performance metrics
                 Constraint::Length(3), // Statistics
+                Constraint::Length(if level_filter.is_some() { 3 } else { 0 }), // Level filter
                 Constraint::Min(10),   // Log messages
                 Constraint::Length(3), // Help text
             ])
@@ -95,11 +107,63 @@ impl DebugView {
         // Render statistics
         self.render_statistics(frame, chunks[1], theme, debug_messages);
 
+        // Render level filter bar
+        if let Some(min_level) = level_filter {
+            self.render_level_filter(frame, chunks[2], theme, min_level);
+        }
+
         // Render log messages
-        self.render_log_messages(frame, chunks[2], theme, debug_messages, scroll_offset);
+        self.render_log_messages(frame, chunks[3], theme, debug_messages, scroll_offset);
 
         // Render help text
-        self.render_help_text(frame, chunks[3], theme);
+        self.render_help_text(frame, chunks[4], theme);
+    }
+
+    /// Render the level filter bar with the selected level highlighted
+    fn render_level_filter(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &Theme,
+        min_level: Option<LogLevel>,
+    ) {
+        let filter_block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Level (←/→) ")
+            .style(
+                Style::default()
+                    .bg(theme.get_color("background"))
+                    .fg(theme.get_color("primary_highlight")),
+            );
+
+        let inner_area = filter_block.inner(area);
+        frame.render_widget(filter_block, area);
+
+        let options = [
+            ("ALL", None),
+            ("DEBUG", Some(LogLevel::Debug)),
+            ("INFO", Some(LogLevel::Info)),
+            ("WARN", Some(LogLevel::Warn)),
+            ("ERROR", Some(LogLevel::Error)),
+        ];
+        let mut spans = Vec::new();
+        for (label, level) in options {
+            let style = if level == min_level {
+                Style::default()
+                    .fg(theme.get_color("background"))
+                    .bg(theme.get_color("primary_highlight"))
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.get_color("inactive_pane"))
+            };
+            spans.push(Span::styled(format!(" {label} "), style));
+            spans.push(Span::raw(" "));
+        }
+
+        let paragraph = Paragraph::new(Line::from(spans))
+            .style(Style::default().bg(theme.get_color("background")))
+            .alignment(Alignment::Center);
+        frame.render_widget(paragraph, inner_area);
     }
 
     /// Render performance metrics section
@@ -309,7 +373,16 @@ impl DebugView {
             .iter()
             .skip(start_idx)
             .take(visible_height)
-            .map(|message| self.format_log_message(message, theme))
+            .enumerate()
+            .map(|(i, message)| {
+                let item = self.format_log_message(message, theme);
+                // The top line is the one Ctrl+C copies
+                if i == 0 {
+                    item.style(Style::default().add_modifier(Modifier::REVERSED))
+                } else {
+                    item
+                }
+            })
             .collect();
 
         let list = List::new(items).style(Style::default().bg(theme.get_color("background")));
@@ -400,7 +473,7 @@ impl DebugView {
         let inner_area = help_block.inner(area);
         frame.render_widget(help_block, area);
 
-        let help_text = "j/k: Scroll • PgUp/PgDn: Page scroll • gg/G: Top/Bottom • c: Clear logs • Ctrl+B: Close debug view";
+        let help_text = "j/k: Scroll • gg/G: Top/Bottom • f: Level filter • Ctrl+C: Copy top line • c: Clear shown logs • Ctrl+B: Close";
 
         let paragraph = Paragraph::new(help_text)
             .style(
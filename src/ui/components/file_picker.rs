@@ -0,0 +1,317 @@
+// FilePath: src/ui/components/file_picker.rs
+
+#![forbid(unsafe_code)]
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::path::{Path, PathBuf};
+
+/// Magic header at the start of every SQLite 3 database file
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// A single entry in the file picker listing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilePickerEntry {
+    pub name: String,
+    pub is_dir: bool,
+}
+
+/// Browsable file picker used for choosing SQLite database files
+#[derive(Debug, Clone)]
+pub struct FilePickerState {
+    /// Directory currently being listed
+    pub current_dir: PathBuf,
+    /// Entries of the current directory (directories first)
+    pub entries: Vec<FilePickerEntry>,
+    /// Selected entry index
+    pub selected: usize,
+}
+
+impl FilePickerState {
+    /// Open the picker in the given directory, falling back to the home directory
+    pub fn new(start_dir: PathBuf) -> Self {
+        let current_dir = if start_dir.is_dir() {
+            start_dir
+        } else {
+            dirs::home_dir().unwrap_or_else(|| PathBuf::from("."))
+        };
+
+        let mut picker = Self {
+            current_dir,
+            entries: Vec::new(),
+            selected: 0,
+        };
+        picker.refresh();
+        picker
+    }
+
+    /// Reload the entries of the current directory
+    pub fn refresh(&mut self) {
+        self.entries = list_directory(&self.current_dir);
+        self.selected = 0;
+    }
+
+    /// Move selection down
+    pub fn select_next(&mut self) {
+        if !self.entries.is_empty() && self.selected < self.entries.len() - 1 {
+            self.selected += 1;
+        }
+    }
+
+    /// Move selection up
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Go to the parent directory
+    pub fn go_up(&mut self) {
+        if let Some(parent) = self.current_dir.parent() {
+            let previous = self
+                .current_dir
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string());
+            self.current_dir = parent.to_path_buf();
+            self.refresh();
+            // Keep the directory we came from selected
+            if let Some(previous) = previous {
+                if let Some(idx) = self.entries.iter().position(|e| e.name == previous) {
+                    self.selected = idx;
+                }
+            }
+        }
+    }
+
+    /// Activate the selected entry: descend into directories, return the path of files
+    pub fn activate(&mut self) -> Option<PathBuf> {
+        let entry = self.entries.get(self.selected)?.clone();
+        let path = self.current_dir.join(&entry.name);
+        if entry.is_dir {
+            self.current_dir = path;
+            self.refresh();
+            None
+        } else {
+            Some(path)
+        }
+    }
+}
+
+/// List a directory, directories first, hidden entries omitted
+fn list_directory(dir: &Path) -> Vec<FilePickerEntry> {
+    let mut entries: Vec<FilePickerEntry> = std::fs::read_dir(dir)
+        .map(|read_dir| {
+            read_dir
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    if name.starts_with('.') {
+                        return None;
+                    }
+                    let is_dir = entry.path().is_dir();
+                    Some(FilePickerEntry { name, is_dir })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+    entries
+}
+
+/// Expand a leading `~` to the user's home directory
+pub fn expand_tilde(path: &str) -> PathBuf {
+    if path == "~" {
+        return dirs::home_dir().unwrap_or_else(|| PathBuf::from(path));
+    }
+    if let Some(rest) = path.strip_prefix("~/") {
+        if let Some(home) = dirs::home_dir() {
+            return home.join(rest);
+        }
+    }
+    PathBuf::from(path)
+}
+
+/// Complete a partially typed path against the filesystem.
+///
+/// Returns the input extended by the longest common prefix of all matching
+/// entries, with a trailing `/` when the single match is a directory.
+/// Returns `None` when nothing matches or no extension is possible.
+pub fn complete_path(input: &str) -> Option<String> {
+    let (dir_part, file_prefix) = match input.rfind('/') {
+        Some(idx) => (&input[..=idx], &input[idx + 1..]),
+        None => ("", input),
+    };
+
+    let dir = if dir_part.is_empty() {
+        PathBuf::from(".")
+    } else {
+        expand_tilde(dir_part)
+    };
+
+    let matches: Vec<FilePickerEntry> = list_directory(&dir)
+        .into_iter()
+        .filter(|entry| entry.name.starts_with(file_prefix))
+        .collect();
+
+    let first = matches.first()?;
+    let mut common = first.name.clone();
+    for entry in &matches[1..] {
+        let shared = common
+            .chars()
+            .zip(entry.name.chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+        common = common.chars().take(shared).collect();
+    }
+
+    let mut completed = format!("{dir_part}{common}");
+    if matches.len() == 1 && first.is_dir {
+        completed.push('/');
+    }
+
+    if completed.len() > input.len() {
+        Some(completed)
+    } else {
+        None
+    }
+}
+
+/// Check that a file starts with the SQLite 3 header.
+/// Empty files are accepted since SQLite initializes them on first write.
+pub fn check_sqlite_header(path: &Path) -> Result<(), String> {
+    use std::io::Read;
+
+    let mut file =
+        std::fs::File::open(path).map_err(|e| format!("Cannot open '{}': {e}", path.display()))?;
+    let mut header = [0u8; 16];
+    let read = file
+        .read(&mut header)
+        .map_err(|e| format!("Cannot read '{}': {e}", path.display()))?;
+
+    if read == 0 || &header == SQLITE_HEADER {
+        Ok(())
+    } else {
+        Err(format!(
+            "'{}' is not a SQLite database file",
+            path.display()
+        ))
+    }
+}
+
+/// Render the file picker as a popup
+pub fn render_file_picker(f: &mut Frame, picker: &FilePickerState, area: Rect) {
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(" 📂 {} ", picker.current_dir.display()))
+        .title_style(
+            Style::default()
+                .fg(Color::Rgb(116, 199, 236))
+                .add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Rgb(116, 199, 236)))
+        .style(Style::default().bg(Color::Rgb(13, 13, 13)));
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    if picker.entries.is_empty() {
+        let empty = Paragraph::new("Empty directory")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(empty, chunks[0]);
+    } else {
+        let items: Vec<ListItem> = picker
+            .entries
+            .iter()
+            .map(|entry| {
+                if entry.is_dir {
+                    ListItem::new(Line::from(Span::styled(
+                        format!("📁 {}/", entry.name),
+                        Style::default().fg(Color::Cyan),
+                    )))
+                } else {
+                    ListItem::new(Line::from(Span::styled(
+                        format!("   {}", entry.name),
+                        Style::default().fg(Color::White),
+                    )))
+                }
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(Color::Rgb(30, 30, 40))
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut list_state = ListState::default();
+        list_state.select(Some(picker.selected));
+        f.render_stateful_widget(list, chunks[0], &mut list_state);
+    }
+
+    let hints = Paragraph::new("j/k: Move • Enter: Open/Select • h: Up • Esc: Close")
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(hints, chunks[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_expand_tilde() {
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(expand_tilde("~/data/app.db"), home.join("data/app.db"));
+        assert_eq!(expand_tilde("/tmp/app.db"), PathBuf::from("/tmp/app.db"));
+    }
+
+    #[test]
+    fn test_complete_path() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("databases")).unwrap();
+        std::fs::write(dir.path().join("app_one.db"), b"").unwrap();
+        std::fs::write(dir.path().join("app_two.db"), b"").unwrap();
+
+        let base = format!("{}/", dir.path().display());
+        assert_eq!(
+            complete_path(&format!("{base}data")),
+            Some(format!("{base}databases/"))
+        );
+        assert_eq!(
+            complete_path(&format!("{base}ap")),
+            Some(format!("{base}app_"))
+        );
+        assert_eq!(complete_path(&format!("{base}app_")), None);
+        assert_eq!(complete_path(&format!("{base}missing")), None);
+    }
+
+    #[test]
+    fn test_check_sqlite_header() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let valid = dir.path().join("valid.db");
+        let mut file = std::fs::File::create(&valid).unwrap();
+        file.write_all(b"SQLite format 3\0rest-of-header").unwrap();
+        assert!(check_sqlite_header(&valid).is_ok());
+
+        let empty = dir.path().join("empty.db");
+        std::fs::File::create(&empty).unwrap();
+        assert!(check_sqlite_header(&empty).is_ok());
+
+        let text = dir.path().join("notes.txt");
+        std::fs::write(&text, b"just some notes").unwrap();
+        assert!(check_sqlite_header(&text).is_err());
+    }
+}
@@ -0,0 +1,149 @@
+// FilePath: src/ui/components/hex_view.rs
+
+#![forbid(unsafe_code)]
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Bytes per hex dump line
+const BYTES_PER_LINE: usize = 16;
+
+/// `K` popup over a binary cell: a hex dump of the bytes loaded for display
+#[derive(Debug, Clone)]
+pub struct HexView {
+    /// Column and row the bytes come from
+    pub title: String,
+    pub bytes: Vec<u8>,
+    /// Size of the whole value; more than `bytes.len()` when it was cut
+    pub total_bytes: usize,
+    /// First dump line shown
+    pub scroll: usize,
+}
+
+impl HexView {
+    pub fn new(title: String, bytes: Vec<u8>, total_bytes: usize) -> Self {
+        Self {
+            title,
+            bytes,
+            total_bytes,
+            scroll: 0,
+        }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.bytes.len().div_ceil(BYTES_PER_LINE)
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll = (self.scroll + lines).min(self.line_count().saturating_sub(1));
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+}
+
+/// Decode a `0x...` cell back into bytes; a trailing half byte left by a
+/// display cut is dropped
+pub fn parse_hex_literal(text: &str) -> Option<Vec<u8>> {
+    let digits = text.strip_prefix("0x")?.as_bytes();
+    digits
+        .chunks_exact(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+/// Bytes a `0x...` literal of `len` characters stands for
+pub fn hex_literal_bytes(len: usize) -> usize {
+    len.saturating_sub(2) / 2
+}
+
+/// One dump line: offset, hex bytes and their printable ASCII
+pub fn hex_dump_line(offset: usize, chunk: &[u8]) -> String {
+    let hex: Vec<String> = chunk.iter().map(|b| format!("{b:02x}")).collect();
+    let ascii: String = chunk
+        .iter()
+        .map(|&b| {
+            if b.is_ascii_graphic() || b == b' ' {
+                b as char
+            } else {
+                '.'
+            }
+        })
+        .collect();
+    format!(
+        "{offset:08x}  {:<width$}  |{ascii}|",
+        hex.join(" "),
+        width = BYTES_PER_LINE * 3 - 1
+    )
+}
+
+/// Render the dump centered over the viewer
+pub fn render_hex_view(frame: &mut Frame, view: &HexView) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(84);
+    let height = screen.height.saturating_sub(4).min(24);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let shown = if view.bytes.len() < view.total_bytes {
+        format!("first {} of {} bytes", view.bytes.len(), view.total_bytes)
+    } else {
+        format!("{} bytes", view.total_bytes)
+    };
+    let block = Block::default()
+        .title(format!(" {} - {shown} ", view.title))
+        .title_bottom(" j/k scroll  Esc close ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let visible = area.height.saturating_sub(2) as usize;
+    let lines: Vec<Line> = view
+        .bytes
+        .chunks(BYTES_PER_LINE)
+        .enumerate()
+        .skip(view.scroll)
+        .take(visible)
+        .map(|(line, chunk)| {
+            Line::from(Span::styled(
+                hex_dump_line(line * BYTES_PER_LINE, chunk),
+                Style::default().fg(Color::Gray),
+            ))
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_literal_round_trip_and_dump() {
+        let bytes = b"PNG\r\n\x1a\n\x00";
+        let literal = crate::database::hex_literal(bytes);
+        assert_eq!(literal, "0x504E470D0A1A0A00");
+        assert_eq!(parse_hex_literal(&literal).as_deref(), Some(&bytes[..]));
+        assert_eq!(parse_hex_literal("0x504E4"), Some(b"PN".to_vec()));
+        assert_eq!(parse_hex_literal("hello"), None);
+        assert_eq!(hex_literal_bytes(literal.len()), 8);
+
+        assert_eq!(
+            hex_dump_line(16, bytes),
+            format!(
+                "00000010  50 4e 47 0d 0a 1a 0a 00{}  |PNG.....|",
+                " ".repeat(24)
+            )
+        );
+    }
+}
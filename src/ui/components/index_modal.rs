@@ -0,0 +1,334 @@
+// FilePath: src/ui/components/index_modal.rs
+
+#![forbid(unsafe_code)]
+
+use crate::{
+    database::{ddl, DatabaseType},
+    ui::theme::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Field of the index form that has focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexField {
+    Columns,
+    Type,
+    Unique,
+    Name,
+}
+
+impl IndexField {
+    fn next(self) -> Self {
+        match self {
+            IndexField::Columns => IndexField::Type,
+            IndexField::Type => IndexField::Unique,
+            IndexField::Unique => IndexField::Name,
+            IndexField::Name => IndexField::Columns,
+        }
+    }
+
+    fn previous(self) -> Self {
+        match self {
+            IndexField::Columns => IndexField::Name,
+            IndexField::Type => IndexField::Columns,
+            IndexField::Unique => IndexField::Type,
+            IndexField::Name => IndexField::Unique,
+        }
+    }
+}
+
+/// Modal for creating an index on the table shown in the Details pane
+#[derive(Debug, Clone)]
+pub struct IndexModal {
+    /// Plain or `schema.name` table
+    pub table_name: String,
+    pub database_type: DatabaseType,
+    pub columns: Vec<String>,
+    /// Chosen columns as indices into `columns`, in key order
+    pub chosen: Vec<usize>,
+    /// Highlighted column
+    pub cursor: usize,
+    pub field: IndexField,
+    /// Position in `ddl::index_types`
+    pub type_index: usize,
+    pub unique: bool,
+    pub name: String,
+    /// The name was typed by hand; stop deriving it from the columns
+    pub name_edited: bool,
+    pub error: Option<String>,
+}
+
+impl IndexModal {
+    pub fn new(table_name: String, database_type: DatabaseType, columns: Vec<String>) -> Self {
+        let mut modal = Self {
+            table_name,
+            database_type,
+            columns,
+            chosen: Vec::new(),
+            cursor: 0,
+            field: IndexField::Columns,
+            type_index: 0,
+            unique: false,
+            name: String::new(),
+            name_edited: false,
+            error: None,
+        };
+        modal.update_name();
+        modal
+    }
+
+    pub fn next_field(&mut self) {
+        self.field = self.field.next();
+        if self.field == IndexField::Type && self.index_types().is_empty() {
+            self.field = self.field.next();
+        }
+    }
+
+    pub fn previous_field(&mut self) {
+        self.field = self.field.previous();
+        if self.field == IndexField::Type && self.index_types().is_empty() {
+            self.field = self.field.previous();
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.cursor + 1 < self.columns.len() {
+            self.cursor += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Add or remove the highlighted column; added columns go last in the key
+    pub fn toggle_column(&mut self) {
+        if self.cursor >= self.columns.len() {
+            return;
+        }
+        match self.chosen.iter().position(|&c| c == self.cursor) {
+            Some(position) => {
+                self.chosen.remove(position);
+            }
+            None => self.chosen.push(self.cursor),
+        }
+        self.update_name();
+    }
+
+    pub fn cycle_type(&mut self, forward: bool) {
+        let count = self.index_types().len();
+        if count > 0 {
+            self.type_index = if forward {
+                (self.type_index + 1) % count
+            } else {
+                (self.type_index + count - 1) % count
+            };
+        }
+    }
+
+    pub fn insert_name_char(&mut self, c: char) {
+        self.name.push(c);
+        self.name_edited = true;
+    }
+
+    pub fn backspace_name(&mut self) {
+        self.name.pop();
+        self.name_edited = true;
+    }
+
+    fn index_types(&self) -> &'static [&'static str] {
+        ddl::index_types(&self.database_type)
+    }
+
+    fn chosen_columns(&self) -> Vec<String> {
+        self.chosen
+            .iter()
+            .map(|&i| self.columns[i].clone())
+            .collect()
+    }
+
+    fn update_name(&mut self) {
+        if !self.name_edited {
+            self.name = ddl::default_index_name(&self.table_name, &self.chosen_columns());
+        }
+    }
+
+    /// `CREATE INDEX` statement for the form, or what is missing
+    pub fn statement(&self) -> Result<String, String> {
+        if self.chosen.is_empty() {
+            return Err("Select at least one column (Space)".to_string());
+        }
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err("Index name is required".to_string());
+        }
+        Ok(ddl::create_index_sql(
+            &self.table_name,
+            name,
+            &self.chosen_columns(),
+            self.index_types().get(self.type_index).copied(),
+            self.unique,
+            &self.database_type,
+        ))
+    }
+
+    /// Render the modal as a centered popup
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = area.width.saturating_sub(4).min(70);
+        let height = (self.columns.len() as u16 + 11).min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Create Index on {} ", self.table_name))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::default().fg(theme.get_color("active_border")))
+            .style(
+                Style::default()
+                    .bg(theme.get_color("background"))
+                    .fg(theme.get_color("foreground")),
+            );
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // Columns label
+                Constraint::Min(1),    // Column list
+                Constraint::Length(3), // Type, unique, name
+                Constraint::Length(1), // Error line
+                Constraint::Length(2), // Help text
+            ])
+            .split(inner);
+
+        let label = |field: IndexField, text: &str| {
+            let style = if self.field == field {
+                Style::default()
+                    .fg(theme.get_color("primary_highlight"))
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.get_color("inactive_pane"))
+            };
+            Span::styled(format!("{text:<9}"), style)
+        };
+
+        frame.render_widget(
+            Paragraph::new(Line::from(label(IndexField::Columns, "Columns"))),
+            chunks[0],
+        );
+        let items: Vec<ListItem> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                let mark = match self.chosen.iter().position(|&c| c == i) {
+                    Some(position) => format!("[{}]", position + 1),
+                    None => "[ ]".to_string(),
+                };
+                ListItem::new(format!("  {mark} {column}"))
+            })
+            .collect();
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(theme.get_color("selection_bg"))
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut list_state = ListState::default();
+        if self.field == IndexField::Columns && !self.columns.is_empty() {
+            list_state.select(Some(self.cursor.min(self.columns.len() - 1)));
+        }
+        frame.render_stateful_widget(list, chunks[1], &mut list_state);
+
+        let index_type = self
+            .index_types()
+            .get(self.type_index)
+            .copied()
+            .unwrap_or("default");
+        let name_cursor = if self.field == IndexField::Name {
+            "█"
+        } else {
+            ""
+        };
+        let settings = Paragraph::new(vec![
+            Line::from(vec![
+                label(IndexField::Type, "Type"),
+                Span::raw(format!("◀ {index_type} ▶")),
+            ]),
+            Line::from(vec![
+                label(IndexField::Unique, "Unique"),
+                Span::raw(if self.unique { "[x]" } else { "[ ]" }),
+            ]),
+            Line::from(vec![
+                label(IndexField::Name, "Name"),
+                Span::raw(format!("{}{name_cursor}", self.name)),
+            ]),
+        ]);
+        frame.render_widget(settings, chunks[2]);
+
+        if let Some(error) = &self.error {
+            let error =
+                Paragraph::new(error.as_str()).style(Style::default().fg(theme.get_color("error")));
+            frame.render_widget(error, chunks[3]);
+        }
+
+        let help = Paragraph::new(
+            "Tab: Next field • Space: Toggle • h/l: Type • Enter: Create • Esc: Cancel",
+        )
+        .block(Block::default().borders(Borders::TOP))
+        .alignment(Alignment::Center);
+        frame.render_widget(help, chunks[4]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_form_builds_statement() {
+        let mut modal = IndexModal::new(
+            "users".to_string(),
+            DatabaseType::PostgreSQL,
+            vec!["id".to_string(), "email".to_string(), "name".to_string()],
+        );
+        assert!(modal.statement().is_err());
+
+        // Columns keep the order they were picked in, and the name follows them
+        modal.cursor = 2;
+        modal.toggle_column();
+        modal.cursor = 1;
+        modal.toggle_column();
+        assert_eq!(modal.name, "idx_users_name_email");
+
+        modal.cycle_type(false);
+        modal.unique = true;
+        assert_eq!(
+            modal.statement().unwrap(),
+            "CREATE UNIQUE INDEX CONCURRENTLY \"idx_users_name_email\" ON \"users\" \
+             USING brin (\"name\", \"email\")"
+        );
+
+        // A typed name sticks when the columns change
+        modal.backspace_name();
+        modal.toggle_column();
+        assert_eq!(modal.name, "idx_users_name_emai");
+
+        // SQLite has no index methods, so Tab skips the type field
+        let mut modal = IndexModal::new("t".to_string(), DatabaseType::SQLite, Vec::new());
+        modal.next_field();
+        assert_eq!(modal.field, IndexField::Unique);
+    }
+}
@@ -0,0 +1,214 @@
+// FilePath: src/ui/components/json_view.rs
+
+#![forbid(unsafe_code)]
+
+use crate::ui::components::sql_highlight::{SyntaxStyles, TokenKind};
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use std::ops::Range;
+
+/// `J` popup over a JSON cell: the value pretty-printed, or the raw text
+/// with the parse error when it isn't valid JSON
+#[derive(Debug, Clone)]
+pub struct JsonView {
+    /// Column and row the value comes from
+    pub title: String,
+    pub text: String,
+    /// Why the value couldn't be parsed
+    pub error: Option<String>,
+    /// First line shown
+    pub scroll: usize,
+}
+
+impl JsonView {
+    pub fn new(title: String, value: &str) -> Self {
+        let (text, error) = match serde_json::from_str::<serde_json::Value>(value) {
+            Ok(json) => (
+                serde_json::to_string_pretty(&json).unwrap_or_else(|_| value.to_string()),
+                None,
+            ),
+            Err(e) => (value.to_string(), Some(e.to_string())),
+        };
+        Self {
+            title,
+            text,
+            error,
+            scroll: 0,
+        }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.text.lines().count()
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll = (self.scroll + lines).min(self.line_count().saturating_sub(1));
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+}
+
+/// Split a line of JSON into highlighted pieces: object keys as functions,
+/// `true`/`false`/`null` as keywords, punctuation as operators. Anything
+/// unrecognised is plain, so raw invalid text still renders.
+pub fn tokenize_json_line(line: &str) -> Vec<(TokenKind, Range<usize>)> {
+    let bytes = line.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let kind = match bytes[i] {
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i = (i + 1).min(bytes.len());
+                let rest = line[i..].trim_start();
+                if rest.starts_with(':') {
+                    TokenKind::Function
+                } else {
+                    TokenKind::String
+                }
+            }
+            b'-' | b'0'..=b'9' => {
+                i += 1;
+                while i < bytes.len()
+                    && matches!(bytes[i], b'0'..=b'9' | b'.' | b'e' | b'E' | b'+' | b'-')
+                {
+                    i += 1;
+                }
+                TokenKind::Number
+            }
+            b'{' | b'}' | b'[' | b']' | b':' | b',' => {
+                i += 1;
+                TokenKind::Operator
+            }
+            b' ' | b'\t' => {
+                while i < bytes.len() && matches!(bytes[i], b' ' | b'\t') {
+                    i += 1;
+                }
+                TokenKind::Plain
+            }
+            _ => {
+                // A word up to the next space or punctuation
+                while i < bytes.len()
+                    && !matches!(
+                        bytes[i],
+                        b' ' | b'\t' | b'"' | b'{' | b'}' | b'[' | b']' | b':' | b','
+                    )
+                {
+                    i += line[i..].chars().next().map_or(1, char::len_utf8);
+                }
+                if matches!(&line[start..i], "true" | "false" | "null") {
+                    TokenKind::Keyword
+                } else {
+                    TokenKind::Plain
+                }
+            }
+        };
+        tokens.push((kind, start..i));
+    }
+    tokens
+}
+
+/// Render the value centered over the viewer
+pub fn render_json_view(frame: &mut Frame, view: &JsonView, theme: &Theme) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(100);
+    let height = screen.height.saturating_sub(4).min(40);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(format!(" {} - {} lines ", view.title, view.line_count()))
+        .title_bottom(" j/k scroll  Ctrl+D/U page  Esc close ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let styles = SyntaxStyles::from_theme(theme);
+    let mut lines = Vec::new();
+    if let Some(error) = &view.error {
+        lines.push(Line::styled(
+            format!("Invalid JSON ({error}) - showing the raw text"),
+            Style::default().fg(Color::Red),
+        ));
+        lines.push(Line::from(""));
+    }
+    lines.extend(view.text.lines().skip(view.scroll).map(|line| {
+        Line::from(
+            tokenize_json_line(line)
+                .into_iter()
+                .map(|(kind, range)| Span::styled(line[range].to_string(), styles.style(kind)))
+                .collect::<Vec<_>>(),
+        )
+    }));
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(line: &str) -> Vec<(TokenKind, &str)> {
+        tokenize_json_line(line)
+            .into_iter()
+            .map(|(kind, range)| (kind, &line[range]))
+            .filter(|(kind, _)| *kind != TokenKind::Plain)
+            .collect()
+    }
+
+    #[test]
+    fn test_pretty_prints_and_keeps_invalid_text() {
+        let view = JsonView::new("payload - row 1".to_string(), r#"{"tags":["a"],"n":1}"#);
+        assert_eq!(view.error, None);
+        assert_eq!(view.line_count(), 6);
+
+        let mut raw = JsonView::new("payload - row 2".to_string(), "{\"a\": tru");
+        assert_eq!(raw.text, "{\"a\": tru");
+        assert!(raw.error.is_some());
+        raw.scroll_down(5);
+        assert_eq!(raw.scroll, 0);
+    }
+
+    #[test]
+    fn test_tokenize_json_line() {
+        assert_eq!(
+            kinds(r#"  "na\"me": "x", "n": -1.5e3, "ok": null"#),
+            vec![
+                (TokenKind::Function, r#""na\"me""#),
+                (TokenKind::Operator, ":"),
+                (TokenKind::String, r#""x""#),
+                (TokenKind::Operator, ","),
+                (TokenKind::Function, r#""n""#),
+                (TokenKind::Operator, ":"),
+                (TokenKind::Number, "-1.5e3"),
+                (TokenKind::Operator, ","),
+                (TokenKind::Function, r#""ok""#),
+                (TokenKind::Operator, ":"),
+                (TokenKind::Keyword, "null"),
+            ]
+        );
+        // Unterminated strings and stray text don't panic
+        assert_eq!(kinds("\"é\\"), vec![(TokenKind::String, "\"é\\")]);
+        assert_eq!(kinds("oops ü"), Vec::new());
+    }
+}
@@ -0,0 +1,271 @@
+// FilePath: src/ui/components/lock_monitor.rs
+
+#![forbid(unsafe_code)]
+
+use crate::database::{BackendSignal, LockInfo};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+use std::time::{Duration, Instant};
+
+/// How often the lock list reloads while the monitor is open
+pub const LOCK_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// `:locks` overlay over `pg_locks` for one PostgreSQL connection, reloaded
+/// from `tick`. `k` cancels and `Ctrl+K` terminates the selected backend.
+#[derive(Debug, Clone)]
+pub struct LockMonitor {
+    pub connection_id: String,
+    pub connection_name: String,
+    pub locks: Vec<LockInfo>,
+    pub selected: usize,
+    /// When the last reload started; None until the first one
+    last_refresh: Option<Instant>,
+    /// A reload is running; due reloads are skipped until it finishes
+    pub in_flight: bool,
+    /// Why the last reload failed
+    pub error: Option<String>,
+    /// Signal and backend waiting for `y` to confirm
+    pub pending: Option<(BackendSignal, i32)>,
+}
+
+impl LockMonitor {
+    /// Open empty; the first reload is due right away
+    pub fn new(connection_id: String, connection_name: String) -> Self {
+        Self {
+            connection_id,
+            connection_name,
+            locks: Vec::new(),
+            selected: 0,
+            last_refresh: None,
+            in_flight: false,
+            error: None,
+            pending: None,
+        }
+    }
+
+    /// Whether a reload should start at `now`
+    pub fn is_due(&self, now: Instant) -> bool {
+        !self.in_flight
+            && self
+                .last_refresh
+                .is_none_or(|last| now.duration_since(last) >= LOCK_REFRESH_INTERVAL)
+    }
+
+    /// Mark a reload as started at `now`
+    pub fn begin(&mut self, now: Instant) {
+        self.in_flight = true;
+        self.last_refresh = Some(now);
+    }
+
+    /// Make the next `tick` reload, e.g. after `r` or a cancel
+    pub fn refresh_now(&mut self) {
+        self.last_refresh = None;
+    }
+
+    /// Take a finished reload, keeping the cursor on the same backend
+    pub fn finish(&mut self, result: Result<Vec<LockInfo>, String>) {
+        self.in_flight = false;
+        match result {
+            Ok(locks) => {
+                let pid = self.selected_lock().map(|lock| lock.pid);
+                self.selected = pid
+                    .and_then(|pid| locks.iter().position(|lock| lock.pid == pid))
+                    .unwrap_or(self.selected)
+                    .min(locks.len().saturating_sub(1));
+                self.locks = locks;
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.locks.is_empty() {
+            self.selected = (self.selected + 1) % self.locks.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.locks.is_empty() {
+            self.selected = (self.selected + self.locks.len() - 1) % self.locks.len();
+        }
+    }
+
+    pub fn selected_lock(&self) -> Option<&LockInfo> {
+        self.locks.get(self.selected)
+    }
+
+    /// Ask to confirm `signal` for the selected backend
+    pub fn request(&mut self, signal: BackendSignal) {
+        self.pending = self.selected_lock().map(|lock| (signal, lock.pid));
+    }
+
+    /// Locks still waiting to be granted
+    pub fn waiting_count(&self) -> usize {
+        self.locks.iter().filter(|lock| !lock.granted).count()
+    }
+}
+
+/// Render the monitor centered over the panes
+pub fn render_lock_monitor(frame: &mut Frame, monitor: &LockMonitor) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(120);
+    let height = screen.height.saturating_sub(4).min(30);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let title = format!(
+        " Locks - {} - {} held, {} waiting{} ",
+        monitor.connection_name,
+        monitor.locks.len() - monitor.waiting_count(),
+        monitor.waiting_count(),
+        if monitor.in_flight { " ⟳" } else { "" }
+    );
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let header = Row::new(
+        [
+            "PID",
+            "Application",
+            "Lock Type",
+            "Relation",
+            "Granted",
+            "Blocked By",
+        ]
+        .map(|name| Cell::from(name).style(Style::default().add_modifier(Modifier::BOLD))),
+    )
+    .style(Style::default().fg(Color::Yellow));
+
+    let rows: Vec<Row> = monitor
+        .locks
+        .iter()
+        .map(|lock| {
+            let blocked_by: Vec<String> = lock
+                .blocking_pids
+                .iter()
+                .map(|pid| pid.to_string())
+                .collect();
+            let style = if lock.granted {
+                Style::default().fg(Color::White)
+            } else {
+                Style::default().fg(Color::Red)
+            };
+            Row::new(vec![
+                lock.pid.to_string(),
+                lock.application_name.clone(),
+                lock.lock_type.clone(),
+                lock.relation.clone().unwrap_or_default(),
+                if lock.granted { "yes" } else { "no" }.to_string(),
+                blocked_by.join(", "),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    if rows.is_empty() {
+        let message = match &monitor.error {
+            Some(e) => format!("Failed to load locks: {e}"),
+            None if monitor.in_flight => "Loading…".to_string(),
+            None => "No locks held by other sessions".to_string(),
+        };
+        frame.render_widget(
+            Paragraph::new(message).style(Style::default().fg(Color::DarkGray)),
+            chunks[0],
+        );
+    } else {
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(8),
+                Constraint::Percentage(20),
+                Constraint::Length(14),
+                Constraint::Percentage(30),
+                Constraint::Length(8),
+                Constraint::Min(10),
+            ],
+        )
+        .header(header)
+        .row_highlight_style(Style::default().bg(Color::DarkGray));
+        let mut table_state = TableState::default();
+        table_state.select(Some(monitor.selected));
+        frame.render_stateful_widget(table, chunks[0], &mut table_state);
+    }
+
+    let footer = match (monitor.pending, &monitor.error) {
+        (Some((signal, pid)), _) => Line::from(vec![
+            Span::styled(
+                format!("{}({pid})? ", signal.function_name()),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("y confirm, any other key cancels"),
+        ]),
+        (None, Some(e)) if !monitor.locks.is_empty() => Line::styled(
+            format!("Refresh failed: {e}"),
+            Style::default().fg(Color::Red),
+        ),
+        _ => Line::styled(
+            "↑/↓ select  k cancel backend  Ctrl+K terminate backend  r refresh  Esc close",
+            Style::default().fg(Color::DarkGray),
+        ),
+    };
+    frame.render_widget(Paragraph::new(footer), chunks[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lock(pid: i32, granted: bool) -> LockInfo {
+        LockInfo {
+            pid,
+            application_name: "psql".to_string(),
+            lock_type: "relation".to_string(),
+            relation: Some("orders".to_string()),
+            granted,
+            blocking_pids: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_refresh_keeps_selected_backend() {
+        let mut monitor = LockMonitor::new("id".to_string(), "prod".to_string());
+        let start = Instant::now();
+        assert!(monitor.is_due(start));
+        monitor.begin(start);
+        assert!(!monitor.is_due(start + LOCK_REFRESH_INTERVAL));
+
+        monitor.finish(Ok(vec![lock(10, false), lock(20, true), lock(30, true)]));
+        assert_eq!(monitor.waiting_count(), 1);
+        assert!(monitor.is_due(start + LOCK_REFRESH_INTERVAL));
+        monitor.select_next();
+        monitor.request(BackendSignal::Cancel);
+        assert_eq!(monitor.pending, Some((BackendSignal::Cancel, 20)));
+
+        monitor.finish(Ok(vec![lock(30, true), lock(20, true)]));
+        assert_eq!(monitor.selected_lock().map(|l| l.pid), Some(20));
+        monitor.finish(Ok(vec![lock(30, true)]));
+        assert_eq!(monitor.selected, 0);
+        monitor.finish(Err("connection reset".to_string()));
+        assert_eq!(monitor.locks.len(), 1);
+    }
+}
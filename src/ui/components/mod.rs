@@ -2,22 +2,84 @@
 
 #![forbid(unsafe_code)]
 
+pub mod bookmark_list;
+pub mod cell_editor;
+pub mod cell_view;
+pub mod command_palette;
+pub mod connection_import;
 pub mod connection_modal;
 pub mod connection_mode;
+pub mod connection_template_list;
+pub mod context_menu;
+pub mod copy_format;
+pub mod csv_import;
 pub mod debug_view;
+pub mod file_picker;
+pub mod hex_view;
+pub mod index_modal;
+pub mod json_view;
+pub mod lock_monitor;
+pub mod name_confirmation;
 pub mod query_editor;
+pub mod query_error;
+pub mod query_stats;
+pub mod query_vars;
+pub mod quick_switcher;
+pub mod rename_prompt;
+pub mod result_diff;
+pub mod routines_pane;
+pub mod snippet_picker;
+pub mod sql_highlight;
 pub mod sql_suggestions;
+pub mod status_bar;
 pub mod suggestion_popup;
+pub mod tab_watch;
 pub mod table_viewer;
 pub mod tables_pane;
+pub mod theme_gallery;
 pub mod toast;
+pub mod toast_history;
+pub mod undo_stack;
+pub mod unlock_prompt;
+pub mod workspace_browser;
 
+pub use bookmark_list::*;
+pub use cell_editor::*;
+pub use cell_view::*;
+pub use command_palette::*;
+pub use connection_import::*;
 pub use connection_modal::*;
 pub use connection_mode::*;
+pub use connection_template_list::*;
+pub use context_menu::*;
+pub use copy_format::*;
+pub use csv_import::*;
 pub use debug_view::*;
+pub use file_picker::*;
+pub use hex_view::*;
+pub use index_modal::*;
+pub use json_view::*;
+pub use lock_monitor::*;
+pub use name_confirmation::*;
 pub use query_editor::*;
+pub use query_error::*;
+pub use query_stats::*;
+pub use query_vars::*;
+pub use quick_switcher::*;
+pub use rename_prompt::*;
+pub use result_diff::*;
+pub use routines_pane::*;
+pub use snippet_picker::*;
+pub use sql_highlight::*;
 pub use sql_suggestions::*;
+pub use status_bar::*;
 pub use suggestion_popup::*;
+pub use tab_watch::*;
 pub use table_viewer::*;
 pub use tables_pane::*;
+pub use theme_gallery::*;
 pub use toast::*;
+pub use toast_history::*;
+pub use undo_stack::*;
+pub use unlock_prompt::*;
+pub use workspace_browser::*;
@@ -0,0 +1,118 @@
+// FilePath: src/ui/components/name_confirmation.rs
+
+#![forbid(unsafe_code)]
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Irreversible table action waiting on its name being typed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameConfirmationKind {
+    /// `TRUNCATE TABLE`
+    Truncate,
+    /// `DROP TABLE` (or view)
+    Drop,
+}
+
+/// Second step of truncating or dropping a table: OK only becomes active
+/// once the table name is typed exactly
+#[derive(Debug, Clone)]
+pub struct NameConfirmation {
+    pub table: String,
+    pub input: String,
+    pub kind: NameConfirmationKind,
+}
+
+impl NameConfirmation {
+    pub fn new(table: String, kind: NameConfirmationKind) -> Self {
+        Self {
+            table,
+            input: String::new(),
+            kind,
+        }
+    }
+
+    /// Whether the typed name matches, case and all
+    pub fn is_confirmed(&self) -> bool {
+        self.input == self.table
+    }
+}
+
+/// Render the prompt centered over everything else
+pub fn render_name_confirmation(frame: &mut Frame, confirmation: &NameConfirmation) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(60);
+    let height = 9.min(screen.height);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    let (title, consequence) = match confirmation.kind {
+        NameConfirmationKind::Truncate => (" Truncate Table ", " to delete all of its rows"),
+        NameConfirmationKind::Drop => (" Drop Table ", " to drop it for good"),
+    };
+
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(title)
+        .title_bottom(" Enter confirm  Esc cancel ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+
+    let ok_style = if confirmation.is_confirmed() {
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::Red)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let lines = vec![
+        Line::from(vec![
+            Span::raw("Type "),
+            Span::styled(
+                confirmation.table.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(consequence),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Table: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(confirmation.input.as_str()),
+            Span::styled("█", Style::default().fg(Color::Gray)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(" OK ", ok_style)).alignment(Alignment::Center),
+    ];
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_the_exact_name_confirms() {
+        let mut confirmation =
+            NameConfirmation::new("public.Orders".to_string(), NameConfirmationKind::Truncate);
+        assert!(!confirmation.is_confirmed());
+        confirmation.input = "public.orders".to_string();
+        assert!(!confirmation.is_confirmed());
+        confirmation.input = "public.Orders".to_string();
+        assert!(confirmation.is_confirmed());
+    }
+}
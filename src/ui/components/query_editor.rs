@@ -2,8 +2,9 @@
 
 #![forbid(unsafe_code)]
 
-use super::{SqlSuggestionEngine, SuggestionPopup};
+use super::{HighlightCache, SqlSuggestionEngine, SuggestionPopup, SyntaxStyles};
 use crate::database::DatabaseType;
+use crate::ui::theme::Theme;
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -12,11 +13,27 @@ use ratatui::{
     Frame,
 };
 use std::collections::HashMap;
-use syntect::{
-    easy::HighlightLines,
-    highlighting::ThemeSet,
-    parsing::{SyntaxReference, SyntaxSet},
-};
+use std::time::{Duration, Instant};
+
+/// How long a pending multi-key command (gg, dd, ...) waits for its second key
+const PENDING_COMMAND_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Visual mode selection granularity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisualMode {
+    /// `v` - characters from the anchor to the cursor
+    Char,
+    /// `V` - whole lines from the anchor to the cursor
+    Line,
+}
+
+/// Text held by the unnamed register
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Register {
+    pub text: String,
+    /// Yanked or deleted whole lines (`yy`, `dd`, `V`); pastes go below/above the line
+    pub linewise: bool,
+}
 
 #[derive(Debug)]
 pub struct QueryEditor {
@@ -27,8 +44,8 @@ pub struct QueryEditor {
     is_focused: bool,
     is_insert_mode: bool,
     database_type: Option<DatabaseType>,
-    syntax_set: SyntaxSet,
-    theme_set: ThemeSet,
+    /// Tokens per line, re-lexed from the edited line down
+    highlight_cache: HighlightCache,
     /// SQL suggestion engine
     suggestion_engine: SqlSuggestionEngine,
     /// Suggestion popup
@@ -45,10 +62,34 @@ pub struct QueryEditor {
     is_modified: bool,
     /// Pending vim command (for commands like 'dd', 'dw', etc.)
     pending_command: Option<String>,
+    /// When the pending command was started, for the timeout
+    pending_since: Option<Instant>,
     /// Whether in command mode (vim : commands)
     is_command_mode: bool,
     /// Command buffer for : commands
     command_buffer: String,
+    /// Active Visual mode, if any
+    visual_mode: Option<VisualMode>,
+    /// Line and column where the Visual selection started
+    visual_anchor: (usize, usize),
+    /// Unnamed register filled by yanks and deletes
+    register: Option<Register>,
+    /// `"+` was typed: the next yank, delete or paste uses the system clipboard
+    clipboard_register: bool,
+    /// Clipboard failure to report after a register operation
+    register_error: Option<String>,
+    /// Indent width in spaces (`editor.tab_size`); tabs are expanded
+    tab_size: usize,
+    /// Remaining cursor stops of an inserted snippet, as byte offsets into `content`
+    snippet_stops: Vec<usize>,
+    /// Line-number gutter (`editor.show_line_numbers`)
+    show_line_numbers: bool,
+    /// Number lines by distance from the cursor (`editor.relative_line_numbers`)
+    relative_line_numbers: bool,
+    /// Shade the cursor line (`editor.highlight_current_line`)
+    highlight_current_line: bool,
+    /// Count typed in Normal mode before `G`
+    count: Option<usize>,
 }
 
 impl Clone for QueryEditor {
@@ -61,8 +102,7 @@ impl Clone for QueryEditor {
             is_focused: self.is_focused,
             is_insert_mode: self.is_insert_mode,
             database_type: self.database_type.clone(),
-            syntax_set: SyntaxSet::load_defaults_newlines(),
-            theme_set: ThemeSet::load_defaults(),
+            highlight_cache: self.highlight_cache.clone(),
             suggestion_engine: SqlSuggestionEngine::new(),
             suggestion_popup: SuggestionPopup::new(),
             suggestions_active: false,
@@ -71,8 +111,20 @@ impl Clone for QueryEditor {
             current_file: self.current_file.clone(),
             is_modified: self.is_modified,
             pending_command: None,
+            pending_since: None,
             is_command_mode: false,
             command_buffer: String::new(),
+            visual_mode: None,
+            visual_anchor: (0, 0),
+            register: self.register.clone(),
+            clipboard_register: false,
+            register_error: None,
+            tab_size: self.tab_size,
+            snippet_stops: self.snippet_stops.clone(),
+            show_line_numbers: self.show_line_numbers,
+            relative_line_numbers: self.relative_line_numbers,
+            highlight_current_line: self.highlight_current_line,
+            count: None,
         }
     }
 }
@@ -93,8 +145,7 @@ impl QueryEditor {
             is_focused: false,
             is_insert_mode: false,
             database_type: None,
-            syntax_set: SyntaxSet::load_defaults_newlines(),
-            theme_set: ThemeSet::load_defaults(),
+            highlight_cache: HighlightCache::default(),
             suggestion_engine: SqlSuggestionEngine::new(),
             suggestion_popup: SuggestionPopup::new(),
             suggestions_active: false,
@@ -103,8 +154,85 @@ impl QueryEditor {
             current_file: None,
             is_modified: false,
             pending_command: None,
+            pending_since: None,
             is_command_mode: false,
             command_buffer: String::new(),
+            visual_mode: None,
+            visual_anchor: (0, 0),
+            register: None,
+            clipboard_register: false,
+            register_error: None,
+            tab_size: 4,
+            snippet_stops: Vec::new(),
+            show_line_numbers: true,
+            relative_line_numbers: false,
+            highlight_current_line: true,
+            count: None,
+        }
+    }
+
+    /// Set the indent width used for Tab and auto-indent
+    pub fn set_tab_size(&mut self, tab_size: usize) {
+        self.tab_size = tab_size.max(1);
+    }
+
+    /// Show or hide the line-number gutter, numbering absolutely or by
+    /// distance from the cursor
+    pub fn set_line_numbers(&mut self, show: bool, relative: bool) {
+        self.show_line_numbers = show;
+        self.relative_line_numbers = relative;
+    }
+
+    pub fn set_highlight_current_line(&mut self, highlight: bool) {
+        self.highlight_current_line = highlight;
+    }
+
+    /// Add a digit to the Normal mode count
+    pub fn push_count_digit(&mut self, digit: u32) {
+        let count = self.count.unwrap_or(0);
+        self.count = Some(count.saturating_mul(10).saturating_add(digit as usize));
+    }
+
+    /// Whether a count is being typed, so `0` extends it rather than moving
+    pub fn has_count(&self) -> bool {
+        self.count.is_some()
+    }
+
+    /// Take the typed count, clearing it
+    pub fn take_count(&mut self) -> Option<usize> {
+        self.count.take()
+    }
+
+    /// Move to the first non-blank of 1-based `line`, clamped to the content
+    pub fn go_to_line(&mut self, line: usize) {
+        let index = line.saturating_sub(1);
+        let indent = self
+            .content
+            .lines()
+            .nth(index)
+            .map_or(0, |text| text.len() - text.trim_start().len());
+        self.set_cursor_position(index, indent);
+    }
+
+    /// Columns taken by the line-number gutter, separator included
+    fn gutter_width(&self) -> usize {
+        if !self.show_line_numbers {
+            return 0;
+        }
+        let total_lines = self.content.lines().count();
+        total_lines.to_string().len().max(3) + 3 // +3 for " │ "
+    }
+
+    /// Gutter text for a line: its number, or its distance from the cursor
+    /// in relative mode, where the cursor line keeps its own number
+    fn gutter_text(&self, line_index: usize) -> String {
+        let width = self.gutter_width() - 3;
+        if !self.relative_line_numbers {
+            format!("{:>width$} │ ", line_index + 1)
+        } else if line_index == self.cursor_line {
+            format!("{:<width$} │ ", line_index + 1)
+        } else {
+            format!("{:>width$} │ ", line_index.abs_diff(self.cursor_line))
         }
     }
 
@@ -114,6 +242,8 @@ impl QueryEditor {
         self.cursor_col = 0;
         self.scroll_offset = 0;
         self.is_modified = false;
+        self.visual_mode = None;
+        self.snippet_stops.clear();
         self.hide_suggestions();
     }
 
@@ -126,9 +256,12 @@ impl QueryEditor {
         self.is_modified = false;
         self.is_insert_mode = false;
         self.current_file = None;
-        self.pending_command = None;
+        self.cancel_pending_command();
+        self.count = None;
         self.is_command_mode = false;
         self.command_buffer.clear();
+        self.visual_mode = None;
+        self.snippet_stops.clear();
         self.hide_suggestions();
     }
 
@@ -159,6 +292,10 @@ impl QueryEditor {
 
     pub fn set_insert_mode(&mut self, insert_mode: bool) {
         self.is_insert_mode = insert_mode;
+        // Leaving Insert mode abandons the remaining snippet stops
+        if !insert_mode {
+            self.snippet_stops.clear();
+        }
     }
 
     pub fn is_insert_mode(&self) -> bool {
@@ -282,45 +419,62 @@ impl QueryEditor {
         }
     }
 
-    /// Move to previous word (b key)
-    pub fn move_to_prev_word(&mut self) {
-        let lines = self.content.lines().collect::<Vec<_>>();
+    /// Move to the start of the previous word (b key). Like Vim, runs of
+    /// word characters and runs of punctuation are separate words, and an
+    /// empty line counts as a word.
+    pub fn move_to_previous_word(&mut self) {
+        let lines: Vec<Vec<char>> = self.content.lines().map(|l| l.chars().collect()).collect();
         if self.cursor_line >= lines.len() {
             return;
         }
 
-        // If at beginning of line, move to end of previous line
-        if self.cursor_col == 0 {
-            if self.cursor_line > 0 {
-                self.cursor_line -= 1;
-                self.cursor_col = lines[self.cursor_line].len();
-                self.adjust_scroll();
-                // Move to beginning of last word on previous line
-                self.move_to_word_beginning();
+        let mut line = self.cursor_line;
+        let mut col = self.cursor_col.min(lines[line].len());
+
+        // Step back one position, treating each line break as whitespace
+        let step_back = |line: &mut usize, col: &mut usize| -> bool {
+            if *col > 0 {
+                *col -= 1;
+                true
+            } else if *line > 0 {
+                *line -= 1;
+                *col = lines[*line].len();
+                true
+            } else {
+                false
             }
+        };
+
+        if !step_back(&mut line, &mut col) {
             return;
         }
 
-        let current_line = lines[self.cursor_line];
-        let chars: Vec<char> = current_line.chars().collect();
-
-        // Move back to find beginning of current or previous word
-        if self.cursor_col > 0 {
-            self.cursor_col -= 1;
+        // Skip whitespace and line breaks, stopping on an empty line
+        loop {
+            let chars = &lines[line];
+            if chars.is_empty() {
+                break;
+            }
+            if col < chars.len() && !chars[col].is_whitespace() {
+                break;
+            }
+            if !step_back(&mut line, &mut col) {
+                break;
+            }
         }
 
-        // Skip whitespace backwards
-        while self.cursor_col > 0
-            && self.cursor_col < chars.len()
-            && chars[self.cursor_col].is_whitespace()
-        {
-            self.cursor_col -= 1;
+        // Walk back to the first character of the same class
+        let chars = &lines[line];
+        if col < chars.len() {
+            let class = word_class(chars[col]);
+            while col > 0 && word_class(chars[col - 1]) == class {
+                col -= 1;
+            }
         }
 
-        // Skip word backwards to find its beginning
-        while self.cursor_col > 0 && !chars[self.cursor_col - 1].is_whitespace() {
-            self.cursor_col -= 1;
-        }
+        self.cursor_line = line;
+        self.cursor_col = col;
+        self.adjust_scroll();
     }
 
     /// Move to end of current word (e key)
@@ -432,7 +586,8 @@ impl QueryEditor {
 
         // Remove current line
         if self.cursor_line < new_lines.len() {
-            new_lines.remove(self.cursor_line);
+            let removed = new_lines.remove(self.cursor_line);
+            self.store_register(format!("{removed}\n"), true);
         }
 
         // Adjust cursor position
@@ -463,33 +618,35 @@ impl QueryEditor {
         if self.cursor_line < new_lines.len() {
             let line = &mut new_lines[self.cursor_line];
             if self.cursor_col < line.len() {
-                line.truncate(self.cursor_col);
+                let removed = line.split_off(self.cursor_col);
                 self.is_modified = true;
+                self.store_register(removed, false);
             }
         }
 
         self.content = new_lines.join("\n");
     }
 
-    /// Delete word under cursor (dw)
+    /// Delete to the start of the next word (dw). Like the `w` motion, a run
+    /// of word characters and a run of punctuation are separate words; the
+    /// blanks after the word go with it.
     pub fn delete_word(&mut self) {
         if self.is_insert_mode {
             return;
         }
 
-        let lines: Vec<String> = self.content.lines().map(|s| s.to_string()).collect();
-        let mut new_lines = lines;
+        let mut lines: Vec<String> = self.content.lines().map(|s| s.to_string()).collect();
 
-        if self.cursor_line < new_lines.len() {
-            let line = &mut new_lines[self.cursor_line];
+        if let Some(line) = lines.get_mut(self.cursor_line) {
             let mut chars: Vec<char> = line.chars().collect();
 
             if self.cursor_col < chars.len() {
                 let start_col = self.cursor_col;
+                let class = word_class(chars[start_col]);
                 let mut end_col = start_col;
 
-                // Find end of word
-                while end_col < chars.len() && !chars[end_col].is_whitespace() {
+                // Skip the rest of the word (or the blank run the cursor is on)
+                while end_col < chars.len() && word_class(chars[end_col]) == class {
                     end_col += 1;
                 }
 
@@ -498,19 +655,20 @@ impl QueryEditor {
                     end_col += 1;
                 }
 
-                // Remove the word
-                chars.drain(start_col..end_col);
+                let removed: String = chars.drain(start_col..end_col).collect();
                 *line = chars.into_iter().collect();
                 self.is_modified = true;
+                self.store_register(removed, false);
 
-                // Adjust cursor
-                if self.cursor_col > line.len() {
-                    self.cursor_col = line.len();
+                // Keep the cursor on the line's last character
+                let len = line.chars().count();
+                if self.cursor_col >= len {
+                    self.cursor_col = len.saturating_sub(1);
                 }
             }
         }
 
-        self.content = new_lines.join("\n");
+        self.content = lines.join("\n");
     }
 
     /// Delete character under cursor (x key)
@@ -528,9 +686,10 @@ impl QueryEditor {
 
             // Delete character at cursor position
             if self.cursor_col < chars.len() {
-                chars.remove(self.cursor_col);
+                let removed = chars.remove(self.cursor_col);
                 *line = chars.into_iter().collect();
                 self.is_modified = true;
+                self.store_register(removed.to_string(), false);
 
                 // Adjust cursor if it's now at the end of line
                 if self.cursor_col > line.len() {
@@ -557,44 +716,366 @@ impl QueryEditor {
 
             // Delete character before cursor position (if cursor is not at beginning)
             if self.cursor_col > 0 {
-                chars.remove(self.cursor_col - 1);
+                let removed = chars.remove(self.cursor_col - 1);
                 *line = chars.into_iter().collect();
                 self.cursor_col -= 1; // Move cursor back by one position
                 self.is_modified = true;
+                self.store_register(removed.to_string(), false);
             }
         }
 
         self.content = new_lines.join("\n");
     }
 
-    /// Helper method to skip whitespace forward
-    fn skip_whitespace_forward(&mut self) {
-        let lines = self.content.lines().collect::<Vec<_>>();
-        if self.cursor_line < lines.len() {
-            let chars: Vec<char> = lines[self.cursor_line].chars().collect();
-            while self.cursor_col < chars.len() && chars[self.cursor_col].is_whitespace() {
-                self.cursor_col += 1;
+    // Visual mode
+
+    /// Start a Visual selection anchored at the cursor
+    pub fn enter_visual_mode(&mut self, mode: VisualMode) {
+        self.cancel_pending_command();
+        self.visual_mode = Some(mode);
+        self.visual_anchor = (self.cursor_line, self.cursor_col);
+    }
+
+    /// `v`/`V`: enter that Visual mode, switch to it, or leave it if already active
+    pub fn toggle_visual_mode(&mut self, mode: VisualMode) {
+        match self.visual_mode {
+            None => self.enter_visual_mode(mode),
+            Some(current) if current == mode => self.exit_visual_mode(),
+            Some(_) => self.visual_mode = Some(mode),
+        }
+    }
+
+    /// Leave Visual mode without changing the buffer
+    pub fn exit_visual_mode(&mut self) {
+        self.visual_mode = None;
+    }
+
+    pub fn visual_mode(&self) -> Option<VisualMode> {
+        self.visual_mode
+    }
+
+    /// Ordered selection bounds as `(line, col)` pairs; the end is inclusive
+    fn selection_bounds(&self) -> Option<((usize, usize), (usize, usize))> {
+        self.visual_mode?;
+        let cursor = (self.cursor_line, self.cursor_col);
+        Some(if self.visual_anchor <= cursor {
+            (self.visual_anchor, cursor)
+        } else {
+            (cursor, self.visual_anchor)
+        })
+    }
+
+    /// Selected character columns of one line, end exclusive
+    fn selection_columns(&self, line_index: usize, line_len: usize) -> Option<(usize, usize)> {
+        let ((start_line, start_col), (end_line, end_col)) = self.selection_bounds()?;
+        if line_index < start_line || line_index > end_line {
+            return None;
+        }
+        if self.visual_mode == Some(VisualMode::Line) {
+            return Some((0, line_len));
+        }
+        let start = if line_index == start_line {
+            start_col
+        } else {
+            0
+        };
+        let end = if line_index == end_line {
+            end_col + 1
+        } else {
+            line_len
+        };
+        Some((start.min(line_len), end.min(line_len)))
+    }
+
+    /// Text covered by the Visual selection; linewise selections end with a newline
+    pub fn selected_text(&self) -> Option<String> {
+        let ((start_line, _), (end_line, _)) = self.selection_bounds()?;
+        let lines: Vec<&str> = self.content.lines().collect();
+        if start_line >= lines.len() {
+            return None;
+        }
+        let end_line = end_line.min(lines.len() - 1);
+
+        let parts: Vec<String> = (start_line..=end_line)
+            .map(|index| {
+                let chars: Vec<char> = lines[index].chars().collect();
+                let (start, end) = self.selection_columns(index, chars.len()).unwrap_or((0, 0));
+                chars[start..end.max(start)].iter().collect()
+            })
+            .collect();
+
+        let mut text = parts.join("\n");
+        if self.visual_mode == Some(VisualMode::Line) {
+            text.push('\n');
+        }
+        Some(text)
+    }
+
+    /// First line of the Visual selection
+    pub fn selection_start_line(&self) -> Option<usize> {
+        self.selection_bounds().map(|((line, _), _)| line)
+    }
+
+    /// Yank the Visual selection into the register and return to Normal mode
+    /// with the cursor at the start of the selection
+    pub fn yank_selection(&mut self) -> Option<String> {
+        let text = self.selected_text()?;
+        let linewise = self.visual_mode == Some(VisualMode::Line);
+        if let Some(((line, col), _)) = self.selection_bounds() {
+            self.cursor_line = line;
+            self.cursor_col = if self.visual_mode == Some(VisualMode::Line) {
+                0
+            } else {
+                col
+            };
+            self.adjust_scroll();
+        }
+        self.visual_mode = None;
+        self.store_register(text.clone(), linewise);
+        Some(text)
+    }
+
+    /// Delete the Visual selection and return to Normal mode, returning the removed text
+    pub fn delete_selection(&mut self) -> Option<String> {
+        let text = self.selected_text()?;
+        let ((start_line, start_col), (end_line, end_col)) = self.selection_bounds()?;
+        let mut lines: Vec<Vec<char>> = self.content.lines().map(|l| l.chars().collect()).collect();
+        let end_line = end_line.min(lines.len() - 1);
+        self.store_register(text.clone(), self.visual_mode == Some(VisualMode::Line));
+
+        if self.visual_mode == Some(VisualMode::Line) {
+            lines.drain(start_line..=end_line);
+            self.cursor_line = start_line.min(lines.len().saturating_sub(1));
+            self.cursor_col = 0;
+        } else {
+            let tail_start = (end_col + 1).min(lines[end_line].len());
+            let tail: Vec<char> = lines[end_line][tail_start..].to_vec();
+            let start_col = start_col.min(lines[start_line].len());
+            lines[start_line].truncate(start_col);
+            lines[start_line].extend(tail);
+            lines.drain(start_line + 1..=end_line);
+            self.cursor_line = start_line;
+            self.cursor_col = start_col;
+        }
+
+        self.content = lines
+            .iter()
+            .map(|line| line.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.visual_mode = None;
+        self.is_modified = true;
+        self.adjust_cursor_column();
+        self.adjust_scroll();
+        Some(text)
+    }
+
+    // Snippets
+
+    /// Byte offset of the cursor in `content`
+    fn cursor_offset(&self) -> usize {
+        let mut offset = 0;
+        for (index, line) in self.content.split('\n').enumerate() {
+            if index == self.cursor_line {
+                return offset + self.cursor_col.min(line.len());
             }
+            offset += line.len() + 1;
         }
+        self.content.len()
     }
 
-    /// Helper method to move to beginning of word
-    fn move_to_word_beginning(&mut self) {
-        let lines = self.content.lines().collect::<Vec<_>>();
-        if self.cursor_line < lines.len() {
-            let chars: Vec<char> = lines[self.cursor_line].chars().collect();
+    /// Line and column of a byte offset in `content`
+    fn position_of(&self, offset: usize) -> (usize, usize) {
+        let before = &self.content[..offset.min(self.content.len())];
+        let line = before.matches('\n').count();
+        let col = before.len() - before.rfind('\n').map_or(0, |i| i + 1);
+        (line, col)
+    }
 
-            // Skip whitespace backwards
-            while self.cursor_col > 0
-                && self.cursor_col <= chars.len()
-                && (self.cursor_col == chars.len() || chars[self.cursor_col].is_whitespace())
-            {
-                self.cursor_col -= 1;
+    /// Cursor offset and content length before an Insert mode edit
+    fn edit_mark(&self) -> (usize, usize) {
+        (self.cursor_offset(), self.content.len())
+    }
+
+    /// Move snippet stops at or after an edit by the change in length
+    fn shift_snippet_stops(&mut self, (offset, old_len): (usize, usize)) {
+        let new_len = self.content.len();
+        for stop in self
+            .snippet_stops
+            .iter_mut()
+            .filter(|stop| **stop >= offset)
+        {
+            *stop = (*stop + new_len).saturating_sub(old_len);
+        }
+    }
+
+    /// Insert a snippet body at the cursor. With `$1`, `$2`, ... stops the
+    /// cursor goes to the first in Insert mode and Tab visits the rest.
+    pub fn insert_snippet(&mut self, body: &str) {
+        let (text, stops) = crate::state::snippets::expand_placeholders(body);
+        let offset = self.cursor_offset();
+        self.content.insert_str(offset, &text);
+        self.visual_mode = None;
+        self.is_modified = true;
+        self.hide_suggestions();
+
+        let mut stops = stops.into_iter().map(|stop| offset + stop);
+        match stops.next() {
+            Some(first) => {
+                (self.cursor_line, self.cursor_col) = self.position_of(first);
+                self.is_insert_mode = true;
+                self.snippet_stops = stops.collect();
+            }
+            None => {
+                (self.cursor_line, self.cursor_col) = self.position_of(offset + text.len());
+                self.snippet_stops.clear();
+            }
+        }
+        self.adjust_scroll();
+    }
+
+    /// Whether Tab still has snippet stops to visit
+    pub fn has_snippet_stops(&self) -> bool {
+        !self.snippet_stops.is_empty()
+    }
+
+    /// Tab: move the cursor to the next snippet stop
+    pub fn jump_to_next_snippet_stop(&mut self) -> bool {
+        if self.snippet_stops.is_empty() {
+            return false;
+        }
+        let stop = self.snippet_stops.remove(0);
+        (self.cursor_line, self.cursor_col) = self.position_of(stop);
+        self.hide_suggestions();
+        self.adjust_scroll();
+        true
+    }
+
+    // Registers
+
+    /// Put yanked or deleted text in the unnamed register, or on the system
+    /// clipboard when the command was prefixed with `"+`
+    fn store_register(&mut self, text: String, linewise: bool) {
+        if std::mem::take(&mut self.clipboard_register) {
+            let result = arboard::Clipboard::new()
+                .and_then(|mut clipboard| clipboard.set_text(text))
+                .map_err(|e| format!("Failed to copy to clipboard: {e}"));
+            self.register_error = result.err();
+        } else {
+            self.register = Some(Register { text, linewise });
+        }
+    }
+
+    /// Register contents for a paste: the unnamed register, or the system
+    /// clipboard after `"+` (text ending in a newline pastes linewise)
+    fn read_register(&mut self) -> Option<Register> {
+        if std::mem::take(&mut self.clipboard_register) {
+            match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.get_text()) {
+                Ok(text) => {
+                    let linewise = text.ends_with('\n');
+                    Some(Register { text, linewise })
+                }
+                Err(e) => {
+                    self.register_error = Some(format!("Failed to read clipboard: {e}"));
+                    None
+                }
+            }
+        } else {
+            self.register.clone()
+        }
+    }
+
+    pub fn register(&self) -> Option<&Register> {
+        self.register.as_ref()
+    }
+
+    /// Clipboard failure from the last register operation, if any
+    pub fn take_register_error(&mut self) -> Option<String> {
+        self.register_error.take()
+    }
+
+    /// Yank the current line (yy)
+    pub fn yank_line(&mut self) {
+        if let Some(line) = self.content.lines().nth(self.cursor_line) {
+            let text = format!("{line}\n");
+            self.store_register(text, true);
+        }
+    }
+
+    /// Paste the register after the cursor (p) or before it (P). Linewise
+    /// text goes below/above the current line.
+    pub fn paste(&mut self, after: bool) {
+        if self.is_insert_mode {
+            return;
+        }
+        let Some(register) = self.read_register() else {
+            return;
+        };
+        if register.text.is_empty() {
+            return;
+        }
+
+        let mut lines: Vec<String> = self.content.lines().map(|s| s.to_string()).collect();
+        if register.linewise {
+            let index = if lines.is_empty() {
+                0
+            } else if after {
+                self.cursor_line + 1
+            } else {
+                self.cursor_line
+            };
+            let index = index.min(lines.len());
+            let pasted: Vec<String> = register
+                .text
+                .strip_suffix('\n')
+                .unwrap_or(&register.text)
+                .split('\n')
+                .map(|s| s.to_string())
+                .collect();
+            lines.splice(index..index, pasted);
+            self.cursor_line = index;
+            self.cursor_col = 0;
+        } else {
+            if lines.is_empty() {
+                lines.push(String::new());
             }
+            self.cursor_line = self.cursor_line.min(lines.len() - 1);
+            let line: Vec<char> = lines[self.cursor_line].chars().collect();
+            let col = if after {
+                (self.cursor_col + 1).min(line.len())
+            } else {
+                self.cursor_col.min(line.len())
+            };
+            let before: String = line[..col].iter().collect();
+            let rest: String = line[col..].iter().collect();
+            let joined = format!("{before}{}{rest}", register.text);
+            let pasted: Vec<String> = joined.split('\n').map(|s| s.to_string()).collect();
+
+            // Leave the cursor on the last pasted character
+            let last_line = self.cursor_line + pasted.len() - 1;
+            let last_segment = register.text.rsplit('\n').next().unwrap_or("");
+            let last_col = if pasted.len() == 1 {
+                col + register.text.chars().count()
+            } else {
+                last_segment.chars().count()
+            };
+            lines.splice(self.cursor_line..=self.cursor_line, pasted);
+            self.cursor_line = last_line;
+            self.cursor_col = last_col.saturating_sub(1);
+        }
 
-            // Move to beginning of word
-            while self.cursor_col > 0 && !chars[self.cursor_col - 1].is_whitespace() {
-                self.cursor_col -= 1;
+        self.content = lines.join("\n");
+        self.is_modified = true;
+        self.adjust_scroll();
+    }
+
+    /// Helper method to skip whitespace forward
+    fn skip_whitespace_forward(&mut self) {
+        let lines = self.content.lines().collect::<Vec<_>>();
+        if self.cursor_line < lines.len() {
+            let chars: Vec<char> = lines[self.cursor_line].chars().collect();
+            while self.cursor_col < chars.len() && chars[self.cursor_col].is_whitespace() {
+                self.cursor_col += 1;
             }
         }
     }
@@ -604,6 +1085,16 @@ impl QueryEditor {
             return;
         }
 
+        // Tabs expand to spaces up to the next tab stop
+        if ch == '\t' {
+            let spaces = self.tab_size - self.cursor_col % self.tab_size;
+            for _ in 0..spaces {
+                self.insert_char(' ');
+            }
+            return;
+        }
+
+        let edit = self.edit_mark();
         let lines: Vec<String> = self.content.lines().map(|s| s.to_string()).collect();
         let mut new_lines = lines;
 
@@ -625,6 +1116,7 @@ impl QueryEditor {
         self.is_modified = true;
 
         self.content = new_lines.join("\n");
+        self.shift_snippet_stops(edit);
 
         // Trigger suggestions after character insertion
         self.update_suggestions();
@@ -634,6 +1126,7 @@ impl QueryEditor {
         if !self.is_insert_mode {
             return;
         }
+        let edit = self.edit_mark();
 
         let lines: Vec<String> = self.content.lines().map(|s| s.to_string()).collect();
         let mut new_lines = lines;
@@ -649,15 +1142,18 @@ impl QueryEditor {
         let line = new_lines[self.cursor_line].clone();
         let (before, after) = line.split_at(self.cursor_col.min(line.len()));
 
+        // Carry the current line's indentation onto the new line
+        let indent = leading_indent(before, self.tab_size);
         new_lines[self.cursor_line] = before.to_string();
-        new_lines.insert(self.cursor_line + 1, after.to_string());
+        new_lines.insert(self.cursor_line + 1, format!("{indent}{after}"));
 
         self.cursor_line += 1;
-        self.cursor_col = 0;
+        self.cursor_col = indent.len();
         self.is_modified = true;
         self.adjust_scroll();
 
         self.content = new_lines.join("\n");
+        self.shift_snippet_stops(edit);
         self.hide_suggestions();
     }
 
@@ -665,6 +1161,7 @@ impl QueryEditor {
         if !self.is_insert_mode {
             return;
         }
+        let edit = self.edit_mark();
 
         if self.cursor_col > 0 {
             let lines: Vec<String> = self.content.lines().map(|s| s.to_string()).collect();
@@ -695,10 +1192,92 @@ impl QueryEditor {
             self.adjust_scroll();
             self.content = new_lines.join("\n");
         }
+        self.shift_snippet_stops(edit);
+
+        // Refresh suggestions for the shortened word
+        self.update_suggestions();
+    }
+
+    /// Position of the bracket pairing with the `(` or `)` under the cursor.
+    /// Brackets inside quoted strings are ignored.
+    pub fn matching_bracket(&self) -> Option<(usize, usize)> {
+        let mut stack = Vec::new();
+        let mut quote = None;
+        for (line_index, line) in self.content.lines().enumerate() {
+            for (col, ch) in line.chars().enumerate() {
+                match (quote, ch) {
+                    (Some(open), c) if c == open => quote = None,
+                    (Some(_), _) => {}
+                    (None, '\'' | '"') => quote = Some(ch),
+                    (None, '(') => stack.push((line_index, col)),
+                    (None, ')') => {
+                        let Some(open) = stack.pop() else {
+                            continue;
+                        };
+                        let close = (line_index, col);
+                        let cursor = (self.cursor_line, self.cursor_col);
+                        if cursor == open {
+                            return Some(close);
+                        }
+                        if cursor == close {
+                            return Some(open);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        None
     }
 
     pub fn get_statement_at_cursor(&self) -> Option<String> {
         let lines: Vec<&str> = self.content.lines().collect();
+        let (start_line, end_line) = self.statement_lines_at_cursor(&lines)?;
+
+        let statement_lines: Vec<&str> = lines[start_line..=end_line].to_vec();
+        let statement = statement_lines.join("\n").trim().to_string();
+
+        if statement.is_empty() {
+            None
+        } else {
+            Some(statement)
+        }
+    }
+
+    /// Line and column where the statement at the cursor starts, matching
+    /// the trimmed text `get_statement_at_cursor` returns
+    pub fn statement_start_at_cursor(&self) -> Option<(usize, usize)> {
+        let lines: Vec<&str> = self.content.lines().collect();
+        let (start_line, end_line) = self.statement_lines_at_cursor(&lines)?;
+        (start_line..=end_line).find_map(|line| {
+            let text = lines[line];
+            let indent = text.len() - text.trim_start().len();
+            (indent < text.len()).then_some((line, indent))
+        })
+    }
+
+    /// Blank-line-delimited block around the cursor (vim's `ip`) and the
+    /// line it starts on
+    pub fn paragraph_at_cursor(&self) -> Option<(String, usize)> {
+        let lines: Vec<&str> = self.content.lines().collect();
+        if self.cursor_line >= lines.len() || lines[self.cursor_line].trim().is_empty() {
+            return None;
+        }
+
+        let mut start_line = self.cursor_line;
+        while start_line > 0 && !lines[start_line - 1].trim().is_empty() {
+            start_line -= 1;
+        }
+        let mut end_line = self.cursor_line;
+        while end_line + 1 < lines.len() && !lines[end_line + 1].trim().is_empty() {
+            end_line += 1;
+        }
+
+        Some((lines[start_line..=end_line].join("\n"), start_line))
+    }
+
+    /// First and last line of the `;`- or blank-line-delimited statement
+    fn statement_lines_at_cursor(&self, lines: &[&str]) -> Option<(usize, usize)> {
         if lines.is_empty() || self.cursor_line >= lines.len() {
             return None;
         }
@@ -722,14 +1301,16 @@ impl QueryEditor {
             end_line += 1;
         }
 
-        let statement_lines: Vec<&str> = lines[start_line..=end_line].to_vec();
-        let statement = statement_lines.join("\n").trim().to_string();
+        Some((start_line, end_line))
+    }
 
-        if statement.is_empty() {
-            None
-        } else {
-            Some(statement)
-        }
+    /// Move the cursor, clamped to the content
+    pub fn set_cursor_position(&mut self, line: usize, col: usize) {
+        let line_count = self.content.lines().count().max(1);
+        self.cursor_line = line.min(line_count - 1);
+        self.cursor_col = col;
+        self.adjust_cursor_column();
+        self.adjust_scroll();
     }
 
     fn adjust_cursor_column(&mut self) {
@@ -749,24 +1330,6 @@ impl QueryEditor {
         }
     }
 
-    fn get_syntax(&self) -> &SyntaxReference {
-        match self.database_type {
-            Some(DatabaseType::MySQL) => self
-                .syntax_set
-                .find_syntax_by_extension("sql")
-                .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text()),
-            Some(DatabaseType::PostgreSQL) => self
-                .syntax_set
-                .find_syntax_by_extension("sql")
-                .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text()),
-            Some(DatabaseType::SQLite) => self
-                .syntax_set
-                .find_syntax_by_extension("sql")
-                .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text()),
-            _ => self.syntax_set.find_syntax_plain_text(),
-        }
-    }
-
     // Suggestion-related methods
 
     /// Set available tables for suggestions
@@ -856,7 +1419,9 @@ impl QueryEditor {
             let insert_text = suggestion.text.clone();
 
             // Replace the partial word with the suggestion
+            let edit = self.edit_mark();
             self.replace_word_at_cursor(&partial_word, &insert_text);
+            self.shift_snippet_stops(edit);
 
             self.hide_suggestions();
         }
@@ -931,29 +1496,46 @@ impl QueryEditor {
 
     /// Handle vim command input
     pub fn handle_vim_command(&mut self, ch: char) -> bool {
+        // A pending command expires if the second key comes too late
+        if self
+            .pending_since
+            .is_some_and(|since| since.elapsed() > PENDING_COMMAND_TIMEOUT)
+        {
+            self.cancel_pending_command();
+        }
+
         // If we have a pending command, try to complete it
-        if let Some(ref pending) = self.pending_command.clone() {
+        if let Some(pending) = self.pending_command.take() {
+            self.pending_since = None;
             let full_command = format!("{}{}", pending, ch);
 
             match full_command.as_str() {
                 "dd" => {
                     self.delete_current_line();
-                    self.pending_command = None;
                     return true;
                 }
                 "dw" => {
                     self.delete_word();
-                    self.pending_command = None;
                     return true;
                 }
                 "d$" => {
                     self.delete_to_line_end();
-                    self.pending_command = None;
+                    return true;
+                }
+                "gg" => {
+                    self.move_to_file_start();
+                    return true;
+                }
+                "yy" => {
+                    self.yank_line();
+                    return true;
+                }
+                "\"+" | "\"*" => {
+                    self.clipboard_register = true;
                     return true;
                 }
                 _ => {
-                    // Invalid command, clear pending
-                    self.pending_command = None;
+                    // Invalid command, pending was cleared
                     return false;
                 }
             }
@@ -961,8 +1543,13 @@ impl QueryEditor {
 
         // Start new command
         match ch {
-            'd' => {
-                self.pending_command = Some("d".to_string());
+            'd' | 'g' | 'y' | '"' => {
+                self.pending_command = Some(ch.to_string());
+                self.pending_since = Some(Instant::now());
+                true
+            }
+            'p' | 'P' => {
+                self.paste(ch == 'p');
                 true
             }
             'x' => {
@@ -979,9 +1566,11 @@ impl QueryEditor {
         }
     }
 
-    /// Cancel any pending vim command
+    /// Cancel any pending vim command, including a `"+` register prefix
     pub fn cancel_pending_command(&mut self) {
         self.pending_command = None;
+        self.pending_since = None;
+        self.clipboard_register = false;
     }
 
     /// Check if there's a pending vim command
@@ -989,6 +1578,21 @@ impl QueryEditor {
         self.pending_command.is_some()
     }
 
+    /// Keys typed towards an unfinished command (`d`, `g`, `"`), or `"+`
+    /// while the clipboard register is armed; `None` once it times out
+    pub fn pending_keys(&self) -> Option<String> {
+        let pending = self.pending_command.clone().filter(|_| {
+            self.pending_since
+                .is_some_and(|since| since.elapsed() <= PENDING_COMMAND_TIMEOUT)
+        });
+        match (pending, self.clipboard_register) {
+            (Some(keys), true) => Some(format!("\"+{keys}")),
+            (Some(keys), false) => Some(keys),
+            (None, true) => Some("\"+".to_string()),
+            (None, false) => None,
+        }
+    }
+
     /// Get the current pending command for display
     pub fn get_pending_command(&self) -> Option<&String> {
         self.pending_command.as_ref()
@@ -1031,77 +1635,78 @@ impl QueryEditor {
         &self.command_buffer
     }
 
-    fn apply_syntax_highlighting_with_line_numbers(&self, text: &str) -> Text<'static> {
-        let syntax = self.get_syntax();
-        let theme = &self.theme_set.themes["base16-ocean.dark"];
-
-        let mut highlighter = HighlightLines::new(syntax, theme);
+    /// Lines with line numbers, colored from the token cache (updated by `render`)
+    fn apply_syntax_highlighting_with_line_numbers(
+        &self,
+        text: &str,
+        styles: &SyntaxStyles,
+        theme: &Theme,
+    ) -> Text<'static> {
         let mut styled_lines = Vec::new();
         let lines: Vec<&str> = text.lines().collect();
-        let total_lines = lines.len();
-        let line_number_width = format!("{}", total_lines).len().max(3); // At least 3 digits
 
-        for (line_index, line_content) in lines.iter().enumerate() {
-            let line_number = line_index + 1;
+        // The bracket under the cursor and its partner
+        let brackets: Vec<(usize, usize)> = self
+            .matching_bracket()
+            .map(|partner| vec![(self.cursor_line, self.cursor_col), partner])
+            .unwrap_or_default();
 
-            // Create line number span with proper formatting
-            let line_number_text = format!("{:>width$} │ ", line_number, width = line_number_width);
+        for (line_index, line_content) in lines.iter().enumerate() {
             let line_number_style = if line_index == self.cursor_line {
                 // Highlight current line number
                 Style::default()
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme.get_color("editor_line_number"))
             };
 
-            let mut spans = vec![Span::styled(line_number_text, line_number_style)];
-
-            // Add syntax highlighting for the actual line content
-            let line_with_newline = format!("{}\n", line_content);
-            if let Ok(ranges) = highlighter.highlight_line(&line_with_newline, &self.syntax_set) {
-                for (style, text) in ranges {
-                    // Skip the newline character we added and convert to owned string
-                    let text_content = text.trim_end_matches('\n').to_string();
-                    if !text_content.is_empty() {
-                        let fg_color = style.foreground;
-                        let color = Color::Rgb(fg_color.r, fg_color.g, fg_color.b);
-
-                        let mut ratatui_style = Style::default().fg(color);
-                        if style
-                            .font_style
-                            .contains(syntect::highlighting::FontStyle::BOLD)
-                        {
-                            ratatui_style = ratatui_style.add_modifier(Modifier::BOLD);
-                        }
-                        if style
-                            .font_style
-                            .contains(syntect::highlighting::FontStyle::ITALIC)
-                        {
-                            ratatui_style = ratatui_style.add_modifier(Modifier::ITALIC);
-                        }
-                        if style
-                            .font_style
-                            .contains(syntect::highlighting::FontStyle::UNDERLINE)
-                        {
-                            ratatui_style = ratatui_style.add_modifier(Modifier::UNDERLINED);
-                        }
-
-                        spans.push(Span::styled(text_content, ratatui_style));
-                    }
+            let mut spans: Vec<Span<'static>> = self
+                .highlight_cache
+                .tokens(line_index)
+                .iter()
+                .filter_map(|token| {
+                    line_content
+                        .get(token.range.clone())
+                        .map(|text| Span::styled(text.to_string(), styles.style(token.kind)))
+                })
+                .collect();
+
+            if let Some((start, end)) =
+                self.selection_columns(line_index, line_content.chars().count())
+            {
+                spans = highlight_columns(spans, start, end, Style::default().bg(Color::DarkGray));
+            }
+            for (bracket_line, bracket_col) in &brackets {
+                if *bracket_line == line_index {
+                    spans = highlight_columns(
+                        spans,
+                        *bracket_col,
+                        bracket_col + 1,
+                        Style::default()
+                            .bg(Color::Rgb(80, 80, 40))
+                            .add_modifier(Modifier::BOLD),
+                    );
                 }
-            } else {
-                // Fallback for lines that can't be highlighted
-                spans.push(Span::raw(line_content.to_string()));
+            }
+            if self.show_line_numbers {
+                spans.insert(
+                    0,
+                    Span::styled(self.gutter_text(line_index), line_number_style),
+                );
             }
 
-            styled_lines.push(Line::from(spans));
+            let mut line = Line::from(spans);
+            if self.highlight_current_line && line_index == self.cursor_line {
+                line = line.style(Style::default().bg(theme.get_color("editor_cursor_line")));
+            }
+            styled_lines.push(line);
         }
 
         Text::from(styled_lines)
     }
 
-    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
         // No inline help - all help goes to help modal (accessible with '?')
         let editor_area = area;
 
@@ -1117,6 +1722,7 @@ impl QueryEditor {
                         DatabaseType::MariaDB => "MariaDB",
                         DatabaseType::SQLite => "SQLite",
                         DatabaseType::Oracle => "Oracle",
+                        DatabaseType::MsSql => "SQL Server",
                         DatabaseType::Redis => "Redis",
                         DatabaseType::MongoDB => "MongoDB",
                     }
@@ -1127,6 +1733,10 @@ impl QueryEditor {
             if self.is_modified { " [+]" } else { "" },
             if self.is_command_mode {
                 " [COMMAND]"
+            } else if self.visual_mode == Some(VisualMode::Char) {
+                " [VISUAL]"
+            } else if self.visual_mode == Some(VisualMode::Line) {
+                " [VISUAL LINE]"
             } else if self.is_insert_mode {
                 " [INSERT]"
             } else {
@@ -1179,7 +1789,18 @@ impl QueryEditor {
             f.render_widget(welcome_paragraph, editor_inner);
         } else {
             // Render syntax-highlighted content with line numbers
-            let highlighted_text = self.apply_syntax_highlighting_with_line_numbers(&self.content);
+            let lines: Vec<&str> = self.content.lines().collect();
+            self.highlight_cache.update(&lines);
+            // Keep the cursor line on screen after jumps down the file
+            let height = editor_inner.height as usize;
+            if height > 0 && self.cursor_line >= self.scroll_offset + height {
+                self.scroll_offset = self.cursor_line + 1 - height;
+            }
+            let highlighted_text = self.apply_syntax_highlighting_with_line_numbers(
+                &self.content,
+                &SyntaxStyles::from_theme(theme),
+                theme,
+            );
 
             let paragraph = Paragraph::new(highlighted_text)
                 .wrap(Wrap { trim: false })
@@ -1197,10 +1818,7 @@ impl QueryEditor {
                 0
             };
 
-            // Calculate line number width to offset cursor position
-            let total_lines = lines.len();
-            let line_number_width = format!("{}", total_lines).len().max(3);
-            let line_number_offset = (line_number_width + 3) as u16; // +3 for " │ "
+            let line_number_offset = self.gutter_width() as u16;
 
             let cursor_x = if self.cursor_line < lines.len() {
                 line_number_offset + self.cursor_col.min(lines[self.cursor_line].len()) as u16
@@ -1213,32 +1831,6 @@ impl QueryEditor {
             }
         }
 
-        // Render command line at bottom if in command mode
-        if self.is_command_mode && self.is_focused {
-            let command_line_y = editor_inner.y + editor_inner.height.saturating_sub(1);
-            let command_line_area = Rect {
-                x: editor_inner.x,
-                y: command_line_y,
-                width: editor_inner.width,
-                height: 1,
-            };
-
-            let command_text = Line::from(vec![Span::styled(
-                &self.command_buffer,
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )]);
-
-            f.render_widget(Paragraph::new(command_text), command_line_area);
-
-            // Set cursor at end of command buffer
-            let cursor_x = editor_inner.x + self.command_buffer.len() as u16;
-            if cursor_x < editor_inner.x + editor_inner.width {
-                f.set_cursor_position((cursor_x, command_line_y));
-            }
-        }
-
         // Render suggestions popup if active
         if self.suggestions_active {
             let cursor_screen_pos = if self.is_focused && !self.content.is_empty() {
@@ -1249,10 +1841,7 @@ impl QueryEditor {
                     editor_inner.y
                 };
 
-                // Calculate line number width to offset cursor position
-                let total_lines = lines.len();
-                let line_number_width = format!("{}", total_lines).len().max(3);
-                let line_number_offset = (line_number_width + 3) as u16; // +3 for " │ "
+                let line_number_offset = self.gutter_width() as u16;
 
                 let cursor_x = if self.cursor_line < lines.len() {
                     editor_inner.x
@@ -1272,6 +1861,62 @@ impl QueryEditor {
     }
 }
 
+/// Character class for word motions: blanks, word characters and punctuation
+fn word_class(c: char) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if c.is_alphanumeric() || c == '_' {
+        1
+    } else {
+        2
+    }
+}
+
+/// Leading whitespace of a line with tabs expanded to `tab_size` spaces
+fn leading_indent(line: &str, tab_size: usize) -> String {
+    let mut indent = String::new();
+    for ch in line.chars().take_while(|c| c.is_whitespace()) {
+        if ch == '\t' {
+            let spaces = tab_size - indent.len() % tab_size;
+            indent.push_str(&" ".repeat(spaces));
+        } else {
+            indent.push(' ');
+        }
+    }
+    indent
+}
+
+/// Patch `style` onto the characters `start..end` of a line's spans
+fn highlight_columns(
+    spans: Vec<Span<'static>>,
+    start: usize,
+    end: usize,
+    style: Style,
+) -> Vec<Span<'static>> {
+    let mut result = Vec::new();
+    let mut offset = 0;
+    for span in spans {
+        let chars: Vec<char> = span.content.chars().collect();
+        let span_end = offset + chars.len();
+        let from = start.clamp(offset, span_end) - offset;
+        let to = end.clamp(offset, span_end) - offset;
+        if from >= to {
+            result.push(span);
+        } else {
+            let piece = |range: std::ops::Range<usize>| chars[range].iter().collect::<String>();
+            if from > 0 {
+                result.push(Span::styled(piece(0..from), span.style));
+            }
+            result.push(Span::styled(piece(from..to), span.style.patch(style)));
+            if to < chars.len() {
+                result.push(Span::styled(piece(to..chars.len()), span.style));
+            }
+        }
+        offset = span_end;
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1330,6 +1975,19 @@ mod tests {
         assert!(statement.unwrap().contains("SELECT * FROM users"));
     }
 
+    #[test]
+    fn test_paragraph_at_cursor() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("SELECT 1;\n\nSELECT 2;\nSELECT 3;\n\nSELECT 4;".to_string());
+        editor.set_cursor_position(3, 0);
+        assert_eq!(
+            editor.paragraph_at_cursor(),
+            Some(("SELECT 2;\nSELECT 3;".to_string(), 2))
+        );
+        editor.set_cursor_position(1, 0);
+        assert_eq!(editor.paragraph_at_cursor(), None);
+    }
+
     #[test]
     fn test_cursor_movement() {
         let mut editor = QueryEditor::new();
@@ -1510,4 +2168,268 @@ mod tests {
         assert_eq!(editor.cursor_col, 0); // Cursor should be at beginning
         assert!(editor.is_modified());
     }
+
+    #[test]
+    fn test_gg_requires_double_press() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("SELECT 1;\nSELECT 2;\nSELECT 3;".to_string());
+        editor.move_to_file_end();
+
+        // A single g only arms the command
+        assert!(editor.handle_vim_command('g'));
+        assert_eq!(editor.cursor_line, 2);
+        assert!(editor.handle_vim_command('g'));
+        assert_eq!((editor.cursor_line, editor.cursor_col), (0, 0));
+        assert!(!editor.has_pending_command());
+    }
+
+    #[test]
+    fn test_pending_g_times_out() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("SELECT 1;\nSELECT 2;".to_string());
+        editor.move_to_file_end();
+
+        editor.handle_vim_command('g');
+        assert_eq!(editor.pending_keys().as_deref(), Some("g"));
+        editor.pending_since = Some(Instant::now() - PENDING_COMMAND_TIMEOUT * 2);
+        assert_eq!(editor.pending_keys(), None);
+        editor.handle_vim_command('g');
+        assert_eq!(editor.cursor_line, 1);
+        assert!(editor.has_pending_command());
+    }
+
+    #[test]
+    fn test_move_to_previous_word_punctuation() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("SELECT u.id, count(*)\nFROM users;".to_string());
+
+        // From "count(*)" back over the comma and the dotted name
+        editor.cursor_line = 0;
+        editor.cursor_col = 13;
+        let mut stops = Vec::new();
+        for _ in 0..5 {
+            editor.move_to_previous_word();
+            stops.push(editor.cursor_col);
+        }
+        assert_eq!(stops, vec![11, 9, 8, 7, 0]);
+    }
+
+    #[test]
+    fn test_move_to_previous_word_across_lines() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("SELECT id\n\n  FROM t;".to_string());
+
+        editor.cursor_line = 2;
+        editor.cursor_col = 2;
+        editor.move_to_previous_word();
+        // The empty line is a stop of its own
+        assert_eq!((editor.cursor_line, editor.cursor_col), (1, 0));
+        editor.move_to_previous_word();
+        assert_eq!((editor.cursor_line, editor.cursor_col), (0, 7));
+
+        // Nothing before the first word
+        editor.cursor_col = 0;
+        editor.move_to_previous_word();
+        assert_eq!((editor.cursor_line, editor.cursor_col), (0, 0));
+    }
+
+    #[test]
+    fn test_dd_and_dw() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("SELECT u.id FROM users;\nWHERE 1".to_string());
+
+        editor.cursor_col = 7;
+        assert!(editor.handle_vim_command('d'));
+        assert!(editor.handle_vim_command('w'));
+        // Stops at the dot, which is punctuation
+        assert_eq!(editor.get_content(), "SELECT .id FROM users;\nWHERE 1");
+        assert!(editor.is_modified());
+
+        editor.handle_vim_command('d');
+        editor.handle_vim_command('d');
+        assert_eq!(editor.get_content(), "WHERE 1");
+        assert_eq!(editor.cursor_line, 0);
+    }
+
+    #[test]
+    fn test_visual_delete_across_lines() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("SELECT id\nFROM users\nWHERE x".to_string());
+
+        editor.cursor_col = 7;
+        editor.enter_visual_mode(VisualMode::Char);
+        editor.move_cursor_down();
+        editor.cursor_col = 4;
+        assert_eq!(editor.selected_text().as_deref(), Some("id\nFROM "));
+
+        assert_eq!(editor.delete_selection().as_deref(), Some("id\nFROM "));
+        assert_eq!(editor.get_content(), "SELECT users\nWHERE x");
+        assert_eq!((editor.cursor_line, editor.cursor_col), (0, 7));
+        assert_eq!(editor.visual_mode(), None);
+    }
+
+    #[test]
+    fn test_visual_line_yank_and_delete() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("a\nb\nc".to_string());
+
+        editor.cursor_line = 2;
+        editor.enter_visual_mode(VisualMode::Line);
+        editor.move_cursor_up();
+        assert_eq!(editor.selected_text().as_deref(), Some("b\nc\n"));
+
+        editor.delete_selection();
+        assert_eq!(editor.get_content(), "a");
+        assert_eq!((editor.cursor_line, editor.cursor_col), (0, 0));
+    }
+
+    #[test]
+    fn test_yank_and_paste_lines() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("SELECT 1;\nSELECT 2;".to_string());
+
+        editor.handle_vim_command('y');
+        editor.handle_vim_command('y');
+        assert_eq!(
+            editor.register(),
+            Some(&Register {
+                text: "SELECT 1;\n".to_string(),
+                linewise: true,
+            })
+        );
+
+        editor.move_cursor_down();
+        editor.handle_vim_command('p');
+        assert_eq!(editor.get_content(), "SELECT 1;\nSELECT 2;\nSELECT 1;");
+        assert_eq!(editor.cursor_line, 2);
+
+        // dd fills the register; P puts it back above the cursor
+        editor.handle_vim_command('d');
+        editor.handle_vim_command('d');
+        editor.cursor_line = 0;
+        editor.handle_vim_command('P');
+        assert_eq!(editor.get_content(), "SELECT 1;\nSELECT 1;\nSELECT 2;");
+        assert_eq!((editor.cursor_line, editor.cursor_col), (0, 0));
+    }
+
+    #[test]
+    fn test_charwise_paste() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("ab".to_string());
+
+        // x then p swaps two characters, as in Vim
+        editor.handle_vim_command('x');
+        assert_eq!(editor.get_content(), "b");
+        editor.handle_vim_command('p');
+        assert_eq!(editor.get_content(), "ba");
+        assert_eq!(editor.cursor_col, 1);
+
+        editor.set_content("SELECT id FROM t".to_string());
+        editor.cursor_col = 7;
+        editor.delete_word();
+        assert_eq!(editor.register().map(|r| r.text.as_str()), Some("id "));
+        editor.cursor_col = 0;
+        editor.handle_vim_command('P');
+        assert_eq!(editor.get_content(), "id SELECT FROM t");
+        assert_eq!(editor.cursor_col, 2);
+    }
+
+    #[test]
+    fn test_auto_indent_and_tab_expansion() {
+        let mut editor = QueryEditor::new();
+        editor.set_tab_size(2);
+        editor.set_content("SELECT\n\tid".to_string());
+        editor.set_insert_mode(true);
+        editor.cursor_line = 1;
+        editor.cursor_col = 3;
+        editor.insert_newline();
+        assert_eq!(editor.get_content(), "SELECT\n\tid\n  ");
+        assert_eq!((editor.cursor_line, editor.cursor_col), (2, 2));
+
+        // Tab fills to the next tab stop
+        editor.insert_char('x');
+        editor.insert_char('\t');
+        assert_eq!(editor.get_content(), "SELECT\n\tid\n  x ");
+    }
+
+    #[test]
+    fn test_matching_bracket() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("SELECT count(')') FROM (\n  t)".to_string());
+        editor.cursor_col = 12;
+        assert_eq!(editor.matching_bracket(), Some((0, 16)));
+        editor.cursor_col = 16;
+        assert_eq!(editor.matching_bracket(), Some((0, 12)));
+
+        // Pairs span lines
+        editor.cursor_col = 23;
+        assert_eq!(editor.matching_bracket(), Some((1, 3)));
+        editor.cursor_col = 0;
+        assert_eq!(editor.matching_bracket(), None);
+    }
+
+    #[test]
+    fn test_snippet_stops_follow_typing() {
+        let mut editor = QueryEditor::new();
+        editor.set_content("-- report\n".to_string());
+        editor.cursor_line = 1;
+        editor.insert_snippet("SELECT $1\nFROM $2;");
+        assert!(editor.is_insert_mode());
+        assert_eq!((editor.cursor_line, editor.cursor_col), (1, 7));
+
+        // Typing at the first stop pushes later stops along
+        for c in "id".chars() {
+            editor.insert_char(c);
+        }
+        editor.insert_newline();
+        assert!(editor.jump_to_next_snippet_stop());
+        assert_eq!((editor.cursor_line, editor.cursor_col), (3, 5));
+        editor.insert_char('t');
+        assert_eq!(editor.get_content(), "-- report\nSELECT id\n\nFROM t;");
+        assert!(!editor.jump_to_next_snippet_stop());
+
+        // Without stops the cursor ends after the inserted text
+        editor.set_insert_mode(false);
+        editor.insert_snippet("EXPLAIN ");
+        assert!(!editor.is_insert_mode());
+        assert_eq!((editor.cursor_line, editor.cursor_col), (3, 14));
+    }
+
+    #[test]
+    fn test_line_jumps_and_gutter() {
+        let mut editor = QueryEditor::new();
+        let content: Vec<String> = (1..=120).map(|n| format!("  SELECT {n};")).collect();
+        editor.set_content(content.join("\n"));
+
+        // `42G`: counts build digit by digit and land on the first non-blank
+        editor.push_count_digit(4);
+        editor.push_count_digit(2);
+        let line = editor.take_count().unwrap();
+        editor.go_to_line(line);
+        assert_eq!((editor.cursor_line, editor.cursor_col), (41, 2));
+        assert!(!editor.has_count());
+        editor.go_to_line(500);
+        assert_eq!(editor.cursor_line, 119);
+
+        // The gutter grows with the digits in the last line number
+        assert_eq!(editor.gutter_width(), 6);
+        assert_eq!(editor.gutter_text(8), "  9 │ ");
+        editor.set_content(
+            (1..=1200)
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+        assert_eq!(editor.gutter_width(), 7);
+
+        // Relative numbers count from the cursor, which keeps its own number
+        editor.set_line_numbers(true, true);
+        editor.go_to_line(10);
+        assert_eq!(editor.gutter_text(9), "10   │ ");
+        assert_eq!(editor.gutter_text(6), "   3 │ ");
+        assert_eq!(editor.gutter_text(12), "   3 │ ");
+
+        editor.set_line_numbers(false, false);
+        assert_eq!(editor.gutter_width(), 0);
+    }
 }
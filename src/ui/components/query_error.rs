@@ -0,0 +1,151 @@
+// FilePath: src/ui/components/query_error.rs
+
+#![forbid(unsafe_code)]
+
+use crate::core::error::LazyTablesError;
+use sqlx::postgres::{PgDatabaseError, PgErrorPosition};
+
+/// Words of the message kept in an error tab title
+const TITLE_WORDS: usize = 4;
+
+/// A failed query as shown in an error results tab
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryError {
+    /// Full database error text, with any detail and hint lines
+    pub message: String,
+    /// SQLSTATE (PostgreSQL, MySQL, SQLite) when the driver reports one
+    pub code: Option<String>,
+    /// The statement as sent to the server
+    pub query: String,
+    /// Line and byte column in `query` the server pointed at
+    pub position: Option<(usize, usize)>,
+    /// Editor line and column the statement starts at
+    pub editor_origin: (usize, usize),
+}
+
+impl QueryError {
+    pub fn new(error: &LazyTablesError, query: String, editor_origin: (usize, usize)) -> Self {
+        let (message, code, offset) = match error {
+            LazyTablesError::Database(sqlx::Error::Database(db)) => {
+                let pg = db.try_downcast_ref::<PgDatabaseError>();
+                let mut message = db.message().to_string();
+                if let Some(detail) = pg.and_then(|pg| pg.detail()) {
+                    message.push_str(&format!("\nDETAIL: {detail}"));
+                }
+                if let Some(hint) = pg.and_then(|pg| pg.hint()) {
+                    message.push_str(&format!("\nHINT: {hint}"));
+                }
+                let offset = pg.and_then(|pg| match pg.position() {
+                    Some(PgErrorPosition::Original(position)) => Some(position),
+                    _ => None,
+                });
+                (message, db.code().map(|code| code.into_owned()), offset)
+            }
+            other => (other.to_string(), None, None),
+        };
+
+        let position = offset
+            .and_then(|offset| position_at_character(&query, offset))
+            .or_else(|| position_from_message(&message, &query));
+
+        Self {
+            message,
+            code,
+            query,
+            position,
+            editor_origin,
+        }
+    }
+
+    /// Tab title, e.g. `Error: relation "users" does not`
+    pub fn title(&self) -> String {
+        let words: Vec<&str> = self.message.split_whitespace().take(TITLE_WORDS).collect();
+        format!("Error: {}", words.join(" "))
+    }
+
+    /// The query line the error points at, with a caret line underneath
+    pub fn offending_line(&self) -> Option<(String, String)> {
+        let (line, column) = self.position?;
+        let text = self.query.lines().nth(line)?;
+        let width = text.get(..column).unwrap_or(text).chars().count();
+        Some((text.to_string(), format!("{}^", " ".repeat(width))))
+    }
+
+    /// Editor line and column of the reported position
+    pub fn editor_position(&self) -> Option<(usize, usize)> {
+        let (line, column) = self.position?;
+        let (origin_line, origin_column) = self.editor_origin;
+        if line == 0 {
+            Some((origin_line, origin_column + column))
+        } else {
+            Some((origin_line + line, column))
+        }
+    }
+}
+
+/// Line and byte column of a 1-based character offset, as PostgreSQL reports it
+pub fn position_at_character(query: &str, offset: usize) -> Option<(usize, usize)> {
+    let index = offset.checked_sub(1)?;
+    let (byte, _) = query.char_indices().nth(index)?;
+    let line = query[..byte].matches('\n').count();
+    let line_start = query[..byte].rfind('\n').map_or(0, |newline| newline + 1);
+    Some((line, byte - line_start))
+}
+
+/// Position from MySQL-style `near '...' at line N` messages
+fn position_from_message(message: &str, query: &str) -> Option<(usize, usize)> {
+    let (before, after) = message.rsplit_once("' at line ")?;
+    let number: usize = after
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .ok()?;
+    let line = number.checked_sub(1)?;
+    let text = query.lines().nth(line)?;
+
+    let near = before
+        .rsplit_once("near '")
+        .map(|(_, near)| near.lines().next().unwrap_or_default())
+        .unwrap_or_default();
+    let column = if near.is_empty() {
+        0
+    } else {
+        text.find(near).unwrap_or(0)
+    };
+    Some((line, column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_position_and_caret() {
+        let query = "SELECT id,\n       nme\nFROM users";
+        assert_eq!(position_at_character(query, 1), Some((0, 0)));
+        assert_eq!(position_at_character(query, 19), Some((1, 7)));
+        assert_eq!(position_at_character(query, 100), None);
+
+        let error = QueryError {
+            message: "column \"nme\" does not exist".to_string(),
+            code: Some("42703".to_string()),
+            query: query.to_string(),
+            position: position_at_character(query, 19),
+            editor_origin: (4, 2),
+        };
+        assert_eq!(error.title(), "Error: column \"nme\" does not");
+        assert_eq!(
+            error.offending_line(),
+            Some(("       nme".to_string(), "       ^".to_string()))
+        );
+        assert_eq!(error.editor_position(), Some((5, 7)));
+
+        let message = "You have an error in your SQL syntax; check the manual \
+                       near 'FORM users' at line 2";
+        assert_eq!(
+            position_from_message(message, "SELECT *\n  FORM users"),
+            Some((1, 2))
+        );
+    }
+}
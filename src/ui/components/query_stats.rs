@@ -0,0 +1,267 @@
+// FilePath: src/ui/components/query_stats.rs
+
+#![forbid(unsafe_code)]
+
+use std::time::{Duration, Instant};
+
+/// Statistics of the query that produced a results tab
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryStats {
+    pub elapsed: Duration,
+    /// Rows returned, or rows affected when `affected` is set
+    pub rows: u64,
+    pub affected: bool,
+    /// Stored row estimate for table opens, on engines that keep one
+    pub estimated_rows: Option<u64>,
+    /// Name of the connection the query ran on
+    pub connection_name: String,
+}
+
+impl QueryStats {
+    /// One-line summary for the status bar, e.g. `42 ms • 10 rows • local`
+    pub fn summary(&self) -> String {
+        let noun = if self.rows == 1 { "row" } else { "rows" };
+        let verb = if self.affected { " affected" } else { "" };
+        let estimate = self
+            .estimated_rows
+            .map(|rows| format!(" of ~{rows}"))
+            .unwrap_or_default();
+        format!(
+            "{} • {} {noun}{verb}{estimate} • {}",
+            format_elapsed(self.elapsed),
+            self.rows,
+            self.connection_name
+        )
+    }
+
+    /// Compact suffix for tab titles, e.g. `(142 rows, 8 ms)`
+    pub fn tab_label(&self) -> String {
+        let rows = match self.estimated_rows {
+            Some(estimate) => format!("~{estimate} rows"),
+            None if self.affected => format!("{} affected", self.rows),
+            None => format!("{} rows", self.rows),
+        };
+        format!("({rows}, {})", format_elapsed(self.elapsed))
+    }
+}
+
+/// What a finished query produced
+#[derive(Debug, Clone)]
+pub enum QueryOutput {
+    /// Column names and rows of a result set
+    Rows(Vec<String>, Vec<Vec<Option<String>>>),
+    /// Rows affected by a data-modifying statement
+    Affected(u64),
+    /// Outcome of each statement of a "run all" script
+    Batch(Vec<StatementResult>),
+}
+
+/// What happened to one statement of a script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatementStatus {
+    Rows(u64),
+    Affected(u64),
+    Failed(String),
+    /// Not run because an earlier statement failed
+    Skipped,
+}
+
+/// One row of the "run all" summary tab
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementResult {
+    /// 0-based editor line the statement starts on
+    pub line: usize,
+    pub statement: String,
+    pub status: StatementStatus,
+    pub elapsed: Duration,
+}
+
+impl StatementResult {
+    pub fn is_failed(&self) -> bool {
+        matches!(self.status, StatementStatus::Failed(_))
+    }
+}
+
+/// Characters of statement text shown in the summary tab
+const STATEMENT_PREVIEW_CHARS: usize = 60;
+
+/// Columns and rows of the "run all" summary tab
+pub fn batch_summary(results: &[StatementResult]) -> (Vec<String>, Vec<Vec<Option<String>>>) {
+    let columns = ["#", "Line", "Statement", "Status", "Rows", "Duration"]
+        .iter()
+        .map(|c| c.to_string())
+        .collect();
+    let rows = results
+        .iter()
+        .enumerate()
+        .map(|(i, result)| {
+            let mut preview: String = result
+                .statement
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ");
+            if preview.chars().count() > STATEMENT_PREVIEW_CHARS {
+                preview = preview.chars().take(STATEMENT_PREVIEW_CHARS).collect();
+                preview.push('…');
+            }
+            let (status, rows, elapsed) = match &result.status {
+                StatementStatus::Rows(rows) => (
+                    "OK".to_string(),
+                    format!("{rows} returned"),
+                    format_elapsed(result.elapsed),
+                ),
+                StatementStatus::Affected(rows) => (
+                    "OK".to_string(),
+                    format!("{rows} affected"),
+                    format_elapsed(result.elapsed),
+                ),
+                StatementStatus::Failed(error) => (
+                    format!("ERROR: {error}"),
+                    String::new(),
+                    format_elapsed(result.elapsed),
+                ),
+                StatementStatus::Skipped => ("SKIPPED".to_string(), String::new(), String::new()),
+            };
+            [
+                (i + 1).to_string(),
+                (result.line + 1).to_string(),
+                preview,
+                status,
+                rows,
+                elapsed,
+            ]
+            .into_iter()
+            .map(Some)
+            .collect()
+        })
+        .collect();
+    (columns, rows)
+}
+
+/// A query executing in the background
+#[derive(Debug, Clone)]
+pub struct RunningQuery {
+    pub query: String,
+    pub connection_id: String,
+    pub connection_name: String,
+    pub started_at: Instant,
+    /// Set once the user asked to cancel the query
+    pub cancelling: bool,
+    /// Editor line and column the statement starts at
+    pub editor_origin: (usize, usize),
+    /// Split (0 = top or left) the query was run from
+    pub split: usize,
+}
+
+impl RunningQuery {
+    pub fn new(query: String, connection_id: String, connection_name: String) -> Self {
+        Self {
+            query,
+            connection_id,
+            connection_name,
+            started_at: Instant::now(),
+            cancelling: false,
+            editor_origin: (0, 0),
+            split: 0,
+        }
+    }
+}
+
+/// Milliseconds below one second, seconds with one decimal above
+pub fn format_elapsed(elapsed: Duration) -> String {
+    if elapsed < Duration::from_secs(1) {
+        format!("{} ms", elapsed.as_millis())
+    } else {
+        format!("{:.1} s", elapsed.as_secs_f64())
+    }
+}
+
+/// Whether a statement reports rows affected instead of returning rows
+/// (`INSERT`/`UPDATE`/`DELETE`/`MERGE` without `RETURNING`)
+pub fn reports_rows_affected(query: &str) -> bool {
+    let upper = query.trim_start().to_uppercase();
+    let keyword = upper
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or("");
+    matches!(keyword, "INSERT" | "UPDATE" | "DELETE" | "MERGE")
+        && !upper
+            .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .any(|word| word == "RETURNING")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_elapsed_precision() {
+        assert_eq!(format_elapsed(Duration::from_millis(0)), "0 ms");
+        assert_eq!(format_elapsed(Duration::from_millis(999)), "999 ms");
+        assert_eq!(format_elapsed(Duration::from_millis(1000)), "1.0 s");
+        assert_eq!(format_elapsed(Duration::from_millis(12_345)), "12.3 s");
+
+        let stats = QueryStats {
+            elapsed: Duration::from_millis(42),
+            rows: 1,
+            affected: true,
+            estimated_rows: None,
+            connection_name: "local".to_string(),
+        };
+        assert_eq!(stats.summary(), "42 ms • 1 row affected • local");
+        assert_eq!(stats.tab_label(), "(1 affected, 42 ms)");
+
+        let table_open = QueryStats {
+            elapsed: Duration::from_millis(8),
+            rows: 20,
+            affected: false,
+            estimated_rows: Some(1500),
+            connection_name: "local".to_string(),
+        };
+        assert_eq!(table_open.summary(), "8 ms • 20 rows of ~1500 • local");
+        assert_eq!(table_open.tab_label(), "(~1500 rows, 8 ms)");
+    }
+
+    #[test]
+    fn test_batch_summary_rows() {
+        let result = |statement: &str, status| StatementResult {
+            line: 2,
+            statement: statement.to_string(),
+            status,
+            elapsed: Duration::from_millis(5),
+        };
+        let (columns, rows) = batch_summary(&[
+            result("UPDATE t\n   SET a = 1", StatementStatus::Affected(3)),
+            result(
+                "SELECT nope",
+                StatementStatus::Failed("bad column".to_string()),
+            ),
+            result(&"x".repeat(70), StatementStatus::Skipped),
+        ]);
+        let rows: Vec<Vec<String>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Option::unwrap_or_default).collect())
+            .collect();
+        assert_eq!(columns.len(), 6);
+        assert_eq!(
+            rows[0],
+            vec!["1", "3", "UPDATE t SET a = 1", "OK", "3 affected", "5 ms"]
+        );
+        assert_eq!(rows[1][3], "ERROR: bad column");
+        assert_eq!(rows[2][2].chars().count(), STATEMENT_PREVIEW_CHARS + 1);
+        assert_eq!(rows[2][3..], ["SKIPPED", "", ""]);
+    }
+
+    #[test]
+    fn test_reports_rows_affected() {
+        assert!(reports_rows_affected("update users set name = 'x'"));
+        assert!(reports_rows_affected("  DELETE FROM t"));
+        assert!(!reports_rows_affected(
+            "INSERT INTO t (a) VALUES (1) RETURNING id"
+        ));
+        assert!(!reports_rows_affected("SELECT * FROM updates"));
+        assert!(!reports_rows_affected(
+            "WITH x AS (SELECT 1) SELECT * FROM x"
+        ));
+    }
+}
@@ -0,0 +1,241 @@
+// FilePath: src/ui/components/query_vars.rs
+
+#![forbid(unsafe_code)]
+
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use std::collections::BTreeMap;
+
+/// Byte ranges and names of each `{{name}}` placeholder in `sql`
+fn placeholders(sql: &str) -> Vec<(std::ops::Range<usize>, &str)> {
+    let mut found = Vec::new();
+    let mut from = 0;
+    while let Some(start) = sql[from..].find("{{").map(|i| from + i) {
+        let Some(end) = sql[start + 2..].find("}}").map(|i| start + 2 + i) else {
+            break;
+        };
+        let name = sql[start + 2..end].trim();
+        if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            found.push((start..end + 2, name));
+            from = end + 2;
+        } else {
+            from = start + 2;
+        }
+    }
+    found
+}
+
+/// Names of the variables referenced in `sql`, in order of first use
+pub fn query_var_names(sql: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for (_, name) in placeholders(sql) {
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+/// Replace every `{{name}}` with its value, verbatim. Fails with the names
+/// that have no value so the query is never sent half-substituted.
+pub fn substitute_query_vars(
+    sql: &str,
+    vars: &BTreeMap<String, String>,
+) -> Result<String, Vec<String>> {
+    let mut result = String::with_capacity(sql.len());
+    let mut missing: Vec<String> = Vec::new();
+    let mut last = 0;
+    for (range, name) in placeholders(sql) {
+        result.push_str(&sql[last..range.start]);
+        match vars.get(name) {
+            Some(value) => result.push_str(value),
+            None if !missing.iter().any(|m| m == name) => missing.push(name.to_string()),
+            None => {}
+        }
+        last = range.end;
+    }
+    result.push_str(&sql[last..]);
+
+    if missing.is_empty() {
+        Ok(result)
+    } else {
+        Err(missing)
+    }
+}
+
+/// `Ctrl+Shift+V` panel below the query editor for viewing and editing `query_vars`
+#[derive(Debug, Clone, Default)]
+pub struct QueryVarsPanel {
+    pub selected: usize,
+    /// Variable whose value is being typed; keystrokes update it directly
+    pub editing: Option<String>,
+    /// Name typed for a variable being added with `a`
+    pub new_name: Option<String>,
+}
+
+/// One row of the panel
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryVarRow {
+    pub name: String,
+    pub value: Option<String>,
+    /// Referenced by the current query text
+    pub used: bool,
+}
+
+impl QueryVarsPanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Variables used by `sql` first (set or not), then the unused ones
+    pub fn rows(vars: &BTreeMap<String, String>, sql: &str) -> Vec<QueryVarRow> {
+        let used = query_var_names(sql);
+        let mut rows: Vec<QueryVarRow> = used
+            .iter()
+            .map(|name| QueryVarRow {
+                name: name.clone(),
+                value: vars.get(name).cloned(),
+                used: true,
+            })
+            .collect();
+        rows.extend(
+            vars.iter()
+                .filter(|(name, _)| !used.contains(name))
+                .map(|(name, value)| QueryVarRow {
+                    name: name.clone(),
+                    value: Some(value.clone()),
+                    used: false,
+                }),
+        );
+        rows
+    }
+}
+
+/// Render the panel; used variables in `primary_highlight`, unused ones greyed out
+pub fn render_query_vars_panel(
+    frame: &mut Frame,
+    area: Rect,
+    panel: &QueryVarsPanel,
+    vars: &BTreeMap<String, String>,
+    sql: &str,
+    theme: &Theme,
+) {
+    let rows = QueryVarsPanel::rows(vars, sql);
+    let highlight = theme.get_color("primary_highlight");
+
+    let mut items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| {
+            let name_style = if row.used {
+                Style::default().fg(highlight).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            let editing = panel.editing.as_deref() == Some(row.name.as_str());
+            let value = match (&row.value, editing) {
+                (Some(value), true) => {
+                    Span::styled(format!("{value}█"), Style::default().fg(Color::Yellow))
+                }
+                (Some(value), false) => Span::styled(
+                    value.clone(),
+                    Style::default().fg(if row.used {
+                        Color::White
+                    } else {
+                        Color::DarkGray
+                    }),
+                ),
+                (None, _) => Span::styled("<unset>", Style::default().fg(Color::Red)),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{{{{{}}}}}", row.name), name_style),
+                Span::raw(" = "),
+                value,
+            ]))
+        })
+        .collect();
+
+    if let Some(name) = &panel.new_name {
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled("New variable: ", Style::default().fg(Color::Cyan)),
+            Span::styled(format!("{name}█"), Style::default().fg(Color::Yellow)),
+        ])));
+    } else if items.is_empty() {
+        items.push(ListItem::new(Span::styled(
+            "No variables - press a to add one, then use {{name}} in the query",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let hint = if panel.editing.is_some() || panel.new_name.is_some() {
+        " Enter/Esc done "
+    } else {
+        " Enter edit • a add • d delete • q close "
+    };
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(" Query Variables ")
+                .title_bottom(hint)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(highlight)),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray));
+
+    let mut list_state = ListState::default();
+    if panel.new_name.is_none() && !rows.is_empty() {
+        list_state.select(Some(panel.selected.min(rows.len() - 1)));
+    }
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_substitute_query_vars() {
+        let mut vars = BTreeMap::new();
+        vars.insert("id".to_string(), "42".to_string());
+        vars.insert("status".to_string(), "'active'".to_string());
+
+        let sql = "SELECT * FROM users WHERE id = {{id}} AND status = {{ status }}";
+        assert_eq!(query_var_names(sql), vec!["id", "status"]);
+        assert_eq!(
+            substitute_query_vars(sql, &vars).unwrap(),
+            "SELECT * FROM users WHERE id = 42 AND status = 'active'"
+        );
+
+        // Not a placeholder: braces around something that isn't a name
+        assert_eq!(
+            substitute_query_vars("SELECT '{{a b}}'", &vars).unwrap(),
+            "SELECT '{{a b}}'"
+        );
+        assert_eq!(
+            substitute_query_vars("{{limit}} {{limit}} {{id}}", &vars),
+            Err(vec!["limit".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_rows_list_used_variables_first() {
+        let mut vars = BTreeMap::new();
+        vars.insert("a".to_string(), "1".to_string());
+        vars.insert("b".to_string(), "2".to_string());
+
+        let rows = QueryVarsPanel::rows(&vars, "SELECT {{c}}, {{b}}");
+        let summary: Vec<(&str, bool, bool)> = rows
+            .iter()
+            .map(|r| (r.name.as_str(), r.value.is_some(), r.used))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("c", false, true), ("b", true, true), ("a", true, false)]
+        );
+    }
+}
@@ -0,0 +1,230 @@
+// FilePath: src/ui/components/quick_switcher.rs
+
+#![forbid(unsafe_code)]
+
+use super::command_palette::match_score;
+use crate::database::DatabaseObjectType;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::collections::HashSet;
+
+/// Where a quick switcher entry comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SwitcherSource {
+    /// Viewer tab at this index, already loaded
+    OpenTab { index: usize, row_count: usize },
+    /// Listed in the Tables pane
+    Table,
+    /// Opened recently on this connection
+    Recent,
+}
+
+/// One table, view or open tab in the switcher
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwitcherEntry {
+    pub name: String,
+    pub object_type: DatabaseObjectType,
+    pub source: SwitcherSource,
+}
+
+/// `Ctrl+T` popup: open viewer tabs, then the Tables pane listing, then
+/// recently opened tables, filtered as you type
+#[derive(Debug, Clone)]
+pub struct QuickSwitcher {
+    entries: Vec<SwitcherEntry>,
+    /// Text typed so far
+    pub query: String,
+    /// Indices into `entries` that match `query`, best match first
+    filtered: Vec<usize>,
+    pub selected: usize,
+}
+
+impl QuickSwitcher {
+    /// Build the list in section order. Tables already open as tabs are not
+    /// repeated; recent tables keep their own section.
+    pub fn new(
+        open_tabs: Vec<SwitcherEntry>,
+        tables: Vec<SwitcherEntry>,
+        recent: Vec<SwitcherEntry>,
+    ) -> Self {
+        let open: HashSet<String> = open_tabs.iter().map(|e| e.name.clone()).collect();
+        let entries = open_tabs
+            .into_iter()
+            .chain(tables.into_iter().filter(|e| !open.contains(&e.name)))
+            .chain(recent.into_iter().filter(|e| !open.contains(&e.name)))
+            .collect();
+        let mut switcher = Self {
+            entries,
+            query: String::new(),
+            filtered: Vec::new(),
+            selected: 0,
+        };
+        switcher.refilter();
+        switcher
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.query.push(c);
+        self.refilter();
+    }
+
+    pub fn backspace(&mut self) {
+        self.query.pop();
+        self.refilter();
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = (self.selected + 1) % self.filtered.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = (self.selected + self.filtered.len() - 1) % self.filtered.len();
+        }
+    }
+
+    /// Entries matching the query, in display order
+    pub fn visible(&self) -> impl Iterator<Item = &SwitcherEntry> {
+        self.filtered.iter().map(|&i| &self.entries[i])
+    }
+
+    /// Entry under the cursor
+    pub fn selected_entry(&self) -> Option<&SwitcherEntry> {
+        self.filtered.get(self.selected).map(|&i| &self.entries[i])
+    }
+
+    /// Rank by name; equal scores keep section order
+    fn refilter(&mut self) {
+        let query = self.query.to_lowercase();
+        let mut scored: Vec<(usize, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                match_score(&entry.name.to_lowercase(), &query).map(|score| (score, i))
+            })
+            .collect();
+        scored.sort();
+        self.filtered = scored.into_iter().map(|(_, i)| i).collect();
+        self.selected = 0;
+    }
+}
+
+/// Render the switcher centered near the top of the screen
+pub fn render_quick_switcher(frame: &mut Frame, switcher: &QuickSwitcher) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(70);
+    let height = screen.height.saturating_sub(4).min(20);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 4,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(" Go to Table ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(inner);
+
+    let input = Paragraph::new(Line::from(vec![
+        Span::styled("> ", Style::default().fg(Color::Yellow)),
+        Span::raw(switcher.query.as_str()),
+        Span::styled("█", Style::default().fg(Color::Gray)),
+    ]))
+    .block(Block::default().borders(Borders::BOTTOM));
+    frame.render_widget(input, chunks[0]);
+
+    let list_width = chunks[1].width as usize;
+    let items: Vec<ListItem> = switcher
+        .visible()
+        .map(|entry| {
+            let (marker, detail) = match entry.source {
+                SwitcherSource::OpenTab { row_count, .. } => ("●", format!("{row_count} rows")),
+                SwitcherSource::Table => (" ", String::new()),
+                SwitcherSource::Recent => ("↺", "recent".to_string()),
+            };
+            let used = entry.name.chars().count() + 6;
+            let padding = list_width.saturating_sub(used + detail.chars().count());
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{marker} "), Style::default().fg(Color::Green)),
+                Span::raw(format!("{} ", entry.object_type.icon())),
+                Span::styled(entry.name.clone(), Style::default().fg(Color::White)),
+                Span::raw(" ".repeat(padding)),
+                Span::styled(detail, Style::default().fg(Color::DarkGray)),
+            ]))
+        })
+        .collect();
+
+    if items.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No matching tables").style(Style::default().fg(Color::DarkGray)),
+            chunks[1],
+        );
+        return;
+    }
+
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut list_state = ListState::default();
+    list_state.select(Some(switcher.selected));
+    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, source: SwitcherSource) -> SwitcherEntry {
+        SwitcherEntry {
+            name: name.to_string(),
+            object_type: DatabaseObjectType::Table,
+            source,
+        }
+    }
+
+    #[test]
+    fn test_sections_in_order_without_open_duplicates() {
+        let open = SwitcherSource::OpenTab {
+            index: 0,
+            row_count: 12,
+        };
+        let mut switcher = QuickSwitcher::new(
+            vec![entry("orders", open.clone())],
+            vec![
+                entry("customers", SwitcherSource::Table),
+                entry("orders", SwitcherSource::Table),
+            ],
+            vec![
+                entry("orders", SwitcherSource::Recent),
+                entry("archive.orders_2023", SwitcherSource::Recent),
+            ],
+        );
+        let names: Vec<&str> = switcher.visible().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["orders", "customers", "archive.orders_2023"]);
+
+        for c in "ord".chars() {
+            switcher.insert_char(c);
+        }
+        assert_eq!(switcher.selected_entry().unwrap().source, open);
+        assert_eq!(switcher.visible().count(), 2);
+    }
+}
@@ -0,0 +1,100 @@
+// FilePath: src/ui/components/rename_prompt.rs
+
+#![forbid(unsafe_code)]
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// New name for a table picked from the Tables pane action menu, typed over
+/// its current name
+#[derive(Debug, Clone)]
+pub struct RenamePrompt {
+    /// Qualified name of the object being renamed
+    pub table: String,
+    pub input: String,
+}
+
+impl RenamePrompt {
+    /// Start from the current name without its schema, which a rename keeps
+    pub fn new(table: String) -> Self {
+        let input = table
+            .split_once('.')
+            .map_or(table.as_str(), |(_, name)| name)
+            .to_string();
+        Self { table, input }
+    }
+
+    /// Typed name, once it is non-empty and differs from the current one
+    pub fn new_name(&self) -> Option<&str> {
+        let name = self.input.trim();
+        let current = self
+            .table
+            .split_once('.')
+            .map_or(self.table.as_str(), |(_, name)| name);
+        (!name.is_empty() && name != current).then_some(name)
+    }
+}
+
+/// Render the prompt centered over everything else
+pub fn render_rename_prompt(frame: &mut Frame, prompt: &RenamePrompt) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(60);
+    let height = 7.min(screen.height);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(" Rename Table ")
+        .title_bottom(" Enter rename  Esc cancel ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let lines = vec![
+        Line::from(vec![
+            Span::raw("Rename "),
+            Span::styled(
+                prompt.table.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" to"),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Name: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(prompt.input.as_str()),
+            Span::styled("█", Style::default().fg(Color::Gray)),
+        ]),
+    ];
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_name_must_change() {
+        let mut prompt = RenamePrompt::new("sales.orders".to_string());
+        assert_eq!(prompt.input, "orders");
+        assert_eq!(prompt.new_name(), None);
+        prompt.input = "  ".to_string();
+        assert_eq!(prompt.new_name(), None);
+        prompt.input = " order_lines ".to_string();
+        assert_eq!(prompt.new_name(), Some("order_lines"));
+    }
+}
@@ -0,0 +1,423 @@
+// FilePath: src/ui/components/result_diff.rs
+
+#![forbid(unsafe_code)]
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use crate::ui::components::table_viewer::display_cell;
+
+/// Kind of difference between a pinned result set and a newer one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    Added,
+    Removed,
+    Changed,
+    /// A run of identical rows, collapsed into one line
+    Unchanged,
+}
+
+impl DiffKind {
+    /// Marker shown in the first column of a diff tab
+    pub fn marker(&self) -> &'static str {
+        match self {
+            DiffKind::Added => "+",
+            DiffKind::Removed => "-",
+            DiffKind::Changed => "~",
+            DiffKind::Unchanged => "=",
+        }
+    }
+}
+
+/// Snapshot of a result set taken with the pin action
+#[derive(Debug, Clone)]
+pub struct PinnedResult {
+    /// Name of the tab the snapshot was taken from
+    pub source: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Option<String>>>,
+    /// Columns used to match rows across runs (empty = full-row matching)
+    pub key_columns: Vec<usize>,
+}
+
+/// A single displayed difference
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffEntry {
+    pub kind: DiffKind,
+    pub values: Vec<Option<String>>,
+    /// Values before the change, for `DiffKind::Changed`
+    pub old_values: Vec<Option<String>>,
+    /// Rows this entry stands for, for `DiffKind::Unchanged`
+    pub unchanged: usize,
+}
+
+impl DiffEntry {
+    fn new(kind: DiffKind, values: Vec<Option<String>>) -> Self {
+        Self {
+            kind,
+            values,
+            old_values: Vec::new(),
+            unchanged: 0,
+        }
+    }
+
+    /// Cells as shown in a diff tab: changed cells read `old → new`, and a
+    /// collapsed run shows its row count in the first column
+    pub fn display_values(&self, width: usize) -> Vec<Option<String>> {
+        match self.kind {
+            DiffKind::Unchanged => {
+                let mut cells = vec![Some(String::new()); width];
+                if let Some(first) = cells.first_mut() {
+                    *first = Some(format!("… {} identical rows", self.unchanged));
+                }
+                cells
+            }
+            DiffKind::Changed => self
+                .values
+                .iter()
+                .enumerate()
+                .map(|(idx, new)| match self.old_values.get(idx) {
+                    Some(old) if old != new => Some(format!(
+                        "{} → {}",
+                        display_cell(old.as_deref()),
+                        display_cell(new.as_deref())
+                    )),
+                    _ => new.clone(),
+                })
+                .collect(),
+            DiffKind::Added | DiffKind::Removed => self.values.clone(),
+        }
+    }
+}
+
+/// Result of comparing a pinned snapshot against a newer result set
+#[derive(Debug, Clone, Default)]
+pub struct ResultDiff {
+    pub entries: Vec<DiffEntry>,
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+    /// Differences left out because of the display cap
+    pub hidden: usize,
+    /// The key columns repeat a value, so rows were compared whole instead
+    pub duplicate_keys: bool,
+    /// Identical rows not yet written out as a collapsed entry
+    pending_unchanged: usize,
+}
+
+impl ResultDiff {
+    /// Total number of differences, including hidden ones
+    pub fn total(&self) -> usize {
+        self.added + self.removed + self.changed
+    }
+
+    fn push(&mut self, entry: DiffEntry, max_entries: usize) {
+        self.flush_unchanged(max_entries);
+        match entry.kind {
+            DiffKind::Added => self.added += 1,
+            DiffKind::Removed => self.removed += 1,
+            DiffKind::Changed => self.changed += 1,
+            DiffKind::Unchanged => {}
+        }
+        if self.entries.len() < max_entries {
+            self.entries.push(entry);
+        } else {
+            self.hidden += 1;
+        }
+    }
+
+    /// Count an identical row; consecutive ones share one collapsed entry
+    fn skip_unchanged(&mut self) {
+        self.pending_unchanged += 1;
+    }
+
+    fn flush_unchanged(&mut self, max_entries: usize) {
+        let count = std::mem::take(&mut self.pending_unchanged);
+        if count > 0 && self.entries.len() < max_entries {
+            self.entries.push(DiffEntry {
+                unchanged: count,
+                ..DiffEntry::new(DiffKind::Unchanged, Vec::new())
+            });
+        }
+    }
+}
+
+/// Pick the columns used to match rows: primary keys when known, otherwise
+/// a column named `id`, otherwise the leftmost column whose values are
+/// present and distinct in every one of `runs`
+pub fn detect_key_columns(
+    columns: &[String],
+    primary_key_columns: &[usize],
+    runs: &[&[Vec<Option<String>>]],
+) -> Vec<usize> {
+    if !primary_key_columns.is_empty() {
+        return primary_key_columns.to_vec();
+    }
+    if let Some(idx) = columns.iter().position(|c| c.eq_ignore_ascii_case("id")) {
+        return vec![idx];
+    }
+    if runs.iter().all(|rows| rows.is_empty()) {
+        return Vec::new();
+    }
+    (0..columns.len())
+        .find(|&idx| runs.iter().all(|rows| is_unique_column(rows, idx)))
+        .map(|idx| vec![idx])
+        .unwrap_or_default()
+}
+
+/// Whether every row has a distinct, non-NULL, non-empty value at `idx`
+fn is_unique_column(rows: &[Vec<Option<String>>], idx: usize) -> bool {
+    let mut seen = HashSet::with_capacity(rows.len());
+    rows.iter().all(|row| {
+        row.get(idx)
+            .and_then(Option::as_deref)
+            .is_some_and(|value| !value.is_empty() && seen.insert(value))
+    })
+}
+
+/// Whether no two rows share a key
+fn has_unique_keys(rows: &[Vec<Option<String>>], key_columns: &[usize]) -> bool {
+    let mut seen = HashSet::with_capacity(rows.len());
+    rows.iter()
+        .all(|row| seen.insert(hash_key(row, key_columns)))
+}
+
+/// Hash a whole row
+fn hash_row(row: &[Option<String>]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    row.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hash the key columns of a row
+fn hash_key(row: &[Option<String>], key_columns: &[usize]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for &idx in key_columns {
+        row.get(idx).hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Compare a pinned snapshot with a newer result set.
+///
+/// Rows are matched by key columns when the snapshot has them, the column
+/// layout is unchanged and no key repeats within either result; otherwise
+/// rows are compared as a multiset of full-row hashes, so row order never
+/// matters. Entries follow the newer result's row
+/// order, with runs of identical rows collapsed and removed rows last. At
+/// most `max_entries` entries are kept for display.
+pub fn compute_diff(
+    pinned: &PinnedResult,
+    columns: &[String],
+    rows: &[Vec<Option<String>>],
+    max_entries: usize,
+) -> ResultDiff {
+    let mut diff = ResultDiff::default();
+
+    let keyed = !pinned.key_columns.is_empty() && pinned.columns == columns;
+    // A repeated key would pair several rows with one, so compare whole rows
+    diff.duplicate_keys = keyed
+        && !(has_unique_keys(&pinned.rows, &pinned.key_columns)
+            && has_unique_keys(rows, &pinned.key_columns));
+
+    if keyed && !diff.duplicate_keys {
+        let mut old_by_key: HashMap<u64, usize> = HashMap::with_capacity(pinned.rows.len());
+        for (idx, row) in pinned.rows.iter().enumerate() {
+            old_by_key.insert(hash_key(row, &pinned.key_columns), idx);
+        }
+
+        for row in rows {
+            match old_by_key.remove(&hash_key(row, &pinned.key_columns)) {
+                Some(old_idx) if hash_row(&pinned.rows[old_idx]) != hash_row(row) => {
+                    diff.push(
+                        DiffEntry {
+                            old_values: pinned.rows[old_idx].clone(),
+                            ..DiffEntry::new(DiffKind::Changed, row.clone())
+                        },
+                        max_entries,
+                    );
+                }
+                Some(_) => diff.skip_unchanged(),
+                None => diff.push(DiffEntry::new(DiffKind::Added, row.clone()), max_entries),
+            }
+        }
+        diff.flush_unchanged(max_entries);
+
+        let mut removed: Vec<usize> = old_by_key.into_values().collect();
+        removed.sort_unstable();
+        for idx in removed {
+            diff.push(
+                DiffEntry::new(DiffKind::Removed, pinned.rows[idx].clone()),
+                max_entries,
+            );
+        }
+    } else {
+        let mut old_counts: HashMap<u64, usize> = HashMap::with_capacity(pinned.rows.len());
+        for row in &pinned.rows {
+            *old_counts.entry(hash_row(row)).or_insert(0) += 1;
+        }
+
+        for row in rows {
+            match old_counts.get_mut(&hash_row(row)) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    diff.skip_unchanged();
+                }
+                _ => diff.push(DiffEntry::new(DiffKind::Added, row.clone()), max_entries),
+            }
+        }
+        diff.flush_unchanged(max_entries);
+
+        for row in &pinned.rows {
+            if let Some(count) = old_counts.get_mut(&hash_row(row)) {
+                if *count > 0 {
+                    *count -= 1;
+                    diff.push(DiffEntry::new(DiffKind::Removed, row.clone()), max_entries);
+                }
+            }
+        }
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(values: &[&str]) -> Vec<Option<String>> {
+        values.iter().map(|v| Some(v.to_string())).collect()
+    }
+
+    fn names(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    fn pinned(rows: Vec<Vec<Option<String>>>, key_columns: Vec<usize>) -> PinnedResult {
+        PinnedResult {
+            source: "Query Result".to_string(),
+            columns: names(&["id", "name"]),
+            rows,
+            key_columns,
+        }
+    }
+
+    #[test]
+    fn test_key_based_matching() {
+        let old = pinned(
+            vec![
+                row(&["1", "alice"]),
+                row(&["2", "bob"]),
+                row(&["3", "carol"]),
+            ],
+            vec![0],
+        );
+        let new_rows = vec![
+            row(&["1", "alice"]),
+            row(&["2", "robert"]),
+            row(&["4", "dave"]),
+        ];
+
+        let diff = compute_diff(&old, &names(&["id", "name"]), &new_rows, 100);
+
+        assert_eq!((diff.added, diff.removed, diff.changed), (1, 1, 1));
+        assert_eq!(
+            diff.entries,
+            vec![
+                DiffEntry {
+                    unchanged: 1,
+                    ..DiffEntry::new(DiffKind::Unchanged, Vec::new())
+                },
+                DiffEntry {
+                    old_values: row(&["2", "bob"]),
+                    ..DiffEntry::new(DiffKind::Changed, row(&["2", "robert"]))
+                },
+                DiffEntry::new(DiffKind::Added, row(&["4", "dave"])),
+                DiffEntry::new(DiffKind::Removed, row(&["3", "carol"])),
+            ]
+        );
+        assert_eq!(
+            diff.entries[0].display_values(2),
+            row(&["… 1 identical rows", ""])
+        );
+        assert_eq!(
+            diff.entries[1].display_values(2),
+            row(&["2", "bob → robert"])
+        );
+    }
+
+    #[test]
+    fn test_ordering_insensitive_comparison() {
+        let old = pinned(
+            vec![row(&["1", "a"]), row(&["2", "b"]), row(&["2", "b"])],
+            Vec::new(),
+        );
+        let new_rows = vec![row(&["2", "b"]), row(&["1", "a"]), row(&["2", "b"])];
+
+        let diff = compute_diff(&old, &names(&["id", "name"]), &new_rows, 100);
+        assert_eq!(diff.total(), 0);
+
+        // A duplicate disappearing is still detected
+        let diff = compute_diff(&old, &names(&["id", "name"]), &new_rows[..2], 100);
+        assert_eq!((diff.added, diff.removed), (0, 1));
+    }
+
+    #[test]
+    fn test_duplicate_keys_compare_whole_rows() {
+        // `id` repeats, e.g. a join: matching by it would drop a pinned row
+        let old = pinned(
+            vec![row(&["1", "a"]), row(&["1", "b"]), row(&["2", "c"])],
+            vec![0],
+        );
+        let new_rows = vec![row(&["1", "a"]), row(&["1", "b"]), row(&["2", "d"])];
+
+        let diff = compute_diff(&old, &names(&["id", "name"]), &new_rows, 100);
+        assert!(diff.duplicate_keys);
+        assert_eq!((diff.added, diff.removed, diff.changed), (1, 1, 0));
+        assert_eq!(
+            diff.entries[1..],
+            [
+                DiffEntry::new(DiffKind::Added, row(&["2", "d"])),
+                DiffEntry::new(DiffKind::Removed, row(&["2", "c"])),
+            ]
+        );
+
+        // A key repeated only in the newer run falls back as well
+        let old = pinned(vec![row(&["1", "a"])], vec![0]);
+        let diff = compute_diff(&old, &names(&["id", "name"]), &new_rows[..2], 100);
+        assert!(diff.duplicate_keys);
+        assert_eq!((diff.added, diff.removed), (1, 0));
+    }
+
+    #[test]
+    fn test_display_cap() {
+        let old = pinned(Vec::new(), Vec::new());
+        let new_rows: Vec<Vec<Option<String>>> =
+            (0..10).map(|i| row(&[&i.to_string(), "x"])).collect();
+
+        let diff = compute_diff(&old, &names(&["id", "name"]), &new_rows, 3);
+        assert_eq!(diff.entries.len(), 3);
+        assert_eq!(diff.added, 10);
+        assert_eq!(diff.hidden, 7);
+    }
+
+    #[test]
+    fn test_detect_key_columns() {
+        let columns = names(&["name", "ID"]);
+        assert_eq!(detect_key_columns(&columns, &[], &[]), vec![1]);
+        assert_eq!(detect_key_columns(&columns, &[0], &[]), vec![0]);
+        assert!(detect_key_columns(&names(&["name"]), &[], &[]).is_empty());
+
+        // Otherwise the leftmost column that is unique in both runs
+        let columns = names(&["status", "job", "note"]);
+        let first = vec![row(&["ok", "a", "x"]), row(&["ok", "b", "y"])];
+        let second = vec![row(&["failed", "a", "x"]), row(&["ok", "b", "x"])];
+        assert_eq!(
+            detect_key_columns(&columns, &[], &[&first, &second]),
+            vec![1]
+        );
+        let second = vec![row(&["ok", "a", "x"]), row(&["ok", "a", "x"])];
+        assert!(detect_key_columns(&columns, &[], &[&first, &second]).is_empty());
+    }
+}
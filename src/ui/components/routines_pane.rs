@@ -0,0 +1,57 @@
+// FilePath: src/ui/components/routines_pane.rs
+
+#![forbid(unsafe_code)]
+
+use crate::{app::AppState, ui::theme::Theme};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+
+/// Render the stored function/procedure browser in the tables pane slot
+pub fn render_routines_pane(frame: &mut Frame, area: Rect, state: &mut AppState, theme: &Theme) {
+    let dim = Style::default().fg(theme.get_color("inactive_pane"));
+
+    let items: Vec<ListItem> = if state.db.routines.is_empty() {
+        vec![
+            ListItem::new(Span::styled("No routines found", dim)),
+            ListItem::new(""),
+            ListItem::new(Span::styled("r: Refresh • Ctrl+R: Back to tables", dim)),
+        ]
+    } else {
+        state
+            .db
+            .routines
+            .iter()
+            .map(|routine| {
+                let returns = if routine.return_type.is_empty() {
+                    routine.kind.to_lowercase()
+                } else {
+                    routine.return_type.clone()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(routine.display_name()),
+                    Span::styled(format!(" → {returns}"), dim),
+                ]))
+            })
+            .collect()
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(" [2] Routines ({}) ", state.db.routines.len()))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.get_color("active_border"))),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.get_color("selection_bg"))
+                .add_modifier(Modifier::BOLD),
+        );
+
+    frame.render_stateful_widget(list, area, &mut state.ui.routines_list_state);
+}
@@ -0,0 +1,216 @@
+// FilePath: src/ui/components/snippet_picker.rs
+
+#![forbid(unsafe_code)]
+
+use super::command_palette::match_score;
+use crate::state::{Snippet, SnippetStore, TomlStore};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// `Ctrl+J` overlay in the query editor: fuzzy search over snippet names,
+/// Enter inserts the highlighted snippet at the cursor
+#[derive(Debug, Clone)]
+pub struct SnippetPicker {
+    snippets: Vec<Snippet>,
+    /// Text typed so far
+    pub query: String,
+    /// Indices into `snippets` that match `query`, best match first
+    filtered: Vec<usize>,
+    pub selected: usize,
+}
+
+impl SnippetPicker {
+    pub fn new(mut snippets: Vec<Snippet>) -> Self {
+        snippets.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut picker = Self {
+            snippets,
+            query: String::new(),
+            filtered: Vec::new(),
+            selected: 0,
+        };
+        picker.refilter();
+        picker
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.query.push(c);
+        self.refilter();
+    }
+
+    pub fn backspace(&mut self) {
+        self.query.pop();
+        self.refilter();
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = (self.selected + 1) % self.filtered.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = (self.selected + self.filtered.len() - 1) % self.filtered.len();
+        }
+    }
+
+    /// Snippets matching the query, in display order
+    pub fn visible(&self) -> impl Iterator<Item = &Snippet> {
+        self.filtered.iter().map(|&i| &self.snippets[i])
+    }
+
+    /// Snippet under the cursor
+    pub fn selected_snippet(&self) -> Option<&Snippet> {
+        self.filtered.get(self.selected).map(|&i| &self.snippets[i])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snippets.is_empty()
+    }
+
+    /// Rank snippets by name against the query
+    fn refilter(&mut self) {
+        let query = self.query.to_lowercase();
+        let mut scored: Vec<(usize, usize)> = self
+            .snippets
+            .iter()
+            .enumerate()
+            .filter_map(|(i, snippet)| {
+                match_score(&snippet.name.to_lowercase(), &query).map(|score| (score, i))
+            })
+            .collect();
+        scored.sort();
+        self.filtered = scored.into_iter().map(|(_, i)| i).collect();
+        self.selected = 0;
+    }
+}
+
+/// Render the picker with a preview of the highlighted snippet's body
+pub fn render_snippet_picker(frame: &mut Frame, picker: &SnippetPicker) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(80);
+    let height = screen.height.saturating_sub(4).min(24);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 4,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(" Snippets ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(3),
+            Constraint::Length(7),
+        ])
+        .split(inner);
+
+    let input = Paragraph::new(Line::from(vec![
+        Span::styled("> ", Style::default().fg(Color::Yellow)),
+        Span::raw(picker.query.as_str()),
+        Span::styled("█", Style::default().fg(Color::Gray)),
+    ]))
+    .block(Block::default().borders(Borders::BOTTOM));
+    frame.render_widget(input, chunks[0]);
+
+    if picker.is_empty() {
+        frame.render_widget(
+            Paragraph::new(format!(
+                "No snippets yet. Select text and run :snip add <name>, or edit {}",
+                SnippetStore::default_path().display()
+            ))
+            .style(Style::default().fg(Color::DarkGray)),
+            chunks[1],
+        );
+        return;
+    }
+
+    let items: Vec<ListItem> = picker
+        .visible()
+        .map(|snippet| {
+            ListItem::new(Line::from(vec![
+                Span::styled(snippet.name.clone(), Style::default().fg(Color::White)),
+                Span::raw("  "),
+                Span::styled(
+                    snippet.description.clone(),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]))
+        })
+        .collect();
+
+    if items.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No matching snippets").style(Style::default().fg(Color::DarkGray)),
+            chunks[1],
+        );
+        return;
+    }
+
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut list_state = ListState::default();
+    list_state.select(Some(picker.selected));
+    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+
+    if let Some(snippet) = picker.selected_snippet() {
+        let preview: Vec<Line> = snippet
+            .body
+            .lines()
+            .map(|line| Line::styled(line.to_string(), Style::default().fg(Color::Gray)))
+            .collect();
+        frame.render_widget(
+            Paragraph::new(preview).block(Block::default().borders(Borders::TOP)),
+            chunks[2],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippet(name: &str) -> Snippet {
+        Snippet {
+            name: name.to_string(),
+            description: String::new(),
+            body: format!("-- {name}"),
+        }
+    }
+
+    #[test]
+    fn test_filters_by_name() {
+        let mut picker = SnippetPicker::new(vec![
+            snippet("window-rank"),
+            snippet("cte"),
+            snippet("explain-analyze"),
+        ]);
+        let names: Vec<&str> = picker.visible().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["cte", "explain-analyze", "window-rank"]);
+
+        for c in "wr".chars() {
+            picker.insert_char(c);
+        }
+        assert_eq!(picker.selected_snippet().unwrap().name, "window-rank");
+
+        picker.insert_char('z');
+        assert!(picker.selected_snippet().is_none());
+    }
+}
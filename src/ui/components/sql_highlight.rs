@@ -0,0 +1,515 @@
+// FilePath: src/ui/components/sql_highlight.rs
+
+#![forbid(unsafe_code)]
+
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+use std::ops::Range;
+
+/// Words coloured as keywords (matched case-insensitively)
+const KEYWORDS: &[&str] = &[
+    "ADD",
+    "ALL",
+    "ALTER",
+    "AND",
+    "ANY",
+    "AS",
+    "ASC",
+    "BEGIN",
+    "BETWEEN",
+    "BY",
+    "CASCADE",
+    "CASE",
+    "CHECK",
+    "COLUMN",
+    "COMMIT",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "DATABASE",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "DROP",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "EXISTS",
+    "EXPLAIN",
+    "FALSE",
+    "FOREIGN",
+    "FROM",
+    "FULL",
+    "FUNCTION",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "IF",
+    "ILIKE",
+    "IN",
+    "INDEX",
+    "INNER",
+    "INSERT",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "KEY",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "MATERIALIZED",
+    "NOT",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVER",
+    "PARTITION",
+    "PRIMARY",
+    "REFERENCES",
+    "RETURNING",
+    "REVOKE",
+    "RIGHT",
+    "ROLLBACK",
+    "SCHEMA",
+    "SELECT",
+    "SET",
+    "TABLE",
+    "THEN",
+    "TRANSACTION",
+    "TRIGGER",
+    "TRUE",
+    "TRUNCATE",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "VIEW",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+/// Kind of a lexical token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Keyword,
+    /// Name followed by `(`
+    Function,
+    /// Bare, `"double"` or `` `backtick` `` quoted name
+    Identifier,
+    String,
+    Number,
+    Comment,
+    Operator,
+    /// Whitespace and `( ) , ; .`
+    Plain,
+}
+
+/// A token as a byte range of its line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub range: Range<usize>,
+}
+
+/// Lexer state carried from the end of one line to the start of the next
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineState {
+    #[default]
+    Normal,
+    /// Inside a literal opened by this quote character
+    Quoted(char),
+    /// Inside `/* */`, at this nesting depth
+    BlockComment(u32),
+}
+
+/// Split one line into tokens, starting in `state`; returns the state the
+/// next line starts in
+pub fn tokenize_line(line: &str, mut state: LineState) -> (Vec<Token>, LineState) {
+    let bytes = line.as_bytes();
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut push = |kind: TokenKind, range: Range<usize>| {
+        if range.is_empty() {
+            return;
+        }
+        match tokens.last_mut() {
+            Some(last) if last.kind == kind && last.range.end == range.start => {
+                last.range.end = range.end
+            }
+            _ => tokens.push(Token { kind, range }),
+        }
+    };
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        match state {
+            LineState::Quoted(quote) => {
+                let (end, closed) = scan_quoted(bytes, i, quote as u8);
+                i = end;
+                push(quote_kind(quote), start..end);
+                if closed {
+                    state = LineState::Normal;
+                }
+                continue;
+            }
+            LineState::BlockComment(depth) => {
+                let (end, depth) = scan_block_comment(bytes, i, depth);
+                i = end;
+                push(TokenKind::Comment, start..end);
+                state = if depth == 0 {
+                    LineState::Normal
+                } else {
+                    LineState::BlockComment(depth)
+                };
+                continue;
+            }
+            LineState::Normal => {}
+        }
+
+        let c = bytes[i];
+        let next = bytes.get(i + 1).copied();
+        if c == b'-' && next == Some(b'-') {
+            push(TokenKind::Comment, i..bytes.len());
+            break;
+        } else if c == b'/' && next == Some(b'*') {
+            state = LineState::BlockComment(1);
+            push(TokenKind::Comment, i..i + 2);
+            i += 2;
+        } else if matches!(c, b'\'' | b'"' | b'`') {
+            state = LineState::Quoted(c as char);
+            push(quote_kind(c as char), i..i + 1);
+            i += 1;
+        } else if c.is_ascii_digit() || (c == b'.' && next.is_some_and(|n| n.is_ascii_digit())) {
+            i = scan_number(bytes, i);
+            push(TokenKind::Number, start..i);
+        } else if c.is_ascii_alphabetic() || c == b'_' || !c.is_ascii() {
+            while i < bytes.len()
+                && (bytes[i].is_ascii_alphanumeric()
+                    || matches!(bytes[i], b'_' | b'$')
+                    || !bytes[i].is_ascii())
+            {
+                i += 1;
+            }
+            let word = &line[start..i];
+            let kind = if KEYWORDS.iter().any(|k| k.eq_ignore_ascii_case(word)) {
+                TokenKind::Keyword
+            } else if line[i..].trim_start().starts_with('(') {
+                TokenKind::Function
+            } else {
+                TokenKind::Identifier
+            };
+            push(kind, start..i);
+        } else if b"=<>!+-*/%|&^~:@#?".contains(&c) {
+            i += 1;
+            push(TokenKind::Operator, start..i);
+        } else {
+            i += 1;
+            push(TokenKind::Plain, start..i);
+        }
+    }
+
+    (tokens, state)
+}
+
+fn quote_kind(quote: char) -> TokenKind {
+    if quote == '\'' {
+        TokenKind::String
+    } else {
+        TokenKind::Identifier
+    }
+}
+
+/// Scan to the closing `quote`, honouring doubled quotes and backslash
+/// escapes; returns the end offset and whether the literal was closed
+fn scan_quoted(bytes: &[u8], mut i: usize, quote: u8) -> (usize, bool) {
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if quote == b'\'' => i += 2,
+            c if c == quote => {
+                if bytes.get(i + 1) == Some(&quote) {
+                    i += 2;
+                } else {
+                    return (i + 1, true);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    (bytes.len(), false)
+}
+
+/// Scan nested `/* */` comments; returns the end offset and remaining depth
+fn scan_block_comment(bytes: &[u8], mut i: usize, mut depth: u32) -> (usize, u32) {
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return (i, 0);
+            }
+        } else if bytes[i..].starts_with(b"/*") {
+            depth += 1;
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    (bytes.len(), depth)
+}
+
+fn scan_number(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+        i += 1;
+    }
+    if i < bytes.len() && matches!(bytes[i], b'e' | b'E') {
+        let mut j = i + 1;
+        if j < bytes.len() && matches!(bytes[j], b'+' | b'-') {
+            j += 1;
+        }
+        if j < bytes.len() && bytes[j].is_ascii_digit() {
+            i = j;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+    }
+    i
+}
+
+/// Theme colours for each token kind
+#[derive(Debug, Clone, Default)]
+pub struct SyntaxStyles {
+    keyword: Style,
+    function: Style,
+    identifier: Style,
+    string: Style,
+    number: Style,
+    comment: Style,
+    operator: Style,
+}
+
+impl SyntaxStyles {
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            keyword: Style::default()
+                .fg(theme.get_color("syntax_keyword"))
+                .add_modifier(Modifier::BOLD),
+            function: Style::default().fg(theme.get_color("syntax_function")),
+            identifier: Style::default().fg(theme.get_color("text")),
+            string: Style::default().fg(theme.get_color("syntax_string")),
+            number: Style::default().fg(theme.get_color("syntax_number")),
+            comment: Style::default()
+                .fg(theme.get_color("syntax_comment"))
+                .add_modifier(Modifier::ITALIC),
+            operator: Style::default().fg(theme.get_color("syntax_operator")),
+        }
+    }
+
+    pub fn style(&self, kind: TokenKind) -> Style {
+        match kind {
+            TokenKind::Keyword => self.keyword,
+            TokenKind::Function => self.function,
+            TokenKind::Identifier | TokenKind::Plain => self.identifier,
+            TokenKind::String => self.string,
+            TokenKind::Number => self.number,
+            TokenKind::Comment => self.comment,
+            TokenKind::Operator => self.operator,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedLine {
+    text: String,
+    start: LineState,
+    tokens: Vec<Token>,
+    end: LineState,
+}
+
+/// Per-line token cache. A line is re-tokenized only when its text or the
+/// state it starts in changed, so an edit re-lexes from the edited line down
+/// until the lexer state settles again.
+#[derive(Debug, Clone, Default)]
+pub struct HighlightCache {
+    lines: Vec<CachedLine>,
+}
+
+impl HighlightCache {
+    /// Bring the cache up to date with `lines`; returns how many lines were re-tokenized
+    pub fn update(&mut self, lines: &[&str]) -> usize {
+        let mut state = LineState::Normal;
+        let mut retokenized = 0;
+        for (index, text) in lines.iter().enumerate() {
+            let fresh = self
+                .lines
+                .get(index)
+                .is_some_and(|cached| cached.start == state && cached.text == *text);
+            if !fresh {
+                let (tokens, end) = tokenize_line(text, state);
+                let line = CachedLine {
+                    text: text.to_string(),
+                    start: state,
+                    tokens,
+                    end,
+                };
+                if index < self.lines.len() {
+                    self.lines[index] = line;
+                } else {
+                    self.lines.push(line);
+                }
+                retokenized += 1;
+            }
+            state = self.lines[index].end;
+        }
+        self.lines.truncate(lines.len());
+        retokenized
+    }
+
+    /// Tokens of line `index` as of the last `update`
+    pub fn tokens(&self, index: usize) -> &[Token] {
+        self.lines
+            .get(index)
+            .map(|line| line.tokens.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// Highlight a read-only SQL text, such as a generated DDL preview, in theme colours
+pub fn highlight_sql_text(sql: &str, styles: &SyntaxStyles) -> Vec<Line<'static>> {
+    let mut state = LineState::Normal;
+    sql.lines()
+        .map(|line| {
+            let (tokens, end) = tokenize_line(line, state);
+            state = end;
+            Line::from(
+                tokens
+                    .into_iter()
+                    .map(|token| {
+                        Span::styled(line[token.range].to_string(), styles.style(token.kind))
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(line: &str, state: LineState) -> Vec<(TokenKind, &str)> {
+        tokenize_line(line, state)
+            .0
+            .into_iter()
+            .map(|t| (t.kind, &line[t.range]))
+            .filter(|(kind, _)| *kind != TokenKind::Plain)
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize_basic_statement() {
+        assert_eq!(
+            kinds("select count(*) from t where x >= 1.5e3", LineState::Normal),
+            vec![
+                (TokenKind::Keyword, "select"),
+                (TokenKind::Function, "count"),
+                (TokenKind::Operator, "*"),
+                (TokenKind::Keyword, "from"),
+                (TokenKind::Identifier, "t"),
+                (TokenKind::Keyword, "where"),
+                (TokenKind::Identifier, "x"),
+                (TokenKind::Operator, ">="),
+                (TokenKind::Number, "1.5e3"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dashes_and_quotes_inside_strings() {
+        // `--` inside a literal is not a comment; doubled and escaped quotes stay inside
+        assert_eq!(
+            kinds(
+                "SELECT 'a -- b', 'it''s', 'x\\'y' -- done",
+                LineState::Normal
+            ),
+            vec![
+                (TokenKind::Keyword, "SELECT"),
+                (TokenKind::String, "'a -- b'"),
+                (TokenKind::String, "'it''s'"),
+                (TokenKind::String, "'x\\'y'"),
+                (TokenKind::Comment, "-- done"),
+            ]
+        );
+        assert_eq!(
+            kinds(r#""say ""hi"" -- not a comment""#, LineState::Normal),
+            vec![(TokenKind::Identifier, r#""say ""hi"" -- not a comment""#)]
+        );
+    }
+
+    #[test]
+    fn test_multiline_string_and_nested_comment() {
+        let (_, state) = tokenize_line("SELECT 'first", LineState::Normal);
+        assert_eq!(state, LineState::Quoted('\''));
+        assert_eq!(
+            kinds("line' FROM t", state),
+            vec![
+                (TokenKind::String, "line'"),
+                (TokenKind::Keyword, "FROM"),
+                (TokenKind::Identifier, "t"),
+            ]
+        );
+
+        let (_, state) = tokenize_line("/* outer /* inner */", LineState::Normal);
+        assert_eq!(state, LineState::BlockComment(1));
+        let (_, state) = tokenize_line("still */ SELECT", state);
+        assert_eq!(state, LineState::Normal);
+    }
+
+    #[test]
+    fn test_cache_retokenizes_from_edit_down() {
+        let mut cache = HighlightCache::default();
+        let lines = ["SELECT 1;", "SELECT 2;", "SELECT 3;"];
+        assert_eq!(cache.update(&lines), 3);
+        assert_eq!(cache.update(&lines), 0);
+
+        // Editing the last line only touches that line
+        assert_eq!(cache.update(&["SELECT 1;", "SELECT 2;", "SELECT 4;"]), 1);
+
+        // Opening a comment changes the state every following line starts in
+        assert_eq!(cache.update(&["SELECT 1; /*", "SELECT 2;", "SELECT 4;"]), 3);
+        assert_eq!(cache.tokens(2)[0].kind, TokenKind::Comment);
+    }
+
+    #[test]
+    fn test_highlight_sql_text_keeps_text_and_styles_keywords() {
+        let styles = SyntaxStyles {
+            keyword: Style::default().add_modifier(Modifier::BOLD),
+            ..SyntaxStyles::default()
+        };
+        let sql = "CREATE TABLE \"users\" (\n    \"id\" SERIAL PRIMARY KEY\n)";
+        let lines = highlight_sql_text(sql, &styles);
+        let text: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(text, sql.lines().collect::<Vec<_>>());
+        assert_eq!(lines[0].spans[0].content, "CREATE");
+        assert_eq!(lines[0].spans[0].style, styles.keyword);
+    }
+}
@@ -5,6 +5,12 @@
 use crate::database::DatabaseType;
 use std::collections::HashMap;
 
+/// Minimum number of typed characters before suggestions are offered
+pub const MIN_PARTIAL_LEN: usize = 2;
+
+/// Maximum number of suggestions returned to the popup
+pub const MAX_SUGGESTIONS: usize = 10;
+
 #[derive(Debug, Clone)]
 pub struct SqlSuggestion {
     /// The text to insert
@@ -190,8 +196,14 @@ impl SqlSuggestionEngine {
         cursor_line: usize,
         cursor_column: usize,
     ) -> Vec<SqlSuggestion> {
-        let context = self.analyze_context(sql_content, cursor_line, cursor_column);
         let partial_word = self.get_partial_word_at_cursor(sql_content, cursor_line, cursor_column);
+        if partial_word.chars().count() < MIN_PARTIAL_LEN
+            || is_in_string_or_comment(sql_content, cursor_line, cursor_column)
+        {
+            return Vec::new();
+        }
+
+        let context = self.analyze_context(sql_content, cursor_line, cursor_column);
 
         let mut suggestions = Vec::new();
 
@@ -253,6 +265,10 @@ impl SqlSuggestionEngine {
             }
         }
 
+        // Ignore the word being typed so it doesn't mask the clause keyword
+        let text_before_cursor =
+            text_before_cursor.trim_end_matches(|c: char| c.is_alphanumeric() || c == '_');
+
         // Normalize and analyze
         let normalized = text_before_cursor.to_uppercase();
         let tokens: Vec<&str> = normalized.split_whitespace().collect();
@@ -359,11 +375,7 @@ impl SqlSuggestionEngine {
 
         statement_keywords
             .iter()
-            .filter(|&keyword| {
-                keyword
-                    .to_lowercase()
-                    .starts_with(&partial_word.to_lowercase())
-            })
+            .filter(|&keyword| matches_partial(keyword, partial_word))
             .map(|&keyword| SqlSuggestion {
                 text: keyword.to_string(),
                 display: keyword.to_string(),
@@ -377,11 +389,7 @@ impl SqlSuggestionEngine {
     fn get_table_suggestions(&self, partial_word: &str) -> Vec<SqlSuggestion> {
         self.tables
             .iter()
-            .filter(|table| {
-                table
-                    .to_lowercase()
-                    .starts_with(&partial_word.to_lowercase())
-            })
+            .filter(|table| matches_partial(table, partial_word))
             .map(|table| SqlSuggestion {
                 text: table.clone(),
                 display: table.clone(),
@@ -398,10 +406,7 @@ impl SqlSuggestionEngine {
         // Add columns from all available tables
         for (table, columns) in &self.table_columns {
             for column in columns {
-                if column
-                    .to_lowercase()
-                    .starts_with(&partial_word.to_lowercase())
-                {
+                if matches_partial(column, partial_word) {
                     suggestions.push(SqlSuggestion {
                         text: column.clone(),
                         display: format!("{} ({})", column, table),
@@ -419,10 +424,7 @@ impl SqlSuggestionEngine {
     fn get_function_suggestions(&self, partial_word: &str) -> Vec<SqlSuggestion> {
         self.functions
             .iter()
-            .filter(|&func| {
-                func.to_lowercase()
-                    .starts_with(&partial_word.to_lowercase())
-            })
+            .filter(|&func| matches_partial(func, partial_word))
             .map(|&func| SqlSuggestion {
                 text: format!("{}()", func),
                 display: format!("{}()", func),
@@ -436,11 +438,7 @@ impl SqlSuggestionEngine {
     fn get_keyword_suggestions(&self, keywords: &[&str], partial_word: &str) -> Vec<SqlSuggestion> {
         keywords
             .iter()
-            .filter(|&keyword| {
-                keyword
-                    .to_lowercase()
-                    .starts_with(&partial_word.to_lowercase())
-            })
+            .filter(|&keyword| matches_partial(keyword, partial_word))
             .map(|&keyword| SqlSuggestion {
                 text: keyword.to_string(),
                 display: keyword.to_string(),
@@ -462,33 +460,25 @@ impl SqlSuggestionEngine {
         suggestions
     }
 
-    /// Filter and sort suggestions based on relevance
+    /// Sort suggestions by relevance: prefix matches first, then fuzzy matches
     fn filter_and_sort_suggestions(
         &self,
         mut suggestions: Vec<SqlSuggestion>,
         partial_word: &str,
     ) -> Vec<SqlSuggestion> {
-        if partial_word.is_empty() {
-            suggestions.sort_by(|a, b| a.display.cmp(&b.display));
-            suggestions.truncate(20); // Limit to prevent overwhelming UI
-            return suggestions;
-        }
-
         let partial_lower = partial_word.to_lowercase();
 
-        // Sort by relevance: exact prefix match first, then contains match
         suggestions.sort_by(|a, b| {
-            let a_exact = a.display.to_lowercase().starts_with(&partial_lower);
-            let b_exact = b.display.to_lowercase().starts_with(&partial_lower);
+            let a_prefix = a.text.to_lowercase().starts_with(&partial_lower);
+            let b_prefix = b.text.to_lowercase().starts_with(&partial_lower);
 
-            match (a_exact, b_exact) {
-                (true, false) => std::cmp::Ordering::Less,
-                (false, true) => std::cmp::Ordering::Greater,
-                _ => a.display.cmp(&b.display),
-            }
+            b_prefix
+                .cmp(&a_prefix)
+                .then_with(|| a.text.len().cmp(&b.text.len()))
+                .then_with(|| a.display.cmp(&b.display))
         });
 
-        suggestions.truncate(20);
+        suggestions.truncate(MAX_SUGGESTIONS);
         suggestions
     }
 
@@ -525,6 +515,77 @@ impl SqlSuggestionEngine {
     }
 }
 
+/// Check whether a candidate matches the typed word, either as a
+/// case-insensitive prefix or as a fuzzy subsequence
+fn matches_partial(candidate: &str, partial_word: &str) -> bool {
+    let candidate = candidate.to_lowercase();
+    let partial = partial_word.to_lowercase();
+
+    if candidate.starts_with(&partial) {
+        return true;
+    }
+
+    let mut candidate_chars = candidate.chars();
+    partial
+        .chars()
+        .all(|p| candidate_chars.by_ref().any(|c| c == p))
+}
+
+/// Check whether the cursor sits inside a string literal or a comment
+pub fn is_in_string_or_comment(
+    sql_content: &str,
+    cursor_line: usize,
+    cursor_column: usize,
+) -> bool {
+    let mut in_string = false;
+    let mut in_block_comment = false;
+
+    for (line_idx, line) in sql_content.lines().enumerate() {
+        if line_idx > cursor_line {
+            break;
+        }
+
+        let end = if line_idx == cursor_line {
+            cursor_column.min(line.len())
+        } else {
+            line.len()
+        };
+        let chars: Vec<char> = line[..end].chars().collect();
+
+        let mut i = 0;
+        while i < chars.len() {
+            let ch = chars[i];
+            let next = chars.get(i + 1).copied();
+
+            if in_block_comment {
+                if ch == '*' && next == Some('/') {
+                    in_block_comment = false;
+                    i += 1;
+                }
+            } else if in_string {
+                if ch == '\'' {
+                    in_string = false;
+                }
+            } else if ch == '\'' {
+                in_string = true;
+            } else if ch == '-' && next == Some('-') {
+                // Line comment runs to the end of the line
+                if line_idx == cursor_line {
+                    return true;
+                }
+                break;
+            } else if ch == '/' && next == Some('*') {
+                in_block_comment = true;
+                i += 1;
+            }
+
+            i += 1;
+        }
+    }
+
+    in_string || in_block_comment
+}
+
 impl Default for SqlSuggestionEngine {
     fn default() -> Self {
         Self::new()
@@ -567,4 +628,52 @@ mod tests {
         let partial = engine.get_partial_word_at_cursor("FROM user_ta", 0, 12);
         assert_eq!(partial, "user_ta");
     }
+
+    #[test]
+    fn test_requires_two_characters() {
+        let engine = SqlSuggestionEngine::new();
+        assert!(engine.get_suggestions("S", 0, 1).is_empty());
+        assert!(!engine.get_suggestions("SE", 0, 2).is_empty());
+    }
+
+    #[test]
+    fn test_prefix_matches_rank_before_fuzzy() {
+        let mut engine = SqlSuggestionEngine::new();
+        engine.set_tables(vec![
+            "user_roles".to_string(),
+            "users".to_string(),
+            "audit_users".to_string(),
+        ]);
+
+        let suggestions = engine.get_suggestions("SELECT * FROM usr", 0, 17);
+        let texts: Vec<&str> = suggestions.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["users", "user_roles", "audit_users"]);
+
+        let suggestions = engine.get_suggestions("SELECT * FROM us", 0, 16);
+        assert_eq!(suggestions[0].text, "users");
+        assert_eq!(suggestions.last().unwrap().text, "audit_users");
+    }
+
+    #[test]
+    fn test_suggestions_limited() {
+        let mut engine = SqlSuggestionEngine::new();
+        engine.set_tables((0..30).map(|i| format!("table_{i}")).collect());
+
+        let suggestions = engine.get_suggestions("SELECT * FROM ta", 0, 16);
+        assert_eq!(suggestions.len(), MAX_SUGGESTIONS);
+    }
+
+    #[test]
+    fn test_no_suggestions_in_strings_or_comments() {
+        let engine = SqlSuggestionEngine::new();
+
+        assert!(engine
+            .get_suggestions("SELECT * FROM t WHERE name = 'sel", 0, 33)
+            .is_empty());
+        assert!(engine.get_suggestions("-- sel", 0, 6).is_empty());
+        assert!(engine.get_suggestions("/* multi\nsel", 1, 3).is_empty());
+        assert!(!engine
+            .get_suggestions("SELECT 'a' AS x, co", 0, 19)
+            .is_empty());
+    }
 }
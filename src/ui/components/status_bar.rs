@@ -0,0 +1,123 @@
+// FilePath: src/ui/components/status_bar.rs
+
+#![forbid(unsafe_code)]
+
+use ratatui::text::{Line, Span};
+
+/// Columns kept free between slots
+const SLOT_GAP: usize = 1;
+
+/// Separator between modules within a slot
+pub const MODULE_SEPARATOR: &str = " | ";
+
+fn spans_width(spans: &[Span]) -> usize {
+    spans.iter().map(|span| span.content.chars().count()).sum()
+}
+
+/// Cut `spans` to `width` characters, ending in `…` when anything was dropped
+pub fn truncate_spans(spans: Vec<Span<'static>>, width: usize) -> Vec<Span<'static>> {
+    if spans_width(&spans) <= width {
+        return spans;
+    }
+    let mut left = width.saturating_sub(1);
+    let mut cut = Vec::new();
+    let mut style = None;
+    for span in spans {
+        if left == 0 {
+            style.get_or_insert(span.style);
+            break;
+        }
+        let len = span.content.chars().count();
+        style = Some(span.style);
+        if len <= left {
+            left -= len;
+            cut.push(span);
+        } else {
+            let text: String = span.content.chars().take(left).collect();
+            cut.push(Span::styled(text, span.style));
+            left = 0;
+        }
+    }
+    if width > 0 {
+        cut.push(Span::styled("…", style.unwrap_or_default()));
+    }
+    cut
+}
+
+/// Lay the three slots out in `width` columns: the center slot sits in the
+/// middle of the bar when there is room, and is cut first when there isn't;
+/// then the left slot is cut. The right slot is only cut when it alone is
+/// wider than the bar.
+pub fn compose_status_line(
+    width: usize,
+    left: Vec<Span<'static>>,
+    center: Vec<Span<'static>>,
+    right: Vec<Span<'static>>,
+) -> Line<'static> {
+    let right = truncate_spans(right, width);
+    let right_width = spans_width(&right);
+    let room = width.saturating_sub(right_width + if right_width > 0 { SLOT_GAP } else { 0 });
+
+    let left = truncate_spans(left, room);
+    let left_width = spans_width(&left);
+    let center_room = room.saturating_sub(left_width + if left_width > 0 { SLOT_GAP } else { 0 });
+    let center = truncate_spans(center, center_room);
+    let center_width = spans_width(&center);
+
+    // Centered on the whole bar, pushed aside by the left and right slots
+    let earliest = left_width + if left_width > 0 { SLOT_GAP } else { 0 };
+    let latest = room.saturating_sub(center_width).max(earliest);
+    let center_start = (width.saturating_sub(center_width) / 2).clamp(earliest, latest);
+
+    let mut spans = left;
+    if center_width > 0 {
+        spans.push(Span::raw(" ".repeat(center_start - left_width)));
+        spans.extend(center);
+    }
+    let used = if center_width > 0 {
+        center_start + center_width
+    } else {
+        left_width
+    };
+    spans.push(Span::raw(
+        " ".repeat(width.saturating_sub(used + right_width)),
+    ));
+    spans.extend(right);
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    fn slot(text: &str) -> Vec<Span<'static>> {
+        vec![Span::raw(text.to_string())]
+    }
+
+    #[test]
+    fn test_center_slot_is_cut_before_left() {
+        let line = compose_status_line(20, slot("left"), slot("mid"), slot("right"));
+        assert_eq!(text(&line), "left    mid    right");
+
+        // The center gives way first...
+        let line = compose_status_line(20, slot("left side"), slot("middle"), slot("right"));
+        assert_eq!(text(&line), "left side mid… right");
+
+        // ...then the left slot; the right slot stays whole
+        let line = compose_status_line(12, slot("left side"), slot("middle"), slot("right"));
+        assert_eq!(text(&line), "left … right");
+
+        let line = compose_status_line(4, slot("left"), Vec::new(), slot("right"));
+        assert_eq!(text(&line), "rig…");
+
+        let line = compose_status_line(10, slot("abc"), Vec::new(), Vec::new());
+        assert_eq!(text(&line), format!("abc{}", " ".repeat(7)));
+    }
+}
@@ -0,0 +1,158 @@
+// FilePath: src/ui/components/tab_watch.rs
+
+#![forbid(unsafe_code)]
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Interval offered when `W` opens the prompt, in seconds
+pub const DEFAULT_WATCH_INTERVAL: &str = "5";
+
+/// Shortest interval a watch accepts
+pub const MIN_WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Consecutive failed refreshes that stop a watch
+pub const MAX_WATCH_FAILURES: u32 = 3;
+
+static NEXT_WATCH_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Statement and connection that filled a query result tab
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchedQuery {
+    pub connection_id: String,
+    pub query: String,
+}
+
+/// What a watched tab reloads
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchSource {
+    /// The tab's table, through the normal page load
+    Table,
+    /// The statement a query result tab came from, run again
+    Query(WatchedQuery),
+}
+
+/// How a failed refresh was taken
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchFailure {
+    /// First failure in a row; worth a toast
+    Report,
+    /// Another failure in the same streak; already reported
+    Quiet,
+    /// Too many failures in a row; the watch is over
+    Stop,
+}
+
+/// Auto-refresh of a table viewer tab, toggled with `W` and driven from `tick`
+#[derive(Debug, Clone)]
+pub struct TabWatch {
+    /// Matches finished background refreshes to their tab
+    pub id: u64,
+    pub interval: Duration,
+    pub source: WatchSource,
+    /// When the last refresh started
+    last_refresh: Instant,
+    /// A refresh is running; due refreshes are skipped until it finishes
+    pub in_flight: bool,
+    /// Consecutive failed refreshes
+    pub failures: u32,
+}
+
+impl TabWatch {
+    /// Start watching; the first refresh comes one interval from now
+    pub fn new(interval: Duration, source: WatchSource) -> Self {
+        Self {
+            id: NEXT_WATCH_ID.fetch_add(1, Ordering::Relaxed),
+            interval,
+            source,
+            last_refresh: Instant::now(),
+            in_flight: false,
+            failures: 0,
+        }
+    }
+
+    /// Whether a refresh should start at `now`
+    pub fn is_due(&self, now: Instant) -> bool {
+        !self.in_flight && now.duration_since(self.last_refresh) >= self.interval
+    }
+
+    /// Mark a refresh as started at `now`
+    pub fn begin(&mut self, now: Instant) {
+        self.in_flight = true;
+        self.last_refresh = now;
+    }
+
+    pub fn succeed(&mut self) {
+        self.in_flight = false;
+        self.failures = 0;
+    }
+
+    pub fn fail(&mut self) -> WatchFailure {
+        self.in_flight = false;
+        self.failures += 1;
+        if self.failures >= MAX_WATCH_FAILURES {
+            WatchFailure::Stop
+        } else if self.failures == 1 {
+            WatchFailure::Report
+        } else {
+            WatchFailure::Quiet
+        }
+    }
+
+    /// Tab strip indicator, e.g. `⟳ 5s`
+    pub fn label(&self) -> String {
+        if self.interval.subsec_millis() == 0 {
+            format!("⟳ {}s", self.interval.as_secs())
+        } else {
+            format!("⟳ {:.1}s", self.interval.as_secs_f64())
+        }
+    }
+}
+
+/// Parse the watch prompt: seconds, optionally fractional or with an `s`
+/// suffix. Anything under a second is rejected.
+pub fn parse_watch_interval(input: &str) -> Result<Duration, String> {
+    let input = input.trim();
+    let number = input.strip_suffix('s').unwrap_or(input).trim();
+    let seconds: f64 = number
+        .parse()
+        .map_err(|_| format!("Invalid interval '{input}' - enter a number of seconds"))?;
+    if !seconds.is_finite() || seconds < MIN_WATCH_INTERVAL.as_secs_f64() {
+        return Err("Watch interval must be at least 1s".to_string());
+    }
+    Ok(Duration::from_secs_f64(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_watch_interval() {
+        assert_eq!(parse_watch_interval("5"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_watch_interval(" 2s "), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_watch_interval("1.5"), Ok(Duration::from_millis(1500)));
+        assert!(parse_watch_interval("0.5").is_err());
+        assert!(parse_watch_interval("").is_err());
+        assert!(parse_watch_interval("soon").is_err());
+    }
+
+    #[test]
+    fn test_skips_while_in_flight_and_stops_after_failures() {
+        let mut watch = TabWatch::new(Duration::from_secs(5), WatchSource::Table);
+        let start = Instant::now();
+        assert_eq!(watch.label(), "⟳ 5s");
+        assert!(watch.is_due(start + Duration::from_secs(5)));
+
+        watch.begin(start + Duration::from_secs(5));
+        assert!(!watch.is_due(start + Duration::from_secs(30)));
+
+        assert_eq!(watch.fail(), WatchFailure::Report);
+        assert!(watch.is_due(start + Duration::from_secs(10)));
+        assert_eq!(watch.fail(), WatchFailure::Quiet);
+        watch.succeed();
+        assert_eq!(watch.fail(), WatchFailure::Report);
+        assert_eq!(watch.fail(), WatchFailure::Quiet);
+        assert_eq!(watch.fail(), WatchFailure::Stop);
+    }
+}
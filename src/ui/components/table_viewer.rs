@@ -2,6 +2,19 @@
 
 #![forbid(unsafe_code)]
 
+use crate::database::{DataType, DatabaseObjectType, DatabaseType, TableMetadata};
+use crate::ui::components::cell_editor::{render_cell_editor, CellEditor, CellEditorKind};
+use crate::ui::components::cell_view::{render_cell_view, CellView};
+use crate::ui::components::copy_format::{render_copy_format_menu, CopyFormat, CopyFormatMenu};
+use crate::ui::components::hex_view::{
+    hex_literal_bytes, parse_hex_literal, render_hex_view, HexView,
+};
+use crate::ui::components::json_view::{render_json_view, JsonView};
+use crate::ui::components::query_error::QueryError;
+use crate::ui::components::query_stats::QueryStats;
+use crate::ui::components::result_diff::{self, DiffKind, PinnedResult, ResultDiff};
+use crate::ui::components::tab_watch::{TabWatch, WatchSource, WatchedQuery};
+use crate::ui::components::undo_stack::UndoStack;
 use crate::ui::theme::Theme;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -10,13 +23,47 @@ use ratatui::{
     widgets::{Block, Borders, Cell as TableCell, Clear, Paragraph, Row, Table, Tabs, Wrap},
     Frame,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+/// Widest a data column is drawn, in characters
+const MAX_COLUMN_WIDTH: usize = 30;
+
+/// Widest a column is auto-fitted to its content, in characters
+const MAX_AUTO_FIT_WIDTH: usize = 60;
+
+/// Narrowest and widest a column can be resized to with `<` and `>`
+const MIN_COLUMN_WIDTH: usize = 4;
+const MAX_RESIZED_COLUMN_WIDTH: usize = 200;
+
+/// Characters `<` and `>` narrow or widen the selected column by
+pub const COLUMN_RESIZE_STEP: usize = 2;
+
+/// Cell padding and separator around each column
+const COLUMN_SPACING: usize = 3;
+
+/// Table borders and row marker
+const TABLE_BORDER_PADDING: usize = 4;
+
+/// Line between frozen and scrolling columns, with its spacing
+const FROZEN_SEPARATOR_WIDTH: usize = 2;
 
 /// View mode for the table viewer
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TableViewMode {
     Data,
     Schema,
+    /// Read-only routine source
+    Source,
+    /// One record at a time, a `column: value` line per field (like `\G`)
+    Vertical,
+}
+
+impl TableViewMode {
+    /// Data and vertical views page through rows; the others scroll text
+    pub fn shows_rows(self) -> bool {
+        matches!(self, TableViewMode::Data | TableViewMode::Vertical)
+    }
 }
 
 /// Represents a single table tab
@@ -24,17 +71,37 @@ pub enum TableViewMode {
 pub struct TableTab {
     pub table_name: String,
     pub columns: Vec<ColumnInfo>,
-    pub rows: Vec<Vec<String>>,
+    /// Cell values; `None` is SQL NULL
+    pub rows: Vec<Vec<Option<String>>>,
     pub total_rows: usize,
     pub current_page: usize,
     pub rows_per_page: usize,
     pub selected_row: usize,
     pub selected_col: usize,
+    /// First visible column; only the columns from here that fit the viewport
+    /// are laid out and styled
     pub scroll_offset_x: usize,
     pub scroll_offset_y: usize,
-    pub modified_cells: HashMap<(usize, usize), String>,
+    /// Leftmost columns kept in view while scrolling right (`zf`)
+    pub frozen_columns: usize,
+    /// Size columns to the widest value on the loaded page (`=`)
+    pub auto_fit: bool,
+    /// Widths set with `<` and `>`, by column name; they win over auto-fit
+    pub width_overrides: HashMap<String, usize>,
+    /// Overrides are saved for this table and restored when it opens again
+    pub remember_widths: bool,
+    /// Absolute row numbers in a `#` column before the data (`#`)
+    pub show_row_numbers: bool,
+    /// Screen width of each column including spacing, computed once per data
+    /// load by `refresh_column_widths`
+    column_widths: Vec<usize>,
+    pub modified_cells: HashMap<(usize, usize), Option<String>>,
+    /// Full byte length of each cell cut to `max_cell_bytes` when loaded
+    pub truncated_cells: HashMap<(usize, usize), usize>,
     pub in_edit_mode: bool,
     pub edit_buffer: String,
+    /// Pending edit sets the cell to SQL NULL rather than the buffer text
+    pub edit_null: bool,
     pub primary_key_columns: Vec<usize>,
     pub loading: bool,
     pub error: Option<String>,
@@ -44,9 +111,33 @@ pub struct TableTab {
     pub in_search_mode: bool,
     pub view_mode: TableViewMode,
     pub table_metadata: Option<crate::database::TableMetadata>,
+    /// Per-row difference kinds when this tab shows a results diff
+    pub diff_kinds: Vec<DiffKind>,
+    /// Differences not shown because of the display cap
+    pub diff_hidden: usize,
+    /// Row deletes and cell updates that `u` can reverse
+    pub undo_stack: UndoStack,
+    /// Highlighted source lines for `TableViewMode::Source` tabs
+    pub source_lines: Vec<Line<'static>>,
+    /// Timing and row count of the query that filled this tab
+    pub query_stats: Option<QueryStats>,
+    /// Failed editor query this tab reports instead of rows
+    pub query_error: Option<QueryError>,
+    /// Kind of object this tab shows; views open read-only
+    pub object_type: DatabaseObjectType,
+    /// Statement a query result tab came from, run again by `r` and watch mode
+    pub origin: Option<WatchedQuery>,
+    /// Auto-refresh started with `W`
+    pub watch: Option<TabWatch>,
+    /// Interval being typed after `W`, in seconds
+    pub watch_prompt: Option<String>,
+    /// Column query results are sorted by with `s`, and whether ascending
+    pub sort: Option<(usize, bool)>,
+    /// Rows whose first cell is one of these stand out in `primary_highlight`
+    pub highlighted_names: &'static [&'static str],
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ColumnInfo {
     pub name: String,
     pub data_type: String,
@@ -68,9 +159,17 @@ impl TableTab {
             selected_col: 0,
             scroll_offset_x: 0,
             scroll_offset_y: 0,
+            frozen_columns: 0,
+            auto_fit: false,
+            width_overrides: HashMap::new(),
+            remember_widths: false,
+            show_row_numbers: false,
+            column_widths: Vec::new(),
             modified_cells: HashMap::new(),
+            truncated_cells: HashMap::new(),
             in_edit_mode: false,
             edit_buffer: String::new(),
+            edit_null: false,
             primary_key_columns: Vec::new(),
             loading: true,
             error: None,
@@ -80,6 +179,29 @@ impl TableTab {
             in_search_mode: false,
             view_mode: TableViewMode::Data,
             table_metadata: None,
+            diff_kinds: Vec::new(),
+            diff_hidden: 0,
+            undo_stack: UndoStack::new(),
+            source_lines: Vec::new(),
+            query_stats: None,
+            query_error: None,
+            object_type: DatabaseObjectType::Table,
+            origin: None,
+            watch: None,
+            watch_prompt: None,
+            sort: None,
+            highlighted_names: &[],
+        }
+    }
+
+    /// Toast shown when an edit is attempted on a read-only object
+    pub fn read_only_message(&self) -> Option<&'static str> {
+        match self.object_type {
+            DatabaseObjectType::View => Some("Views are read-only"),
+            DatabaseObjectType::MaterializedView => {
+                Some("Materialized views are read-only - press R in Details to refresh")
+            }
+            _ => None,
         }
     }
 
@@ -87,21 +209,210 @@ impl TableTab {
     pub fn toggle_view_mode(&mut self) {
         self.view_mode = match self.view_mode {
             TableViewMode::Data => TableViewMode::Schema,
-            TableViewMode::Schema => TableViewMode::Data,
+            TableViewMode::Schema | TableViewMode::Vertical => TableViewMode::Data,
+            TableViewMode::Source => return,
         };
         // Reset selection when switching views
         self.selected_row = 0;
         self.selected_col = 0;
     }
 
-    /// Get the current cell value (including any modifications)
-    pub fn get_cell_value(&self, row: usize, col: usize) -> String {
+    /// Toggle between the grid and the vertical record view, keeping the
+    /// selected row. Returns false for schema and source tabs.
+    pub fn toggle_vertical_view(&mut self) -> bool {
+        self.view_mode = match self.view_mode {
+            TableViewMode::Data => TableViewMode::Vertical,
+            TableViewMode::Vertical => TableViewMode::Data,
+            TableViewMode::Schema | TableViewMode::Source => return false,
+        };
+        self.scroll_offset_y = 0;
+        self.ensure_selection_visible();
+        true
+    }
+
+    /// Fields of the selected record scrolled off the top of the vertical
+    /// view, so a record taller than the pane still shows the selected field
+    pub fn record_field_offset(&self, viewport_height: usize) -> usize {
+        // One line goes to the `*** row N ***` header
+        let fields = viewport_height.saturating_sub(1).max(1);
+        (self.selected_col + 1).saturating_sub(fields)
+    }
+
+    /// What `W` reloads: the statement behind a query result, or the table
+    /// itself. Diffs, errors, sources and script summaries can't be watched.
+    pub fn watch_source(&self) -> Option<WatchSource> {
+        if let Some(origin) = &self.origin {
+            return Some(WatchSource::Query(origin.clone()));
+        }
+        (self.view_mode != TableViewMode::Source && self.source_table().is_some())
+            .then_some(WatchSource::Table)
+    }
+
+    /// The table the rows were loaded from; None for query results, diffs
+    /// and errors
+    pub fn source_table(&self) -> Option<&str> {
+        let generated = ["Query Result (", "Run All (", "Diff ("]
+            .iter()
+            .any(|prefix| self.table_name.starts_with(prefix));
+        (self.origin.is_none()
+            && self.diff_kinds.is_empty()
+            && self.query_error.is_none()
+            && !generated)
+            .then_some(self.table_name.as_str())
+    }
+
+    /// Fill the tab with the columns and rows of an editor query
+    pub fn set_result_rows(
+        &mut self,
+        columns: &[String],
+        rows: Vec<Vec<Option<String>>>,
+        max_cell_bytes: usize,
+    ) {
+        self.columns = columns
+            .iter()
+            .enumerate()
+            .map(|(col, col_name)| ColumnInfo {
+                name: col_name.clone(),
+                // Query results carry no types; binary values are recognised
+                // by the hex literal every engine turns them into
+                data_type: if looks_binary(&rows, col) {
+                    DataType::Bytea.to_sql()
+                } else {
+                    "TEXT".to_string()
+                },
+                is_nullable: true,
+                is_primary_key: false,
+                max_display_width: col_name.len().clamp(10, 30),
+            })
+            .collect();
+        self.set_rows(rows, max_cell_bytes);
+        self.total_rows = self.rows.len();
+        self.loading = false;
+        // A re-run keeps the order and search picked on the previous rows
+        self.sort = self.sort.filter(|&(col, _)| col < self.columns.len());
+        self.apply_sort();
+        self.refresh_column_widths();
+        self.error = None;
+        self.clamp_selection();
+    }
+
+    /// Replace the rows, cutting values over `max_cell_bytes` so huge text
+    /// never reaches the renderer; their full lengths go to `truncated_cells`
+    pub fn set_rows(&mut self, mut rows: Vec<Vec<Option<String>>>, max_cell_bytes: usize) {
+        self.truncated_cells.clear();
+        for (row_idx, row) in rows.iter_mut().enumerate() {
+            for (col_idx, value) in row.iter_mut().enumerate() {
+                let Some(value) = value else {
+                    continue;
+                };
+                if let Some(full_len) = truncate_cell(value, max_cell_bytes) {
+                    self.truncated_cells.insert((row_idx, col_idx), full_len);
+                }
+            }
+        }
+        self.rows = rows;
+    }
+
+    /// Put back the full value of a cell that was cut for display
+    pub fn restore_full_value(&mut self, row: usize, col: usize, value: Option<String>) {
+        if let Some(cell) = self.rows.get_mut(row).and_then(|cells| cells.get_mut(col)) {
+            *cell = value;
+        }
+        self.truncated_cells.remove(&(row, col));
+    }
+
+    /// Whether a column holds binary data (`bytea`, `BLOB`, `varbinary`, ...)
+    pub fn is_binary_column(&self, col: usize) -> bool {
+        self.columns
+            .get(col)
+            .is_some_and(|column| column.data_type == DataType::Bytea.to_sql())
+    }
+
+    /// Text shown for a cell: binary values as a size placeholder, cut
+    /// values with their true size in front and an ellipsis after
+    pub fn cell_display_text(&self, row: usize, col: usize, value: Option<&str>) -> String {
+        let full_len = self.truncated_cells.get(&(row, col)).copied();
+        let Some(value) = value else {
+            return display_cell(None).to_string();
+        };
+        if self.is_binary_column(col) && value.starts_with("0x") {
+            let bytes = hex_literal_bytes(full_len.unwrap_or(value.len()));
+            return format!("<binary {}>", TableMetadata::format_size(bytes as i64));
+        }
+        match full_len {
+            Some(len) => format!("[{}] {value}…", TableMetadata::format_size(len as i64)),
+            None => display_cell(Some(value)).to_string(),
+        }
+    }
+
+    /// Keep the cursor on a cell that still exists after a reload
+    pub fn clamp_selection(&mut self) {
+        self.selected_row = self.selected_row.min(self.rows.len().saturating_sub(1));
+        self.selected_col = self.selected_col.min(self.columns.len().saturating_sub(1));
+    }
+
+    /// Sort by the selected column, ascending first and flipping on the next
+    /// press. Only query results hold every row, so false for other tabs.
+    pub fn sort_by_selected_column(&mut self) -> bool {
+        if self.origin.is_none() || !self.view_mode.shows_rows() || self.columns.is_empty() {
+            return false;
+        }
+        let ascending = self.sort != Some((self.selected_col, true));
+        self.sort = Some((self.selected_col, ascending));
+        self.apply_sort();
+        true
+    }
+
+    /// Put the rows in `sort` order, keeping the selected row, cut and
+    /// edited values and search hits with the rows they belong to
+    fn apply_sort(&mut self) {
+        let Some((col, ascending)) = self.sort else {
+            return;
+        };
+        let mut rows: Vec<(usize, Vec<Option<String>>)> = std::mem::take(&mut self.rows)
+            .into_iter()
+            .enumerate()
+            .collect();
+        rows.sort_by(|(_, a), (_, b)| {
+            let ordering = compare_cells(
+                a.get(col).and_then(Option::as_deref),
+                b.get(col).and_then(Option::as_deref),
+            );
+            if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+
+        let mut new_index = vec![0; rows.len()];
+        for (new, (old, _)) in rows.iter().enumerate() {
+            new_index[*old] = new;
+        }
+        self.rows = rows.into_iter().map(|(_, row)| row).collect();
+        self.truncated_cells = std::mem::take(&mut self.truncated_cells)
+            .into_iter()
+            .map(|((row, col), len)| ((new_index[row], col), len))
+            .collect();
+        self.modified_cells = std::mem::take(&mut self.modified_cells)
+            .into_iter()
+            .map(|((row, col), value)| ((new_index[row], col), value))
+            .collect();
+        if let Some(&row) = new_index.get(self.selected_row) {
+            self.selected_row = row;
+        }
+        if !self.search_query.is_empty() {
+            let query = self.search_query.clone();
+            self.update_search(&query);
+        }
+    }
+
+    /// Get the current cell value (including any modifications); `None` is NULL
+    pub fn get_cell_value(&self, row: usize, col: usize) -> Option<String> {
         if let Some(modified) = self.modified_cells.get(&(row, col)) {
             modified.clone()
-        } else if let Some(row_data) = self.rows.get(row) {
-            row_data.get(col).cloned().unwrap_or_default()
         } else {
-            String::new()
+            self.rows.get(row)?.get(col).cloned().flatten()
         }
     }
 
@@ -109,16 +420,56 @@ impl TableTab {
     pub fn start_edit(&mut self) {
         if !self.in_edit_mode && !self.rows.is_empty() {
             self.in_edit_mode = true;
-            self.edit_buffer = self.get_cell_value(self.selected_row, self.selected_col);
+            let value = self.get_cell_value(self.selected_row, self.selected_col);
+            self.edit_null = value.is_none();
+            self.edit_buffer = value.unwrap_or_default();
         }
     }
 
     /// Cancel editing
     pub fn cancel_edit(&mut self) {
         self.in_edit_mode = false;
+        self.edit_null = false;
+        self.edit_buffer.clear();
+    }
+
+    /// Set the pending edit value to SQL NULL
+    pub fn set_edit_null(&mut self) {
+        self.edit_null = true;
         self.edit_buffer.clear();
     }
 
+    /// Type a character into the edit buffer; typing `\N` means NULL
+    pub fn push_edit_char(&mut self, c: char) {
+        self.edit_null = false;
+        self.edit_buffer.push(c);
+        if self.edit_buffer == "\\N" {
+            self.set_edit_null();
+        }
+    }
+
+    /// Delete the last character, or clear a pending NULL
+    pub fn pop_edit_char(&mut self) {
+        if self.edit_null {
+            self.edit_null = false;
+        } else {
+            self.edit_buffer.pop();
+        }
+    }
+
+    /// Reject a pending NULL for a column declared NOT NULL
+    pub fn validate_edit(&self) -> Result<(), String> {
+        if !self.edit_null {
+            return Ok(());
+        }
+        match self.columns.get(self.selected_col) {
+            Some(column) if !column.is_nullable => {
+                Err(format!("Column '{}' does not allow NULL", column.name))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Save the current edit
     pub fn save_edit(&mut self) -> Option<CellUpdate> {
         if !self.in_edit_mode {
@@ -127,48 +478,42 @@ impl TableTab {
 
         let row_idx = self.selected_row;
         let col_idx = self.selected_col;
-        let new_value = self.edit_buffer.clone();
+        let is_null = self.edit_null;
+        let new_value = (!is_null).then(|| self.edit_buffer.clone());
 
-        // Get the original value
-        let original_value = if let Some(row_data) = self.rows.get(row_idx) {
-            row_data.get(col_idx).cloned().unwrap_or_default()
-        } else {
-            String::new()
-        };
+        // Get the value shown before this edit (earlier edits included)
+        let original_value = self.get_cell_value(row_idx, col_idx);
 
         // Only save if value changed
-        if new_value != original_value {
+        let update = (new_value != original_value).then(|| {
             self.modified_cells
                 .insert((row_idx, col_idx), new_value.clone());
 
             // Prepare update info for database
-            let update = CellUpdate {
+            CellUpdate {
                 table_name: self.table_name.clone(),
                 column_name: self.columns[col_idx].name.clone(),
-                new_value,
+                new_value: new_value.unwrap_or_default(),
+                is_null,
+                sql_value: None,
+                old_value: original_value,
                 row_index: row_idx,
                 primary_key_values: self.get_primary_key_values(row_idx),
-            };
-
-            self.in_edit_mode = false;
-            self.edit_buffer.clear();
+            }
+        });
 
-            Some(update)
-        } else {
-            self.in_edit_mode = false;
-            self.edit_buffer.clear();
-            None
-        }
+        self.cancel_edit();
+        update
     }
 
     /// Get primary key values for a row
-    fn get_primary_key_values(&self, row_idx: usize) -> Vec<(String, String)> {
+    pub fn get_primary_key_values(&self, row_idx: usize) -> Vec<(String, String)> {
         let mut pk_values = Vec::new();
 
         if let Some(row_data) = self.rows.get(row_idx) {
             for &pk_col_idx in &self.primary_key_columns {
                 if let Some(column) = self.columns.get(pk_col_idx) {
-                    if let Some(value) = row_data.get(pk_col_idx) {
+                    if let Some(Some(value)) = row_data.get(pk_col_idx) {
                         pk_values.push((column.name.clone(), value.clone()));
                     }
                 }
@@ -195,6 +540,26 @@ impl TableTab {
         }
     }
 
+    /// Fit the scroll position to a content area of this size
+    pub fn reflow(&mut self, area: Rect) {
+        match self.view_mode {
+            TableViewMode::Data => {
+                self.update_viewport_height(area.height as usize);
+                let data_width = self.data_width(area.width as usize);
+                self.ensure_column_visible(data_width);
+            }
+            TableViewMode::Source => {
+                self.scroll_offset_y = crate::ui::layout::clamp_scroll(
+                    self.scroll_offset_y,
+                    self.source_lines.len(),
+                    area.height.saturating_sub(2) as usize,
+                );
+            }
+            // Their content is only laid out when drawn, which clamps them
+            TableViewMode::Schema | TableViewMode::Vertical => {}
+        }
+    }
+
     /// Update viewport height and adjust scrolling accordingly
     pub fn update_viewport_height(&mut self, height: usize) {
         if height <= 4 {
@@ -272,11 +637,11 @@ impl TableTab {
     /// Move selection up
     pub fn move_up(&mut self) {
         match self.view_mode {
-            TableViewMode::Schema => {
+            TableViewMode::Schema | TableViewMode::Source => {
                 // In schema view, scroll up the content
                 self.scroll_offset_y = self.scroll_offset_y.saturating_sub(1);
             }
-            TableViewMode::Data => {
+            TableViewMode::Data | TableViewMode::Vertical => {
                 // In data view, move cell selection up
                 if self.selected_row > 0 {
                     self.selected_row -= 1;
@@ -290,12 +655,12 @@ impl TableTab {
     /// Move selection down
     pub fn move_down(&mut self) {
         match self.view_mode {
-            TableViewMode::Schema => {
+            TableViewMode::Schema | TableViewMode::Source => {
                 // In schema view, scroll down the content
                 // Note: We don't have a max scroll limit here, but the rendering will handle it
                 self.scroll_offset_y += 1;
             }
-            TableViewMode::Data => {
+            TableViewMode::Data | TableViewMode::Vertical => {
                 // In data view, move cell selection down
                 if self.selected_row < self.rows.len().saturating_sub(1) {
                     self.selected_row += 1;
@@ -306,8 +671,13 @@ impl TableTab {
         }
     }
 
-    /// Move selection left
+    /// Move selection left; the vertical view shows every column, so there
+    /// it steps to the previous record instead
     pub fn move_left(&mut self) {
+        if self.view_mode == TableViewMode::Vertical {
+            self.move_up();
+            return;
+        }
         crate::log_debug!(
             "move_left called, current col: {}, total cols: {}",
             self.selected_col,
@@ -321,8 +691,12 @@ impl TableTab {
         }
     }
 
-    /// Move selection right
+    /// Move selection right, or to the next record in the vertical view
     pub fn move_right(&mut self) {
+        if self.view_mode == TableViewMode::Vertical {
+            self.move_down();
+            return;
+        }
         crate::log_debug!(
             "move_right called, current col: {}, total cols: {}",
             self.selected_col,
@@ -380,71 +754,185 @@ impl TableTab {
         self.scroll_offset_y = 1000; // Large value, will be clipped by rendering
     }
 
-    /// Ensure the selected column is visible within the horizontal viewport
-    pub fn ensure_column_visible(&mut self, available_width: usize) {
-        if self.columns.is_empty() {
-            return;
+    /// Recompute the cached column widths after `columns` or `rows` change
+    pub fn refresh_column_widths(&mut self) {
+        self.column_widths = (0..self.columns.len())
+            .map(|col| self.content_width(col) + COLUMN_SPACING)
+            .collect();
+    }
+
+    /// Width a column is drawn at, padding included: its override, its
+    /// fitted width in auto-fit mode, or its capped display width
+    fn content_width(&self, col: usize) -> usize {
+        let column = &self.columns[col];
+        if let Some(&width) = self.width_overrides.get(&column.name) {
+            return width;
         }
+        if !self.auto_fit {
+            return column.max_display_width.min(MAX_COLUMN_WIDTH);
+        }
+        let key = if column.is_primary_key { 3 } else { 0 };
+        let frozen = if col < self.frozen_count() { 2 } else { 0 };
+        let header = Span::raw(column.name.as_str()).width() + key + frozen;
+        let widest_value = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter_map(|(row, values)| {
+                let value = values.get(col)?;
+                Some(Span::raw(self.cell_display_text(row, col, value.as_deref())).width())
+            })
+            .max()
+            .unwrap_or(0);
+        (header.max(widest_value) + 2).clamp(MIN_COLUMN_WIDTH, MAX_AUTO_FIT_WIDTH)
+    }
 
-        // If selected column is before the current scroll offset, scroll left
-        if self.selected_col < self.scroll_offset_x {
-            self.scroll_offset_x = self.selected_col;
+    /// Columns drawn at exactly their width rather than sharing spare space
+    fn has_fixed_width(&self, col: usize) -> bool {
+        self.auto_fit || self.width_overrides.contains_key(&self.columns[col].name)
+    }
+
+    /// Switch auto-fit on or off; returns whether it is now on
+    pub fn toggle_auto_fit(&mut self) -> bool {
+        self.auto_fit = !self.auto_fit;
+        self.refresh_column_widths();
+        self.auto_fit
+    }
+
+    /// Widen (positive `delta`) or narrow the selected column, keeping the
+    /// width as an override. Returns the column name and its new width.
+    pub fn resize_selected_column(&mut self, delta: isize) -> Option<(String, usize)> {
+        self.sync_column_widths();
+        let name = self.columns.get(self.selected_col)?.name.clone();
+        let current = self.column_widths[self.selected_col] - COLUMN_SPACING;
+        let width = current
+            .saturating_add_signed(delta)
+            .clamp(MIN_COLUMN_WIDTH, MAX_RESIZED_COLUMN_WIDTH);
+        self.width_overrides.insert(name.clone(), width);
+        self.refresh_column_widths();
+        Some((name, width))
+    }
+
+    /// Refresh the width cache if `columns` was replaced without it
+    fn sync_column_widths(&mut self) {
+        if self.column_widths.len() != self.columns.len() {
+            self.refresh_column_widths();
         }
-        // If selected column is beyond visible columns, scroll right
-        else {
-            let visible_columns = self.calculate_visible_columns(available_width);
-            let visible_end = self.scroll_offset_x + visible_columns.len();
-            if !visible_columns.is_empty() && self.selected_col >= visible_end {
-                // Find the rightmost scroll position that includes the selected column
-                let mut new_offset = self.selected_col;
-                loop {
-                    self.scroll_offset_x = new_offset;
-                    let test_visible = self.calculate_visible_columns(available_width);
-                    if !test_visible.is_empty()
-                        && self.selected_col < self.scroll_offset_x + test_visible.len()
-                    {
-                        break;
-                    }
-                    if new_offset == 0 {
-                        break;
-                    }
-                    new_offset = new_offset.saturating_sub(1);
-                }
-            }
+    }
+
+    /// Show or hide the `#` column; returns whether it is now shown
+    pub fn toggle_row_numbers(&mut self) -> bool {
+        self.show_row_numbers = !self.show_row_numbers;
+        self.show_row_numbers
+    }
+
+    /// Width of the `#` column: the digits of the last row number
+    fn row_number_width(&self) -> usize {
+        if !self.show_row_numbers {
+            return 0;
+        }
+        self.total_rows
+            .max(self.rows.len())
+            .max(1)
+            .to_string()
+            .len()
+    }
+
+    /// 1-based number of a row on the current page across all pages
+    pub fn absolute_row_number(&self, row: usize) -> usize {
+        self.current_page * self.rows_per_page + row + 1
+    }
+
+    /// Screen width left for data columns beside the `#` column
+    fn data_width(&self, available_width: usize) -> usize {
+        match self.row_number_width() {
+            0 => available_width,
+            width => available_width.saturating_sub(width + 1),
         }
     }
 
-    /// Calculate which columns can fit in the available width
-    pub fn calculate_visible_columns(&self, available_width: usize) -> Vec<usize> {
-        let mut visible_columns = Vec::new();
-        let mut used_width = 0usize;
+    /// Frozen columns that exist in this tab
+    pub fn frozen_count(&self) -> usize {
+        self.frozen_columns.min(self.columns.len())
+    }
 
-        // Account for column spacing and borders (approximately 3 chars per column)
-        let spacing_per_column = 3;
-        let border_padding = 4; // Account for table borders
+    /// Screen width left for the columns that scroll
+    fn scrolling_width(&self, available_width: usize) -> usize {
+        let frozen = self.frozen_count();
+        if frozen == 0 {
+            return available_width;
+        }
+        let frozen_width: usize = self.column_widths[..frozen].iter().sum();
+        available_width.saturating_sub(frozen_width + FROZEN_SEPARATOR_WIDTH)
+    }
 
-        let effective_width = available_width.saturating_sub(border_padding);
+    /// Freeze the columns up to and including the selected one, or unfreeze
+    /// them when exactly those are frozen already. Returns the new count.
+    pub fn toggle_freeze_columns(&mut self) -> usize {
+        let through_selected = self.selected_col + 1;
+        self.frozen_columns = if self.frozen_count() == through_selected {
+            0
+        } else {
+            through_selected.min(self.columns.len())
+        };
+        self.refresh_column_widths();
+        self.frozen_columns
+    }
 
-        for (idx, col) in self.columns.iter().enumerate().skip(self.scroll_offset_x) {
-            let col_width = col.max_display_width.min(30) + spacing_per_column;
+    /// Freeze one more column, up to and including the cursor's. Returns
+    /// the new count.
+    pub fn freeze_more_columns(&mut self) -> usize {
+        let through_selected = (self.selected_col + 1).min(self.columns.len());
+        self.frozen_columns = (self.frozen_count() + 1).min(through_selected);
+        self.refresh_column_widths();
+        self.frozen_columns
+    }
 
-            if used_width + col_width <= effective_width {
-                visible_columns.push(idx);
-                used_width += col_width;
-            } else {
-                break;
-            }
+    /// Unfreeze the rightmost frozen column. Returns the new count.
+    pub fn freeze_fewer_columns(&mut self) -> usize {
+        self.frozen_columns = self.frozen_count().saturating_sub(1);
+        self.refresh_column_widths();
+        self.frozen_columns
+    }
+
+    /// Ensure the selected column is visible within the horizontal viewport;
+    /// frozen columns are always visible and never scrolled past
+    pub fn ensure_column_visible(&mut self, available_width: usize) {
+        self.sync_column_widths();
+        if self.columns.is_empty() {
+            return;
         }
 
-        // Ensure at least one column is visible if possible
-        if visible_columns.is_empty()
-            && !self.columns.is_empty()
-            && self.scroll_offset_x < self.columns.len()
-        {
-            visible_columns.push(self.scroll_offset_x);
+        let frozen = self.frozen_count();
+        let width = self.scrolling_width(available_width);
+        self.scroll_offset_x = self.scroll_offset_x.max(frozen);
+        if self.selected_col < frozen {
+            return;
+        }
+        if self.selected_col < self.scroll_offset_x {
+            self.scroll_offset_x = self.selected_col;
+        } else {
+            let visible = visible_column_range(&self.column_widths, self.scroll_offset_x, width);
+            if self.selected_col >= visible.end {
+                self.scroll_offset_x = frozen
+                    + first_column_showing(
+                        &self.column_widths[frozen..],
+                        self.selected_col - frozen,
+                        width,
+                    );
+            }
         }
+    }
 
-        visible_columns
+    /// Calculate which columns after the frozen ones fit in the available width
+    pub fn calculate_visible_columns(&mut self, available_width: usize) -> Range<usize> {
+        self.sync_column_widths();
+        let first = self.scroll_offset_x.max(self.frozen_count());
+        visible_column_range(
+            &self.column_widths,
+            first,
+            self.scrolling_width(available_width),
+        )
     }
 
     /// Start search mode
@@ -483,7 +971,9 @@ impl TableTab {
                     cell_value.clone()
                 };
 
-                if value.to_lowercase().contains(&self.search_query) {
+                // NULL cells are found by searching for "null"
+                let text = value.as_deref().unwrap_or("NULL");
+                if text.to_lowercase().contains(&self.search_query) {
                     self.search_results.push((row_idx, col_idx));
                 }
             }
@@ -524,6 +1014,12 @@ pub struct CellUpdate {
     pub table_name: String,
     pub column_name: String,
     pub new_value: String,
+    /// Set the column to SQL NULL instead of `new_value`
+    pub is_null: bool,
+    /// Expression written instead of quoting `new_value`, e.g. `ARRAY[...]`
+    pub sql_value: Option<String>,
+    /// Value before the edit, kept for undo; `None` is NULL
+    pub old_value: Option<String>,
     pub row_index: usize,
     pub primary_key_values: Vec<(String, String)>,
 }
@@ -536,8 +1032,24 @@ pub struct TableViewerState {
     pub show_help: bool,
     pub delete_confirmation: Option<DeleteConfirmation>,
     pub set_null_confirmation: Option<SetNullConfirmation>,
-    pub last_d_press: Option<std::time::Instant>,
-    pub last_y_press: Option<std::time::Instant>,
+    /// Result snapshot taken with `P`, compared against later runs
+    pub pinned_result: Option<PinnedResult>,
+    /// Longest cell value loaded in full; `[results] max_cell_bytes`
+    pub max_cell_bytes: usize,
+    /// Frozen columns new tabs start with; `[results] frozen_columns`
+    pub frozen_columns: usize,
+    /// Whether new tabs auto-fit their columns; `[results] auto_fit_columns`
+    pub auto_fit_columns: bool,
+    /// `K` hex dump of a binary cell
+    pub hex_view: Option<HexView>,
+    /// `J` pretty-printed JSON of a cell
+    pub json_view: Option<JsonView>,
+    /// `Space` full, wrapped value of a cell
+    pub cell_view: Option<CellView>,
+    /// List or JSON editor over an array or json cell
+    pub cell_editor: Option<CellEditor>,
+    /// `yf` choice of format for copying the selected row
+    pub copy_format_menu: Option<CopyFormatMenu>,
 }
 
 /// Delete confirmation dialog state
@@ -556,7 +1068,8 @@ pub struct SetNullConfirmation {
     pub table_name: String,
     pub column_name: String,
     pub is_nullable: bool,
-    pub current_value: String,
+    /// `None` when the cell is already NULL
+    pub current_value: Option<String>,
     pub primary_key_values: Vec<(String, String)>,
 }
 
@@ -568,8 +1081,15 @@ impl TableViewerState {
             show_help: false,
             delete_confirmation: None,
             set_null_confirmation: None,
-            last_d_press: None,
-            last_y_press: None,
+            pinned_result: None,
+            max_cell_bytes: crate::config::ResultsConfig::default().max_cell_bytes,
+            frozen_columns: 0,
+            auto_fit_columns: false,
+            hex_view: None,
+            json_view: None,
+            cell_view: None,
+            cell_editor: None,
+            copy_format_menu: None,
         }
     }
 
@@ -583,12 +1103,101 @@ impl TableViewerState {
             }
         }
 
-        // Add new tab
-        self.tabs.push(TableTab::new(table_name));
+        self.add_new_tab(table_name)
+    }
+
+    /// Layout: Tabs at top, table content below, optional help overlay
+    fn layout(&self, area: Rect) -> std::rc::Rc<[Rect]> {
+        if self.show_help {
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(3), // Tabs
+                    Constraint::Min(10),   // Table content
+                    Constraint::Length(8), // Help
+                ])
+                .split(area)
+        } else {
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(3), // Tabs
+                    Constraint::Min(0),    // Table content (takes remaining space)
+                ])
+                .split(area)
+        }
+    }
+
+    /// Fit every tab's scroll position to the viewer being resized to `area`,
+    /// so no tab is left scrolled past its rows or columns
+    pub fn reflow(&mut self, area: Rect) {
+        let content = self.layout(area)[1];
+        for tab in &mut self.tabs {
+            tab.reflow(content);
+        }
+    }
+
+    /// Open another tab on `table_name` even when one is already open
+    pub fn add_new_tab(&mut self, table_name: String) -> usize {
+        let mut tab = TableTab::new(table_name);
+        tab.frozen_columns = self.frozen_columns;
+        tab.auto_fit = self.auto_fit_columns;
+        self.tabs.push(tab);
         self.active_tab = self.tabs.len() - 1;
         self.active_tab
     }
 
+    /// Close every tab showing `table_name`, e.g. after it was dropped or
+    /// renamed; returns how many were closed
+    pub fn close_tabs_for(&mut self, table_name: &str) -> usize {
+        // The active tab keeps focus when earlier tabs go
+        let closed_before_active = self
+            .tabs
+            .iter()
+            .take(self.active_tab)
+            .filter(|tab| tab.table_name == table_name)
+            .count();
+        let before = self.tabs.len();
+        self.tabs.retain(|tab| tab.table_name != table_name);
+        self.active_tab =
+            (self.active_tab - closed_before_active).min(self.tabs.len().saturating_sub(1));
+        before - self.tabs.len()
+    }
+
+    /// Show a failed query, reusing the error tab of an earlier failure
+    pub fn add_error_tab(&mut self, error: QueryError) -> usize {
+        let title = error.title();
+        let idx = match self.tabs.iter().position(|tab| tab.query_error.is_some()) {
+            Some(idx) => {
+                self.tabs[idx] = TableTab::new(title);
+                idx
+            }
+            None => {
+                self.tabs.push(TableTab::new(title));
+                self.tabs.len() - 1
+            }
+        };
+        self.active_tab = idx;
+
+        let tab = &mut self.tabs[idx];
+        tab.loading = false;
+        tab.error = Some(error.message.clone());
+        tab.query_error = Some(error);
+        idx
+    }
+
+    /// Open (or refresh) a read-only tab showing highlighted SQL source
+    pub fn add_source_tab(&mut self, title: String, source: &str) -> usize {
+        let idx = self.add_tab(title);
+        let tab = &mut self.tabs[idx];
+        tab.view_mode = TableViewMode::Source;
+        tab.loading = false;
+        tab.error = None;
+        tab.scroll_offset_y = 0;
+        tab.source_lines = highlight_sql(source);
+        idx
+    }
+
     /// Close current tab
     pub fn close_current_tab(&mut self) {
         if !self.tabs.is_empty() {
@@ -632,6 +1241,122 @@ impl TableViewerState {
         self.tabs.get_mut(self.active_tab)
     }
 
+    /// Snapshot the rows of the current tab for a later diff
+    pub fn pin_current_tab(&mut self) -> Result<usize, String> {
+        let tab = self
+            .current_tab()
+            .ok_or_else(|| "No table open".to_string())?;
+        if !tab.diff_kinds.is_empty() {
+            return Err("Cannot pin a diff tab".to_string());
+        }
+
+        let columns: Vec<String> = tab.columns.iter().map(|c| c.name.clone()).collect();
+        let key_columns =
+            result_diff::detect_key_columns(&columns, &tab.primary_key_columns, &[&tab.rows]);
+        let row_count = tab.rows.len();
+
+        self.pinned_result = Some(PinnedResult {
+            source: tab.table_name.clone(),
+            columns,
+            rows: tab.rows.clone(),
+            key_columns,
+        });
+
+        Ok(row_count)
+    }
+
+    /// Compare the current tab against the pinned snapshot, or without a pin
+    /// against another run of the same query, and open the differences in a
+    /// new tab
+    pub fn open_diff_tab(&mut self, max_entries: usize) -> Result<ResultDiff, String> {
+        let tab = self
+            .current_tab()
+            .ok_or_else(|| "No table open".to_string())?;
+        if !tab.diff_kinds.is_empty() {
+            return Err("Cannot diff a diff tab".to_string());
+        }
+
+        let previous_run;
+        let (baseline, tab) = match &self.pinned_result {
+            Some(pinned) => (pinned, tab),
+            None => {
+                let (old, new) = self.other_run_of_current_query().ok_or_else(|| {
+                    "No pinned results - press P on a results tab first, or run the same query again"
+                        .to_string()
+                })?;
+                let columns: Vec<String> = old.columns.iter().map(|c| c.name.clone()).collect();
+                let key_columns = result_diff::detect_key_columns(
+                    &columns,
+                    &old.primary_key_columns,
+                    &[&old.rows, &new.rows],
+                );
+                previous_run = PinnedResult {
+                    source: old.table_name.clone(),
+                    columns,
+                    rows: old.rows.clone(),
+                    key_columns,
+                };
+                (&previous_run, new)
+            }
+        };
+
+        let columns: Vec<String> = tab.columns.iter().map(|c| c.name.clone()).collect();
+        let diff = result_diff::compute_diff(baseline, &columns, &tab.rows, max_entries);
+
+        let mut diff_tab = TableTab::new(format!(
+            "Diff ({})",
+            chrono::Local::now().format("%H:%M:%S")
+        ));
+        diff_tab.columns = std::iter::once(ColumnInfo {
+            name: "±".to_string(),
+            data_type: "TEXT".to_string(),
+            is_nullable: false,
+            is_primary_key: false,
+            max_display_width: 3,
+        })
+        .chain(tab.columns.iter().cloned())
+        .collect();
+        diff_tab.rows = diff
+            .entries
+            .iter()
+            .map(|entry| {
+                std::iter::once(Some(entry.kind.marker().to_string()))
+                    .chain(entry.display_values(tab.columns.len()))
+                    .collect()
+            })
+            .collect();
+        diff_tab.diff_kinds = diff.entries.iter().map(|entry| entry.kind).collect();
+        diff_tab.diff_hidden = diff.hidden;
+        diff_tab.total_rows = diff_tab.rows.len();
+        diff_tab.rows_per_page = diff_tab.total_rows.max(1);
+        diff_tab.loading = false;
+        diff_tab.frozen_columns = self.frozen_columns;
+        diff_tab.auto_fit = self.auto_fit_columns;
+        diff_tab.refresh_column_widths();
+
+        self.tabs.push(diff_tab);
+        self.active_tab = self.tabs.len() - 1;
+
+        Ok(diff)
+    }
+
+    /// The current query result tab and another tab showing a run of the
+    /// same statement, ordered older first. The nearest earlier run wins.
+    pub fn other_run_of_current_query(&self) -> Option<(&TableTab, &TableTab)> {
+        let current = self.current_tab()?;
+        let query = &current.origin.as_ref()?.query;
+        let same_query = |tab: &&TableTab| {
+            tab.diff_kinds.is_empty() && tab.origin.as_ref().is_some_and(|o| &o.query == query)
+        };
+        if let Some(earlier) = self.tabs[..self.active_tab].iter().rev().find(same_query) {
+            return Some((earlier, current));
+        }
+        self.tabs[self.active_tab + 1..]
+            .iter()
+            .find(same_query)
+            .map(|later| (current, later))
+    }
+
     /// Toggle help
     pub fn toggle_help(&mut self) {
         self.show_help = !self.show_help;
@@ -641,18 +1366,7 @@ impl TableViewerState {
     pub fn copy_row_csv(&self) -> Result<(), String> {
         if let Some(tab) = self.current_tab() {
             if let Some(row_data) = tab.rows.get(tab.selected_row) {
-                // Escape CSV values that contain commas, quotes, or newlines
-                let csv_row = row_data
-                    .iter()
-                    .map(|cell| {
-                        if cell.contains(',') || cell.contains('"') || cell.contains('\n') {
-                            format!("\"{}\"", cell.replace('"', "\"\""))
-                        } else {
-                            cell.clone()
-                        }
-                    })
-                    .collect::<Vec<_>>()
-                    .join(",");
+                let csv_row = row_to_csv(row_data);
 
                 // Copy to clipboard
                 let mut clipboard = arboard::Clipboard::new()
@@ -670,24 +1384,217 @@ impl TableViewerState {
         }
     }
 
-    /// Copy current cell to clipboard (raw value)
-    pub fn copy_cell(&self) -> Result<(), String> {
+    /// The selected row encoded as `format`. INSERT needs the tab's source
+    /// table, so query results can't be copied that way.
+    pub fn row_as(
+        &self,
+        format: CopyFormat,
+        database_type: &DatabaseType,
+    ) -> Result<String, String> {
+        let tab = self
+            .current_tab()
+            .ok_or_else(|| "No table open".to_string())?;
+        let row = tab
+            .rows
+            .get(tab.selected_row)
+            .ok_or_else(|| "No row selected".to_string())?;
+        let row: Vec<Option<String>> = (0..row.len())
+            .map(|col| tab.get_cell_value(tab.selected_row, col))
+            .collect();
+        let table_name = match (format, tab.source_table()) {
+            (CopyFormat::Insert, None) => {
+                return Err("INSERT needs a table; this tab isn't one".to_string())
+            }
+            (_, table_name) => table_name.unwrap_or(&tab.table_name),
+        };
+        Ok(format.format_row(table_name, &tab.columns, &row, database_type))
+    }
+
+    /// Copy the selected row to the clipboard as `format`
+    pub fn copy_row_as(
+        &self,
+        format: CopyFormat,
+        database_type: &DatabaseType,
+    ) -> Result<(), String> {
+        let text = self.row_as(format, database_type)?;
+        let mut clipboard =
+            arboard::Clipboard::new().map_err(|e| format!("Failed to access clipboard: {e}"))?;
+        clipboard
+            .set_text(text)
+            .map_err(|e| format!("Failed to copy to clipboard: {e}"))
+    }
+
+    /// Open the list or JSON editor when the selected cell is an array or
+    /// json column. Returns false for other columns, which edit inline.
+    pub fn open_cell_editor(&mut self) -> Result<bool, String> {
+        let Some(tab) = self.current_tab() else {
+            return Ok(false);
+        };
+        let (row, col) = (tab.selected_row, tab.selected_col);
+        let Some(column) = tab.columns.get(col).filter(|_| row < tab.rows.len()) else {
+            return Ok(false);
+        };
+        let Some(kind) = CellEditorKind::for_column(column) else {
+            return Ok(false);
+        };
+        let value = tab.get_cell_value(row, col);
+        self.cell_editor = Some(CellEditor::open(
+            kind,
+            column.name.clone(),
+            row,
+            col,
+            value.as_deref(),
+        )?);
+        Ok(true)
+    }
+
+    /// Save the open cell editor: the update to run, or None when nothing
+    /// changed. Invalid input keeps the editor open.
+    pub fn finish_cell_editor(&mut self) -> Result<Option<CellUpdate>, String> {
+        let Some(editor) = self.cell_editor.as_mut() else {
+            return Ok(None);
+        };
+        let edited = editor.finish()?;
+        let editor = self.cell_editor.take().expect("checked above");
+        let (Some(edited), Some(tab)) = (edited, self.current_tab_mut()) else {
+            return Ok(None);
+        };
+        let Some(column) = tab.columns.get(editor.col) else {
+            return Ok(None);
+        };
+        let update = CellUpdate {
+            table_name: tab.table_name.clone(),
+            column_name: column.name.clone(),
+            new_value: edited.text.clone(),
+            is_null: false,
+            sql_value: edited.sql,
+            old_value: editor.original,
+            row_index: editor.row,
+            primary_key_values: tab.get_primary_key_values(editor.row),
+        };
+        tab.modified_cells
+            .insert((editor.row, editor.col), Some(edited.text));
+        Ok(Some(update))
+    }
+
+    /// Open the `J` view of the selected cell's JSON. json columns open even
+    /// when the value doesn't parse; other text columns only for objects
+    /// and arrays.
+    pub fn open_json_view(&mut self) -> Result<(), String> {
+        let tab = self
+            .current_tab()
+            .ok_or_else(|| "No table open".to_string())?;
+        let (row, col) = (tab.selected_row, tab.selected_col);
+        let column = tab
+            .columns
+            .get(col)
+            .filter(|_| row < tab.rows.len())
+            .ok_or_else(|| "No cell selected".to_string())?;
+        let value = tab
+            .get_cell_value(row, col)
+            .ok_or_else(|| "The cell is NULL".to_string())?;
+        let view = JsonView::new(format!("{} - row {}", column.name, row + 1), &value);
+        let json_column = CellEditorKind::for_column(column) == Some(CellEditorKind::Json);
+        let looks_json = view.error.is_none() && value.trim_start().starts_with(['{', '[']);
+        if !json_column && !looks_json {
+            return Err(format!("'{}' does not hold JSON", column.name));
+        }
+        self.json_view = Some(view);
+        Ok(())
+    }
+
+    /// Open the `Space` view of the selected cell's full value
+    pub fn open_cell_view(&mut self) -> Result<(), String> {
+        let tab = self
+            .current_tab()
+            .ok_or_else(|| "No table open".to_string())?;
+        let (row, col) = (tab.selected_row, tab.selected_col);
+        let column = tab
+            .columns
+            .get(col)
+            .filter(|_| row < tab.rows.len())
+            .ok_or_else(|| "No cell selected".to_string())?;
+        let value = tab
+            .get_cell_value(row, col)
+            .ok_or_else(|| "The cell is NULL".to_string())?;
+        self.cell_view = Some(CellView::new(
+            format!("{} - row {}", column.name, row + 1),
+            value,
+        ));
+        Ok(())
+    }
+
+    /// Open the `K` hex dump of the selected binary cell
+    pub fn open_hex_view(&mut self) -> Result<(), String> {
+        let tab = self
+            .current_tab()
+            .ok_or_else(|| "No table open".to_string())?;
+        let (row, col) = (tab.selected_row, tab.selected_col);
+        let column = tab
+            .columns
+            .get(col)
+            .filter(|_| row < tab.rows.len())
+            .ok_or_else(|| "No cell selected".to_string())?;
+        if !tab.is_binary_column(col) {
+            return Err(format!("'{}' is not a binary column", column.name));
+        }
+        let value = tab
+            .get_cell_value(row, col)
+            .ok_or_else(|| "The cell is NULL".to_string())?;
+        let bytes = parse_hex_literal(&value)
+            .ok_or_else(|| "The cell does not hold binary data".to_string())?;
+        let total_bytes = tab
+            .truncated_cells
+            .get(&(row, col))
+            .map_or(bytes.len(), |&len| hex_literal_bytes(len));
+        self.hex_view = Some(HexView::new(
+            format!("{} - row {}", column.name, row + 1),
+            bytes,
+            total_bytes,
+        ));
+        Ok(())
+    }
+
+    /// Copy the selected row's primary key as a WHERE clause fragment and
+    /// return what was copied
+    pub fn copy_primary_key(&self) -> Result<String, String> {
+        let tab = self
+            .current_tab()
+            .ok_or_else(|| "No table open".to_string())?;
+        if tab.selected_row >= tab.rows.len() {
+            return Err("No row selected".to_string());
+        }
+        let fragment = primary_key_where(&tab.get_primary_key_values(tab.selected_row))
+            .ok_or_else(|| "No primary key found".to_string())?;
+
+        let mut clipboard =
+            arboard::Clipboard::new().map_err(|e| format!("Failed to access clipboard: {e}"))?;
+        clipboard
+            .set_text(fragment.clone())
+            .map_err(|e| format!("Failed to copy to clipboard: {e}"))?;
+        Ok(fragment)
+    }
+
+    /// Copy current cell to clipboard (raw value) and return what was copied
+    pub fn copy_cell(&self) -> Result<String, String> {
         if let Some(tab) = self.current_tab() {
             if tab.rows.is_empty() {
                 return Err("No data in table".to_string());
             }
 
-            // Get the current cell value (including any modifications)
-            let cell_value = tab.get_cell_value(tab.selected_row, tab.selected_col);
+            // Get the current cell value (including any modifications), NULL as shown
+            let cell_value = tab
+                .get_cell_value(tab.selected_row, tab.selected_col)
+                .unwrap_or_else(|| "NULL".to_string());
 
             // Copy to clipboard
             let mut clipboard = arboard::Clipboard::new()
                 .map_err(|e| format!("Failed to access clipboard: {e}"))?;
             clipboard
-                .set_text(cell_value)
+                .set_text(cell_value.clone())
                 .map_err(|e| format!("Failed to copy to clipboard: {e}"))?;
 
-            Ok(())
+            Ok(cell_value)
         } else {
             Err("No table open".to_string())
         }
@@ -702,7 +1609,7 @@ impl TableViewerState {
                 for &pk_idx in &tab.primary_key_columns {
                     if let Some(pk_col) = tab.columns.get(pk_idx) {
                         if let Some(row) = tab.rows.get(tab.selected_row) {
-                            if let Some(value) = row.get(pk_idx) {
+                            if let Some(Some(value)) = row.get(pk_idx) {
                                 primary_key_values.push((pk_col.name.clone(), value.clone()));
                             }
                         }
@@ -747,7 +1654,7 @@ impl TableViewerState {
                 for &pk_idx in &tab.primary_key_columns {
                     if let Some(pk_col) = tab.columns.get(pk_idx) {
                         if let Some(row) = tab.rows.get(tab.selected_row) {
-                            if let Some(value) = row.get(pk_idx) {
+                            if let Some(Some(value)) = row.get(pk_idx) {
                                 primary_key_values.push((pk_col.name.clone(), value.clone()));
                             }
                         }
@@ -783,60 +1690,229 @@ impl Default for TableViewerState {
     }
 }
 
-/// Render the table viewer
-pub fn render_table_viewer(
-    f: &mut Frame,
-    state: &mut TableViewerState,
-    area: Rect,
-    theme: &Theme,
-    is_focused: bool,
-) {
-    if state.tabs.is_empty() {
-        render_empty_state(f, area, theme, is_focused);
-        return;
+/// Columns starting at `first` whose widths fit in `available_width`. The
+/// first column is always included so an over-wide column still shows.
+pub fn visible_column_range(
+    column_widths: &[usize],
+    first: usize,
+    available_width: usize,
+) -> Range<usize> {
+    if first >= column_widths.len() {
+        return column_widths.len()..column_widths.len();
     }
-
-    // Layout: Tabs at top, table content below, optional help overlay
-    let chunks = if state.show_help {
-        Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3), // Tabs
-                Constraint::Min(10),   // Table content
-                Constraint::Length(8), // Help
-            ])
-            .split(area)
-    } else {
-        Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3), // Tabs
-                Constraint::Min(0),    // Table content (takes remaining space)
-            ])
-            .split(area)
-    };
-
-    // Render tabs
-    render_tabs(f, state, chunks[0], theme, is_focused);
-
-    // Render current table
-    if let Some(tab) = state.current_tab_mut() {
-        render_table_content(f, tab, chunks[1], theme, is_focused);
+    let effective_width = available_width.saturating_sub(TABLE_BORDER_PADDING);
+    let mut used_width = 0usize;
+    let mut end = first;
+    for &width in &column_widths[first..] {
+        if used_width + width > effective_width {
+            break;
+        }
+        used_width += width;
+        end += 1;
     }
+    first..end.max(first + 1)
+}
 
-    // Render help if requested (no persistent status bar)
-    if state.show_help {
-        render_help(f, chunks[2], theme);
+/// Leftmost first column that still keeps `selected` in view
+pub fn first_column_showing(
+    column_widths: &[usize],
+    selected: usize,
+    available_width: usize,
+) -> usize {
+    let effective_width = available_width.saturating_sub(TABLE_BORDER_PADDING);
+    let mut first = selected;
+    let mut used_width = column_widths.get(selected).copied().unwrap_or(0);
+    while first > 0 && used_width + column_widths[first - 1] <= effective_width {
+        first -= 1;
+        used_width += column_widths[first];
     }
+    first
+}
 
-    // Render delete confirmation dialog if active
-    if let Some(confirmation) = &state.delete_confirmation {
-        render_delete_confirmation(f, confirmation, f.area(), theme);
+/// Text shown for a cell: NULL as `NULL` and an empty string as `""`, so
+/// the two never both render blank
+pub fn display_cell(value: Option<&str>) -> &str {
+    match value {
+        None => "NULL",
+        Some("") => "\"\"",
+        Some(value) => value,
     }
+}
 
-    // Render set NULL confirmation dialog if active
-    if let Some(confirmation) = &state.set_null_confirmation {
-        render_set_null_confirmation(f, confirmation, f.area(), theme);
+/// Cut `value` to at most `max_bytes` on a character boundary, returning its
+/// original length when it was longer
+pub fn truncate_cell(value: &mut String, max_bytes: usize) -> Option<usize> {
+    if value.len() <= max_bytes {
+        return None;
+    }
+    let full_len = value.len();
+    let mut cut = max_bytes;
+    while !value.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    value.truncate(cut);
+    Some(full_len)
+}
+
+/// Shorten `text` to `max` characters, ending in `…` when cut
+pub fn ellipsize(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut short: String = text.chars().take(max.saturating_sub(1)).collect();
+    short.push('…');
+    short
+}
+
+/// Primary key of a row as a WHERE clause fragment: `id = 42`, or
+/// `(tenant_id, id) = (7, 42)` for composite keys. Numbers stay bare, other
+/// values become quoted string literals.
+pub fn primary_key_where(primary_key_values: &[(String, String)]) -> Option<String> {
+    let literal = |value: &str| {
+        if value.parse::<f64>().is_ok_and(f64::is_finite) {
+            value.to_string()
+        } else {
+            format!("'{}'", value.replace('\'', "''"))
+        }
+    };
+    match primary_key_values {
+        [] => None,
+        [(column, value)] => Some(format!("{column} = {}", literal(value))),
+        values => {
+            let columns: Vec<&str> = values.iter().map(|(column, _)| column.as_str()).collect();
+            let literals: Vec<String> = values.iter().map(|(_, value)| literal(value)).collect();
+            Some(format!(
+                "({}) = ({})",
+                columns.join(", "),
+                literals.join(", ")
+            ))
+        }
+    }
+}
+
+/// Order of two cells when sorting: numbers by value before text, text
+/// case-insensitively, NULLs last
+fn compare_cells(a: Option<&str>, b: Option<&str>) -> std::cmp::Ordering {
+    let (a, b) = match (a, b) {
+        (Some(a), Some(b)) => (a, b),
+        (a, b) => return a.is_none().cmp(&b.is_none()),
+    };
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        (Ok(_), Err(_)) => std::cmp::Ordering::Less,
+        (Err(_), Ok(_)) => std::cmp::Ordering::Greater,
+        (Err(_), Err(_)) => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+/// Whether a result column holds only `hex_literal` values (and NULLs),
+/// i.e. came from a binary column
+fn looks_binary(rows: &[Vec<Option<String>>], col: usize) -> bool {
+    let mut values = rows
+        .iter()
+        .filter_map(|row| row.get(col)?.as_deref())
+        .peekable();
+    values.peek().is_some()
+        && values.all(|value| {
+            value.strip_prefix("0x").is_some_and(|hex| {
+                hex.len() % 2 == 0 && hex.bytes().all(|b| matches!(b, b'0'..=b'9' | b'A'..=b'F'))
+            })
+        })
+}
+
+/// Encode a row as CSV: NULL becomes an empty unquoted field and empty
+/// strings are quoted so the two stay distinguishable, as in PostgreSQL's
+/// `COPY ... WITH (FORMAT csv, NULL '')`
+pub fn row_to_csv(row: &[Option<String>]) -> String {
+    row.iter()
+        .map(|cell| match cell.as_deref() {
+            None => String::new(),
+            Some(cell) if cell.is_empty() || cell == "\\." => format!("\"{cell}\""),
+            Some(cell) if cell.contains([',', '"', '\n', '\r']) => {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            }
+            Some(cell) => cell.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Encode a whole result as CSV: a header line, then one line per row
+pub fn results_to_csv(columns: &[String], rows: &[Vec<Option<String>>]) -> String {
+    let header: Vec<String> = columns
+        .iter()
+        .map(|name| crate::database::csv_import::encode_field(name))
+        .collect();
+    let mut out = header.join(",");
+    out.push('\n');
+    for row in rows {
+        out.push_str(&row_to_csv(row));
+        out.push('\n');
+    }
+    out
+}
+
+/// Render the table viewer
+pub fn render_table_viewer(
+    f: &mut Frame,
+    state: &mut TableViewerState,
+    area: Rect,
+    theme: &Theme,
+    is_focused: bool,
+) {
+    if state.tabs.is_empty() {
+        render_empty_state(f, area, theme, is_focused);
+        return;
+    }
+
+    let chunks = state.layout(area);
+
+    // Render tabs
+    render_tabs(f, state, chunks[0], theme, is_focused);
+
+    // Render current table
+    if let Some(tab) = state.current_tab_mut() {
+        render_table_content(f, tab, chunks[1], theme, is_focused);
+    }
+
+    // Render help if requested (no persistent status bar)
+    if state.show_help {
+        render_help(f, chunks[2], theme);
+    }
+
+    // Render delete confirmation dialog if active
+    if let Some(confirmation) = &state.delete_confirmation {
+        render_delete_confirmation(f, confirmation, f.area(), theme);
+    }
+
+    // Render set NULL confirmation dialog if active
+    if let Some(confirmation) = &state.set_null_confirmation {
+        render_set_null_confirmation(f, confirmation, f.area(), theme);
+    }
+
+    // Render the hex dump of a binary cell
+    if let Some(view) = &state.hex_view {
+        render_hex_view(f, view);
+    }
+
+    // Render the pretty-printed JSON of a cell
+    if let Some(view) = &state.json_view {
+        render_json_view(f, view, theme);
+    }
+
+    // Render the full value of a cell
+    if let Some(view) = &mut state.cell_view {
+        render_cell_view(f, view);
+    }
+
+    // Render the array or JSON editor
+    if let Some(editor) = &state.cell_editor {
+        render_cell_editor(f, editor);
+    }
+
+    // Render the row copy format menu
+    if let Some(menu) = &state.copy_format_menu {
+        render_copy_format_menu(f, menu);
     }
 }
 
@@ -1000,10 +2076,11 @@ fn render_set_null_confirmation(
     };
 
     // Truncate current value if too long
-    let display_value = if confirmation.current_value.len() > 30 {
-        format!("{}...", &confirmation.current_value[..27])
+    let current_value = display_cell(confirmation.current_value.as_deref());
+    let display_value = if current_value.chars().count() > 30 {
+        format!("{}...", current_value.chars().take(27).collect::<String>())
     } else {
-        confirmation.current_value.clone()
+        current_value.to_string()
     };
 
     // Build the content lines with proper formatting
@@ -1119,16 +2196,38 @@ fn render_tabs(
             } else {
                 " *"
             };
+            let stats = tab
+                .query_stats
+                .as_ref()
+                .map(|stats| format!(" {}", stats.tab_label()))
+                .unwrap_or_default();
+
+            let lock = if tab.read_only_message().is_some() {
+                " 🔒"
+            } else {
+                ""
+            };
+            let watch = tab
+                .watch
+                .as_ref()
+                .map(|watch| format!(" {}", watch.label()))
+                .unwrap_or_default();
 
             if idx == state.active_tab {
                 format!(
-                    " {} {}{} ",
+                    " {} {}{}{}{}{} ",
                     if idx == state.active_tab { "▶" } else { " " },
                     tab.table_name,
+                    lock,
+                    watch,
+                    stats,
                     modified
                 )
             } else {
-                format!("  {}{}  ", tab.table_name, modified)
+                format!(
+                    "  {}{}{}{}{}  ",
+                    tab.table_name, lock, watch, stats, modified
+                )
             }
         })
         .collect();
@@ -1164,8 +2263,9 @@ fn render_table_content(
 ) {
     if tab.loading {
         let loading_msg = match tab.view_mode {
-            TableViewMode::Data => "Loading table data...",
+            TableViewMode::Data | TableViewMode::Vertical => "Loading table data...",
             TableViewMode::Schema => "Loading table schema...",
+            TableViewMode::Source => "Loading source...",
         };
         let loading = Paragraph::new(loading_msg)
             .style(Style::default().fg(theme.get_color("warning")))
@@ -1179,6 +2279,11 @@ fn render_table_content(
         return;
     }
 
+    if let Some(query_error) = &tab.query_error {
+        render_query_error(f, query_error, area, theme);
+        return;
+    }
+
     if let Some(ref error) = tab.error {
         let error_text = Paragraph::new(error.as_str())
             .style(Style::default().fg(theme.get_color("danger")))
@@ -1197,7 +2302,236 @@ fn render_table_content(
     match tab.view_mode {
         TableViewMode::Data => render_data_view(f, tab, area, theme, is_focused),
         TableViewMode::Schema => render_schema_view(f, tab, area, theme, is_focused),
+        TableViewMode::Source => render_source_view(f, tab, area, theme, is_focused),
+        TableViewMode::Vertical => render_vertical_view(f, tab, area, theme, is_focused),
+    }
+}
+
+/// Full error text, SQLSTATE and the offending query line with a caret
+fn render_query_error(f: &mut Frame, error: &QueryError, area: Rect, theme: &Theme) {
+    let danger = Style::default().fg(theme.get_color("danger"));
+    let muted = Style::default().fg(theme.get_color("inactive_pane"));
+
+    let mut lines: Vec<Line> = error
+        .message
+        .lines()
+        .map(|line| Line::styled(line.to_string(), danger))
+        .collect();
+    if let Some(code) = &error.code {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("SQLSTATE ", muted),
+            Span::raw(code.clone()),
+        ]));
+    }
+    if let (Some((line, column)), Some((text, caret))) = (error.position, error.offending_line()) {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!("Line {}, column {}:", line + 1, column + 1),
+            muted,
+        ));
+        lines.push(Line::from(text));
+        lines.push(Line::styled(caret, danger.add_modifier(Modifier::BOLD)));
+        lines.push(Line::from(""));
+        lines.push(Line::styled("e: jump to error in the query editor", muted));
+    }
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Query Error ")
+            .border_style(danger),
+    );
+    f.render_widget(paragraph, area);
+}
+
+fn render_source_view(
+    f: &mut Frame,
+    tab: &mut TableTab,
+    area: Rect,
+    theme: &Theme,
+    is_focused: bool,
+) {
+    // Keep the last line reachable without scrolling past it
+    tab.scroll_offset_y = crate::ui::layout::clamp_scroll(
+        tab.scroll_offset_y,
+        tab.source_lines.len(),
+        area.height.saturating_sub(2) as usize,
+    );
+
+    let number_width = tab.source_lines.len().to_string().len().max(3);
+    let lines: Vec<Line> = tab
+        .source_lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut spans = vec![Span::styled(
+                format!("{:>number_width$} │ ", i + 1),
+                Style::default().fg(theme.get_color("text_muted")),
+            )];
+            spans.extend(line.spans.iter().cloned());
+            Line::from(spans)
+        })
+        .collect();
+
+    let border_color = if is_focused {
+        theme.get_color("active_border")
+    } else {
+        theme.get_color("border")
+    };
+    let source = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} - Source (read-only) ", tab.table_name))
+                .border_style(Style::default().fg(border_color)),
+        )
+        .scroll((tab.scroll_offset_y as u16, 0));
+    f.render_widget(source, area);
+}
+
+/// Title suffix while the `W` interval prompt is open
+fn watch_prompt_title(tab: &TableTab) -> Option<String> {
+    tab.watch_prompt.as_ref().map(|input| {
+        format!(" | Refresh every (seconds): {input}█ - Enter to start, Esc to cancel")
+    })
+}
+
+/// Records from the selected one down, each as a `*** row N ***` header
+/// followed by a `column: value` line per field
+fn render_vertical_view(
+    f: &mut Frame,
+    tab: &mut TableTab,
+    area: Rect,
+    theme: &Theme,
+    is_focused: bool,
+) {
+    let viewport_height = area.height.saturating_sub(2) as usize;
+    let name_width = tab
+        .columns
+        .iter()
+        .map(|col| col.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    let first_row = tab.current_page * tab.rows_per_page;
+    let record = if tab.rows.is_empty() {
+        0
+    } else {
+        first_row + tab.selected_row + 1
+    };
+
+    let field_offset = tab.record_field_offset(viewport_height);
+
+    let mut lines: Vec<Line> = Vec::new();
+    for row in tab.selected_row..tab.rows.len() {
+        if lines.len() >= viewport_height {
+            break;
+        }
+        let skip = if row == tab.selected_row {
+            field_offset
+        } else {
+            0
+        };
+        let header_style = if row == tab.selected_row {
+            Style::default()
+                .fg(theme.get_color("selected_text"))
+                .bg(theme.get_color("selected_bg"))
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.get_color("text_muted"))
+        };
+        lines.push(Line::styled(
+            format!("*** row {} ***", first_row + row + 1),
+            header_style,
+        ));
+
+        for (col, column) in tab.columns.iter().enumerate().skip(skip) {
+            let value = tab.get_cell_value(row, col);
+            let name_style = if row == tab.selected_row && col == tab.selected_col {
+                Style::default()
+                    .fg(theme.get_color("selected_text"))
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.get_color("text_muted"))
+            };
+            let value_style = if tab.modified_cells.contains_key(&(row, col)) {
+                Style::default()
+                    .fg(theme.get_color("modified_cell"))
+                    .add_modifier(Modifier::ITALIC)
+            } else if value.is_none() {
+                Style::default()
+                    .fg(theme.get_color("table_null_fg"))
+                    .add_modifier(Modifier::ITALIC)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:>name_width$}: ", column.name), name_style),
+                Span::styled(
+                    tab.cell_display_text(row, col, value.as_deref()),
+                    value_style,
+                ),
+            ]));
+        }
     }
+
+    let border_color = if is_focused {
+        theme.get_color("active_border")
+    } else {
+        theme.get_color("border")
+    };
+    let records = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                " {} - Record {}/{} - Page {}/{} [V] Grid View{} ",
+                tab.table_name,
+                record,
+                tab.total_rows,
+                tab.current_page + 1,
+                (tab.total_rows.saturating_sub(1)) / tab.rows_per_page + 1,
+                watch_prompt_title(tab).unwrap_or_default(),
+            ))
+            .border_style(Style::default().fg(border_color)),
+    );
+    f.render_widget(records, area);
+}
+
+/// Highlight SQL source once, when a source tab is opened
+fn highlight_sql(source: &str) -> Vec<Line<'static>> {
+    use std::sync::OnceLock;
+    use syntect::{easy::HighlightLines, highlighting::ThemeSet, parsing::SyntaxSet};
+
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    static THEMES: OnceLock<ThemeSet> = OnceLock::new();
+    let syntaxes = SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines);
+    let themes = THEMES.get_or_init(ThemeSet::load_defaults);
+
+    let syntax = syntaxes
+        .find_syntax_by_extension("sql")
+        .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+    let mut highlighter = HighlightLines::new(syntax, &themes.themes["base16-ocean.dark"]);
+
+    source
+        .lines()
+        .map(
+            |line| match highlighter.highlight_line(&format!("{line}\n"), syntaxes) {
+                Ok(ranges) => Line::from(
+                    ranges
+                        .into_iter()
+                        .map(|(style, text)| {
+                            let fg = style.foreground;
+                            Span::styled(
+                                text.trim_end_matches('\n').to_string(),
+                                Style::default().fg(ratatui::style::Color::Rgb(fg.r, fg.g, fg.b)),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+                Err(_) => Line::from(line.to_string()),
+            },
+        )
+        .collect()
 }
 
 fn render_data_view(
@@ -1207,12 +2541,20 @@ fn render_data_view(
     theme: &Theme,
     is_focused: bool,
 ) {
-    // Calculate visible columns based on available width
-    tab.ensure_column_visible(area.width as usize);
-    let visible_column_indices = tab.calculate_visible_columns(area.width as usize);
+    // Only the columns that fit are laid out and styled; the rest are never touched
+    let data_width = tab.data_width(area.width as usize);
+    tab.ensure_column_visible(data_width);
+    let scrolling_columns = tab.calculate_visible_columns(data_width);
+    let row_number_width = tab.row_number_width();
+    let row_number_style = Style::default().fg(theme.get_color("text_muted"));
+    let frozen = tab.frozen_count();
+    let visible_column_indices: Vec<usize> = (0..frozen).chain(scrolling_columns.clone()).collect();
+    // A thin line where the frozen columns end, once something scrolls past them
+    let separator_at = (frozen > 0 && !scrolling_columns.is_empty()).then_some(frozen);
+    let separator_style = Style::default().fg(theme.get_color("border"));
 
     // Prepare table headers - only for visible columns
-    let headers: Vec<TableCell> = visible_column_indices
+    let mut headers: Vec<TableCell> = visible_column_indices
         .iter()
         .map(|&idx| {
             let col = &tab.columns[idx];
@@ -1228,15 +2570,27 @@ fn render_data_view(
                 Style::default().fg(theme.get_color("text_primary"))
             };
 
+            let marker = if idx < frozen { "❄ " } else { "" };
+            let arrow = match tab.sort {
+                Some((sorted, true)) if sorted == idx => " ▲",
+                Some((sorted, false)) if sorted == idx => " ▼",
+                _ => "",
+            };
             let name = if col.is_primary_key {
-                format!(" 🔑 {} ", col.name)
+                format!(" {marker}🔑 {}{arrow} ", col.name)
             } else {
-                format!(" {} ", col.name)
+                format!(" {marker}{}{arrow} ", col.name)
             };
 
             TableCell::from(name).style(style)
         })
         .collect();
+    if let Some(at) = separator_at {
+        headers.insert(at, TableCell::from("│").style(separator_style));
+    }
+    if row_number_width > 0 {
+        headers.insert(0, TableCell::from("#").style(row_number_style));
+    }
 
     let header = Row::new(headers)
         .style(Style::default().add_modifier(Modifier::BOLD))
@@ -1257,20 +2611,33 @@ fn render_data_view(
         .take(viewport_height)
         .collect();
 
+    // Search hits inside the window, so each cell is a set lookup
+    let visible_row_range = tab.scroll_offset_y..tab.scroll_offset_y + viewport_height;
+    let visible_matches: HashSet<(usize, usize)> = tab
+        .search_results
+        .iter()
+        .filter(|(row, col)| {
+            visible_row_range.contains(row) && visible_column_indices.contains(col)
+        })
+        .copied()
+        .collect();
+
     let rows: Vec<Row> = visible_rows
         .iter()
         .map(|(row_idx, row_data)| {
-            let cells: Vec<TableCell> = visible_column_indices
+            let mut cells: Vec<TableCell> = visible_column_indices
                 .iter()
                 .map(|&col_idx| {
-                    let value = row_data.get(col_idx).cloned().unwrap_or_default();
+                    let value = row_data.get(col_idx).cloned().flatten();
                     let is_selected = *row_idx == tab.selected_row && col_idx == tab.selected_col;
                     let is_modified = tab.modified_cells.contains_key(&(*row_idx, col_idx));
-                    let is_search_match = tab.search_results.contains(&(*row_idx, col_idx));
+                    let is_search_match = visible_matches.contains(&(*row_idx, col_idx));
                     let is_current_search = tab.search_results.get(tab.current_search_result)
                         == Some(&(*row_idx, col_idx));
 
-                    let display_value = if is_selected && tab.in_edit_mode {
+                    let display_value = if is_selected && tab.in_edit_mode && tab.edit_null {
+                        " <NULL>▌ ".to_string()
+                    } else if is_selected && tab.in_edit_mode {
                         format!(" {}▌ ", tab.edit_buffer)
                     } else if is_modified {
                         let val = tab
@@ -1278,9 +2645,12 @@ fn render_data_view(
                             .get(&(*row_idx, col_idx))
                             .cloned()
                             .unwrap_or_else(|| value.clone());
-                        format!(" {val} ")
+                        format!(" {} ", display_cell(val.as_deref()))
                     } else {
-                        format!(" {value} ")
+                        format!(
+                            " {} ",
+                            tab.cell_display_text(*row_idx, col_idx, value.as_deref())
+                        )
                     };
 
                     // Base style with alternating row background
@@ -1290,6 +2660,26 @@ fn render_data_view(
                         Style::default()
                     };
 
+                    // Diff tabs color whole rows by kind of change
+                    let base_style = match tab.diff_kinds.get(*row_idx) {
+                        Some(DiffKind::Added) => base_style.fg(theme.get_color("diff_added")),
+                        Some(DiffKind::Removed) => base_style.fg(theme.get_color("diff_removed")),
+                        Some(DiffKind::Changed) => base_style.fg(theme.get_color("diff_changed")),
+                        Some(DiffKind::Unchanged) => base_style.add_modifier(Modifier::DIM),
+                        None => base_style,
+                    };
+                    let base_style = if row_data
+                        .first()
+                        .and_then(Option::as_deref)
+                        .is_some_and(|name| tab.highlighted_names.contains(&name))
+                    {
+                        base_style
+                            .fg(theme.get_color("primary_highlight"))
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        base_style
+                    };
+
                     let style = if is_selected && tab.in_edit_mode {
                         Style::default()
                             .fg(theme.get_color("edit_mode_text"))
@@ -1312,8 +2702,12 @@ fn render_data_view(
                         base_style
                             .fg(theme.get_color("modified_cell"))
                             .add_modifier(Modifier::ITALIC)
-                    } else if value == "NULL" || value.is_empty() {
-                        base_style.fg(theme.get_color("null_value"))
+                    } else if tab.diff_kinds.is_empty() && value.is_none() {
+                        base_style
+                            .fg(theme.get_color("table_null_fg"))
+                            .add_modifier(Modifier::ITALIC)
+                    } else if tab.diff_kinds.is_empty() && value.as_deref() == Some("") {
+                        base_style.add_modifier(Modifier::DIM)
                     } else {
                         base_style
                     };
@@ -1321,19 +2715,39 @@ fn render_data_view(
                     TableCell::from(display_value).style(style)
                 })
                 .collect();
+            if let Some(at) = separator_at {
+                cells.insert(at, TableCell::from("│").style(separator_style));
+            }
+            if row_number_width > 0 {
+                let number = tab.absolute_row_number(*row_idx);
+                cells.insert(
+                    0,
+                    TableCell::from(format!("{number:>row_number_width$}")).style(row_number_style),
+                );
+            }
 
             Row::new(cells).height(1).bottom_margin(0)
         })
         .collect();
 
-    // Calculate column widths for visible columns only
-    let widths: Vec<Constraint> = visible_column_indices
+    // Column widths for visible columns only, from the per-load cache
+    let mut widths: Vec<Constraint> = visible_column_indices
         .iter()
         .map(|&idx| {
-            let col = &tab.columns[idx];
-            Constraint::Min(col.max_display_width.min(30) as u16)
+            let width = (tab.column_widths[idx] - COLUMN_SPACING) as u16;
+            if tab.has_fixed_width(idx) {
+                Constraint::Length(width)
+            } else {
+                Constraint::Min(width)
+            }
         })
         .collect();
+    if let Some(at) = separator_at {
+        widths.insert(at, Constraint::Length(1));
+    }
+    if row_number_width > 0 {
+        widths.insert(0, Constraint::Length(row_number_width as u16));
+    }
 
     let table = Table::new(rows, widths)
         .header(header)
@@ -1350,14 +2764,16 @@ fn render_data_view(
                     if visible_column_indices.len() < tab.columns.len() {
                         format!(
                             "[{}-{}/{}]",
-                            tab.scroll_offset_x + 1,
-                            tab.scroll_offset_x + visible_column_indices.len(),
+                            scrolling_columns.start + 1,
+                            scrolling_columns.end,
                             tab.columns.len()
                         )
                     } else {
                         String::new()
                     },
-                    if tab.in_search_mode {
+                    if let Some(prompt) = watch_prompt_title(tab) {
+                        prompt
+                    } else if tab.in_search_mode {
                         format!(
                             " | Search: '{}' ({}/{})",
                             tab.search_query,
@@ -1386,6 +2802,14 @@ fn render_data_view(
                     Style::default().fg(theme.get_color("active_border"))
                 } else {
                     Style::default().fg(theme.get_color("border"))
+                })
+                .title_bottom(if tab.diff_hidden > 0 {
+                    Line::from(Span::styled(
+                        format!(" … and {} more differences ", tab.diff_hidden),
+                        Style::default().fg(theme.get_color("text_muted")),
+                    ))
+                } else {
+                    Line::default()
                 }),
         )
         .column_spacing(1)
@@ -1401,304 +2825,366 @@ fn render_schema_view(
     theme: &Theme,
     is_focused: bool,
 ) {
-    use ratatui::widgets::Paragraph;
+    let lines = schema_lines(tab, theme);
+    // `G` jumps far past the end; stop where the last row reaches the bottom
+    let scroll = crate::ui::layout::clamp_scroll(
+        tab.scroll_offset_y,
+        crate::ui::layout::wrapped_rows(&lines, area.width.saturating_sub(2)),
+        area.height.saturating_sub(2) as usize,
+    );
 
-    // Build comprehensive schema information as text lines
-    let mut lines = Vec::new();
+    // Render as scrollable paragraph
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    " {} - Schema View [t] Toggle | [j/k] Scroll ",
+                    tab.table_name
+                ))
+                .border_style(if is_focused {
+                    Style::default().fg(theme.get_color("active_border"))
+                } else {
+                    Style::default().fg(theme.get_color("border"))
+                }),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((scroll as u16, 0));
 
-    // Title
+    f.render_widget(paragraph, area);
+    tab.scroll_offset_y = scroll;
+}
+
+/// Start a schema view section with a ━━━ heading
+fn push_schema_section(lines: &mut Vec<Line<'_>>, title: &str, theme: &Theme) {
+    if !lines.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(""));
+    }
     lines.push(Line::from(vec![Span::styled(
-        format!("📋 TABLE SCHEMA: {}", tab.table_name),
+        format!("━━━ {title} ━━━"),
         Style::default()
-            .fg(theme.get_color("primary_highlight"))
+            .fg(theme.get_color("accent"))
             .add_modifier(Modifier::BOLD),
     )]));
     lines.push(Line::from(""));
+}
 
-    // Section 1: COLUMNS
+/// Placeholder for a section the table has nothing in
+fn schema_none(theme: &Theme) -> Line<'static> {
+    Line::from(Span::styled(
+        "  none",
+        Style::default().fg(theme.get_color("text_muted")),
+    ))
+}
+
+/// Column definitions followed by indexes, foreign keys, constraints and
+/// table statistics from the table's metadata
+fn schema_lines<'a>(tab: &'a TableTab, theme: &Theme) -> Vec<Line<'a>> {
+    let mut lines = Vec::new();
+
+    // Title
     lines.push(Line::from(vec![Span::styled(
-        "━━━ COLUMNS ━━━",
+        format!("📋 TABLE SCHEMA: {}", tab.table_name),
         Style::default()
-            .fg(theme.get_color("accent"))
+            .fg(theme.get_color("primary_highlight"))
             .add_modifier(Modifier::BOLD),
     )]));
-    lines.push(Line::from(""));
 
-    for col in &tab.columns {
-        let pk_marker = if col.is_primary_key { " 🔑" } else { "" };
-        let nullable = if col.is_nullable { "NULL" } else { "NOT NULL" };
+    // Section 1: COLUMNS - metadata carries defaults, the loaded columns don't
+    push_schema_section(&mut lines, "COLUMNS", theme);
+    let columns: Vec<(&str, &str, bool, bool, Option<&str>)> = match &tab.table_metadata {
+        Some(metadata) if !metadata.columns_summary.is_empty() => metadata
+            .columns_summary
+            .iter()
+            .map(|col| {
+                (
+                    col.name.as_str(),
+                    col.data_type.as_str(),
+                    col.is_nullable,
+                    col.is_primary_key,
+                    col.default_value.as_deref(),
+                )
+            })
+            .collect(),
+        _ => tab
+            .columns
+            .iter()
+            .map(|col| {
+                (
+                    col.name.as_str(),
+                    col.data_type.as_str(),
+                    col.is_nullable,
+                    col.is_primary_key,
+                    None,
+                )
+            })
+            .collect(),
+    };
+    let name_width = columns
+        .iter()
+        .map(|(name, ..)| name.chars().count())
+        .max()
+        .unwrap_or(0);
+    let type_width = columns
+        .iter()
+        .map(|(_, data_type, ..)| data_type.chars().count())
+        .max()
+        .unwrap_or(0);
 
-        lines.push(Line::from(vec![
+    for (name, data_type, is_nullable, is_primary_key, default) in columns {
+        let nullable = if is_nullable { "NULL" } else { "NOT NULL" };
+        let mut spans = vec![
             Span::styled("  • ", Style::default().fg(theme.get_color("success"))),
             Span::styled(
-                format!("{}{}", col.name, pk_marker),
+                format!("{name:<name_width$}"),
                 Style::default()
                     .fg(theme.get_color("text_primary"))
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw(" : "),
-            Span::styled(&col.data_type, Style::default().fg(theme.get_color("info"))),
             Span::raw("  "),
             Span::styled(
-                nullable,
-                Style::default().fg(if col.is_nullable {
+                format!("{data_type:<type_width$}"),
+                Style::default().fg(theme.get_color("info")),
+            ),
+            Span::raw("  "),
+            Span::styled(
+                format!("{nullable:<8}"),
+                Style::default().fg(if is_nullable {
                     theme.get_color("warning")
                 } else {
                     theme.get_color("text_secondary")
                 }),
             ),
-        ]));
-    }
-
-    // Section 2: INDEXES (if metadata available)
-    if let Some(metadata) = &tab.table_metadata {
-        if !metadata.indexes.is_empty() {
-            lines.push(Line::from(""));
-            lines.push(Line::from(""));
-            lines.push(Line::from(vec![Span::styled(
-                "━━━ INDEXES ━━━",
+        ];
+        if is_primary_key {
+            spans.push(Span::styled(
+                "  🔑 PK",
                 Style::default()
-                    .fg(theme.get_color("accent"))
-                    .add_modifier(Modifier::BOLD),
-            )]));
-            lines.push(Line::from(""));
-
-            for idx in &metadata.indexes {
-                let idx_type = idx.index_type.as_deref().unwrap_or("BTREE");
-                let unique_marker = if idx.is_unique { " UNIQUE" } else { "" };
-                let primary_marker = if idx.is_primary { " PRIMARY" } else { "" };
-
-                lines.push(Line::from(vec![
-                    Span::styled("  📑 ", Style::default().fg(theme.get_color("info"))),
-                    Span::styled(
-                        &idx.name,
-                        Style::default()
-                            .fg(theme.get_color("text_primary"))
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::raw(" "),
-                    Span::styled(
-                        format!("({}{}{})", idx_type, unique_marker, primary_marker),
-                        Style::default().fg(theme.get_color("text_secondary")),
-                    ),
-                ]));
-
-                lines.push(Line::from(vec![
-                    Span::raw("     Columns: "),
-                    Span::styled(
-                        idx.columns.join(", "),
-                        Style::default().fg(theme.get_color("success")),
-                    ),
-                ]));
-
-                if let Some(size) = idx.size {
-                    let size_mb = size as f64 / (1024.0 * 1024.0);
-                    lines.push(Line::from(vec![
-                        Span::raw("     Size: "),
-                        Span::styled(
-                            format!("{:.2} MB", size_mb),
-                            Style::default().fg(theme.get_color("warning")),
-                        ),
-                    ]));
-                }
-            }
-        }
-
-        // Section 3: FOREIGN KEYS
-        if !metadata.foreign_keys.is_empty() {
-            lines.push(Line::from(""));
-            lines.push(Line::from(""));
-            lines.push(Line::from(vec![Span::styled(
-                "━━━ FOREIGN KEYS ━━━",
-                Style::default()
-                    .fg(theme.get_color("accent"))
+                    .fg(theme.get_color("primary_highlight"))
                     .add_modifier(Modifier::BOLD),
-            )]));
-            lines.push(Line::from(""));
-
-            for fk in &metadata.foreign_keys {
-                lines.push(Line::from(vec![
-                    Span::styled("  🔗 ", Style::default().fg(theme.get_color("info"))),
-                    Span::styled(
-                        &fk.constraint_name,
-                        Style::default()
-                            .fg(theme.get_color("text_primary"))
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                ]));
-
-                lines.push(Line::from(vec![
-                    Span::raw("     "),
-                    Span::styled(
-                        fk.column_names.join(", "),
-                        Style::default().fg(theme.get_color("success")),
-                    ),
-                    Span::raw(" → "),
-                    Span::styled(
-                        &fk.referenced_table,
-                        Style::default().fg(theme.get_color("info")),
-                    ),
-                    Span::raw("("),
-                    Span::styled(
-                        fk.referenced_columns.join(", "),
-                        Style::default().fg(theme.get_color("success")),
-                    ),
-                    Span::raw(")"),
-                ]));
-
-                if let (Some(on_delete), Some(on_update)) = (&fk.on_delete, &fk.on_update) {
-                    lines.push(Line::from(vec![
-                        Span::raw("     ON DELETE "),
-                        Span::styled(on_delete, Style::default().fg(theme.get_color("warning"))),
-                        Span::raw(" / ON UPDATE "),
-                        Span::styled(on_update, Style::default().fg(theme.get_color("warning"))),
-                    ]));
-                }
-            }
+            ));
         }
-
-        // Section 4: CONSTRAINTS
-        if !metadata.constraints.is_empty() {
-            lines.push(Line::from(""));
-            lines.push(Line::from(""));
-            lines.push(Line::from(vec![Span::styled(
-                "━━━ CONSTRAINTS ━━━",
-                Style::default()
-                    .fg(theme.get_color("accent"))
-                    .add_modifier(Modifier::BOLD),
-            )]));
-            lines.push(Line::from(""));
-
-            for constraint in &metadata.constraints {
-                lines.push(Line::from(vec![
-                    Span::styled("  ⚡ ", Style::default().fg(theme.get_color("warning"))),
-                    Span::styled(
-                        &constraint.constraint_type,
-                        Style::default()
-                            .fg(theme.get_color("text_primary"))
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::raw(": "),
-                    Span::styled(
-                        &constraint.name,
-                        Style::default().fg(theme.get_color("info")),
-                    ),
-                ]));
-
-                if let Some(definition) = &constraint.definition {
-                    lines.push(Line::from(vec![
-                        Span::raw("     "),
-                        Span::styled(
-                            definition,
-                            Style::default().fg(theme.get_color("text_secondary")),
-                        ),
-                    ]));
-                }
-            }
+        if let Some(default) = default {
+            spans.push(Span::raw("  DEFAULT "));
+            spans.push(Span::styled(
+                default,
+                Style::default().fg(theme.get_color("text_secondary")),
+            ));
         }
+        lines.push(Line::from(spans));
+    }
 
-        // Section 5: TABLE STATISTICS
-        lines.push(Line::from(""));
-        lines.push(Line::from(""));
-        lines.push(Line::from(vec![Span::styled(
-            "━━━ TABLE INFO ━━━",
-            Style::default()
-                .fg(theme.get_color("accent"))
-                .add_modifier(Modifier::BOLD),
-        )]));
-        lines.push(Line::from(""));
+    let Some(metadata) = &tab.table_metadata else {
+        return lines;
+    };
 
-        let table_size_mb = metadata.table_size as f64 / (1024.0 * 1024.0);
-        let indexes_size_mb = metadata.indexes_size as f64 / (1024.0 * 1024.0);
-        let total_size_mb = metadata.total_size as f64 / (1024.0 * 1024.0);
+    // Section 2: INDEXES
+    push_schema_section(&mut lines, "INDEXES", theme);
+    if metadata.indexes.is_empty() {
+        lines.push(schema_none(theme));
+    }
+    for idx in &metadata.indexes {
+        let idx_type = idx.index_type.as_deref().unwrap_or("BTREE");
+        let unique_marker = if idx.is_unique { " UNIQUE" } else { "" };
+        let primary_marker = if idx.is_primary { " PRIMARY" } else { "" };
 
         lines.push(Line::from(vec![
-            Span::raw("  • Rows: "),
+            Span::styled("  📑 ", Style::default().fg(theme.get_color("info"))),
             Span::styled(
-                format!("{}", metadata.row_count),
+                &idx.name,
                 Style::default()
-                    .fg(theme.get_color("success"))
+                    .fg(theme.get_color("text_primary"))
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw("  Columns: "),
+            Span::raw(" "),
             Span::styled(
-                format!("{}", metadata.column_count),
-                Style::default()
-                    .fg(theme.get_color("success"))
-                    .add_modifier(Modifier::BOLD),
+                format!("({}{}{})", idx_type, unique_marker, primary_marker),
+                Style::default().fg(theme.get_color("text_secondary")),
             ),
         ]));
 
+        if !idx.columns.is_empty() {
+            lines.push(Line::from(vec![
+                Span::raw("     Columns: "),
+                Span::styled(
+                    idx.columns.join(", "),
+                    Style::default().fg(theme.get_color("success")),
+                ),
+            ]));
+        }
+
+        if let Some(size) = idx.size {
+            let size_mb = size as f64 / (1024.0 * 1024.0);
+            lines.push(Line::from(vec![
+                Span::raw("     Size: "),
+                Span::styled(
+                    format!("{:.2} MB", size_mb),
+                    Style::default().fg(theme.get_color("warning")),
+                ),
+            ]));
+        }
+    }
+
+    // Section 3: FOREIGN KEYS
+    push_schema_section(&mut lines, "FOREIGN KEYS", theme);
+    if metadata.foreign_keys.is_empty() {
+        lines.push(schema_none(theme));
+    }
+    for fk in &metadata.foreign_keys {
         lines.push(Line::from(vec![
-            Span::raw("  • Table Size: "),
+            Span::styled("  🔗 ", Style::default().fg(theme.get_color("info"))),
             Span::styled(
-                format!("{:.2} MB", table_size_mb),
-                Style::default().fg(theme.get_color("info")),
-            ),
-            Span::raw("  Index Size: "),
-            Span::styled(
-                format!("{:.2} MB", indexes_size_mb),
-                Style::default().fg(theme.get_color("info")),
-            ),
-            Span::raw("  Total: "),
-            Span::styled(
-                format!("{:.2} MB", total_size_mb),
+                &fk.constraint_name,
                 Style::default()
-                    .fg(theme.get_color("warning"))
+                    .fg(theme.get_color("text_primary"))
                     .add_modifier(Modifier::BOLD),
             ),
         ]));
 
-        if let Some(last_vacuum) = &metadata.last_vacuum {
+        if !fk.referenced_table.is_empty() {
             lines.push(Line::from(vec![
-                Span::raw("  • Last Vacuum: "),
+                Span::raw("     "),
                 Span::styled(
-                    last_vacuum,
-                    Style::default().fg(theme.get_color("text_secondary")),
+                    fk.column_names.join(", "),
+                    Style::default().fg(theme.get_color("success")),
+                ),
+                Span::raw(" → "),
+                Span::styled(
+                    &fk.referenced_table,
+                    Style::default().fg(theme.get_color("info")),
                 ),
+                Span::raw("("),
+                Span::styled(
+                    fk.referenced_columns.join(", "),
+                    Style::default().fg(theme.get_color("success")),
+                ),
+                Span::raw(")"),
             ]));
         }
 
-        if let Some(last_analyze) = &metadata.last_analyze {
+        if let Some(actions) = fk.actions() {
             lines.push(Line::from(vec![
-                Span::raw("  • Last Analyze: "),
-                Span::styled(
-                    last_analyze,
-                    Style::default().fg(theme.get_color("text_secondary")),
-                ),
+                Span::raw("     "),
+                Span::styled(actions, Style::default().fg(theme.get_color("warning"))),
             ]));
         }
+    }
+
+    // Section 4: CONSTRAINTS
+    push_schema_section(&mut lines, "CONSTRAINTS", theme);
+    if metadata.constraints.is_empty() {
+        lines.push(schema_none(theme));
+    }
+    for constraint in &metadata.constraints {
+        let mut spans = vec![
+            Span::styled("  ⚡ ", Style::default().fg(theme.get_color("warning"))),
+            Span::styled(
+                &constraint.constraint_type,
+                Style::default()
+                    .fg(theme.get_color("text_primary"))
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(": "),
+            Span::styled(
+                &constraint.name,
+                Style::default().fg(theme.get_color("info")),
+            ),
+        ];
+        if !constraint.columns.is_empty() {
+            spans.push(Span::styled(
+                format!(" ({})", constraint.columns.join(", ")),
+                Style::default().fg(theme.get_color("success")),
+            ));
+        }
+        lines.push(Line::from(spans));
 
-        if let Some(owner) = &metadata.table_owner {
+        if let Some(definition) = &constraint.definition {
             lines.push(Line::from(vec![
-                Span::raw("  • Owner: "),
+                Span::raw("     "),
                 Span::styled(
-                    owner,
+                    definition,
                     Style::default().fg(theme.get_color("text_secondary")),
                 ),
             ]));
         }
     }
 
-    // Render as scrollable paragraph
-    let paragraph = Paragraph::new(lines)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(format!(
-                    " {} - Schema View [t] Toggle | [j/k] Scroll ",
-                    tab.table_name
-                ))
-                .border_style(if is_focused {
-                    Style::default().fg(theme.get_color("active_border"))
-                } else {
-                    Style::default().fg(theme.get_color("border"))
-                }),
-        )
-        .wrap(Wrap { trim: false })
-        .scroll((tab.scroll_offset_y as u16, 0));
+    // Section 5: TABLE STATISTICS
+    push_schema_section(&mut lines, "TABLE INFO", theme);
 
-    f.render_widget(paragraph, area);
+    let table_size_mb = metadata.table_size as f64 / (1024.0 * 1024.0);
+    let indexes_size_mb = metadata.indexes_size as f64 / (1024.0 * 1024.0);
+    let total_size_mb = metadata.total_size as f64 / (1024.0 * 1024.0);
+
+    lines.push(Line::from(vec![
+        Span::raw("  • Rows: "),
+        Span::styled(
+            format!("{}", metadata.row_count),
+            Style::default()
+                .fg(theme.get_color("success"))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  Columns: "),
+        Span::styled(
+            format!("{}", metadata.column_count),
+            Style::default()
+                .fg(theme.get_color("success"))
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+
+    lines.push(Line::from(vec![
+        Span::raw("  • Table Size: "),
+        Span::styled(
+            format!("{:.2} MB", table_size_mb),
+            Style::default().fg(theme.get_color("info")),
+        ),
+        Span::raw("  Index Size: "),
+        Span::styled(
+            format!("{:.2} MB", indexes_size_mb),
+            Style::default().fg(theme.get_color("info")),
+        ),
+        Span::raw("  Total: "),
+        Span::styled(
+            format!("{:.2} MB", total_size_mb),
+            Style::default()
+                .fg(theme.get_color("warning"))
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+
+    if let Some(last_vacuum) = &metadata.last_vacuum {
+        lines.push(Line::from(vec![
+            Span::raw("  • Last Vacuum: "),
+            Span::styled(
+                last_vacuum,
+                Style::default().fg(theme.get_color("text_secondary")),
+            ),
+        ]));
+    }
+
+    if let Some(last_analyze) = &metadata.last_analyze {
+        lines.push(Line::from(vec![
+            Span::raw("  • Last Analyze: "),
+            Span::styled(
+                last_analyze,
+                Style::default().fg(theme.get_color("text_secondary")),
+            ),
+        ]));
+    }
+
+    if let Some(owner) = &metadata.table_owner {
+        lines.push(Line::from(vec![
+            Span::raw("  • Owner: "),
+            Span::styled(
+                owner,
+                Style::default().fg(theme.get_color("text_secondary")),
+            ),
+        ]));
+    }
+
+    lines
 }
 
 fn render_help(f: &mut Frame, area: Rect, theme: &Theme) {
@@ -1721,7 +3207,9 @@ fn render_help(f: &mut Frame, area: Rect, theme: &Theme) {
                     .fg(theme.get_color("primary_highlight"))
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw("i - Edit cell | ESC - Save changes | Ctrl+C - Cancel edit"),
+            Span::raw(
+                "i - Edit cell | ESC - Save changes | Ctrl+C - Cancel edit | Ctrl+N - Set NULL",
+            ),
         ]),
         Line::from(vec![
             Span::styled(
@@ -1757,7 +3245,7 @@ fn render_help(f: &mut Frame, area: Rect, theme: &Theme) {
                     .fg(theme.get_color("primary_highlight"))
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw("r - Refresh data | / - Search | ? - Toggle this help"),
+            Span::raw("r - Refresh data | / - Search | s - Sort results | ? - Toggle this help"),
         ]),
     ];
 
@@ -1772,3 +3260,582 @@ fn render_help(f: &mut Frame, area: Rect, theme: &Theme) {
 
     f.render_widget(help, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tab_with_cell(value: &str, is_nullable: bool) -> TableTab {
+        let mut tab = TableTab::new("users".to_string());
+        tab.columns = vec![ColumnInfo {
+            name: "nickname".to_string(),
+            data_type: "text".to_string(),
+            is_nullable,
+            is_primary_key: false,
+            max_display_width: 10,
+        }];
+        tab.rows = vec![vec![Some(value.to_string())]];
+        tab
+    }
+
+    #[test]
+    fn test_edit_to_null() {
+        let mut tab = tab_with_cell("", true);
+        tab.start_edit();
+        assert!(!tab.edit_null);
+        for c in "\\N".chars() {
+            tab.push_edit_char(c);
+        }
+        assert!(tab.edit_null);
+        assert!(tab.validate_edit().is_ok());
+
+        let update = tab.save_edit().unwrap();
+        assert!(update.is_null);
+        assert_eq!(update.old_value.as_deref(), Some(""));
+
+        // Clearing a NULL cell saves an empty string, not NULL
+        tab.start_edit();
+        assert!(tab.edit_null);
+        tab.pop_edit_char();
+        let update = tab.save_edit().unwrap();
+        assert!(!update.is_null);
+        assert_eq!(update.new_value, "");
+    }
+
+    #[test]
+    fn test_null_rejected_for_not_null_column() {
+        let mut tab = tab_with_cell("bob", false);
+        tab.start_edit();
+        tab.set_edit_null();
+        assert!(tab.validate_edit().is_err());
+    }
+
+    #[test]
+    fn test_row_to_csv_null_and_empty() {
+        let row = vec![
+            Some("1".to_string()),
+            None,
+            Some(String::new()),
+            Some("a,b".to_string()),
+            Some("NULL".to_string()),
+        ];
+        assert_eq!(row_to_csv(&row), "1,,\"\",\"a,b\",NULL");
+
+        let columns = vec!["id".to_string(), "note, text".to_string()];
+        let rows = vec![
+            vec![Some("1".to_string()), Some("line\r\nbreak".to_string())],
+            vec![Some("2".to_string()), Some("\\.".to_string())],
+        ];
+        assert_eq!(
+            results_to_csv(&columns, &rows),
+            "id,\"note, text\"\n1,\"line\r\nbreak\"\n2,\"\\.\"\n"
+        );
+    }
+
+    #[test]
+    fn test_primary_key_where() {
+        let pk = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(c, v)| (c.to_string(), v.to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(primary_key_where(&[]), None);
+        assert_eq!(
+            primary_key_where(&pk(&[("id", "42")])).as_deref(),
+            Some("id = 42")
+        );
+        assert_eq!(
+            primary_key_where(&pk(&[("tenant_id", "7"), ("code", "o'neil")])).as_deref(),
+            Some("(tenant_id, code) = (7, 'o''neil')")
+        );
+        assert_eq!(ellipsize("abcdef", 4), "abc…");
+        assert_eq!(ellipsize("abc", 4), "abc");
+    }
+
+    #[test]
+    fn test_huge_and_binary_cells_display() {
+        let mut tab = tab_with_cell("", true);
+        tab.columns[0].data_type = DataType::Bytea.to_sql();
+        tab.set_rows(
+            vec![vec![
+                Some(format!("0x{}", "AB".repeat(10))),
+                Some("héllo".to_string()),
+            ]],
+            2,
+        );
+        tab.columns.push(ColumnInfo {
+            name: "bio".to_string(),
+            data_type: "text".to_string(),
+            is_nullable: true,
+            is_primary_key: false,
+            max_display_width: 10,
+        });
+
+        // "héllo" is cut before the two-byte é rather than through it
+        assert_eq!(tab.rows[0][1].as_deref(), Some("h"));
+        assert_eq!(tab.cell_display_text(0, 1, Some("h")), "[6 B] h…");
+        assert_eq!(
+            tab.cell_display_text(0, 0, tab.rows[0][0].as_deref()),
+            "<binary 10 B>"
+        );
+
+        tab.restore_full_value(0, 1, Some("héllo".to_string()));
+        assert_eq!(tab.cell_display_text(0, 1, Some("héllo")), "héllo");
+        assert!(tab.truncated_cells.contains_key(&(0, 0)));
+
+        // Query results are typed from their values: hex literals are binary,
+        // hex-looking text like lowercase hashes is not
+        let columns = ["blob", "hash", "empty"].map(String::from);
+        let mut result = TableTab::new("Query Result".to_string());
+        result.set_result_rows(
+            &columns,
+            vec![
+                vec![
+                    Some(format!("0x{}", "00".repeat(1536))),
+                    Some("0xab12".to_string()),
+                    None,
+                ],
+                vec![None, Some("0xAB12".to_string()), None],
+            ],
+            usize::MAX,
+        );
+        assert!(result.is_binary_column(0));
+        assert!(!result.is_binary_column(1));
+        assert!(!result.is_binary_column(2));
+        assert_eq!(
+            result.cell_display_text(0, 0, result.rows[0][0].as_deref()),
+            "<binary 1.5 KB>"
+        );
+    }
+
+    #[test]
+    fn test_reflow_keeps_selection_in_view() {
+        let mut tab = TableTab::new("events".to_string());
+        tab.columns = (0..30)
+            .map(|i| ColumnInfo {
+                name: format!("c{i}"),
+                data_type: "text".to_string(),
+                is_nullable: true,
+                is_primary_key: false,
+                max_display_width: 7,
+            })
+            .collect();
+        tab.rows = vec![vec![Some(String::new()); 30]; 50];
+        tab.refresh_column_widths();
+        tab.selected_row = 45;
+        tab.selected_col = 29;
+
+        // 24 rows minus borders and header leave 20 visible rows
+        tab.reflow(Rect::new(0, 0, 200, 24));
+        assert_eq!(tab.scroll_offset_y, 26);
+        let columns = tab.calculate_visible_columns(200);
+        assert!(columns.contains(&29));
+
+        // Shrinking scrolls just enough to keep the cursor row and column on screen
+        tab.reflow(Rect::new(0, 0, 60, 14));
+        assert_eq!(tab.scroll_offset_y, 36);
+        assert!(tab.calculate_visible_columns(60).contains(&29));
+
+        tab.view_mode = TableViewMode::Source;
+        tab.source_lines = vec![Line::from(""); 30];
+        tab.scroll_offset_y = 25;
+        tab.reflow(Rect::new(0, 0, 60, 12));
+        assert_eq!(tab.scroll_offset_y, 20);
+    }
+
+    #[test]
+    fn test_auto_fit_and_resize_columns() {
+        let mut tab = TableTab::new("users".to_string());
+        tab.columns = ["id", "email"]
+            .map(|name| ColumnInfo {
+                name: name.to_string(),
+                data_type: "text".to_string(),
+                is_nullable: true,
+                is_primary_key: false,
+                max_display_width: 50,
+            })
+            .to_vec();
+        tab.rows = vec![
+            vec![Some("1".to_string()), Some("ann@example.com".to_string())],
+            vec![Some("22".to_string()), Some("bo@example.com".to_string())],
+        ];
+        tab.refresh_column_widths();
+        assert_eq!(tab.column_widths, vec![33, 33]);
+
+        // Fitted to the widest of header and values, never under the minimum
+        assert!(tab.toggle_auto_fit());
+        assert_eq!(tab.column_widths, vec![4 + 3, 17 + 3]);
+
+        tab.selected_col = 1;
+        assert_eq!(
+            tab.resize_selected_column(-(COLUMN_RESIZE_STEP as isize)),
+            Some(("email".to_string(), 15))
+        );
+        // The override outlives switching auto-fit off
+        assert!(!tab.toggle_auto_fit());
+        assert_eq!(tab.column_widths, vec![33, 18]);
+        tab.width_overrides.insert("email".to_string(), 2);
+        tab.resize_selected_column(-100);
+        assert_eq!(tab.width_overrides["email"], MIN_COLUMN_WIDTH);
+    }
+
+    #[test]
+    fn test_layout_bounded_by_viewport_not_column_count() {
+        let mut tab = TableTab::new("wide".to_string());
+        tab.columns = (0..400)
+            .map(|i| ColumnInfo {
+                name: format!("c{i}"),
+                data_type: "text".to_string(),
+                is_nullable: true,
+                is_primary_key: false,
+                max_display_width: 7,
+            })
+            .collect();
+        tab.refresh_column_widths();
+
+        // 10-wide slots in 104 columns of screen: 10 columns, whatever the total
+        let visible = tab.calculate_visible_columns(104);
+        assert_eq!(visible, 0..10);
+
+        // `$` scrolls so the last column is the rightmost one shown
+        tab.jump_to_last_col();
+        tab.ensure_column_visible(104);
+        assert_eq!(tab.scroll_offset_x, 390);
+        assert_eq!(tab.calculate_visible_columns(104), 390..400);
+
+        // A column wider than the viewport is still shown on its own
+        assert_eq!(visible_column_range(&[40, 10], 0, 20), 0..1);
+        assert_eq!(first_column_showing(&[10, 10, 10], 2, 24), 1);
+    }
+
+    #[test]
+    fn test_row_numbers_are_absolute_and_take_their_width() {
+        let mut tab = TableTab::new("events".to_string());
+        tab.total_rows = 42_305;
+        tab.rows_per_page = 100;
+        tab.current_page = 423;
+        assert_eq!(tab.row_number_width(), 0);
+        assert_eq!(tab.data_width(104), 104);
+
+        assert!(tab.toggle_row_numbers());
+        assert_eq!(tab.absolute_row_number(4), 42_305);
+        // Five digits plus the column gap come off the data columns
+        assert_eq!(tab.row_number_width(), 5);
+        assert_eq!(tab.data_width(104), 98);
+        // The cursor still moves over data columns only
+        assert_eq!(tab.selected_col, 0);
+
+        assert!(!tab.toggle_row_numbers());
+        assert_eq!(tab.data_width(104), 104);
+    }
+
+    #[test]
+    fn test_new_tabs_and_closing_a_table() {
+        let mut state = TableViewerState::new();
+        state.add_tab("orders".to_string());
+        state.add_tab("users".to_string());
+        assert_eq!(state.add_tab("orders".to_string()), 0);
+        assert_eq!(state.add_new_tab("orders".to_string()), 2);
+        state.add_tab("items".to_string());
+        assert_eq!(state.tabs.len(), 4);
+
+        // Focus stays on "items" once the two "orders" tabs before it close
+        assert_eq!(state.close_tabs_for("orders"), 2);
+        let names: Vec<&str> = state
+            .tabs
+            .iter()
+            .map(|tab| tab.table_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["users", "items"]);
+        assert_eq!(state.active_tab, 1);
+        assert_eq!(state.close_tabs_for("items"), 1);
+        assert_eq!(state.active_tab, 0);
+        assert_eq!(state.close_tabs_for("missing"), 0);
+    }
+
+    #[test]
+    fn test_row_as_insert_uses_source_table() {
+        let mut state = TableViewerState::new();
+        state.tabs.push(tab_with_cell("O'Neil", true));
+        state.tabs[0].modified_cells.insert((0, 0), None);
+        assert_eq!(
+            state.row_as(CopyFormat::Insert, &DatabaseType::MySQL),
+            Ok("INSERT INTO `users` (`nickname`) VALUES (NULL);".to_string())
+        );
+
+        state.tabs[0].table_name = "Query Result (10:42:00)".to_string();
+        assert_eq!(state.tabs[0].source_table(), None);
+        assert!(state
+            .row_as(CopyFormat::Insert, &DatabaseType::MySQL)
+            .is_err());
+        assert_eq!(
+            state.row_as(CopyFormat::Json, &DatabaseType::MySQL),
+            Ok(r#"{"nickname":null}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_schema_view_details() {
+        use crate::database::{ColumnSummary, ConstraintInfo, ForeignKeyInfo};
+
+        let mut tab = TableTab::new("orders".to_string());
+        let mut metadata = TableMetadata::basic(
+            "orders".to_string(),
+            0,
+            2,
+            0,
+            0,
+            0,
+            vec!["id".to_string()],
+            vec![],
+            vec![],
+            None,
+        );
+        metadata.columns_summary = vec![
+            ColumnSummary {
+                name: "id".to_string(),
+                data_type: "INTEGER".to_string(),
+                is_nullable: false,
+                is_primary_key: true,
+                default_value: Some("nextval('orders_id_seq')".to_string()),
+                max_length: None,
+            },
+            ColumnSummary {
+                name: "user_id".to_string(),
+                data_type: "INTEGER".to_string(),
+                is_nullable: true,
+                is_primary_key: false,
+                default_value: None,
+                max_length: None,
+            },
+        ];
+        metadata.foreign_keys = vec![ForeignKeyInfo {
+            constraint_name: "orders_user_id_fkey".to_string(),
+            column_names: vec!["user_id".to_string()],
+            referenced_table: "users".to_string(),
+            referenced_columns: vec!["id".to_string()],
+            on_delete: Some("CASCADE".to_string()),
+            on_update: None,
+        }];
+        metadata.constraints = vec![ConstraintInfo {
+            name: "orders_total_check".to_string(),
+            constraint_type: "CHECK".to_string(),
+            definition: Some("CHECK (total >= 0)".to_string()),
+            columns: vec!["total".to_string()],
+        }];
+        tab.table_metadata = Some(metadata);
+
+        let text: Vec<String> = schema_lines(&tab, &Theme::default())
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+        assert!(text.contains(
+            &"  • id       INTEGER  NOT NULL  🔑 PK  DEFAULT nextval('orders_id_seq')".to_string()
+        ));
+        assert!(text.contains(&"  • user_id  INTEGER  NULL    ".to_string()));
+        assert!(text.contains(&"     user_id → users(id)".to_string()));
+        assert!(text.contains(&"     ON DELETE CASCADE".to_string()));
+        assert!(text.contains(&"  ⚡ CHECK: orders_total_check (total)".to_string()));
+        // Sections without entries still say so
+        let indexes = text
+            .iter()
+            .position(|line| line == "━━━ INDEXES ━━━")
+            .unwrap();
+        assert_eq!(text[indexes + 2], "  none");
+    }
+
+    #[test]
+    fn test_frozen_columns_stay_in_view() {
+        let mut tab = TableTab::new("wide".to_string());
+        tab.columns = (0..400)
+            .map(|i| ColumnInfo {
+                name: format!("c{i}"),
+                data_type: "text".to_string(),
+                is_nullable: true,
+                is_primary_key: false,
+                max_display_width: 7,
+            })
+            .collect();
+        tab.refresh_column_widths();
+
+        // Freezing through the cursor, then the same key again unfreezes
+        tab.selected_col = 1;
+        assert_eq!(tab.toggle_freeze_columns(), 2);
+        assert_eq!(tab.toggle_freeze_columns(), 0);
+
+        // Ctrl+F steps one column at a time, never past the cursor
+        assert_eq!(tab.freeze_more_columns(), 1);
+        assert_eq!(tab.freeze_more_columns(), 2);
+        assert_eq!(tab.freeze_more_columns(), 2);
+        assert_eq!(tab.freeze_fewer_columns(), 1);
+        assert_eq!(tab.freeze_fewer_columns(), 0);
+        assert_eq!(tab.freeze_fewer_columns(), 0);
+        tab.selected_col = 0;
+        assert_eq!(tab.toggle_freeze_columns(), 1);
+
+        // The frozen column and separator leave room for 8 of the rest
+        assert_eq!(tab.calculate_visible_columns(104), 1..9);
+        tab.jump_to_last_col();
+        tab.ensure_column_visible(104);
+        assert_eq!(tab.calculate_visible_columns(104), 392..400);
+
+        // Going back to the frozen column doesn't scroll
+        tab.selected_col = 0;
+        tab.ensure_column_visible(104);
+        assert_eq!(tab.scroll_offset_x, 392);
+        tab.selected_col = 5;
+        tab.ensure_column_visible(104);
+        assert_eq!(tab.calculate_visible_columns(104), 5..13);
+    }
+
+    #[test]
+    fn test_display_cell_shows_empty_string() {
+        assert_eq!(display_cell(Some("")), "\"\"");
+        assert_eq!(display_cell(None), "NULL");
+        assert_eq!(display_cell(Some("NULL")), "NULL");
+        assert_eq!(display_cell(Some("bob")), "bob");
+    }
+
+    #[test]
+    fn test_sort_query_results_survives_rerun() {
+        let columns = ["Variable_name".to_string(), "Value".to_string()];
+        let rows = |values: &[(&str, Option<&str>)]| -> Vec<Vec<Option<String>>> {
+            values
+                .iter()
+                .map(|(name, value)| vec![Some(name.to_string()), value.map(str::to_string)])
+                .collect()
+        };
+        let mut tab = TableTab::new("Status (prod)".to_string());
+        tab.set_result_rows(
+            &columns,
+            rows(&[
+                ("Questions", Some("120")),
+                ("Uptime", Some("9")),
+                ("Ssl_cipher", Some("")),
+                ("Ssl_version", None),
+            ]),
+            usize::MAX,
+        );
+        // Table tabs only hold a page, so they can't be sorted
+        assert!(!tab.sort_by_selected_column());
+
+        tab.origin = Some(WatchedQuery {
+            connection_id: "local".to_string(),
+            query: "SHOW GLOBAL STATUS".to_string(),
+        });
+        tab.selected_col = 1;
+        tab.selected_row = 1;
+        assert!(tab.sort_by_selected_column());
+        let values: Vec<Option<&str>> = tab.rows.iter().map(|r| r[1].as_deref()).collect();
+        // Numbers by value, before text, NULL last
+        assert_eq!(values, vec![Some("9"), Some("120"), Some(""), None]);
+        assert_eq!(tab.rows[tab.selected_row][0].as_deref(), Some("Uptime"));
+
+        assert!(tab.sort_by_selected_column());
+        assert_eq!(tab.sort, Some((1, false)));
+        tab.start_search();
+        tab.update_search("uptime");
+        tab.set_result_rows(
+            &columns,
+            rows(&[
+                ("Questions", Some("130")),
+                ("Uptime", Some("10")),
+                ("Ssl_cipher", Some("")),
+                ("Ssl_version", None),
+            ]),
+            usize::MAX,
+        );
+        let names: Vec<&str> = tab.rows.iter().filter_map(|r| r[0].as_deref()).collect();
+        assert_eq!(
+            names,
+            vec!["Ssl_version", "Ssl_cipher", "Questions", "Uptime"]
+        );
+        assert_eq!(tab.search_results, vec![(3, 0)]);
+    }
+
+    #[test]
+    fn test_vertical_view_steps_between_records() {
+        let mut tab = tab_with_cell("alice", true);
+        tab.rows.push(vec![Some("bob".to_string())]);
+        tab.rows.push(vec![Some("carol".to_string())]);
+
+        assert!(tab.toggle_vertical_view());
+        assert_eq!(tab.view_mode, TableViewMode::Vertical);
+        tab.move_right();
+        tab.move_down();
+        assert_eq!(tab.selected_row, 2);
+        tab.move_left();
+        assert_eq!((tab.selected_row, tab.selected_col), (1, 0));
+
+        // Back to the grid on the same row; schema tabs don't switch
+        assert!(tab.toggle_vertical_view());
+        assert_eq!((tab.view_mode, tab.selected_row), (TableViewMode::Data, 1));
+        tab.toggle_view_mode();
+        assert!(!tab.toggle_vertical_view());
+        assert_eq!(tab.view_mode, TableViewMode::Schema);
+    }
+
+    #[test]
+    fn test_vertical_view_keeps_selected_field_visible() {
+        let mut tab = tab_with_cell("alice", true);
+        tab.columns = (0..30)
+            .map(|i| ColumnInfo {
+                name: format!("field_{i}"),
+                data_type: "text".to_string(),
+                is_nullable: true,
+                is_primary_key: false,
+                max_display_width: 10,
+            })
+            .collect();
+        tab.rows = vec![vec![Some("x".to_string()); 30]];
+        assert!(tab.toggle_vertical_view());
+
+        // A 10-line pane holds the header and nine fields
+        assert_eq!(tab.record_field_offset(10), 0);
+        tab.jump_to_last_col();
+        assert_eq!(tab.record_field_offset(10), 21);
+        tab.selected_col = 8;
+        assert_eq!(tab.record_field_offset(10), 0);
+        assert_eq!(tab.record_field_offset(0), 8);
+    }
+
+    #[test]
+    fn test_diff_against_previous_run_of_same_query() {
+        let mut state = TableViewerState::new();
+        let runs = [
+            vec![["a", "queued"], ["b", "running"], ["c", "running"]],
+            vec![["a", "queued"], ["b", "done"], ["d", "queued"]],
+        ];
+        for (i, rows) in runs.iter().enumerate() {
+            let idx = state.add_tab(format!("Query Result (run {i})"));
+            let tab = &mut state.tabs[idx];
+            tab.set_result_rows(
+                &["job".to_string(), "status".to_string()],
+                rows.iter()
+                    .map(|row| row.iter().map(|v| Some(v.to_string())).collect())
+                    .collect(),
+                usize::MAX,
+            );
+            tab.origin = Some(WatchedQuery {
+                connection_id: "local".to_string(),
+                query: "SELECT job, status FROM jobs".to_string(),
+            });
+        }
+
+        let diff = state.open_diff_tab(100).unwrap();
+        assert_eq!((diff.added, diff.removed, diff.changed), (1, 1, 1));
+        let diff_tab = state.current_tab().unwrap();
+        let first_cells: Vec<&str> = diff_tab
+            .rows
+            .iter()
+            .filter_map(|r| r[2].as_deref())
+            .collect();
+        assert_eq!(first_cells, vec!["", "running → done", "queued", "running"]);
+    }
+}
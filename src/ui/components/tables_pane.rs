@@ -2,7 +2,10 @@
 
 #![forbid(unsafe_code)]
 
-use crate::{app::AppState, database::objects::DatabaseObjectList};
+use crate::{
+    app::AppState,
+    database::objects::{DatabaseObjectList, DatabaseObjectType},
+};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -61,6 +64,15 @@ pub fn render_tables_pane(
         );
 
     frame.render_stateful_widget(tables, area, &mut state.ui.tables_list_state);
+
+    // Remember where the selected row landed so popups can anchor to it
+    let offset = state.ui.tables_list_state.offset();
+    state.ui.tables_selected_row = state.ui.tables_list_state.selected().map(|selected| Rect {
+        x: area.x + 1,
+        y: area.y + 1 + selected.saturating_sub(offset) as u16,
+        width: area.width.saturating_sub(2),
+        height: 1,
+    });
 }
 
 /// Get message when no database is connected
@@ -126,9 +138,9 @@ fn get_selectable_items_list(
             // Empty line separator
             items.push(ListItem::new(""));
         } else if item.is_selectable {
-            // Selectable table/view item
+            // Selectable table/view item, tinted by object type
             let text_color = if is_enabled {
-                Color::White
+                object_type_color(&item.object_type)
             } else {
                 Color::DarkGray
             };
@@ -174,6 +186,17 @@ fn get_selectable_items_list(
     items
 }
 
+/// Item color per object type so views stand apart from tables
+fn object_type_color(object_type: &DatabaseObjectType) -> Color {
+    match object_type {
+        DatabaseObjectType::Table => Color::White,
+        DatabaseObjectType::View => Color::LightGreen,
+        DatabaseObjectType::MaterializedView => Color::LightMagenta,
+        DatabaseObjectType::ForeignTable => Color::LightYellow,
+        DatabaseObjectType::SystemTable => Color::Gray,
+    }
+}
+
 /// Get adaptive title based on database objects and connection info
 fn get_adaptive_title(
     db_objects: &Option<DatabaseObjectList>,
@@ -208,15 +231,29 @@ fn get_adaptive_title(
             title_parts.push(counts.join(", "));
         }
 
-        let base_title = if !title_parts.is_empty() {
+        // `T` hides some groups
+        if ui_state.tables_type_filter != crate::state::ui::TableTypeFilter::All {
+            title_parts.push(ui_state.tables_type_filter.label().to_string());
+        }
+
+        let mut base_title = if !title_parts.is_empty() {
             format!(" [2] Tables/Views ({}) ", title_parts.join(" | "))
         } else {
             " [2] Tables/Views ".to_string()
         };
 
+        // Objects are still streaming in
+        if let Some(listing) = &db_state.object_listing {
+            base_title.push_str(&format!("(loading… {} so far) ", listing.loaded));
+        }
+
         // Add search indicator if search is active
         if ui_state.tables_search_active {
-            let filter_count = ui_state.filtered_table_items.len();
+            let filter_count = ui_state
+                .filtered_table_items
+                .iter()
+                .filter(|item| item.is_selectable)
+                .count();
             format!(
                 " [2] Tables/Views [Search: {} result{}]",
                 filter_count,
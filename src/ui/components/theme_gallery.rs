@@ -0,0 +1,148 @@
+// FilePath: src/ui/components/theme_gallery.rs
+
+#![forbid(unsafe_code)]
+
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+use std::path::PathBuf;
+
+/// Theme colors shown as swatches next to each name
+const SWATCHES: [&str; 6] = [
+    "background",
+    "foreground",
+    "primary_highlight",
+    "success",
+    "warning",
+    "error",
+];
+
+/// `:theme list` overlay: moving the cursor previews a theme, Enter keeps it
+/// and Esc goes back to the theme that was active when it opened
+#[derive(Debug, Clone)]
+pub struct ThemeGallery {
+    /// Themes with the file each was loaded from (None for built-ins)
+    entries: Vec<(Theme, Option<PathBuf>)>,
+    pub selected: usize,
+    /// Theme to restore on Esc
+    original: (Theme, Option<PathBuf>),
+}
+
+impl ThemeGallery {
+    /// Gallery over `entries` with the cursor on the active theme
+    pub fn new(entries: Vec<(Theme, Option<PathBuf>)>, active: (Theme, Option<PathBuf>)) -> Self {
+        let selected = entries
+            .iter()
+            .position(|(theme, _)| theme.name == active.0.name)
+            .unwrap_or(0);
+        Self {
+            entries,
+            selected,
+            original: active,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = (self.selected + 1) % self.entries.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = (self.selected + self.entries.len() - 1) % self.entries.len();
+        }
+    }
+
+    /// Theme under the cursor
+    pub fn selected(&self) -> Option<&(Theme, Option<PathBuf>)> {
+        self.entries.get(self.selected)
+    }
+
+    /// Theme that was active when the gallery opened
+    pub fn original(&self) -> &(Theme, Option<PathBuf>) {
+        &self.original
+    }
+}
+
+pub fn render_theme_gallery(frame: &mut Frame, gallery: &ThemeGallery) {
+    let screen = frame.area();
+    let name_width = gallery
+        .entries
+        .iter()
+        .map(|(theme, _)| theme.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    let width = (name_width as u16 + SWATCHES.len() as u16 * 3 + 8)
+        .max(40)
+        .min(screen.width.saturating_sub(4));
+    let height = (gallery.entries.len() as u16 + 2).min(screen.height.saturating_sub(4));
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 4,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(" Themes ")
+        .title_bottom(" j/k preview · Enter keep · Esc revert ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let items: Vec<ListItem> = gallery
+        .entries
+        .iter()
+        .map(|(theme, path)| {
+            let mut spans = vec![Span::styled(
+                format!(" {:<name_width$} ", theme.name),
+                Style::default().fg(Color::White),
+            )];
+            spans.extend(
+                SWATCHES
+                    .iter()
+                    .map(|key| Span::styled("██ ", Style::default().fg(theme.get_color(key)))),
+            );
+            if path.is_none() {
+                spans.push(Span::styled(
+                    "built-in",
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items).block(block).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut list_state = ListState::default();
+    list_state.select(Some(gallery.selected));
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gallery_starts_on_active_theme_and_wraps() {
+        let entries = vec![(Theme::dark_theme(), None), (Theme::light_theme(), None)];
+        let mut gallery = ThemeGallery::new(entries, (Theme::light_theme(), None));
+        assert_eq!(gallery.selected, 1);
+
+        gallery.select_next();
+        assert_eq!(gallery.selected().unwrap().0.name, "LazyDark");
+        gallery.select_previous();
+        assert_eq!(gallery.selected().unwrap().0.name, "LazyLight");
+        assert_eq!(gallery.original().0.name, "LazyLight");
+    }
+}
@@ -2,6 +2,7 @@
 
 #![forbid(unsafe_code)]
 
+use crate::config::ToastConfig;
 use crate::ui::theme::Theme;
 use ratatui::{
     layout::{Alignment, Rect},
@@ -10,6 +11,7 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
 /// Toast notification types
@@ -97,11 +99,24 @@ impl Toast {
     }
 }
 
+/// A toast recorded in the session history
+#[derive(Debug, Clone)]
+pub struct ToastHistoryEntry {
+    pub message: String,
+    pub toast_type: ToastType,
+    pub timestamp: chrono::DateTime<chrono::Local>,
+}
+
 /// Toast manager to handle multiple notifications
 #[derive(Debug, Clone)]
 pub struct ToastManager {
     toasts: Vec<Toast>,
     max_toasts: usize,
+    /// Every toast shown this session, oldest first
+    history: VecDeque<ToastHistoryEntry>,
+    max_history: usize,
+    /// Display durations per severity, from `[toasts]` in the config
+    durations: ToastConfig,
 }
 
 impl ToastManager {
@@ -110,11 +125,54 @@ impl ToastManager {
         Self {
             toasts: Vec::new(),
             max_toasts: 5, // Show max 5 toasts at once
+            history: VecDeque::new(),
+            max_history: 200,
+            durations: ToastConfig::default(),
+        }
+    }
+
+    /// Apply durations and history size from the config
+    pub fn configure(&mut self, config: &ToastConfig) {
+        self.max_history = config.history_size.max(1);
+        self.durations = config.clone();
+        while self.history.len() > self.max_history {
+            self.history.pop_front();
         }
     }
 
+    /// Configured display duration for a severity
+    fn duration_for(&self, toast_type: &ToastType) -> Duration {
+        let secs = match toast_type {
+            ToastType::Success => self.durations.success_secs,
+            ToastType::Error => self.durations.error_secs,
+            ToastType::Warning => self.durations.warning_secs,
+            ToastType::Info => self.durations.info_secs,
+        };
+        Duration::from_secs(secs)
+    }
+
+    /// Build a toast with the configured duration and add it
+    fn add_typed(&mut self, message: impl Into<String>, toast_type: ToastType) {
+        let mut toast = Toast::new(message, toast_type);
+        toast.duration = self.duration_for(&toast.toast_type);
+        self.add(toast);
+    }
+
     /// Add a new toast
     pub fn add(&mut self, toast: Toast) {
+        // Errors also go to the log files, which outlive the session history
+        if toast.toast_type == ToastType::Error {
+            crate::log_error!("{}", toast.message);
+        }
+        self.history.push_back(ToastHistoryEntry {
+            message: toast.message.clone(),
+            toast_type: toast.toast_type.clone(),
+            timestamp: chrono::Local::now(),
+        });
+        while self.history.len() > self.max_history {
+            self.history.pop_front();
+        }
+
         self.toasts.push(toast);
 
         // Keep only the most recent toasts
@@ -125,22 +183,22 @@ impl ToastManager {
 
     /// Add a success toast
     pub fn success(&mut self, message: impl Into<String>) {
-        self.add(Toast::success(message));
+        self.add_typed(message, ToastType::Success);
     }
 
     /// Add an error toast
     pub fn error(&mut self, message: impl Into<String>) {
-        self.add(Toast::error(message));
+        self.add_typed(message, ToastType::Error);
     }
 
     /// Add a warning toast
     pub fn warning(&mut self, message: impl Into<String>) {
-        self.add(Toast::warning(message));
+        self.add_typed(message, ToastType::Warning);
     }
 
     /// Add an info toast
     pub fn info(&mut self, message: impl Into<String>) {
-        self.add(Toast::info(message));
+        self.add_typed(message, ToastType::Info);
     }
 
     /// Remove expired toasts
@@ -157,6 +215,16 @@ impl ToastManager {
     pub fn clear(&mut self) {
         self.toasts.clear();
     }
+
+    /// Forget every recorded toast
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Get the toast history, oldest first
+    pub fn history(&self) -> &VecDeque<ToastHistoryEntry> {
+        &self.history
+    }
 }
 
 impl Default for ToastManager {
@@ -240,3 +308,30 @@ fn render_single_toast(f: &mut Frame, toast: &Toast, area: Rect, theme: &Theme)
 
     f.render_widget(paragraph, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configured_durations_and_history_size() {
+        let mut manager = ToastManager::new();
+        manager.configure(&ToastConfig {
+            error_secs: 30,
+            history_size: 2,
+            ..ToastConfig::default()
+        });
+        manager.info("one");
+        manager.error("two");
+        manager.warning("three");
+
+        assert_eq!(manager.toasts[1].duration, Duration::from_secs(30));
+        assert_eq!(manager.toasts[0].duration, Duration::from_secs(3));
+        let messages: Vec<_> = manager
+            .history()
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(messages, ["two", "three"]);
+    }
+}
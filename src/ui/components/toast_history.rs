@@ -0,0 +1,224 @@
+// FilePath: src/ui/components/toast_history.rs
+
+#![forbid(unsafe_code)]
+
+use crate::ui::{
+    components::toast::{ToastHistoryEntry, ToastManager, ToastType},
+    theme::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Full-screen overlay listing the toast notifications of this session.
+/// Opened with `~` or `:messages` rather than Ctrl+H, which stays the
+/// `focus_left` pane key and arrives as Backspace on many terminals.
+#[derive(Debug, Clone, Default)]
+pub struct ToastHistoryPane {
+    /// Index of the selected entry in the filtered list
+    pub selected: usize,
+    /// Text filter applied to messages
+    pub filter: String,
+    /// Whether the filter input is being edited
+    pub filter_active: bool,
+    /// First `y` of `yy` was pressed
+    pub pending_yank: bool,
+}
+
+impl ToastHistoryPane {
+    /// Create a new toast history pane
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reset selection and filter when the overlay is opened
+    pub fn reset(&mut self) {
+        self.selected = 0;
+        self.filter.clear();
+        self.filter_active = false;
+        self.pending_yank = false;
+    }
+
+    /// Get the entries matching the filter, newest first
+    pub fn filtered_entries<'a>(&self, manager: &'a ToastManager) -> Vec<&'a ToastHistoryEntry> {
+        let filter = self.filter.to_lowercase();
+        manager
+            .history()
+            .iter()
+            .rev()
+            .filter(|entry| filter.is_empty() || entry.message.to_lowercase().contains(&filter))
+            .collect()
+    }
+
+    /// Move selection down
+    pub fn select_next(&mut self, count: usize) {
+        if count > 0 && self.selected < count - 1 {
+            self.selected += 1;
+        }
+    }
+
+    /// Move selection up
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Drop the whole history, e.g. after reading through a burst of errors
+    pub fn clear(&mut self, manager: &mut ToastManager) {
+        manager.clear_history();
+        self.selected = 0;
+    }
+
+    /// Copy the selected message to the clipboard
+    pub fn copy_selected(&self, manager: &ToastManager) -> Result<(), String> {
+        let entries = self.filtered_entries(manager);
+        let entry = entries
+            .get(self.selected)
+            .ok_or_else(|| "No message selected".to_string())?;
+
+        let mut clipboard =
+            arboard::Clipboard::new().map_err(|e| format!("Failed to access clipboard: {e}"))?;
+        clipboard
+            .set_text(entry.message.clone())
+            .map_err(|e| format!("Failed to copy to clipboard: {e}"))?;
+
+        Ok(())
+    }
+
+    /// Render the toast history as a full-screen overlay
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme, manager: &ToastManager) {
+        frame.render_widget(Clear, area);
+
+        let main_block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Notification History (~ to toggle) ")
+            .title_alignment(Alignment::Center)
+            .style(
+                Style::default()
+                    .bg(theme.get_color("background"))
+                    .fg(theme.get_color("foreground")),
+            );
+
+        let inner_area = main_block.inner(area);
+        frame.render_widget(main_block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(3),    // Messages
+                Constraint::Length(3), // Filter / help text
+            ])
+            .split(inner_area);
+
+        let entries = self.filtered_entries(manager);
+
+        if entries.is_empty() {
+            let empty_text = if self.filter.is_empty() {
+                "No notifications yet"
+            } else {
+                "No notifications match the filter"
+            };
+            let paragraph = Paragraph::new(empty_text)
+                .style(Style::default().fg(theme.get_color("inactive_pane")))
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, chunks[0]);
+        } else {
+            let items: Vec<ListItem> = entries
+                .iter()
+                .map(|entry| format_entry(entry, theme))
+                .collect();
+
+            let list = List::new(items)
+                .style(Style::default().bg(theme.get_color("background")))
+                .highlight_style(
+                    Style::default()
+                        .bg(theme.get_color("selection_bg"))
+                        .add_modifier(Modifier::BOLD),
+                );
+
+            let mut list_state = ListState::default();
+            list_state.select(Some(self.selected.min(entries.len() - 1)));
+            frame.render_stateful_widget(list, chunks[0], &mut list_state);
+        }
+
+        let footer = if self.filter_active {
+            Line::from(vec![
+                Span::styled(
+                    "/",
+                    Style::default().fg(theme.get_color("primary_highlight")),
+                ),
+                Span::raw(self.filter.clone()),
+                Span::styled(
+                    "█",
+                    Style::default().fg(theme.get_color("primary_highlight")),
+                ),
+            ])
+        } else if !self.filter.is_empty() {
+            Line::from(format!(
+                "Filter: {} • j/k: Navigate • /: Edit filter • yy: Copy message • c: Clear • Esc: Close",
+                self.filter
+            ))
+        } else {
+            Line::from("j/k: Navigate • /: Filter • yy: Copy message • c: Clear • Esc: Close")
+        };
+
+        let help = Paragraph::new(footer)
+            .block(Block::default().borders(Borders::TOP))
+            .style(Style::default().fg(theme.get_color("foreground")))
+            .alignment(Alignment::Center);
+        frame.render_widget(help, chunks[1]);
+    }
+}
+
+/// Format a single history entry as a list item
+fn format_entry(entry: &ToastHistoryEntry, theme: &Theme) -> ListItem<'static> {
+    let (color, label) = match entry.toast_type {
+        ToastType::Error => (theme.get_color("error"), "ERROR"),
+        ToastType::Warning => (theme.get_color("warning"), "WARN"),
+        ToastType::Success => (theme.get_color("success"), "OK"),
+        ToastType::Info => (theme.get_color("info"), "INFO"),
+    };
+
+    ListItem::new(Line::from(vec![
+        Span::styled(
+            entry.timestamp.format("%H:%M:%S").to_string(),
+            Style::default().fg(theme.get_color("inactive_pane")),
+        ),
+        Span::raw(" "),
+        Span::styled(format!("{label:5}"), Style::default().fg(color)),
+        Span::raw(" "),
+        Span::styled(entry.message.clone(), Style::default().fg(color)),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_newest_first_and_clear() {
+        let mut manager = ToastManager::new();
+        manager.error("first failure");
+        manager.error("second failure");
+        manager.success("saved");
+
+        let mut pane = ToastHistoryPane::new();
+        let messages: Vec<_> = pane
+            .filtered_entries(&manager)
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(messages, ["saved", "second failure", "first failure"]);
+
+        pane.filter = "failure".to_string();
+        assert_eq!(pane.filtered_entries(&manager).len(), 2);
+
+        pane.selected = 1;
+        pane.clear(&mut manager);
+        assert!(manager.history().is_empty());
+        assert_eq!(pane.selected, 0);
+    }
+}
@@ -0,0 +1,276 @@
+// FilePath: src/ui/components/undo_stack.rs
+
+#![forbid(unsafe_code)]
+
+use crate::database::DatabaseType;
+use crate::state::database::{delete_row_sql, update_cell_sql};
+use crate::ui::components::copy_format::{row_to_insert, sql_literal};
+use crate::ui::components::table_viewer::ColumnInfo;
+
+/// Maximum number of changes remembered per tab
+pub const MAX_UNDO_DEPTH: usize = 50;
+
+/// A destructive data change, recorded with enough detail to reverse it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataChange {
+    /// A deleted row, with every column value so it can be re-inserted
+    RowDeleted {
+        table_name: String,
+        /// Columns with their types, so values go back as typed literals
+        columns: Vec<ColumnInfo>,
+        /// Column values; `None` is NULL
+        values: Vec<Option<String>>,
+        primary_key_values: Vec<(String, String)>,
+    },
+    /// A cell changed in place (edit or set NULL)
+    CellUpdated {
+        table_name: String,
+        column: ColumnInfo,
+        primary_key_values: Vec<(String, String)>,
+        /// Values before and after the change; `None` is NULL
+        old_value: Option<String>,
+        new_value: Option<String>,
+    },
+}
+
+impl DataChange {
+    /// SQL that reverses the change, with names quoted and values typed for
+    /// `database_type`; `None` when the row has no primary key to find it by
+    pub fn undo_sql(&self, database_type: &DatabaseType) -> Option<String> {
+        match self {
+            DataChange::RowDeleted {
+                table_name,
+                columns,
+                values,
+                ..
+            } => Some(row_to_insert(table_name, columns, values, database_type)),
+            DataChange::CellUpdated {
+                table_name,
+                column,
+                primary_key_values,
+                old_value,
+                ..
+            } => update_cell_sql(
+                table_name,
+                &column.name,
+                &sql_literal(old_value.as_deref(), Some(column), database_type),
+                primary_key_values,
+                database_type,
+            ),
+        }
+    }
+
+    /// SQL that applies the change again
+    pub fn redo_sql(&self, database_type: &DatabaseType) -> Option<String> {
+        match self {
+            DataChange::RowDeleted {
+                table_name,
+                primary_key_values,
+                ..
+            } => delete_row_sql(table_name, primary_key_values, database_type),
+            DataChange::CellUpdated {
+                table_name,
+                column,
+                primary_key_values,
+                new_value,
+                ..
+            } => update_cell_sql(
+                table_name,
+                &column.name,
+                &sql_literal(new_value.as_deref(), Some(column), database_type),
+                primary_key_values,
+                database_type,
+            ),
+        }
+    }
+
+    /// Short description for toasts
+    pub fn description(&self) -> String {
+        match self {
+            DataChange::RowDeleted { table_name, .. } => format!("row delete in {table_name}"),
+            DataChange::CellUpdated {
+                table_name, column, ..
+            } => format!("update of {table_name}.{}", column.name),
+        }
+    }
+}
+
+/// Undo/redo history of data changes for a single tab
+#[derive(Debug, Clone, Default)]
+pub struct UndoStack {
+    undo: Vec<DataChange>,
+    redo: Vec<DataChange>,
+}
+
+impl UndoStack {
+    /// Create an empty stack
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a new change; drops the oldest one past the cap and clears redo
+    pub fn record(&mut self, change: DataChange) {
+        self.undo.push(change);
+        if self.undo.len() > MAX_UNDO_DEPTH {
+            self.undo.remove(0);
+        }
+        self.redo.clear();
+    }
+
+    /// Change that `u` would reverse
+    pub fn peek_undo(&self) -> Option<&DataChange> {
+        self.undo.last()
+    }
+
+    /// Change that Ctrl+R would re-apply
+    pub fn peek_redo(&self) -> Option<&DataChange> {
+        self.redo.last()
+    }
+
+    /// Move the top undo entry to the redo stack once its SQL succeeded
+    pub fn commit_undo(&mut self) {
+        if let Some(change) = self.undo.pop() {
+            self.redo.push(change);
+        }
+    }
+
+    /// Move the top redo entry back to the undo stack once its SQL succeeded
+    pub fn commit_redo(&mut self) {
+        if let Some(change) = self.redo.pop() {
+            self.undo.push(change);
+        }
+    }
+
+    /// Forget all history, e.g. after the tab's data was reloaded
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Number of changes that can be undone
+    pub fn len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Whether there is nothing to undo
+    pub fn is_empty(&self) -> bool {
+        self.undo.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, data_type: &str) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            is_nullable: true,
+            is_primary_key: false,
+            max_display_width: 10,
+        }
+    }
+
+    fn cell_change(new_value: Option<&str>) -> DataChange {
+        DataChange::CellUpdated {
+            table_name: "users".to_string(),
+            column: column("name", "text"),
+            primary_key_values: vec![("id".to_string(), "7".to_string())],
+            old_value: Some("O'Brien".to_string()),
+            new_value: new_value.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_row_delete_sql() {
+        let change = DataChange::RowDeleted {
+            table_name: "users".to_string(),
+            columns: vec![
+                column("id", "integer"),
+                column("name", "text"),
+                column("email", "text"),
+                column("avatar", "bytea"),
+            ],
+            values: vec![
+                Some("7".to_string()),
+                Some("O'Brien".to_string()),
+                None,
+                Some("0x00ff".to_string()),
+            ],
+            primary_key_values: vec![("id".to_string(), "7".to_string())],
+        };
+        assert_eq!(
+            change.undo_sql(&DatabaseType::PostgreSQL).unwrap(),
+            "INSERT INTO \"users\" (\"id\", \"name\", \"email\", \"avatar\") \
+             VALUES (7, 'O''Brien', NULL, '\\x00ff'::bytea);"
+        );
+        assert_eq!(
+            change.redo_sql(&DatabaseType::MySQL).unwrap(),
+            "DELETE FROM `users` WHERE `id` = '7'"
+        );
+    }
+
+    #[test]
+    fn test_cell_update_sql() {
+        let change = cell_change(None);
+        assert_eq!(
+            change.undo_sql(&DatabaseType::PostgreSQL).unwrap(),
+            "UPDATE \"users\" SET \"name\" = 'O''Brien' WHERE \"id\" = '7'"
+        );
+        assert_eq!(
+            change.redo_sql(&DatabaseType::SQLite).unwrap(),
+            "UPDATE \"users\" SET \"name\" = NULL WHERE \"id\" = '7'"
+        );
+
+        // The text 'NULL' is written back as a string
+        assert_eq!(
+            cell_change(Some("NULL"))
+                .redo_sql(&DatabaseType::SQLite)
+                .unwrap(),
+            "UPDATE \"users\" SET \"name\" = 'NULL' WHERE \"id\" = '7'"
+        );
+
+        // Typed columns get their literal back, not a string
+        let flag = DataChange::CellUpdated {
+            table_name: "users".to_string(),
+            column: column("active", "boolean"),
+            primary_key_values: vec![("id".to_string(), "7".to_string())],
+            old_value: Some("true".to_string()),
+            new_value: Some("false".to_string()),
+        };
+        assert_eq!(
+            flag.undo_sql(&DatabaseType::MsSql).unwrap(),
+            "UPDATE [users] SET [active] = 1 WHERE [id] = '7'"
+        );
+
+        // Without a key the row can't be found again
+        let keyless = DataChange::CellUpdated {
+            table_name: "users".to_string(),
+            column: column("name", "text"),
+            primary_key_values: Vec::new(),
+            old_value: None,
+            new_value: None,
+        };
+        assert!(keyless.undo_sql(&DatabaseType::PostgreSQL).is_none());
+    }
+
+    #[test]
+    fn test_stack_cap_and_redo() {
+        let mut stack = UndoStack::new();
+        for i in 0..MAX_UNDO_DEPTH + 5 {
+            stack.record(cell_change(Some(&i.to_string())));
+        }
+        assert_eq!(stack.len(), MAX_UNDO_DEPTH);
+
+        stack.commit_undo();
+        assert_eq!(stack.peek_redo(), Some(&cell_change(Some("54"))));
+        stack.commit_redo();
+        assert!(stack.peek_redo().is_none());
+
+        // A new change invalidates redo history
+        stack.commit_undo();
+        stack.record(cell_change(Some("new")));
+        assert!(stack.peek_redo().is_none());
+    }
+}
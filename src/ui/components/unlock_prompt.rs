@@ -0,0 +1,119 @@
+// FilePath: src/ui/components/unlock_prompt.rs
+
+#![forbid(unsafe_code)]
+
+use crate::security::mask_secret;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// What to carry on with once the session key is set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnlockThen {
+    /// Startup prompt: nothing waits on it
+    Nothing,
+    /// Connect to the selected connection
+    Connect,
+    /// Save the connection form, which encrypts its password
+    SaveConnection,
+}
+
+/// Master-password prompt for `Encrypted` connection passwords, shown at
+/// startup and whenever one is needed before the session is unlocked
+#[derive(Clone)]
+pub struct UnlockPrompt {
+    pub input: String,
+    /// Hint stored with the password the key is checked against
+    pub hint: Option<String>,
+    /// Why the last key was rejected
+    pub error: Option<String>,
+    pub then: UnlockThen,
+    /// No encrypted password to check against yet, so the key is a new one
+    pub new_key: bool,
+}
+
+impl std::fmt::Debug for UnlockPrompt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnlockPrompt")
+            .field("input", &crate::security::REDACTED)
+            .field("hint", &self.hint)
+            .field("error", &self.error)
+            .field("then", &self.then)
+            .field("new_key", &self.new_key)
+            .finish()
+    }
+}
+
+impl UnlockPrompt {
+    pub fn new(then: UnlockThen, hint: Option<String>, new_key: bool) -> Self {
+        Self {
+            input: String::new(),
+            hint,
+            error: None,
+            then,
+            new_key,
+        }
+    }
+}
+
+/// Render the prompt centered over everything else
+pub fn render_unlock_prompt(frame: &mut Frame, prompt: &UnlockPrompt) {
+    let screen = frame.area();
+    let width = screen.width.saturating_sub(4).min(60);
+    let height = 8.min(screen.height);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + screen.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, area);
+    let title = if prompt.new_key {
+        " Set Encryption Key "
+    } else {
+        " Unlock Encrypted Passwords "
+    };
+    let block = Block::default()
+        .title(title)
+        .title_bottom(" Enter unlock  Esc skip ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let intro = if prompt.new_key {
+        "Encrypts connection passwords for this session; it is never saved"
+    } else {
+        "Decrypts connection passwords for this session; it is never saved"
+    };
+    let mut lines = vec![
+        Line::styled(intro, Style::default().fg(Color::Gray)),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Key: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(mask_secret(&prompt.input)),
+            Span::styled("█", Style::default().fg(Color::Gray)),
+        ]),
+    ];
+    if let Some(hint) = &prompt.hint {
+        lines.push(Line::styled(
+            format!("Hint: {hint}"),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    if let Some(error) = &prompt.error {
+        lines.push(Line::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        ));
+    }
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
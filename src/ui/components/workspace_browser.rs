@@ -0,0 +1,123 @@
+// FilePath: src/ui/components/workspace_browser.rs
+
+#![forbid(unsafe_code)]
+
+use crate::{
+    state::workspace::{Workspace, WorkspaceSummary},
+    ui::theme::Theme,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Full-screen overlay listing saved workspaces
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceBrowser {
+    /// Saved workspaces, newest first
+    pub entries: Vec<WorkspaceSummary>,
+    /// Index of the selected entry
+    pub selected: usize,
+}
+
+impl WorkspaceBrowser {
+    /// Create a new workspace browser
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reload the saved workspaces from disk
+    pub fn refresh(&mut self) {
+        self.entries = Workspace::list();
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+    }
+
+    /// Move selection down
+    pub fn select_next(&mut self) {
+        if !self.entries.is_empty() && self.selected < self.entries.len() - 1 {
+            self.selected += 1;
+        }
+    }
+
+    /// Move selection up
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Name of the selected workspace
+    pub fn selected_name(&self) -> Option<String> {
+        self.entries.get(self.selected).map(|e| e.name.clone())
+    }
+
+    /// Render the workspace browser as a full-screen overlay
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        frame.render_widget(Clear, area);
+
+        let main_block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Workspaces ")
+            .title_alignment(Alignment::Center)
+            .style(
+                Style::default()
+                    .bg(theme.get_color("background"))
+                    .fg(theme.get_color("foreground")),
+            );
+
+        let inner_area = main_block.inner(area);
+        frame.render_widget(main_block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(3),    // Workspace list
+                Constraint::Length(3), // Help text
+            ])
+            .split(inner_area);
+
+        if self.entries.is_empty() {
+            let paragraph = Paragraph::new("No saved workspaces (save one with :ws <name>)")
+                .style(Style::default().fg(theme.get_color("inactive_pane")))
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, chunks[0]);
+        } else {
+            let items: Vec<ListItem> = self
+                .entries
+                .iter()
+                .map(|entry| {
+                    ListItem::new(Line::from(vec![
+                        Span::styled(
+                            entry.created_at.format("%Y-%m-%d %H:%M").to_string(),
+                            Style::default().fg(theme.get_color("inactive_pane")),
+                        ),
+                        Span::raw("  "),
+                        Span::styled(
+                            entry.name.clone(),
+                            Style::default().fg(theme.get_color("foreground")),
+                        ),
+                    ]))
+                })
+                .collect();
+
+            let list = List::new(items)
+                .style(Style::default().bg(theme.get_color("background")))
+                .highlight_style(
+                    Style::default()
+                        .bg(theme.get_color("selection_bg"))
+                        .add_modifier(Modifier::BOLD),
+                );
+
+            let mut list_state = ListState::default();
+            list_state.select(Some(self.selected.min(self.entries.len() - 1)));
+            frame.render_stateful_widget(list, chunks[0], &mut list_state);
+        }
+
+        let help = Paragraph::new("j/k: Navigate • Enter: Load workspace • Esc: Close")
+            .block(Block::default().borders(Borders::TOP))
+            .style(Style::default().fg(theme.get_color("foreground")))
+            .alignment(Alignment::Center);
+        frame.render_widget(help, chunks[1]);
+    }
+}
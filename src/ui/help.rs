@@ -10,14 +10,18 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::state::HelpMode;
+use crate::app::{
+    shortcuts::{Action, ActionContext, HotkeyManager},
+    state::HelpMode,
+};
 
 /// Help content for each pane
 pub struct HelpSystem;
 
 impl HelpSystem {
-    /// Create the left column content (current pane + global)
-    pub fn create_left_column(mode: HelpMode) -> Vec<Line<'static>> {
+    /// Create the left column content (current pane + global), showing the
+    /// effective bindings for configurable actions
+    pub fn create_left_column(mode: HelpMode, keys: &HotkeyManager) -> Vec<Line<'static>> {
         let mut lines = vec![];
 
         // Current pane header
@@ -41,11 +45,11 @@ impl HelpSystem {
 
         // Add pane-specific commands for current pane
         match mode {
-            HelpMode::Connections => Self::add_connections_commands(&mut lines),
-            HelpMode::Tables => Self::add_tables_commands(&mut lines),
+            HelpMode::Connections => Self::add_connections_commands(&mut lines, keys),
+            HelpMode::Tables => Self::add_tables_commands(&mut lines, keys),
             HelpMode::Details => Self::add_details_commands(&mut lines),
-            HelpMode::TabularOutput => Self::add_tabular_commands(&mut lines),
-            HelpMode::SqlFiles => Self::add_sql_files_commands(&mut lines),
+            HelpMode::TabularOutput => Self::add_tabular_commands(&mut lines, keys),
+            HelpMode::SqlFiles => Self::add_sql_files_commands(&mut lines, keys),
             HelpMode::QueryWindow => Self::add_query_window_commands(&mut lines),
             HelpMode::None => {}
         }
@@ -64,10 +68,22 @@ impl HelpSystem {
         Self::add_command(&mut lines, "q", "Quit LazyTables");
         Self::add_command(&mut lines, "?", "Toggle help");
         Self::add_command(&mut lines, "C-B", "Toggle debug view");
+        Self::add_command(&mut lines, "C-P", "Command palette");
+        Self::add_command(&mut lines, "C-T", "Go to table (tabs, listing, recent)");
+        Self::add_command(&mut lines, "C-M", "MySQL server status and variables");
+        Self::add_command(&mut lines, "~", "Toggle notification history");
         lines.push(Line::from(""));
-        Self::add_command(&mut lines, "1-6", "Jump to pane (by number)");
-        Self::add_command(&mut lines, "Tab", "Next pane");
-        Self::add_command(&mut lines, "S-Tab", "Previous pane");
+        for action in Action::ALL
+            .iter()
+            .filter(|action| action.context() == ActionContext::Navigation)
+        {
+            Self::add_action(&mut lines, keys, *action);
+        }
+        Self::add_command(
+            &mut lines,
+            &format!("{}+/-", keys.leader_label()),
+            "Grow / shrink the focused pane by 5%",
+        );
 
         lines
     }
@@ -95,6 +111,9 @@ impl HelpSystem {
         Self::add_command(&mut lines, "q", "Quit LazyTables");
         Self::add_command(&mut lines, "?", "Toggle help guide");
         Self::add_command(&mut lines, "C-B", "Toggle debug view");
+        Self::add_command(&mut lines, "C-P", "Command palette");
+        Self::add_command(&mut lines, "C-T", "Go to table");
+        Self::add_command(&mut lines, "C-M", "MySQL status / variables");
         lines.push(Line::from(""));
 
         // Navigation commands
@@ -179,11 +198,18 @@ impl HelpSystem {
         ]));
     }
 
-    fn add_connections_commands(lines: &mut Vec<Line<'static>>) {
+    /// Helper to add a configurable action with its effective key bindings
+    fn add_action(lines: &mut Vec<Line<'static>>, keys: &HotkeyManager, action: Action) {
+        let label = keys.label(action);
+        let label = if label.is_empty() { "unbound" } else { &label };
+        Self::add_command(lines, label, action.description());
+    }
+
+    fn add_connections_commands(lines: &mut Vec<Line<'static>>, keys: &HotkeyManager) {
         // Basic Navigation
         Self::add_command(lines, "j/k", "Navigate up/down connections");
-        Self::add_command(lines, "Enter/Space", "Connect to selected database");
-        Self::add_command(lines, "x", "Disconnect current connection");
+        Self::add_action(lines, keys, Action::Connect);
+        Self::add_action(lines, keys, Action::Disconnect);
         lines.push(Line::from(""));
 
         // Connection Management
@@ -193,9 +219,13 @@ impl HelpSystem {
                 .fg(Color::Rgb(120, 180, 255))
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
-        Self::add_command(lines, "a", "Add new connection");
-        Self::add_command(lines, "e", "Edit selected connection");
-        Self::add_command(lines, "d", "Delete connection (with confirmation)");
+        Self::add_action(lines, keys, Action::AddConnection);
+        Self::add_action(lines, keys, Action::EditConnection);
+        Self::add_action(lines, keys, Action::DuplicateConnection);
+        Self::add_action(lines, keys, Action::AddConnectionFromTemplate);
+        Self::add_action(lines, keys, Action::DeleteConnection);
+        Self::add_action(lines, keys, Action::RefreshConnections);
+        Self::add_action(lines, keys, Action::ToggleFavoriteConnection);
         lines.push(Line::from(""));
 
         // Search Functions
@@ -205,7 +235,8 @@ impl HelpSystem {
                 .fg(Color::Rgb(255, 200, 100))
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
-        Self::add_command(lines, "/", "Start search mode");
+        Self::add_action(lines, keys, Action::SearchConnections);
+        Self::add_action(lines, keys, Action::ImportConnections);
         Self::add_command(lines, "ESC", "Exit search mode");
         Self::add_command(lines, "↑/↓", "Navigate search results");
         lines.push(Line::from(""));
@@ -271,13 +302,14 @@ impl HelpSystem {
         ]));
     }
 
-    fn add_tables_commands(lines: &mut Vec<Line<'static>>) {
+    fn add_tables_commands(lines: &mut Vec<Line<'static>>, keys: &HotkeyManager) {
         // Basic Navigation
         Self::add_command(lines, "j/k", "Navigate up/down tables");
         Self::add_command(lines, "gg/G", "Jump to first/last table");
         Self::add_command(lines, "C-d/C-u", "Page down/up (half page)");
-        Self::add_command(lines, "Enter/Space", "Open table for viewing");
+        Self::add_action(lines, keys, Action::OpenTable);
         Self::add_command(lines, "Tab", "Toggle group expansion (on headers)");
+        Self::add_action(lines, keys, Action::TableActions);
         lines.push(Line::from(""));
 
         // Table Management
@@ -287,7 +319,21 @@ impl HelpSystem {
                 .fg(Color::Rgb(120, 180, 255))
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
-        Self::add_command(lines, "r", "Refresh tables list");
+        Self::add_action(lines, keys, Action::RefreshTables);
+        lines.push(Line::from(""));
+
+        // Routines
+        lines.push(Line::from(vec![Span::styled(
+            "ƒ Routines",
+            Style::default()
+                .fg(Color::Rgb(200, 160, 255))
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        )]));
+        Self::add_action(lines, keys, Action::ShowRoutines);
+        Self::add_action(lines, keys, Action::OpenRoutine);
+        Self::add_action(lines, keys, Action::DropRoutine);
+        Self::add_action(lines, keys, Action::RefreshRoutines);
+        Self::add_command(lines, "Esc/C-r", "Back to tables");
         lines.push(Line::from(""));
 
         // Search & Filter
@@ -297,7 +343,13 @@ impl HelpSystem {
                 .fg(Color::Rgb(255, 200, 100))
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
-        Self::add_command(lines, "/", "Start search mode");
+        Self::add_action(lines, keys, Action::SearchTables);
+        Self::add_action(lines, keys, Action::ImportCsv);
+        Self::add_action(lines, keys, Action::BackupTable);
+        Self::add_action(lines, keys, Action::TruncateTable);
+        Self::add_action(lines, keys, Action::BookmarkTable);
+        Self::add_action(lines, keys, Action::ToggleTableGroup);
+        Self::add_action(lines, keys, Action::CycleTableFilter);
         Self::add_command(lines, "ESC", "Exit search mode");
         Self::add_command(lines, "↑/↓", "Navigate search results");
         Self::add_command(lines, "Enter", "Open selected search result");
@@ -359,11 +411,19 @@ impl HelpSystem {
 
     fn add_details_commands(lines: &mut Vec<Line<'static>>) {
         // Basic Navigation
-        Self::add_command(lines, "j/k", "Scroll up/down");
+        Self::add_command(lines, "j/k", "Next/previous column or index, or scroll");
         Self::add_command(lines, "↑/↓", "Scroll up/down (arrows)");
         Self::add_command(lines, "Ctrl+D/U", "Page down/up (half page)");
         Self::add_command(lines, "gg", "Jump to top");
         Self::add_command(lines, "G", "Jump to bottom");
+        Self::add_command(lines, "yy", "Copy highlighted column name");
+        Self::add_command(lines, "Y", "Copy full column list");
+        Self::add_command(lines, "P", "Profile column statistics");
+        Self::add_command(lines, "p", "Toggle table and index usage statistics");
+        Self::add_command(lines, "c", "Count rows exactly (replaces an estimate)");
+        Self::add_command(lines, "R", "Refresh materialized view");
+        Self::add_command(lines, "I", "Create an index");
+        Self::add_command(lines, "d", "Drop highlighted index");
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
             "Information Displayed:",
@@ -375,12 +435,19 @@ impl HelpSystem {
             "• Object type (Table/View/Materialized View)",
         )));
         lines.push(Line::from(Span::raw("• Row count and column count")));
+        lines.push(Line::from(Span::raw(
+            "• Column list (type, nullability, PK, default)",
+        )));
+        lines.push(Line::from(Span::raw(
+            "• Column profile (null %, distinct, min/max/avg, top values)",
+        )));
         lines.push(Line::from(Span::raw(
             "• Storage size (total, table, indexes)",
         )));
         lines.push(Line::from(Span::raw("• Primary keys and foreign keys")));
         lines.push(Line::from(Span::raw("• Index information")));
         lines.push(Line::from(Span::raw("• Table comments and metadata")));
+        lines.push(Line::from(Span::raw("• View definition SQL")));
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
             "Status Messages:",
@@ -393,7 +460,7 @@ impl HelpSystem {
         lines.push(Line::from(Span::raw("• No table selected")));
     }
 
-    fn add_tabular_commands(lines: &mut Vec<Line<'static>>) {
+    fn add_tabular_commands(lines: &mut Vec<Line<'static>>, keys: &HotkeyManager) {
         // Basic Navigation
         lines.push(Line::from(vec![Span::styled(
             "🧭 Table Navigation",
@@ -404,7 +471,15 @@ impl HelpSystem {
         Self::add_command(lines, "h/j/k/l", "Navigate table cells");
         Self::add_command(lines, "Arrow Keys", "Alternative cell navigation");
         Self::add_command(lines, "gg/G", "Jump to first/last row");
-        Self::add_command(lines, "0/$", "Jump to first/last column");
+        Self::add_action(lines, keys, Action::FirstColumn);
+        Self::add_action(lines, keys, Action::LastColumn);
+        Self::add_action(lines, keys, Action::FreezeColumns);
+        Self::add_action(lines, keys, Action::FreezeMoreColumns);
+        Self::add_action(lines, keys, Action::FreezeFewerColumns);
+        Self::add_action(lines, keys, Action::ToggleRowNumbers);
+        Self::add_action(lines, keys, Action::AutoFitColumns);
+        Self::add_action(lines, keys, Action::WidenColumn);
+        Self::add_action(lines, keys, Action::NarrowColumn);
         Self::add_command(lines, "Ctrl+D/U", "Page down/up through data");
         lines.push(Line::from(""));
 
@@ -415,7 +490,8 @@ impl HelpSystem {
                 .fg(Color::Rgb(255, 200, 100))
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
-        Self::add_command(lines, "i", "Enter edit mode for current cell");
+        Self::add_action(lines, keys, Action::EditCell);
+        Self::add_action(lines, keys, Action::SetCellNull);
         Self::add_command(lines, "Enter", "Save cell changes and exit edit");
         Self::add_command(lines, "ESC", "Cancel cell edit and revert");
         Self::add_command(lines, "Ctrl+C", "Cancel edit (alternative)");
@@ -428,7 +504,7 @@ impl HelpSystem {
                 .fg(Color::Rgb(180, 140, 255))
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
-        Self::add_command(lines, "/", "Start search mode");
+        Self::add_action(lines, keys, Action::SearchRows);
         Self::add_command(lines, "n/N", "Navigate to next/previous match");
         Self::add_command(lines, "ESC", "Exit search mode");
         lines.push(Line::from(""));
@@ -440,8 +516,16 @@ impl HelpSystem {
                 .fg(Color::Rgb(255, 160, 160))
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
-        Self::add_command(lines, "dd", "Delete current row (with confirmation)");
-        Self::add_command(lines, "yy", "Copy row data to clipboard (CSV format)");
+        Self::add_action(lines, keys, Action::DeleteRow);
+        Self::add_action(lines, keys, Action::CopyRow);
+        Self::add_action(lines, keys, Action::CopyRowAs);
+        Self::add_action(lines, keys, Action::CopyCell);
+        Self::add_action(lines, keys, Action::CopyPrimaryKey);
+        Self::add_action(lines, keys, Action::InspectBinary);
+        Self::add_action(lines, keys, Action::ViewJson);
+        Self::add_action(lines, keys, Action::ViewCell);
+        Self::add_action(lines, keys, Action::UndoChange);
+        Self::add_action(lines, keys, Action::RedoChange);
         lines.push(Line::from(""));
 
         // View Controls
@@ -451,8 +535,13 @@ impl HelpSystem {
                 .fg(Color::Rgb(120, 200, 255))
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
-        Self::add_command(lines, "t", "Toggle between Data and Schema view");
-        Self::add_command(lines, "r", "Refresh/reload current table data");
+        Self::add_action(lines, keys, Action::ToggleTableView);
+        Self::add_action(lines, keys, Action::ToggleVerticalView);
+        Self::add_action(lines, keys, Action::RefreshTableData);
+        Self::add_action(lines, keys, Action::ToggleWatch);
+        Self::add_action(lines, keys, Action::PinResults);
+        Self::add_action(lines, keys, Action::DiffResults);
+        Self::add_command(lines, "e", "Jump to the error position (error tab)");
         lines.push(Line::from(""));
 
         // Tab Management
@@ -462,8 +551,9 @@ impl HelpSystem {
                 .fg(Color::Rgb(255, 220, 120))
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
-        Self::add_command(lines, "x", "Close current tab");
-        Self::add_command(lines, "H/L", "Switch to previous/next tab");
+        Self::add_action(lines, keys, Action::CloseTab);
+        Self::add_action(lines, keys, Action::PreviousTab);
+        Self::add_action(lines, keys, Action::NextTab);
         lines.push(Line::from(""));
 
         // Status Information
@@ -499,10 +589,10 @@ impl HelpSystem {
         lines.push(Line::from(""));
     }
 
-    fn add_sql_files_commands(lines: &mut Vec<Line<'static>>) {
+    fn add_sql_files_commands(lines: &mut Vec<Line<'static>>, keys: &HotkeyManager) {
         // Basic Navigation
         Self::add_command(lines, "j/k", "Navigate up/down files");
-        Self::add_command(lines, "Enter/Space", "Load selected SQL file");
+        Self::add_action(lines, keys, Action::LoadSqlFile);
         lines.push(Line::from(""));
 
         // File Management
@@ -512,9 +602,9 @@ impl HelpSystem {
                 .fg(Color::Rgb(120, 180, 255))
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
-        Self::add_command(lines, "n", "Create new file (enter create mode)");
-        Self::add_command(lines, "r", "Rename file (enter rename mode)");
-        Self::add_command(lines, "d", "Delete file (with confirmation)");
+        Self::add_action(lines, keys, Action::NewSqlFile);
+        Self::add_action(lines, keys, Action::RenameSqlFile);
+        Self::add_action(lines, keys, Action::DeleteSqlFile);
         lines.push(Line::from(""));
 
         // Quick Actions
@@ -536,7 +626,8 @@ impl HelpSystem {
                 .fg(Color::Rgb(255, 200, 100))
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
-        Self::add_command(lines, "/", "Start search mode");
+        Self::add_action(lines, keys, Action::SearchSqlFiles);
+        Self::add_action(lines, keys, Action::BookmarkSqlFile);
         Self::add_command(lines, "j/k", "Navigate search results");
         Self::add_command(lines, "Enter", "Load selected search result");
         Self::add_command(lines, "ESC", "Exit search mode");
@@ -629,6 +720,12 @@ impl HelpSystem {
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )]));
         Self::add_command(lines, "Ctrl+Enter", "Execute query at cursor position");
+        Self::add_command(
+            lines,
+            "Ctrl+Shift+Enter",
+            "Run all statements (selection in Visual)",
+        );
+        Self::add_command(lines, "Ctrl+C/Esc", "Cancel the running query");
         lines.push(Line::from(""));
 
         // Query Mode Navigation & Editing
@@ -659,6 +756,29 @@ impl HelpSystem {
         Self::add_command(lines, "w/b/e", "Next word/Previous word/End word");
         Self::add_command(lines, "0/$", "Line start/Line end");
         Self::add_command(lines, "g/G", "File start/File end (gg for start)");
+        Self::add_command(lines, "42G, :42", "Jump to line 42");
+        lines.push(Line::from(""));
+
+        lines.push(Line::from(vec![
+            Span::styled("  ✂ ", Style::default().fg(Color::Red)),
+            Span::raw("Deleting & Visual Mode:"),
+        ]));
+        Self::add_command(lines, "x/X", "Delete character under/before cursor");
+        Self::add_command(lines, "dd/dw/d$", "Delete line/to next word/to line end");
+        Self::add_command(lines, "v/V", "Visual mode (characters/lines)");
+        Self::add_command(lines, "d/x, y", "Delete or yank the selection (Visual)");
+        Self::add_command(lines, "yy, p/P", "Yank line, paste after/before cursor");
+        Self::add_command(
+            lines,
+            "\"+",
+            "Prefix: next yank/delete/paste uses the clipboard",
+        );
+        Self::add_command(lines, "Ctrl+J", "Insert a snippet at the cursor");
+        Self::add_command(
+            lines,
+            ":snip add <name>",
+            "Save the Visual selection as a snippet",
+        );
         lines.push(Line::from(""));
 
         // Insert Mode Features
@@ -672,7 +792,7 @@ impl HelpSystem {
             Span::styled("  💡 ", Style::default().fg(Color::Yellow)),
             Span::raw("Auto-completion & Suggestions:"),
         ]));
-        Self::add_command(lines, "Tab", "Accept selected suggestion");
+        Self::add_command(lines, "Tab/Enter", "Accept selected suggestion");
         Self::add_command(lines, "↑/↓", "Navigate suggestions (when active)");
         Self::add_command(lines, "ESC", "Hide suggestions and stay in insert");
         lines.push(Line::from(""));
@@ -682,6 +802,7 @@ impl HelpSystem {
             Span::raw("Text Editing:"),
         ]));
         Self::add_command(lines, "Enter", "Insert new line");
+        Self::add_command(lines, "Tab", "Next snippet stop ($2, $3, ...)");
         Self::add_command(lines, "Backspace", "Delete character before cursor");
         Self::add_command(lines, "←/→/↑/↓", "Move cursor in insert mode");
         lines.push(Line::from(""));
@@ -731,7 +852,11 @@ impl HelpSystem {
     }
 
     /// Render the help overlay
-    pub fn render_help(f: &mut Frame, ui_state: &crate::state::ui::UIState) {
+    pub fn render_help(
+        f: &mut Frame,
+        ui_state: &mut crate::state::ui::UIState,
+        keys: &HotkeyManager,
+    ) {
         let help_mode = ui_state.help_mode;
         if help_mode == HelpMode::None {
             return;
@@ -798,7 +923,16 @@ impl HelpSystem {
             .split(main_layout[1]);
 
         // Left column - current pane commands + global
-        let left_content = Self::create_left_column(help_mode);
+        let left_content = Self::create_left_column(help_mode, keys);
+        let right_content = Self::create_right_column(help_mode);
+
+        // Keep both panes scrolled within their content at this size
+        let inner_width = |area: Rect| area.width.saturating_sub(2);
+        ui_state.reflow_help(
+            crate::ui::layout::wrapped_rows(&left_content, inner_width(columns[0])),
+            crate::ui::layout::wrapped_rows(&right_content, inner_width(columns[2])),
+            columns[0].height.saturating_sub(2) as usize,
+        );
         let left_focused = ui_state.help_pane_focus == crate::state::ui::HelpPaneFocus::Left;
         let left_border_style = if left_focused {
             Style::default()
@@ -850,7 +984,6 @@ impl HelpSystem {
         f.render_widget(left_widget, columns[0]);
 
         // Right column - global commands
-        let right_content = Self::create_right_column(help_mode);
         let right_focused = ui_state.help_pane_focus == crate::state::ui::HelpPaneFocus::Right;
         let right_border_style = if right_focused {
             Style::default()
@@ -2,7 +2,22 @@
 
 #![forbid(unsafe_code)]
 
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use crate::config::LayoutConfig;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::Line,
+};
+
+/// Smallest height, in rows, of a pane in the left column
+pub const MIN_PANE_ROWS: u16 = 3;
+/// Allowed width of the left column, in percent of the terminal
+const LEFT_WIDTH_RANGE: (u16, u16) = (15, 60);
+/// Smallest share of the left column any one pane can be resized down to
+const MIN_LEFT_PANE_PERCENT: u16 = 10;
+/// Allowed height of the results above the query editor, in percent
+const OUTPUT_HEIGHT_RANGE: (u16, u16) = (20, 80);
+/// Allowed width of the SQL files column, in percent of the editor row
+const SQL_FILES_WIDTH_RANGE: (u16, u16) = (10, 50);
 
 /// Areas for each pane in the layout
 #[derive(Debug, Clone, Copy)]
@@ -15,9 +30,34 @@ pub struct LayoutAreas {
     pub sql_files: Rect,
     pub query_window: Rect,
     pub status_bar: Rect,
+    /// Results area of the split without focus, when `main_content` is split
+    pub split_output: Option<Rect>,
+    /// Query editor area of the split without focus
+    pub split_query_window: Option<Rect>,
+}
+
+/// How `main_content` is divided between two sets of results and editor
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SplitMode {
+    /// One results pane and editor
+    #[default]
+    None,
+    /// Two splits stacked top and bottom (`Ctrl+W s`)
+    Horizontal,
+    /// Two splits side by side (`Ctrl+W v`)
+    Vertical,
+}
+
+/// One of the panes stacked in the left column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeftPane {
+    Connections,
+    Tables,
+    Details,
 }
 
 /// Manages the six-pane layout
+#[derive(Debug, Clone)]
 pub struct LayoutManager {
     /// Width percentage for left section (connections, tables, details)
     left_width_percent: u16,
@@ -29,6 +69,10 @@ pub struct LayoutManager {
     output_height_percent: u16,
     /// Width percentage for SQL files column (right side of SQL area)
     sql_files_width_percent: u16,
+    /// Whether and how the results/editor area is split in two
+    split_mode: SplitMode,
+    /// Index (0 = top or left) of the split holding focus
+    focused_split: usize,
 }
 
 impl LayoutManager {
@@ -41,6 +85,145 @@ impl LayoutManager {
             details_height_percent: 20,
             output_height_percent: 65, // 65% for tabular output, 35% for SQL area
             sql_files_width_percent: 25, // 25% width for files column, 75% for editor
+            split_mode: SplitMode::None,
+            focused_split: 0,
+        }
+    }
+
+    /// Layout with the proportions saved in `[layout]`, clamped to sane values
+    pub fn from_config(config: &LayoutConfig) -> Self {
+        let mut layout = Self::new();
+        layout.left_width_percent = config
+            .left_width_percent
+            .clamp(LEFT_WIDTH_RANGE.0, LEFT_WIDTH_RANGE.1);
+
+        let max_pane = 100 - 2 * MIN_LEFT_PANE_PERCENT;
+        let connections = config
+            .connections_height_percent
+            .clamp(MIN_LEFT_PANE_PERCENT, max_pane);
+        let tables = config.tables_height_percent.clamp(
+            MIN_LEFT_PANE_PERCENT,
+            100 - connections - MIN_LEFT_PANE_PERCENT,
+        );
+        layout.connections_height_percent = connections;
+        layout.tables_height_percent = tables;
+        layout.details_height_percent = 100 - connections - tables;
+        layout.output_height_percent = config
+            .output_height_percent
+            .clamp(OUTPUT_HEIGHT_RANGE.0, OUTPUT_HEIGHT_RANGE.1);
+        layout.sql_files_width_percent = config
+            .sql_files_width_percent
+            .clamp(SQL_FILES_WIDTH_RANGE.0, SQL_FILES_WIDTH_RANGE.1);
+        layout
+    }
+
+    /// Current proportions, for writing back to `[layout]`
+    pub fn to_config(&self) -> LayoutConfig {
+        LayoutConfig {
+            left_width_percent: self.left_width_percent,
+            connections_height_percent: self.connections_height_percent,
+            tables_height_percent: self.tables_height_percent,
+            output_height_percent: self.output_height_percent,
+            sql_files_width_percent: self.sql_files_width_percent,
+        }
+    }
+
+    /// Current proportions, shown in the status bar while resizing
+    pub fn summary(&self) -> String {
+        format!(
+            "left {}% · connections {}% · tables {}% · details {}% · results {}% · files {}%",
+            self.left_width_percent,
+            self.connections_height_percent,
+            self.tables_height_percent,
+            self.details_height_percent,
+            self.output_height_percent,
+            self.sql_files_width_percent
+        )
+    }
+
+    /// Widen (positive) or narrow (negative) the left column; false when already at a limit
+    pub fn resize_left_column(&mut self, delta: i16) -> bool {
+        let width = (self.left_width_percent as i16 + delta)
+            .clamp(LEFT_WIDTH_RANGE.0 as i16, LEFT_WIDTH_RANGE.1 as i16) as u16;
+        let changed = width != self.left_width_percent;
+        self.left_width_percent = width;
+        changed
+    }
+
+    /// Grow (positive) or shrink (negative) a left-column pane at the expense of
+    /// its neighbour: Tables trades with Details, the others trade with Tables
+    pub fn resize_left_pane(&mut self, pane: LeftPane, delta: i16) -> bool {
+        let mut heights = [
+            self.connections_height_percent,
+            self.tables_height_percent,
+            self.details_height_percent,
+        ];
+        let (index, neighbour) = match pane {
+            LeftPane::Connections => (0, 1),
+            LeftPane::Tables => (1, 2),
+            LeftPane::Details => (2, 1),
+        };
+
+        let pair = heights[index] + heights[neighbour];
+        let size = (heights[index] as i16 + delta).clamp(
+            MIN_LEFT_PANE_PERCENT as i16,
+            (pair - MIN_LEFT_PANE_PERCENT) as i16,
+        ) as u16;
+        if size == heights[index] {
+            return false;
+        }
+        heights[index] = size;
+        heights[neighbour] = pair - size;
+
+        self.connections_height_percent = heights[0];
+        self.tables_height_percent = heights[1];
+        self.details_height_percent = heights[2];
+        true
+    }
+
+    /// Make the results taller (positive) or shorter (negative) than the
+    /// query editor below them; false when already at a limit
+    pub fn resize_output(&mut self, delta: i16) -> bool {
+        let height = (self.output_height_percent as i16 + delta)
+            .clamp(OUTPUT_HEIGHT_RANGE.0 as i16, OUTPUT_HEIGHT_RANGE.1 as i16)
+            as u16;
+        let changed = height != self.output_height_percent;
+        self.output_height_percent = height;
+        changed
+    }
+
+    /// Widen (positive) or narrow (negative) the SQL files column; false when already at a limit
+    pub fn resize_sql_files(&mut self, delta: i16) -> bool {
+        let width = (self.sql_files_width_percent as i16 + delta).clamp(
+            SQL_FILES_WIDTH_RANGE.0 as i16,
+            SQL_FILES_WIDTH_RANGE.1 as i16,
+        ) as u16;
+        let changed = width != self.sql_files_width_percent;
+        self.sql_files_width_percent = width;
+        changed
+    }
+
+    pub fn split_mode(&self) -> SplitMode {
+        self.split_mode
+    }
+
+    /// Split `main_content` in two, or join it back with `SplitMode::None`
+    pub fn set_split_mode(&mut self, mode: SplitMode) {
+        self.split_mode = mode;
+        if mode == SplitMode::None {
+            self.focused_split = 0;
+        }
+    }
+
+    /// Index (0 = top or left) of the split holding focus
+    pub fn focused_split(&self) -> usize {
+        self.focused_split
+    }
+
+    /// Move focus to the other split
+    pub fn toggle_split_focus(&mut self) {
+        if self.split_mode != SplitMode::None {
+            self.focused_split = 1 - self.focused_split;
         }
     }
 
@@ -72,54 +255,91 @@ impl LayoutManager {
         let left_section = body_chunks[0];
         let right_section = body_chunks[1];
 
-        // Split left section vertically into three panes
+        // Split left section vertically into three panes of at least MIN_PANE_ROWS
+        let rows = split_rows(
+            left_section.height,
+            [
+                self.connections_height_percent,
+                self.tables_height_percent,
+                self.details_height_percent,
+            ],
+        );
         let left_chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Percentage(self.connections_height_percent),
-                Constraint::Percentage(self.tables_height_percent),
-                Constraint::Percentage(self.details_height_percent),
-            ])
+            .constraints(rows.map(Constraint::Length))
             .split(left_section);
 
         let connections = left_chunks[0];
         let tables = left_chunks[1];
         let details = left_chunks[2];
 
-        // Split right section vertically into tabular output and SQL area
+        // Split the main content in two when asked; the SQL files column
+        // stays with the first split
+        let (first, second) = match self.split_mode {
+            SplitMode::None => (right_section, None),
+            SplitMode::Horizontal | SplitMode::Vertical => {
+                let direction = if self.split_mode == SplitMode::Vertical {
+                    Direction::Horizontal
+                } else {
+                    Direction::Vertical
+                };
+                let halves = Layout::default()
+                    .direction(direction)
+                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .split(right_section);
+                (halves[0], Some(halves[1]))
+            }
+        };
+
+        let (mut tabular_output, mut query_window, sql_files) =
+            self.main_content_areas(first, self.sql_files_width_percent);
+        let mut split_output = None;
+        let mut split_query_window = None;
+        if let Some(second) = second {
+            let (output, query, _) = self.main_content_areas(second, 0);
+            split_output = Some(output);
+            split_query_window = Some(query);
+            if self.focused_split == 1 {
+                split_output = Some(std::mem::replace(&mut tabular_output, output));
+                split_query_window = Some(std::mem::replace(&mut query_window, query));
+            }
+        }
+
+        LayoutAreas {
+            header,
+            connections,
+            tables,
+            details,
+            tabular_output,
+            sql_files,
+            query_window,
+            status_bar,
+            split_output,
+            split_query_window,
+        }
+    }
+
+    /// Tabular output, query window and SQL files areas of one split
+    fn main_content_areas(&self, area: Rect, files_width_percent: u16) -> (Rect, Rect, Rect) {
+        // Split vertically into tabular output and SQL area
         let right_chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Percentage(self.output_height_percent),
                 Constraint::Min(0), // SQL area takes remaining space
             ])
-            .split(right_section);
-
-        let tabular_output = right_chunks[0];
-        let sql_area = right_chunks[1];
+            .split(area);
 
         // Split SQL area horizontally into query editor and files column
         let sql_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
                 Constraint::Min(0), // Query window takes remaining space
-                Constraint::Percentage(self.sql_files_width_percent),
+                Constraint::Percentage(files_width_percent),
             ])
-            .split(sql_area);
+            .split(right_chunks[1]);
 
-        let query_window = sql_chunks[0];
-        let sql_files = sql_chunks[1];
-
-        LayoutAreas {
-            header,
-            connections,
-            tables,
-            details,
-            tabular_output,
-            sql_files,
-            query_window,
-            status_bar,
-        }
+        (right_chunks[0], sql_chunks[0], sql_chunks[1])
     }
 
     /// Check if the terminal size meets minimum requirements
@@ -138,3 +358,147 @@ impl Default for LayoutManager {
         Self::new()
     }
 }
+
+/// Largest offset that still fills `viewport_rows` of `content_rows`; a
+/// smaller viewport after a resize never leaves the view scrolled past the end
+pub fn clamp_scroll(offset: usize, content_rows: usize, viewport_rows: usize) -> usize {
+    offset.min(content_rows.saturating_sub(viewport_rows))
+}
+
+/// Rows `lines` take once wrapped to `width` columns, for scroll bounds of
+/// wrapping paragraphs
+pub fn wrapped_rows(lines: &[Line], width: u16) -> usize {
+    let width = usize::from(width.max(1));
+    lines
+        .iter()
+        .map(|line| line.width().div_ceil(width).max(1))
+        .sum()
+}
+
+/// Rows for each stacked pane: proportional to `percents`, with the rounding
+/// remainder on the last pane and short panes topped up from the tallest one
+fn split_rows(total: u16, percents: [u16; 3]) -> [u16; 3] {
+    let mut rows = percents.map(|percent| (total as u32 * percent as u32 / 100) as u16);
+    rows[2] = total.saturating_sub(rows[0] + rows[1]);
+
+    for i in 0..rows.len() {
+        while rows[i] < MIN_PANE_ROWS {
+            let tallest = (0..rows.len()).max_by_key(|&j| rows[j]).unwrap_or(0);
+            if rows[tallest] <= MIN_PANE_ROWS {
+                break;
+            }
+            rows[tallest] -= 1;
+            rows[i] += 1;
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_clamps_to_new_viewport() {
+        // 100 rows in 20: the last full page starts at 80
+        assert_eq!(clamp_scroll(95, 100, 20), 80);
+        assert_eq!(clamp_scroll(40, 100, 20), 40);
+        // Growing the viewport pulls the offset back so no blank rows show
+        assert_eq!(clamp_scroll(80, 100, 50), 50);
+        // Everything fits: no scrolling at all
+        assert_eq!(clamp_scroll(7, 10, 30), 0);
+        assert_eq!(clamp_scroll(0, 0, 0), 0);
+
+        let lines = [
+            Line::from("x".repeat(25)),
+            Line::from(""),
+            Line::from("short"),
+        ];
+        assert_eq!(wrapped_rows(&lines, 10), 5);
+        assert_eq!(wrapped_rows(&lines, 25), 3);
+        assert_eq!(wrapped_rows(&lines, 0), 31);
+    }
+
+    #[test]
+    fn test_resize_clamps_and_trades_with_neighbour() {
+        let mut layout = LayoutManager::new();
+        assert!(layout.resize_left_pane(LeftPane::Connections, -25));
+        let config = layout.to_config();
+        assert_eq!(config.connections_height_percent, 15);
+        assert_eq!(config.tables_height_percent, 65);
+
+        // Details can't push Tables below the minimum share
+        assert!(layout.resize_left_pane(LeftPane::Details, 100));
+        assert_eq!(
+            layout.to_config().tables_height_percent,
+            MIN_LEFT_PANE_PERCENT
+        );
+        assert!(!layout.resize_left_pane(LeftPane::Details, 5));
+
+        assert!(layout.resize_left_column(100));
+        assert_eq!(layout.to_config().left_width_percent, LEFT_WIDTH_RANGE.1);
+        assert!(!layout.resize_left_column(1));
+
+        assert!(layout.resize_output(-5));
+        assert!(layout.resize_sql_files(100));
+        assert!(!layout.resize_sql_files(1));
+        assert_eq!(
+            layout.summary(),
+            "left 60% · connections 15% · tables 10% · details 75% · results 60% · files 50%"
+        );
+    }
+
+    #[test]
+    fn test_split_rows_keeps_minimum_height() {
+        assert_eq!(split_rows(28, [40, 40, 20]), [11, 11, 6]);
+        // 5% of 20 rows would collapse connections to a single row
+        let rows = split_rows(20, [5, 85, 10]);
+        assert!(rows.iter().all(|&r| r >= MIN_PANE_ROWS));
+        assert_eq!(rows.iter().sum::<u16>(), 20);
+    }
+
+    #[test]
+    fn test_from_config_clamps_bad_values() {
+        let layout = LayoutManager::from_config(&LayoutConfig {
+            left_width_percent: 95,
+            connections_height_percent: 0,
+            tables_height_percent: 99,
+            output_height_percent: 5,
+            sql_files_width_percent: 25,
+        });
+        let config = layout.to_config();
+        assert_eq!(config.left_width_percent, LEFT_WIDTH_RANGE.1);
+        assert_eq!(config.connections_height_percent, MIN_LEFT_PANE_PERCENT);
+        assert_eq!(config.tables_height_percent, 80);
+        assert_eq!(config.output_height_percent, OUTPUT_HEIGHT_RANGE.0);
+    }
+
+    #[test]
+    fn test_split_areas_follow_focus() {
+        let area = Rect::new(0, 0, 200, 50);
+        let mut layout = LayoutManager::new();
+        let single = layout.calculate_layout(area);
+        assert!(single.split_output.is_none());
+
+        layout.set_split_mode(SplitMode::Vertical);
+        let areas = layout.calculate_layout(area);
+        let other = areas.split_output.unwrap();
+        assert_eq!(areas.tabular_output.y, other.y);
+        assert!(areas.tabular_output.x < other.x);
+        // The SQL files column stays with the left split
+        assert!(areas.sql_files.right() <= other.x);
+
+        // Focus moves the primary areas to the right-hand split
+        layout.toggle_split_focus();
+        let focused = layout.calculate_layout(area);
+        assert_eq!(focused.tabular_output, other);
+        assert_eq!(focused.split_query_window, Some(areas.query_window));
+
+        layout.set_split_mode(SplitMode::Horizontal);
+        let stacked = layout.calculate_layout(area);
+        assert!(stacked.tabular_output.y > stacked.split_output.unwrap().y);
+
+        layout.set_split_mode(SplitMode::None);
+        assert_eq!(layout.focused_split(), 0);
+    }
+}
@@ -5,10 +5,14 @@
 use crate::{
     app::{AppState, FocusedPane},
     config::Config,
+    config::StatusModule,
     constants,
     core::error::Result,
     database::ConnectionStatus,
     state::OverlayView,
+    ui::components::status_bar::{compose_status_line, MODULE_SEPARATOR},
+    ui::components::table_viewer::{ellipsize, primary_key_where},
+    ui::components::VisualMode,
 };
 use ratatui::{
     layout::{Alignment, Constraint, Rect},
@@ -40,7 +44,17 @@ pub struct ConfirmationModal {
 pub enum ConfirmationAction {
     DeleteConnection(usize),
     DeleteTable(String),
+    /// First step of truncating the named table; the name is typed next
+    TruncateTable(String),
     DeleteSqlFile(usize),
+    DropRoutine(crate::database::RoutineInfo),
+    /// `REFRESH MATERIALIZED VIEW CONCURRENTLY` on the named view
+    RefreshMaterializedView(String),
+    /// `DROP INDEX` of an index listed in the Details pane
+    DropIndex {
+        table: String,
+        index: String,
+    },
     ExitApplication,
     QuitQueryEditor,
     // Add more actions as needed
@@ -48,35 +62,38 @@ pub enum ConfirmationAction {
 
 /// Main UI structure
 pub struct UI {
-    layout_manager: LayoutManager,
     pub theme: Theme,
+    /// File the theme was loaded from, watched for live reloads
+    pub theme_path: Option<std::path::PathBuf>,
+    /// Modules in each status bar slot, from `[status_bar]`
+    pub status_bar: crate::config::StatusBarConfig,
 }
 
 impl UI {
     /// Create a new UI instance
     pub fn new(config: &Config) -> Result<Self> {
-        let layout_manager = LayoutManager::new();
-
         // Load theme based on config or use default
+        let mut theme_path = None;
         let theme = if !config.theme.name.is_empty() {
-            // Try to load theme from available themes
-            let themes = theme::ThemeLoader::list_available_themes();
-            if let Some((_, path)) = themes.iter().find(|(name, _)| name == &config.theme.name) {
-                Theme::load_from_file(path).unwrap_or_else(|e| {
-                    tracing::warn!("Failed to load theme '{}': {}", config.theme.name, e);
+            // Built-in themes and installed theme files are both accepted
+            match theme::ThemeLoader::find_theme(&config.theme.name) {
+                Some((theme, path)) => {
+                    theme_path = path;
+                    theme
+                }
+                None => {
+                    tracing::warn!("Theme '{}' not found, using default", config.theme.name);
                     Theme::default()
-                })
-            } else {
-                tracing::warn!("Theme '{}' not found, using default", config.theme.name);
-                Theme::default()
+                }
             }
         } else {
             Theme::default()
         };
 
         Ok(Self {
-            layout_manager,
             theme,
+            theme_path,
+            status_bar: config.status_bar.clone(),
         })
     }
 
@@ -178,12 +195,41 @@ impl UI {
         }
     }
 
+    /// Centered notice shown in place of the layout when the terminal is too small
+    fn draw_size_warning(&self, frame: &mut Frame, area: Rect) {
+        let message = format!(
+            "{}\nCurrent: {}x{}",
+            LayoutManager::size_warning_message(),
+            area.width,
+            area.height
+        );
+        let top = area.height.saturating_sub(2) / 2;
+        let warning = Paragraph::new(message)
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(
+            warning,
+            Rect {
+                y: area.y + top,
+                height: area.height - top,
+                ..area
+            },
+        );
+    }
+
     /// Draw the entire UI
     pub fn draw(&mut self, frame: &mut Frame, state: &mut AppState) {
         // Clear the frame to prevent artifacts
         frame.render_widget(ratatui::widgets::Clear, frame.area());
 
-        let areas = self.layout_manager.calculate_layout(frame.area());
+        // Too small to lay out six panes without overlapping; say so instead
+        if !state.layout.is_size_valid(frame.area()) {
+            self.draw_size_warning(frame, frame.area());
+            return;
+        }
+
+        let areas = state.layout.calculate_layout(frame.area());
 
         // Draw header
         self.draw_header(frame, areas.header, state);
@@ -203,15 +249,43 @@ impl UI {
         // Draw SQL files browser
         self.draw_sql_files_pane(frame, areas.sql_files, state);
 
-        // Draw query window area
-        self.draw_query_window(frame, areas.query_window, state);
+        // Draw query window area; the variables panel, when open, takes its bottom rows
+        if let Some(panel) = &state.query_vars_panel {
+            let rows = components::QueryVarsPanel::rows(&state.query_vars, &state.query_content);
+            let panel_height = (rows.len() as u16 + 3)
+                .max(4)
+                .min(areas.query_window.height / 2);
+            let chunks = ratatui::layout::Layout::default()
+                .direction(ratatui::layout::Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(panel_height)])
+                .split(areas.query_window);
+            components::render_query_vars_panel(
+                frame,
+                chunks[1],
+                panel,
+                &state.query_vars,
+                &state.query_content,
+                &self.theme,
+            );
+            self.draw_query_window(frame, chunks[0], state);
+        } else {
+            self.draw_query_window(frame, areas.query_window, state);
+        }
+
+        // Draw the split without focus from its own tabs and editor
+        if let (Some(output), Some(query_window)) = (areas.split_output, areas.split_query_window) {
+            state.with_other_split(|state| {
+                self.draw_tabular_output(frame, output, state);
+                self.draw_query_window(frame, query_window, state);
+            });
+        }
 
         // Draw status bar
         self.draw_status_bar(frame, areas.status_bar, state);
 
         // Draw help overlay if active
         use crate::ui::help::HelpSystem;
-        HelpSystem::render_help(frame, &state.ui);
+        HelpSystem::render_help(frame, &mut state.ui, &state.hotkeys);
 
         // Cleanup expired toasts
         state.toast_manager.cleanup();
@@ -219,13 +293,61 @@ impl UI {
         // Draw toast notifications
         components::toast::render_toasts(frame, &state.toast_manager, frame.area(), &self.theme);
 
-        // Command mode is handled internally, not shown in UI
+        // Draw the Tables pane action menu below the selected row
+        if let (Some(menu), Some(anchor)) =
+            (&state.table_context_menu, state.ui.tables_selected_row)
+        {
+            components::render_context_menu(frame, menu, anchor);
+        }
+
+        // Draw the command palette above the panes
+        if let Some(palette) = &state.command_palette {
+            components::render_command_palette(frame, palette);
+        }
+
+        // Draw the quick switcher above the panes
+        if let Some(switcher) = &state.quick_switcher {
+            components::render_quick_switcher(frame, switcher);
+        }
+
+        // Draw the snippet picker over the query editor
+        if let Some(picker) = &state.snippet_picker {
+            components::render_snippet_picker(frame, picker);
+        }
+
+        // Draw the theme gallery over the panes it is previewing
+        if let Some(gallery) = &state.theme_gallery {
+            components::render_theme_gallery(frame, gallery);
+        }
+
+        // Draw the lock monitor above the panes
+        if let Some(monitor) = &state.lock_monitor {
+            components::render_lock_monitor(frame, monitor);
+        }
+
+        // Draw the bookmarks above the panes
+        if let Some(bookmarks) = &state.bookmark_list {
+            components::render_bookmark_list(frame, bookmarks);
+        }
+
+        // Draw the connection templates above the panes
+        if let Some(templates) = &state.connection_template_list {
+            components::render_connection_template_list(frame, templates);
+        }
 
         // Draw confirmation modal if active
         if let Some(modal) = &state.ui.confirmation_modal {
             self.render_confirmation_modal(frame, modal, frame.area());
         }
 
+        // Then the typed table name that confirms a truncation or drop
+        if let Some(confirmation) = &state.name_confirmation {
+            components::render_name_confirmation(frame, confirmation);
+        }
+        if let Some(prompt) = &state.rename_prompt {
+            components::render_rename_prompt(frame, prompt);
+        }
+
         // Draw connection modal if active (either add or edit)
         if state.ui.current_view.is_connection_form() || state.ui.current_view.is_connection_form()
         {
@@ -240,7 +362,7 @@ impl UI {
                     .test_start_time
                     .map(|start| start.elapsed().as_secs())
                     .unwrap_or(0),
-                state.connection_timeout_seconds,
+                state.test_timeout_seconds,
             );
         }
 
@@ -257,17 +379,55 @@ impl UI {
             }
         }
 
+        // Draw the unlock prompt above the connection form waiting on it
+        if let Some(prompt) = &state.unlock_prompt {
+            components::render_unlock_prompt(frame, prompt);
+        }
+
         // Draw debug view if active (full-screen overlay)
         if state.ui.current_view.is_debug_view() {
-            let debug_messages = crate::logging::get_debug_messages();
+            let debug_messages = crate::logging::get_debug_messages(state.ui.debug_view_min_level);
             state.debug_view.render(
                 frame,
                 frame.area(),
                 &self.theme,
                 &debug_messages,
                 state.ui.debug_view_scroll_offset,
+                state
+                    .ui
+                    .debug_view_filter_bar
+                    .then_some(state.ui.debug_view_min_level),
             );
         }
+
+        // Draw toast history if active (full-screen overlay)
+        if state.ui.current_view.is_toast_history() {
+            state
+                .toast_history
+                .render(frame, frame.area(), &self.theme, &state.toast_manager);
+        }
+
+        // Draw connection import checklist if active
+        if let Some(connection_import) = &state.connection_import {
+            connection_import.render(frame, frame.area(), &self.theme);
+        }
+
+        // Draw CSV import modal if active
+        if let Some(csv_import) = &state.csv_import {
+            csv_import.render(frame, frame.area(), &self.theme);
+        }
+
+        // Draw create-index modal if active
+        if let Some(index_modal) = &state.index_modal {
+            index_modal.render(frame, frame.area(), &self.theme);
+        }
+
+        // Draw workspace browser if active (full-screen overlay)
+        if state.ui.current_view.is_workspaces() {
+            state
+                .workspace_browser
+                .render(frame, frame.area(), &self.theme);
+        }
     }
 
     /// Draw the header bar
@@ -341,12 +501,17 @@ impl UI {
                     crate::database::DatabaseType::MariaDB => "🗄️",
                     crate::database::DatabaseType::SQLite => "📁",
                     crate::database::DatabaseType::Oracle => "🏛️",
+                    crate::database::DatabaseType::MsSql => "🪟",
                     crate::database::DatabaseType::Redis => "🔴",
                     crate::database::DatabaseType::MongoDB => "🍃",
                 };
 
                 // Format: "🐘 ✓ ConnectionName (postgresql) [DB: database_name] Connected"
-                let db_name = connection.database.as_deref().unwrap_or("default");
+                let db_name = connection
+                    .service_name
+                    .as_deref()
+                    .or(connection.database.as_deref())
+                    .unwrap_or("default");
                 let db_type_name = connection.database_type.display_name();
 
                 let line = Line::from(vec![
@@ -354,7 +519,22 @@ impl UI {
                         format!("{} ", db_type_icon),
                         Style::default().fg(Color::Cyan),
                     ),
-                    Span::styled(format!("{} ", connection.status_symbol()), symbol_style),
+                    Span::styled(
+                        // Spin while an async connect is in flight
+                        if matches!(connection.status, ConnectionStatus::Connecting) {
+                            format!(
+                                "{} ",
+                                widgets::spinner_frame(state.connecting_animation_frame)
+                            )
+                        } else {
+                            format!("{} ", connection.status_symbol())
+                        },
+                        symbol_style,
+                    ),
+                    Span::styled(
+                        if connection.favorite { "★ " } else { "" },
+                        Style::default().fg(Color::Yellow),
+                    ),
                     Span::styled(
                         &connection.name,
                         Style::default()
@@ -373,7 +553,7 @@ impl UI {
                         if matches!(connection.status, ConnectionStatus::Connecting)
                             && state.connecting_in_progress == Some(index)
                         {
-                            let dots = match state.connecting_animation_frame {
+                            let dots = match state.connecting_animation_frame % 3 {
                                 0 => "•",
                                 1 => "••",
                                 2 => "•••",
@@ -420,6 +600,24 @@ impl UI {
                         Span::styled(error, Style::default().fg(Color::Red)),
                     ])));
                 }
+
+                // Session settings applied on connect
+                let label_style = Style::default().fg(Color::DarkGray);
+                if let Some(schema) = &connection.default_schema {
+                    items.push(ListItem::new(Line::from(vec![
+                        Span::styled("Schema: ", label_style),
+                        Span::styled(schema.as_str(), Style::default().fg(Color::Cyan)),
+                    ])));
+                }
+                if !connection.init_sql.is_empty() {
+                    items.push(ListItem::new(Line::from(vec![
+                        Span::styled("Init SQL: ", label_style),
+                        Span::styled(
+                            connection.init_sql.join("; "),
+                            Style::default().fg(Color::Gray),
+                        ),
+                    ])));
+                }
             }
         }
 
@@ -456,6 +654,11 @@ impl UI {
 
     /// Draw the tables/views pane
     fn draw_tables_pane(&self, frame: &mut Frame, area: Rect, state: &mut AppState) {
+        // The routines browser takes over this slot while it has focus
+        if state.ui.focused_pane == FocusedPane::Routines {
+            components::render_routines_pane(frame, area, state, &self.theme);
+            return;
+        }
         // Use the dedicated TablesPane component with database-adaptive features
         components::render_tables_pane(frame, area, state, &self.theme);
     }
@@ -531,6 +734,7 @@ impl UI {
         }
 
         // Pane is enabled - show normal content
+        let mut columns_start = None;
         let details_text = if state.db.tables.is_empty() {
             vec![
                 Line::from(""),
@@ -539,13 +743,23 @@ impl UI {
                     Style::default().fg(Color::Yellow),
                 )]),
             ]
+        } else if let Some(statistics) = state.ui.get_selected_table_name().and_then(|name| {
+            state
+                .db
+                .table_statistics
+                .as_ref()
+                .filter(|statistics| statistics.table_name == name)
+        }) {
+            self.build_table_statistics(statistics, is_focused)
         } else if let Some(selected_table_name) = state.ui.get_selected_table_name() {
-            self.build_comprehensive_table_details(
+            let (lines, start) = self.build_comprehensive_table_details(
                 selected_table_name,
                 &state.db,
                 &state.ui,
                 is_focused,
-            )
+            );
+            columns_start = start;
+            lines
         } else {
             vec![
                 Line::from(""),
@@ -556,20 +770,18 @@ impl UI {
             ]
         };
 
+        state.ui.details_columns_start = columns_start;
+
         // Apply scrolling if content is too long
         let content_height = details_text.len();
         let available_height = area.height.saturating_sub(2) as usize; // Account for borders
 
         // Store content dimensions for scroll bounds checking
         state.ui.details_content_height = content_height;
-        state.ui.details_viewport_height = available_height;
-        state.ui.details_max_scroll_offset = content_height.saturating_sub(available_height);
+        state.ui.reflow_details(available_height);
 
         let visible_lines = if content_height > available_height {
-            let start = state
-                .ui
-                .details_viewport_offset
-                .min(content_height.saturating_sub(available_height));
+            let start = state.ui.details_viewport_offset;
             let end = (start + available_height).min(content_height);
             details_text[start..end].to_vec()
         } else {
@@ -600,15 +812,205 @@ impl UI {
         frame.render_widget(details, area);
     }
 
-    /// Build comprehensive table details with all available metadata
+    /// Build the table statistics view, with warning conditions highlighted
+    fn build_table_statistics(
+        &self,
+        statistics: &crate::database::TableStatistics,
+        is_focused: bool,
+    ) -> Vec<Line<'static>> {
+        let label_color = if is_focused {
+            Color::Cyan
+        } else {
+            Color::DarkGray
+        };
+        let text_color = if is_focused {
+            Color::White
+        } else {
+            Color::Gray
+        };
+        let section_color = if is_focused {
+            Color::Yellow
+        } else {
+            Color::DarkGray
+        };
+        let warning_color = self.theme.get_color("warning");
+        let danger_color = self.theme.get_color("danger");
+        let section = |title: &str| {
+            Line::from(vec![Span::styled(
+                title.to_string(),
+                Style::default()
+                    .fg(section_color)
+                    .add_modifier(if is_focused {
+                        Modifier::BOLD
+                    } else {
+                        Modifier::empty()
+                    }),
+            )])
+        };
+        let counter = |label: &str, value: String, color: Color| {
+            Line::from(vec![
+                Span::styled(format!("  {label}: "), Style::default().fg(label_color)),
+                Span::styled(value, Style::default().fg(color)),
+            ])
+        };
+        let count = |value: Option<i64>| value.map(|value| value.to_string());
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("Object: ".to_string(), Style::default().fg(label_color)),
+                Span::styled(
+                    statistics.table_name.clone(),
+                    Style::default().fg(text_color).add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Source: ".to_string(), Style::default().fg(label_color)),
+                Span::styled(
+                    format!("{} (p to go back)", statistics.source),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]),
+        ];
+        if let Some(ref note) = statistics.note {
+            lines.push(Line::from(vec![Span::styled(
+                format!("ℹ {note}"),
+                Style::default().fg(self.theme.get_color("info")),
+            )]));
+        }
+
+        // Scans
+        let scan_color = if statistics.is_seq_scan_heavy() {
+            warning_color
+        } else {
+            text_color
+        };
+        let scans = [
+            ("Seq scans", count(statistics.seq_scans), scan_color),
+            (
+                "Rows read by full scans",
+                count(statistics.seq_rows_read),
+                scan_color,
+            ),
+            ("Index scans", count(statistics.index_scans), text_color),
+        ];
+        if scans.iter().any(|(_, value, _)| value.is_some()) {
+            lines.push(Line::from(""));
+            lines.push(section("🔎 Scans"));
+            for (label, value, color) in scans {
+                if let Some(value) = value {
+                    lines.push(counter(label, value, color));
+                }
+            }
+            if statistics.is_seq_scan_heavy() {
+                lines.push(Line::from(vec![Span::styled(
+                    "  ⚠ Mostly sequential scans - a missing index?".to_string(),
+                    Style::default().fg(warning_color),
+                )]));
+            }
+        }
+
+        // Rows and maintenance
+        let dead_color = if statistics.has_vacuum_debt() {
+            warning_color
+        } else {
+            text_color
+        };
+        let dead_rows = statistics
+            .dead_rows
+            .map(|dead| match statistics.dead_row_ratio() {
+                Some(ratio) => format!("{dead} ({:.1}%)", ratio * 100.0),
+                None => dead.to_string(),
+            });
+        let rows = [
+            ("Live rows", count(statistics.live_rows), text_color),
+            ("Dead rows", dead_rows, dead_color),
+            ("Inserted", count(statistics.rows_inserted), text_color),
+            ("Updated", count(statistics.rows_updated), text_color),
+            ("Deleted", count(statistics.rows_deleted), text_color),
+            ("Last vacuum", statistics.last_vacuum.clone(), text_color),
+            ("Last analyze", statistics.last_analyze.clone(), text_color),
+        ];
+        if rows.iter().any(|(_, value, _)| value.is_some()) {
+            lines.push(Line::from(""));
+            lines.push(section("🧹 Rows & Maintenance"));
+            for (label, value, color) in rows {
+                if let Some(value) = value {
+                    lines.push(counter(label, value, color));
+                }
+            }
+            if statistics.has_vacuum_debt() {
+                lines.push(Line::from(vec![Span::styled(
+                    "  ⚠ Many dead rows - vacuum is falling behind".to_string(),
+                    Style::default().fg(warning_color),
+                )]));
+            }
+        }
+
+        // Per-index usage, least used first
+        lines.push(Line::from(""));
+        let unused = statistics.unused_indexes().count();
+        lines.push(if unused > 0 {
+            section(&format!("🗂 Index Usage ({unused} unused)"))
+        } else {
+            section("🗂 Index Usage")
+        });
+        if statistics.indexes.is_empty() {
+            lines.push(Line::from(vec![Span::styled(
+                "  No indexes".to_string(),
+                Style::default().fg(Color::DarkGray),
+            )]));
+        }
+        for index in &statistics.indexes {
+            let unused = index.is_unused();
+            let mut spans = vec![
+                Span::styled("  ".to_string(), Style::default()),
+                Span::styled(
+                    index.name.clone(),
+                    Style::default().fg(if unused { danger_color } else { text_color }),
+                ),
+                Span::styled(
+                    match index.scans {
+                        Some(scans) => format!(" {scans} scans"),
+                        None => " scans unknown".to_string(),
+                    },
+                    Style::default().fg(label_color),
+                ),
+            ];
+            if let Some(size) = index.size_bytes {
+                spans.push(Span::styled(
+                    format!(" {}", crate::database::TableMetadata::format_size(size)),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            if index.is_unique {
+                spans.push(Span::styled(
+                    " UNIQUE".to_string(),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            if unused {
+                spans.push(Span::styled(
+                    " ⚠ never used, candidate to drop".to_string(),
+                    Style::default().fg(danger_color),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        lines
+    }
+
+    /// Build comprehensive table details with all available metadata, along
+    /// with the line index of the first entry in the column list
     fn build_comprehensive_table_details(
         &self,
         table_name: String,
         db_state: &crate::state::DatabaseState,
-        _ui_state: &crate::state::UIState,
+        ui_state: &crate::state::UIState,
         is_focused: bool,
-    ) -> Vec<Line<'static>> {
+    ) -> (Vec<Line<'static>>, Option<usize>) {
         let mut lines = Vec::new();
+        let mut columns_start = None;
 
         // Define colors based on focus state
         let label_color = if is_focused {
@@ -636,38 +1038,20 @@ impl UI {
         ]));
 
         // Determine table type
-        let table_type = if let Some(ref db_objects) = db_state.database_objects {
-            if db_objects
-                .tables
-                .iter()
-                .any(|t| t.name == table_name || t.qualified_name() == table_name)
-            {
-                "Table"
-            } else if db_objects
-                .views
-                .iter()
-                .any(|v| v.name == table_name || v.qualified_name() == table_name)
-            {
-                "View"
-            } else if db_objects
-                .materialized_views
-                .iter()
-                .any(|mv| mv.name == table_name || mv.qualified_name() == table_name)
-            {
-                "Materialized View"
-            } else {
-                "Unknown"
-            }
-        } else {
-            "Table"
+        let table_type = match db_state.database_objects {
+            Some(ref db_objects) => db_objects
+                .object_type_of(&table_name)
+                .map(|object_type| object_type.display_name().to_string())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            None => "Table".to_string(),
         };
 
         lines.push(Line::from(vec![
             Span::styled("Type: ".to_string(), Style::default().fg(label_color)),
             Span::styled(
-                table_type.to_string(),
+                table_type.clone(),
                 Style::default().fg(if is_focused {
-                    match table_type {
+                    match table_type.as_str() {
                         "Table" => Color::Blue,
                         "View" => Color::Green,
                         "Materialized View" => Color::Magenta,
@@ -703,9 +1087,14 @@ impl UI {
 
             lines.push(Line::from(vec![
                 Span::styled("  Rows: ".to_string(), Style::default().fg(label_color)),
+                Span::styled(metadata.row_count_label(), Style::default().fg(text_color)),
                 Span::styled(
-                    metadata.row_count.to_string(),
-                    Style::default().fg(text_color),
+                    if metadata.row_count_exact {
+                        " (exact)".to_string()
+                    } else {
+                        " (estimated, c to count)".to_string()
+                    },
+                    Style::default().fg(Color::DarkGray),
                 ),
             ]));
 
@@ -717,6 +1106,130 @@ impl UI {
                 ),
             ]));
 
+            // Column list
+            if !metadata.columns_summary.is_empty() {
+                lines.push(Line::from("".to_string()));
+                lines.push(Line::from(vec![Span::styled(
+                    "📋 Columns".to_string(),
+                    Style::default()
+                        .fg(section_color)
+                        .add_modifier(if is_focused {
+                            Modifier::BOLD
+                        } else {
+                            Modifier::empty()
+                        }),
+                )]));
+
+                columns_start = Some(lines.len());
+                // The selection runs on from the columns into the indexes section
+                let selectable = metadata.columns_summary.len()
+                    + if metadata.supports_indexes() {
+                        metadata.indexes.len()
+                    } else {
+                        0
+                    };
+                let selected = ui_state.details_selected_column.min(selectable - 1);
+                for (index, column) in metadata.columns_summary.iter().enumerate() {
+                    let mut spans = vec![
+                        Span::styled("  ".to_string(), Style::default()),
+                        Span::styled(column.name.clone(), Style::default().fg(text_color)),
+                        Span::styled(
+                            format!(" {}", column.data_type),
+                            Style::default().fg(label_color),
+                        ),
+                        Span::styled(
+                            if column.is_nullable {
+                                " NULL".to_string()
+                            } else {
+                                " NOT NULL".to_string()
+                            },
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                    ];
+                    if column.is_primary_key {
+                        spans.push(Span::styled(
+                            " 🔑 PK".to_string(),
+                            Style::default().fg(if is_focused {
+                                Color::Yellow
+                            } else {
+                                Color::DarkGray
+                            }),
+                        ));
+                    }
+                    if column.default_value.is_some() {
+                        spans.push(Span::styled(
+                            " ⚙ default".to_string(),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                    }
+
+                    let mut line = Line::from(spans);
+                    if is_focused && index == selected {
+                        line = line.style(Style::default().add_modifier(Modifier::REVERSED));
+                    }
+                    lines.push(line);
+                }
+
+                // Index list, directly after the columns so `d` can pick one
+                if metadata.supports_indexes() {
+                    lines.push(Line::from("".to_string()));
+                    lines.push(Line::from(vec![Span::styled(
+                        "🗂 Indexes".to_string(),
+                        Style::default()
+                            .fg(section_color)
+                            .add_modifier(if is_focused {
+                                Modifier::BOLD
+                            } else {
+                                Modifier::empty()
+                            }),
+                    )]));
+
+                    if metadata.indexes.is_empty() {
+                        lines.push(Line::from(vec![Span::styled(
+                            "  No indexes - press I to create one".to_string(),
+                            Style::default().fg(Color::DarkGray),
+                        )]));
+                    }
+                    for (index, info) in metadata.indexes.iter().enumerate() {
+                        let mut spans = vec![
+                            Span::styled("  ".to_string(), Style::default()),
+                            Span::styled(info.name.clone(), Style::default().fg(text_color)),
+                            Span::styled(
+                                format!(" ({})", info.columns.join(", ")),
+                                Style::default().fg(label_color),
+                            ),
+                        ];
+                        if info.is_primary {
+                            spans.push(Span::styled(
+                                " 🔑 PK".to_string(),
+                                Style::default().fg(if is_focused {
+                                    Color::Yellow
+                                } else {
+                                    Color::DarkGray
+                                }),
+                            ));
+                        } else if info.is_unique {
+                            spans.push(Span::styled(
+                                " UNIQUE".to_string(),
+                                Style::default().fg(Color::DarkGray),
+                            ));
+                        }
+                        if let Some(ref index_type) = info.index_type {
+                            spans.push(Span::styled(
+                                format!(" {}", index_type.to_uppercase()),
+                                Style::default().fg(Color::DarkGray),
+                            ));
+                        }
+
+                        let mut line = Line::from(spans);
+                        if is_focused && metadata.columns_summary.len() + index == selected {
+                            line = line.style(Style::default().add_modifier(Modifier::REVERSED));
+                        }
+                        lines.push(line);
+                    }
+                }
+            }
+
             // Storage information
             lines.push(Line::from("".to_string()));
             lines.push(Line::from(vec![Span::styled(
@@ -839,6 +1352,83 @@ impl UI {
                     ),
                 ]));
             }
+
+            // Defining query of views and materialized views
+            if let Some(ref definition) = metadata.view_definition {
+                lines.push(Line::from("".to_string()));
+                let mut header = vec![Span::styled(
+                    "📜 Definition".to_string(),
+                    Style::default()
+                        .fg(section_color)
+                        .add_modifier(if is_focused {
+                            Modifier::BOLD
+                        } else {
+                            Modifier::empty()
+                        }),
+                )];
+                if metadata.is_materialized_view() {
+                    header.push(Span::styled(
+                        " (R to refresh)".to_string(),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                lines.push(Line::from(header));
+                for line in definition.trim().lines() {
+                    lines.push(Line::from(vec![Span::styled(
+                        format!("  {line}"),
+                        Style::default().fg(text_color),
+                    )]));
+                }
+            }
+
+            // Column statistics, fetched on demand with `P`
+            if let Some(profile) = db_state
+                .column_profile
+                .as_ref()
+                .filter(|profile| profile.table_name == metadata.table_name)
+            {
+                let source = match profile.sample_size {
+                    Some(rows) => format!("{}, first {rows} rows", profile.source),
+                    None => profile.source.to_string(),
+                };
+                lines.push(Line::from("".to_string()));
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        "📈 Column Profile".to_string(),
+                        Style::default()
+                            .fg(section_color)
+                            .add_modifier(if is_focused {
+                                Modifier::BOLD
+                            } else {
+                                Modifier::empty()
+                            }),
+                    ),
+                    Span::styled(format!(" ({source})"), Style::default().fg(Color::DarkGray)),
+                ]));
+
+                for column in &profile.columns {
+                    lines.push(Line::from(vec![Span::styled(
+                        format!("  {}", column.name),
+                        Style::default().fg(text_color),
+                    )]));
+                    lines.push(Line::from(vec![Span::styled(
+                        format!("    {}", column.counts_summary()),
+                        Style::default().fg(label_color),
+                    )]));
+                    if let Some(range) = column.range_summary() {
+                        lines.push(Line::from(vec![Span::styled(
+                            format!("    {range}"),
+                            Style::default().fg(label_color),
+                        )]));
+                    }
+                    if !column.top_values.is_empty() {
+                        lines.push(Line::from(vec![Span::styled(
+                            format!("    top: {}", column.top_values.join(", ")),
+                            Style::default().fg(Color::DarkGray),
+                        )]));
+                    }
+                }
+            }
         } else {
             // No metadata loaded yet
             lines.push(Line::from(vec![Span::styled(
@@ -847,7 +1437,7 @@ impl UI {
             )]));
         }
 
-        lines
+        (lines, columns_start)
     }
 
     /// Draw the tabular output area
@@ -1285,7 +1875,7 @@ impl UI {
         }
 
         // Render the QueryEditor component
-        state.query_editor.render(frame, area);
+        state.query_editor.render(frame, area, &self.theme);
 
         // Sync content back to legacy state if it was modified
         let new_content = state.query_editor.get_content().to_string();
@@ -1295,9 +1885,30 @@ impl UI {
         }
     }
 
+    /// Command prompt: the `:` buffer being typed, with the terminal cursor at its end
+    fn draw_command_line(&self, frame: &mut Frame, area: Rect, buffer: &str) {
+        let prompt = Paragraph::new(Line::from(Span::styled(
+            buffer.to_string(),
+            Style::default()
+                .fg(self.theme.get_color("foreground"))
+                .add_modifier(Modifier::BOLD),
+        )))
+        .style(Style::default().bg(self.theme.get_color("background")));
+        frame.render_widget(prompt, area);
+
+        let cursor_x = area.x + buffer.chars().count() as u16;
+        if cursor_x < area.x + area.width {
+            frame.set_cursor_position((cursor_x, area.y));
+        }
+    }
+
     /// Draw the status bar
     fn draw_status_bar(&self, frame: &mut Frame, area: Rect, state: &AppState) {
-        let brand = format!("{} v{}", constants::APP_NAME, constants::VERSION);
+        // The `:` command line takes over the status bar while it is open
+        if state.query_editor.is_in_command_mode() {
+            self.draw_command_line(frame, area, state.query_editor.get_command_buffer());
+            return;
+        }
 
         // Get real connection info
         let connection_text = if let Some(connection) = state
@@ -1345,17 +1956,30 @@ impl UI {
                     )
                 }
             }
+            FocusedPane::Routines => {
+                if state.db.routines.is_empty() {
+                    "[ROUTINES] No routines".to_string()
+                } else {
+                    format!(
+                        "[ROUTINES] Routine {}/{}",
+                        state.ui.selected_routine + 1,
+                        state.db.routines.len()
+                    )
+                }
+            }
             FocusedPane::TabularOutput => {
                 if let Some(tab) = state.table_viewer_state.current_tab() {
+                    // Primary key of the selected row, so you know where you are
+                    let primary_key = (tab.selected_row < tab.rows.len())
+                        .then(|| primary_key_where(&tab.get_primary_key_values(tab.selected_row)))
+                        .flatten()
+                        .map(|fragment| format!(" | PK {}", ellipsize(&fragment, 40)))
+                        .unwrap_or_default();
                     format!(
-                        "[TABLE_VIEWER] Row {} Col {} | {}",
+                        "[TABLE_VIEWER] Row {} Col {}{}",
                         tab.selected_row + 1,
                         tab.selected_col + 1,
-                        if tab.in_edit_mode {
-                            "EDITING"
-                        } else {
-                            "READ-ONLY"
-                        }
+                        primary_key,
                     )
                 } else {
                     "[TABLE_VIEWER] No table open".to_string()
@@ -1366,46 +1990,90 @@ impl UI {
             FocusedPane::Details => "[DETAILS] Table Details".to_string(),
         };
 
-        // Get current date and time
-        let now = chrono::Local::now();
-        let datetime_text = now.format("%b %d, %Y  %H:%M:%S").to_string();
+        // Live timer while a query runs, otherwise the current tab's last query
+        let query_text = if let Some(running) = &state.running_query {
+            format!(
+                " | ⏱ {} {} on {}",
+                if running.cancelling {
+                    "Cancelling…"
+                } else {
+                    "Running"
+                },
+                crate::ui::components::format_elapsed(running.started_at.elapsed()),
+                running.connection_name
+            )
+        } else if let Some(backup) = &state.backup {
+            format!(
+                " | 💾 {} {}",
+                if backup.is_cancelled() {
+                    "Cancelling backup"
+                } else {
+                    "Backing up"
+                },
+                backup.summary()
+            )
+        } else if let Some(stats) = state
+            .table_viewer_state
+            .current_tab()
+            .and_then(|tab| tab.query_stats.as_ref())
+        {
+            format!(" | ⏱ {}", stats.summary())
+        } else {
+            String::new()
+        };
 
         // Add help hint when not showing help
-        let help_hint = if state.ui.help_mode == crate::app::state::HelpMode::None {
-            " | Press ? for help or q to quit"
+        let help_hint = if state.ui.resize_mode {
+            " | RESIZE: h/l width, j/k height, Esc to finish".to_string()
+        } else if state.hotkeys.is_leader_active() {
+            format!(" | +/- resize: {}", state.layout.summary())
+        } else if state.ui.help_mode == crate::app::state::HelpMode::None {
+            " | Press ? for help or q to quit".to_string()
         } else {
-            ""
+            String::new()
         };
 
-        // Calculate the width of left side content
-        let left_content = format!("{brand} | {connection_text} | {position_text}{help_hint}");
-
-        // Calculate padding needed to right-align the date/time
-        let available_width = area.width as usize;
-        let left_width = left_content.len();
-        let datetime_width = datetime_text.len();
-        let padding_width = available_width.saturating_sub(left_width + datetime_width + 2); // 2 for margins
+        // Keys of an unfinished sequence (gg, dd, leader...), shown like Vim's showcmd
+        let pending_text = state
+            .pending_keys()
+            .map(|keys| format!(" ⌨ {keys} "))
+            .unwrap_or_default();
+
+        let slot = |names: &[String]| {
+            let mut spans = Vec::new();
+            for module in crate::config::StatusBarConfig::modules(names) {
+                if let Some(span) =
+                    self.status_module(module, state, &connection_text, &position_text)
+                {
+                    if !spans.is_empty() {
+                        spans.push(Span::raw(MODULE_SEPARATOR));
+                    }
+                    spans.push(span);
+                }
+            }
+            spans
+        };
 
-        let status_line = Line::from(vec![
-            Span::styled(
-                brand.as_str(),
-                Style::default()
-                    .fg(self.theme.get_color("primary_highlight"))
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(" | "),
-            Span::raw(&connection_text),
-            Span::raw(" | "),
-            Span::raw(&position_text),
-            Span::raw(help_hint),
-            Span::raw(" ".repeat(padding_width)),
-            Span::styled(
-                datetime_text,
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::ITALIC),
-            ),
-        ]);
+        // Running queries and hints follow the left slot; pending keys lead the right one
+        let mut left = slot(&self.status_bar.left);
+        left.push(Span::styled(
+            query_text,
+            Style::default().fg(self.theme.get_color("primary_highlight")),
+        ));
+        left.push(Span::raw(help_hint));
+        let mut right = vec![Span::styled(
+            pending_text,
+            Style::default()
+                .fg(self.theme.get_color("warning"))
+                .add_modifier(Modifier::BOLD),
+        )];
+        right.extend(slot(&self.status_bar.right));
+        let status_line = compose_status_line(
+            area.width as usize,
+            left,
+            slot(&self.status_bar.center),
+            right,
+        );
 
         let status_bar = Paragraph::new(status_line).style(
             Style::default()
@@ -1415,4 +2083,101 @@ impl UI {
 
         frame.render_widget(status_bar, area);
     }
+
+    /// Text of one status bar module; None when it has nothing to show
+    fn status_module(
+        &self,
+        module: StatusModule,
+        state: &AppState,
+        connection_text: &str,
+        position_text: &str,
+    ) -> Option<Span<'static>> {
+        let text = match module {
+            StatusModule::Brand => {
+                return Some(Span::styled(
+                    format!("{} v{}", constants::APP_NAME, constants::VERSION),
+                    Style::default()
+                        .fg(self.theme.get_color("primary_highlight"))
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            StatusModule::Connection => connection_text.to_string(),
+            StatusModule::Position => position_text.to_string(),
+            StatusModule::Mode => {
+                let mode = if state.ui.resize_mode {
+                    "RESIZE"
+                } else {
+                    match state.ui.focused_pane {
+                        FocusedPane::TabularOutput => {
+                            match state.table_viewer_state.current_tab() {
+                                Some(tab) if tab.in_edit_mode => "EDITING",
+                                Some(_) => "READ-ONLY",
+                                None => "",
+                            }
+                        }
+                        FocusedPane::QueryWindow => match state.query_editor.visual_mode() {
+                            Some(VisualMode::Char) => "VISUAL",
+                            Some(VisualMode::Line) => "VISUAL LINE",
+                            None if state.query_editor.is_insert_mode() => "INSERT",
+                            None => "NORMAL",
+                        },
+                        _ => "",
+                    }
+                };
+                mode.to_string()
+            }
+            StatusModule::Datetime => {
+                return Some(Span::styled(
+                    chrono::Local::now()
+                        .format("%b %d, %Y  %H:%M:%S")
+                        .to_string(),
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::ITALIC),
+                ));
+            }
+            StatusModule::GitBranch => match std::env::var("GIT_BRANCH") {
+                Ok(branch) if !branch.trim().is_empty() => format!("⎇ {}", branch.trim()),
+                _ => String::new(),
+            },
+            StatusModule::Encoding => "UTF-8".to_string(),
+            StatusModule::LineCount => {
+                let lines = state.query_editor.get_content().lines().count().max(1);
+                format!("{lines} lines")
+            }
+        };
+        (!text.is_empty()).then(|| Span::raw(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn status_line(ui: &UI, state: &AppState) -> (String, ratatui::layout::Position) {
+        let mut terminal = Terminal::new(TestBackend::new(60, 1)).unwrap();
+        terminal
+            .draw(|f| ui.draw_status_bar(f, f.area(), state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let line = (0..60).map(|x| buffer[(x, 0)].symbol()).collect();
+        (line, terminal.get_cursor_position().unwrap())
+    }
+
+    #[test]
+    fn test_command_line_replaces_the_status_bar() {
+        let ui = UI::new(&Config::default()).unwrap();
+        let mut state = AppState::default();
+        let (line, _) = status_line(&ui, &state);
+        assert!(line.starts_with(constants::APP_NAME));
+
+        state.query_editor.enter_command_mode();
+        for c in "w report".chars() {
+            state.query_editor.add_to_command_buffer(c);
+        }
+        let (line, cursor) = status_line(&ui, &state);
+        assert_eq!(line.trim_end(), ":w report");
+        assert_eq!((cursor.x, cursor.y), (9, 0));
+    }
 }
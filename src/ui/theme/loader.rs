@@ -117,6 +117,33 @@ impl ThemeLoader {
         themes
     }
 
+    /// Built-in themes followed by every installed theme, with the file each
+    /// was loaded from. An installed theme replaces a built-in one of the same name.
+    pub fn all_themes() -> Vec<(Theme, Option<PathBuf>)> {
+        let mut themes: Vec<(Theme, Option<PathBuf>)> =
+            vec![(Theme::dark_theme(), None), (Theme::light_theme(), None)];
+        for (_, path) in Self::list_available_themes() {
+            let Ok(theme) = Theme::load_from_file(&path) else {
+                continue;
+            };
+            match themes
+                .iter_mut()
+                .find(|(known, _)| known.name == theme.name)
+            {
+                Some(entry) => *entry = (theme, Some(path)),
+                None => themes.push((theme, Some(path))),
+            }
+        }
+        themes
+    }
+
+    /// Theme called `name`, ignoring case, with the file it was loaded from
+    pub fn find_theme(name: &str) -> Option<(Theme, Option<PathBuf>)> {
+        Self::all_themes()
+            .into_iter()
+            .find(|(theme, _)| theme.name.eq_ignore_ascii_case(name))
+    }
+
     /// Export built-in themes to a directory
     pub fn export_builtin_themes(export_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
         fs::create_dir_all(export_dir)?;
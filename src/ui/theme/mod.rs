@@ -46,6 +46,9 @@ pub struct ThemeColors {
     pub table_row_bg: String,
     pub table_row_alt_bg: String,
     pub selected_cell_bg: String,
+    /// Dimmed NULL marker in result cells
+    #[serde(default = "default_table_null_fg")]
+    pub table_null_fg: String,
 
     // Modal colors
     pub modal_bg: String,
@@ -100,6 +103,11 @@ pub struct ThemeColors {
     pub help_description: String,
 }
 
+/// Older theme files predate `table_null_fg`
+fn default_table_null_fg() -> String {
+    "#6c7086".to_string()
+}
+
 impl Theme {
     pub fn from_toml(content: &str) -> Result<Self, toml::de::Error> {
         toml::from_str(content)
@@ -145,6 +153,7 @@ impl Theme {
             "table_row_bg" => &self.colors.table_row_bg,
             "table_row_alt_bg" => &self.colors.table_row_alt_bg,
             "selected_cell_bg" => &self.colors.selected_cell_bg,
+            "table_null_fg" => &self.colors.table_null_fg,
             "modal_bg" => &self.colors.modal_bg,
             "modal_border" => &self.colors.modal_border,
             "modal_title" => &self.colors.modal_title,
@@ -199,7 +208,10 @@ impl Theme {
             "search_match" => &self.colors.info,
             "search_mode_border" => &self.colors.info,
             "modified_cell" => &self.colors.syntax_string,
-            "null_value" => &self.colors.input_placeholder,
+            "diff_added" => &self.colors.success,
+            "diff_removed" => &self.colors.error,
+            "diff_changed" => &self.colors.warning,
+            "null_value" => &self.colors.table_null_fg,
             "danger" => &self.colors.error,
             "modal_overlay" => "#00000099",
             "modal_background" => &self.colors.modal_bg,
@@ -247,6 +259,7 @@ impl Theme {
                 table_row_bg: "#0d0d0d".to_string(),
                 table_row_alt_bg: "#141424".to_string(),
                 selected_cell_bg: "#2a2a3a".to_string(),
+                table_null_fg: "#6c7086".to_string(),
 
                 // Modal colors
                 modal_bg: "#0d0d0d".to_string(),
@@ -333,6 +346,7 @@ impl Theme {
                 table_row_bg: "#ffffff".to_string(),
                 table_row_alt_bg: "#f5f5f5".to_string(),
                 selected_cell_bg: "#dce0e8".to_string(),
+                table_null_fg: "#9ca0b0".to_string(),
 
                 // Modal colors
                 modal_bg: "#ffffff".to_string(),
@@ -2,4 +2,6 @@
 
 #![forbid(unsafe_code)]
 
-// Custom widgets will be added here
+pub mod spinner;
+
+pub use spinner::{spinner_frame, SPINNER_FRAMES};
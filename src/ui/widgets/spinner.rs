@@ -0,0 +1,24 @@
+// FilePath: src/ui/widgets/spinner.rs
+
+#![forbid(unsafe_code)]
+
+/// Braille spinner, one frame per tick
+pub const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Spinner frame for a tick counter that may wrap
+pub fn spinner_frame(tick: u8) -> &'static str {
+    SPINNER_FRAMES[tick as usize % SPINNER_FRAMES.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_cycle() {
+        assert_eq!(spinner_frame(0), "⠋");
+        assert_eq!(spinner_frame(10), "⠋");
+        assert_eq!(spinner_frame(13), "⠸");
+        assert_eq!(spinner_frame(u8::MAX), SPINNER_FRAMES[255 % 10]);
+    }
+}